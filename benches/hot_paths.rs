@@ -0,0 +1,158 @@
+// Benchmarks for code that runs per frame or per request
+// JSON colouring runs on every redraw of the response panel, formatting on
+// every response, substitution on every request a load test sends, and the
+// percentiles on every load test tick. `cargo bench --bench hot_paths` times
+// them with criterion and then checks each against its budget, failing when
+// one is over so a slowdown shows up before it shows up as UI jank.
+
+use criterion::{black_box, criterion_group, BenchmarkId, Criterion, Throughput};
+use rest_api_tui::formatter::format_json;
+use rest_api_tui::load_test::calculate_percentiles;
+use rest_api_tui::template::substitute;
+use rest_api_tui::tui::ui::colorize_json;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Lines of a response the panel shows at once on a large terminal
+const WINDOW_LINES: usize = 200;
+
+/// A JSON array of `n` user records, roughly 150 bytes each
+fn users_json(n: usize) -> Vec<u8> {
+    let users: Vec<serde_json::Value> = (0..n)
+        .map(|i| serde_json::json!({
+            "id": i,
+            "name": format!("User {}", i),
+            "email": format!("user{}@example.com", i),
+            "active": i % 3 != 0,
+            "score": i as f64 * 1.5,
+            "tags": ["alpha", "beta"],
+            "address": { "city": "Springfield", "zip": format!("{:05}", i) },
+        }))
+        .collect();
+    serde_json::to_vec(&users).unwrap()
+}
+
+/// A body template with `tags` variables over `vars` distinct names
+fn template_with(tags: usize, vars: usize) -> (String, HashMap<String, String>) {
+    let template = (0..tags)
+        .map(|i| format!("\"field{}\": \"{{{{var{}}}}}\",\n", i, i % vars))
+        .collect::<String>();
+    let variables = (0..vars).map(|i| (format!("var{}", i), format!("value-{}", i))).collect();
+    (template, variables)
+}
+
+/// `n` latencies spread between 1ms and 1s, in arrival order
+fn latencies(n: usize) -> Vec<Duration> {
+    (0..n).map(|i| Duration::from_micros(1_000 + (i as u64 * 7_919) % 999_000)).collect()
+}
+
+fn bench_colorize_json(c: &mut Criterion) {
+    let pretty = format_json(&users_json(5_000)).unwrap();
+    let window: String = pretty.lines().take(WINDOW_LINES).collect::<Vec<_>>().join("\n");
+    let mut group = c.benchmark_group("colorize_json");
+    group.throughput(Throughput::Bytes(window.len() as u64));
+    group.bench_function("window", |b| b.iter(|| colorize_json(black_box(&window), 0)));
+    group.throughput(Throughput::Bytes(pretty.len() as u64));
+    group.sample_size(10);
+    group.bench_function("document", |b| b.iter(|| colorize_json(black_box(&pretty), 0)));
+    group.finish();
+}
+
+fn bench_format_json(c: &mut Criterion) {
+    let mut group = c.benchmark_group("format_json");
+    group.sample_size(20);
+    for n in [100, 5_000] {
+        let body = users_json(n);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &body, |b, body| b.iter(|| format_json(black_box(body))));
+    }
+    group.finish();
+}
+
+fn bench_substitute(c: &mut Criterion) {
+    let mut group = c.benchmark_group("substitute");
+    for tags in [10, 500] {
+        let (template, variables) = template_with(tags, 50);
+        group.throughput(Throughput::Bytes(template.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(tags), &template, |b, template| {
+            b.iter(|| substitute(black_box(template), &variables))
+        });
+    }
+    group.finish();
+}
+
+fn bench_percentiles(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calculate_percentiles");
+    group.sample_size(20);
+    for n in [10_000, 100_000] {
+        let samples = latencies(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &samples, |b, samples| b.iter(|| calculate_percentiles(black_box(samples))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_colorize_json, bench_format_json, bench_substitute, bench_percentiles);
+
+/// A hot path and the most its median run may take in a release build
+struct Budget {
+    name: &'static str,
+    limit: Duration,
+    run: Box<dyn FnMut()>,
+}
+
+fn budgets() -> Vec<Budget> {
+    let pretty = format_json(&users_json(5_000)).unwrap();
+    let window: String = pretty.lines().take(WINDOW_LINES).collect::<Vec<_>>().join("\n");
+    let body = users_json(5_000);
+    let (template, variables) = template_with(500, 50);
+    let samples = latencies(100_000);
+    vec![
+        // A redraw has 16ms in all; colouring is only part of it
+        Budget { name: "colorize_json/window", limit: Duration::from_millis(2), run: Box::new(move || { black_box(colorize_json(&window, 0)); }) },
+        Budget { name: "format_json/5000", limit: Duration::from_millis(60), run: Box::new(move || { black_box(format_json(&body).unwrap()); }) },
+        // Load test workers substitute every request's body
+        Budget { name: "substitute/500", limit: Duration::from_millis(1), run: Box::new(move || { black_box(substitute(&template, &variables).unwrap()); }) },
+        // Runs on every load test tick (100ms by default)
+        Budget { name: "calculate_percentiles/100000", limit: Duration::from_millis(25), run: Box::new(move || { black_box(calculate_percentiles(&samples)); }) },
+    ]
+}
+
+/// Median of `runs` timed runs
+fn median_time(run: &mut dyn FnMut(), runs: usize) -> Duration {
+    run(); // Warm up
+    let mut times: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let started = Instant::now();
+            run();
+            started.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[runs / 2]
+}
+
+/// Check every budget; debug builds are too slow for the numbers to mean anything
+fn check_budgets() -> bool {
+    if cfg!(debug_assertions) {
+        return true;
+    }
+    println!("\nBudgets (median of 15 runs):");
+    let mut within = true;
+    for mut budget in budgets() {
+        let median = median_time(&mut budget.run, 15);
+        let ok = median <= budget.limit;
+        within &= ok;
+        println!("  {} {:<30} {:>10.3?} / {:?}", if ok { "✓" } else { "✗" }, budget.name, median, budget.limit);
+    }
+    within
+}
+
+fn main() {
+    benches();
+    Criterion::default().configure_from_args().final_summary();
+    if !check_budgets() {
+        eprintln!("A hot path is over its budget");
+        std::process::exit(1);
+    }
+}
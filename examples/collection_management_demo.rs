@@ -25,6 +25,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         method: HttpMethod::GET,
         url: "https://jsonplaceholder.typicode.com/users".to_string(),
         description: Some("Get all users".to_string()),
+        load_test_config: None,
+        timeout_secs: None,
+        tags: Vec::new(),
+        archived: false,
+        soap: None,
+        protobuf: None,
+        empty_body: Default::default(),
+        visualizer: None,
+        assertions: Vec::new(),
+        connection: Default::default(),
+        extractions: Vec::new(),
+        graphql: None,
         headers: HashMap::new(),
         body_template: None,
         auth: None,
@@ -38,6 +50,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         method: HttpMethod::POST,
         url: "https://jsonplaceholder.typicode.com/posts".to_string(),
         description: Some("Create a new post".to_string()),
+        load_test_config: None,
+        timeout_secs: None,
+        tags: Vec::new(),
+        archived: false,
+        soap: None,
+        protobuf: None,
+        empty_body: Default::default(),
+        visualizer: None,
+        assertions: Vec::new(),
+        connection: Default::default(),
+        extractions: Vec::new(),
+        graphql: None,
         headers: HashMap::new(),
         body_template: Some(r#"{"title": "{{title}}", "body": "{{body}}", "userId": {{userId}}}"#.to_string()),
         auth: None,
@@ -51,6 +75,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         method: HttpMethod::DELETE,
         url: "https://jsonplaceholder.typicode.com/posts/{{postId}}".to_string(),
         description: Some("Delete a post by ID".to_string()),
+        load_test_config: None,
+        timeout_secs: None,
+        tags: Vec::new(),
+        archived: false,
+        soap: None,
+        protobuf: None,
+        empty_body: Default::default(),
+        visualizer: None,
+        assertions: Vec::new(),
+        connection: Default::default(),
+        extractions: Vec::new(),
+        graphql: None,
         headers: HashMap::new(),
         body_template: None,
         auth: None,
@@ -75,7 +111,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("   ID: {}", coll.id);
         println!("   Endpoints: {}", coll.endpoints.len());
         for (j, ep) in coll.endpoints.iter().enumerate() {
-            println!("     {}. {:?} {} - {}", j + 1, ep.method, ep.name, ep.url);
+            println!("     {}. {} {} - {}", j + 1, ep.method, ep.name, ep.url);
         }
     }
     
@@ -30,7 +30,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("   Collection: {}", collection.name);
         println!("   Endpoints: {}", collection.endpoints.len());
         for (i, endpoint) in collection.endpoints.iter().enumerate() {
-            println!("     {}. {:?} {}", i + 1, endpoint.method, endpoint.url);
+            println!("     {}. {} {}", i + 1, endpoint.method, endpoint.url);
         }
     }
     println!();
@@ -62,6 +62,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         body_template: None,
         auth: None,
         load_test_config: None,
+        timeout_secs: None,
+        tags: Vec::new(),
+        archived: false,
+        soap: None,
+        protobuf: None,
+        empty_body: Default::default(),
+        visualizer: None,
+        assertions: Vec::new(),
+        connection: Default::default(),
+        extractions: Vec::new(),
+        graphql: None,
     };
     
     let inputs = RequestInputs {
@@ -69,6 +80,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         query_params: HashMap::new(),
         body: None,
         variables: HashMap::new(),
+        progress: None,
     };
     
     match client.execute(&endpoint, &inputs).await {
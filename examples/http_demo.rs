@@ -1,6 +1,5 @@
-use rest_api_tui::models::{ApiEndpoint, HttpMethod, AuthConfig, ApiKeyLocation};
+use rest_api_tui::models::{ApiEndpoint, HttpMethod, AuthConfig};
 use rest_api_tui::http::{HttpClient, RequestInputs};
-use std::collections::HashMap;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -17,7 +16,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         "https://jsonplaceholder.typicode.com/posts/1".to_string(),
     );
     
-    println!("  {} {}", format!("{:?}", endpoint1.method), endpoint1.url);
+    println!("  {} {}", endpoint1.method, endpoint1.url);
     
     let inputs1 = RequestInputs::default();
     let response1 = client.execute(&endpoint1, &inputs1).await?;
@@ -45,7 +44,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut inputs2 = RequestInputs::default();
     inputs2.query_params.insert("userId".to_string(), "1".to_string());
     
-    println!("  {} {} (with userId=1)", format!("{:?}", endpoint2.method), endpoint2.url);
+    println!("  {} {} (with userId=1)", endpoint2.method, endpoint2.url);
     
     let response2 = client.execute(&endpoint2, &inputs2).await?;
     
@@ -107,7 +106,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     inputs4.variables.insert("body".to_string(), "This is a test post created via REST API TUI".to_string());
     inputs4.variables.insert("user_id".to_string(), "1".to_string());
     
-    println!("  {} {}", format!("{:?}", endpoint4.method), endpoint4.url);
+    println!("  {} {}", endpoint4.method, endpoint4.url);
     println!("  Content-Type: application/json");
     
     let response4 = client.execute(&endpoint4, &inputs4).await?;
@@ -134,7 +133,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut inputs5 = RequestInputs::default();
     inputs5.variables.insert("custom_value".to_string(), "test-123".to_string());
     
-    println!("  {} {}", format!("{:?}", endpoint5.method), endpoint5.url);
+    println!("  {} {}", endpoint5.method, endpoint5.url);
     println!("  Headers: X-Custom-Header, User-Agent");
     
     let response5 = client.execute(&endpoint5, &inputs5).await?;
@@ -170,7 +169,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut inputs6 = RequestInputs::default();
     inputs6.variables.insert("access_token".to_string(), "my-secret-token-12345".to_string());
     
-    println!("  {} {}", format!("{:?}", endpoint6.method), endpoint6.url);
+    println!("  {} {}", endpoint6.method, endpoint6.url);
     println!("  Auth: Bearer token");
     
     let response6 = client.execute(&endpoint6, &inputs6).await?;
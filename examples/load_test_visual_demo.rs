@@ -22,16 +22,14 @@ struct LoadTestSimulation {
     metrics: LoadTestMetrics,
     start_time: Instant,
     duration: Duration,
-    target_requests: u64,
 }
 
 impl LoadTestSimulation {
-    fn new(duration: Duration, target_requests: u64) -> Self {
+    fn new(duration: Duration) -> Self {
         Self {
             metrics: LoadTestMetrics::new(),
             start_time: Instant::now(),
             duration,
-            target_requests,
         }
     }
     
@@ -49,17 +47,17 @@ impl LoadTestSimulation {
         // Simulate varying latencies and occasional failures
         let request_num = self.metrics.total_requests;
         
-        if request_num % 25 == 0 {
+        if request_num.is_multiple_of(25) {
             // Occasional timeout (4% of requests)
             self.metrics.record_failure("Timeout".to_string(), Duration::from_millis(30000));
-        } else if request_num % 50 == 0 {
+        } else if request_num.is_multiple_of(50) {
             // Rare server error (2% of requests)
             self.metrics.record_failure("500 Server Error".to_string(), Duration::from_millis(500));
         } else {
             // Normal request with realistic latency distribution
             let base = 50;
             let variance = (request_num % 20) * 10;
-            let spike = if request_num % 100 == 0 { 200 } else { 0 }; // Occasional spike
+            let spike = if request_num.is_multiple_of(100) { 200 } else { 0 }; // Occasional spike
             let latency = Duration::from_millis(base + variance + spike);
             self.metrics.record_success(latency);
         }
@@ -77,7 +75,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create simulation
     let duration = Duration::from_secs(30);
     let target_requests = 300;
-    let mut simulation = LoadTestSimulation::new(duration, target_requests);
+    let mut simulation = LoadTestSimulation::new(duration);
     
     let mut last_update = Instant::now();
     let update_interval = Duration::from_millis(100); // Update every 100ms
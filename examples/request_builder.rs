@@ -0,0 +1,33 @@
+use rest_api_tui::http::{HttpClient, Request};
+use rest_api_tui::storage::StorageManager;
+use rest_api_tui::variables::VariableManager;
+
+/// Send the first endpoint of a saved collection with the "staging" environment,
+/// the way an integration test in another project would
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("=== REST API TUI - Request Builder Demo ===\n");
+
+    let storage = StorageManager::with_defaults()?;
+    let collections = storage.load_collections()?;
+    let Some(endpoint) = collections.iter().flat_map(|c| &c.endpoints).next() else {
+        println!("No saved endpoints yet; create one in the TUI first.");
+        return Ok(());
+    };
+
+    let variables = VariableManager::new()?;
+    let values = variables.variables_for(Some("staging")).unwrap_or_else(|_| variables.get_all().clone());
+
+    let client = HttpClient::new()?;
+    println!("📝 {} {}", endpoint.method, endpoint.url);
+    let response = Request::from_endpoint(endpoint)
+        .vars(values)
+        .header("X-Request-Source", "request_builder example")
+        .send(&client)
+        .await?;
+
+    println!("  Status: {}", response.status);
+    println!("  Duration: {:?}", response.duration);
+    println!("  Body: {}", response.text().chars().take(200).collect::<String>());
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use rest_api_tui::runner::Collections;
+use rest_api_tui::variables::VariableManager;
+
+/// Run a saved collection the way a CI job would, exiting non-zero on failure:
+/// `cargo run --example run_collection -- "Users API" staging`
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let Some(name) = args.next() else {
+        eprintln!("usage: run_collection <collection> [environment]");
+        std::process::exit(2);
+    };
+    let environment = args.next();
+
+    let collections = Collections::load("~/.rest-api-tui/collections")?;
+    let Some(collection) = collections.get(&name) else {
+        eprintln!("No collection named '{}'", name);
+        std::process::exit(2);
+    };
+    let env = VariableManager::new()?.resolved_set(environment.as_deref())?;
+
+    let run = collection.run(&env, &[]).await?;
+    for result in &run.results {
+        let outcome = if result.passed() { "✓" } else { "✗" };
+        let status = result.status.map(|s| s.to_string()).unwrap_or_else(|| "---".to_string());
+        println!("{} {:<7} {} {} ({}ms)", outcome, result.method, status, result.url, result.duration_ms);
+        for failure in result.error.iter().chain(&result.assertion_failures) {
+            println!("    {}", failure);
+        }
+    }
+    println!("\n{} passed, {} failed in {}ms", run.passed(), run.failed(), run.duration_ms);
+    std::process::exit(if run.is_success() { 0 } else { 1 });
+}
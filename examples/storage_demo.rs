@@ -35,6 +35,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             token: "{{github_token}}".to_string(),
         }),
         description: Some("Fetch a GitHub user by username".to_string()),
+        load_test_config: None,
+        timeout_secs: None,
+        tags: Vec::new(),
+        archived: false,
+        soap: None,
+        protobuf: None,
+        empty_body: Default::default(),
+        visualizer: None,
+        assertions: Vec::new(),
+        connection: Default::default(),
+        extractions: Vec::new(),
+        graphql: None,
     };
     
     let endpoint2 = ApiEndpoint {
@@ -50,6 +62,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             location: ApiKeyLocation::Header,
         }),
         description: Some("List repositories for a user".to_string()),
+        load_test_config: None,
+        timeout_secs: None,
+        tags: Vec::new(),
+        archived: false,
+        soap: None,
+        protobuf: None,
+        empty_body: Default::default(),
+        visualizer: None,
+        assertions: Vec::new(),
+        connection: Default::default(),
+        extractions: Vec::new(),
+        graphql: None,
     };
     
     collection.add_endpoint(endpoint1);
@@ -59,7 +83,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  ID: {}", collection.id);
     println!("  Endpoints: {}", collection.endpoints.len());
     for (i, endpoint) in collection.endpoints.iter().enumerate() {
-        println!("    {}. {:?} {} - {}", i + 1, endpoint.method, endpoint.name, endpoint.url);
+        println!("    {}. {} {} - {}", i + 1, endpoint.method, endpoint.name, endpoint.url);
     }
     println!();
     
@@ -90,6 +114,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         body_template: None,
         auth: None,
         description: Some("Fetch all posts".to_string()),
+        load_test_config: None,
+        timeout_secs: None,
+        tags: Vec::new(),
+        archived: false,
+        soap: None,
+        protobuf: None,
+        empty_body: Default::default(),
+        visualizer: None,
+        assertions: Vec::new(),
+        connection: Default::default(),
+        extractions: Vec::new(),
+        graphql: None,
     };
     collection2.add_endpoint(endpoint3);
     storage.save_collection(&collection2)?;
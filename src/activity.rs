@@ -0,0 +1,125 @@
+// Activity journal
+// Imports and edits of collections are appended as JSON lines to
+// activity.jsonl next to the collections directory. Together with request
+// history and load test runs they make up the activity timeline.
+
+use crate::models::ApiCollection;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ActivityError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, ActivityError>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityKind {
+    Import,
+    Edit,
+    Delete,
+}
+
+impl std::fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ActivityKind::Import => "import",
+            ActivityKind::Edit => "edit",
+            ActivityKind::Delete => "delete",
+        })
+    }
+}
+
+/// One change to a collection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    pub timestamp: DateTime<Utc>,
+    pub kind: ActivityKind,
+    pub collection_id: Uuid,
+    pub collection: String, // Name at the time, so deleted collections still read well
+    pub summary: String,
+}
+
+impl ActivityEntry {
+    pub fn new(kind: ActivityKind, collection: &ApiCollection, summary: impl Into<String>) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            kind,
+            collection_id: collection.id,
+            collection: collection.name.clone(),
+            summary: summary.into(),
+        }
+    }
+}
+
+/// Persistent, append-only journal of collection changes
+pub struct ActivityLog {
+    path: PathBuf,
+    entries: Vec<ActivityEntry>,
+}
+
+impl ActivityLog {
+    /// Load the journal; a missing file is an empty one
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut entries = Vec::new();
+        if path.exists() {
+            for line in fs::read_to_string(path)?.lines() {
+                // Skip lines that don't parse (e.g. a write cut short by a crash)
+                if let Ok(entry) = serde_json::from_str::<ActivityEntry>(line) {
+                    entries.push(entry);
+                }
+            }
+        }
+        Ok(Self { path: path.to_path_buf(), entries })
+    }
+
+    /// Append an entry to memory and disk
+    pub fn record(&mut self, entry: ActivityEntry) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// All entries, oldest first
+    pub fn entries(&self) -> &[ActivityEntry] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_reload() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("activity.jsonl");
+        let collection = ApiCollection::new("Shop".to_string());
+
+        let mut log = ActivityLog::load(&path).unwrap();
+        assert!(log.entries().is_empty());
+        log.record(ActivityEntry::new(ActivityKind::Import, &collection, "Imported from shop.json")).unwrap();
+        log.record(ActivityEntry::new(ActivityKind::Edit, &collection, "Saved 'orders'")).unwrap();
+        fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"{\"truncated\n").unwrap();
+
+        let reloaded = ActivityLog::load(&path).unwrap();
+        assert_eq!(reloaded.entries(), log.entries());
+        assert_eq!(reloaded.entries()[1].kind, ActivityKind::Edit);
+        assert_eq!(reloaded.entries()[1].collection, "Shop");
+    }
+}
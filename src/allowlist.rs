@@ -0,0 +1,115 @@
+// Outgoing host allowlist
+// A safety mode for the workspace: once enabled, requests only go out without
+// asking to hosts listed in allowlist.json next to the collections directory,
+// e.g. so an imported collection pointing at production can't fire by accident.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AllowlistError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, AllowlistError>;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostAllowlist {
+    /// Off by default: every host is allowed
+    #[serde(default)]
+    pub enabled: bool,
+    /// Host names; `*.example.com` matches any subdomain of example.com
+    #[serde(default)]
+    pub hosts: Vec<String>,
+}
+
+impl HostAllowlist {
+    /// Load the allowlist; a missing file means allowlist mode is off
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Whether requests to this host may go out without confirmation
+    pub fn allows(&self, host: &str) -> bool {
+        !self.enabled || self.hosts.iter().any(|pattern| host_matches(pattern, host))
+    }
+
+    pub fn add(&mut self, host: &str) {
+        if !self.hosts.iter().any(|h| h.eq_ignore_ascii_case(host)) {
+            self.hosts.push(host.to_lowercase());
+        }
+    }
+}
+
+fn host_matches(pattern: &str, host: &str) -> bool {
+    let (pattern, host) = (pattern.trim().to_lowercase(), host.to_lowercase());
+    match pattern.strip_prefix("*.") {
+        Some(domain) => host.strip_suffix(domain).is_some_and(|rest| rest.ends_with('.')),
+        None => pattern == host,
+    }
+}
+
+/// Host part of a URL; scheme-less URLs are read as http
+pub fn host_of(url: &str) -> Option<String> {
+    // `localhost:8080/x` parses with "localhost" as the scheme, so retry whenever there's no host
+    let host = |url: &str| reqwest::Url::parse(url).ok()?.host_str().map(str::to_string);
+    let host = host(url).or_else(|| host(&format!("http://{}", url)))?;
+    Some(host.trim_start_matches('[').trim_end_matches(']').to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        let mut allowlist = HostAllowlist::default();
+        assert!(allowlist.allows("prod.example.com"));
+
+        allowlist.enabled = true;
+        allowlist.hosts = vec!["localhost".to_string(), "*.staging.example.com".to_string()];
+        assert!(allowlist.allows("LOCALHOST"));
+        assert!(allowlist.allows("api.staging.example.com"));
+        assert!(!allowlist.allows("staging.example.com"));
+        assert!(!allowlist.allows("evilstaging.example.com"));
+        assert!(!allowlist.allows("prod.example.com"));
+
+        allowlist.add("Prod.Example.com");
+        allowlist.add("prod.example.com");
+        assert_eq!(allowlist.hosts.len(), 3);
+        assert!(allowlist.allows("prod.example.com"));
+    }
+
+    #[test]
+    fn test_host_of() {
+        assert_eq!(host_of("https://API.example.com:8443/v1?x=1").as_deref(), Some("api.example.com"));
+        assert_eq!(host_of("localhost:8080/users").as_deref(), Some("localhost"));
+        assert_eq!(host_of("http://[::1]:3000/").as_deref(), Some("::1"));
+        assert_eq!(host_of(""), None);
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("allowlist.json");
+        assert_eq!(HostAllowlist::load(&path).unwrap(), HostAllowlist::default());
+
+        let allowlist = HostAllowlist { enabled: true, hosts: vec!["localhost".to_string()] };
+        allowlist.save(&path).unwrap();
+        assert_eq!(HostAllowlist::load(&path).unwrap(), allowlist);
+    }
+}
@@ -0,0 +1,191 @@
+// Endpoint changelog
+// Field-level edits to a collection's endpoints (who, when, which field, old
+// and new value), kept in the collection file itself so a shared collection
+// carries its own history: "who pointed the URL at staging, and when".
+
+use crate::models::{ApiCollection, ApiEndpoint, ApiKeyLocation, AuthConfig};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+/// Changes kept per collection; the oldest go first
+pub const MAX_CHANGES: usize = 500;
+
+/// Longer values are cut, so a big body template doesn't bloat the collection file
+const MAX_VALUE_CHARS: usize = 200;
+
+/// The field of created and deleted endpoints; its value is `METHOD url`
+pub const ENDPOINT_FIELD: &str = "endpoint";
+
+/// One field of one endpoint changing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointChange {
+    pub timestamp: DateTime<Utc>,
+    pub author: String,
+    pub endpoint_id: Uuid,
+    pub endpoint: String, // Name at the time, so deleted endpoints still read well
+    pub field: String,
+    pub old: String, // Empty when the field was unset or the endpoint didn't exist
+    pub new: String,
+}
+
+/// Who is making changes: the OS user, as shared collections have no accounts
+pub fn author() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Tracked fields of an endpoint and how they read in the log; auth shows its kind, never the secret
+fn fields(endpoint: &ApiEndpoint) -> Vec<(String, String)> {
+    let join = |items: Vec<String>, separator: &str| items.join(separator);
+    let auth = endpoint.auth.as_ref().map(|auth| match auth {
+        AuthConfig::Bearer { .. } => "Bearer token".to_string(),
+        AuthConfig::Basic { username, .. } => format!("Basic, user {}", username),
+        AuthConfig::ApiKey { name, location: ApiKeyLocation::Header, .. } => format!("API key in header {}", name),
+        AuthConfig::ApiKey { name, location: ApiKeyLocation::QueryParam, .. } => format!("API key in query {}", name),
+    });
+    let graphql = endpoint.graphql.as_ref();
+    let mut fields = vec![
+        ("name", endpoint.name.clone()),
+        ("method", endpoint.method.to_string()),
+        ("url", endpoint.url.clone()),
+        ("body", endpoint.body_template.clone().unwrap_or_default()),
+        ("auth", auth.unwrap_or_default()),
+        ("description", endpoint.description.clone().unwrap_or_default()),
+        ("timeout", endpoint.timeout_secs.map(|secs| format!("{}s", secs)).unwrap_or_default()),
+        ("tags", join(endpoint.tags.clone(), ", ")),
+        ("archived", if endpoint.archived { "yes".to_string() } else { String::new() }),
+        ("assertions", join(endpoint.assertions.iter().map(|a| a.to_string()).collect(), "; ")),
+        ("extractions", join(endpoint.extractions.iter().map(|e| e.to_string()).collect(), "; ")),
+        ("connection", endpoint.connection.to_string()),
+        ("graphql query", graphql.map(|g| g.query.clone()).unwrap_or_default()),
+        ("graphql variables", graphql.map(|g| g.variables.clone()).unwrap_or_default()),
+        ("protobuf", endpoint.protobuf.as_ref().map(|p| p.to_string()).unwrap_or_default()),
+        ("visualizer", endpoint.visualizer.clone().unwrap_or_default()),
+    ]
+    .into_iter()
+    .map(|(field, value)| (field.to_string(), value))
+    .collect::<Vec<_>>();
+    let mut headers: Vec<_> = endpoint.headers.iter().collect();
+    headers.sort();
+    fields.extend(headers.into_iter().map(|(name, value)| (format!("header {}", name), value.clone())));
+    fields
+}
+
+/// (field, old, new) for every tracked field that differs; None for an endpoint that doesn't exist
+pub fn diff(before: Option<&ApiEndpoint>, after: Option<&ApiEndpoint>) -> Vec<(String, String, String)> {
+    let summary = |endpoint: &ApiEndpoint| format!("{} {}", endpoint.method, endpoint.url);
+    let (before, after) = match (before, after) {
+        (Some(before), Some(after)) => (fields(before), fields(after)),
+        (None, Some(after)) => return vec![(ENDPOINT_FIELD.to_string(), String::new(), summary(after))],
+        (Some(before), None) => return vec![(ENDPOINT_FIELD.to_string(), summary(before), String::new())],
+        (None, None) => return Vec::new(),
+    };
+    let value = |fields: &[(String, String)], name: &str| {
+        fields.iter().find(|(field, _)| field == name).map(|(_, value)| value.clone()).unwrap_or_default()
+    };
+    // Fixed fields in their order, then headers in name order
+    let mut names: Vec<String> = before.iter().map(|(field, _)| field.clone()).collect();
+    let headers: BTreeSet<&String> = after.iter().map(|(field, _)| field).filter(|field| !names.contains(field)).collect();
+    names.extend(headers.into_iter().cloned());
+    names.into_iter()
+        .filter_map(|name| {
+            let (old, new) = (value(&before, &name), value(&after, &name));
+            (old != new).then_some((name, old, new))
+        })
+        .collect()
+}
+
+/// Log how `after` differs from `before`, matching endpoints by ID
+pub fn record(before: &ApiCollection, after: &mut ApiCollection) {
+    let author = author();
+    let timestamp = Utc::now();
+    let mut changes = Vec::new();
+    let mut log = |endpoint: &ApiEndpoint, old: Option<&ApiEndpoint>, new: Option<&ApiEndpoint>| {
+        for (field, old, new) in diff(old, new) {
+            changes.push(EndpointChange {
+                timestamp,
+                author: author.clone(),
+                endpoint_id: endpoint.id,
+                endpoint: endpoint.name.clone(),
+                field,
+                old: cut(old),
+                new: cut(new),
+            });
+        }
+    };
+    for endpoint in &after.endpoints {
+        log(endpoint, before.endpoints.iter().find(|e| e.id == endpoint.id), Some(endpoint));
+    }
+    for endpoint in before.endpoints.iter().filter(|e| !after.endpoints.iter().any(|a| a.id == e.id)) {
+        log(endpoint, Some(endpoint), None);
+    }
+    after.changelog.extend(changes);
+    let excess = after.changelog.len().saturating_sub(MAX_CHANGES);
+    after.changelog.drain(..excess);
+}
+
+fn cut(value: String) -> String {
+    if value.chars().count() <= MAX_VALUE_CHARS {
+        return value;
+    }
+    let kept: String = value.chars().take(MAX_VALUE_CHARS - 1).collect();
+    format!("{}…", kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::HttpMethod;
+
+    #[test]
+    fn test_diff_endpoints() {
+        let mut before = ApiEndpoint::new("orders".to_string(), HttpMethod::GET, "https://api.example.com/orders".to_string());
+        before.headers.insert("X-Tenant".to_string(), "acme".to_string());
+        before.headers.insert("Accept".to_string(), "application/json".to_string());
+        let mut after = before.clone();
+        after.url = "https://staging.example.com/orders".to_string();
+        after.headers.remove("X-Tenant");
+        after.headers.insert("X-Debug".to_string(), "1".to_string());
+        after.auth = Some(AuthConfig::Bearer { token: "s3cret".to_string() });
+
+        assert_eq!(diff(Some(&before), Some(&after)), vec![
+            ("url".to_string(), "https://api.example.com/orders".to_string(), "https://staging.example.com/orders".to_string()),
+            ("auth".to_string(), String::new(), "Bearer token".to_string()),
+            ("header X-Tenant".to_string(), "acme".to_string(), String::new()),
+            ("header X-Debug".to_string(), String::new(), "1".to_string()),
+        ]);
+        assert!(diff(Some(&before), Some(&before)).is_empty());
+        assert_eq!(diff(None, Some(&before)), vec![("endpoint".to_string(), String::new(), "GET https://api.example.com/orders".to_string())]);
+    }
+
+    #[test]
+    fn test_record_collection_changes() {
+        let mut collection = ApiCollection::new("Shop".to_string());
+        collection.add_endpoint(ApiEndpoint::new("orders".to_string(), HttpMethod::GET, "https://a/orders".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("users".to_string(), HttpMethod::GET, "https://a/users".to_string()));
+        let before = collection.clone();
+        collection.endpoints[0].body_template = Some("x".repeat(500));
+        collection.endpoints.remove(1);
+
+        record(&before, &mut collection);
+        let changes: Vec<(&str, &str)> = collection.changelog.iter().map(|c| (c.endpoint.as_str(), c.field.as_str())).collect();
+        assert_eq!(changes, vec![("orders", "body"), ("users", "endpoint")]);
+        assert_eq!(collection.changelog[0].new.chars().count(), MAX_VALUE_CHARS);
+        assert_eq!(collection.changelog[1].old, "GET https://a/users");
+        assert_eq!(collection.changelog[1].author, author());
+
+        // Only the most recent changes are kept
+        for i in 0..MAX_CHANGES {
+            let before = collection.clone();
+            collection.endpoints[0].url = format!("https://a/orders/{}", i);
+            record(&before, &mut collection);
+        }
+        assert_eq!(collection.changelog.len(), MAX_CHANGES);
+        assert_eq!(collection.changelog[0].field, "url");
+    }
+}
@@ -0,0 +1,575 @@
+// Headless command line
+// `rest_api_tui run <collection> <endpoint> --env prod --var id=42` sends a
+// saved endpoint without starting the TUI and prints the formatted response,
+// for shell scripts and CI. The request goes out the way the TUI would send
+// it: config.toml's timeout and redirects, proxy.toml, and the environment's
+// variables and client defaults. `loadtest` runs the load test engine against
+// a saved endpoint the same way and writes a JSON or CSV report, so a CI job
+// can fail on errors. Without a subcommand the TUI starts.
+
+use crate::formatter::{self, ViewAs};
+use crate::http::{HttpClient, HttpError, Request};
+#[cfg(feature = "load-test")]
+use crate::load_test::{LoadTestConfig, LoadTestEngine, LoadTestReport, LoadTestTarget};
+use crate::models::{ApiCollection, ApiEndpoint};
+use crate::proxy::{ProxyConfig, ProxyError};
+use crate::runner::{Collections, EndpointResult, RunnerError};
+use crate::settings::{Settings, SettingsError};
+use crate::storage::{self, StorageError};
+use crate::variables::{VariableError, VariableManager, VariableSet};
+use clap::{Args, Parser, Subcommand};
+#[cfg(feature = "load-test")]
+use clap::ValueEnum;
+use std::io::{self, Write};
+use std::path::Path;
+#[cfg(feature = "load-test")]
+use std::{path::PathBuf, time::Duration};
+use std::time::Instant;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Runner(#[from] RunnerError),
+
+    #[error("{0}")]
+    Settings(#[from] SettingsError),
+
+    #[error("{0}")]
+    Proxy(#[from] ProxyError),
+
+    #[error("{0}")]
+    Variables(#[from] VariableError),
+
+    #[error("HTTP error: {0}")]
+    Http(#[from] HttpError),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    #[error("Invalid load test: {0}")]
+    LoadTest(String),
+
+    #[error("No collection named '{0}'")]
+    CollectionNotFound(String),
+
+    #[error("No endpoint named '{endpoint}' in '{collection}'")]
+    EndpointNotFound { collection: String, endpoint: String },
+
+    #[error("No home directory; set REST_API_TUI_HOME")]
+    NoDataDir,
+}
+
+pub type Result<T> = std::result::Result<T, CliError>;
+
+/// Exit code for a request that was sent but failed (status, assertions)
+pub const EXIT_FAILED: i32 = 1;
+/// Exit code for anything that kept the request from being sent
+pub const EXIT_ERROR: i32 = 2;
+
+#[derive(Debug, Parser)]
+#[command(name = "rest_api_tui", version, about = "Terminal client for REST APIs; starts the TUI without a command")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Send a saved endpoint and print its response
+    Run(RunArgs),
+    /// Load test a saved endpoint and write a report
+    #[cfg(feature = "load-test")]
+    Loadtest(LoadTestArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct RunArgs {
+    /// Collection name (case-insensitive) or id
+    pub collection: String,
+
+    /// Endpoint name (case-insensitive) or id
+    pub endpoint: String,
+
+    /// Environment whose variables and client defaults apply
+    #[arg(long, value_name = "NAME")]
+    pub env: Option<String>,
+
+    /// A variable for this request, over the saved and environment ones; repeatable
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = parse_var)]
+    pub vars: Vec<(String, String)>,
+
+    /// Print the status line and headers before the body
+    #[arg(short, long)]
+    pub include: bool,
+
+    /// Print the body as received instead of pretty-printing it
+    #[arg(long)]
+    pub raw: bool,
+}
+
+#[cfg(feature = "load-test")]
+#[derive(Debug, Clone, Args)]
+pub struct LoadTestArgs {
+    /// Collection name (case-insensitive) or id
+    pub collection: String,
+
+    /// Endpoint name (case-insensitive) or id
+    pub endpoint: String,
+
+    /// Workers sending requests at once
+    #[arg(short, long, default_value_t = 10)]
+    pub concurrency: usize,
+
+    /// How long to run, in seconds
+    #[arg(short, long, value_name = "SECONDS", default_value_t = 30)]
+    pub duration: u64,
+
+    /// Start the workers over this many seconds instead of all at once
+    #[arg(long, value_name = "SECONDS")]
+    pub ramp_up: Option<u64>,
+
+    /// Environment whose variables and client defaults apply
+    #[arg(long, value_name = "NAME")]
+    pub env: Option<String>,
+
+    /// A variable for every request, over the saved and environment ones; repeatable
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = parse_var)]
+    pub vars: Vec<(String, String)>,
+
+    /// Write the report here as well as to the results directory
+    #[arg(short, long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// Report format; defaults to csv for a .csv output and json otherwise
+    #[arg(long, value_enum)]
+    pub format: Option<ReportFormat>,
+
+    /// Percentage of failed requests (errors and failed assertions) the run may have and still pass
+    #[arg(long, value_name = "PERCENT", default_value_t = 0.0)]
+    pub max_error_rate: f64,
+}
+
+#[cfg(feature = "load-test")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+#[cfg(feature = "load-test")]
+impl LoadTestArgs {
+    /// The format asked for, or the one the output's extension names
+    pub fn report_format(&self) -> ReportFormat {
+        self.format.unwrap_or_else(|| match self.output.as_ref().and_then(|p| p.extension()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ReportFormat::Csv,
+            _ => ReportFormat::Json,
+        })
+    }
+
+    /// Whether a finished run is within the allowed error rate; a run that
+    /// sent nothing never passes
+    pub fn passed(&self, report: &LoadTestReport) -> bool {
+        let failed = report.failed_requests + report.assertion_failed_requests;
+        report.total_requests > 0 && failed as f64 * 100.0 <= self.max_error_rate * report.total_requests as f64
+    }
+}
+
+fn parse_var(text: &str) -> std::result::Result<(String, String), String> {
+    match text.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got '{}'", text)),
+    }
+}
+
+/// Run a command, printing to stdout and stderr; returns the exit code
+pub fn run(command: Command) -> i32 {
+    let Some(dir) = storage::data_dir() else {
+        eprintln!("{}", CliError::NoDataDir);
+        return EXIT_ERROR;
+    };
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("{}", e);
+            return EXIT_ERROR;
+        }
+    };
+    match command {
+        Command::Run(args) => match runtime.block_on(run_endpoint(&dir, &args, &mut io::stdout().lock())) {
+            Ok(result) => {
+                for failure in result.error.iter().chain(&result.assertion_failures).chain(&result.extraction_failures) {
+                    eprintln!("✗ {}", failure);
+                }
+                if result.passed() { 0 } else { EXIT_FAILED }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                EXIT_ERROR
+            }
+        },
+        #[cfg(feature = "load-test")]
+        Command::Loadtest(args) => match runtime.block_on(run_load_test(&dir, &args, &mut io::stdout().lock())) {
+            Ok(report) if args.passed(&report) => 0,
+            Ok(_) => {
+                eprintln!("✗ more than {}% of requests failed", args.max_error_rate);
+                EXIT_FAILED
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                EXIT_ERROR
+            }
+        },
+    }
+}
+
+/// Send a saved endpoint from the workspace in `dir` (usually
+/// `~/.rest-api-tui`) and write its response to `out`. The result says whether
+/// it passed: a 2xx/3xx status, or the endpoint's own assertions
+pub async fn run_endpoint(dir: &Path, args: &RunArgs, out: &mut impl Write) -> Result<EndpointResult> {
+    let collections = Collections::load(dir.join("collections"))?;
+    let (collection, endpoint) = find(&collections, &args.collection, &args.endpoint)?;
+    let env = VariableManager::with_path(dir.join("variables.json"))?.resolved_set(args.env.as_deref())?;
+    let mut variables = env.variables.clone();
+    variables.extend(args.vars.iter().cloned());
+    let client = client_for(dir, collection, &env)?;
+
+    let mut result = EndpointResult::new(endpoint, &variables);
+    let started = Instant::now();
+    match Request::from_endpoint(endpoint).vars(variables).send(&client).await {
+        Ok(response) => {
+            result.record_response(endpoint, &[], &response);
+            if args.include {
+                let reason = response.status.canonical_reason().unwrap_or_default();
+                writeln!(out, "HTTP {} {} ({}ms)", response.status.as_u16(), reason, result.duration_ms)?;
+                let mut headers: Vec<_> = response.headers.iter().collect();
+                headers.sort();
+                for (name, value) in headers {
+                    writeln!(out, "{}: {}", name, value)?;
+                }
+                writeln!(out)?;
+            }
+            if args.raw {
+                out.write_all(&response.body)?;
+            } else if !response.body.is_empty() {
+                let body = formatter::format_body(&response.body, response.content_type(), ViewAs::Auto)
+                    .unwrap_or_else(|_| response.text());
+                writeln!(out, "{}", body.trim_end())?;
+            }
+        }
+        Err(e) => result.record_error(e.to_string(), started.elapsed()),
+    }
+    Ok(result)
+}
+
+#[cfg(feature = "load-test")]
+/// Load test a saved endpoint from the workspace in `dir`, save the report to
+/// its results directory and to `--output`, and write a summary to `out`
+pub async fn run_load_test(dir: &Path, args: &LoadTestArgs, out: &mut impl Write) -> Result<LoadTestReport> {
+    let collections = Collections::load(dir.join("collections"))?;
+    let (collection, endpoint) = find(&collections, &args.collection, &args.endpoint)?;
+    let env = VariableManager::with_path(dir.join("variables.json"))?.resolved_set(args.env.as_deref())?;
+    let mut variables = env.variables.clone();
+    variables.extend(args.vars.iter().cloned());
+    let client = client_for(dir, collection, &env)?;
+
+    let mut config = LoadTestConfig::new(args.concurrency, Duration::from_secs(args.duration));
+    if let Some(ramp_up) = args.ramp_up {
+        config = config.with_ramp_up(Duration::from_secs(ramp_up));
+    }
+    let mut engine = LoadTestEngine::new(config).map_err(CliError::LoadTest)?;
+    engine.set_target(LoadTestTarget {
+        collection_id: collection.id,
+        endpoint_id: endpoint.id,
+        endpoint_name: endpoint.name.clone(),
+        method: endpoint.method.to_string(),
+        url: endpoint.url.clone(),
+    });
+    writeln!(out, "Load testing '{}' with {} workers for {}s...", endpoint.name, args.concurrency, args.duration)?;
+    engine.set_start_time(Instant::now());
+    engine.set_running(true);
+    engine.workers(endpoint.clone(), client, variables).await;
+    let report = engine.take_report().expect("a finished run with a target has a report");
+
+    let storage = storage::StorageManager::new(dir.join("collections"), dir.join("results"))?;
+    let saved = storage.save_load_test_report(&report)?;
+    let latency = &report.latency_ms;
+    writeln!(out, "{} requests in {:.1}s ({:.1} req/s): {} ok, {} failed, {} failed assertions",
+        report.total_requests, report.duration_secs, report.avg_rps,
+        report.successful_requests, report.failed_requests, report.assertion_failed_requests)?;
+    writeln!(out, "Latency (ms): avg {:.1}, p50 {:.1}, p90 {:.1}, p95 {:.1}, p99 {:.1}, max {:.1}",
+        latency.avg, latency.p50, latency.p90, latency.p95, latency.p99, latency.max)?;
+    let mut errors: Vec<_> = report.error_counts.iter().chain(&report.assertion_failure_counts).collect();
+    errors.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    for (error, count) in errors {
+        writeln!(out, "  {} × {}", count, error)?;
+    }
+    writeln!(out, "Saved to {}", saved.display())?;
+    if let Some(path) = &args.output {
+        let contents = match args.report_format() {
+            ReportFormat::Json => serde_json::to_string_pretty(&report).map_err(io::Error::from)?,
+            ReportFormat::Csv => report_csv(&report),
+        };
+        std::fs::write(path, contents)?;
+        writeln!(out, "Report written to {}", path.display())?;
+    }
+    Ok(report)
+}
+
+#[cfg(feature = "load-test")]
+/// A header row and one row for the run, for spreadsheets and CI dashboards
+pub fn report_csv(report: &LoadTestReport) -> String {
+    let rate = |n: u64| if report.total_requests > 0 { n as f64 / report.total_requests as f64 } else { 0.0 };
+    let latency = &report.latency_ms;
+    let fields = [
+        csv_field(&report.target.endpoint_name),
+        report.target.method.clone(),
+        csv_field(&report.target.url),
+        report.started_at.to_rfc3339(),
+        format!("{:.3}", report.duration_secs),
+        report.concurrency.to_string(),
+        report.total_requests.to_string(),
+        report.successful_requests.to_string(),
+        report.failed_requests.to_string(),
+        report.assertion_failed_requests.to_string(),
+        format!("{:.4}", rate(report.successful_requests)),
+        format!("{:.4}", rate(report.failed_requests)),
+        format!("{:.4}", rate(report.assertion_failed_requests)),
+        format!("{:.2}", report.avg_rps),
+        format!("{:.3}", latency.avg),
+        format!("{:.3}", latency.min),
+        format!("{:.3}", latency.p50),
+        format!("{:.3}", latency.p90),
+        format!("{:.3}", latency.p95),
+        format!("{:.3}", latency.p99),
+        format!("{:.3}", latency.max),
+    ];
+    format!(
+        "endpoint,method,url,started_at,duration_secs,concurrency,total_requests,successful_requests,failed_requests,\
+         assertion_failed_requests,success_rate,error_rate,assertion_failure_rate,avg_rps,\
+         latency_avg_ms,latency_min_ms,latency_p50_ms,latency_p90_ms,latency_p95_ms,latency_p99_ms,latency_max_ms\n{}\n",
+        fields.join(","),
+    )
+}
+
+#[cfg(feature = "load-test")]
+/// Quote a field that holds a comma, quote or line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// A collection and one of its endpoints by name (case-insensitive) or id
+fn find<'a>(collections: &'a Collections, collection: &str, endpoint: &str) -> Result<(&'a ApiCollection, &'a ApiEndpoint)> {
+    let collection = collections.get(collection)
+        .ok_or_else(|| CliError::CollectionNotFound(collection.to_string()))?;
+    let endpoint = find_endpoint(collection, endpoint).ok_or_else(|| CliError::EndpointNotFound {
+        collection: collection.name.clone(),
+        endpoint: endpoint.to_string(),
+    })?;
+    Ok((collection, endpoint))
+}
+
+/// A client for `collection`'s requests, with the same layering as the TUI:
+/// settings, workspace proxy, environment, collection proxy
+fn client_for(dir: &Path, collection: &ApiCollection, env: &VariableSet) -> Result<HttpClient> {
+    let settings = Settings::load(&dir.join("config.toml"))?;
+    let mut proxies = ProxyConfig::load(&dir.join("proxy.toml"))?;
+    if proxies == ProxyConfig::default() {
+        proxies.global.url = settings.proxy.clone(); // Not moved to proxy.toml yet
+    }
+    let mut config = settings.client_config();
+    proxies.global.apply(&mut config);
+    env.defaults.apply(&mut config);
+    if let Some(proxy) = proxies.for_collection(collection.id) {
+        proxy.apply(&mut config);
+    }
+    Ok(HttpClient::with_config(config)?)
+}
+
+/// An endpoint by name (case-insensitive) or id
+fn find_endpoint<'a>(collection: &'a ApiCollection, name_or_id: &str) -> Option<&'a ApiEndpoint> {
+    collection.endpoints.iter()
+        .find(|e| e.name.eq_ignore_ascii_case(name_or_id) || e.id.to_string() == name_or_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::HttpMethod;
+    use crate::variables::VariableSet;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_parse_run_command() {
+        let cli = Cli::try_parse_from(["rest_api_tui", "run", "Users API", "get user", "--env", "prod", "--var", "id=42", "--var", "q=a=b", "-i"]).unwrap();
+        let Some(Command::Run(args)) = cli.command else { panic!("expected run") };
+        assert_eq!((args.collection.as_str(), args.endpoint.as_str(), args.env.as_deref()), ("Users API", "get user", Some("prod")));
+        assert_eq!(args.vars, vec![("id".to_string(), "42".to_string()), ("q".to_string(), "a=b".to_string())]);
+        assert!(args.include && !args.raw);
+
+        assert!(Cli::try_parse_from(["rest_api_tui", "run", "A", "b", "--var", "novalue"]).is_err());
+        assert!(Cli::try_parse_from(["rest_api_tui"]).unwrap().command.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_endpoint_prints_formatted_response() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let mut request_lines = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).into_owned();
+                let response: &[u8] = if request.contains("/users/42 ") {
+                    b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 20\r\nconnection: close\r\n\r\n{\"id\":42,\"ok\":true}\n"
+                } else {
+                    b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                };
+                socket.write_all(response).await.unwrap();
+                request_lines.push(request.lines().next().unwrap_or_default().to_string());
+            }
+            request_lines
+        });
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::create_dir_all(dir.join("collections")).unwrap();
+        let mut collection = ApiCollection::new("Users API".to_string());
+        collection.add_endpoint(ApiEndpoint::new("Get user".to_string(), HttpMethod::GET, "{{host}}/users/{{id}}".to_string()));
+        std::fs::write(dir.join("collections/users.json"), serde_json::to_string(&collection).unwrap()).unwrap();
+        let variables = VariableManager::with_path(dir.join("variables.json")).unwrap();
+        let mut local = VariableSet::new("local".to_string());
+        local.set("host".to_string(), format!("http://127.0.0.1:{}", port));
+        local.set("id".to_string(), "7".to_string());
+        variables.save_environment(&local).unwrap();
+
+        let args = RunArgs {
+            collection: "users api".to_string(),
+            endpoint: "GET USER".to_string(),
+            env: Some("local".to_string()),
+            vars: vec![("id".to_string(), "42".to_string())],
+            include: false,
+            raw: false,
+        };
+        let mut out = Vec::new();
+        let result = run_endpoint(dir, &args, &mut out).await.unwrap();
+        assert!(result.passed());
+        assert_eq!(String::from_utf8(out).unwrap(), "{\n  \"id\": 42,\n  \"ok\": true\n}\n");
+
+        // A 404 fails; -i prints the status line and headers
+        let args = RunArgs { vars: Vec::new(), include: true, ..args };
+        let mut out = Vec::new();
+        let result = run_endpoint(dir, &args, &mut out).await.unwrap();
+        assert_eq!(result.assertion_failures, vec!["status 404 is not 2xx/3xx"]);
+        assert!(String::from_utf8(out).unwrap().starts_with("HTTP 404 Not Found ("));
+        assert_eq!(server.await.unwrap(), vec!["GET /users/42 HTTP/1.1", "GET /users/7 HTTP/1.1"]);
+
+        let missing = RunArgs { endpoint: "nope".to_string(), ..args };
+        let err = run_endpoint(dir, &missing, &mut Vec::new()).await.unwrap_err();
+        assert_eq!(err.to_string(), "No endpoint named 'nope' in 'Users API'");
+    }
+
+    #[cfg(feature = "load-test")]
+    #[test]
+    fn test_parse_loadtest_command() {
+        let cli = Cli::try_parse_from(["rest_api_tui", "loadtest", "Users API", "Get user", "-c", "50", "-d", "60", "--output", "out/report.CSV"]).unwrap();
+        let Some(Command::Loadtest(args)) = cli.command else { panic!("expected loadtest") };
+        assert_eq!((args.concurrency, args.duration, args.ramp_up), (50, 60, None));
+        assert_eq!(args.report_format(), ReportFormat::Csv);
+        let args = LoadTestArgs { format: Some(ReportFormat::Json), ..args };
+        assert_eq!(args.report_format(), ReportFormat::Json);
+        let args = LoadTestArgs { format: None, output: None, ..args };
+        assert_eq!(args.report_format(), ReportFormat::Json);
+
+        assert!(Cli::try_parse_from(["rest_api_tui", "loadtest", "A", "b", "--format", "xml"]).is_err());
+    }
+
+    #[cfg(feature = "load-test")]
+    #[test]
+    fn test_csv_field_quotes_when_needed() {
+        assert_eq!(csv_field("Get user"), "Get user");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[cfg(feature = "load-test")]
+    #[tokio::test]
+    async fn test_run_load_test_writes_reports() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut buf = vec![0u8; 4096];
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    let response: &[u8] = if String::from_utf8_lossy(&buf[..n]).contains("/items/9 ") {
+                        b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok"
+                    } else {
+                        b"HTTP/1.1 500 Internal Server Error\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    };
+                    let _ = socket.write_all(response).await;
+                });
+            }
+        });
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::create_dir_all(dir.join("collections")).unwrap();
+        let mut collection = ApiCollection::new("Items".to_string());
+        let url = format!("http://127.0.0.1:{}/items/{{{{id}}}}", port);
+        collection.add_endpoint(ApiEndpoint::new("Get item, by id".to_string(), HttpMethod::GET, url));
+        std::fs::write(dir.join("collections/items.json"), serde_json::to_string(&collection).unwrap()).unwrap();
+
+        let output = dir.join("report.csv");
+        let args = LoadTestArgs {
+            collection: "items".to_string(),
+            endpoint: "get item, by id".to_string(),
+            concurrency: 2,
+            duration: 1,
+            ramp_up: None,
+            env: None,
+            vars: vec![("id".to_string(), "9".to_string())],
+            output: Some(output.clone()),
+            format: None,
+            max_error_rate: 0.0,
+        };
+        let mut out = Vec::new();
+        let report = run_load_test(dir, &args, &mut out).await.unwrap();
+        assert!(report.total_requests > 0);
+        assert_eq!(report.successful_requests, report.total_requests);
+        assert!(args.passed(&report));
+        let summary = String::from_utf8(out).unwrap();
+        assert!(summary.contains(&format!("{} requests in", report.total_requests)), "{}", summary);
+
+        // The run lands in the results directory for the TUI to show
+        let storage = storage::StorageManager::new(dir.join("collections"), dir.join("results")).unwrap();
+        let saved: Vec<_> = storage.load_test_reports().unwrap().iter().map(|r| (r.id, r.total_requests)).collect();
+        assert_eq!(saved, vec![(report.id, report.total_requests)]);
+
+        let csv = std::fs::read_to_string(&output).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("endpoint,method,url,"));
+        assert!(lines[1].starts_with(&format!("\"Get item, by id\",GET,{},", collection.endpoints[0].url)));
+        assert_eq!(lines[0].split(',').count(), 21);
+
+        // Every request fails without the variable that picks the good item
+        let json = dir.join("report.json");
+        let args = LoadTestArgs { vars: Vec::new(), output: Some(json.clone()), max_error_rate: 50.0, ..args };
+        let report = run_load_test(dir, &args, &mut Vec::new()).await.unwrap();
+        assert!(report.failed_requests + report.assertion_failed_requests == report.total_requests);
+        assert!(!args.passed(&report));
+        let saved: LoadTestReport = serde_json::from_str(&std::fs::read_to_string(&json).unwrap()).unwrap();
+        assert_eq!((saved.id, saved.failed_requests), (report.id, report.failed_requests));
+    }
+}
@@ -0,0 +1,100 @@
+// Response comparison
+// Structural diff of two response bodies, used to compare the same endpoint
+// across environments. JSON is compared by path; anything else line by line.
+
+use serde_json::Value;
+
+/// One place where the two responses disagree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// JSON path (`$.user.roles[1]`) or `line 12` for non-JSON bodies
+    pub path: String,
+    /// Value on the left, or None when it is missing there
+    pub left: Option<String>,
+    /// Value on the right, or None when it is missing there
+    pub right: Option<String>,
+}
+
+/// Compare two bodies, structurally when both are JSON
+pub fn diff_bodies(left: &[u8], right: &[u8]) -> Vec<Difference> {
+    match (serde_json::from_slice::<Value>(left), serde_json::from_slice::<Value>(right)) {
+        (Ok(left), Ok(right)) => diff_json(&left, &right),
+        _ => diff_lines(&String::from_utf8_lossy(left), &String::from_utf8_lossy(right)),
+    }
+}
+
+/// Differences between two JSON values; object keys are matched by name and
+/// array items by position
+pub fn diff_json(left: &Value, right: &Value) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    diff_value("$".to_string(), Some(left), Some(right), &mut differences);
+    differences
+}
+
+fn diff_value(path: String, left: Option<&Value>, right: Option<&Value>, out: &mut Vec<Difference>) {
+    match (left, right) {
+        (Some(Value::Object(l)), Some(Value::Object(r))) => {
+            let mut keys: Vec<&String> = l.keys().collect();
+            keys.extend(r.keys().filter(|key| !l.contains_key(*key)));
+            for key in keys {
+                diff_value(format!("{}.{}", path, key), l.get(key), r.get(key), out);
+            }
+        }
+        (Some(Value::Array(l)), Some(Value::Array(r))) => {
+            for i in 0..l.len().max(r.len()) {
+                diff_value(format!("{}[{}]", path, i), l.get(i), r.get(i), out);
+            }
+        }
+        (l, r) if l != r => out.push(Difference {
+            path,
+            left: l.map(Value::to_string),
+            right: r.map(Value::to_string),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_lines(left: &str, right: &str) -> Vec<Difference> {
+    let left: Vec<&str> = left.lines().collect();
+    let right: Vec<&str> = right.lines().collect();
+    (0..left.len().max(right.len()))
+        .filter(|&i| left.get(i) != right.get(i))
+        .map(|i| Difference {
+            path: format!("line {}", i + 1),
+            left: left.get(i).map(|line| line.to_string()),
+            right: right.get(i).map(|line| line.to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_json_reports_changed_added_and_removed_paths() {
+        let left = json!({ "id": 1, "user": { "name": "bob", "roles": ["admin", "dev"] }, "old": true });
+        let right = json!({ "id": 1, "user": { "name": "Bob", "roles": ["admin"] }, "new": null });
+        let paths: Vec<(String, Option<String>, Option<String>)> = diff_json(&left, &right)
+            .into_iter()
+            .map(|d| (d.path, d.left, d.right))
+            .collect();
+        assert_eq!(paths, vec![
+            ("$.old".to_string(), Some("true".to_string()), None),
+            ("$.user.name".to_string(), Some("\"bob\"".to_string()), Some("\"Bob\"".to_string())),
+            ("$.user.roles[1]".to_string(), Some("\"dev\"".to_string()), None),
+            ("$.new".to_string(), None, Some("null".to_string())),
+        ]);
+        assert!(diff_json(&left, &left).is_empty());
+    }
+
+    #[test]
+    fn test_diff_bodies_falls_back_to_lines() {
+        let differences = diff_bodies(b"ok\nsame\nv1", b"ok\nsame\nv2\nextra");
+        assert_eq!(differences.len(), 2);
+        assert_eq!(differences[0].path, "line 3");
+        assert_eq!(differences[1].left, None);
+        assert_eq!(diff_bodies(b"{\"a\": 1}", b"{ \"a\" : 1 }"), vec![]);
+    }
+}
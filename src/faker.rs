@@ -1,17 +1,27 @@
 // Faker library integration for dynamic data generation
 
+#[cfg(feature = "faker")]
 use fake::Fake;
+#[cfg(feature = "faker")]
 use fake::faker::name::en::*;
+#[cfg(feature = "faker")]
 use fake::faker::internet::en::*;
+#[cfg(feature = "faker")]
 use fake::faker::phone_number::en::*;
+#[cfg(feature = "faker")]
 use fake::faker::address::en::*;
+#[cfg(feature = "faker")]
 use fake::faker::company::en::*;
+#[cfg(feature = "faker")]
 use fake::faker::lorem::en::*;
+#[cfg(feature = "faker")]
 use fake::faker::chrono::en::*;
+#[cfg(feature = "faker")]
 use rand::Rng;
 
 /// Generate fake data based on the variable name
 /// Supports syntax: {{f:firstname}}, {{f:lastname}}, {{f:email}}, etc.
+#[cfg(feature = "faker")]
 pub fn generate_fake_value(variable_name: &str) -> Option<String> {
     let name = variable_name.to_lowercase();
     
@@ -98,6 +108,12 @@ pub fn generate_fake_value(variable_name: &str) -> Option<String> {
     }
 }
 
+/// Built without the `faker` feature: no name is known
+#[cfg(not(feature = "faker"))]
+pub fn generate_fake_value(_variable_name: &str) -> Option<String> {
+    None
+}
+
 /// Check if a variable uses faker syntax (f:variablename)
 pub fn is_faker_variable(variable: &str) -> bool {
     variable.starts_with("f:")
@@ -132,6 +148,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "faker")]
     fn test_generate_fake_value() {
         // Test that we can generate values for known types
         assert!(generate_fake_value("firstname").is_some());
@@ -144,6 +161,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "faker")]
     fn test_generate_fake_value_case_insensitive() {
         assert!(generate_fake_value("FirstName").is_some());
         assert!(generate_fake_value("FIRSTNAME").is_some());
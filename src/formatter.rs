@@ -1,3 +1,6 @@
+use encoding_rs::Encoding;
+use std::borrow::Cow;
+use std::collections::HashSet;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -43,8 +46,8 @@ pub fn format_json_with_colors(json_bytes: &[u8]) -> Result<String> {
                 if in_string {
                     // Start of string - check if it's a key or value
                     let mut lookahead = String::new();
-                    let mut temp_chars = chars.clone();
-                    while let Some(next) = temp_chars.next() {
+                    let temp_chars = chars.clone();
+                    for next in temp_chars {
                         if next == '"' {
                             break;
                         }
@@ -72,23 +75,16 @@ pub fn format_json_with_colors(json_bytes: &[u8]) -> Result<String> {
                     result.push_str("\x1b[0m"); // Reset color
                 }
             }
-            't' | 'f' if !in_string => {
+            't' | 'f' if !in_string
+                && (formatted[result.len()..].starts_with("true") || formatted[result.len()..].starts_with("false")) => {
                 // Boolean values
-                if formatted[result.len()..].starts_with("true") || formatted[result.len()..].starts_with("false") {
-                    result.push_str("\x1b[33m"); // Yellow for booleans
-                    result.push(c);
-                } else {
-                    result.push(c);
-                }
+                result.push_str("\x1b[33m"); // Yellow for booleans
+                result.push(c);
             }
-            'n' if !in_string => {
+            'n' if !in_string && formatted[result.len()..].starts_with("null") => {
                 // null value
-                if formatted[result.len()..].starts_with("null") {
-                    result.push_str("\x1b[90m"); // Gray for null
-                    result.push(c);
-                } else {
-                    result.push(c);
-                }
+                result.push_str("\x1b[90m"); // Gray for null
+                result.push(c);
             }
             '0'..='9' | '-' if !in_string => {
                 // Numbers
@@ -204,11 +200,474 @@ pub fn is_xml(content: &[u8]) -> bool {
     }
 }
 
+/// Where a JSON body template stops parsing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonIssue {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for JsonIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, col {}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// Does this body template look like it is meant to be JSON?
+pub fn looks_like_json(text: &str) -> bool {
+    let trimmed = text.trim_start();
+    (trimmed.starts_with('{') && !trimmed.starts_with("{{")) || trimmed.starts_with('[')
+}
+
+/// Validate a JSON body template. `{{placeholders}}` are masked with a number of
+/// the same length first, so they may stand for values and positions stay exact.
+pub fn validate_json_template(text: &str) -> std::result::Result<(), JsonIssue> {
+    // `{{#if}}`/`{{#each}}` blocks only form JSON once expanded
+    if text.contains("{{#") {
+        return Ok(());
+    }
+    let mut masked = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        // An escaped `\{{` is sent as literal braces; blank out the backslash
+        if rest[..start].ends_with('\\') {
+            masked.push_str(&rest[..start - 1]);
+            masked.push_str(" {{");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let Some(len) = rest[start..].find("}}").map(|end| end + 2) else { break };
+        masked.push_str(&rest[..start]);
+        masked.push('1');
+        masked.extend(std::iter::repeat_n('0', len - 1));
+        rest = &rest[start + len..];
+    }
+    masked.push_str(rest);
+
+    serde_json::from_str::<serde_json::Value>(&masked).map(|_| ()).map_err(|e| {
+        let message = e.to_string();
+        let message = message.split(" at line ").next().unwrap_or_default().to_string();
+        JsonIssue { line: e.line(), column: e.column(), message }
+    })
+}
+
+/// Re-indent a valid JSON body template, leaving strings and placeholders untouched
+pub fn format_json_template(text: &str) -> std::result::Result<String, JsonIssue> {
+    validate_json_template(text)?;
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut depth = 0usize;
+    let newline = |out: &mut String, depth: usize| {
+        out.push('\n');
+        out.push_str(&"  ".repeat(depth));
+    };
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '\\' => out.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '{' if chars.peek() == Some(&'{') => {
+                out.push(c);
+                let mut prev = c;
+                for c in chars.by_ref() {
+                    out.push(c);
+                    if prev == '}' && c == '}' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '{' | '[' => {
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+                let close = if c == '{' { '}' } else { ']' };
+                out.push(c);
+                if chars.peek() == Some(&close) {
+                    out.push(close);
+                    chars.next();
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                newline(&mut out, depth);
+            }
+            ':' => out.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+/// Check that content is well-formed XML
+pub fn validate_xml(content: &[u8]) -> Result<()> {
+    let text = String::from_utf8(content.to_vec())?;
+    roxmltree::Document::parse(&text)
+        .map(|_| ())
+        .map_err(|e| FormatterError::XmlParse(e.to_string()))
+}
+
+/// Extract the message of a SOAP fault (1.1 `faultstring` or 1.2 `Reason/Text`)
+pub fn soap_fault(content: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(content).ok()?;
+    let doc = roxmltree::Document::parse(text).ok()?;
+    let fault = doc.descendants().find(|n| n.is_element() && n.tag_name().name() == "Fault")?;
+    let message = fault.descendants()
+        .find(|n| n.is_element() && matches!(n.tag_name().name(), "faultstring" | "Text"))
+        .and_then(|n| n.text())
+        .unwrap_or("(no message)");
+    Some(message.trim().to_string())
+}
+
+/// Display-only options for numbers in a formatted JSON response
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberDisplay {
+    pub epoch_dates: bool, // Follow epoch-millisecond numbers with the UTC date
+    pub thousands_separators: bool, // 1719943 → 1,719,943
+}
+
+impl NumberDisplay {
+    pub fn is_active(&self) -> bool {
+        self.epoch_dates || self.thousands_separators
+    }
+}
+
+/// Epoch milliseconds from 2001-09-09 to 2100, so ids and counts aren't mistaken for dates
+const EPOCH_MILLIS: std::ops::Range<i64> = 1_000_000_000_000..4_102_444_800_000;
+
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+fn display_number(number: &str, options: NumberDisplay) -> String {
+    if options.epoch_dates {
+        let date = number.parse::<i64>().ok()
+            .filter(|ms| EPOCH_MILLIS.contains(ms))
+            .and_then(chrono::DateTime::from_timestamp_millis);
+        if let Some(date) = date {
+            return format!("{} ({})", number, date.format("%Y-%m-%d %H:%M:%S%.3f UTC"));
+        }
+    }
+    // Exponent notation is left alone
+    if options.thousands_separators && !number.contains(['e', 'E']) {
+        let (sign, unsigned) = number.strip_prefix('-').map_or(("", number), |rest| ("-", rest));
+        let (integer, fraction) = unsigned.split_once('.').map_or((unsigned, None), |(i, f)| (i, Some(f)));
+        return match fraction {
+            Some(fraction) => format!("{}{}.{}", sign, group_thousands(integer), fraction),
+            None => format!("{}{}", sign, group_thousands(integer)),
+        };
+    }
+    number.to_string()
+}
+
+/// Rewrite the numbers of formatted JSON for reading; strings are untouched
+pub fn humanize_numbers(json: &str, options: NumberDisplay) -> String {
+    if !options.is_active() {
+        return json.to_string();
+    }
+    let mut out = String::with_capacity(json.len());
+    let mut chars = json.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '"' => {
+                out.push(c);
+                while let Some((_, c)) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '\\' => out.extend(chars.next().map(|(_, c)| c)),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '-' | '0'..='9' => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !matches!(c, '0'..='9' | '.' | 'e' | 'E' | '+' | '-') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                out.push_str(&display_number(&json[start..end], options));
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 /// Format plain text (pass through as-is)
 pub fn format_plain_text(text_bytes: &[u8]) -> Result<String> {
     Ok(String::from_utf8(text_bytes.to_vec())?)
 }
 
+/// How a body's character encoding was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharsetSource {
+    ContentType, // `charset=` parameter
+    Bom,
+    XmlDeclaration, // `<?xml version="1.0" encoding="..."?>`
+    Default, // Valid UTF-8 without any declaration
+    Guessed, // Not valid UTF-8 and nothing declared; read as windows-1252
+}
+
+/// A body's character encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Charset {
+    pub encoding: &'static Encoding,
+    pub source: CharsetSource,
+}
+
+impl Charset {
+    /// `Shift_JIS`, `windows-1252`, ...
+    pub fn name(&self) -> &'static str {
+        self.encoding.name()
+    }
+
+    /// Worth pointing out: anything but UTF-8 that was assumed
+    pub fn is_notable(&self) -> bool {
+        self.encoding != encoding_rs::UTF_8 || self.source == CharsetSource::Bom
+    }
+
+    /// The body as UTF-8; unmappable bytes become U+FFFD
+    pub fn decode<'a>(&self, content: &'a [u8]) -> Cow<'a, str> {
+        self.encoding.decode_with_bom_removal(content).0
+    }
+}
+
+impl std::fmt::Display for Charset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let how = match self.source {
+            CharsetSource::ContentType => "from Content-Type",
+            CharsetSource::Bom => "from byte order mark",
+            CharsetSource::XmlDeclaration => "from XML declaration",
+            CharsetSource::Default => "default",
+            CharsetSource::Guessed => "guessed, not valid UTF-8",
+        };
+        write!(f, "{} ({})", self.name(), how)
+    }
+}
+
+/// The encoding to read a body with: the Content-Type's charset, a byte order
+/// mark, an XML declaration, then UTF-8 if the bytes are valid UTF-8 and
+/// windows-1252 if they aren't. Unknown labels are skipped.
+pub fn detect_charset(content: &[u8], content_type: Option<&str>) -> Charset {
+    let declared = content_type
+        .and_then(|ct| ct.split(';').skip(1).find_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            key.trim().eq_ignore_ascii_case("charset").then(|| value.trim().trim_matches('"'))
+        }))
+        .and_then(|label| Encoding::for_label(label.as_bytes()));
+    if let Some(encoding) = declared {
+        return Charset { encoding, source: CharsetSource::ContentType };
+    }
+    if let Some((encoding, _)) = Encoding::for_bom(content) {
+        return Charset { encoding, source: CharsetSource::Bom };
+    }
+    if let Some(encoding) = xml_declared_encoding(content) {
+        return Charset { encoding, source: CharsetSource::XmlDeclaration };
+    }
+    match std::str::from_utf8(content) {
+        Ok(_) => Charset { encoding: encoding_rs::UTF_8, source: CharsetSource::Default },
+        Err(_) => Charset { encoding: encoding_rs::WINDOWS_1252, source: CharsetSource::Guessed },
+    }
+}
+
+/// `encoding="..."` from an XML declaration; the declaration itself is ASCII
+fn xml_declared_encoding(content: &[u8]) -> Option<&'static Encoding> {
+    let head = content.get(..content.len().min(200))?;
+    let declaration = head.strip_prefix(b"<?xml")?;
+    let end = declaration.windows(2).position(|w| w == b"?>")?;
+    let declaration = std::str::from_utf8(&declaration[..end]).ok()?;
+    let value = declaration.split("encoding=").nth(1)?;
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let label = value[1..].split(quote).next()?;
+    Encoding::for_label(label.as_bytes())
+}
+
+/// The body as text in its detected encoding, for display
+pub fn decode_text(content: &[u8], content_type: Option<&str>) -> String {
+    detect_charset(content, content_type).decode(content).into_owned()
+}
+
+/// `format_as` for a body in any encoding: text views are transcoded to UTF-8
+/// first, hex and image views see the raw bytes
+pub fn format_body(content: &[u8], content_type: Option<&str>, view: ViewAs) -> Result<String> {
+    match view {
+        ViewAs::Hex | ViewAs::Image => format_as(content, view),
+        _ => format_as(detect_charset(content, content_type).decode(content).as_bytes(), view),
+    }
+}
+
+/// Where the response viewer stops laying out JSON, so payloads such as a
+/// 100k-element array or 50 levels of nesting don't freeze the UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonLimits {
+    pub max_depth: usize, // Containers nested deeper are folded to `[… 3 items]`
+    pub max_children: usize, // Items or keys shown per container
+}
+
+impl Default for JsonLimits {
+    fn default() -> Self {
+        Self { max_depth: 20, max_children: 500 }
+    }
+}
+
+impl JsonLimits {
+    /// Banner text for a body that was cut short
+    pub fn describe(&self, cuts: &[JsonCut]) -> String {
+        let long = cuts.iter().filter(|c| c.kind == JsonCutKind::Children).count();
+        let deep = cuts.len() - long;
+        let mut parts = Vec::new();
+        if long > 0 {
+            parts.push(format!("{} long array/object(s) show their first {}", long, self.max_children));
+        }
+        if deep > 0 {
+            parts.push(format!("{} value(s) nested past {} levels are folded", deep, self.max_depth));
+        }
+        format!("⚠ Large JSON: {}", parts.join(", "))
+    }
+}
+
+/// Why part of a JSON body isn't shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonCutKind {
+    Children, // Only the first `max_children` items are shown
+    Depth, // Folded onto one line
+}
+
+/// A container `format_json_limited` didn't lay out in full
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonCut {
+    pub pointer: String, // JSON pointer, e.g. `/data/items`
+    pub line: usize, // 0-based line of the fold or the "more items" marker
+    pub hidden: usize, // Items or keys not shown
+    pub kind: JsonCutKind,
+}
+
+/// A body formatted for the viewer, with what was left out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormattedBody {
+    pub text: String,
+    pub cuts: Vec<JsonCut>,
+}
+
+/// Pretty-print JSON like `serde_json::to_string_pretty`, but fold containers
+/// past the limits. Containers whose pointer is in `expanded` are shown in full.
+pub fn format_json_limited(value: &serde_json::Value, limits: JsonLimits, expanded: &HashSet<String>) -> FormattedBody {
+    let mut out = FormattedBody::default();
+    let mut line = 0;
+    write_json_limited(value, &mut String::new(), 0, limits, expanded, &mut out, &mut line);
+    out
+}
+
+fn write_json_limited(
+    value: &serde_json::Value,
+    pointer: &mut String,
+    depth: usize,
+    limits: JsonLimits,
+    expanded: &HashSet<String>,
+    out: &mut FormattedBody,
+    line: &mut usize,
+) {
+    use serde_json::Value;
+    let (len, open, close, noun) = match value {
+        Value::Array(items) => (items.len(), '[', ']', "items"),
+        Value::Object(map) => (map.len(), '{', '}', "keys"),
+        scalar => {
+            out.text.push_str(&scalar.to_string());
+            return;
+        }
+    };
+    if len == 0 {
+        out.text.push(open);
+        out.text.push(close);
+        return;
+    }
+    let is_expanded = expanded.contains(pointer.as_str());
+    if depth >= limits.max_depth && !is_expanded {
+        out.text.push_str(&format!("{}… {} {}{}", open, len, noun, close));
+        out.cuts.push(JsonCut { pointer: pointer.clone(), line: *line, hidden: len, kind: JsonCutKind::Depth });
+        return;
+    }
+    let shown = if is_expanded { len } else { len.min(limits.max_children) };
+    let indent = "  ".repeat(depth + 1);
+    out.text.push(open);
+    let children: Box<dyn Iterator<Item = (String, Option<&String>, &Value)>> = match value {
+        Value::Array(items) => Box::new(items.iter().enumerate().map(|(i, v)| (i.to_string(), None, v))),
+        Value::Object(map) => Box::new(map.iter().map(|(k, v)| (k.replace('~', "~0").replace('/', "~1"), Some(k), v))),
+        _ => unreachable!("scalars returned above"),
+    };
+    for (i, (token, key, child)) in children.take(shown).enumerate() {
+        out.text.push_str(if i == 0 { "\n" } else { ",\n" });
+        *line += 1;
+        out.text.push_str(&indent);
+        if let Some(key) = key {
+            out.text.push_str(&serde_json::Value::String(key.clone()).to_string());
+            out.text.push_str(": ");
+        }
+        let parent_len = pointer.len();
+        pointer.push('/');
+        pointer.push_str(&token);
+        write_json_limited(child, pointer, depth + 1, limits, expanded, out, line);
+        pointer.truncate(parent_len);
+    }
+    if shown < len {
+        out.text.push_str(&format!(",\n{}… {} more {}", indent, len - shown, noun));
+        *line += 1;
+        out.cuts.push(JsonCut { pointer: pointer.clone(), line: *line, hidden: len - shown, kind: JsonCutKind::Children });
+    }
+    out.text.push('\n');
+    *line += 1;
+    out.text.push_str(&"  ".repeat(depth));
+    out.text.push(close);
+}
+
+/// `format_body` for the viewer: JSON is laid out with `format_json_limited`
+pub fn format_body_limited(
+    content: &[u8],
+    content_type: Option<&str>,
+    view: ViewAs,
+    limits: JsonLimits,
+    expanded: &HashSet<String>,
+) -> Result<FormattedBody> {
+    if matches!(view, ViewAs::Auto | ViewAs::Json) {
+        let text = detect_charset(content, content_type).decode(content);
+        match serde_json::from_str::<serde_json::Value>(&text) {
+            Ok(value) => return Ok(format_json_limited(&value, limits, expanded)),
+            Err(e) if view == ViewAs::Json => return Err(e.into()),
+            Err(_) => {}
+        }
+    }
+    format_body(content, content_type, view).map(|text| FormattedBody { text, cuts: Vec::new() })
+}
+
 /// Auto-detect content type and format accordingly
 pub fn format_auto(content: &[u8]) -> Result<String> {
     if is_json(content) {
@@ -220,10 +679,290 @@ pub fn format_auto(content: &[u8]) -> Result<String> {
     }
 }
 
+/// How to show a response body when its Content-Type can't be trusted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ViewAs {
+    #[default]
+    Auto,
+    Json,
+    Xml,
+    Text,
+    Hex,
+    Image,
+}
+
+impl ViewAs {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Auto => Self::Json,
+            Self::Json => Self::Xml,
+            Self::Xml => Self::Text,
+            Self::Text => Self::Hex,
+            Self::Hex => Self::Image,
+            Self::Image => Self::Auto,
+        }
+    }
+    
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Json => "json",
+            Self::Xml => "xml",
+            Self::Text => "text",
+            Self::Hex => "hex",
+            Self::Image => "image",
+        }
+    }
+}
+
+/// Format a body the given way regardless of what it looks like
+pub fn format_as(content: &[u8], view: ViewAs) -> Result<String> {
+    match view {
+        ViewAs::Auto => format_auto(content),
+        ViewAs::Json => format_json(content),
+        ViewAs::Xml => format_xml(content),
+        ViewAs::Text => Ok(String::from_utf8_lossy(content).into_owned()),
+        ViewAs::Hex => Ok(hex_dump(content)),
+        ViewAs::Image => Ok(describe_image(content)),
+    }
+}
+
+/// `xxd`-style dump: offset, 16 bytes in hex, then the printable ones
+pub fn hex_dump(content: &[u8]) -> String {
+    content.chunks(16)
+        .enumerate()
+        .map(|(row, bytes)| {
+            let hex: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = bytes.iter()
+                .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+                .collect();
+            format!("{:08x}  {:<47}  |{}|", row * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Image type and size from the file header; the terminal can't show the pixels
+pub fn describe_image(content: &[u8]) -> String {
+    let be32 = |at: usize| content.get(at..at + 4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]));
+    let le16 = |at: usize| content.get(at..at + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as u32);
+    let le32 = |at: usize| content.get(at..at + 4).map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]).unsigned_abs());
+    let (kind, size) = if content.starts_with(b"\x89PNG\r\n\x1a\n") {
+        ("PNG", be32(16).zip(be32(20)))
+    } else if content.starts_with(b"GIF87a") || content.starts_with(b"GIF89a") {
+        ("GIF", le16(6).zip(le16(8)))
+    } else if content.starts_with(b"BM") {
+        ("BMP", le32(18).zip(le32(22)))
+    } else if content.starts_with(&[0xff, 0xd8, 0xff]) {
+        ("JPEG", jpeg_size(content))
+    } else if content.starts_with(b"RIFF") && content.get(8..12) == Some(b"WEBP") {
+        ("WebP", None)
+    } else {
+        return format!("Not a recognised image (PNG, JPEG, GIF, WebP or BMP), {} bytes", content.len());
+    };
+    match size {
+        Some((width, height)) => format!("{} image, {} × {} px, {} bytes", kind, width, height, content.len()),
+        None => format!("{} image, {} bytes", kind, content.len()),
+    }
+}
+
+/// Width and height from the first start-of-frame marker
+fn jpeg_size(content: &[u8]) -> Option<(u32, u32)> {
+    let mut at = 2;
+    while at + 9 < content.len() {
+        if content[at] != 0xff {
+            return None;
+        }
+        let marker = content[at + 1];
+        let length = u16::from_be_bytes([content[at + 2], content[at + 3]]) as usize;
+        if matches!(marker, 0xc0..=0xc3 | 0xc5..=0xc7 | 0xc9..=0xcb | 0xcd..=0xcf) {
+            let height = u16::from_be_bytes([content[at + 5], content[at + 6]]) as u32;
+            let width = u16::from_be_bytes([content[at + 7], content[at + 8]]) as u32;
+            return Some((width, height));
+        }
+        at += 2 + length;
+    }
+    None
+}
+
+/// Byte ranges of `query` in `line`. Case-insensitive unless the query has
+/// an uppercase letter, like `smartcase` in vim.
+pub fn find_matches(line: &str, query: &str) -> Vec<std::ops::Range<usize>> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    // ASCII lowercasing keeps byte offsets valid in the original line
+    let (haystack, needle) = if query.chars().any(char::is_uppercase) {
+        (Cow::Borrowed(line), Cow::Borrowed(query))
+    } else {
+        (Cow::Owned(line.to_ascii_lowercase()), Cow::Owned(query.to_ascii_lowercase()))
+    };
+    haystack.match_indices(needle.as_ref())
+        .map(|(start, found)| start..start + found.len())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_json_template_validation() {
+        assert!(looks_like_json(" {\"a\": 1}"));
+        assert!(!looks_like_json("{{body}}"));
+        assert!(!looks_like_json("a=1&b=2"));
+
+        assert_eq!(validate_json_template(r#"{"id": {{id}}, "name": "{{name}}", "tags": [{{$uuid}}]}"#), Ok(()));
+        assert_eq!(validate_json_template(r#"{"id": 1{{#if email}}, "email": "{{email}}"{{/if}}}"#), Ok(()));
+        assert_eq!(validate_json_template(r#"{"template": "Hi \{{name}}", "id": {{id}}}"#), Ok(()));
+        let issue = validate_json_template("{\n  \"id\": {{id}},\n  \"name\": \n}").unwrap_err();
+        assert_eq!((issue.line, issue.column), (4, 1));
+        assert_eq!(issue.message, "expected value");
+    }
+
+    #[test]
+    fn test_format_json_limited_folds_huge_payloads() {
+        let value = serde_json::json!({"a/b": [1, {"x": null}], "empty": [], "s": "q\"uote"});
+        let full = format_json_limited(&value, JsonLimits::default(), &HashSet::new());
+        assert_eq!(full.text, serde_json::to_string_pretty(&value).unwrap());
+        assert!(full.cuts.is_empty());
+
+        let items: Vec<u32> = (0..100_000).collect();
+        let mut deep = serde_json::json!("bottom");
+        for _ in 0..50 {
+            deep = serde_json::json!({ "next": deep });
+        }
+        let value = serde_json::json!({ "items": items, "deep": deep });
+        let limits = JsonLimits { max_depth: 3, max_children: 2 };
+        let cut = format_json_limited(&value, limits, &HashSet::new());
+        let lines: Vec<&str> = cut.text.lines().collect();
+        assert_eq!(lines, vec![
+            "{",
+            "  \"deep\": {",
+            "    \"next\": {",
+            "      \"next\": {… 1 keys}",
+            "    }",
+            "  },",
+            "  \"items\": [",
+            "    0,",
+            "    1,",
+            "    … 99998 more items",
+            "  ]",
+            "}",
+        ]);
+        assert_eq!(cut.cuts, vec![
+            JsonCut { pointer: "/deep/next/next".to_string(), line: 3, hidden: 1, kind: JsonCutKind::Depth },
+            JsonCut { pointer: "/items".to_string(), line: 9, hidden: 99_998, kind: JsonCutKind::Children },
+        ]);
+        assert_eq!(limits.describe(&cut.cuts), "⚠ Large JSON: 1 long array/object(s) show their first 2, 1 value(s) nested past 3 levels are folded");
+
+        // Expanding a fold shows one more level; expanding a list shows all of it
+        let expanded = HashSet::from(["/deep/next/next".to_string(), "/items".to_string()]);
+        let more = format_json_limited(&value, limits, &expanded);
+        assert_eq!(more.cuts.len(), 1);
+        assert_eq!(more.cuts[0].pointer, "/deep/next/next/next");
+        assert!(more.text.contains("    99999\n"));
+
+        let body = format_body_limited(b"{\"a\": 1}", None, ViewAs::Auto, limits, &HashSet::new()).unwrap();
+        assert_eq!(body.text, "{\n  \"a\": 1\n}");
+        assert_eq!(format_body_limited(b"plain", None, ViewAs::Auto, limits, &HashSet::new()).unwrap().text, "plain");
+        assert!(format_body_limited(b"plain", None, ViewAs::Json, limits, &HashSet::new()).is_err());
+    }
+
+    #[test]
+    fn test_find_matches_is_smart_case() {
+        assert_eq!(find_matches("Error: error", "error"), vec![0..5, 7..12]);
+        assert_eq!(find_matches("Error: error", "Error"), vec![0..5]);
+        assert_eq!(find_matches("café Café", "é"), vec![3..5, 9..11]);
+        assert!(find_matches("anything", "").is_empty());
+    }
+
+    #[test]
+    fn test_charset_detection_and_transcoding() {
+        // "café" in ISO-8859-1, "日本" in Shift_JIS
+        let latin1 = b"caf\xe9";
+        let charset = detect_charset(latin1, Some("text/plain; charset=ISO-8859-1"));
+        assert_eq!((charset.name(), charset.source), ("windows-1252", CharsetSource::ContentType));
+        assert_eq!(decode_text(latin1, Some("text/plain; charset=\"iso-8859-1\"")), "café");
+        let sjis = b"{\"name\": \"\x93\xfa\x96\x7b\"}";
+        let formatted = format_body(sjis, Some("application/json;charset=Shift_JIS"), ViewAs::Auto).unwrap();
+        assert!(formatted.contains("\"name\": \"日本\""), "{}", formatted);
+        assert!(format_body(sjis, Some("application/json;charset=Shift_JIS"), ViewAs::Hex).unwrap().contains("93 fa"));
+
+        let xml = b"<?xml version=\"1.0\" encoding='Shift_JIS'?><a>\x93\xfa</a>";
+        assert_eq!(detect_charset(xml, Some("text/xml")).source, CharsetSource::XmlDeclaration);
+        assert!(decode_text(xml, None).ends_with("<a>日</a>"));
+        let bom = b"\xef\xbb\xbfok";
+        assert_eq!(detect_charset(bom, None).source, CharsetSource::Bom);
+        assert_eq!(decode_text(bom, None), "ok");
+
+        let plain = detect_charset("café".as_bytes(), Some("text/plain; charset=bogus"));
+        assert_eq!(plain.source, CharsetSource::Default);
+        assert!(!plain.is_notable());
+        let guessed = detect_charset(latin1, None);
+        assert_eq!(guessed.to_string(), "windows-1252 (guessed, not valid UTF-8)");
+    }
+
+    #[test]
+    fn test_view_as() {
+        let json = br#"{"a": 1}"#;
+        assert_eq!(format_as(json, ViewAs::Text).unwrap(), r#"{"a": 1}"#);
+        assert!(format_as(json, ViewAs::Json).unwrap().contains("\"a\": 1"));
+        assert!(format_as(b"not json", ViewAs::Json).is_err());
+        assert_eq!(
+            format_as(b"hello\x00world", ViewAs::Hex).unwrap(),
+            "00000000  68 65 6c 6c 6f 00 77 6f 72 6c 64                 |hello.world|",
+        );
+        assert_eq!(hex_dump(&[b'a'; 17]).lines().nth(1), Some("00000010  61                                               |a|"));
+
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&[0, 0, 0, 64, 0, 0, 0, 32]);
+        assert_eq!(describe_image(&png), "PNG image, 64 × 32 px, 24 bytes");
+        let jpeg = [0xff, 0xd8, 0xff, 0xe0, 0, 4, 0, 0, 0xff, 0xc0, 0, 11, 8, 0, 10, 0, 20, 3, 0, 0];
+        assert_eq!(describe_image(&jpeg), "JPEG image, 20 × 10 px, 20 bytes");
+        assert!(describe_image(b"GIF89a\x05\0\x07\0").starts_with("GIF image, 5 × 7 px"));
+        assert!(describe_image(b"text").starts_with("Not a recognised image"));
+        assert_eq!(ViewAs::Image.next(), ViewAs::Auto);
+    }
+
+    #[test]
+    fn test_humanize_numbers() {
+        let json = r#"{"created": 1719943125000, "count": 1234567, "price": -9876.5, "id": "1719943125000", "small": 42, "exp": 1.5e10}"#;
+        assert_eq!(humanize_numbers(json, NumberDisplay::default()), json);
+
+        let dates = NumberDisplay { epoch_dates: true, thousands_separators: false };
+        assert_eq!(
+            humanize_numbers(json, dates),
+            r#"{"created": 1719943125000 (2024-07-02 17:58:45.000 UTC), "count": 1234567, "price": -9876.5, "id": "1719943125000", "small": 42, "exp": 1.5e10}"#,
+        );
+
+        let both = NumberDisplay { epoch_dates: true, thousands_separators: true };
+        assert_eq!(
+            humanize_numbers(json, both),
+            r#"{"created": 1719943125000 (2024-07-02 17:58:45.000 UTC), "count": 1,234,567, "price": -9,876.5, "id": "1719943125000", "small": 42, "exp": 1.5e10}"#,
+        );
+    }
+
+    #[test]
+    fn test_format_json_template() {
+        let formatted = format_json_template(r#"{"id":{{id}},"note":"a, {b}: c","list":[1,{}],"empty":[ ]}"#).unwrap();
+        assert_eq!(formatted, "{\n  \"id\": {{id}},\n  \"note\": \"a, {b}: c\",\n  \"list\": [\n    1,\n    {}\n  ],\n  \"empty\": []\n}");
+        assert_eq!(format_json_template(&formatted).unwrap(), formatted);
+        assert!(format_json_template(r#"{"a": }"#).is_err());
+    }
+
+    #[test]
+    fn test_validate_xml_and_soap_fault() {
+        assert!(validate_xml(b"<a><b/></a>").is_ok());
+        assert!(validate_xml(b"<a><b></a>").is_err());
+
+        let fault11 = br#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"><soap:Body><soap:Fault><faultcode>soap:Server</faultcode><faultstring>Boom</faultstring></soap:Fault></soap:Body></soap:Envelope>"#;
+        assert_eq!(soap_fault(fault11).as_deref(), Some("Boom"));
+        let fault12 = br#"<env:Envelope xmlns:env="http://www.w3.org/2003/05/soap-envelope"><env:Body><env:Fault><env:Reason><env:Text xml:lang="en">Bad input</env:Text></env:Reason></env:Fault></env:Body></env:Envelope>"#;
+        assert_eq!(soap_fault(fault12).as_deref(), Some("Bad input"));
+        assert_eq!(soap_fault(b"<ok/>"), None);
+    }
+
     #[test]
     fn test_format_json_simple() {
         let json = r#"{"name":"Alice","age":30}"#;
@@ -0,0 +1,358 @@
+// HAR capture
+// Writes sampled request/response exchanges as a HAR 1.2 file, so a load test
+// leaves concrete examples behind next to its aggregate numbers. Any HAR
+// viewer (browser dev tools, Charles, ...) can open the result.
+
+use crate::http::HttpResponse;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HarError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, HarError>;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Har {
+    pub log: HarLog,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HarLog {
+    pub version: &'static str,
+    pub creator: HarCreator,
+    pub entries: Vec<HarEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HarCreator {
+    pub name: &'static str,
+    pub version: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarEntry {
+    pub started_date_time: DateTime<Utc>,
+    pub time: f64, // Milliseconds
+    pub request: HarRequest,
+    pub response: HarResponse,
+    pub cache: HashMap<String, String>,
+    pub timings: HarTimings,
+    #[serde(rename = "serverIPAddress", skip_serializing_if = "Option::is_none")]
+    pub server_ip_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    pub http_version: &'static str,
+    pub headers: Vec<HarHeader>,
+    pub query_string: Vec<HarHeader>,
+    pub cookies: Vec<HarHeader>,
+    pub headers_size: i64,
+    pub body_size: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<HarPostData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarResponse {
+    pub status: u16,
+    pub status_text: String,
+    pub http_version: &'static str,
+    pub headers: Vec<HarHeader>,
+    pub cookies: Vec<HarHeader>,
+    pub content: HarContent,
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+/// A name/value pair, used for headers and query parameters alike
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HarHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPostData {
+    pub mime_type: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarContent {
+    pub size: i64,
+    pub mime_type: String,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<&'static str>, // "base64" for binary bodies
+}
+
+/// Phase durations in milliseconds; -1 means the phase didn't apply
+#[derive(Debug, Clone, Serialize)]
+pub struct HarTimings {
+    pub dns: f64,
+    pub connect: f64,
+    pub ssl: f64,
+    pub send: f64,
+    pub wait: f64,
+    pub receive: f64,
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn header_list(headers: &HashMap<String, String>) -> Vec<HarHeader> {
+    let mut list: Vec<HarHeader> = headers.iter()
+        .map(|(name, value)| HarHeader { name: name.clone(), value: value.clone() })
+        .collect();
+    list.sort_by(|a, b| a.name.cmp(&b.name));
+    list
+}
+
+fn mime_type(headers: &HashMap<String, String>) -> String {
+    headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| value.clone())
+        .unwrap_or_default()
+}
+
+impl HarEntry {
+    /// Build an entry from a response; None when the request details weren't captured
+    pub fn from_response(response: &HttpResponse, started: DateTime<Utc>) -> Option<Self> {
+        let traffic = response.traffic.as_ref()?;
+        let request = &traffic.request;
+        let timing = &traffic.timing;
+
+        let query_string = reqwest::Url::parse(&request.url)
+            .map(|url| url.query_pairs()
+                .map(|(name, value)| HarHeader { name: name.into_owned(), value: value.into_owned() })
+                .collect())
+            .unwrap_or_default();
+        let post_data = request.body.as_ref().map(|body| HarPostData {
+            mime_type: mime_type(&request.headers),
+            text: String::from_utf8_lossy(body).into_owned(),
+        });
+
+        // Text bodies are stored as-is, anything else base64-encoded
+        let (text, encoding) = match std::str::from_utf8(&response.body) {
+            Ok(text) => (text.to_string(), None),
+            Err(_) => (base64::engine::general_purpose::STANDARD.encode(&response.body), Some("base64")),
+        };
+        let redirect_url = response.headers.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("location"))
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default();
+
+        Some(Self {
+            started_date_time: started,
+            time: millis(response.duration),
+            request: HarRequest {
+                method: request.method.clone(),
+                url: request.url.clone(),
+                http_version: "HTTP/1.1",
+                headers: header_list(&request.headers),
+                query_string,
+                cookies: Vec::new(),
+                headers_size: -1,
+                body_size: request.body_size as i64,
+                post_data,
+            },
+            response: HarResponse {
+                status: response.status.as_u16(),
+                status_text: response.status.canonical_reason().unwrap_or_default().to_string(),
+                http_version: "HTTP/1.1",
+                headers: header_list(&response.headers),
+                cookies: Vec::new(),
+                content: HarContent {
+                    size: response.body.len() as i64,
+                    mime_type: mime_type(&response.headers),
+                    text,
+                    encoding,
+                },
+                redirect_url,
+                headers_size: traffic.response_headers_size as i64,
+                body_size: traffic.response_body_size as i64,
+            },
+            cache: HashMap::new(),
+            timings: HarTimings {
+                dns: timing.dns_lookup.map(millis).unwrap_or(-1.0),
+                connect: timing.tcp_connect.map(millis).unwrap_or(-1.0),
+                ssl: timing.tls_handshake.map(millis).unwrap_or(-1.0),
+                // HAR requires send; when it wasn't measured it is part of wait
+                send: timing.request_sent.map(millis).unwrap_or(0.0),
+                wait: millis(timing.waiting),
+                receive: millis(timing.content_download),
+            },
+            server_ip_address: traffic.remote_addr.map(|addr| addr.ip().to_string()),
+        })
+    }
+}
+
+impl Har {
+    pub fn new(mut entries: Vec<HarEntry>) -> Self {
+        entries.sort_by_key(|entry| entry.started_date_time);
+        Self {
+            log: HarLog {
+                version: "1.2",
+                creator: HarCreator { name: "rest-api-tui", version: env!("CARGO_PKG_VERSION") },
+                entries,
+            },
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Keeps a uniform random sample of at most `capacity` exchanges from a run of
+/// unknown length (reservoir sampling), so late requests are as likely to be
+/// kept as the first ones
+#[derive(Debug)]
+pub struct HarSampler {
+    capacity: usize,
+    seen: u64,
+    entries: Vec<HarEntry>,
+}
+
+impl HarSampler {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, seen: 0, entries: Vec::with_capacity(capacity) }
+    }
+
+    /// Consider one exchange for the sample
+    pub fn offer(&mut self, response: &HttpResponse, started: DateTime<Utc>) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.seen += 1;
+        let slot = if self.entries.len() < self.capacity {
+            None
+        } else {
+            let pick = rand::thread_rng().gen_range(0..self.seen) as usize;
+            if pick >= self.capacity {
+                return;
+            }
+            Some(pick)
+        };
+        // Only build the entry once it is known to be kept
+        let Some(entry) = HarEntry::from_response(response, started) else { return };
+        match slot {
+            Some(slot) => self.entries[slot] = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn into_har(self) -> Har {
+        Har::new(self.entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{NetworkTiming, NetworkTraffic, RequestDetails};
+    use reqwest::StatusCode;
+
+    fn response(url: &str) -> HttpResponse {
+        let timing = NetworkTiming {
+            dns_lookup: None,
+            tcp_connect: Some(Duration::from_millis(2)),
+            tls_handshake: None,
+            request_sent: Some(Duration::from_millis(1)),
+            waiting: Duration::from_millis(10),
+            content_download: Duration::from_millis(3),
+            total: Duration::from_millis(16),
+            expect_continue: None,
+        };
+        HttpResponse {
+            status: StatusCode::CREATED,
+            headers: HashMap::from([("Content-Type".to_string(), "application/json".to_string())]),
+            body: b"{\"id\":7}".to_vec(),
+            duration: Duration::from_millis(16),
+            traffic: Some(NetworkTraffic {
+                timing,
+                request: RequestDetails {
+                    method: "POST".to_string(),
+                    url: url.to_string(),
+                    headers: HashMap::from([("X-Load-Test".to_string(), "true".to_string())]),
+                    sent_headers: Default::default(),
+                    body: Some(b"{\"name\":\"a\"}".to_vec()),
+                    body_size: 12,
+                },
+                remote_addr: Some("127.0.0.1:8080".parse().unwrap()),
+                interim: Vec::new(),
+                trailers: Vec::new(),
+                response_headers_size: 34,
+                response_body_size: 8,
+                body_mismatch: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_entry_serializes_as_har() {
+        let entry = HarEntry::from_response(&response("http://localhost/users?page=2"), Utc::now()).unwrap();
+        let json = serde_json::to_value(Har::new(vec![entry])).unwrap();
+        let entry = &json["log"]["entries"][0];
+        assert_eq!(json["log"]["version"], "1.2");
+        assert_eq!(entry["request"]["queryString"][0]["value"], "2");
+        assert_eq!(entry["request"]["postData"]["text"], "{\"name\":\"a\"}");
+        assert_eq!(entry["response"]["status"], 201);
+        assert_eq!(entry["response"]["statusText"], "Created");
+        assert_eq!(entry["response"]["content"]["mimeType"], "application/json");
+        assert_eq!(entry["response"]["redirectURL"], "");
+        assert_eq!(entry["timings"]["dns"], -1.0);
+        assert_eq!(entry["timings"]["wait"], 10.0);
+        assert_eq!(entry["serverIPAddress"], "127.0.0.1");
+    }
+
+    #[test]
+    fn test_sampler_keeps_at_most_capacity() {
+        let mut sampler = HarSampler::new(3);
+        for i in 0..50 {
+            sampler.offer(&response(&format!("http://localhost/{}", i)), Utc::now());
+        }
+        assert_eq!(sampler.len(), 3);
+
+        let mut disabled = HarSampler::new(0);
+        disabled.offer(&response("http://localhost/"), Utc::now());
+        assert!(disabled.is_empty());
+    }
+}
@@ -0,0 +1,451 @@
+// Request history
+// Every executed request is appended as one JSON line to
+// ~/.rest-api-tui/history.jsonl so stats survive restarts. Response bodies are
+// kept next to it in responses/<entry id> so history can be searched.
+
+use crate::models::HttpMethod;
+use crate::settings::Retention;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum HistoryError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, HistoryError>;
+
+/// Bodies are cut off at this size when stored
+const MAX_STORED_BODY: usize = 1024 * 1024;
+
+/// Snippets around a search match are cut to about this many characters
+const SNIPPET_WIDTH: usize = 80;
+
+/// One executed request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HistoryEntry {
+    pub id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub collection_id: Uuid,
+    pub endpoint_id: Uuid,
+    pub method: HttpMethod,
+    pub url: String,
+    pub status: Option<u16>, // None when no response was received
+    pub duration_ms: u64,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub assertion_failures: Vec<String>,
+}
+
+impl HistoryEntry {
+    pub fn new(collection_id: Uuid, endpoint_id: Uuid, method: HttpMethod, url: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            collection_id,
+            endpoint_id,
+            method,
+            url,
+            status: None,
+            duration_ms: 0,
+            error: None,
+            assertion_failures: Vec::new(),
+        }
+    }
+
+    /// Got a 2xx/3xx response and no assertion failed
+    pub fn is_success(&self) -> bool {
+        matches!(self.status, Some(200..=399)) && self.error.is_none() && self.assertion_failures.is_empty()
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.duration_ms)
+    }
+}
+
+/// Aggregated history for one endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStats<'a> {
+    pub runs: usize,
+    pub failures: usize,
+    pub last: Option<&'a HistoryEntry>,
+    pub avg_latency: Option<Duration>, // Average over runs that got a response
+}
+
+/// Responses whose latency fell in `[lower_ms, upper_ms)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyBucket {
+    pub lower_ms: u64,
+    pub upper_ms: u64,
+    pub count: usize,
+}
+
+/// Where a search matched a history entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    pub index: usize, // Into `entries()`
+    pub location: String, // "url", "error" or "line 12"
+    pub snippet: String,
+}
+
+/// Persistent, append-only request history
+pub struct HistoryStore {
+    path: PathBuf,
+    bodies_dir: PathBuf,
+    entries: Vec<HistoryEntry>,
+}
+
+impl HistoryStore {
+    /// Create a history store at the default path
+    pub fn new() -> Result<Self> {
+        let dir = crate::storage::data_dir()
+            .ok_or_else(|| HistoryError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Home directory not found"
+            )))?;
+        Self::with_path(dir.join("history.jsonl"))
+    }
+
+    /// Create a history store at a custom path, loading existing entries
+    pub fn with_path(path: PathBuf) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut entries = Vec::new();
+        if path.exists() {
+            for line in fs::read_to_string(&path)?.lines() {
+                // Skip lines that don't parse (e.g. a write cut short by a crash)
+                if let Ok(entry) = serde_json::from_str::<HistoryEntry>(line) {
+                    entries.push(entry);
+                }
+            }
+        }
+
+        let bodies_dir = path.with_file_name("responses");
+        Ok(Self { path, bodies_dir, entries })
+    }
+
+    /// Append an entry to memory and disk
+    pub fn record(&mut self, entry: HistoryEntry) -> Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Append an entry along with its response body
+    pub fn record_with_body(&mut self, entry: HistoryEntry, body: &[u8]) -> Result<()> {
+        if !body.is_empty() {
+            fs::create_dir_all(&self.bodies_dir)?;
+            fs::write(self.bodies_dir.join(entry.id.to_string()), &body[..body.len().min(MAX_STORED_BODY)])?;
+        }
+        self.record(entry)
+    }
+
+    /// Drop the oldest entries (and their bodies) beyond any of the retention
+    /// limits, rewriting the file; returns how many were removed
+    pub fn prune(&mut self, retention: &Retention) -> Result<usize> {
+        let cutoff = retention.cutoff();
+        let budget = retention.max_disk_bytes();
+        let mut used = 0;
+        let mut excess = self.entries.len();
+        // Keep the newest entries until one doesn't fit
+        for (kept, entry) in self.entries.iter().rev().enumerate() {
+            if retention.max_entries.is_some_and(|max| kept >= max) || cutoff.is_some_and(|c| entry.timestamp < c) {
+                break;
+            }
+            used += self.size_of(entry)?;
+            if budget.is_some_and(|budget| used > budget) {
+                break;
+            }
+            excess -= 1;
+        }
+        if excess == 0 {
+            return Ok(0);
+        }
+        for entry in self.entries.drain(..excess) {
+            // Most entries have no stored body
+            let _ = fs::remove_file(self.bodies_dir.join(entry.id.to_string()));
+        }
+        let mut contents = String::new();
+        for entry in &self.entries {
+            contents.push_str(&serde_json::to_string(entry)?);
+            contents.push('\n');
+        }
+        fs::write(&self.path, contents)?;
+        tracing::info!("Pruned {} old history entries", excess);
+        Ok(excess)
+    }
+
+    /// Bytes on disk for the history file and stored bodies
+    pub fn disk_usage(&self) -> u64 {
+        let file = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        let bodies = fs::read_dir(&self.bodies_dir).into_iter().flatten().flatten()
+            .filter_map(|entry| entry.metadata().ok())
+            .map(|m| m.len())
+            .sum::<u64>();
+        file + bodies
+    }
+
+    /// An entry's line in the file plus its stored body
+    fn size_of(&self, entry: &HistoryEntry) -> Result<u64> {
+        let line = serde_json::to_string(entry)?.len() as u64 + 1;
+        let body = fs::metadata(self.bodies_dir.join(entry.id.to_string())).map(|m| m.len()).unwrap_or(0);
+        Ok(line + body)
+    }
+
+    /// The stored response body of an entry, if one was kept
+    pub fn body(&self, entry: &HistoryEntry) -> Option<Vec<u8>> {
+        fs::read(self.bodies_dir.join(entry.id.to_string())).ok()
+    }
+
+    /// Entries whose URL, error or response body contain `query` (ignoring
+    /// case), newest first with the first place each one matched
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        let query = query.to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut hits = Vec::new();
+        for (index, entry) in self.entries.iter().enumerate().rev() {
+            let hit = if let Some(snippet) = snippet(&entry.url, &query) {
+                Some(("url".to_string(), snippet))
+            } else if let Some(snippet) = entry.error.as_deref().and_then(|e| snippet(e, &query)) {
+                Some(("error".to_string(), snippet))
+            } else {
+                self.body(entry).and_then(|body| {
+                    String::from_utf8_lossy(&body).lines().enumerate()
+                        .find_map(|(n, line)| snippet(line, &query).map(|s| (format!("line {}", n + 1), s)))
+                })
+            };
+            if let Some((location, snippet)) = hit {
+                hits.push(SearchHit { index, location, snippet });
+            }
+        }
+        hits
+    }
+
+    /// All entries, oldest first
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn for_endpoint(&self, endpoint_id: Uuid) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter().filter(move |e| e.endpoint_id == endpoint_id)
+    }
+
+    pub fn endpoint_stats(&self, endpoint_id: Uuid) -> EndpointStats<'_> {
+        let mut stats = EndpointStats { runs: 0, failures: 0, last: None, avg_latency: None };
+        let mut total_ms = 0u64;
+        let mut responded = 0u32;
+        for entry in self.for_endpoint(endpoint_id) {
+            stats.runs += 1;
+            if !entry.is_success() {
+                stats.failures += 1;
+            }
+            if entry.status.is_some() {
+                total_ms += entry.duration_ms;
+                responded += 1;
+            }
+            stats.last = Some(entry);
+        }
+        if responded > 0 {
+            stats.avg_latency = Some(Duration::from_millis(total_ms / responded as u64));
+        }
+        stats
+    }
+
+    /// Latencies of an endpoint's answered requests, in at most `buckets`
+    /// equal-width buckets from the fastest to the slowest run
+    pub fn latency_histogram(&self, endpoint_id: Uuid, buckets: usize) -> Vec<LatencyBucket> {
+        let latencies: Vec<u64> = self.for_endpoint(endpoint_id)
+            .filter(|e| e.status.is_some())
+            .map(|e| e.duration_ms)
+            .collect();
+        let (Some(&min), Some(&max)) = (latencies.iter().min(), latencies.iter().max()) else {
+            return Vec::new();
+        };
+        let width = (max - min + 1).div_ceil(buckets.max(1) as u64);
+        let mut histogram: Vec<LatencyBucket> = (0..)
+            .map(|i| LatencyBucket { lower_ms: min + i * width, upper_ms: min + (i + 1) * width, count: 0 })
+            .take_while(|bucket| bucket.lower_ms <= max)
+            .collect();
+        for latency in latencies {
+            histogram[((latency - min) / width) as usize].count += 1;
+        }
+        histogram
+    }
+}
+
+/// The text around the first case-insensitive match of a lowercase query
+fn snippet(text: &str, query: &str) -> Option<String> {
+    let lower = text.to_lowercase();
+    let at = lower.find(query)?;
+    // Lowercasing can change byte lengths, so count characters before the match
+    let start_chars = lower[..at].chars().count().saturating_sub(SNIPPET_WIDTH / 4);
+    let snippet: String = text.trim_end().chars().skip(start_chars).take(SNIPPET_WIDTH).collect();
+    let snippet = snippet.trim();
+    Some(if start_chars > 0 { format!("…{}", snippet) } else { snippet.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(endpoint_id: Uuid, status: Option<u16>, duration_ms: u64) -> HistoryEntry {
+        let mut entry = HistoryEntry::new(Uuid::new_v4(), endpoint_id, HttpMethod::GET, "https://example.com".to_string());
+        entry.status = status;
+        entry.duration_ms = duration_ms;
+        entry
+    }
+
+    #[test]
+    fn test_latency_histogram() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = HistoryStore::with_path(temp_dir.path().join("history.jsonl")).unwrap();
+        let endpoint_id = Uuid::new_v4();
+        assert!(store.latency_histogram(endpoint_id, 4).is_empty());
+
+        for ms in [10, 12, 19, 30, 49] {
+            store.record(entry(endpoint_id, Some(200), ms)).unwrap();
+        }
+        // Failed requests have no meaningful latency
+        store.record(entry(endpoint_id, None, 5000)).unwrap();
+        store.record(entry(Uuid::new_v4(), Some(200), 1)).unwrap();
+
+        let histogram = store.latency_histogram(endpoint_id, 4);
+        let counts: Vec<(u64, u64, usize)> = histogram.iter().map(|b| (b.lower_ms, b.upper_ms, b.count)).collect();
+        assert_eq!(counts, vec![(10, 20, 3), (20, 30, 0), (30, 40, 1), (40, 50, 1)]);
+
+        // Identical latencies make a single bucket
+        let steady = Uuid::new_v4();
+        store.record(entry(steady, Some(200), 7)).unwrap();
+        store.record(entry(steady, Some(200), 7)).unwrap();
+        assert_eq!(store.latency_histogram(steady, 10), vec![LatencyBucket { lower_ms: 7, upper_ms: 8, count: 2 }]);
+    }
+
+    #[test]
+    fn test_record_and_reload() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("history.jsonl");
+        let endpoint_id = Uuid::new_v4();
+
+        let mut store = HistoryStore::with_path(path.clone()).unwrap();
+        store.record(entry(endpoint_id, Some(200), 10)).unwrap();
+        store.record(entry(endpoint_id, Some(500), 30)).unwrap();
+
+        let reloaded = HistoryStore::with_path(path).unwrap();
+        assert_eq!(reloaded.entries(), store.entries());
+    }
+
+    #[test]
+    fn test_prune_keeps_newest() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("history.jsonl");
+        let mut store = HistoryStore::with_path(path.clone()).unwrap();
+        let mut ancient = entry(Uuid::new_v4(), Some(200), 0);
+        ancient.timestamp = Utc::now() - chrono::Duration::days(40);
+        store.record(ancient).unwrap();
+        let old = entry(Uuid::new_v4(), Some(200), 1);
+        store.record_with_body(old.clone(), &vec![b'x'; 4 * 1024 * 1024]).unwrap();
+        for ms in [2, 3, 4] {
+            store.record(entry(Uuid::new_v4(), Some(200), ms)).unwrap();
+        }
+        let kept = |store: &HistoryStore| store.entries().iter().map(|e| e.duration_ms).collect::<Vec<u64>>();
+
+        assert_eq!(store.prune(&Retention::default()).unwrap(), 0);
+        assert_eq!(store.prune(&Retention { max_age_days: Some(30), ..Retention::default() }).unwrap(), 1);
+        assert_eq!(kept(&store), vec![1, 2, 3, 4]);
+
+        // The 1 MiB stored body doesn't fit a 1 MB budget
+        assert!(store.disk_usage() > 1024 * 1024);
+        assert_eq!(store.prune(&Retention { max_disk_mb: Some(1), ..Retention::default() }).unwrap(), 1);
+        assert_eq!(store.body(&old), None);
+        assert!(store.disk_usage() < 1024);
+
+        assert_eq!(store.prune(&Retention { max_entries: Some(2), ..Retention::default() }).unwrap(), 1);
+        assert_eq!(kept(&HistoryStore::with_path(path).unwrap()), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_skips_corrupt_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("history.jsonl");
+        let mut store = HistoryStore::with_path(path.clone()).unwrap();
+        store.record(entry(Uuid::new_v4(), Some(200), 10)).unwrap();
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "{{\"truncated\":").unwrap();
+
+        assert_eq!(HistoryStore::with_path(path).unwrap().entries().len(), 1);
+    }
+
+    #[test]
+    fn test_endpoint_stats() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut store = HistoryStore::with_path(temp_dir.path().join("history.jsonl")).unwrap();
+        let endpoint_id = Uuid::new_v4();
+
+        store.record(entry(endpoint_id, Some(200), 10)).unwrap();
+        store.record(entry(endpoint_id, Some(404), 30)).unwrap();
+        store.record(entry(endpoint_id, None, 5000)).unwrap();
+        store.record(entry(Uuid::new_v4(), Some(200), 999)).unwrap();
+
+        let stats = store.endpoint_stats(endpoint_id);
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.failures, 2);
+        assert_eq!(stats.avg_latency, Some(Duration::from_millis(20)));
+        assert_eq!(stats.last.unwrap().status, None);
+
+        let none = store.endpoint_stats(Uuid::new_v4());
+        assert_eq!((none.runs, none.last, none.avg_latency), (0, None, None));
+    }
+
+    #[test]
+    fn test_search_urls_errors_and_bodies() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("history.jsonl");
+        let mut store = HistoryStore::with_path(path.clone()).unwrap();
+
+        store.record_with_body(entry(Uuid::new_v4(), Some(200), 10), b"{\n  \"order_id\": \"ORD-4711\"\n}").unwrap();
+        let mut failed = entry(Uuid::new_v4(), None, 10);
+        failed.error = Some("connection refused for ord-4711".to_string());
+        store.record(failed).unwrap();
+        store.record_with_body(entry(Uuid::new_v4(), Some(200), 10), b"nothing here").unwrap();
+
+        // Bodies are found again after a reload; newest hits come first
+        let store = HistoryStore::with_path(path).unwrap();
+        let hits = store.search("ORD-4711");
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[0].index, hits[0].location.as_str()), (1, "error"));
+        assert_eq!((hits[1].index, hits[1].location.as_str()), (0, "line 2"));
+        assert_eq!(hits[1].snippet, "\"order_id\": \"ORD-4711\"");
+        assert_eq!(store.search("example.com").len(), 3);
+        assert!(store.search("").is_empty());
+        assert_eq!(store.body(&store.entries()[2]).unwrap(), b"nothing here");
+        assert_eq!(store.body(&store.entries()[1]), None);
+    }
+
+    #[test]
+    fn test_snippet_is_cut_around_the_match() {
+        let line = format!("{}needle{}", "a".repeat(100), "b".repeat(100));
+        let cut = snippet(&line, "needle").unwrap();
+        assert!(cut.starts_with('…'));
+        assert!(cut.contains("needle"));
+        assert_eq!(cut.chars().count(), SNIPPET_WIDTH + 1);
+        assert_eq!(snippet("Straße", "ße"), Some("Straße".to_string()));
+    }
+}
@@ -1,9 +1,31 @@
 // HTTP client layer for executing API requests
+// Has no TUI dependency: other crates can send saved endpoints through the
+// `Request` builder.
 
-use crate::models::{ApiEndpoint, AuthConfig, ApiKeyLocation, HttpMethod};
+pub mod headers;
+pub mod request;
+
+pub use headers::{HeaderSource, MergedHeaders, SentHeader};
+pub use request::Request;
+
+use crate::formatter::{self, Charset};
+use crate::models::{ApiEndpoint, AuthConfig, ApiKeyLocation, ConnectionOptions, EmptyBody, GraphqlBody, HttpMethod, IpFamily};
+use crate::protobuf::{ProtoSchema, ProtobufError};
+use crate::storage::expand_home;
 use crate::template;
-use reqwest::{Client, StatusCode};
-use std::collections::HashMap;
+use futures_util::StreamExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Empty, Full, StreamBody};
+use hyper::body::{Body as _, Bytes, Frame};
+use hyper_tls::HttpsConnector;
+use hyper_util::client::legacy::connect::{HttpConnector, HttpInfo};
+use hyper_util::rt::{TokioExecutor, TokioTimer};
+use reqwest::header::{HeaderMap, LOCATION};
+use reqwest::redirect::Policy;
+use reqwest::{Certificate, Client, ClientBuilder, Method, NoProxy, Proxy, StatusCode, Url};
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use thiserror::Error;
 
@@ -20,6 +42,24 @@ pub enum HttpError {
     
     #[error("Invalid header: {0}")]
     InvalidHeader(String),
+    
+    #[error("Invalid HTTP method: {0}")]
+    InvalidMethod(String),
+    
+    #[error("Invalid GraphQL variables: {0}")]
+    GraphqlVariables(String),
+    
+    #[error("Protobuf error: {0}")]
+    Protobuf(#[from] ProtobufError),
+    
+    #[error("Invalid CA certificate {0}: {1}")]
+    Certificate(String, String),
+    
+    #[error("Connection error: {0}")]
+    Connection(String),
+    
+    #[error("Request timed out after {}s", .0.as_secs())]
+    Timeout(Duration),
 }
 
 pub type Result<T> = std::result::Result<T, HttpError>;
@@ -31,18 +71,224 @@ pub struct RequestInputs {
     pub query_params: HashMap<String, String>,
     pub body: Option<String>,
     pub variables: HashMap<String, String>,
+    pub progress: Option<DownloadProgress>, // Updated while the response body downloads
+}
+
+/// Shared view of a response body download, updated as chunks arrive
+#[derive(Debug, Clone, Default)]
+pub struct DownloadProgress {
+    state: Arc<Mutex<Option<ProgressSnapshot>>>,
+}
+
+/// How far a download has got
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressSnapshot {
+    pub received: u64,
+    pub total: Option<u64>, // From Content-Length, when the server sent one
+    pub elapsed: Duration,
+    started: Instant,
+}
+
+impl DownloadProgress {
+    fn start(&self, total: Option<u64>) {
+        let started = Instant::now();
+        *self.state.lock().unwrap() = Some(ProgressSnapshot { received: 0, total, elapsed: Duration::ZERO, started });
+    }
+    
+    fn add(&self, bytes: usize) {
+        if let Some(snapshot) = self.state.lock().unwrap().as_mut() {
+            snapshot.received += bytes as u64;
+            snapshot.elapsed = snapshot.started.elapsed();
+        }
+    }
+    
+    /// The download so far; `None` until the response headers have arrived
+    pub fn snapshot(&self) -> Option<ProgressSnapshot> {
+        *self.state.lock().unwrap()
+    }
+}
+
+impl ProgressSnapshot {
+    /// Fraction downloaded, when the size is known
+    pub fn ratio(&self) -> Option<f64> {
+        self.total.filter(|&t| t > 0).map(|t| (self.received as f64 / t as f64).min(1.0))
+    }
+    
+    pub fn bytes_per_sec(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 { self.received as f64 / secs } else { 0.0 }
+    }
+    
+    /// Time left at the current average speed
+    pub fn eta(&self) -> Option<Duration> {
+        let remaining = self.total?.saturating_sub(self.received);
+        let speed = self.bytes_per_sec();
+        (speed > 0.0).then(|| Duration::from_secs_f64(remaining as f64 / speed))
+    }
+    
+    /// e.g. `1.5 MB / 10.0 MB · 2.0 MB/s · ETA 4s`
+    pub fn label(&self) -> String {
+        let mut label = format_bytes(self.received);
+        if let Some(total) = self.total {
+            label.push_str(&format!(" / {}", format_bytes(total)));
+        }
+        label.push_str(&format!(" · {}/s", format_bytes(self.bytes_per_sec() as u64)));
+        if let Some(eta) = self.eta() {
+            label.push_str(&format!(" · ETA {}s", eta.as_secs()));
+        }
+        label
+    }
 }
 
-/// Detailed timing breakdown for network traffic analysis
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Detailed timing breakdown for network traffic analysis. Phases reqwest
+/// doesn't expose are None and counted in `waiting` instead.
 #[derive(Debug, Clone)]
 pub struct NetworkTiming {
     pub dns_lookup: Option<Duration>,
     pub tcp_connect: Option<Duration>,
     pub tls_handshake: Option<Duration>,
-    pub request_sent: Duration,
-    pub waiting: Duration,
+    pub request_sent: Option<Duration>,
+    pub waiting: Duration, // From sending the request until the response headers arrived
     pub content_download: Duration,
     pub total: Duration,
+    pub expect_continue: Option<ExpectContinue>, // Set when `Expect: 100-continue` was sent
+}
+
+/// How long a body sent with `Expect: 100-continue` is held back, as curl does.
+/// An interim `100 Continue` doesn't end the wait (reqwest never hands it over), only a final answer does.
+pub const EXPECT_CONTINUE_WAIT: Duration = Duration::from_secs(1);
+
+/// What happened to a request sent with `Expect: 100-continue`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectContinue {
+    /// The server gave its final answer before the body went out
+    AnsweredEarly,
+    /// No early answer; the body went out this long after the headers
+    BodySent(Duration),
+}
+
+/// A response body that didn't arrive as announced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BodyMismatch {
+    /// More or fewer bytes than the `Content-Length` header said
+    Length { declared: u64, received: u64 },
+    /// The body broke off without a `Content-Length` to compare against,
+    /// e.g. a chunked body missing its last chunk
+    Incomplete { received: u64, reason: String },
+}
+
+impl BodyMismatch {
+    /// Compare what arrived with the `Content-Length` header. Responses that
+    /// never carry a body (HEAD, 1xx, 204, 304) are not checked.
+    pub fn check(method: &str, status: StatusCode, headers: &HashMap<String, String>, received: u64, error: Option<String>) -> Option<Self> {
+        let bodyless = method.eq_ignore_ascii_case("HEAD")
+            || status.is_informational()
+            || status == StatusCode::NO_CONTENT
+            || status == StatusCode::NOT_MODIFIED;
+        if bodyless {
+            return None;
+        }
+        let declared = headers.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<u64>().ok());
+        match (declared, error) {
+            (Some(declared), _) if declared != received => Some(Self::Length { declared, received }),
+            (_, Some(reason)) => Some(Self::Incomplete { received, reason }),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Length { declared, received } if received < declared => "Truncated body",
+            Self::Length { .. } => "Body size mismatch",
+            Self::Incomplete { .. } => "Incomplete body",
+        }
+    }
+}
+
+impl std::fmt::Display for BodyMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Length { declared, received } if received < declared => {
+                write!(f, "{}: {} of {} bytes (Content-Length)", self.kind(), received, declared)
+            }
+            Self::Length { declared, received } => {
+                write!(f, "{}: {} bytes received, Content-Length says {}", self.kind(), received, declared)
+            }
+            Self::Incomplete { received, reason } => {
+                write!(f, "{} after {} bytes: {}", self.kind(), received, reason)
+            }
+        }
+    }
+}
+
+/// One row of the timing waterfall
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimingPhase {
+    pub label: &'static str,
+    pub start: Duration, // Offset from the start of the request
+    pub duration: Duration,
+}
+
+impl TimingPhase {
+    /// (offset, length) in cells of a waterfall `width` cells wide spanning `scale`.
+    /// Every phase gets at least one cell so short ones stay visible.
+    pub fn cells(&self, scale: Duration, width: usize) -> (usize, usize) {
+        if width == 0 {
+            return (0, 0);
+        }
+        let scale = scale.as_secs_f64().max(f64::EPSILON);
+        let to_cells = |d: Duration| (d.as_secs_f64() / scale * width as f64).round() as usize;
+        let offset = to_cells(self.start).min(width - 1);
+        let length = to_cells(self.duration).clamp(1, width - offset);
+        (offset, length)
+    }
+}
+
+impl NetworkTiming {
+    fn steps(&self) -> [(&'static str, Option<Duration>); 6] {
+        [
+            ("DNS Lookup", self.dns_lookup),
+            ("TCP Connect", self.tcp_connect),
+            ("TLS Handshake", self.tls_handshake),
+            ("Request Sent", self.request_sent),
+            ("Time to First Byte", Some(self.waiting)),
+            ("Content Download", Some(self.content_download)),
+        ]
+    }
+    
+    /// Measured phases in order, each starting where the previous one ended
+    pub fn phases(&self) -> Vec<TimingPhase> {
+        let mut start = Duration::ZERO;
+        self.steps().into_iter()
+            .filter_map(|(label, duration)| {
+                let duration = duration?;
+                let phase = TimingPhase { label, start, duration };
+                start += duration;
+                Some(phase)
+            })
+            .collect()
+    }
+    
+    /// Phases that weren't measured, so they aren't drawn as if they were
+    pub fn unmeasured(&self) -> Vec<&'static str> {
+        self.steps().into_iter().filter(|(_, duration)| duration.is_none()).map(|(label, _)| label).collect()
+    }
 }
 
 /// Captured request details for traffic analysis
@@ -51,17 +297,30 @@ pub struct RequestDetails {
     pub method: String,
     pub url: String,
     pub headers: HashMap<String, String>,
+    pub sent_headers: MergedHeaders, // The same headers in the order sent, with the values they overrode
     pub body: Option<Vec<u8>>,
     pub body_size: usize,
 }
 
+/// A 1xx response that came before the final one, e.g. `103 Early Hints`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterimResponse {
+    pub status: StatusCode,
+    pub headers: Vec<(String, String)>,
+    pub elapsed: Duration, // Since the request was sent
+}
+
 /// Network traffic details (Wireshark-style)
 #[derive(Debug, Clone)]
 pub struct NetworkTraffic {
     pub timing: NetworkTiming,
     pub request: RequestDetails,
+    pub remote_addr: Option<SocketAddr>, // Address the request actually went to
+    pub interim: Vec<InterimResponse>, // Only listed when asked for in `RequestInputs`
+    pub trailers: Vec<(String, String)>, // Sent after a chunked body, in arrival order
     pub response_headers_size: usize,
     pub response_body_size: usize,
+    pub body_mismatch: Option<BodyMismatch>, // Set when the body was cut short or its size is off
 }
 
 /// HTTP response with metadata
@@ -74,36 +333,241 @@ pub struct HttpResponse {
     pub traffic: Option<NetworkTraffic>,
 }
 
+impl HttpResponse {
+    /// The body as text, decoded with the charset of its Content-Type (or a
+    /// BOM or XML declaration); bytes that don't decode are replaced
+    pub fn text(&self) -> String {
+        self.charset().decode(&self.body).into_owned()
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn charset(&self) -> Charset {
+        formatter::detect_charset(&self.body, self.content_type())
+    }
+
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_slice(&self.body)
+    }
+
+    /// Why the body can't be trusted to be complete, if it can't
+    pub fn body_mismatch(&self) -> Option<&BodyMismatch> {
+        self.traffic.as_ref()?.body_mismatch.as_ref()
+    }
+}
+
+/// Settings used to build the underlying reqwest client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpClientConfig {
+    pub timeout: Duration,
+    pub pool_max_idle_per_host: usize,
+    pub http1_only: bool,
+    pub disable_compression: bool,
+    pub max_redirects: usize, // 0 = hand back redirect responses as they are
+    pub proxy: Option<String>, // Used for every request, e.g. `http://proxy.local:3128` or `socks5://127.0.0.1:1080`
+    pub no_proxy: Vec<String>, // Hosts that bypass the proxy
+    pub accept_invalid_certs: bool, // Skip TLS certificate checks, e.g. for self-signed staging hosts
+    pub ca_cert: Option<String>, // Extra trusted root, a PEM file path
+    pub default_headers: BTreeMap<String, String>, // Sent unless the request sets them; may use {{variables}}
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            pool_max_idle_per_host: 10,
+            http1_only: false,
+            disable_compression: false,
+            max_redirects: 10,
+            proxy: None,
+            no_proxy: Vec::new(),
+            accept_invalid_certs: false,
+            ca_cert: None,
+            default_headers: BTreeMap::new(),
+        }
+    }
+}
+
+/// The per-endpoint connection options that need a client of their own
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct ClientVariant {
+    ip_family: IpFamily,
+    fresh: bool,
+    idle_timeout_secs: Option<u64>,
+}
+
+impl From<&ConnectionOptions> for ClientVariant {
+    fn from(options: &ConnectionOptions) -> Self {
+        Self {
+            ip_family: options.ip_family,
+            fresh: options.fresh,
+            idle_timeout_secs: options.idle_timeout_secs,
+        }
+    }
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+type DirectBody = UnsyncBoxBody<Bytes, BoxError>;
+type DirectClient = hyper_util::client::legacy::Client<HttpsConnector<HttpConnector>, DirectBody>;
+
+/// A response as either client hands it over, before its body is read
+struct Received {
+    status: StatusCode,
+    headers: HeaderMap,
+    remote_addr: Option<SocketAddr>,
+    body: DirectBody,
+    interim: Vec<InterimResponse>,
+    deadline: Option<tokio::time::Instant>, // When the request times out, if the client doesn't see to it
+}
+
 /// HTTP client for executing API requests
 #[derive(Clone)]
 pub struct HttpClient {
     client: Client,
-    default_timeout: Duration,
+    config: HttpClientConfig,
+    variants: Arc<Mutex<HashMap<ClientVariant, Client>>>, // Built on first use, shared by clones
+    direct: Arc<Mutex<Option<DirectClient>>>, // Hyper client for sends that list 1xx responses, built on first use
 }
 
 impl HttpClient {
     /// Create a new HTTP client with default timeout of 30 seconds
     pub fn new() -> Result<Self> {
-        Self::with_timeout(Duration::from_secs(30))
+        Self::with_config(HttpClientConfig::default())
     }
     
     /// Create a new HTTP client with custom timeout
     pub fn with_timeout(timeout: Duration) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(timeout)
-            .pool_max_idle_per_host(10)
-            .build()
-            .map_err(HttpError::Request)?;
+        Self::with_config(HttpClientConfig {
+            timeout,
+            ..HttpClientConfig::default()
+        })
+    }
+    
+    /// Create a new HTTP client from explicit client settings
+    pub fn with_config(config: HttpClientConfig) -> Result<Self> {
+        let client = Self::builder(&config)?.build().map_err(HttpError::Request)?;
         
         Ok(Self {
             client,
-            default_timeout: timeout,
+            config,
+            variants: Arc::new(Mutex::new(HashMap::new())),
+            direct: Arc::new(Mutex::new(None)),
         })
     }
     
+    fn builder(config: &HttpClientConfig) -> Result<ClientBuilder> {
+        let mut builder = Client::builder()
+            .timeout(config.timeout)
+            .pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .redirect(match config.max_redirects {
+                0 => Policy::none(),
+                max => Policy::limited(max),
+            });
+        
+        if let Some(proxy) = &config.proxy {
+            let proxy = Proxy::all(proxy).map_err(HttpError::Request)?
+                .no_proxy(NoProxy::from_string(&config.no_proxy.join(",")));
+            builder = builder.proxy(proxy);
+        }
+        
+        if config.accept_invalid_certs {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        
+        if let Some(path) = &config.ca_cert {
+            let invalid = |e: String| HttpError::Certificate(path.clone(), e);
+            let pem = std::fs::read(expand_home(path)).map_err(|e| invalid(e.to_string()))?;
+            builder = builder.add_root_certificate(Certificate::from_pem(&pem).map_err(|e| invalid(e.to_string()))?);
+        }
+        
+        if config.http1_only {
+            builder = builder.http1_only();
+        }
+        
+        if config.disable_compression {
+            builder = builder.no_gzip().no_brotli().no_deflate();
+        }
+        
+        Ok(builder)
+    }
+    
+    /// The client for an endpoint's connection options
+    fn client_for(&self, options: &ConnectionOptions) -> Result<Client> {
+        let variant = ClientVariant::from(options);
+        if variant == ClientVariant::default() {
+            return Ok(self.client.clone());
+        }
+        let mut variants = self.variants.lock().unwrap();
+        if let Some(client) = variants.get(&variant) {
+            return Ok(client.clone());
+        }
+        
+        let mut builder = Self::builder(&self.config)?;
+        // Binding to the unspecified address of a family only connects to addresses of that family
+        builder = match variant.ip_family {
+            IpFamily::Any => builder,
+            IpFamily::V4 => builder.local_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+            IpFamily::V6 => builder.local_address(IpAddr::V6(Ipv6Addr::UNSPECIFIED)),
+        };
+        if variant.fresh {
+            builder = builder.pool_max_idle_per_host(0);
+        }
+        if let Some(secs) = variant.idle_timeout_secs {
+            builder = builder.pool_idle_timeout(Duration::from_secs(secs));
+        }
+        let client = builder.build().map_err(HttpError::Request)?;
+        variants.insert(variant, client.clone());
+        Ok(client)
+    }
+    
+    /// The hyper client that `send_direct` uses, with the same TLS settings
+    fn direct_client(&self) -> Result<DirectClient> {
+        let mut direct = self.direct.lock().unwrap();
+        if let Some(client) = direct.as_ref() {
+            return Ok(client.clone());
+        }
+        
+        let mut tls = native_tls::TlsConnector::builder();
+        tls.danger_accept_invalid_certs(self.config.accept_invalid_certs);
+        if let Some(path) = &self.config.ca_cert {
+            let invalid = |e: String| HttpError::Certificate(path.clone(), e);
+            let pem = std::fs::read(expand_home(path)).map_err(|e| invalid(e.to_string()))?;
+            tls.add_root_certificate(native_tls::Certificate::from_pem(&pem).map_err(|e| invalid(e.to_string()))?);
+        }
+        let tls = tls.build().map_err(|e| HttpError::Connection(e.to_string()))?;
+        let mut http = HttpConnector::new();
+        http.enforce_http(false); // https URLs go on to the TLS connector
+        let client = hyper_util::client::legacy::Client::builder(TokioExecutor::new())
+            .pool_timer(TokioTimer::new())
+            .pool_max_idle_per_host(self.config.pool_max_idle_per_host)
+            .build(HttpsConnector::from((http, tls.into())));
+        *direct = Some(client.clone());
+        Ok(client)
+    }
+    
+    /// Whether a send goes through hyper directly so the 1xx responses before
+    /// the final one can be listed; reqwest drops them. Only endpoints with the
+    /// `interim` connection option do. Proxied requests, including proxies from
+    /// the environment, and other client-level connection options stay on reqwest.
+    fn sends_direct(&self, endpoint: &ApiEndpoint) -> bool {
+        endpoint.connection.interim
+            && self.config.proxy.is_none()
+            && !proxy_from_env()
+            && ClientVariant::from(&endpoint.connection) == ClientVariant::default()
+    }
+    
     /// Get the default timeout
     pub fn timeout(&self) -> Duration {
-        self.default_timeout
+        self.config.timeout
+    }
+    
+    /// Get the settings this client was built with
+    pub fn config(&self) -> &HttpClientConfig {
+        &self.config
     }
     
     /// Build a URL with query parameters
@@ -157,12 +621,12 @@ impl HttpClient {
     ) -> Result<()> {
         match auth {
             AuthConfig::Bearer { token } => {
-                let token_value = template::substitute(token, variables)?;
+                let token_value = template::substitute_field("bearer token", token, variables)?;
                 headers.insert("Authorization".to_string(), format!("Bearer {}", token_value));
             }
             AuthConfig::Basic { username, password } => {
-                let username_value = template::substitute(username, variables)?;
-                let password_value = template::substitute(password, variables)?;
+                let username_value = template::substitute_field("basic auth username", username, variables)?;
+                let password_value = template::substitute_field("basic auth password", password, variables)?;
                 let credentials = format!("{}:{}", username_value, password_value);
                 let encoded = base64::Engine::encode(
                     &base64::engine::general_purpose::STANDARD,
@@ -171,14 +635,14 @@ impl HttpClient {
                 headers.insert("Authorization".to_string(), format!("Basic {}", encoded));
             }
             AuthConfig::ApiKey { name, value, location } => {
-                let key_value = template::substitute(value, variables)?;
+                let key_value = template::substitute_field("API key", value, variables)?;
                 match location {
                     ApiKeyLocation::Header => {
-                        let header_name = template::substitute(name, variables)?;
+                        let header_name = template::substitute_field("API key name", name, variables)?;
                         headers.insert(header_name, key_value);
                     }
                     ApiKeyLocation::QueryParam => {
-                        let param_name = template::substitute(name, variables)?;
+                        let param_name = template::substitute_field("API key name", name, variables)?;
                         query_params.insert(param_name, key_value);
                     }
                 }
@@ -194,86 +658,43 @@ impl HttpClient {
         inputs: &RequestInputs,
     ) -> Result<HttpResponse> {
         let start = Instant::now();
-        
-        // Substitute variables in URL
-        let url = template::substitute(&endpoint.url, &inputs.variables)?;
-        
-        // Build query parameters (merge endpoint defaults with inputs)
-        let mut query_params = inputs.query_params.clone();
-        
-        // Apply authentication (may add to headers or query params)
-        let mut headers = inputs.headers.clone();
-        if let Some(auth) = &endpoint.auth {
-            Self::apply_auth(auth, &mut headers, &mut query_params, &inputs.variables)?;
-        }
-        
-        // Merge endpoint headers with input headers (inputs override)
-        for (key, value) in &endpoint.headers {
-            if !headers.contains_key(key) {
-                let substituted = template::substitute(value, &inputs.variables)?;
-                headers.insert(key.clone(), substituted);
-            }
+        let request_details = self.prepare(endpoint, inputs)?;
+        for header in request_details.sent_headers.conflicts() {
+            tracing::warn!(
+                "{} header set by {} and overridden by {}; sending the {} value",
+                header.name,
+                header.source,
+                header.overridden.iter().map(|(source, _)| source.to_string()).collect::<Vec<_>>().join(", "),
+                header.source,
+            );
         }
         
-        // Build final URL with query parameters
-        let final_url = Self::build_url(&url, &query_params)?;
-        
-        // Capture request details for traffic analysis
-        let request_body = if let Some(body) = &inputs.body {
-            Some(template::substitute(body, &inputs.variables)?)
-        } else if let Some(body_template) = &endpoint.body_template {
-            Some(template::substitute(body_template, &inputs.variables)?)
-        } else {
-            None
-        };
-        
-        let request_body_bytes = request_body.as_ref().map(|b| b.as_bytes().to_vec());
-        let request_body_size = request_body_bytes.as_ref().map(|b| b.len()).unwrap_or(0);
-        
-        let request_details = RequestDetails {
-            method: format!("{:?}", endpoint.method),
-            url: final_url.clone(),
-            headers: headers.clone(),
-            body: request_body_bytes.clone(),
-            body_size: request_body_size,
-        };
-        
-        // Build request
-        let mut request = match endpoint.method {
-            HttpMethod::GET => self.client.get(&final_url),
-            HttpMethod::POST => self.client.post(&final_url),
-            HttpMethod::PUT => self.client.put(&final_url),
-            HttpMethod::PATCH => self.client.patch(&final_url),
-            HttpMethod::DELETE => self.client.delete(&final_url),
-            HttpMethod::HEAD => self.client.head(&final_url),
-            HttpMethod::OPTIONS => self.client.request(reqwest::Method::OPTIONS, &final_url),
-        };
-        
-        // Add headers
-        for (key, value) in headers {
-            Self::validate_header_name(&key)?;
-            request = request.header(key, value);
-        }
-        
-        // Add body if present
-        if let Some(body_content) = request_body {
-            request = request.body(body_content);
-        }
+        // A body sent with `Expect: 100-continue` is held back so the server gets
+        // a chance to answer first
+        let body_released = (request_details.body.is_some() && endpoint.connection.expect_continue)
+            .then(|| Arc::new(Mutex::new(None)));
         
         // Mark request send start
         let request_send_start = Instant::now();
         
         // Execute request
-        let response = request.send().await?;
+        let response = if self.sends_direct(endpoint) {
+            self.send_direct(endpoint, &request_details, body_released.clone()).await?
+        } else {
+            self.send(endpoint, &request_details, body_released.clone()).await?
+        };
         
         // Mark waiting time (time to first byte)
         let waiting_end = Instant::now();
         let waiting_duration = waiting_end.duration_since(request_send_start);
+        let expect_continue = body_released.map(|released| match *released.lock().unwrap() {
+            Some(at) => ExpectContinue::BodySent(at.duration_since(request_send_start)),
+            None => ExpectContinue::AnsweredEarly,
+        });
         
         // Extract response data
-        let status = response.status();
-        let response_headers: HashMap<String, String> = response
-            .headers()
+        let Received { status, headers, remote_addr, body: mut frames, interim, deadline } = response;
+        let response_headers: HashMap<String, String> = headers
             .iter()
             .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
             .collect();
@@ -284,31 +705,80 @@ impl HttpClient {
             .map(|(k, v)| k.len() + v.len() + 4) // +4 for ": " and "\r\n"
             .sum();
         
-        // Download response body
+        // Download response body frame by frame so trailers aren't dropped
         let download_start = Instant::now();
-        let body = response.bytes().await?.to_vec();
+        if let Some(progress) = &inputs.progress {
+            progress.start(frames.size_hint().exact());
+        }
+        let mut body = Vec::new();
+        let mut trailers = Vec::new();
+        let mut body_error = None;
+        loop {
+            let frame = match deadline {
+                Some(deadline) => tokio::time::timeout_at(deadline, frames.frame()).await
+                    .map_err(|_| HttpError::Timeout(self.config.timeout))?,
+                None => frames.frame().await,
+            };
+            let Some(frame) = frame else { break };
+            // A connection that breaks off mid-body still leaves a response
+            // worth showing; timeouts stay errors
+            let frame = match frame.map_err(|e| e.downcast::<reqwest::Error>()) {
+                Ok(frame) => frame,
+                Err(Ok(e)) if e.is_timeout() => return Err(HttpError::Request(*e)),
+                Err(Ok(e)) => {
+                    body_error = Some(innermost_cause(&*e));
+                    break;
+                }
+                Err(Err(e)) => {
+                    body_error = Some(innermost_cause(&*e));
+                    break;
+                }
+            };
+            match frame.into_data() {
+                Ok(data) => {
+                    if let Some(progress) = &inputs.progress {
+                        progress.add(data.len());
+                    }
+                    body.extend_from_slice(&data);
+                }
+                Err(frame) => {
+                    if let Ok(fields) = frame.into_trailers() {
+                        trailers.extend(fields.iter().map(|(k, v)| {
+                            (k.to_string(), v.to_str().unwrap_or("").to_string())
+                        }));
+                    }
+                }
+            }
+        }
         let download_duration = download_start.elapsed();
+        let body_mismatch = BodyMismatch::check(&request_details.method, status, &response_headers, body.len() as u64, body_error);
+        if let Some(mismatch) = &body_mismatch {
+            tracing::warn!("{} {}: {}", request_details.method, request_details.url, mismatch);
+        }
         
         let total_duration = start.elapsed();
         
         // Build network traffic details
-        // Note: We can't easily get DNS/TCP/TLS timing from reqwest without custom connectors
-        // So we'll estimate based on what we have
-        let request_sent_duration = Duration::from_millis(1); // Approximate
-        
+        // Note: neither client reports DNS/TCP/TLS or send timing without custom
+        // connectors, so those phases are left out rather than estimated
         let traffic = NetworkTraffic {
             timing: NetworkTiming {
-                dns_lookup: None, // Would need custom DNS resolver
-                tcp_connect: None, // Would need custom connector
-                tls_handshake: None, // Would need custom TLS connector
-                request_sent: request_sent_duration,
+                dns_lookup: None,
+                tcp_connect: None,
+                tls_handshake: None,
+                request_sent: None,
                 waiting: waiting_duration,
                 content_download: download_duration,
                 total: total_duration,
+                expect_continue,
             },
             request: request_details,
+            remote_addr,
+            interim,
+            trailers,
             response_headers_size,
             response_body_size: body.len(),
+            body_mismatch,
         };
         
         Ok(HttpResponse {
@@ -319,6 +789,277 @@ impl HttpClient {
             traffic: Some(traffic),
         })
     }
+    
+    /// Send through reqwest, which sees to proxies, decompression and the
+    /// endpoint's connection options
+    async fn send(&self, endpoint: &ApiEndpoint, details: &RequestDetails, body_released: Option<Arc<Mutex<Option<Instant>>>>) -> Result<Received> {
+        let final_url = &details.url;
+        let client = self.client_for(&endpoint.connection)?;
+        let mut request = match &endpoint.method {
+            HttpMethod::GET => client.get(final_url),
+            HttpMethod::POST => client.post(final_url),
+            HttpMethod::PUT => client.put(final_url),
+            HttpMethod::PATCH => client.patch(final_url),
+            HttpMethod::DELETE => client.delete(final_url),
+            HttpMethod::HEAD => client.head(final_url),
+            HttpMethod::OPTIONS => client.request(Method::OPTIONS, final_url),
+            HttpMethod::Custom(name) => {
+                let method = Method::from_bytes(name.as_bytes())
+                    .map_err(|_| HttpError::InvalidMethod(name.clone()))?;
+                client.request(method, final_url)
+            }
+        };
+        
+        // Add headers
+        for header in details.sent_headers.iter() {
+            Self::validate_header_name(&header.name)?;
+            request = request.header(&header.name, &header.value);
+        }
+        
+        // Add body if present
+        match (details.body.clone(), body_released) {
+            (Some(body_content), Some(released)) => {
+                let length = body_content.len();
+                request = request.header(reqwest::header::CONTENT_LENGTH, length)
+                    .body(reqwest::Body::wrap_stream(held_back(body_content, released)));
+            }
+            (Some(body_content), None) => request = request.body(body_content),
+            (None, _) if endpoint.empty_body == EmptyBody::Empty => {
+                // A body of unknown length is sent chunked, so this ends up as a lone `0` chunk
+                let empty = futures_util::stream::empty::<std::io::Result<Vec<u8>>>();
+                request = request.body(reqwest::Body::wrap_stream(empty));
+            }
+            (None, _) => {}
+        }
+        
+        let response = request.send().await?;
+        Ok(Received {
+            status: response.status(),
+            headers: response.headers().clone(),
+            remote_addr: response.remote_addr(),
+            body: reqwest::Body::from(response).map_err(BoxError::from).boxed_unsync(),
+            interim: Vec::new(),
+            deadline: None,
+        })
+    }
+    
+    /// Send through hyper directly, noting each 1xx response that comes before
+    /// the final one. Redirects are followed as reqwest follows them. No
+    /// `Accept-Encoding` is added, so the body is asked for uncompressed.
+    async fn send_direct(&self, endpoint: &ApiEndpoint, details: &RequestDetails, body_released: Option<Arc<Mutex<Option<Instant>>>>) -> Result<Received> {
+        let client = self.direct_client()?;
+        let deadline = tokio::time::Instant::now() + self.config.timeout;
+        let sent = Instant::now();
+        let interim = Arc::new(Mutex::new(Vec::new()));
+        let mut method = Method::from_bytes(details.method.as_bytes())
+            .map_err(|_| HttpError::InvalidMethod(details.method.clone()))?;
+        let mut url = Url::parse(&details.url).map_err(|e| HttpError::InvalidUrl(format!("{}: {}", details.url, e)))?;
+        let mut headers: Vec<(String, String)> = details.sent_headers.iter()
+            .map(|header| (header.name.clone(), header.value.clone()))
+            .collect();
+        let mut body = details.body.clone().map(|body| (body, body_released));
+        let mut redirects = 0;
+        loop {
+            let mut request = hyper::Request::builder().method(method.clone()).uri(url.as_str());
+            for (name, value) in &headers {
+                Self::validate_header_name(name)?;
+                request = request.header(name, value);
+            }
+            let outgoing = match body.clone() {
+                Some((body_content, Some(released))) => {
+                    let chunks = held_back(body_content, released).map(|chunk| chunk.map(|data| Frame::data(Bytes::from(data))));
+                    StreamBody::new(chunks).map_err(BoxError::from).boxed_unsync()
+                }
+                Some((body_content, None)) => Full::new(Bytes::from(body_content)).map_err(BoxError::from).boxed_unsync(),
+                // Sent chunked as a lone `0` chunk, as with reqwest
+                None if endpoint.empty_body == EmptyBody::Empty => {
+                    StreamBody::new(futures_util::stream::empty::<std::io::Result<Frame<Bytes>>>()).map_err(BoxError::from).boxed_unsync()
+                }
+                None => Empty::new().map_err(BoxError::from).boxed_unsync(),
+            };
+            let mut request = request.body(outgoing).map_err(|e| HttpError::InvalidHeader(e.to_string()))?;
+            let seen = interim.clone();
+            hyper::ext::on_informational(&mut request, move |response| {
+                seen.lock().unwrap().push(InterimResponse {
+                    status: response.status(),
+                    headers: response.headers().iter()
+                        .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
+                        .collect(),
+                    elapsed: sent.elapsed(),
+                });
+            });
+            let response = tokio::time::timeout_at(deadline, client.request(request)).await
+                .map_err(|_| HttpError::Timeout(self.config.timeout))?
+                .map_err(|e| HttpError::Connection(innermost_cause(&e)))?;
+            
+            let status = response.status();
+            let location = response.headers().get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .and_then(|location| url.join(location).ok());
+            let redirected = matches!(status.as_u16(), 301 | 302 | 303 | 307 | 308) && self.config.max_redirects > 0;
+            match location {
+                Some(_) if redirected && redirects == self.config.max_redirects => {
+                    return Err(HttpError::Connection(format!("too many redirects from {}", details.url)));
+                }
+                Some(next) if redirected => {
+                    redirects += 1;
+                    // A 303, or a 301/302 after a POST, is followed with a GET and no body
+                    if (status == StatusCode::SEE_OTHER && method != Method::HEAD)
+                        || (matches!(status.as_u16(), 301 | 302) && method == Method::POST) {
+                        method = Method::GET;
+                        body = None;
+                        headers.retain(|(name, _)| !is_body_header(name));
+                    }
+                    // Credentials don't go on to another host
+                    if (next.host_str(), next.port_or_known_default()) != (url.host_str(), url.port_or_known_default()) {
+                        headers.retain(|(name, _)| !is_sensitive_header(name));
+                    }
+                    // Only the first send waits on `Expect: 100-continue`
+                    body = body.map(|(body_content, _)| (body_content, None));
+                    url = next;
+                }
+                _ => {
+                    let remote_addr = response.extensions().get::<HttpInfo>().map(HttpInfo::remote_addr);
+                    let (parts, incoming) = response.into_parts();
+                    let interim = std::mem::take(&mut *interim.lock().unwrap());
+                    return Ok(Received {
+                        status: parts.status,
+                        headers: parts.headers,
+                        remote_addr,
+                        body: incoming.map_err(BoxError::from).boxed_unsync(),
+                        interim,
+                        deadline: Some(deadline),
+                    });
+                }
+            }
+        }
+    }
+    
+    /// Everything `execute` would send, without sending it: variables
+    /// substituted, auth applied and headers merged
+    pub fn prepare(&self, endpoint: &ApiEndpoint, inputs: &RequestInputs) -> Result<RequestDetails> {
+        // Substitute variables in URL
+        let url = template::substitute_field("URL", &endpoint.url, &inputs.variables)?;
+        
+        // Build query parameters (merge endpoint defaults with inputs)
+        let mut query_params = inputs.query_params.clone();
+        
+        // Auth first (it may also add to the query params), then input headers,
+        // then the endpoint's; the first to set a name wins
+        let mut headers = MergedHeaders::default();
+        if let Some(auth) = &endpoint.auth {
+            let mut auth_headers = HashMap::new();
+            Self::apply_auth(auth, &mut auth_headers, &mut query_params, &inputs.variables)?;
+            headers.add_all(&auth_headers, HeaderSource::Auth);
+        }
+        headers.add_all(&inputs.headers, HeaderSource::Input);
+        let mut endpoint_headers: Vec<_> = endpoint.headers.iter().collect();
+        endpoint_headers.sort();
+        for (key, value) in endpoint_headers {
+            let substituted = template::substitute_field(&format!("header {}", key), value, &inputs.variables)?;
+            headers.add(key.clone(), substituted, HeaderSource::Endpoint);
+        }
+        
+        // Then the environment's defaults, e.g. a tenant header
+        for (key, value) in &self.config.default_headers {
+            if !headers.contains(key) {
+                let substituted = template::substitute_field(&format!("header {}", key), value, &inputs.variables)?;
+                headers.add(key.clone(), substituted, HeaderSource::Default);
+            }
+        }
+        
+        // SOAP endpoints get their action headers unless set explicitly
+        if let Some(soap) = &endpoint.soap {
+            for (key, value) in soap.headers() {
+                headers.add_missing(key, value, HeaderSource::Soap);
+            }
+        }
+        
+        if endpoint.connection.close {
+            headers.add_missing("Connection", "close", HeaderSource::Connection);
+        }
+        if endpoint.connection.expect_continue {
+            headers.add_missing("Expect", "100-continue", HeaderSource::Connection);
+        }
+        
+        // Build final URL with query parameters
+        let final_url = Self::build_url(&url, &query_params)?;
+        
+        // Capture request details for traffic analysis
+        let request_body = if let Some(body) = &inputs.body {
+            Some(template::substitute_field("body", body, &inputs.variables)?)
+        } else if let Some(graphql) = &endpoint.graphql {
+            // GraphQL endpoints send the query and its variables wrapped in one JSON payload
+            let query = template::substitute_field("GraphQL query", &graphql.query, &inputs.variables)?;
+            let variables = template::substitute_field("GraphQL variables", &graphql.variables, &inputs.variables)?;
+            headers.add_missing("Content-Type", "application/json", HeaderSource::Body);
+            Some(GraphqlBody::payload(&query, &variables).map_err(HttpError::GraphqlVariables)?)
+        } else if let Some(body_template) = &endpoint.body_template {
+            Some(template::substitute_field("body", body_template, &inputs.variables)?)
+        } else {
+            None
+        };
+        
+        // Protobuf endpoints author the body as JSON and send the encoded message
+        let request_body_bytes = match (request_body, &endpoint.protobuf) {
+            (Some(body), Some(proto)) if proto.request_message.is_some() => {
+                let schema = ProtoSchema::load_cached(&expand_home(&proto.proto_file))?;
+                let message = proto.request_message.as_deref().unwrap_or_default();
+                headers.add_missing("Content-Type", "application/x-protobuf", HeaderSource::Body);
+                Some(schema.encode_json(message, &body)?)
+            }
+            (body, _) => body.map(String::into_bytes),
+        };
+        let request_body_bytes = request_body_bytes.filter(|b| !b.is_empty());
+        if request_body_bytes.is_none() && endpoint.empty_body == EmptyBody::ContentLengthZero {
+            headers.add_missing("Content-Length", "0", HeaderSource::Body);
+        }
+        let request_body_size = request_body_bytes.as_ref().map(|b| b.len()).unwrap_or(0);
+        
+        Ok(RequestDetails {
+            method: endpoint.method.to_string(),
+            url: final_url,
+            headers: headers.to_map(),
+            sent_headers: headers,
+            body: request_body_bytes,
+            body_size: request_body_size,
+        })
+    }
+}
+
+/// A body sent `EXPECT_CONTINUE_WAIT` after the headers, noting when it went
+fn held_back(body: Vec<u8>, released: Arc<Mutex<Option<Instant>>>) -> impl futures_util::Stream<Item = std::io::Result<Vec<u8>>> {
+    futures_util::stream::once(async move {
+        tokio::time::sleep(EXPECT_CONTINUE_WAIT).await;
+        *released.lock().unwrap() = Some(Instant::now());
+        Ok(body)
+    })
+}
+
+/// reqwest's and hyper's own messages are generic; the innermost cause says what went wrong
+fn innermost_cause(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut cause = error;
+    while let Some(source) = cause.source() {
+        cause = source;
+    }
+    cause.to_string()
+}
+
+/// Whether reqwest would pick up a proxy from the environment
+fn proxy_from_env() -> bool {
+    ["http_proxy", "https_proxy", "all_proxy"].iter()
+        .flat_map(|name| [name.to_string(), name.to_ascii_uppercase()])
+        .any(|name| std::env::var_os(name).is_some_and(|value| !value.is_empty()))
+}
+
+/// Headers that describe the body, dropped when a redirect turns into a GET
+fn is_body_header(name: &str) -> bool {
+    ["content-type", "content-length", "content-encoding", "transfer-encoding"].iter().any(|h| name.eq_ignore_ascii_case(h))
+}
+
+/// Headers that don't follow a redirect to another host
+fn is_sensitive_header(name: &str) -> bool {
+    ["authorization", "cookie", "proxy-authorization", "www-authenticate"].iter().any(|h| name.eq_ignore_ascii_case(h))
 }
 
 impl Default for HttpClient {
@@ -344,6 +1085,25 @@ mod tests {
         assert_eq!(client.timeout(), timeout);
     }
 
+    #[test]
+    fn test_http_client_with_config() {
+        let config = HttpClientConfig {
+            timeout: Duration::from_secs(5),
+            pool_max_idle_per_host: 0,
+            http1_only: true,
+            disable_compression: true,
+            max_redirects: 0,
+            proxy: Some("http://127.0.0.1:3128".to_string()),
+            no_proxy: vec!["localhost".to_string()],
+            accept_invalid_certs: true,
+            ca_cert: None,
+            default_headers: BTreeMap::from([("X-Tenant".to_string(), "acme".to_string())]),
+        };
+        let client = HttpClient::with_config(config.clone()).unwrap();
+        assert_eq!(client.timeout(), Duration::from_secs(5));
+        assert_eq!(client.config(), &config);
+    }
+
     #[test]
     fn test_request_inputs_default() {
         let inputs = RequestInputs::default();
@@ -470,5 +1230,512 @@ mod tests {
         assert_eq!(query_params.get("api_key"), Some(&"secret-456".to_string()));
         assert_eq!(headers.len(), 0);
     }
-}
 
+    #[test]
+    fn test_timing_waterfall_cells() {
+        let ms = Duration::from_millis;
+        let timing = NetworkTiming {
+            dns_lookup: None,
+            tcp_connect: Some(ms(20)),
+            tls_handshake: None,
+            request_sent: Some(ms(0)),
+            waiting: ms(60),
+            content_download: ms(20),
+            total: ms(100),
+            expect_continue: None,
+        };
+        let phases = timing.phases();
+        assert_eq!(phases.iter().map(|p| p.label).collect::<Vec<_>>(),
+            vec!["TCP Connect", "Request Sent", "Time to First Byte", "Content Download"]);
+        assert_eq!(phases[2].start, ms(20));
+        assert_eq!(timing.unmeasured(), vec!["DNS Lookup", "TLS Handshake"]);
+
+        let cells: Vec<_> = phases.iter().map(|p| p.cells(timing.total, 50)).collect();
+        assert_eq!(cells, vec![(0, 10), (10, 1), (10, 30), (40, 10)]);
+        assert_eq!(phases[0].cells(Duration::ZERO, 10), (0, 10));
+        assert_eq!(phases[3].cells(ms(50), 10), (9, 1));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_and_no_proxy() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut request_lines = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                socket.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await.unwrap();
+                request_lines.push(String::from_utf8_lossy(&buf[..n]).lines().next().unwrap_or_default().to_string());
+            }
+            request_lines
+        });
+
+        // Through the proxy the request line carries the whole URL
+        let proxied = HttpClient::with_config(HttpClientConfig {
+            proxy: Some(format!("http://{}", addr)),
+            ..HttpClientConfig::default()
+        }).unwrap();
+        let endpoint = ApiEndpoint::new("Users".to_string(), HttpMethod::GET, "http://api.example.test/users".to_string());
+        assert_eq!(proxied.execute(&endpoint, &RequestInputs::default()).await.unwrap().status, StatusCode::NO_CONTENT);
+
+        // Hosts on the no-proxy list skip the (unreachable) proxy
+        let bypassed = HttpClient::with_config(HttpClientConfig {
+            proxy: Some("http://127.0.0.1:9".to_string()),
+            no_proxy: vec!["127.0.0.1".to_string()],
+            ..HttpClientConfig::default()
+        }).unwrap();
+        let endpoint = ApiEndpoint::new("Direct".to_string(), HttpMethod::GET, format!("http://{}/direct", addr));
+        assert_eq!(bypassed.execute(&endpoint, &RequestInputs::default()).await.unwrap().status, StatusCode::NO_CONTENT);
+
+        assert_eq!(server.await.unwrap(), vec!["GET http://api.example.test/users HTTP/1.1", "GET /direct HTTP/1.1"]);
+    }
+
+    /// Raw request head the client sends for an empty POST in each mode
+    async fn capture_empty_post(mode: EmptyBody) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_lowercase()
+        });
+
+        let mut endpoint = ApiEndpoint::new("Empty".to_string(), HttpMethod::POST, url);
+        endpoint.empty_body = mode;
+        HttpClient::new().unwrap().execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        server.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ip_family_is_forced_and_reported() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await.unwrap();
+        });
+
+        let client = HttpClient::new().unwrap();
+        let mut endpoint = ApiEndpoint::new("v4".to_string(), HttpMethod::GET, format!("http://localhost:{}/", port));
+        endpoint.connection.ip_family = IpFamily::V4;
+        let response = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        let remote = response.traffic.unwrap().remote_addr.unwrap();
+        assert!(remote.is_ipv4(), "{}", remote);
+        server.await.unwrap();
+
+        // Nothing listens on the IPv6 side, and IPv4 addresses are never tried
+        endpoint.connection.ip_family = IpFamily::V6;
+        endpoint.url = format!("http://127.0.0.1:{}/", port);
+        assert!(client.execute(&endpoint, &RequestInputs::default()).await.is_err());
+        assert_eq!(client.variants.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_compression_can_be_disabled() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // {"ok":true} gzipped
+        const GZIPPED: [u8; 31] = [
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xab, 0x56, 0xca, 0xcf, 0x56, 0xb2,
+            0x2a, 0x29, 0x2a, 0x4d, 0xad, 0x05, 0x00, 0x90, 0x5f, 0xd4, 0xa7, 0x0b, 0x00, 0x00, 0x00,
+        ];
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut heads = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let head = format!("HTTP/1.1 200 OK\r\ncontent-encoding: gzip\r\ncontent-length: {}\r\nconnection: close\r\n\r\n", GZIPPED.len());
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(&GZIPPED).await.unwrap();
+                heads.push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+            }
+            heads
+        });
+
+        let endpoint = ApiEndpoint::new("Gzip".to_string(), HttpMethod::GET, url);
+        let compressed = HttpClient::new().unwrap().execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(compressed.body, br#"{"ok":true}"#);
+        let plain = HttpClient::with_config(HttpClientConfig { disable_compression: true, ..HttpClientConfig::default() })
+            .unwrap()
+            .execute(&endpoint, &RequestInputs::default())
+            .await
+            .unwrap();
+        assert_eq!(plain.body, GZIPPED);
+
+        let heads = server.await.unwrap();
+        assert!(heads[0].contains("accept-encoding: gzip"), "{}", heads[0]);
+        assert!(!heads[1].contains("accept-encoding"), "{}", heads[1]);
+    }
+
+    #[tokio::test]
+    async fn test_fresh_and_close_connections() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Answers one request per connection and leaves the connection open
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            let mut open = Vec::new();
+            for _ in 0..3 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                requests.push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+                socket.write_all(b"HTTP/1.1 204 No Content\r\n\r\n").await.unwrap();
+                open.push(socket);
+            }
+            requests
+        });
+
+        let client = HttpClient::new().unwrap();
+        let mut endpoint = ApiEndpoint::new("fresh".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        endpoint.connection = ConnectionOptions::parse("fresh").unwrap();
+        client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        endpoint.connection = ConnectionOptions::parse("close").unwrap();
+        client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+
+        // A reused connection would leave the server waiting for a third accept
+        let requests = tokio::time::timeout(Duration::from_secs(5), server).await.unwrap().unwrap();
+        assert!(!requests[0].contains("connection: close"));
+        assert!(requests[2].contains("connection: close"));
+    }
+
+    #[tokio::test]
+    async fn test_trailers_are_captured() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket.write_all(concat!(
+                "HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\ntrailer: x-checksum\r\nconnection: close\r\n\r\n",
+                "5\r\nhello\r\n0\r\nx-checksum: abc123\r\n\r\n",
+            ).as_bytes()).await.unwrap();
+        });
+
+        let client = HttpClient::new().unwrap();
+        let endpoint = ApiEndpoint::new("trailers".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        let response = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(response.body, b"hello");
+        let traffic = response.traffic.unwrap();
+        assert_eq!(traffic.trailers, vec![("x-checksum".to_string(), "abc123".to_string())]);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_interim_responses_are_listed_for_endpoints_that_ask() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                for answer in [
+                    "HTTP/1.1 103 Early Hints\r\nlink: </app.css>; rel=preload\r\n\r\nHTTP/1.1 302 Found\r\nlocation: /next\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    "HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok",
+                ] {
+                    let (mut socket, _) = listener.accept().await.unwrap();
+                    let mut buf = vec![0u8; 4096];
+                    let n = socket.read(&mut buf).await.unwrap();
+                    requests.push(String::from_utf8_lossy(&buf[..n]).lines().next().unwrap_or_default().to_string());
+                    socket.write_all(answer.as_bytes()).await.unwrap();
+                }
+            }
+            requests
+        });
+
+        let client = HttpClient::new().unwrap();
+        let mut endpoint = ApiEndpoint::new("hints".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        endpoint.connection = ConnectionOptions::parse("interim").unwrap();
+        let response = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!((response.status, response.body.as_slice()), (StatusCode::OK, &b"ok"[..]));
+        let traffic = response.traffic.unwrap();
+        assert_eq!(traffic.interim.len(), 1);
+        assert_eq!(traffic.interim[0].status.as_u16(), 103);
+        assert_eq!(traffic.interim[0].headers, vec![("link".to_string(), "</app.css>; rel=preload".to_string())]);
+        assert!(traffic.remote_addr.is_some());
+
+        // reqwest skips them
+        endpoint.connection = ConnectionOptions::default();
+        let response = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert!(response.traffic.unwrap().interim.is_empty());
+        assert_eq!(server.await.unwrap(), vec!["GET / HTTP/1.1", "GET /next HTTP/1.1", "GET / HTTP/1.1", "GET /next HTTP/1.1"]);
+    }
+
+    #[tokio::test]
+    async fn test_body_mismatch_is_flagged() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let responses: [&[u8]; 3] = [
+                b"HTTP/1.1 200 OK\r\ncontent-length: 10\r\nconnection: close\r\n\r\nabcd",
+                b"HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\nconnection: close\r\n\r\n4\r\nabcd\r\n",
+                b"HTTP/1.1 200 OK\r\ncontent-length: 4\r\nconnection: close\r\n\r\nabcd",
+            ];
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await.unwrap();
+                socket.write_all(response).await.unwrap();
+            }
+        });
+
+        let client = HttpClient::new().unwrap();
+        let endpoint = ApiEndpoint::new("flaky".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        let truncated = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(truncated.body, b"abcd");
+        assert_eq!(truncated.body_mismatch(), Some(&BodyMismatch::Length { declared: 10, received: 4 }));
+        assert_eq!(truncated.body_mismatch().unwrap().to_string(), "Truncated body: 4 of 10 bytes (Content-Length)");
+
+        let unterminated = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(unterminated.body, b"abcd");
+        assert!(matches!(unterminated.body_mismatch(), Some(BodyMismatch::Incomplete { received: 4, .. })));
+
+        let complete = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(complete.body_mismatch(), None);
+        server.await.unwrap();
+
+        let headers = HashMap::from([("Content-Length".to_string(), "512".to_string())]);
+        assert_eq!(BodyMismatch::check("HEAD", StatusCode::OK, &headers, 0, None), None);
+        assert_eq!(BodyMismatch::check("GET", StatusCode::NOT_MODIFIED, &headers, 0, None), None);
+        assert_eq!(
+            BodyMismatch::check("GET", StatusCode::OK, &headers, 600, None).unwrap().to_string(),
+            "Body size mismatch: 600 bytes received, Content-Length says 512",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_redirects_can_be_left_unfollowed() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 302 Found\r\nlocation: /elsewhere\r\ncontent-length: 0\r\nconnection: close\r\n\r\n").await.unwrap();
+        });
+
+        let client = HttpClient::with_config(HttpClientConfig { max_redirects: 0, ..HttpClientConfig::default() }).unwrap();
+        let endpoint = ApiEndpoint::new("redirect".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        let response = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(response.status, 302);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_yield_to_the_endpoint() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_lowercase()
+        });
+
+        let mut config = HttpClientConfig::default();
+        config.default_headers.insert("X-Tenant".to_string(), "{{tenant}}".to_string());
+        config.default_headers.insert("Accept".to_string(), "text/plain".to_string());
+        let client = HttpClient::with_config(config).unwrap();
+        let mut endpoint = ApiEndpoint::new("users".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        endpoint.headers.insert("accept".to_string(), "application/json".to_string());
+        let inputs = RequestInputs { variables: HashMap::from([("tenant".to_string(), "acme".to_string())]), ..Default::default() };
+        client.execute(&endpoint, &inputs).await.unwrap();
+        let request = server.await.unwrap();
+        assert!(request.contains("x-tenant: acme"));
+        assert!(request.contains("accept: application/json"));
+        assert!(!request.contains("text/plain"));
+
+        let config = HttpClientConfig { ca_cert: Some("/nonexistent/ca.pem".to_string()), ..HttpClientConfig::default() };
+        assert!(matches!(HttpClient::with_config(config), Err(HttpError::Certificate(..))));
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_headers_are_sent_once() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_lowercase()
+        });
+
+        let client = HttpClient::new().unwrap();
+        let mut endpoint = ApiEndpoint::new("users".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        endpoint.auth = Some(AuthConfig::Bearer { token: "new".to_string() });
+        endpoint.headers.insert("authorization".to_string(), "Bearer old".to_string());
+        endpoint.headers.insert("X-Trace".to_string(), "endpoint".to_string());
+        let inputs = RequestInputs {
+            headers: HashMap::from([("x-trace".to_string(), "input".to_string())]),
+            ..Default::default()
+        };
+        let response = client.execute(&endpoint, &inputs).await.unwrap();
+        let request = server.await.unwrap();
+        assert_eq!(request.matches("authorization:").count(), 1);
+        assert!(request.contains("authorization: bearer new"));
+        assert_eq!(request.matches("x-trace:").count(), 1);
+        assert!(request.contains("x-trace: input"));
+
+        let sent = &response.traffic.unwrap().request.sent_headers;
+        let order: Vec<_> = sent.iter().map(|h| (h.name.as_str(), h.source)).collect();
+        assert_eq!(order, vec![("Authorization", HeaderSource::Auth), ("x-trace", HeaderSource::Input)]);
+        assert_eq!(sent.conflicts().count(), 2);
+    }
+
+    #[test]
+    fn test_progress_snapshot() {
+        let snapshot = ProgressSnapshot {
+            received: 2 * 1024 * 1024,
+            total: Some(10 * 1024 * 1024),
+            elapsed: Duration::from_secs(2),
+            started: Instant::now(),
+        };
+        assert_eq!(snapshot.ratio(), Some(0.2));
+        assert_eq!(snapshot.eta(), Some(Duration::from_secs(8)));
+        assert_eq!(snapshot.label(), "2.0 MB / 10.0 MB · 1.0 MB/s · ETA 8s");
+
+        let unknown = ProgressSnapshot { total: None, received: 512, ..snapshot };
+        assert_eq!(unknown.ratio(), None);
+        assert_eq!(unknown.label(), "512 B · 256 B/s");
+    }
+
+    #[tokio::test]
+    async fn test_download_progress_is_tracked() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 10000\r\nconnection: close\r\n\r\n").await.unwrap();
+            socket.write_all(&[b'x'; 10000]).await.unwrap();
+        });
+
+        let progress = DownloadProgress::default();
+        assert!(progress.snapshot().is_none());
+        let inputs = RequestInputs { progress: Some(progress.clone()), ..Default::default() };
+        let endpoint = ApiEndpoint::new("download".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port));
+        HttpClient::new().unwrap().execute(&endpoint, &inputs).await.unwrap();
+        let snapshot = progress.snapshot().unwrap();
+        assert_eq!((snapshot.received, snapshot.total), (10000, Some(10000)));
+        assert_eq!(snapshot.ratio(), Some(1.0));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_expect_continue_outcomes() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Rejects the first request from its headers alone, then reads the second in full
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let mut open = Vec::new();
+            for (until, reply) in [("\r\n\r\n", "417 Expectation Failed"), ("hello", "200 OK")] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = vec![0u8; 4096];
+                while !String::from_utf8_lossy(&request).contains(until) {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                assert!(String::from_utf8_lossy(&request).to_lowercase().contains("expect: 100-continue"));
+                let response = format!("HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", reply);
+                socket.write_all(response.as_bytes()).await.unwrap();
+                open.push(socket);
+            }
+        });
+
+        let client = HttpClient::new().unwrap();
+        let mut endpoint = ApiEndpoint::new("expect".to_string(), HttpMethod::POST, format!("http://127.0.0.1:{}/", port));
+        endpoint.body_template = Some("hello".to_string());
+        endpoint.connection = ConnectionOptions::parse("expect-continue").unwrap();
+
+        let response = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(response.status.as_u16(), 417);
+        assert_eq!(response.traffic.unwrap().timing.expect_continue, Some(ExpectContinue::AnsweredEarly));
+
+        let response = client.execute(&endpoint, &RequestInputs::default()).await.unwrap();
+        assert_eq!(response.status.as_u16(), 200);
+        match response.traffic.unwrap().timing.expect_continue {
+            Some(ExpectContinue::BodySent(waited)) => assert!(waited >= EXPECT_CONTINUE_WAIT),
+            other => panic!("unexpected {:?}", other),
+        }
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_graphql_payload_on_the_wire() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+
+        let mut endpoint = ApiEndpoint::new("Users".to_string(), HttpMethod::POST, url);
+        endpoint.graphql = Some(GraphqlBody { query: "{ users(first: $n) { id } }".to_string(), variables: "{\"n\": {{limit}}}".to_string() });
+        let inputs = RequestInputs { variables: HashMap::from([("limit".to_string(), "5".to_string())]), ..Default::default() };
+        HttpClient::new().unwrap().execute(&endpoint, &inputs).await.unwrap();
+        let request = server.await.unwrap();
+        assert!(request.to_lowercase().contains("content-type: application/json"));
+        let body: serde_json::Value = serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({"query": "{ users(first: $n) { id } }", "variables": {"n": 5}}));
+
+        endpoint.graphql = Some(GraphqlBody { query: "{ users { id } }".to_string(), variables: "[1]".to_string() });
+        let error = HttpClient::new().unwrap().execute(&endpoint, &RequestInputs::default()).await.unwrap_err();
+        assert!(matches!(error, HttpError::GraphqlVariables(_)));
+    }
+
+    #[tokio::test]
+    async fn test_empty_body_modes_on_the_wire() {
+        let omitted = capture_empty_post(EmptyBody::Omit).await;
+        assert!(!omitted.contains("content-length") && !omitted.contains("transfer-encoding"));
+
+        let empty = capture_empty_post(EmptyBody::Empty).await;
+        assert!(empty.contains("transfer-encoding: chunked"));
+
+        let zero = capture_empty_post(EmptyBody::ContentLengthZero).await;
+        assert!(zero.contains("content-length: 0") && !zero.contains("transfer-encoding"));
+    }
+}
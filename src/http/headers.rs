@@ -0,0 +1,132 @@
+// Header merging for outgoing requests
+// A request's headers come from several places: auth, per-call inputs, the
+// endpoint, the environment's defaults and options such as SOAP or
+// `Connection: close`. The first source to set a name wins, compared
+// case-insensitively, so each header goes out once. Values that lost to auth,
+// input or endpoint headers are kept so the traffic panel can show them and
+// warn when they disagreed; the rest are fallbacks that quietly step aside.
+
+use std::collections::HashMap;
+
+/// Where a sent header came from, highest priority first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderSource {
+    Auth,
+    Input,
+    Endpoint,
+    Default, // The active environment's default headers
+    Soap,
+    Connection, // `Connection: close` and `Expect: 100-continue` options
+    Body, // Content-Type of protobuf bodies, `Content-Length: 0`
+}
+
+impl std::fmt::Display for HeaderSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Auth => "auth",
+            Self::Input => "input",
+            Self::Endpoint => "endpoint",
+            Self::Default => "environment default",
+            Self::Soap => "SOAP",
+            Self::Connection => "connection option",
+            Self::Body => "body",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A header as sent, with the values other sources wanted for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentHeader {
+    pub name: String,
+    pub value: String,
+    pub source: HeaderSource,
+    pub overridden: Vec<(HeaderSource, String)>, // In the order they were dropped
+}
+
+impl SentHeader {
+    /// Another source set this header to something else
+    pub fn is_conflict(&self) -> bool {
+        self.overridden.iter().any(|(_, value)| *value != self.value)
+    }
+}
+
+/// Headers in the order they are sent
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergedHeaders {
+    headers: Vec<SentHeader>,
+}
+
+impl MergedHeaders {
+    /// Add a header unless an earlier source already set it
+    pub fn add(&mut self, name: impl Into<String>, value: impl Into<String>, source: HeaderSource) {
+        let (name, value) = (name.into(), value.into());
+        match self.headers.iter_mut().find(|h| h.name.eq_ignore_ascii_case(&name)) {
+            Some(existing) => existing.overridden.push((source, value)),
+            None => self.headers.push(SentHeader { name, value, source, overridden: Vec::new() }),
+        }
+    }
+
+    /// Add a fallback that yields silently to any header already set, e.g.
+    /// `Connection: close` from the endpoint's connection options
+    pub fn add_missing(&mut self, name: impl Into<String>, value: impl Into<String>, source: HeaderSource) {
+        let name = name.into();
+        if !self.contains(&name) {
+            self.add(name, value, source);
+        }
+    }
+
+    /// Add every header of a map; sorted by name, since maps have no order of their own
+    pub fn add_all<'a>(&mut self, headers: impl IntoIterator<Item = (&'a String, &'a String)>, source: HeaderSource) {
+        let mut headers: Vec<_> = headers.into_iter().collect();
+        headers.sort();
+        for (name, value) in headers {
+            self.add(name.clone(), value.clone(), source);
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.headers.iter().any(|h| h.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SentHeader> {
+        self.headers.iter()
+    }
+
+    /// Headers that more than one source set to different values
+    pub fn conflicts(&self) -> impl Iterator<Item = &SentHeader> {
+        self.headers.iter().filter(|h| h.is_conflict())
+    }
+
+    pub fn to_map(&self) -> HashMap<String, String> {
+        self.headers.iter().map(|h| (h.name.clone(), h.value.clone())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_source_wins_and_conflicts_are_kept() {
+        let mut headers = MergedHeaders::default();
+        headers.add("Authorization", "Bearer new", HeaderSource::Auth);
+        let endpoint = HashMap::from([
+            ("authorization".to_string(), "Bearer old".to_string()),
+            ("Accept".to_string(), "application/json".to_string()),
+        ]);
+        headers.add_all(&endpoint, HeaderSource::Endpoint);
+        headers.add("accept", "application/json", HeaderSource::Default);
+
+        let sent: Vec<_> = headers.iter().map(|h| (h.name.as_str(), h.value.as_str(), h.source)).collect();
+        assert_eq!(sent, vec![
+            ("Authorization", "Bearer new", HeaderSource::Auth),
+            ("Accept", "application/json", HeaderSource::Endpoint),
+        ]);
+        let conflicts: Vec<_> = headers.conflicts().collect();
+        assert_eq!(conflicts.len(), 1, "the same value twice isn't a conflict");
+        assert_eq!(conflicts[0].overridden, vec![(HeaderSource::Endpoint, "Bearer old".to_string())]);
+        assert!(headers.contains("ACCEPT"));
+        assert_eq!(headers.to_map().len(), 2);
+    }
+}
@@ -0,0 +1,129 @@
+// Request builder for using the HTTP layer as a library
+// Wraps an endpoint (usually one from a saved collection) and the values it
+// is sent with, so integration tests elsewhere can reuse collections:
+// `Request::from_endpoint(&endpoint).var("id", "1").send(&client).await`.
+
+use super::{DownloadProgress, HttpClient, HttpResponse, RequestInputs, Result};
+use crate::models::{ApiEndpoint, AuthConfig, HttpMethod};
+
+/// A request ready to send: an endpoint plus variables and per-call overrides
+#[derive(Debug, Clone)]
+pub struct Request {
+    endpoint: ApiEndpoint,
+    inputs: RequestInputs,
+}
+
+impl Request {
+    /// Start from a saved endpoint; its headers, auth and body template apply
+    pub fn from_endpoint(endpoint: &ApiEndpoint) -> Self {
+        Self { endpoint: endpoint.clone(), inputs: RequestInputs::default() }
+    }
+
+    /// Start from scratch; the URL may contain `{{variables}}`
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        let url = url.into();
+        Self::from_endpoint(&ApiEndpoint::new(format!("{} {}", method, url), method, url))
+    }
+
+    /// Value for a `{{name}}` in the URL, headers, auth or body
+    pub fn var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inputs.variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Several variables at once, e.g. the saved variables or an environment
+    pub fn vars<K: Into<String>, V: Into<String>>(mut self, variables: impl IntoIterator<Item = (K, V)>) -> Self {
+        self.inputs.variables.extend(variables.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Send a header, replacing the endpoint's header of the same name
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inputs.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add a query parameter to the URL
+    pub fn query(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inputs.query_params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Send this body instead of the endpoint's body template
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.inputs.body = Some(body.into());
+        self
+    }
+
+    pub fn auth(mut self, auth: AuthConfig) -> Self {
+        self.endpoint.auth = Some(auth);
+        self
+    }
+
+    /// Track the response body download, e.g. to show a progress bar
+    pub fn progress(mut self, progress: DownloadProgress) -> Self {
+        self.inputs.progress = Some(progress);
+        self
+    }
+
+    pub fn endpoint(&self) -> &ApiEndpoint {
+        &self.endpoint
+    }
+
+    pub fn inputs(&self) -> &RequestInputs {
+        &self.inputs
+    }
+
+    pub async fn send(&self, client: &HttpClient) -> Result<HttpResponse> {
+        client.execute(&self.endpoint, &self.inputs).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::HttpError;
+    use crate::template::TemplateError;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_builder_sends_endpoint_with_overrides() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 11\r\nconnection: close\r\n\r\n{\"id\": \"1\"}").await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).into_owned()
+        });
+
+        let mut endpoint = ApiEndpoint::new("user".to_string(), HttpMethod::PUT, "{{host}}/users/{{id}}".to_string());
+        endpoint.headers.insert("X-Trace".to_string(), "endpoint".to_string());
+        endpoint.body_template = Some("{\"name\": \"{{name}}\"}".to_string());
+        let client = HttpClient::new().unwrap();
+        let response = Request::from_endpoint(&endpoint)
+            .vars([("host", format!("http://127.0.0.1:{}", port))])
+            .var("id", "1")
+            .var("name", "bob")
+            .header("X-Trace", "test")
+            .query("dry_run", "true")
+            .auth(AuthConfig::Bearer { token: "{{token}}".to_string() })
+            .var("token", "secret")
+            .send(&client)
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.text(), "{\"id\": \"1\"}");
+        assert_eq!(response.json::<serde_json::Value>().unwrap()["id"], "1");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("PUT /users/1?dry_run=true HTTP/1.1"));
+        assert!(request.to_lowercase().contains("x-trace: test"));
+        assert!(request.to_lowercase().contains("authorization: bearer secret"));
+        assert!(request.ends_with("{\"name\": \"bob\"}"));
+
+        let missing = Request::new(HttpMethod::GET, "{{host}}/health").send(&client).await;
+        assert!(matches!(missing, Err(HttpError::Template(TemplateError::Located(_)))));
+    }
+}
@@ -0,0 +1,134 @@
+// UI message catalog
+// Translatable UI text lives in one TOML file per language under locales/,
+// compiled into the binary, with a table per screen: `[help] title = ...` is
+// looked up as "help.title". Keys missing from a translation fall back to the
+// English text, so a catalog can be filled in a bit at a time. Text with
+// `{name}` placeholders is filled in by `tr_with`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// Language of the UI text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+    De,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::Es, Locale::De];
+
+    pub fn next(self) -> Self {
+        match self {
+            Locale::En => Locale::Es,
+            Locale::Es => Locale::De,
+            Locale::De => Locale::En,
+        }
+    }
+
+    /// The language's own name for itself
+    pub fn label(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::De => "Deutsch",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Locale::En => include_str!("../locales/en.toml"),
+            Locale::Es => include_str!("../locales/es.toml"),
+            Locale::De => include_str!("../locales/de.toml"),
+        }
+    }
+}
+
+type Catalog = HashMap<String, String>;
+
+fn parse(source: &str) -> Result<Catalog, toml::de::Error> {
+    let sections: HashMap<String, HashMap<String, String>> = toml::from_str(source)?;
+    Ok(sections.into_iter()
+        .flat_map(|(section, messages)| messages.into_iter().map(move |(key, text)| (format!("{}.{}", section, key), text)))
+        .collect())
+}
+
+fn catalogs() -> &'static HashMap<Locale, Catalog> {
+    static CATALOGS: OnceLock<HashMap<Locale, Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        Locale::ALL.into_iter()
+            .map(|locale| {
+                // The files are part of the build and checked by the tests below
+                let catalog = parse(locale.source()).unwrap_or_else(|e| {
+                    tracing::error!("Invalid {:?} message catalog: {}", locale, e);
+                    Catalog::new()
+                });
+                (locale, catalog)
+            })
+            .collect()
+    })
+}
+
+/// The text for `key` in `locale`, else in English, else the key itself
+pub fn tr(locale: Locale, key: &'static str) -> &'static str {
+    let lookup = |locale| catalogs().get(&locale).and_then(|c| c.get(key)).map(String::as_str);
+    lookup(locale).or_else(|| lookup(Locale::En)).unwrap_or(key)
+}
+
+/// `tr` with each `{name}` placeholder replaced by its value
+pub fn tr_with(locale: Locale, key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter().fold(tr(locale, key).to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
+
+#[cfg(test)]
+fn placeholders(text: &str) -> Vec<&str> {
+    let mut names: Vec<&str> = text.split('{').skip(1).filter_map(|rest| rest.split_once('}')).map(|(name, _)| name).collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalogs_have_the_same_keys() {
+        let english = parse(Locale::En.source()).unwrap();
+        for locale in [Locale::Es, Locale::De] {
+            let catalog = parse(locale.source()).unwrap();
+            let mut missing: Vec<&String> = english.keys().filter(|k| !catalog.contains_key(*k)).collect();
+            missing.sort();
+            assert!(missing.is_empty(), "{:?} is missing {:?}", locale, missing);
+            let mut unknown: Vec<&String> = catalog.keys().filter(|k| !english.contains_key(*k)).collect();
+            unknown.sort();
+            assert!(unknown.is_empty(), "{:?} has keys English doesn't: {:?}", locale, unknown);
+        }
+    }
+
+    #[test]
+    fn test_translations_keep_the_placeholders() {
+        let english = parse(Locale::En.source()).unwrap();
+        for locale in [Locale::Es, Locale::De] {
+            let catalog = parse(locale.source()).unwrap();
+            for (key, text) in &catalog {
+                assert_eq!(placeholders(text), placeholders(&english[key]), "{:?} {}", locale, key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup_and_fallback() {
+        assert_eq!(tr(Locale::En, "help.title"), "⌨️  Keyboard Shortcuts");
+        assert_eq!(tr(Locale::De, "help.title"), "⌨️  Tastenkürzel");
+        assert_eq!(tr(Locale::Es, "no.such.key"), "no.such.key");
+        assert_eq!(Locale::De.next(), Locale::En);
+        assert_eq!(tr_with(Locale::En, "status.saved_notes", &[("name", &"API")]), "Saved notes for 'API'");
+    }
+}
@@ -0,0 +1,276 @@
+// Import and export of other tools' collection formats
+// Each format lives in its own submodule; `import_any` sniffs the file so the
+// UI can offer a single "import file" command.
+
+pub mod curl;
+pub mod httpie;
+pub mod insomnia;
+pub mod openapi;
+pub mod postman;
+pub mod wsdl;
+
+use crate::models::{ApiCollection, HttpMethod};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum InteropError {
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Unsupported format: {0}")]
+    Format(String),
+
+    #[error("Download failed: {0}")]
+    Fetch(String),
+}
+
+pub type Result<T> = std::result::Result<T, InteropError>;
+
+/// Export formats offered by the export screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Native,
+    Insomnia,
+    OpenApiJson,
+    OpenApiYaml,
+}
+
+impl ExportFormat {
+    pub fn next(self) -> Self {
+        match self {
+            ExportFormat::Native => ExportFormat::Insomnia,
+            ExportFormat::Insomnia => ExportFormat::OpenApiJson,
+            ExportFormat::OpenApiJson => ExportFormat::OpenApiYaml,
+            ExportFormat::OpenApiYaml => ExportFormat::Native,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Native => "REST API TUI (JSON)",
+            ExportFormat::Insomnia => "Insomnia v4",
+            ExportFormat::OpenApiJson => "OpenAPI 3.0 (JSON)",
+            ExportFormat::OpenApiYaml => "OpenAPI 3.0 (YAML)",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Native => "json",
+            ExportFormat::Insomnia => "insomnia.json",
+            ExportFormat::OpenApiJson => "openapi.json",
+            ExportFormat::OpenApiYaml => "openapi.yaml",
+        }
+    }
+}
+
+/// Parse a file in any supported format into fresh collections
+pub fn import_any(contents: &str) -> Result<Vec<ApiCollection>> {
+    if contents.trim_start().starts_with('<') {
+        return Ok(vec![wsdl::import(contents)?]);
+    }
+    let value: serde_json::Value = serde_json::from_str(contents)?;
+    if insomnia::is_insomnia(&value) {
+        return insomnia::import_value(&value);
+    }
+    if postman::is_postman(&value) {
+        return Ok(vec![postman::import_value(&value)?]);
+    }
+    if openapi::is_openapi(&value) {
+        return Ok(vec![openapi::import_value(&value)?]);
+    }
+    if value.get("endpoints").is_some() {
+        // Our own export: give it a new identity so it never replaces the original
+        let collection: ApiCollection = serde_json::from_value(value)?;
+        return Ok(vec![collection.editable_copy_named(collection.name.clone())]);
+    }
+    Err(InteropError::Format("expected an OpenAPI, Postman, Insomnia, WSDL or REST API TUI export".to_string()))
+}
+
+/// How long a download for import may take
+const FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Whether an import source is a URL rather than a file path
+pub fn is_url(source: &str) -> bool {
+    let source = source.trim().to_ascii_lowercase();
+    source.starts_with("http://") || source.starts_with("https://")
+}
+
+/// `Name: value` → (name, value); an empty line means no header
+pub fn parse_header(line: &str) -> Result<Option<(String, String)>> {
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    match line.split_once(':') {
+        Some((name, value)) if !name.trim().is_empty() => Ok(Some((name.trim().to_string(), value.trim().to_string()))),
+        _ => Err(InteropError::Fetch(format!("header '{}' should look like 'Name: value'", line.trim()))),
+    }
+}
+
+/// Download a file to import, e.g. a spec published by CI, sending `header`
+/// (such as an Authorization header) along
+pub async fn fetch(url: &str, header: Option<(String, String)>) -> Result<String> {
+    let fetch_error = |e: reqwest::Error| InteropError::Fetch(e.to_string());
+    let client = reqwest::Client::builder().timeout(FETCH_TIMEOUT).build().map_err(fetch_error)?;
+    let mut request = client.get(url.trim());
+    if let Some((name, value)) = header {
+        request = request.header(name, value);
+    }
+    let response = request.send().await.map_err(fetch_error)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(InteropError::Fetch(format!("server answered {}", status)));
+    }
+    response.text().await.map_err(fetch_error)
+}
+
+pub fn export(collection: &ApiCollection, format: ExportFormat) -> Result<String> {
+    match format {
+        ExportFormat::Native => Ok(serde_json::to_string_pretty(collection)?),
+        ExportFormat::Insomnia => insomnia::export(collection),
+        ExportFormat::OpenApiJson => openapi::export(collection, false),
+        ExportFormat::OpenApiYaml => openapi::export(collection, true),
+    }
+}
+
+/// Name for an endpoint imported from a command line: `POST /users`
+pub(crate) fn endpoint_name(method: &HttpMethod, url: &str) -> String {
+    let path = url.split("://").nth(1).and_then(|rest| rest.find('/').map(|i| &rest[i..])).unwrap_or("/");
+    format!("{} {}", method, path)
+}
+
+/// Split a shell command line into words, honouring quotes, backslash escapes
+/// and `\`-newline continuations as copied from runbooks, and bash's `$'...'`
+/// strings as copied from browsers
+pub(crate) fn shell_words(line: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err(InteropError::Format("unterminated single quote".to_string())),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => word.push(c),
+                            Some('\n') => {}
+                            Some(c) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => return Err(InteropError::Format("unterminated double quote".to_string())),
+                        },
+                        Some(c) => word.push(c),
+                        None => return Err(InteropError::Format("unterminated double quote".to_string())),
+                    }
+                }
+            }
+            '$' if chars.as_str().starts_with('\'') => {
+                chars.next();
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some('\\') => word.push(ansi_c_escape(&mut chars)?),
+                        Some(c) => word.push(c),
+                        None => return Err(InteropError::Format("unterminated $' quote".to_string())),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') | None => {}
+                Some(c) => {
+                    in_word = true;
+                    word.push(c);
+                }
+            },
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            c => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+/// The character after a `\` in a `$'...'` string
+fn ansi_c_escape(chars: &mut std::str::Chars) -> Result<char> {
+    let digits = match chars.next() {
+        Some('n') => return Ok('\n'),
+        Some('t') => return Ok('\t'),
+        Some('r') => return Ok('\r'),
+        Some('0') => return Ok('\0'),
+        Some('x') => 2,
+        Some('u') => 4,
+        Some(c) => return Ok(c), // `\\`, `\'`, `\"` and anything unknown
+        None => return Err(InteropError::Format("unterminated $' quote".to_string())),
+    };
+    let code: String = chars.take(digits).collect();
+    u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+        .ok_or_else(|| InteropError::Format(format!("bad escape in $' quote: {}", code)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_words() {
+        let words = shell_words("http POST \\\n  :80/a 'x=a b' \"q=\\\"c\\\"\" d\\ e ''").unwrap();
+        assert_eq!(words, vec!["http", "POST", ":80/a", "x=a b", "q=\"c\"", "d e", ""]);
+        assert!(shell_words("http 'oops").is_err());
+        let words = shell_words(r#"curl --data-raw $'{"a":"it\'s\n\u00e9"}' $'x'y"#).unwrap();
+        assert_eq!(words, vec!["curl", "--data-raw", "{\"a\":\"it's\n\u{e9}\"}", "xy"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_sends_header() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            for reply in ["200 OK", "401 Unauthorized"] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+                let body = if request.contains("authorization: bearer ci") { "{\"openapi\": \"3.0.0\"}" } else { "" };
+                let response = format!("HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", reply, body.len(), body);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        let url = format!("http://127.0.0.1:{}/spec.json", port);
+        assert!(is_url(&url) && !is_url("~/spec.json"));
+        let header = parse_header("Authorization: Bearer ci").unwrap();
+        assert_eq!(fetch(&url, header).await.unwrap(), "{\"openapi\": \"3.0.0\"}");
+        let error = fetch(&url, None).await.unwrap_err();
+        assert_eq!(error.to_string(), "Download failed: server answered 401 Unauthorized");
+        assert!(parse_header("no colon").is_err());
+        assert_eq!(parse_header(" ").unwrap(), None);
+        server.await.unwrap();
+    }
+}
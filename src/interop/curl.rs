@@ -0,0 +1,367 @@
+// cURL command lines
+// Turns a pasted `curl ...` command, e.g. from a browser's "Copy as cURL" or a
+// runbook, into an endpoint: method, URL, headers, data and auth flags.
+// Requests that need local files (`-d @file`, `-F`, `-T`) can't be stored in
+// an endpoint and are rejected; output and transfer flags are ignored.
+// Going the other way, `export` writes a prepared request out as a command.
+
+use super::{endpoint_name, shell_words, InteropError, Result};
+use crate::http::RequestDetails;
+use crate::models::{ApiEndpoint, AuthConfig, HttpMethod};
+
+/// Does this line look like a curl invocation?
+pub fn is_curl(line: &str) -> bool {
+    matches!(line.split_whitespace().next(), Some("curl" | "curl.exe"))
+}
+
+/// A pasted command that continues on the next line: it ends in a `\` or
+/// has a quote still open, so Enter should add a newline rather than import
+pub fn is_incomplete(command: &str) -> bool {
+    command.trim_end_matches(' ').ends_with('\\') || shell_words(command).is_err()
+}
+
+/// Short options that take a value, which may be attached (`-XPOST`)
+const SHORT_WITH_VALUE: &str = "AbcCdeEFHKmorTuUwxXyYz";
+
+/// Long options that take a value; any other long option is a switch
+const LONG_WITH_VALUE: [&str; 38] = [
+    "--request", "--header", "--data", "--data-raw", "--data-binary", "--data-ascii", "--data-urlencode",
+    "--json", "--user", "--oauth2-bearer", "--max-time", "--connect-timeout", "--user-agent", "--referer",
+    "--cookie", "--cookie-jar", "--form", "--form-string", "--url", "--upload-file", "--output", "--write-out",
+    "--proxy", "--proxy-user", "--cert", "--key", "--cacert", "--capath", "--resolve", "--connect-to",
+    "--retry", "--retry-delay", "--max-redirs", "--limit-rate", "--range", "--config", "--aws-sigv4",
+    "--unix-socket",
+];
+
+/// How the `-d` family sent the data
+enum Data {
+    Form(Vec<String>), // `-d`, joined with `&` like curl does
+    Json(String), // `--json`, concatenated
+}
+
+pub fn parse(command: &str) -> Result<ApiEndpoint> {
+    let words = shell_words(command)?;
+    let mut words = words.into_iter();
+    if !words.next().as_deref().is_some_and(is_curl) {
+        return Err(InteropError::Format("expected a `curl` command".to_string()));
+    }
+
+    let mut method = None;
+    let mut url = None;
+    let mut headers: Vec<(String, String)> = Vec::new();
+    let mut data: Option<Data> = None;
+    let mut get = false;
+    let mut head = false;
+    let mut auth = None;
+    let mut timeout = None;
+    while let Some(word) = words.next() {
+        // `-H 'A: b'`, `-H'A: b'`, `--header='A: b'` or `-sSL`
+        let (flag, mut value) = if let Some(long) = word.strip_prefix("--") {
+            match long.split_once('=') {
+                Some((name, value)) if LONG_WITH_VALUE.contains(&format!("--{}", name).as_str()) => {
+                    (format!("--{}", name), Some(value.to_string()))
+                }
+                _ => (word.clone(), None),
+            }
+        } else if word.len() > 2 && word.starts_with('-') {
+            let mut letters = word[1..].chars();
+            let first = letters.next().unwrap_or_default();
+            if SHORT_WITH_VALUE.contains(first) {
+                (format!("-{}", first), Some(letters.as_str().to_string()))
+            } else {
+                // A bundle of switches: only the ones that change the request matter
+                for letter in word[1..].chars() {
+                    match letter {
+                        'G' => get = true,
+                        'I' => head = true,
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+        } else if word.starts_with('-') && word != "-" {
+            (word.clone(), None)
+        } else {
+            if url.is_none() {
+                url = Some(word);
+            }
+            continue;
+        };
+        let takes_value = (flag.len() == 2 && SHORT_WITH_VALUE.contains(&flag[1..]))
+            || LONG_WITH_VALUE.contains(&flag.as_str());
+        if takes_value && value.is_none() {
+            value = Some(words.next().ok_or_else(|| InteropError::Format(format!("{} needs a value", flag)))?);
+        }
+        let value = value.unwrap_or_default();
+        match flag.as_str() {
+            "-X" | "--request" => method = Some(HttpMethod::from(value)),
+            "--url" => url = Some(value),
+            "-H" | "--header" => match value.split_once(':') {
+                // `Name:` removes a header curl would send, so there is nothing to keep
+                Some((_, v)) if v.trim().is_empty() => {}
+                Some((name, v)) => headers.push((name.trim().to_string(), v.trim().to_string())),
+                // `Name;` sends the header with no value
+                None => match value.strip_suffix(';') {
+                    Some(name) => headers.push((name.trim().to_string(), String::new())),
+                    None => return Err(InteropError::Format(format!("header '{}' should look like 'Name: value'", value))),
+                },
+            },
+            "-d" | "--data" | "--data-ascii" | "--data-binary" | "--data-raw" | "--data-urlencode" => {
+                if value.starts_with('@') && flag != "--data-raw" {
+                    return Err(InteropError::Format(format!("data from files is not supported: {}", value)));
+                }
+                let value = if flag == "--data-urlencode" { urlencode_data(&value) } else { value };
+                match &mut data {
+                    Some(Data::Form(parts)) => parts.push(value),
+                    _ => data = Some(Data::Form(vec![value])),
+                }
+            }
+            "--json" => {
+                if value.starts_with('@') {
+                    return Err(InteropError::Format(format!("data from files is not supported: {}", value)));
+                }
+                match &mut data {
+                    Some(Data::Json(body)) => body.push_str(&value),
+                    _ => data = Some(Data::Json(value)),
+                }
+            }
+            "-F" | "--form" | "--form-string" => {
+                return Err(InteropError::Format("multipart forms (-F) are not supported".to_string()));
+            }
+            "-T" | "--upload-file" => {
+                return Err(InteropError::Format("file uploads (-T) are not supported".to_string()));
+            }
+            "-u" | "--user" => {
+                let (username, password) = value.split_once(':').unwrap_or((&value, ""));
+                auth = Some(AuthConfig::Basic { username: username.to_string(), password: password.to_string() });
+            }
+            "--oauth2-bearer" => auth = Some(AuthConfig::Bearer { token: value }),
+            "--digest" | "--ntlm" | "--negotiate" | "--aws-sigv4" => {
+                return Err(InteropError::Format(format!("{} authentication is not supported", flag.trim_start_matches('-'))));
+            }
+            "-m" | "--max-time" => timeout = value.parse::<f64>().ok(),
+            "-A" | "--user-agent" => headers.push(("User-Agent".to_string(), value)),
+            "-e" | "--referer" => headers.push(("Referer".to_string(), value)),
+            // Without `=` the value names a cookie file
+            "-b" | "--cookie" if value.contains('=') => headers.push(("Cookie".to_string(), value)),
+            "-G" | "--get" => get = true,
+            "-I" | "--head" => head = true,
+            _ => {}
+        }
+    }
+
+    let url = url.ok_or_else(|| InteropError::Format("missing URL".to_string()))?;
+    let mut url = if url.contains("://") || url.starts_with("{{") { url } else { format!("http://{}", url) };
+    let has_header = |headers: &[(String, String)], name: &str| headers.iter().any(|(k, _)| k.eq_ignore_ascii_case(name));
+    let mut body = None;
+    match data {
+        // `-G` moves the data into the query string
+        Some(Data::Form(parts)) if get => {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&parts.join("&"));
+        }
+        Some(Data::Form(parts)) => {
+            if !has_header(&headers, "content-type") {
+                headers.push(("Content-Type".to_string(), "application/x-www-form-urlencoded".to_string()));
+            }
+            body = Some(parts.join("&"));
+        }
+        Some(Data::Json(json)) => {
+            for name in ["Content-Type", "Accept"] {
+                if !has_header(&headers, name) {
+                    headers.push((name.to_string(), "application/json".to_string()));
+                }
+            }
+            body = Some(json);
+        }
+        None => {}
+    }
+
+    // Like curl: -I is HEAD, data means POST, and -X overrides both
+    let method = method.unwrap_or(if head {
+        HttpMethod::HEAD
+    } else if body.is_some() {
+        HttpMethod::POST
+    } else {
+        HttpMethod::GET
+    });
+    let mut endpoint = ApiEndpoint::new(endpoint_name(&method, &url), method, url);
+    for (name, value) in headers {
+        // A bearer token is auth, so it follows the environment like other tokens
+        match value.strip_prefix("Bearer ") {
+            Some(token) if name.eq_ignore_ascii_case("authorization") && auth.is_none() => {
+                auth = Some(AuthConfig::Bearer { token: token.trim().to_string() });
+            }
+            _ => { endpoint.headers.insert(name, value); }
+        }
+    }
+    endpoint.body_template = body;
+    endpoint.auth = auth;
+    endpoint.timeout_secs = timeout.map(|t| t.ceil() as u64);
+    endpoint.description = Some(format!("Imported from: {}", command.replace("\\\n", "").trim()));
+    Ok(endpoint)
+}
+
+/// `--data-urlencode`: `name=value` encodes the value, anything else all of it
+fn urlencode_data(value: &str) -> String {
+    match value.split_once('=') {
+        Some((name, content)) if !name.is_empty() => format!("{}={}", name, urlencoding::encode(content)),
+        Some((_, content)) => urlencoding::encode(content).into_owned(),
+        None => urlencoding::encode(value).into_owned(),
+    }
+}
+
+/// A prepared request as a curl command, one option per line. `-X` is left
+/// out where curl would pick the method itself, and the body goes through
+/// `--data-raw` so a leading `@` isn't read as a file name
+pub fn export(request: &RequestDetails) -> String {
+    let has_body = request.body.as_ref().is_some_and(|body| !body.is_empty());
+    let mut parts = vec![format!("curl {}", quote(request.url.as_bytes()))];
+    match request.method.as_str() {
+        "GET" if !has_body => {}
+        "POST" if has_body => {}
+        "HEAD" if !has_body => parts.push("--head".to_string()),
+        method => parts.push(format!("-X {}", method)),
+    }
+    for header in request.sent_headers.iter() {
+        // `Name;` is curl's way of sending a header with no value
+        let header = if header.value.is_empty() { format!("{};", header.name) } else { format!("{}: {}", header.name, header.value) };
+        parts.push(format!("-H {}", quote(header.as_bytes())));
+    }
+    if let Some(body) = request.body.as_ref().filter(|body| !body.is_empty()) {
+        let flag = if std::str::from_utf8(body).is_ok() { "--data-raw" } else { "--data-binary" };
+        parts.push(format!("{} {}", flag, quote(body)));
+    }
+    parts.join(" \\\n  ")
+}
+
+/// Single-quote a word for the shell, or use bash's `$'...'` for bytes a
+/// terminal would mangle: control characters and anything that isn't UTF-8
+fn quote(word: &[u8]) -> String {
+    let plain = std::str::from_utf8(word).ok()
+        .filter(|text| !text.chars().any(|c| c.is_control() && c != '\n' && c != '\t'));
+    if let Some(text) = plain {
+        return format!("'{}'", text.replace('\'', "'\\''"));
+    }
+    let mut quoted = String::from("$'");
+    for &byte in word {
+        match byte {
+            b'\\' => quoted.push_str("\\\\"),
+            b'\'' => quoted.push_str("\\'"),
+            b'\n' => quoted.push_str("\\n"),
+            b'\r' => quoted.push_str("\\r"),
+            b'\t' => quoted.push_str("\\t"),
+            b' '..=b'~' => quoted.push(byte as char),
+            _ => quoted.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{HeaderSource, MergedHeaders};
+
+    #[test]
+    fn test_parse_browser_copy() {
+        let command = r#"curl 'https://api.example.com/v1/users?page=2' \
+  -H 'accept: application/json' \
+  -H 'authorization: Bearer abc.def' \
+  -H 'content-type: application/json' \
+  --data-raw $'{"name":"O\'Brien"}' \
+  --compressed"#;
+        let endpoint = parse(command).unwrap();
+        assert_eq!(endpoint.method, HttpMethod::POST);
+        assert_eq!(endpoint.url, "https://api.example.com/v1/users?page=2");
+        assert_eq!(endpoint.name, "POST /v1/users?page=2");
+        assert_eq!(endpoint.auth, Some(AuthConfig::Bearer { token: "abc.def".to_string() }));
+        assert_eq!(endpoint.headers.len(), 2);
+        assert_eq!(endpoint.headers.get("content-type").map(String::as_str), Some("application/json"));
+        assert_eq!(endpoint.body_template.as_deref(), Some(r#"{"name":"O'Brien"}"#));
+        assert!(!endpoint.description.unwrap().contains("\\\n"), "line continuations are dropped");
+    }
+
+    #[test]
+    fn test_flags_and_data_forms() {
+        let endpoint = parse("curl -sSL -XPUT -u admin:s3cret -m 2.5 -d a=1 --data-urlencode 'q=a b' example.com/items").unwrap();
+        assert_eq!(endpoint.method, HttpMethod::PUT);
+        assert_eq!(endpoint.url, "http://example.com/items");
+        assert_eq!(endpoint.body_template.as_deref(), Some("a=1&q=a%20b"));
+        assert_eq!(endpoint.headers.get("Content-Type").map(String::as_str), Some("application/x-www-form-urlencoded"));
+        assert_eq!(endpoint.auth, Some(AuthConfig::Basic { username: "admin".to_string(), password: "s3cret".to_string() }));
+        assert_eq!(endpoint.timeout_secs, Some(3));
+
+        let endpoint = parse("curl -G --data 'q=rust' --url https://example.com/search").unwrap();
+        assert_eq!(endpoint.method, HttpMethod::GET);
+        assert_eq!(endpoint.url, "https://example.com/search?q=rust");
+        assert_eq!(endpoint.body_template, None);
+
+        let endpoint = parse(r#"curl --json '{"a":1}' -I {{host}}/things"#).unwrap();
+        assert_eq!(endpoint.method, HttpMethod::HEAD);
+        assert_eq!(endpoint.url, "{{host}}/things");
+        assert_eq!(endpoint.headers.get("Accept").map(String::as_str), Some("application/json"));
+
+        let endpoint = parse("curl -X PURGE -A probe/1.0 -b session=1 https://cdn.example.com/").unwrap();
+        assert_eq!(endpoint.method, HttpMethod::Custom("PURGE".to_string()));
+        assert_eq!(endpoint.headers.get("User-Agent").map(String::as_str), Some("probe/1.0"));
+        assert_eq!(endpoint.headers.get("Cookie").map(String::as_str), Some("session=1"));
+    }
+
+    #[test]
+    fn test_rejects_what_an_endpoint_cannot_hold() {
+        assert!(parse("curl -d @body.json https://example.com").is_err());
+        assert!(parse("curl -F file=@a.png https://example.com").is_err());
+        assert!(parse("curl --digest -u a:b https://example.com").is_err());
+        assert!(parse("curl -H").is_err());
+        assert!(parse("curl -s").is_err());
+        assert!(parse("http :8080").is_err());
+    }
+
+    #[test]
+    fn test_export_round_trips() {
+        let mut sent_headers = MergedHeaders::default();
+        sent_headers.add("Authorization", "Bearer abc", HeaderSource::Auth);
+        sent_headers.add("Content-Type", "application/json", HeaderSource::Endpoint);
+        sent_headers.add("X-Empty", "", HeaderSource::Endpoint);
+        let mut request = RequestDetails {
+            method: "PATCH".to_string(),
+            url: "https://api.example.com/users/1?x=a&y=b".to_string(),
+            headers: sent_headers.to_map(),
+            sent_headers,
+            body: Some(br#"{"name":"O'Brien"}"#.to_vec()),
+            body_size: 18,
+        };
+        let command = export(&request);
+        assert_eq!(command, r#"curl 'https://api.example.com/users/1?x=a&y=b' \
+  -X PATCH \
+  -H 'Authorization: Bearer abc' \
+  -H 'Content-Type: application/json' \
+  -H 'X-Empty;' \
+  --data-raw '{"name":"O'\''Brien"}'"#);
+        let endpoint = parse(&command).unwrap();
+        assert_eq!(endpoint.method, HttpMethod::PATCH);
+        assert_eq!(endpoint.url, request.url);
+        assert_eq!(endpoint.auth, Some(AuthConfig::Bearer { token: "abc".to_string() }));
+        assert_eq!(endpoint.headers.get("X-Empty").map(String::as_str), Some(""));
+        assert_eq!(endpoint.body_template.as_deref(), Some(r#"{"name":"O'Brien"}"#));
+
+        request.method = "POST".to_string();
+        request.body = Some(vec![0x00, b'a', 0xff, b'\n']);
+        let command = export(&request);
+        assert!(!command.contains("-X"), "curl posts data by itself");
+        assert!(command.ends_with(r"--data-binary $'\x00a\xff\n'"));
+
+        request.method = "HEAD".to_string();
+        request.body = None;
+        assert!(export(&request).contains("--head"));
+    }
+
+    #[test]
+    fn test_incomplete_commands_continue() {
+        assert!(is_incomplete("curl https://example.com \\"));
+        assert!(is_incomplete("curl -d '{\"a\":"));
+        assert!(!is_incomplete("curl https://example.com"));
+    }
+}
@@ -0,0 +1,210 @@
+// HTTPie command lines
+// Turns `http POST :8080/users name=bob X-Api-Key:abc` into an endpoint.
+// Supported request items: `Header:Value`, `param==value`, `field=value`,
+// `field:=json`. File items (`@file`, `=@`, `:=@`) can't be stored in an
+// endpoint and are rejected.
+
+use super::{endpoint_name, shell_words, InteropError, Result};
+use crate::models::{ApiEndpoint, AuthConfig, HttpMethod};
+use serde_json::{Map, Value};
+
+/// Does this line look like an HTTPie invocation?
+pub fn is_httpie(line: &str) -> bool {
+    matches!(line.split_whitespace().next(), Some("http" | "https"))
+}
+
+enum Item {
+    Header(String, String),
+    Query(String, String),
+    Field(String, Value),
+}
+
+/// Split a request item on its earliest separator; at equal positions the longer one wins
+fn parse_item(arg: &str) -> Option<Item> {
+    const SEPARATORS: [&str; 4] = [":=", "==", "=", ":"];
+    let (pos, sep) = SEPARATORS.iter()
+        .filter_map(|sep| arg.find(sep).map(|pos| (pos, *sep)))
+        .min_by_key(|(pos, sep)| (*pos, std::cmp::Reverse(sep.len())))?;
+    let key = arg[..pos].to_string();
+    let value = &arg[pos + sep.len()..];
+    Some(match sep {
+        ":=" => Item::Field(key, serde_json::from_str(value).ok()?),
+        "==" => Item::Query(key, value.to_string()),
+        "=" => Item::Field(key, Value::String(value.to_string())),
+        _ => Item::Header(key, value.to_string()),
+    })
+}
+
+/// Like HTTPie, any all-uppercase word before the URL is a method (`PROPFIND`, `PURGE`, ...)
+fn parse_method(arg: &str) -> Option<HttpMethod> {
+    let is_method = !arg.is_empty() && arg.chars().all(|c| c.is_ascii_uppercase() || c == '-' || c == '_');
+    is_method.then(|| HttpMethod::from(arg.to_string()))
+}
+
+/// `:8080/users` → `http://localhost:8080/users`, `example.com` → `http://example.com`
+fn expand_url(url: &str, scheme: &str) -> String {
+    if url.contains("://") || url.starts_with("{{") {
+        return url.to_string();
+    }
+    match url.strip_prefix(':') {
+        Some(rest) if rest.starts_with('/') || rest.is_empty() => format!("{}://localhost{}", scheme, rest),
+        Some(rest) => format!("{}://localhost:{}", scheme, rest),
+        None => format!("{}://{}", scheme, url),
+    }
+}
+
+pub fn parse(command: &str) -> Result<ApiEndpoint> {
+    let args = shell_words(command)?;
+    let mut args = args.iter().map(String::as_str);
+    let scheme = match args.next() {
+        Some("http") => "http",
+        Some("https") => "https",
+        _ => return Err(InteropError::Format("expected an `http` or `https` command".to_string())),
+    };
+
+    let mut form = false;
+    let mut auth = None;
+    let mut auth_type = "basic".to_string();
+    let mut timeout = None;
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') || arg == "-" {
+            positional.push(arg);
+            continue;
+        }
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg, None),
+        };
+        let mut value = || inline.clone().or_else(|| args.next().map(str::to_string));
+        match flag {
+            "-f" | "--form" => form = true,
+            "-j" | "--json" => form = false,
+            "-a" | "--auth" => auth = value(),
+            "-A" | "--auth-type" => auth_type = value().unwrap_or_default(),
+            "--timeout" => timeout = value().and_then(|t| t.parse::<f64>().ok()),
+            // Flags that take a value we don't use
+            "--verify" | "--cert" | "--cert-key" | "--proxy" | "--session" | "--session-read-only"
+            | "-o" | "--output" | "--pretty" | "-s" | "--style" | "-p" | "--print" => {
+                value();
+            }
+            _ => {}
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let first = positional.next()
+        .ok_or_else(|| InteropError::Format("missing URL".to_string()))?;
+    let (explicit_method, url) = match parse_method(first) {
+        Some(method) => {
+            let url = positional.next().ok_or_else(|| InteropError::Format("missing URL".to_string()))?;
+            (Some(method), url)
+        }
+        None => (None, first),
+    };
+
+    let mut headers = Vec::new();
+    let mut query = Vec::new();
+    let mut fields = Map::new();
+    for arg in positional {
+        if arg.contains('@') && (arg.starts_with('@') || arg.contains("=@") || arg.contains(":=@")) {
+            return Err(InteropError::Format(format!("file items are not supported: {}", arg)));
+        }
+        match parse_item(arg) {
+            Some(Item::Header(key, value)) => headers.push((key, value)),
+            Some(Item::Query(key, value)) => query.push((key, value)),
+            Some(Item::Field(key, value)) => { fields.insert(key, value); }
+            None => return Err(InteropError::Format(format!("can't parse request item: {}", arg))),
+        }
+    }
+
+    let mut url = expand_url(url, scheme);
+    if !query.is_empty() {
+        let params: Vec<String> = query.iter()
+            .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+            .collect();
+        url.push(if url.contains('?') { '&' } else { '?' });
+        url.push_str(&params.join("&"));
+    }
+
+    // HTTPie switches to POST as soon as there is data
+    let method = explicit_method.unwrap_or(if fields.is_empty() { HttpMethod::GET } else { HttpMethod::POST });
+    let mut endpoint = ApiEndpoint::new(endpoint_name(&method, &url), method, url.clone());
+
+    for (key, value) in headers {
+        endpoint.headers.insert(key, value);
+    }
+    if !fields.is_empty() {
+        let has_content_type = endpoint.headers.keys().any(|k| k.eq_ignore_ascii_case("content-type"));
+        if form {
+            let body: Vec<String> = fields.iter()
+                .map(|(k, v)| {
+                    let v = v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string());
+                    format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v))
+                })
+                .collect();
+            endpoint.body_template = Some(body.join("&"));
+            if !has_content_type {
+                endpoint.headers.insert("Content-Type".to_string(), "application/x-www-form-urlencoded".to_string());
+            }
+        } else {
+            endpoint.body_template = Some(serde_json::to_string_pretty(&Value::Object(fields))?);
+            if !has_content_type {
+                endpoint.headers.insert("Content-Type".to_string(), "application/json".to_string());
+            }
+        }
+    }
+
+    endpoint.auth = auth.map(|credentials| match auth_type.as_str() {
+        "bearer" => AuthConfig::Bearer { token: credentials },
+        _ => {
+            let (username, password) = credentials.split_once(':').unwrap_or((&credentials, ""));
+            AuthConfig::Basic { username: username.to_string(), password: password.to_string() }
+        }
+    });
+    endpoint.timeout_secs = timeout.map(|t| t.ceil() as u64);
+    endpoint.description = Some(format!("Imported from: {}", command.trim()));
+    Ok(endpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_runbook_example() {
+        let endpoint = parse("http POST :8080/users name=bob age:=42 X-Api-Key:abc verbose==1").unwrap();
+        assert_eq!(endpoint.method, HttpMethod::POST);
+        assert_eq!(endpoint.url, "http://localhost:8080/users?verbose=1");
+        assert_eq!(endpoint.name, "POST /users?verbose=1");
+        assert_eq!(endpoint.headers.get("X-Api-Key").map(String::as_str), Some("abc"));
+        let body: Value = serde_json::from_str(endpoint.body_template.as_deref().unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({ "name": "bob", "age": 42 }));
+    }
+
+    #[test]
+    fn test_implicit_method_and_flags() {
+        let endpoint = parse("https -A bearer -a tok3n example.com/items").unwrap();
+        assert_eq!(endpoint.method, HttpMethod::GET);
+        assert_eq!(endpoint.url, "https://example.com/items");
+        assert_eq!(endpoint.auth, Some(AuthConfig::Bearer { token: "tok3n".to_string() }));
+
+        let endpoint = parse("http --form --timeout=2.5 :3000/login 'user=a b'").unwrap();
+        assert_eq!(endpoint.method, HttpMethod::POST);
+        assert_eq!(endpoint.body_template.as_deref(), Some("user=a%20b"));
+        assert_eq!(endpoint.timeout_secs, Some(3));
+    }
+
+    #[test]
+    fn test_custom_method() {
+        let endpoint = parse("http PROPFIND :8080/dav/ Depth:1").unwrap();
+        assert_eq!(endpoint.method, HttpMethod::Custom("PROPFIND".to_string()));
+        assert_eq!(endpoint.name, "PROPFIND /dav/");
+    }
+
+    #[test]
+    fn test_rejects_file_items() {
+        assert!(parse("http POST :8080/upload @data.json").is_err());
+        assert!(parse("curl https://example.com").is_err());
+    }
+}
@@ -0,0 +1,306 @@
+// Insomnia v4 export format
+// An export is a flat list of resources linked by `parentId`: workspaces,
+// request groups (folders) and requests, plus environments and cookie jars we
+// ignore. Workspaces become collections; a request's folder names become tags.
+
+use super::{InteropError, Result};
+use crate::models::{ApiCollection, ApiEndpoint, ApiKeyLocation, AuthConfig, HttpMethod};
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+const NO_WORKSPACE: &str = "Insomnia import";
+
+pub fn is_insomnia(value: &Value) -> bool {
+    value.get("_type").and_then(Value::as_str) == Some("export")
+        && value.get("resources").is_some_and(Value::is_array)
+}
+
+pub fn import(contents: &str) -> Result<Vec<ApiCollection>> {
+    import_value(&serde_json::from_str(contents)?)
+}
+
+pub fn import_value(value: &Value) -> Result<Vec<ApiCollection>> {
+    if !is_insomnia(value) {
+        return Err(InteropError::Format("not an Insomnia export".to_string()));
+    }
+    let resources = value["resources"].as_array().map(Vec::as_slice).unwrap_or_default();
+    let by_id: HashMap<&str, &Value> = resources.iter()
+        .filter_map(|r| Some((r.get("_id")?.as_str()?, r)))
+        .collect();
+
+    let mut collections: Vec<(String, ApiCollection)> = resources.iter()
+        .filter(|r| resource_type(r) == "workspace")
+        .map(|r| (str_field(r, "_id"), ApiCollection::new(str_field(r, "name"))))
+        .collect();
+
+    for request in resources.iter().filter(|r| resource_type(r) == "request") {
+        // Walk up through folders to the workspace
+        let mut folders = Vec::new();
+        let mut parent = request.get("parentId").and_then(Value::as_str);
+        let mut workspace_id = None;
+        while let Some(id) = parent {
+            match by_id.get(id) {
+                Some(resource) if resource_type(resource) == "request_group" => {
+                    folders.push(str_field(resource, "name").to_lowercase());
+                    parent = resource.get("parentId").and_then(Value::as_str);
+                }
+                Some(resource) if resource_type(resource) == "workspace" => {
+                    workspace_id = Some(id.to_string());
+                    break;
+                }
+                _ => break,
+            }
+        }
+        let workspace_id = workspace_id.unwrap_or_else(|| NO_WORKSPACE.to_string());
+        if !collections.iter().any(|(id, _)| *id == workspace_id) {
+            collections.push((workspace_id.clone(), ApiCollection::new(NO_WORKSPACE.to_string())));
+        }
+
+        let mut endpoint = request_to_endpoint(request)?;
+        folders.reverse();
+        endpoint.tags = folders;
+        if let Some((_, collection)) = collections.iter_mut().find(|(id, _)| *id == workspace_id) {
+            collection.add_endpoint(endpoint);
+        }
+    }
+
+    Ok(collections.into_iter().map(|(_, c)| c).collect())
+}
+
+fn request_to_endpoint(request: &Value) -> Result<ApiEndpoint> {
+    let method = match str_field(request, "method") {
+        name if name.is_empty() => HttpMethod::GET,
+        name => HttpMethod::from(name),
+    };
+    if !method.is_valid() {
+        return Err(InteropError::Format(format!("unsupported HTTP method {}", method)));
+    }
+    let mut endpoint = ApiEndpoint::new(
+        str_field(request, "name"),
+        method,
+        from_insomnia_template(&str_field(request, "url")),
+    );
+
+    for header in request.get("headers").and_then(Value::as_array).into_iter().flatten() {
+        if header.get("disabled").and_then(Value::as_bool) == Some(true) {
+            continue;
+        }
+        let name = str_field(header, "name");
+        if !name.is_empty() {
+            endpoint.headers.insert(name, from_insomnia_template(&str_field(header, "value")));
+        }
+    }
+
+    if let Some(body) = request.get("body") {
+        let text = str_field(body, "text");
+        if !text.is_empty() {
+            endpoint.body_template = Some(from_insomnia_template(&text));
+            let mime = str_field(body, "mimeType");
+            if !mime.is_empty() && !endpoint.headers.keys().any(|k| k.eq_ignore_ascii_case("content-type")) {
+                endpoint.headers.insert("Content-Type".to_string(), mime);
+            }
+        }
+    }
+
+    endpoint.auth = request.get("authentication").and_then(|auth| {
+        let field = |name| from_insomnia_template(&str_field(auth, name));
+        match auth.get("type").and_then(Value::as_str)? {
+            "bearer" => Some(AuthConfig::Bearer { token: field("token") }),
+            "basic" => Some(AuthConfig::Basic { username: field("username"), password: field("password") }),
+            "apikey" => Some(AuthConfig::ApiKey {
+                name: field("key"),
+                value: field("value"),
+                location: if str_field(auth, "addTo") == "queryParams" {
+                    ApiKeyLocation::QueryParam
+                } else {
+                    ApiKeyLocation::Header
+                },
+            }),
+            _ => None,
+        }
+    });
+
+    let description = str_field(request, "description");
+    if !description.is_empty() {
+        endpoint.description = Some(description);
+    }
+    Ok(endpoint)
+}
+
+pub fn export(collection: &ApiCollection) -> Result<String> {
+    let workspace_id = format!("wrk_{}", collection.id.simple());
+    let mut resources = vec![json!({
+        "_id": workspace_id,
+        "_type": "workspace",
+        "parentId": null,
+        "name": collection.name,
+        "description": "",
+        "scope": "collection",
+    })];
+
+    for endpoint in &collection.endpoints {
+        let mut request = Map::new();
+        request.insert("_id".into(), json!(format!("req_{}", endpoint.id.simple())));
+        request.insert("_type".into(), json!("request"));
+        request.insert("parentId".into(), json!(workspace_id));
+        request.insert("name".into(), json!(endpoint.name));
+        request.insert("method".into(), json!(endpoint.method.to_string()));
+        request.insert("url".into(), json!(to_insomnia_template(&endpoint.url)));
+        request.insert("description".into(), json!(endpoint.description.clone().unwrap_or_default()));
+
+        let mut headers: Vec<(&String, &String)> = endpoint.headers.iter().collect();
+        headers.sort();
+        request.insert("headers".into(), Value::Array(headers.iter()
+            .map(|(name, value)| json!({ "name": name, "value": to_insomnia_template(value) }))
+            .collect()));
+
+        let body = match &endpoint.body_template {
+            Some(text) => {
+                let mime = endpoint.headers.iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case("content-type"))
+                    .map(|(_, v)| v.clone())
+                    .unwrap_or_else(|| "application/json".to_string());
+                json!({ "mimeType": mime, "text": to_insomnia_template(text) })
+            }
+            None => json!({}),
+        };
+        request.insert("body".into(), body);
+
+        let authentication = match &endpoint.auth {
+            Some(AuthConfig::Bearer { token }) => json!({ "type": "bearer", "token": to_insomnia_template(token) }),
+            Some(AuthConfig::Basic { username, password }) => json!({
+                "type": "basic",
+                "username": to_insomnia_template(username),
+                "password": to_insomnia_template(password),
+            }),
+            Some(AuthConfig::ApiKey { name, value, location }) => json!({
+                "type": "apikey",
+                "key": to_insomnia_template(name),
+                "value": to_insomnia_template(value),
+                "addTo": if *location == ApiKeyLocation::QueryParam { "queryParams" } else { "header" },
+            }),
+            None => json!({}),
+        };
+        request.insert("authentication".into(), authentication);
+        resources.push(Value::Object(request));
+    }
+
+    Ok(serde_json::to_string_pretty(&json!({
+        "_type": "export",
+        "__export_format": 4,
+        "__export_date": Utc::now().to_rfc3339(),
+        "__export_source": concat!("rest-api-tui:v", env!("CARGO_PKG_VERSION")),
+        "resources": resources,
+    }))?)
+}
+
+fn resource_type(resource: &Value) -> &str {
+    resource.get("_type").and_then(Value::as_str).unwrap_or_default()
+}
+
+fn str_field(value: &Value, name: &str) -> String {
+    value.get(name).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// Rewrite every `{{ ... }}` tag in `text` with `f`, which gets the trimmed inner text
+fn map_tags(text: &str, f: impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start..].find("}}") else { break };
+        out.push_str(&rest[..start]);
+        out.push_str(&f(rest[start + 2..start + len].trim()));
+        rest = &rest[start + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `{{ _.base_url }}` → `{{base_url}}`
+fn from_insomnia_template(text: &str) -> String {
+    map_tags(text, |inner| format!("{{{{{}}}}}", inner.strip_prefix("_.").unwrap_or(inner)))
+}
+
+/// `{{base_url}}` → `{{ _.base_url }}`; faker variables like `{{$uuid}}` stay as they are
+fn to_insomnia_template(text: &str) -> String {
+    map_tags(text, |inner| {
+        if inner.starts_with('$') {
+            format!("{{{{{}}}}}", inner)
+        } else {
+            format!("{{{{ _.{} }}}}", inner)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"{
+        "_type": "export",
+        "__export_format": 4,
+        "resources": [
+            { "_id": "wrk_1", "_type": "workspace", "parentId": null, "name": "Users API" },
+            { "_id": "fld_1", "_type": "request_group", "parentId": "wrk_1", "name": "Auth" },
+            { "_id": "env_1", "_type": "environment", "parentId": "wrk_1", "data": {} },
+            {
+                "_id": "req_1", "_type": "request", "parentId": "fld_1",
+                "name": "Login", "method": "POST", "url": "{{ _.base_url }}/login",
+                "body": { "mimeType": "application/json", "text": "{\"user\": \"{{ _.user }}\"}" },
+                "headers": [
+                    { "name": "X-Trace", "value": "1" },
+                    { "name": "X-Off", "value": "1", "disabled": true }
+                ],
+                "authentication": { "type": "bearer", "token": "{{ _.token }}" }
+            },
+            { "_id": "req_2", "_type": "request", "parentId": "wrk_1", "name": "List", "method": "GET", "url": "https://api/users" }
+        ]
+    }"#;
+
+    #[test]
+    fn test_import() {
+        let collections = import(EXPORT).unwrap();
+        assert_eq!(collections.len(), 1);
+        let collection = &collections[0];
+        assert_eq!(collection.name, "Users API");
+        assert_eq!(collection.endpoints.len(), 2);
+
+        let login = &collection.endpoints[0];
+        assert_eq!(login.method, HttpMethod::POST);
+        assert_eq!(login.url, "{{base_url}}/login");
+        assert_eq!(login.body_template.as_deref(), Some("{\"user\": \"{{user}}\"}"));
+        assert_eq!(login.headers.get("Content-Type").map(String::as_str), Some("application/json"));
+        assert!(!login.headers.contains_key("X-Off"));
+        assert_eq!(login.tags, vec!["auth"]);
+        assert_eq!(login.auth, Some(AuthConfig::Bearer { token: "{{token}}".to_string() }));
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let mut collection = ApiCollection::new("API".to_string());
+        let mut endpoint = ApiEndpoint::new("Create".to_string(), HttpMethod::PUT, "{{host}}/items/{{$uuid}}".to_string());
+        endpoint.body_template = Some("{}".to_string());
+        endpoint.auth = Some(AuthConfig::ApiKey {
+            name: "key".to_string(),
+            value: "{{api_key}}".to_string(),
+            location: ApiKeyLocation::QueryParam,
+        });
+        collection.add_endpoint(endpoint);
+
+        let exported = export(&collection).unwrap();
+        assert!(exported.contains("{{ _.host }}/items/{{$uuid}}"));
+
+        let imported = import(&exported).unwrap();
+        assert_eq!(imported[0].name, "API");
+        let endpoint = &imported[0].endpoints[0];
+        assert_eq!(endpoint.url, collection.endpoints[0].url);
+        assert_eq!(endpoint.auth, collection.endpoints[0].auth);
+        assert_eq!(endpoint.body_template.as_deref(), Some("{}"));
+    }
+
+    #[test]
+    fn test_rejects_other_json() {
+        assert!(matches!(import("{\"foo\": 1}"), Err(InteropError::Format(_))));
+    }
+}
@@ -0,0 +1,504 @@
+// OpenAPI 3 and Swagger 2 specs (JSON), and OpenAPI 3.0 export (JSON or YAML)
+// A spec becomes one collection named after its title, with an endpoint per
+// operation. Path parameters turn into variables (`/users/{id}` becomes
+// `/users/{{id}}`), as do required query and header parameters. A relative or
+// missing server URL is prefixed with `{{base_url}}`. Exports go the other
+// way, so an exported collection imports back with the same URLs.
+
+use super::{InteropError, Result};
+use crate::models::{ApiCollection, ApiEndpoint, ApiKeyLocation, AuthConfig, HttpMethod};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+const METHODS: [&str; 7] = ["get", "post", "put", "patch", "delete", "head", "options"];
+
+pub fn is_openapi(value: &Value) -> bool {
+    value.get("openapi").and_then(Value::as_str).is_some_and(|v| v.starts_with('3'))
+        || value.get("swagger").and_then(Value::as_str) == Some("2.0")
+}
+
+pub fn import(contents: &str) -> Result<ApiCollection> {
+    import_value(&serde_json::from_str(contents)?)
+}
+
+pub fn import_value(spec: &Value) -> Result<ApiCollection> {
+    if !is_openapi(spec) {
+        return Err(InteropError::Format("not an OpenAPI 3 or Swagger 2 spec".to_string()));
+    }
+    let title = spec.pointer("/info/title").and_then(Value::as_str).unwrap_or("OpenAPI import");
+    let mut collection = ApiCollection::new(title.to_string());
+    let base = base_url(spec);
+
+    let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+        return Ok(collection);
+    };
+    for (path, item) in paths {
+        let shared = item.get("parameters").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+        for method in METHODS {
+            if let Some(operation) = item.get(method) {
+                collection.add_endpoint(operation_to_endpoint(spec, &base, path, method, operation, shared));
+            }
+        }
+    }
+    Ok(collection)
+}
+
+/// `servers[0].url` (OpenAPI 3) or `schemes://host/basePath` (Swagger 2).
+/// Server variables take their default, or become our variables without one
+fn base_url(spec: &Value) -> String {
+    let base = match spec.pointer("/servers/0/url").and_then(Value::as_str) {
+        Some(url) => map_braces(url, |name| {
+            let pointer = format!("/servers/0/variables/{}/default", name);
+            match spec.pointer(&pointer).and_then(Value::as_str) {
+                Some(default) if !default.is_empty() => default.to_string(),
+                _ => format!("{{{{{}}}}}", name),
+            }
+        }),
+        None => match spec.get("host").and_then(Value::as_str) {
+            Some(host) => {
+                let scheme = spec.pointer("/schemes/0").and_then(Value::as_str).unwrap_or("https");
+                let base_path = spec.get("basePath").and_then(Value::as_str).unwrap_or_default();
+                format!("{}://{}{}", scheme, host, base_path)
+            }
+            None => String::new(),
+        },
+    };
+    let base = base.trim_end_matches('/');
+    if base.starts_with("http://") || base.starts_with("https://") || base.starts_with("{{") {
+        base.to_string()
+    } else {
+        format!("{{{{base_url}}}}{}", base)
+    }
+}
+
+fn operation_to_endpoint(spec: &Value, base: &str, path: &str, method: &str, operation: &Value, shared: &[Value]) -> ApiEndpoint {
+    let method = HttpMethod::from(method.to_uppercase());
+    let name = text(operation, "summary")
+        .or_else(|| text(operation, "operationId"))
+        .unwrap_or_else(|| format!("{} {}", method, path));
+    let mut url = format!("{}{}", base, path.replace('{', "{{").replace('}', "}}"));
+    let mut endpoint = ApiEndpoint::new(name, method, String::new());
+    endpoint.description = text(operation, "description");
+    endpoint.tags = operation.get("tags").and_then(Value::as_array).into_iter().flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_lowercase)
+        .collect();
+
+    let own = operation.get("parameters").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+    let mut query = Vec::new();
+    for parameter in shared.iter().chain(own).map(|p| resolve(spec, p)) {
+        let Some(name) = parameter.get("name").and_then(Value::as_str) else { continue };
+        let required = parameter.get("required").and_then(Value::as_bool) == Some(true);
+        match parameter.get("in").and_then(Value::as_str) {
+            Some("query") if required => query.push(format!("{}={{{{{}}}}}", name, name)),
+            Some("header") if required => {
+                endpoint.headers.insert(name.to_string(), format!("{{{{{}}}}}", name));
+            }
+            // Swagger 2 bodies are a parameter
+            Some("body") => set_body(&mut endpoint, spec, "application/json", parameter.get("schema")),
+            _ => {}
+        }
+    }
+    if !query.is_empty() {
+        url.push('?');
+        url.push_str(&query.join("&"));
+    }
+    endpoint.url = url;
+
+    // OpenAPI 3 bodies, preferring JSON when several media types are offered
+    if let Some(content) = operation.get("requestBody").map(|b| resolve(spec, b)).and_then(|b| b.get("content")).and_then(Value::as_object) {
+        let media = content.get_key_value("application/json").or_else(|| content.iter().next());
+        if let Some((mime, media)) = media {
+            let example = media.get("example")
+                .or_else(|| media.get("examples").and_then(Value::as_object).and_then(|e| e.values().next()).and_then(|e| e.get("value")));
+            match example {
+                Some(example) => set_example(&mut endpoint, mime, example),
+                None => set_body(&mut endpoint, spec, mime, media.get("schema")),
+            }
+        }
+    }
+    endpoint
+}
+
+/// Body from a schema's example, if it has one
+fn set_body(endpoint: &mut ApiEndpoint, spec: &Value, mime: &str, schema: Option<&Value>) {
+    if let Some(example) = schema.map(|s| resolve(spec, s)).and_then(|s| s.get("example")) {
+        set_example(endpoint, mime, example);
+    }
+}
+
+fn set_example(endpoint: &mut ApiEndpoint, mime: &str, example: &Value) {
+    let body = match example {
+        Value::String(text) => text.clone(),
+        other => serde_json::to_string_pretty(other).unwrap_or_default(),
+    };
+    endpoint.body_template = Some(body);
+    endpoint.headers.insert("Content-Type".to_string(), mime.to_string());
+}
+
+/// Follow a local `{"$ref": "#/components/..."}`; anything else is returned as is
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    value.get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| spec.pointer(pointer))
+        .unwrap_or(value)
+}
+
+fn text(value: &Value, name: &str) -> Option<String> {
+    value.get(name).and_then(Value::as_str).filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+/// Rewrite every `{name}` in `text` with `f`; our `{{name}}` tags are left alone
+fn map_braces(text: &str, f: impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        if rest[start..].starts_with("{{") {
+            let end = rest[start..].find("}}").map(|i| start + i + 2).unwrap_or(rest.len());
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        let Some(len) = rest[start..].find('}') else { break };
+        out.push_str(&rest[..start]);
+        out.push_str(&f(&rest[start + 1..start + len]));
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[derive(Serialize)]
+struct Document {
+    openapi: &'static str,
+    info: Info,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    servers: Vec<Server>,
+    paths: BTreeMap<String, BTreeMap<String, Operation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Components>,
+}
+
+#[derive(Serialize)]
+struct Info {
+    title: String,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct Server {
+    url: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    variables: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Operation {
+    summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    servers: Vec<Server>, // Only when the endpoint's server isn't the first one
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parameters: Vec<Parameter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_body: Option<Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    security: Vec<BTreeMap<String, Vec<String>>>,
+    responses: Value,
+}
+
+#[derive(Serialize)]
+struct Parameter {
+    name: String,
+    #[serde(rename = "in")]
+    location: &'static str,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    required: bool,
+    schema: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<String>,
+}
+
+impl Parameter {
+    /// A query or header parameter: templated values are required, fixed ones an example
+    fn new(name: &str, location: &'static str, value: &str) -> Self {
+        let templated = value.contains("{{");
+        Parameter {
+            name: name.to_string(),
+            location,
+            required: templated || location == "path",
+            schema: json!({ "type": "string" }),
+            example: Some(value.to_string()).filter(|value| !templated && !value.is_empty()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Components {
+    security_schemes: BTreeMap<String, Value>,
+}
+
+/// The collection as an OpenAPI 3.0 document. Custom methods have no place in
+/// OpenAPI and are left out, as is a second endpoint with the same method and path
+pub fn export(collection: &ApiCollection, yaml: bool) -> Result<String> {
+    let parts: Vec<(String, String, String)> = collection.endpoints.iter().map(|e| split_url(&e.url)).collect();
+    let mut origins: Vec<&str> = Vec::new();
+    for (origin, _, _) in &parts {
+        if !origin.is_empty() && !origins.contains(&origin.as_str()) {
+            origins.push(origin);
+        }
+    }
+
+    let mut paths: BTreeMap<String, BTreeMap<String, Operation>> = BTreeMap::new();
+    let mut schemes: BTreeMap<String, Value> = BTreeMap::new();
+    for (endpoint, (origin, path, query)) in collection.endpoints.iter().zip(&parts) {
+        let method = endpoint.method.as_str().to_lowercase();
+        if !METHODS.contains(&method.as_str()) {
+            continue;
+        }
+        let operations = paths.entry(to_path_template(path)).or_default();
+        if operations.contains_key(&method) {
+            continue;
+        }
+        let mut operation = endpoint_to_operation(endpoint, path, query);
+        if origins.len() > 1 && origin != origins[0] && !origin.is_empty() {
+            operation.servers = vec![server(origin)];
+        }
+        if let Some(auth) = &endpoint.auth {
+            let name = add_scheme(&mut schemes, auth);
+            operation.security = vec![BTreeMap::from([(name, Vec::new())])];
+        }
+        operations.insert(method, operation);
+    }
+
+    let document = Document {
+        openapi: "3.0.3",
+        info: Info { title: collection.name.clone(), version: "1.0.0" },
+        servers: origins.first().map(|origin| server(origin)).into_iter().collect(),
+        paths,
+        components: (!schemes.is_empty()).then_some(Components { security_schemes: schemes }),
+    };
+    if yaml {
+        serde_yaml::to_string(&document).map_err(|e| InteropError::Format(e.to_string()))
+    } else {
+        Ok(serde_json::to_string_pretty(&document)?)
+    }
+}
+
+fn endpoint_to_operation(endpoint: &ApiEndpoint, path: &str, query: &str) -> Operation {
+    let mut parameters: Vec<Parameter> = variables_in(path).into_iter()
+        .map(|name| Parameter::new(&name, "path", ""))
+        .collect();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        parameters.push(Parameter::new(name, "query", value));
+    }
+    let mut headers: Vec<(&String, &String)> = endpoint.headers.iter()
+        .filter(|(name, _)| !name.eq_ignore_ascii_case("content-type"))
+        .collect();
+    headers.sort();
+    parameters.extend(headers.into_iter().map(|(name, value)| Parameter::new(name, "header", value)));
+
+    let request_body = endpoint.body_template.as_ref().map(|body| {
+        let parsed = serde_json::from_str::<Value>(body).ok();
+        let mime = endpoint.headers.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone())
+            .unwrap_or_else(|| if parsed.is_some() { "application/json" } else { "text/plain" }.to_string());
+        // Bodies with {{variables}} rarely parse as JSON; those go out as text
+        let example = parsed.unwrap_or_else(|| Value::String(body.clone()));
+        json!({ "content": { mime: { "example": example } } })
+    });
+
+    Operation {
+        summary: endpoint.name.clone(),
+        description: endpoint.description.clone(),
+        tags: endpoint.tags.clone(),
+        servers: Vec::new(),
+        parameters,
+        request_body,
+        security: Vec::new(),
+        responses: json!({ "default": { "description": "Response" } }),
+    }
+}
+
+/// Register the auth as a security scheme, reusing an identical one, and return its name
+fn add_scheme(schemes: &mut BTreeMap<String, Value>, auth: &AuthConfig) -> String {
+    let (base, scheme) = match auth {
+        AuthConfig::Bearer { .. } => ("bearerAuth", json!({ "type": "http", "scheme": "bearer" })),
+        AuthConfig::Basic { .. } => ("basicAuth", json!({ "type": "http", "scheme": "basic" })),
+        AuthConfig::ApiKey { name, location, .. } => ("apiKeyAuth", json!({
+            "type": "apiKey",
+            "name": name,
+            "in": if *location == ApiKeyLocation::QueryParam { "query" } else { "header" },
+        })),
+    };
+    if let Some((name, _)) = schemes.iter().find(|(_, existing)| **existing == scheme) {
+        return name.clone();
+    }
+    let name = (1..)
+        .map(|n| if n == 1 { base.to_string() } else { format!("{}{}", base, n) })
+        .find(|name| !schemes.contains_key(name))
+        .unwrap_or_default();
+    schemes.insert(name.clone(), scheme);
+    name
+}
+
+/// `https://api/v1/users?page=2` → (`https://api`, `/v1/users`, `page=2`);
+/// a leading `{{base_url}}` counts as the server
+fn split_url(url: &str) -> (String, String, String) {
+    let (url, query) = url.split_once('?').unwrap_or((url, ""));
+    let after_scheme = url.find("://").map(|i| i + 3);
+    let split = match after_scheme {
+        Some(start) => url[start..].find('/').map(|i| start + i).unwrap_or(url.len()),
+        None if url.starts_with("{{") => url.find("}}").map(|i| i + 2).unwrap_or(url.len()),
+        None => 0,
+    };
+    let path = match &url[split..] {
+        "" => "/".to_string(),
+        path if path.starts_with('/') => path.to_string(),
+        path => format!("/{}", path),
+    };
+    (url[..split].to_string(), path, query.to_string())
+}
+
+/// `{{base_url}}` → `{base_url}`, declared as a server variable without a default value
+fn server(origin: &str) -> Server {
+    let variables = variables_in(origin).into_iter()
+        .map(|name| (name, json!({ "default": "" })))
+        .collect();
+    Server { url: to_path_template(origin), variables }
+}
+
+/// `/users/{{id}}` → `/users/{id}`
+fn to_path_template(text: &str) -> String {
+    text.replace("{{", "{").replace("}}", "}")
+}
+
+/// Names of the `{{variables}}` in `text`, in order
+fn variables_in(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start..].find("}}") else { break };
+        names.push(rest[start + 2..start + len].trim().to_string());
+        rest = &rest[start + len + 2..];
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"{
+        "openapi": "3.0.3",
+        "info": { "title": "Users API", "version": "1" },
+        "servers": [{ "url": "https://api.example.com/v1/" }],
+        "components": {
+            "parameters": { "Tenant": { "name": "X-Tenant", "in": "header", "required": true } }
+        },
+        "paths": {
+            "/users/{id}": {
+                "parameters": [{ "name": "id", "in": "path", "required": true }],
+                "get": { "operationId": "getUser", "tags": ["Users"], "parameters": [{ "$ref": "#/components/parameters/Tenant" }] },
+                "delete": { "description": "Gone for good" }
+            },
+            "/users": {
+                "post": {
+                    "summary": "Create user",
+                    "parameters": [{ "name": "dry_run", "in": "query", "required": true }, { "name": "page", "in": "query" }],
+                    "requestBody": { "content": { "application/json": { "example": { "name": "bob" } } } }
+                }
+            }
+        }
+    }"##;
+
+    #[test]
+    fn test_import_openapi3() {
+        let collection = import(SPEC).unwrap();
+        assert_eq!(collection.name, "Users API");
+        let summary: Vec<(String, String, String)> = collection.endpoints.iter()
+            .map(|e| (e.name.clone(), e.method.to_string(), e.url.clone()))
+            .collect();
+        assert_eq!(summary, vec![
+            ("Create user".to_string(), "POST".to_string(), "https://api.example.com/v1/users?dry_run={{dry_run}}".to_string()),
+            ("getUser".to_string(), "GET".to_string(), "https://api.example.com/v1/users/{{id}}".to_string()),
+            ("DELETE /users/{id}".to_string(), "DELETE".to_string(), "https://api.example.com/v1/users/{{id}}".to_string()),
+        ]);
+        assert_eq!(collection.endpoints[0].body_template.as_deref(), Some("{\n  \"name\": \"bob\"\n}"));
+        assert_eq!(collection.endpoints[0].headers.get("Content-Type").map(String::as_str), Some("application/json"));
+        assert_eq!(collection.endpoints[1].headers.get("X-Tenant").map(String::as_str), Some("{{X-Tenant}}"));
+        assert_eq!(collection.endpoints[1].tags, vec!["users"]);
+        assert_eq!(collection.endpoints[2].description.as_deref(), Some("Gone for good"));
+    }
+
+    #[test]
+    fn test_import_swagger2() {
+        let spec = r#"{
+            "swagger": "2.0", "info": { "title": "Pets" }, "host": "pets.local", "basePath": "/api", "schemes": ["http"],
+            "paths": { "/pets": { "post": { "parameters": [{ "name": "pet", "in": "body", "schema": { "example": { "kind": "cat" } } }] } } }
+        }"#;
+        let collection = import(spec).unwrap();
+        let endpoint = &collection.endpoints[0];
+        assert_eq!((endpoint.method.to_string(), endpoint.url.as_str()), ("POST".to_string(), "http://pets.local/api/pets"));
+        assert_eq!(endpoint.body_template.as_deref(), Some("{\n  \"kind\": \"cat\"\n}"));
+
+        let relative = r#"{ "openapi": "3.1.0", "info": { "title": "R" }, "servers": [{ "url": "/v2" }], "paths": { "/ping": { "get": {} } } }"#;
+        assert_eq!(import(relative).unwrap().endpoints[0].url, "{{base_url}}/v2/ping");
+        assert!(matches!(import(r#"{"openapi": "2"}"#), Err(InteropError::Format(_))));
+
+        let variables = r#"{ "openapi": "3.0.3", "info": { "title": "V" },
+            "servers": [{ "url": "{scheme}://{host}/v1", "variables": { "scheme": { "default": "https" }, "host": { "default": "" } } }],
+            "paths": { "/ping": { "get": {} } } }"#;
+        assert_eq!(import(variables).unwrap().endpoints[0].url, "https://{{host}}/v1/ping");
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let mut collection = ApiCollection::new("Users API".to_string());
+        let mut create = ApiEndpoint::new("Create user".to_string(), HttpMethod::POST, "{{base_url}}/users?dry_run={{dry_run}}".to_string());
+        create.headers.insert("Content-Type".to_string(), "application/json".to_string());
+        create.headers.insert("X-Tenant".to_string(), "{{X-Tenant}}".to_string());
+        create.body_template = Some("{\n  \"name\": \"bob\"\n}".to_string());
+        create.auth = Some(AuthConfig::Bearer { token: "{{token}}".to_string() });
+        create.tags = vec!["users".to_string()];
+        collection.add_endpoint(create);
+        let mut get = ApiEndpoint::new("Get user".to_string(), HttpMethod::GET, "{{base_url}}/users/{{id}}".to_string());
+        get.auth = Some(AuthConfig::Bearer { token: "{{other_token}}".to_string() });
+        collection.add_endpoint(get);
+        let mut status = ApiEndpoint::new("Status".to_string(), HttpMethod::GET, "https://status.example.com".to_string());
+        status.auth = Some(AuthConfig::ApiKey { name: "key".to_string(), value: "{{key}}".to_string(), location: ApiKeyLocation::QueryParam });
+        collection.add_endpoint(status);
+        collection.add_endpoint(ApiEndpoint::new("Purge".to_string(), HttpMethod::Custom("PURGE".to_string()), "{{base_url}}/cache".to_string()));
+
+        let json: Value = serde_json::from_str(&export(&collection, false).unwrap()).unwrap();
+        assert_eq!(json["openapi"], "3.0.3");
+        assert_eq!(json["servers"][0]["url"], "{base_url}");
+        assert_eq!(json["paths"]["/"]["get"]["servers"][0]["url"], "https://status.example.com");
+        assert_eq!(json["paths"]["/users/{id}"]["get"]["security"][0]["bearerAuth"], json!([]));
+        assert_eq!(json["paths"]["/"]["get"]["security"][0]["apiKeyAuth"], json!([]));
+        assert_eq!(json["components"]["securitySchemes"]["apiKeyAuth"], json!({ "type": "apiKey", "name": "key", "in": "query" }));
+        assert_eq!(json["paths"]["/users"]["post"]["requestBody"]["content"]["application/json"]["example"], json!({ "name": "bob" }));
+        assert!(json["paths"].get("/cache").is_none(), "custom methods aren't OpenAPI");
+
+        let yaml = export(&collection, true).unwrap();
+        assert!(yaml.starts_with("openapi: 3.0.3\ninfo:\n  title: Users API\n"));
+
+        let imported = import(&serde_json::to_string(&json).unwrap()).unwrap();
+        let by_name = |name: &str| imported.endpoints.iter().find(|e| e.name == name).unwrap();
+        let create = by_name("Create user");
+        assert_eq!(create.url, collection.endpoints[0].url);
+        assert_eq!(create.headers, collection.endpoints[0].headers);
+        assert_eq!(create.body_template, collection.endpoints[0].body_template);
+        assert_eq!(create.tags, vec!["users"]);
+        assert_eq!(by_name("Get user").url, "{{base_url}}/users/{{id}}");
+    }
+}
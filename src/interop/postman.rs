@@ -0,0 +1,334 @@
+// Postman collection v2.1 (and v2.0) import
+// A collection is a tree of items: folders hold more items, leaves hold a
+// request. Folder names become tags, and auth set on a folder or the
+// collection applies to every request below it that doesn't set its own.
+
+use super::{InteropError, Result};
+use crate::faker;
+use crate::models::{ApiCollection, ApiEndpoint, ApiKeyLocation, AuthConfig, HttpMethod};
+use serde_json::{json, Value};
+
+pub fn is_postman(value: &Value) -> bool {
+    let schema = value.pointer("/info/schema").and_then(Value::as_str).unwrap_or_default();
+    schema.contains("getpostman.com")
+        || (value.get("info").is_some_and(Value::is_object) && value.get("item").is_some_and(Value::is_array))
+}
+
+pub fn import(contents: &str) -> Result<ApiCollection> {
+    import_value(&serde_json::from_str(contents)?)
+}
+
+pub fn import_value(value: &Value) -> Result<ApiCollection> {
+    if !is_postman(value) {
+        return Err(InteropError::Format("not a Postman collection".to_string()));
+    }
+    let name = value.pointer("/info/name").and_then(Value::as_str).unwrap_or("Postman import");
+    let mut collection = ApiCollection::new(name.to_string());
+    let auth = Inherited::from(value.get("auth"));
+    add_items(&mut collection, value, &[], &auth)?;
+    Ok(collection)
+}
+
+/// Auth in effect for a level of the tree: a folder without `auth` inherits
+/// its parent's, while `noauth` switches it off
+#[derive(Clone)]
+struct Inherited(Option<AuthConfig>);
+
+impl Inherited {
+    fn from(auth: Option<&Value>) -> Self {
+        Inherited(auth.and_then(to_auth))
+    }
+
+    fn below(&self, auth: Option<&Value>) -> Self {
+        match auth {
+            Some(auth) if !auth.is_null() => Self::from(Some(auth)),
+            _ => self.clone(),
+        }
+    }
+}
+
+fn add_items(collection: &mut ApiCollection, parent: &Value, folders: &[String], auth: &Inherited) -> Result<()> {
+    for item in parent.get("item").and_then(Value::as_array).into_iter().flatten() {
+        let auth = auth.below(item.get("auth").or_else(|| item.pointer("/request/auth")));
+        match item.get("request") {
+            Some(request) => {
+                let mut endpoint = request_to_endpoint(&str_field(item, "name"), request)?;
+                endpoint.tags = folders.to_vec();
+                endpoint.auth = auth.0;
+                collection.add_endpoint(endpoint);
+            }
+            None => {
+                let mut folders = folders.to_vec();
+                folders.push(str_field(item, "name").to_lowercase());
+                add_items(collection, item, &folders, &auth)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn request_to_endpoint(name: &str, request: &Value) -> Result<ApiEndpoint> {
+    // A request may be just its URL
+    if let Some(url) = request.as_str() {
+        return Ok(ApiEndpoint::new(name.to_string(), HttpMethod::GET, from_postman_template(url)));
+    }
+    let method = match str_field(request, "method") {
+        method if method.is_empty() => HttpMethod::GET,
+        method => HttpMethod::from(method),
+    };
+    if !method.is_valid() {
+        return Err(InteropError::Format(format!("unsupported HTTP method {}", method)));
+    }
+    let url = match request.get("url") {
+        Some(Value::String(url)) => url.clone(),
+        Some(url) => str_field(url, "raw"),
+        None => String::new(),
+    };
+    let mut endpoint = ApiEndpoint::new(name.to_string(), method, from_postman_template(&url));
+
+    for header in request.get("header").and_then(Value::as_array).into_iter().flatten() {
+        let key = str_field(header, "key");
+        if !key.is_empty() && !is_disabled(header) {
+            endpoint.headers.insert(key, from_postman_template(&str_field(header, "value")));
+        }
+    }
+
+    if let Some((body, content_type)) = request.get("body").and_then(to_body) {
+        endpoint.body_template = Some(from_postman_template(&body));
+        if !endpoint.headers.keys().any(|k| k.eq_ignore_ascii_case("content-type")) {
+            if let Some(content_type) = content_type {
+                endpoint.headers.insert("Content-Type".to_string(), content_type.to_string());
+            }
+        }
+    }
+
+    let description = match request.get("description") {
+        Some(Value::String(text)) => text.clone(),
+        Some(description) => str_field(description, "content"),
+        None => String::new(),
+    };
+    if !description.is_empty() {
+        endpoint.description = Some(description);
+    }
+    Ok(endpoint)
+}
+
+/// Body text and the content type it implies; form-data and file bodies have
+/// no text form and are left out
+fn to_body(body: &Value) -> Option<(String, Option<&'static str>)> {
+    match body.get("mode").and_then(Value::as_str)? {
+        "raw" => {
+            let text = str_field(body, "raw");
+            let content_type = match body.pointer("/options/raw/language").and_then(Value::as_str) {
+                Some("json") => Some("application/json"),
+                Some("xml") => Some("application/xml"),
+                Some("html") => Some("text/html"),
+                Some("text") => Some("text/plain"),
+                _ => None,
+            };
+            Some((text, content_type)).filter(|(text, _)| !text.is_empty())
+        }
+        "urlencoded" => {
+            let pairs: Vec<String> = body.get("urlencoded").and_then(Value::as_array).into_iter().flatten()
+                .filter(|pair| !is_disabled(pair))
+                .map(|pair| format!("{}={}", str_field(pair, "key"), str_field(pair, "value")))
+                .collect();
+            Some((pairs.join("&"), Some("application/x-www-form-urlencoded"))).filter(|(text, _)| !text.is_empty())
+        }
+        "graphql" => {
+            let graphql = body.get("graphql")?;
+            let variables = match graphql.get("variables") {
+                Some(Value::String(text)) if !text.trim().is_empty() => serde_json::from_str(text).unwrap_or(Value::Null),
+                _ => Value::Null,
+            };
+            let body = json!({ "query": str_field(graphql, "query"), "variables": variables });
+            Some((serde_json::to_string_pretty(&body).ok()?, Some("application/json")))
+        }
+        _ => None,
+    }
+}
+
+fn to_auth(auth: &Value) -> Option<AuthConfig> {
+    let kind = auth.get("type").and_then(Value::as_str)?;
+    // v2.1 stores the settings as a list of {key, value}; v2.0 as an object
+    let field = |name: &str| {
+        let value = match auth.get(kind) {
+            Some(Value::Array(pairs)) => pairs.iter()
+                .find(|pair| pair.get("key").and_then(Value::as_str) == Some(name))
+                .and_then(|pair| pair.get("value")),
+            Some(settings) => settings.get(name),
+            None => None,
+        };
+        from_postman_template(value.and_then(Value::as_str).unwrap_or_default())
+    };
+    match kind {
+        "bearer" => Some(AuthConfig::Bearer { token: field("token") }),
+        "basic" => Some(AuthConfig::Basic { username: field("username"), password: field("password") }),
+        "apikey" => Some(AuthConfig::ApiKey {
+            name: field("key"),
+            value: field("value"),
+            location: if field("in") == "query" { ApiKeyLocation::QueryParam } else { ApiKeyLocation::Header },
+        }),
+        _ => None,
+    }
+}
+
+fn is_disabled(value: &Value) -> bool {
+    value.get("disabled").and_then(Value::as_bool) == Some(true)
+}
+
+fn str_field(value: &Value, name: &str) -> String {
+    value.get(name).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// Postman's dynamic variables in our spelling: `{{$guid}}` → `{{f:uuid}}`,
+/// `{{$randomEmail}}` → `{{f:email}}`, `{{$timestamp}}` → `{{$now:unix}}`.
+/// Plain variables are written the same way in both tools.
+fn from_postman_template(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{$") {
+        let Some(len) = rest[start..].find("}}") else { break };
+        let name = &rest[start + 3..start + len];
+        let replacement = match name {
+            "guid" | "randomUUID" => "f:uuid".to_string(),
+            "timestamp" => "$now:unix".to_string(),
+            "isoTimestamp" => "$now:iso".to_string(),
+            "randomInt" => "f:number".to_string(),
+            _ => match name.strip_prefix("random").map(str::to_lowercase) {
+                Some(fake) if faker::generate_fake_value(&fake).is_some() => format!("f:{}", fake),
+                _ => format!("${}", name),
+            },
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&format!("{{{{{}}}}}", replacement));
+        rest = &rest[start + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLLECTION: &str = r#"{
+        "info": {
+            "name": "Users API",
+            "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+        },
+        "auth": { "type": "bearer", "bearer": [{ "key": "token", "value": "{{token}}", "type": "string" }] },
+        "item": [
+            {
+                "name": "Auth",
+                "auth": { "type": "noauth" },
+                "item": [{
+                    "name": "Login",
+                    "request": {
+                        "method": "POST",
+                        "header": [
+                            { "key": "X-Trace", "value": "{{$guid}}" },
+                            { "key": "X-Off", "value": "1", "disabled": true }
+                        ],
+                        "url": { "raw": "{{base_url}}/login", "host": ["{{base_url}}"], "path": ["login"] },
+                        "body": { "mode": "raw", "raw": "{\"user\": \"{{$randomEmail}}\"}", "options": { "raw": { "language": "json" } } },
+                        "description": "Get a session"
+                    }
+                }]
+            },
+            {
+                "name": "Users",
+                "item": [
+                    { "name": "List", "request": { "method": "GET", "url": "{{base_url}}/users" } },
+                    {
+                        "name": "Search",
+                        "request": {
+                            "method": "POST",
+                            "url": "{{base_url}}/search",
+                            "auth": { "type": "apikey", "apikey": [
+                                { "key": "key", "value": "api_key" },
+                                { "key": "value", "value": "{{key}}" },
+                                { "key": "in", "value": "query" }
+                            ] },
+                            "body": { "mode": "urlencoded", "urlencoded": [
+                                { "key": "q", "value": "bob" },
+                                { "key": "page", "value": "2", "disabled": true }
+                            ] }
+                        }
+                    }
+                ]
+            },
+            { "name": "Health", "request": "https://api.example.com/health" }
+        ]
+    }"#;
+
+    #[test]
+    fn test_import() {
+        let collection = import(COLLECTION).unwrap();
+        assert_eq!(collection.name, "Users API");
+        assert_eq!(collection.endpoints.len(), 4);
+
+        let login = &collection.endpoints[0];
+        assert_eq!(login.method, HttpMethod::POST);
+        assert_eq!(login.url, "{{base_url}}/login");
+        assert_eq!(login.tags, vec!["auth"]);
+        assert_eq!(login.headers.get("X-Trace").map(String::as_str), Some("{{f:uuid}}"));
+        assert!(!login.headers.contains_key("X-Off"));
+        assert_eq!(login.headers.get("Content-Type").map(String::as_str), Some("application/json"));
+        #[cfg(feature = "faker")]
+        assert_eq!(login.body_template.as_deref(), Some("{\"user\": \"{{f:email}}\"}"));
+        assert_eq!(login.description.as_deref(), Some("Get a session"));
+        assert_eq!(login.auth, None);
+
+        let list = &collection.endpoints[1];
+        assert_eq!(list.tags, vec!["users"]);
+        assert_eq!(list.auth, Some(AuthConfig::Bearer { token: "{{token}}".to_string() }));
+
+        let search = &collection.endpoints[2];
+        assert_eq!(search.body_template.as_deref(), Some("q=bob"));
+        assert_eq!(search.headers.get("Content-Type").map(String::as_str), Some("application/x-www-form-urlencoded"));
+        assert_eq!(search.auth, Some(AuthConfig::ApiKey {
+            name: "api_key".to_string(),
+            value: "{{key}}".to_string(),
+            location: ApiKeyLocation::QueryParam,
+        }));
+
+        let health = &collection.endpoints[3];
+        assert_eq!(health.method, HttpMethod::GET);
+        assert_eq!(health.url, "https://api.example.com/health");
+        assert!(health.tags.is_empty());
+    }
+
+    #[test]
+    fn test_v2_0_auth_and_graphql() {
+        let collection = import(r#"{
+            "info": { "name": "Shop", "schema": "https://schema.getpostman.com/json/collection/v2.0.0/collection.json" },
+            "item": [{
+                "name": "Products",
+                "request": {
+                    "method": "POST",
+                    "url": "https://shop/graphql",
+                    "auth": { "type": "basic", "basic": { "username": "admin", "password": "{{password}}" } },
+                    "body": { "mode": "graphql", "graphql": { "query": "{ products { id } }", "variables": "{\"first\": 5}" } }
+                }
+            }]
+        }"#).unwrap();
+        let products = &collection.endpoints[0];
+        assert_eq!(products.auth, Some(AuthConfig::Basic { username: "admin".to_string(), password: "{{password}}".to_string() }));
+        let body: Value = serde_json::from_str(products.body_template.as_deref().unwrap()).unwrap();
+        assert_eq!(body, json!({ "query": "{ products { id } }", "variables": { "first": 5 } }));
+    }
+
+    #[test]
+    fn test_dynamic_variables() {
+        assert_eq!(from_postman_template("{{$timestamp}}-{{id}}"), "{{$now:unix}}-{{id}}");
+        #[cfg(feature = "faker")]
+        assert_eq!(from_postman_template("{{$randomFirstName}}"), "{{f:firstname}}");
+        assert_eq!(from_postman_template("{{$randomBankAccount}}"), "{{$randomBankAccount}}");
+    }
+
+    #[test]
+    fn test_rejects_other_json() {
+        assert!(matches!(import("{\"foo\": 1}"), Err(InteropError::Format(_))));
+    }
+}
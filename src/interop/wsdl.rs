@@ -0,0 +1,262 @@
+// WSDL 1.1 service descriptions
+// Every operation of the service's SOAP port (1.1 preferred over 1.2) becomes
+// a POST endpoint in SOAP mode, with an envelope body template holding a
+// `{{field}}` placeholder for each input element.
+
+use super::{InteropError, Result};
+use crate::models::{ApiCollection, ApiEndpoint, HttpMethod, SoapConfig, SoapVersion};
+use roxmltree::{Document, Node};
+
+const SOAP11_BINDING: &str = "http://schemas.xmlsoap.org/wsdl/soap/";
+const SOAP12_BINDING: &str = "http://schemas.xmlsoap.org/wsdl/soap12/";
+const SOAP11_ENVELOPE: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+const SOAP12_ENVELOPE: &str = "http://www.w3.org/2003/05/soap-envelope";
+const MAX_DEPTH: usize = 8; // Guards against recursive schema types
+
+/// `tns:GetUser` → `GetUser`
+fn local(qname: &str) -> &str {
+    qname.rsplit(':').next().unwrap_or(qname)
+}
+
+fn children<'a, 'i>(node: Node<'a, 'i>, tag: &'static str) -> impl Iterator<Item = Node<'a, 'i>> {
+    node.children().filter(move |n| n.has_tag_name(tag))
+}
+
+fn named<'a, 'i>(parent: Node<'a, 'i>, tag: &'static str, qname: &str) -> Option<Node<'a, 'i>> {
+    children(parent, tag).find(|n| n.attribute("name") == Some(local(qname)))
+}
+
+pub fn import(contents: &str) -> Result<ApiCollection> {
+    let doc = Document::parse(contents)
+        .map_err(|e| InteropError::Format(format!("invalid XML: {}", e)))?;
+    let definitions = doc.root_element();
+    if !definitions.has_tag_name("definitions") {
+        return Err(InteropError::Format("expected a WSDL 1.1 <definitions> document".to_string()));
+    }
+
+    let ports: Vec<(Node, SoapVersion, &str)> = children(definitions, "service")
+        .flat_map(|service| children(service, "port"))
+        .filter_map(|port| {
+            let binding = named(definitions, "binding", port.attribute("binding")?)?;
+            let address = children(port, "address").next()?;
+            let version = match address.tag_name().namespace()? {
+                SOAP11_BINDING => SoapVersion::V1_1,
+                SOAP12_BINDING => SoapVersion::V1_2,
+                _ => return None,
+            };
+            Some((binding, version, address.attribute("location")?))
+        })
+        .collect();
+    let (binding, version, location) = ports.iter()
+        .find(|(_, version, _)| *version == SoapVersion::V1_1)
+        .or_else(|| ports.first())
+        .copied()
+        .ok_or_else(|| InteropError::Format("no SOAP port found in the WSDL".to_string()))?;
+
+    let name = definitions.attribute("name")
+        .or_else(|| children(definitions, "service").next().and_then(|s| s.attribute("name")))
+        .unwrap_or("WSDL import");
+    let port_type = binding.attribute("type").and_then(|t| named(definitions, "portType", t));
+    let schema = Schema {
+        schemas: definitions.descendants().filter(|n| n.has_tag_name("schema")).collect(),
+    };
+    let target_ns = definitions.attribute("targetNamespace").unwrap_or_default();
+
+    let mut collection = ApiCollection::new(name.to_string());
+    for operation in children(binding, "operation") {
+        let Some(op_name) = operation.attribute("name") else { continue };
+        let action = children(operation, "operation").next()
+            .and_then(|n| n.attribute("soapAction"))
+            .unwrap_or_default();
+        let abstract_op = port_type.and_then(|pt| named(pt, "operation", op_name));
+        let message = abstract_op
+            .and_then(|op| children(op, "input").next())
+            .and_then(|input| input.attribute("message"))
+            .and_then(|m| named(definitions, "message", m));
+
+        let mut body = Vec::new();
+        if let Some(message) = message {
+            let rpc_parts: Vec<&str> = children(message, "part")
+                .filter(|p| p.attribute("element").is_none())
+                .filter_map(|p| p.attribute("name"))
+                .collect();
+            for part in children(message, "part") {
+                if let Some((element, element_schema)) = part.attribute("element").and_then(|e| schema.element(e)) {
+                    let ns = element_schema.attribute("targetNamespace").unwrap_or_default();
+                    let qualified = element_schema.attribute("elementFormDefault") == Some("qualified");
+                    let xmlns = if qualified {
+                        format!(" xmlns=\"{}\"", ns)
+                    } else {
+                        format!(" xmlns:tns=\"{}\"", ns)
+                    };
+                    let prefix = if qualified { "" } else { "tns:" };
+                    schema.write(&mut body, element, prefix, &xmlns, 2, 0);
+                }
+            }
+            // RPC style: the operation element wraps one child per part
+            if !rpc_parts.is_empty() {
+                body.push(format!("    <tns:{} xmlns:tns=\"{}\">", op_name, target_ns));
+                for part in rpc_parts {
+                    body.push(format!("      <{0}>{{{{{0}}}}}</{0}>", part));
+                }
+                body.push(format!("    </tns:{}>", op_name));
+            }
+        }
+
+        let mut endpoint = ApiEndpoint::new(op_name.to_string(), HttpMethod::POST, location.to_string());
+        endpoint.body_template = Some(envelope(version, &body));
+        endpoint.soap = Some(SoapConfig { action: action.to_string(), version });
+        endpoint.description = abstract_op
+            .and_then(|op| children(op, "documentation").next())
+            .and_then(|d| d.text())
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty());
+        collection.endpoints.push(endpoint);
+    }
+    if collection.endpoints.is_empty() {
+        return Err(InteropError::Format("the SOAP binding has no operations".to_string()));
+    }
+    Ok(collection)
+}
+
+fn envelope(version: SoapVersion, body: &[String]) -> String {
+    let ns = match version {
+        SoapVersion::V1_1 => SOAP11_ENVELOPE,
+        SoapVersion::V1_2 => SOAP12_ENVELOPE,
+    };
+    let mut lines = vec![
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>".to_string(),
+        format!("<soap:Envelope xmlns:soap=\"{}\">", ns),
+        "  <soap:Header/>".to_string(),
+        "  <soap:Body>".to_string(),
+    ];
+    lines.extend(body.iter().cloned());
+    lines.push("  </soap:Body>".to_string());
+    lines.push("</soap:Envelope>".to_string());
+    lines.join("\n")
+}
+
+/// The `<types>` schemas, used to expand input elements into sample XML
+struct Schema<'a, 'i> {
+    schemas: Vec<Node<'a, 'i>>,
+}
+
+impl<'a, 'i> Schema<'a, 'i> {
+    /// A top-level element together with the schema declaring it
+    fn element(&self, qname: &str) -> Option<(Node<'a, 'i>, Node<'a, 'i>)> {
+        self.schemas.iter().find_map(|schema| Some((named(*schema, "element", qname)?, *schema)))
+    }
+
+    fn complex_type(&self, qname: &str) -> Option<Node<'a, 'i>> {
+        self.schemas.iter().find_map(|schema| named(*schema, "complexType", qname))
+    }
+
+    /// Append `element` as indented XML; leaves become `{{name}}` placeholders
+    fn write(&self, out: &mut Vec<String>, element: Node<'a, 'i>, prefix: &str, attrs: &str, indent: usize, depth: usize) {
+        let element = match element.attribute("ref").and_then(|r| self.element(r)) {
+            Some((target, _)) => target,
+            None => element,
+        };
+        let Some(name) = element.attribute("name") else { return };
+        let pad = "  ".repeat(indent);
+        let complex = children(element, "complexType").next()
+            .or_else(|| element.attribute("type").and_then(|t| self.complex_type(t)));
+
+        let mut fields = Vec::new();
+        if let Some(complex) = complex.filter(|_| depth < MAX_DEPTH) {
+            particles(complex, &mut fields);
+        }
+        if fields.is_empty() && complex.is_some() {
+            out.push(format!("{}<{}{}{}/>", pad, prefix, name, attrs));
+        } else if fields.is_empty() {
+            out.push(format!("{0}<{1}{2}{3}>{{{{{2}}}}}</{1}{2}>", pad, prefix, name, attrs));
+        } else {
+            out.push(format!("{}<{}{}{}>", pad, prefix, name, attrs));
+            for field in fields {
+                self.write(out, field, "", "", indent + 1, depth + 1);
+            }
+            out.push(format!("{}</{}{}>", pad, prefix, name));
+        }
+    }
+}
+
+/// Child elements of a complex type, looking through sequence/all/choice and extensions
+fn particles<'a, 'i>(node: Node<'a, 'i>, out: &mut Vec<Node<'a, 'i>>) {
+    for child in node.children().filter(|n| n.is_element()) {
+        match child.tag_name().name() {
+            "element" => out.push(child),
+            "sequence" | "all" | "choice" | "complexContent" | "extension" | "restriction" => particles(child, out),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WSDL: &str = r#"<?xml version="1.0"?>
+<definitions name="UserService" targetNamespace="urn:users"
+    xmlns="http://schemas.xmlsoap.org/wsdl/" xmlns:tns="urn:users"
+    xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/" xmlns:soap12="http://schemas.xmlsoap.org/wsdl/soap12/"
+    xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+  <types>
+    <xsd:schema targetNamespace="urn:users" elementFormDefault="qualified">
+      <xsd:element name="GetUser">
+        <xsd:complexType><xsd:sequence>
+          <xsd:element name="id" type="xsd:int"/>
+          <xsd:element name="filter" type="tns:Filter"/>
+        </xsd:sequence></xsd:complexType>
+      </xsd:element>
+      <xsd:complexType name="Filter"><xsd:sequence>
+        <xsd:element name="active" type="xsd:boolean"/>
+      </xsd:sequence></xsd:complexType>
+    </xsd:schema>
+  </types>
+  <message name="GetUserRequest"><part name="parameters" element="tns:GetUser"/></message>
+  <portType name="UserPort">
+    <operation name="GetUser">
+      <documentation>Fetch a user</documentation>
+      <input message="tns:GetUserRequest"/>
+    </operation>
+  </portType>
+  <binding name="UserBinding12" type="tns:UserPort">
+    <soap12:binding transport="http://schemas.xmlsoap.org/soap/http"/>
+    <operation name="GetUser"><soap12:operation soapAction="urn:users#GetUser12"/></operation>
+  </binding>
+  <binding name="UserBinding" type="tns:UserPort">
+    <soap:binding transport="http://schemas.xmlsoap.org/soap/http"/>
+    <operation name="GetUser"><soap:operation soapAction="urn:users#GetUser"/></operation>
+  </binding>
+  <service name="Users">
+    <port name="Users12" binding="tns:UserBinding12"><soap12:address location="http://example.com/soap12"/></port>
+    <port name="Users" binding="tns:UserBinding"><soap:address location="http://example.com/soap"/></port>
+  </service>
+</definitions>"#;
+
+    #[test]
+    fn test_import_document_literal() {
+        let collection = import(WSDL).unwrap();
+        assert_eq!(collection.name, "UserService");
+        assert_eq!(collection.endpoints.len(), 1);
+
+        let endpoint = &collection.endpoints[0];
+        assert_eq!(endpoint.name, "GetUser");
+        assert_eq!(endpoint.method, HttpMethod::POST);
+        assert_eq!(endpoint.url, "http://example.com/soap");
+        assert_eq!(endpoint.description.as_deref(), Some("Fetch a user"));
+        assert_eq!(endpoint.soap, Some(SoapConfig { action: "urn:users#GetUser".to_string(), version: SoapVersion::V1_1 }));
+
+        let body = endpoint.body_template.as_deref().unwrap();
+        assert!(body.contains("<GetUser xmlns=\"urn:users\">"));
+        assert!(body.contains("<id>{{id}}</id>"));
+        assert!(body.contains("<active>{{active}}</active>"));
+        assert!(roxmltree::Document::parse(body).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_non_wsdl() {
+        assert!(import("<html></html>").is_err());
+        assert!(import("<definitions><oops></definitions>").is_err());
+    }
+}
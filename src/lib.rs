@@ -2,10 +2,36 @@ pub mod models;
 pub mod template;
 pub mod storage;
 pub mod http;
+pub mod ws;
 pub mod formatter;
+#[cfg(feature = "load-test")]
 pub mod load_test;
+#[cfg(feature = "load-test")]
+pub mod har;
 pub mod variables;
+pub mod history;
+pub mod activity;
+pub mod changelog;
+pub mod i18n;
+pub mod settings;
+pub mod proxy;
+pub mod logging;
+pub mod allowlist;
+pub mod merge;
+pub mod compare;
+pub mod links;
+pub mod visualize;
+pub mod protobuf;
+#[cfg(feature = "importers")]
+pub mod interop;
+pub mod runner;
+pub mod scaffold;
+#[cfg(feature = "cli")]
+pub mod cli;
 pub mod faker;
+#[cfg(feature = "tui")]
 pub mod tui_app;
+#[cfg(feature = "tui")]
 pub mod app;
+#[cfg(feature = "tui")]
 pub mod tui;
@@ -0,0 +1,219 @@
+// Links in responses
+// URLs a response points at, for following one with a GET: `Link` and
+// `Location` headers, HAL `href`s, JSON:API `links` and any absolute http(s)
+// URL in a JSON body. Relative links are resolved against the request's URL.
+
+use crate::http::HttpResponse;
+use reqwest::Url;
+use serde_json::Value;
+
+/// Links listed per response at most; huge collections repeat the same few shapes
+const MAX_LINKS: usize = 200;
+
+/// A URL found in a response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseLink {
+    /// Where it was found: `Link rel=next`, `Location` or a JSON path like `$._links.self.href`
+    pub source: String,
+    /// Absolute URL, ready to send
+    pub url: String,
+}
+
+/// Every distinct link in a response, headers first, then the body in document order
+pub fn response_links(response: &HttpResponse) -> Vec<ResponseLink> {
+    let base = response.traffic.as_ref().and_then(|t| Url::parse(&t.request.url).ok());
+    let mut found = Vec::new();
+    let mut headers: Vec<_> = response.headers.iter().collect();
+    headers.sort();
+    for (name, value) in headers {
+        if name.eq_ignore_ascii_case("link") {
+            for (href, rel) in parse_link_header(value) {
+                let source = rel.map_or_else(|| "Link".to_string(), |rel| format!("Link rel={}", rel));
+                found.push((source, href));
+            }
+        } else if name.eq_ignore_ascii_case("location") || name.eq_ignore_ascii_case("content-location") {
+            found.push((canonical_header(name), value.trim().to_string()));
+        }
+    }
+    if let Ok(json) = serde_json::from_slice::<Value>(&response.body) {
+        json_links("$".to_string(), &json, false, &mut found);
+    }
+
+    let mut links: Vec<ResponseLink> = Vec::new();
+    for (source, href) in found {
+        let Some(url) = resolve(base.as_ref(), &href) else { continue };
+        if links.len() == MAX_LINKS {
+            break;
+        }
+        if !links.iter().any(|link| link.url == url) {
+            links.push(ResponseLink { source, url });
+        }
+    }
+    links
+}
+
+/// `<https://api/items?page=2>; rel="next", </items?page=9>; rel=last` as (href, rel) pairs
+pub fn parse_link_header(value: &str) -> Vec<(String, Option<String>)> {
+    let mut links = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else { break };
+        let href = rest[start + 1..end].trim().to_string();
+        // Parameters run up to the next link
+        let params_end = rest[end..].find('<').map_or(rest.len(), |next| end + next);
+        let rel = rest[end + 1..params_end]
+            .split(';')
+            .filter_map(|param| param.split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("rel"))
+            .map(|(_, rel)| rel.trim().trim_end_matches(',').trim().trim_matches('"').to_string());
+        links.push((href, rel));
+        rest = &rest[params_end..];
+    }
+    links
+}
+
+/// `location` as `Location`, since header names arrive lowercased
+fn canonical_header(name: &str) -> String {
+    name.split('-')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|first| first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()).unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Strings that are links: `href`s and the members of a `_links`/`links`
+/// object may be relative, anything else has to be an absolute http(s) URL
+fn json_links(path: String, value: &Value, in_links: bool, out: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let in_links = in_links || key == "_links" || key == "links";
+                match value {
+                    Value::String(text) if key == "href" || in_links || is_absolute(text) => {
+                        out.push((format!("{}.{}", path, key), text.clone()));
+                    }
+                    _ => json_links(format!("{}.{}", path, key), value, in_links, out),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                match item {
+                    Value::String(text) if in_links || is_absolute(text) => out.push((format!("{}[{}]", path, i), text.clone())),
+                    _ => json_links(format!("{}[{}]", path, i), item, in_links, out),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_absolute(text: &str) -> bool {
+    (text.starts_with("http://") || text.starts_with("https://")) && !text.contains(char::is_whitespace)
+}
+
+/// An absolute http(s) URL for a link, or None for templates and other schemes
+fn resolve(base: Option<&Url>, href: &str) -> Option<String> {
+    // RFC 6570 templates such as HAL's `/items{?page}` need values first
+    if href.is_empty() || href.contains('{') {
+        return None;
+    }
+    let url = match base {
+        Some(base) => base.join(href).ok()?,
+        None => Url::parse(href).ok()?,
+    };
+    matches!(url.scheme(), "http" | "https").then(|| url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{NetworkTiming, NetworkTraffic, RequestDetails};
+    use reqwest::StatusCode;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn response(headers: &[(&str, &str)], body: &str) -> HttpResponse {
+        let request = RequestDetails {
+            method: "GET".to_string(),
+            url: "https://api.example.com/v1/orders?page=1".to_string(),
+            headers: HashMap::new(),
+            sent_headers: Default::default(),
+            body: None,
+            body_size: 0,
+        };
+        HttpResponse {
+            status: StatusCode::OK,
+            headers: headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            body: body.as_bytes().to_vec(),
+            duration: Duration::from_millis(5),
+            traffic: Some(NetworkTraffic {
+                timing: NetworkTiming {
+                    dns_lookup: None,
+                    tcp_connect: None,
+                    tls_handshake: None,
+                    request_sent: None,
+                    waiting: Duration::from_millis(5),
+                    content_download: Duration::ZERO,
+                    total: Duration::from_millis(5),
+                    expect_continue: None,
+                },
+                request,
+                remote_addr: None,
+                interim: Vec::new(),
+                trailers: Vec::new(),
+                response_headers_size: 0,
+                response_body_size: body.len(),
+                body_mismatch: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_parse_link_header() {
+        assert_eq!(
+            parse_link_header(r#"<https://api.example.com/items?page=2>; rel="next", </items?page=9>; rel=last, <https://other/a,b>"#),
+            vec![
+                ("https://api.example.com/items?page=2".to_string(), Some("next".to_string())),
+                ("/items?page=9".to_string(), Some("last".to_string())),
+                ("https://other/a,b".to_string(), None),
+            ]
+        );
+        assert!(parse_link_header("not a link").is_empty());
+    }
+
+    #[test]
+    fn test_links_from_headers_and_hal_body() {
+        let body = r#"{
+            "_links": {
+                "self": {"href": "/v1/orders?page=1"},
+                "next": {"href": "orders?page=2"},
+                "find": {"href": "/v1/orders{?id}", "templated": true}
+            },
+            "items": [
+                {"id": 1, "customer": "https://crm.example.com/customers/7", "note": "see https://x"},
+                {"id": 2, "receipt": "mailto:someone@example.com"}
+            ]
+        }"#;
+        let response = response(&[("link", "</v1/orders?page=9>; rel=\"last\""), ("location", "/v1/orders/1")], body);
+        let links: Vec<(String, String)> = response_links(&response).into_iter().map(|l| (l.source, l.url)).collect();
+        assert_eq!(links, vec![
+            ("Link rel=last".to_string(), "https://api.example.com/v1/orders?page=9".to_string()),
+            ("Location".to_string(), "https://api.example.com/v1/orders/1".to_string()),
+            ("$._links.next.href".to_string(), "https://api.example.com/v1/orders?page=2".to_string()),
+            ("$._links.self.href".to_string(), "https://api.example.com/v1/orders?page=1".to_string()),
+            ("$.items[0].customer".to_string(), "https://crm.example.com/customers/7".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_json_api_links_and_duplicates() {
+        let body = r#"{"links": {"self": "/v1/orders", "related": "/v1/orders"}, "data": []}"#;
+        let links = response_links(&response(&[], body));
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].source, "$.links.related");
+        assert!(response_links(&response(&[], "plain https://api.example.com")).is_empty());
+    }
+}
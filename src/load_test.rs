@@ -1,8 +1,18 @@
 // Load testing engine for concurrent request execution
 
+use crate::har::HarSampler;
+use crate::http::{HttpClient, HttpClientConfig, RequestInputs};
+use crate::models::ApiEndpoint;
+pub use crate::models::ChaosConfig;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
+use uuid::Uuid;
 
 /// Time-series data point for charting
 #[derive(Debug, Clone)]
@@ -17,17 +27,86 @@ pub struct TimeSeriesDataPoint {
     pub request_count: u64,
 }
 
+/// A time-series point as saved with a run's report; latencies in milliseconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeSeriesSample {
+    pub elapsed_secs: u64,
+    pub rps: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub request_count: u64,
+}
+
+impl From<&TimeSeriesDataPoint> for TimeSeriesSample {
+    fn from(point: &TimeSeriesDataPoint) -> Self {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        Self {
+            elapsed_secs: point.elapsed_secs,
+            rps: point.rps,
+            p50_ms: ms(point.p50),
+            p90_ms: ms(point.p90),
+            p95_ms: ms(point.p95),
+            p99_ms: ms(point.p99),
+            request_count: point.request_count,
+        }
+    }
+}
+
+/// How a request of a run went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Success,
+    Failure,
+    AssertionFailure,
+}
+
+/// One endpoint's share of a run that sends to several
+#[derive(Debug, Clone, Default)]
+pub struct EndpointMetrics {
+    pub name: String,
+    pub method: String,
+    pub weight: u32,
+    pub total_requests: u64,
+    pub successful_requests: u64,
+    pub failed_requests: u64,
+    pub assertion_failed_requests: u64,
+    pub latencies: Vec<Duration>,
+}
+
+impl EndpointMetrics {
+    pub fn new(endpoint: &ApiEndpoint, weight: u32) -> Self {
+        Self { name: endpoint.name.clone(), method: endpoint.method.to_string(), weight, ..Default::default() }
+    }
+
+    fn record(&mut self, outcome: Outcome, latency: Duration) {
+        self.total_requests += 1;
+        match outcome {
+            Outcome::Success => self.successful_requests += 1,
+            Outcome::Failure => self.failed_requests += 1,
+            Outcome::AssertionFailure => self.assertion_failed_requests += 1,
+        }
+        self.latencies.push(latency);
+    }
+}
+
 /// Metrics collected during a load test
 #[derive(Debug, Clone)]
 pub struct LoadTestMetrics {
     pub total_requests: u64,
     pub successful_requests: u64,
-    pub failed_requests: u64,
+    pub failed_requests: u64, // No response: connection errors, timeouts, ...
     pub error_counts: HashMap<String, u64>,
+    pub assertion_failed_requests: u64, // Got a response, but an endpoint assertion failed
+    pub assertion_failure_counts: HashMap<String, u64>, // Keyed by the failing assertion
+    pub chaos_counts: HashMap<String, u64>, // Injected faults by kind; dropped requests aren't counted above
     pub latencies: Vec<Duration>,
     pub timestamps: Vec<Instant>,
     pub current_rps: f64,
-    pub time_series: Vec<TimeSeriesDataPoint>,
+    pub time_series: Vec<TimeSeriesDataPoint>, // The last minute, for the live charts
+    pub samples: Vec<TimeSeriesSample>, // Every point of the run, for its saved report
+    pub endpoints: Vec<EndpointMetrics>, // Per endpoint of a scenario, in its order; empty for single-endpoint runs
 }
 
 impl LoadTestMetrics {
@@ -38,10 +117,15 @@ impl LoadTestMetrics {
             successful_requests: 0,
             failed_requests: 0,
             error_counts: HashMap::new(),
+            assertion_failed_requests: 0,
+            assertion_failure_counts: HashMap::new(),
+            chaos_counts: HashMap::new(),
             latencies: Vec::new(),
             timestamps: Vec::new(),
             current_rps: 0.0,
             time_series: Vec::new(),
+            samples: Vec::new(),
+            endpoints: Vec::new(),
         }
     }
     
@@ -63,6 +147,39 @@ impl LoadTestMetrics {
         *self.error_counts.entry(error_type).or_insert(0) += 1;
     }
     
+    /// Record a response that failed one or more endpoint assertions
+    pub fn record_assertion_failure(&mut self, failed: Vec<String>, latency: Duration) {
+        self.total_requests += 1;
+        self.assertion_failed_requests += 1;
+        self.latencies.push(latency);
+        self.timestamps.push(Instant::now());
+        
+        for assertion in failed {
+            *self.assertion_failure_counts.entry(assertion).or_insert(0) += 1;
+        }
+    }
+    
+    /// Count a fault injected by the chaos options
+    pub fn record_chaos(&mut self, kind: &str) {
+        *self.chaos_counts.entry(kind.to_string()).or_insert(0) += 1;
+    }
+    
+    /// Count a request towards the scenario endpoint at `index`; totals are recorded separately
+    pub fn record_endpoint(&mut self, index: usize, outcome: Outcome, latency: Duration) {
+        if let Some(endpoint) = self.endpoints.get_mut(index) {
+            endpoint.record(outcome, latency);
+        }
+    }
+    
+    /// Failing assertions with their counts, most frequent first
+    pub fn assertion_breakdown(&self) -> Vec<(&str, u64)> {
+        let mut breakdown: Vec<(&str, u64)> = self.assertion_failure_counts.iter()
+            .map(|(assertion, count)| (assertion.as_str(), *count))
+            .collect();
+        breakdown.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        breakdown
+    }
+    
     /// Update current requests per second
     pub fn update_rps(&mut self, window_duration: Duration) {
         if self.timestamps.is_empty() {
@@ -73,10 +190,9 @@ impl LoadTestMetrics {
         let now = Instant::now();
         let cutoff = now - window_duration;
         
-        // Count requests in the time window
-        let recent_count = self.timestamps.iter()
-            .filter(|&&ts| ts >= cutoff)
-            .count();
+        // Older timestamps are never counted again; dropping them keeps long runs' memory flat
+        self.timestamps.retain(|&ts| ts >= cutoff);
+        let recent_count = self.timestamps.len();
         
         self.current_rps = recent_count as f64 / window_duration.as_secs_f64();
     }
@@ -100,6 +216,7 @@ impl LoadTestMetrics {
             request_count: self.total_requests,
         };
         
+        self.samples.push(TimeSeriesSample::from(&data_point));
         self.time_series.push(data_point);
         
         // Keep only last 12 data points (60 seconds of history)
@@ -143,6 +260,34 @@ impl MetricsCollector {
         }
     }
     
+    /// Count an injected fault
+    pub fn record_chaos(&self, kind: &str) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.record_chaos(kind);
+        }
+    }
+    
+    /// Break the run down by these endpoints
+    pub fn set_endpoints(&self, endpoints: Vec<EndpointMetrics>) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.endpoints = endpoints;
+        }
+    }
+    
+    /// Count a request towards one endpoint of the breakdown
+    pub fn record_endpoint(&self, index: usize, outcome: Outcome, latency: Duration) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.record_endpoint(index, outcome, latency);
+        }
+    }
+    
+    /// Record a response that failed endpoint assertions
+    pub fn record_assertion_failure(&self, failed: Vec<String>, latency: Duration) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.record_assertion_failure(failed, latency);
+        }
+    }
+    
     /// Update current RPS calculation
     pub fn update_rps(&self, window_duration: Duration) {
         if let Ok(mut metrics) = self.metrics.lock() {
@@ -243,6 +388,7 @@ pub struct LoadTestStatistics {
     pub total_requests: u64,
     pub success_rate: f64,
     pub error_rate: f64,
+    pub assertion_failure_rate: f64,
     pub avg_latency: Duration,
     pub min_latency: Duration,
     pub max_latency: Duration,
@@ -270,6 +416,12 @@ impl LoadTestStatistics {
             0.0
         };
         
+        let assertion_failure_rate = if metrics.total_requests > 0 {
+            metrics.assertion_failed_requests as f64 / metrics.total_requests as f64
+        } else {
+            0.0
+        };
+        
         let avg_latency = if !metrics.latencies.is_empty() {
             let total: Duration = metrics.latencies.iter().sum();
             total / metrics.latencies.len() as u32
@@ -287,6 +439,7 @@ impl LoadTestStatistics {
             total_requests: metrics.total_requests,
             success_rate,
             error_rate,
+            assertion_failure_rate,
             avg_latency,
             min_latency: percentiles.min,
             max_latency: percentiles.max,
@@ -299,6 +452,96 @@ impl LoadTestStatistics {
     }
 }
 
+/// Header name and a builder for its malformed value
+type MalformedHeader = (&'static str, fn() -> String);
+
+/// Headers that are legal enough to send but odd enough to exercise a server's parsing
+const MALFORMED_HEADERS: [MalformedHeader; 4] = [
+    ("Content-Type", || "application/json;;charset==".to_string()),
+    ("Accept", || "*/*;q=bogus, text/html;q=".to_string()),
+    ("X-Chaos-Oversized", || "A".repeat(64 * 1024)),
+    ("X-Chaos-Non-Ascii", || "ÿ\u{80}\u{fffd}".to_string()),
+];
+
+/// What to do to one request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChaosPlan {
+    pub delay: Option<Duration>,
+    pub drop_after: Option<Duration>, // Give up on the request after this long
+    pub malformed_header: Option<(String, String)>,
+}
+
+impl ChaosConfig {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut chaos = Self::default();
+        for part in spec.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+            let words: Vec<&str> = part.split_whitespace().collect();
+            let percent = |word: &str| word.strip_suffix('%')
+                .and_then(|n| n.parse::<u8>().ok())
+                .filter(|n| *n <= 100)
+                .ok_or_else(|| format!("Invalid chaos option '{}': expected a percentage like 10%", part));
+            match words.as_slice() {
+                ["delay", ms, share] => {
+                    chaos.delay_ms = ms.trim_end_matches("ms").parse()
+                        .map_err(|_| format!("Invalid chaos option '{}': expected a delay like 200ms", part))?;
+                    chaos.delay_percent = percent(share)?;
+                }
+                ["drop", share] => chaos.drop_percent = percent(share)?,
+                ["malformed", share] => chaos.malformed_percent = percent(share)?,
+                _ => return Err(format!(
+                    "Invalid chaos option '{}': use `delay 200ms 10%`, `drop 5%` or `malformed 2%`",
+                    part,
+                )),
+            }
+        }
+        Ok(chaos)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        (self.delay_percent > 0 && self.delay_ms > 0) || self.drop_percent > 0 || self.malformed_percent > 0
+    }
+
+    /// Roll the dice for one request
+    pub fn plan(&self) -> ChaosPlan {
+        let mut rng = rand::thread_rng();
+        let mut hit = |percent: u8| percent > 0 && rng.gen_range(0..100) < percent;
+        let delay = hit(self.delay_percent).then(|| Duration::from_millis(self.delay_ms)).filter(|d| !d.is_zero());
+        let drop = hit(self.drop_percent);
+        let malformed = hit(self.malformed_percent);
+        ChaosPlan {
+            delay,
+            drop_after: drop.then(|| Duration::from_millis(rand::thread_rng().gen_range(1..=50))),
+            malformed_header: malformed.then(|| {
+                let (name, value) = MALFORMED_HEADERS[rand::thread_rng().gen_range(0..MALFORMED_HEADERS.len())];
+                (name.to_string(), value())
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for ChaosConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if self.delay_percent > 0 && self.delay_ms > 0 {
+            parts.push(format!("delay {}ms {}%", self.delay_ms, self.delay_percent));
+        }
+        if self.drop_percent > 0 {
+            parts.push(format!("drop {}%", self.drop_percent));
+        }
+        if self.malformed_percent > 0 {
+            parts.push(format!("malformed {}%", self.malformed_percent));
+        }
+        write!(f, "{}", parts.join("; "))
+    }
+}
+
+/// Longest regular run
+pub const MAX_DURATION_SECS: u64 = 3600;
+/// Longest soak run (three days)
+pub const MAX_SOAK_DURATION_SECS: u64 = 72 * 3600;
+/// How often a soak run's results are written to disk while it runs
+pub const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Load test configuration
 #[derive(Debug, Clone)]
 pub struct LoadTestConfig {
@@ -306,6 +549,12 @@ pub struct LoadTestConfig {
     pub duration: Duration,
     pub rate_limit: Option<usize>, // requests per second
     pub ramp_up: Option<Duration>,
+    pub client_config: Option<HttpClientConfig>, // None = inherit the interactive client
+    pub headers: HashMap<String, String>, // Added to every request, overriding the endpoint's
+    pub har_samples: usize, // Exchanges to keep as a HAR file, 0 = none
+    pub cool_down: Duration, // Pause before the next queued run starts
+    pub soak: bool, // Long run: longer durations allowed, progress checkpointed to disk
+    pub chaos: ChaosConfig,
 }
 
 impl LoadTestConfig {
@@ -315,6 +564,12 @@ impl LoadTestConfig {
             duration,
             rate_limit: None,
             ramp_up: None,
+            client_config: None,
+            headers: HashMap::new(),
+            har_samples: 0,
+            cool_down: Duration::ZERO,
+            soak: false,
+            chaos: ChaosConfig::default(),
         }
     }
     
@@ -328,14 +583,59 @@ impl LoadTestConfig {
         self
     }
     
+    pub fn with_client_config(mut self, client_config: HttpClientConfig) -> Self {
+        self.client_config = Some(client_config);
+        self
+    }
+    
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+    
+    pub fn with_har_samples(mut self, samples: usize) -> Self {
+        self.har_samples = samples;
+        self
+    }
+    
+    pub fn with_cool_down(mut self, cool_down: Duration) -> Self {
+        self.cool_down = cool_down;
+        self
+    }
+    
+    pub fn with_soak(mut self, soak: bool) -> Self {
+        self.soak = soak;
+        self
+    }
+    
+    pub fn with_chaos(mut self, chaos: ChaosConfig) -> Self {
+        self.chaos = chaos;
+        self
+    }
+    
+    /// Headers to send with every request of this run: the endpoint's own,
+    /// with same-named ones (ignoring case) replaced by the run's
+    pub fn request_headers(&self, endpoint_headers: &HashMap<String, String>) -> HashMap<String, String> {
+        self.headers.iter()
+            .map(|(name, value)| {
+                // Reuse the endpoint's spelling so the override replaces it rather than doubling up
+                let name = endpoint_headers.keys()
+                    .find(|key| key.eq_ignore_ascii_case(name))
+                    .unwrap_or(name);
+                (name.clone(), value.clone())
+            })
+            .collect()
+    }
+    
     /// Validate configuration
     pub fn validate(&self) -> Result<(), String> {
         if self.concurrency == 0 || self.concurrency > 1000 {
             return Err("Concurrency must be between 1 and 1000".to_string());
         }
         
-        if self.duration.as_secs() == 0 || self.duration.as_secs() > 3600 {
-            return Err("Duration must be between 1 and 3600 seconds".to_string());
+        let max_duration = if self.soak { MAX_SOAK_DURATION_SECS } else { MAX_DURATION_SECS };
+        if self.duration.as_secs() == 0 || self.duration.as_secs() > max_duration {
+            return Err(format!("Duration must be between 1 and {} seconds", max_duration));
         }
         
         if let Some(rate) = self.rate_limit {
@@ -350,6 +650,24 @@ impl LoadTestConfig {
             }
         }
         
+        if let Some(client_config) = &self.client_config {
+            if client_config.timeout.as_secs() == 0 || client_config.timeout.as_secs() > 300 {
+                return Err("Request timeout must be between 1 and 300 seconds".to_string());
+            }
+            
+            if client_config.pool_max_idle_per_host > 1000 {
+                return Err("Connection pool size must be between 0 and 1000".to_string());
+            }
+        }
+        
+        if self.har_samples > 1000 {
+            return Err("HAR samples must be between 0 and 1000".to_string());
+        }
+        
+        if self.cool_down.as_secs() > 3600 {
+            return Err("Cool-down must be between 0 and 3600 seconds".to_string());
+        }
+        
         Ok(())
     }
     
@@ -369,6 +687,285 @@ impl LoadTestConfig {
     }
 }
 
+/// Parse run headers written as `Name: value; Other: value`
+pub fn parse_headers(spec: &str) -> Result<HashMap<String, String>, String> {
+    spec.split(';')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once(':') {
+            Some((name, value)) if !name.trim().is_empty() && !name.trim().contains(char::is_whitespace) => {
+                Ok((name.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(format!("Invalid header '{}': expected `Name: value`", pair)),
+        })
+        .collect()
+}
+
+/// Write run headers back in the form's `Name: value; Other: value` syntax
+pub fn format_headers(headers: &HashMap<String, String>) -> String {
+    let mut pairs: Vec<String> = headers.iter().map(|(name, value)| format!("{}: {}", name, value)).collect();
+    pairs.sort();
+    pairs.join("; ")
+}
+
+/// Template variable holding the request's number within its worker, from 1
+pub const ITERATION_VARIABLE: &str = "$iteration";
+/// Template variable holding the number of the worker sending the request, from 1
+pub const WORKER_ID_VARIABLE: &str = "$worker_id";
+
+/// Variables for one load test request, so generated entities can be traced
+/// back to the worker and iteration that created them
+pub fn iteration_variables(worker_id: usize, iteration: u64) -> HashMap<String, String> {
+    HashMap::from([
+        (WORKER_ID_VARIABLE.to_string(), worker_id.to_string()),
+        (ITERATION_VARIABLE.to_string(), iteration.to_string()),
+    ])
+}
+
+/// The endpoint a run was aimed at, recorded in its report
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoadTestTarget {
+    pub collection_id: Uuid,
+    pub endpoint_id: Uuid,
+    pub endpoint_name: String,
+    pub method: String,
+    pub url: String,
+}
+
+/// Latency summary in milliseconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub avg: f64,
+    pub min: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p95: f64,
+    pub p99: f64,
+    pub max: f64,
+}
+
+impl LatencySummary {
+    pub fn of(latencies: &[Duration]) -> Self {
+        let percentiles = calculate_percentiles(latencies);
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        let avg = if latencies.is_empty() {
+            0.0
+        } else {
+            latencies.iter().map(|&d| ms(d)).sum::<f64>() / latencies.len() as f64
+        };
+        Self {
+            avg,
+            min: ms(percentiles.min),
+            p50: ms(percentiles.p50),
+            p90: ms(percentiles.p90),
+            p95: ms(percentiles.p95),
+            p99: ms(percentiles.p99),
+            max: ms(percentiles.max),
+        }
+    }
+}
+
+/// One endpoint of a scenario run, as saved in its report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointSummary {
+    pub name: String,
+    pub method: String,
+    pub weight: u32,
+    pub total_requests: u64,
+    pub successful_requests: u64,
+    pub failed_requests: u64,
+    pub assertion_failed_requests: u64,
+    pub latency_ms: LatencySummary,
+}
+
+impl From<&EndpointMetrics> for EndpointSummary {
+    fn from(endpoint: &EndpointMetrics) -> Self {
+        Self {
+            name: endpoint.name.clone(),
+            method: endpoint.method.clone(),
+            weight: endpoint.weight,
+            total_requests: endpoint.total_requests,
+            successful_requests: endpoint.successful_requests,
+            failed_requests: endpoint.failed_requests,
+            assertion_failed_requests: endpoint.assertion_failed_requests,
+            latency_ms: LatencySummary::of(&endpoint.latencies),
+        }
+    }
+}
+
+/// Whether a saved run is complete
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RunStatus {
+    #[default]
+    Completed,
+    /// A checkpoint of a run that is still going
+    Running,
+    /// The app exited mid-run; the report holds the last checkpoint
+    Interrupted,
+}
+
+/// A run as saved in the results directory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoadTestReport {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub target: LoadTestTarget,
+    #[serde(default)]
+    pub status: RunStatus,
+    pub started_at: DateTime<Utc>,
+    pub duration_secs: f64,
+    #[serde(default)]
+    pub planned_duration_secs: u64,
+    pub concurrency: usize,
+    pub total_requests: u64,
+    pub successful_requests: u64,
+    pub failed_requests: u64,
+    pub assertion_failed_requests: u64,
+    pub error_counts: HashMap<String, u64>,
+    pub assertion_failure_counts: HashMap<String, u64>,
+    #[serde(default)]
+    pub chaos_counts: HashMap<String, u64>,
+    pub avg_rps: f64,
+    pub latency_ms: LatencySummary,
+    #[serde(default)]
+    pub time_series: Vec<TimeSeriesSample>,
+    #[serde(default)]
+    pub har_path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub endpoints: Vec<EndpointSummary>, // Breakdown of a scenario run
+}
+
+impl LoadTestReport {
+    pub fn new(target: LoadTestTarget, config: &LoadTestConfig, metrics: &LoadTestMetrics, elapsed: Duration) -> Self {
+        let stats = LoadTestStatistics::from_metrics(metrics, elapsed);
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        Self {
+            id: Uuid::new_v4(),
+            target,
+            status: RunStatus::Completed,
+            started_at: Utc::now() - chrono::Duration::from_std(elapsed).unwrap_or_default(),
+            duration_secs: elapsed.as_secs_f64(),
+            planned_duration_secs: config.duration.as_secs(),
+            concurrency: config.concurrency,
+            total_requests: metrics.total_requests,
+            successful_requests: metrics.successful_requests,
+            failed_requests: metrics.failed_requests,
+            assertion_failed_requests: metrics.assertion_failed_requests,
+            error_counts: metrics.error_counts.clone(),
+            assertion_failure_counts: metrics.assertion_failure_counts.clone(),
+            chaos_counts: metrics.chaos_counts.clone(),
+            avg_rps: stats.avg_rps,
+            latency_ms: LatencySummary {
+                avg: ms(stats.avg_latency),
+                min: ms(stats.min_latency),
+                p50: ms(stats.p50_latency),
+                p90: ms(stats.p90_latency),
+                p95: ms(stats.p95_latency),
+                p99: ms(stats.p99_latency),
+                max: ms(stats.max_latency),
+            },
+            time_series: metrics.samples.clone(),
+            har_path: None,
+            endpoints: metrics.endpoints.iter().map(EndpointSummary::from).collect(),
+        }
+    }
+}
+
+/// Changes smaller than this, in percent, count as noise between runs
+pub const UNCHANGED_PERCENT: f64 = 1.0;
+
+/// Which way a metric moved between two runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Improved,
+    Regressed,
+    Unchanged,
+}
+
+/// One metric of a baseline run and a later one
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricDelta {
+    pub name: &'static str,
+    pub unit: &'static str,
+    pub before: f64,
+    pub after: f64,
+    pub lower_is_better: bool,
+}
+
+impl MetricDelta {
+    pub fn change(&self) -> f64 {
+        self.after - self.before
+    }
+
+    /// Change relative to the baseline; None when the baseline is zero
+    pub fn percent(&self) -> Option<f64> {
+        (self.before != 0.0).then(|| self.change() / self.before * 100.0)
+    }
+
+    pub fn trend(&self) -> Trend {
+        let moved = match self.percent() {
+            Some(percent) => percent.abs() >= UNCHANGED_PERCENT,
+            None => self.after != 0.0,
+        };
+        match (moved, (self.change() < 0.0) == self.lower_is_better) {
+            (false, _) => Trend::Unchanged,
+            (true, true) => Trend::Improved,
+            (true, false) => Trend::Regressed,
+        }
+    }
+}
+
+/// Throughput, error rate and latencies of `after` against the `before` baseline
+pub fn compare_reports(before: &LoadTestReport, after: &LoadTestReport) -> Vec<MetricDelta> {
+    let error_rate = |r: &LoadTestReport| if r.total_requests > 0 {
+        (r.failed_requests + r.assertion_failed_requests) as f64 / r.total_requests as f64 * 100.0
+    } else {
+        0.0
+    };
+    let metric = |name, unit, before, after, lower_is_better| MetricDelta { name, unit, before, after, lower_is_better };
+    let (b, a) = (&before.latency_ms, &after.latency_ms);
+    vec![
+        metric("Requests/s", "", before.avg_rps, after.avg_rps, false),
+        metric("Error rate", "%", error_rate(before), error_rate(after), true),
+        metric("Latency avg", "ms", b.avg, a.avg, true),
+        metric("Latency p50", "ms", b.p50, a.p50, true),
+        metric("Latency p90", "ms", b.p90, a.p90, true),
+        metric("Latency p95", "ms", b.p95, a.p95, true),
+        metric("Latency p99", "ms", b.p99, a.p99, true),
+        metric("Latency max", "ms", b.max, a.max, true),
+    ]
+}
+
+/// Each endpoint's share of the requests, e.g. "70% List items, 30% Create item"
+pub fn describe_mix(endpoints: &[(ApiEndpoint, u32)]) -> String {
+    let total: u32 = endpoints.iter().map(|(_, weight)| weight).sum();
+    endpoints.iter()
+        .map(|(endpoint, weight)| format!("{:.0}% {}", share(*weight, total), endpoint.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `weight` out of `total`, in percent
+pub fn share(weight: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        weight as f64 / total as f64 * 100.0
+    }
+}
+
+/// Index of the weight a roll in `0..sum of weights` lands on
+pub fn pick_weighted(weights: &[u32], roll: u32) -> usize {
+    let mut end = 0;
+    for (index, weight) in weights.iter().enumerate() {
+        end += weight;
+        if roll < end {
+            return index;
+        }
+    }
+    weights.len().saturating_sub(1)
+}
+
 /// Load test engine for executing concurrent HTTP requests
 pub struct LoadTestEngine {
     collector: MetricsCollector,
@@ -376,6 +973,12 @@ pub struct LoadTestEngine {
     config: LoadTestConfig,
     start_time: Arc<Mutex<Option<Instant>>>,
     is_running: Arc<Mutex<bool>>,
+    har_path: Option<PathBuf>,
+    target: Option<LoadTestTarget>,
+    reported: bool, // The finished run's report has been saved
+    run_id: Uuid, // Shared by the run's checkpoints and final report, so they land in one file
+    started_at: DateTime<Utc>,
+    last_checkpoint: Option<Instant>,
 }
 
 impl LoadTestEngine {
@@ -387,6 +990,12 @@ impl LoadTestEngine {
             config,
             start_time: Arc::new(Mutex::new(None)),
             is_running: Arc::new(Mutex::new(false)),
+            har_path: None,
+            target: None,
+            reported: false,
+            run_id: Uuid::new_v4(),
+            started_at: Utc::now(),
+            last_checkpoint: None,
         })
     }
     
@@ -435,6 +1044,11 @@ impl LoadTestEngine {
         }
     }
     
+    /// The flag workers poll; clearing it (or `stop`) ends the run
+    pub fn running_flag(&self) -> Arc<Mutex<bool>> {
+        self.is_running.clone()
+    }
+    
     /// Get the collector for recording metrics
     pub fn collector(&self) -> MetricsCollector {
         self.collector.clone()
@@ -444,6 +1058,233 @@ impl LoadTestEngine {
     pub fn config(&self) -> &LoadTestConfig {
         &self.config
     }
+    
+    /// Where sampled exchanges are written when the run ends
+    pub fn set_har_path(&mut self, path: PathBuf) {
+        self.har_path = Some(path);
+    }
+    
+    pub fn har_path(&self) -> Option<&Path> {
+        self.har_path.as_deref()
+    }
+    
+    pub fn set_target(&mut self, target: LoadTestTarget) {
+        self.target = Some(target);
+    }
+    
+    /// Report for a finished run, once; None while it runs or when already taken
+    pub fn take_report(&mut self) -> Option<LoadTestReport> {
+        if self.reported || self.is_running() {
+            return None;
+        }
+        self.reported = true;
+        self.report(RunStatus::Completed)
+    }
+    
+    /// Progress so far of a soak run that is due a checkpoint
+    pub fn take_checkpoint(&mut self) -> Option<LoadTestReport> {
+        let due = self.last_checkpoint.is_none_or(|at| at.elapsed() >= CHECKPOINT_INTERVAL);
+        if !self.config.soak || !self.is_running() || !due {
+            return None;
+        }
+        self.last_checkpoint = Some(Instant::now());
+        self.report(RunStatus::Running)
+    }
+    
+    fn report(&self, status: RunStatus) -> Option<LoadTestReport> {
+        let target = self.target.clone()?;
+        let elapsed = self.elapsed().min(self.config.duration);
+        let mut report = LoadTestReport::new(target, &self.config, &self.metrics(), elapsed);
+        report.id = self.run_id;
+        report.started_at = self.started_at;
+        report.status = status;
+        report.har_path = self.har_path.clone();
+        Some(report)
+    }
+    
+    /// Whether the run is over and its report has been taken
+    pub fn is_reported(&self) -> bool {
+        self.reported
+    }
+
+    /// The run itself, for the caller to drive on a tokio runtime: workers
+    /// send `endpoint` until the duration is up or the run is stopped, then
+    /// sampled exchanges are written and the running flag is cleared.
+    /// `variables` apply to every request, under each iteration's own
+    pub fn workers(
+        &self,
+        endpoint: ApiEndpoint,
+        http_client: HttpClient,
+        variables: HashMap<String, String>,
+    ) -> impl Future<Output = ()> + Send + 'static {
+        self.scenario_workers(vec![(endpoint, 1)], http_client, variables)
+    }
+
+    /// Like `workers`, but every request goes to one of `endpoints`, picked
+    /// by weight; with more than one, the metrics break the run down by endpoint
+    pub fn scenario_workers(
+        &self,
+        endpoints: Vec<(ApiEndpoint, u32)>,
+        http_client: HttpClient,
+        variables: HashMap<String, String>,
+    ) -> impl Future<Output = ()> + Send + 'static {
+        let config = self.config.clone();
+        let collector = self.collector.clone();
+        let is_running = self.is_running.clone();
+        let har_path = self.har_path.clone();
+        let breakdown = endpoints.len() > 1;
+        if breakdown {
+            collector.set_endpoints(endpoints.iter().map(|(endpoint, weight)| EndpointMetrics::new(endpoint, *weight)).collect());
+        }
+        let weights: Vec<u32> = endpoints.iter().map(|(_, weight)| *weight).collect();
+        // Each endpoint with its own headers under the run's
+        let targets: Arc<Vec<(ApiEndpoint, HashMap<String, String>)>> = Arc::new(endpoints.into_iter()
+            .map(|(endpoint, _)| {
+                let headers = config.request_headers(&endpoint.headers);
+                (endpoint, headers)
+            })
+            .collect());
+        async move {
+            let start = Instant::now();
+            let sampler = Arc::new(Mutex::new(HarSampler::new(config.har_samples)));
+            let mut handles = vec![];
+
+            // Spawn concurrent tasks based on ramp-up
+            for worker_id in 0..config.concurrency {
+                let targets = targets.clone();
+                let weights = weights.clone();
+                let variables = variables.clone();
+                let sampler = sampler.clone();
+                let chaos = config.chaos.clone();
+                let http_client = http_client.clone();
+                let collector = collector.clone();
+                let is_running = is_running.clone();
+                let duration = config.duration;
+                let ramp_up = config.ramp_up;
+                let concurrency = config.concurrency;
+
+                let handle = tokio::spawn(async move {
+                    // Calculate delay for this worker based on ramp-up
+                    if let Some(ramp_up_duration) = ramp_up {
+                        let worker_delay = ramp_up_duration.as_secs_f64() * (worker_id as f64 / concurrency as f64);
+                        tokio::time::sleep(Duration::from_secs_f64(worker_delay)).await;
+                    }
+
+                    let total_weight: u32 = weights.iter().sum();
+                    let mut iteration = 0;
+                    while start.elapsed() < duration && *is_running.lock().unwrap() {
+                        iteration += 1;
+                        let index = if breakdown {
+                            pick_weighted(&weights, rand::thread_rng().gen_range(0..total_weight.max(1)))
+                        } else {
+                            0
+                        };
+                        let (endpoint, headers) = &targets[index];
+                        let plan = chaos.plan();
+                        if let Some(delay) = plan.delay {
+                            collector.record_chaos("delayed");
+                            tokio::time::sleep(delay).await;
+                        }
+                        let mut request_variables = variables.clone();
+                        request_variables.extend(iteration_variables(worker_id + 1, iteration));
+                        let mut inputs = RequestInputs {
+                            headers: headers.clone(),
+                            variables: request_variables,
+                            ..Default::default()
+                        };
+                        if let Some((name, value)) = plan.malformed_header {
+                            collector.record_chaos("malformed header");
+                            inputs.headers.insert(name, value);
+                        }
+                        let req_start = Instant::now();
+                        let started_at = Utc::now();
+
+                        let result = match plan.drop_after {
+                            // Abandon the request mid-flight, as a client that goes away would
+                            Some(after) => match tokio::time::timeout(after, http_client.execute(endpoint, &inputs)).await {
+                                Ok(result) => result,
+                                Err(_) => {
+                                    collector.record_chaos("dropped");
+                                    continue;
+                                }
+                            },
+                            None => http_client.execute(endpoint, &inputs).await,
+                        };
+                        let (outcome, latency) = match result {
+                            Ok(response) => {
+                                sampler.lock().unwrap().offer(&response, started_at);
+                                let failed: Vec<String> = endpoint
+                                    .failed_assertions(response.status.as_u16(), response.duration, &response.headers, &response.body)
+                                    .iter()
+                                    .map(|a| a.to_string())
+                                    .collect();
+                                if let Some(mismatch) = response.body_mismatch() {
+                                    // Grouped by kind, not by byte count
+                                    collector.record_failure(mismatch.kind().to_string(), response.duration);
+                                    (Outcome::Failure, response.duration)
+                                } else if failed.is_empty() {
+                                    collector.record_success(response.duration);
+                                    (Outcome::Success, response.duration)
+                                } else {
+                                    collector.record_assertion_failure(failed, response.duration);
+                                    (Outcome::AssertionFailure, response.duration)
+                                }
+                            }
+                            Err(e) => {
+                                collector.record_failure(e.to_string(), req_start.elapsed());
+                                (Outcome::Failure, req_start.elapsed())
+                            }
+                        };
+                        if breakdown {
+                            collector.record_endpoint(index, outcome, latency);
+                        }
+
+                        // Small delay to prevent overwhelming the server
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                });
+
+                handles.push(handle);
+            }
+
+            // Periodically update RPS
+            let collector_for_rps = collector.clone();
+            let is_running_for_rps = is_running.clone();
+            tokio::spawn(async move {
+                while *is_running_for_rps.lock().unwrap() {
+                    collector_for_rps.update_rps(Duration::from_secs(1));
+                    tokio::time::sleep(Duration::from_millis(500)).await;
+                }
+            });
+
+            // Periodically collect time-series data (every 5 seconds)
+            let collector_for_timeseries = collector.clone();
+            let is_running_for_timeseries = is_running.clone();
+            tokio::spawn(async move {
+                while *is_running_for_timeseries.lock().unwrap() {
+                    collector_for_timeseries.add_time_series_point(start);
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            });
+
+            // Wait for all tasks to complete
+            for handle in handles {
+                let _ = handle.await;
+            }
+            // The saved time series ends where the run did
+            collector.update_rps(Duration::from_secs(1));
+            collector.add_time_series_point(start);
+
+            // Keep the sampled exchanges; a failed write only loses the samples
+            if let Some(path) = har_path {
+                let sampler = std::mem::replace(&mut *sampler.lock().unwrap(), HarSampler::new(0));
+                let _ = sampler.into_har().save(&path);
+            }
+
+            // Mark as stopped
+            *is_running.lock().unwrap() = false;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -495,6 +1336,29 @@ mod tests {
         assert_eq!(metrics.error_counts.get("ConnectionRefused"), Some(&1));
     }
 
+    #[test]
+    fn test_assertion_failures_are_not_successes() {
+        let mut metrics = LoadTestMetrics::new();
+        metrics.record_success(Duration::from_millis(10));
+        metrics.record_failure("Timeout".to_string(), Duration::from_millis(5000));
+        metrics.record_assertion_failure(vec!["status == 200".to_string()], Duration::from_millis(20));
+        metrics.record_assertion_failure(
+            vec!["status == 200".to_string(), "latency <= 100ms".to_string()],
+            Duration::from_millis(300),
+        );
+        
+        assert_eq!(metrics.total_requests, 4);
+        assert_eq!(metrics.successful_requests, 1);
+        assert_eq!(metrics.failed_requests, 1);
+        assert_eq!(metrics.assertion_failed_requests, 2);
+        assert_eq!(metrics.assertion_breakdown(), vec![("status == 200", 2), ("latency <= 100ms", 1)]);
+        
+        let stats = LoadTestStatistics::from_metrics(&metrics, Duration::from_secs(1));
+        assert_eq!(stats.success_rate, 0.25);
+        assert_eq!(stats.error_rate, 0.25);
+        assert_eq!(stats.assertion_failure_rate, 0.5);
+    }
+
     #[test]
     fn test_metrics_collector_thread_safe() {
         let collector = MetricsCollector::new();
@@ -667,6 +1531,256 @@ mod tests {
         assert_eq!(stats.avg_rps, 100.0);
     }
     
+    #[test]
+    fn test_config_validate_client_overrides() {
+        let config = LoadTestConfig::new(10, Duration::from_secs(30))
+            .with_client_config(HttpClientConfig {
+                timeout: Duration::from_secs(5),
+                pool_max_idle_per_host: 50,
+                http1_only: true,
+                disable_compression: true,
+                ..HttpClientConfig::default()
+            });
+        assert!(config.validate().is_ok());
+        
+        let config = LoadTestConfig::new(10, Duration::from_secs(30))
+            .with_client_config(HttpClientConfig {
+                timeout: Duration::from_secs(0),
+                ..HttpClientConfig::default()
+            });
+        assert!(config.validate().is_err());
+        
+        let config = LoadTestConfig::new(10, Duration::from_secs(30))
+            .with_client_config(HttpClientConfig {
+                pool_max_idle_per_host: 5000,
+                ..HttpClientConfig::default()
+            });
+        assert!(config.validate().is_err());
+    }
+    
+    #[test]
+    fn test_run_headers() {
+        let headers = parse_headers("X-Load-Test: true; x-tenant: acme ;").unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(parse_headers(&format_headers(&headers)).unwrap(), headers);
+        assert!(parse_headers("no colon").is_err());
+        assert!(parse_headers("Bad Name: x").is_err());
+        
+        let config = LoadTestConfig::new(1, Duration::from_secs(1)).with_headers(headers);
+        let endpoint_headers = HashMap::from([("X-Tenant".to_string(), "default".to_string())]);
+        let sent = config.request_headers(&endpoint_headers);
+        assert_eq!(sent.get("X-Tenant").map(String::as_str), Some("acme"));
+        assert_eq!(sent.get("X-Load-Test").map(String::as_str), Some("true"));
+        assert!(!sent.contains_key("x-tenant"));
+    }
+
+    #[test]
+    fn test_iteration_variables() {
+        let variables = iteration_variables(3, 42);
+        let body = crate::template::substitute(r#"{"name": "lt-{{$worker_id}}-{{ $iteration }}"}"#, &variables).unwrap();
+        assert_eq!(body, r#"{"name": "lt-3-42"}"#);
+    }
+    
+    #[test]
+    fn test_report_taken_once_after_run() {
+        let mut engine = LoadTestEngine::new(LoadTestConfig::new(2, Duration::from_secs(5))).unwrap();
+        engine.set_target(LoadTestTarget {
+            collection_id: Uuid::new_v4(),
+            endpoint_id: Uuid::new_v4(),
+            endpoint_name: "list".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/".to_string(),
+        });
+        engine.set_start_time(Instant::now());
+        engine.set_running(true);
+        engine.collector().record_success(Duration::from_millis(40));
+        assert!(engine.take_report().is_none());
+        
+        *engine.running_flag().lock().unwrap() = false;
+        let report = engine.take_report().unwrap();
+        assert_eq!(report.total_requests, 1);
+        assert_eq!(report.concurrency, 2);
+        assert_eq!(report.latency_ms.max, 40.0);
+        assert!(engine.is_reported());
+        assert!(engine.take_report().is_none());
+        
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"endpoint_name\":\"list\""));
+        assert_eq!(serde_json::from_str::<LoadTestReport>(&json).unwrap(), report);
+    }
+    
+    #[test]
+    fn test_compare_reports() {
+        let target = LoadTestTarget {
+            collection_id: Uuid::new_v4(),
+            endpoint_id: Uuid::new_v4(),
+            endpoint_name: "list".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/".to_string(),
+        };
+        let config = LoadTestConfig::new(1, Duration::from_secs(10));
+        let run = |latency_ms: u64, failures: u64| {
+            let mut metrics = LoadTestMetrics::new();
+            for _ in 0..100 {
+                metrics.record_success(Duration::from_millis(latency_ms));
+            }
+            for _ in 0..failures {
+                metrics.record_failure("Timeout".to_string(), Duration::from_millis(latency_ms));
+            }
+            LoadTestReport::new(target.clone(), &config, &metrics, Duration::from_secs(10))
+        };
+        let before = run(100, 0);
+        let after = run(150, 25);
+        let deltas = compare_reports(&before, &after);
+        let by_name = |name: &str| deltas.iter().find(|d| d.name == name).unwrap().clone();
+
+        // More requests per second is better; here the later run sent more
+        let rps = by_name("Requests/s");
+        assert_eq!((rps.before, rps.after), (10.0, 12.5));
+        assert_eq!(rps.percent(), Some(25.0));
+        assert_eq!(rps.trend(), Trend::Improved);
+        // From no errors to some has no percentage but is still a regression
+        let errors = by_name("Error rate");
+        assert_eq!((errors.before, errors.after, errors.percent()), (0.0, 20.0, None));
+        assert_eq!(errors.trend(), Trend::Regressed);
+        let p95 = by_name("Latency p95");
+        assert_eq!(p95.percent(), Some(50.0));
+        assert_eq!(p95.trend(), Trend::Regressed);
+        assert_eq!(compare_reports(&before, &before).iter().map(|d| d.trend()).collect::<Vec<_>>(), vec![Trend::Unchanged; 8]);
+        assert_eq!(compare_reports(&after, &before)[7].trend(), Trend::Improved);
+    }
+    
+    #[test]
+    fn test_report_keeps_whole_time_series() {
+        let start = Instant::now();
+        let mut metrics = LoadTestMetrics::new();
+        for i in 0..15 {
+            metrics.record_success(Duration::from_millis(10 + i));
+            metrics.add_time_series_point(start);
+        }
+        // The charts only show the last minute; the report has every point
+        assert_eq!(metrics.time_series.len(), 12);
+        assert_eq!(metrics.samples.len(), 15);
+        let target = LoadTestTarget {
+            collection_id: Uuid::new_v4(),
+            endpoint_id: Uuid::new_v4(),
+            endpoint_name: "list".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/".to_string(),
+        };
+        let report = LoadTestReport::new(target, &LoadTestConfig::new(1, Duration::from_secs(75)), &metrics, Duration::from_secs(75));
+        assert_eq!(report.time_series.len(), 15);
+        assert_eq!(report.time_series[14].request_count, 15);
+        assert_eq!(report.time_series[0].p50_ms, 10.0);
+        
+        // Reports saved before time series were kept still load
+        let mut json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        json.as_object_mut().unwrap().remove("time_series");
+        let loaded: LoadTestReport = serde_json::from_value(json).unwrap();
+        assert!(loaded.time_series.is_empty());
+    }
+    
+    #[test]
+    fn test_pick_weighted() {
+        let picks: Vec<usize> = (0..10).map(|roll| pick_weighted(&[7, 0, 3], roll)).collect();
+        assert_eq!(picks, vec![0, 0, 0, 0, 0, 0, 0, 2, 2, 2]);
+        assert_eq!(pick_weighted(&[1], 0), 0);
+    }
+
+    #[test]
+    fn test_scenario_run_breaks_down_by_endpoint() {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            for mut socket in listener.incoming().flatten() {
+                let mut buf = [0u8; 1024];
+                let n = socket.read(&mut buf).unwrap_or(0);
+                let status = if buf[..n].starts_with(b"POST") { "201 Created" } else { "200 OK" };
+                let _ = socket.write_all(format!("HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status).as_bytes());
+            }
+        });
+        let url = format!("http://127.0.0.1:{}/items", port);
+        let list = ApiEndpoint::new("List items".to_string(), crate::models::HttpMethod::GET, url.clone());
+        let mut create = ApiEndpoint::new("Create item".to_string(), crate::models::HttpMethod::POST, url);
+        create.assertions = vec![crate::models::Assertion::parse("status == 200").unwrap()];
+
+        let mut engine = LoadTestEngine::new(LoadTestConfig::new(2, Duration::from_secs(1))).unwrap();
+        engine.set_target(LoadTestTarget {
+            collection_id: Uuid::new_v4(),
+            endpoint_id: Uuid::new_v4(),
+            endpoint_name: "Shop scenario".to_string(),
+            method: "MIX".to_string(),
+            url: "70% List items, 30% Create item".to_string(),
+        });
+        engine.set_start_time(Instant::now());
+        engine.set_running(true);
+        let workers = engine.scenario_workers(vec![(list, 7), (create, 3)], HttpClient::new().unwrap(), HashMap::new());
+        tokio::runtime::Runtime::new().unwrap().block_on(workers);
+
+        let metrics = engine.metrics();
+        let names: Vec<(&str, u32)> = metrics.endpoints.iter().map(|e| (e.name.as_str(), e.weight)).collect();
+        assert_eq!(names, vec![("List items", 7), ("Create item", 3)]);
+        let (list, create) = (&metrics.endpoints[0], &metrics.endpoints[1]);
+        assert_eq!(list.total_requests + create.total_requests, metrics.total_requests);
+        assert!(list.total_requests > create.total_requests, "{} vs {}", list.total_requests, create.total_requests);
+        assert_eq!(list.successful_requests, list.total_requests);
+        // Only the POSTs fail their assertion
+        assert_eq!(create.assertion_failed_requests, create.total_requests);
+        assert_eq!(metrics.assertion_failed_requests, create.total_requests);
+
+        let report = engine.take_report().unwrap();
+        assert_eq!(report.endpoints.len(), 2);
+        assert_eq!(report.endpoints[1].method, "POST");
+        assert_eq!(report.endpoints[0].total_requests, metrics.endpoints[0].total_requests);
+    }
+
+    #[test]
+    fn test_soak_checkpoints() {
+        assert!(LoadTestConfig::new(1, Duration::from_secs(4 * 3600)).validate().is_err());
+        let config = LoadTestConfig::new(1, Duration::from_secs(4 * 3600)).with_soak(true);
+        assert!(config.validate().is_ok());
+        
+        let mut engine = LoadTestEngine::new(config).unwrap();
+        engine.set_target(LoadTestTarget {
+            collection_id: Uuid::new_v4(),
+            endpoint_id: Uuid::new_v4(),
+            endpoint_name: "soak".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/".to_string(),
+        });
+        engine.set_start_time(Instant::now());
+        engine.set_running(true);
+        let checkpoint = engine.take_checkpoint().unwrap();
+        assert_eq!(checkpoint.status, RunStatus::Running);
+        assert_eq!(checkpoint.planned_duration_secs, 4 * 3600);
+        // Not due again until the interval has passed
+        assert!(engine.take_checkpoint().is_none());
+        
+        engine.stop();
+        let report = engine.take_report().unwrap();
+        assert_eq!((report.id, report.started_at), (checkpoint.id, checkpoint.started_at));
+        assert_eq!(report.status, RunStatus::Completed);
+    }
+    
+    #[test]
+    fn test_chaos_config() {
+        let chaos = ChaosConfig::parse("delay 200ms 10%; drop 5% ; malformed 100%").unwrap();
+        assert_eq!(chaos, ChaosConfig { delay_ms: 200, delay_percent: 10, drop_percent: 5, malformed_percent: 100 });
+        assert_eq!(ChaosConfig::parse(&chaos.to_string()).unwrap(), chaos);
+        assert!(ChaosConfig::parse("drop 150%").is_err());
+        assert!(ChaosConfig::parse("explode 5%").is_err());
+        assert!(!ChaosConfig::parse("").unwrap().is_enabled());
+        
+        // At 100% every request gets the fault, at 0% none do
+        let plan = chaos.plan();
+        assert!(plan.malformed_header.is_some());
+        let plan = ChaosConfig { delay_ms: 200, delay_percent: 100, ..Default::default() }.plan();
+        assert_eq!(plan.delay, Some(Duration::from_millis(200)));
+        assert_eq!(plan.drop_after, None);
+        assert_eq!(ChaosConfig::default().plan(), ChaosPlan::default());
+    }
+    
     #[test]
     fn test_statistics_empty() {
         let metrics = LoadTestMetrics::new();
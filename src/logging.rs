@@ -0,0 +1,81 @@
+// Application log
+// `tracing` events go to app.log next to the collections directory instead of
+// the terminal, which the TUI owns. REST_API_TUI_LOG picks the level (default
+// info); the log viewer screen reads the file back.
+
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::Level;
+
+/// The previous log is kept as app.log.1 once the current one reaches this size
+const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Install the file logger; later calls (e.g. a second app state) are no-ops
+pub fn init(path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::metadata(path).is_ok_and(|m| m.len() > MAX_LOG_SIZE) {
+        fs::rename(path, path.with_extension("log.1"))?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let level = std::env::var("REST_API_TUI_LOG").ok()
+        .and_then(|level| level.parse::<Level>().ok())
+        .unwrap_or(Level::INFO);
+    let _ = tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .with_max_level(level)
+        .try_init();
+    Ok(())
+}
+
+/// One line of the log file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub level: Option<Level>, // None for continuation lines, e.g. of a multi-line message
+    pub text: String,
+}
+
+/// The last `max_lines` lines of the log, oldest first; a missing log is empty
+pub fn tail(path: &Path, max_lines: usize) -> io::Result<Vec<LogLine>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let lines: Vec<&str> = contents.lines().collect();
+    Ok(lines[lines.len().saturating_sub(max_lines)..].iter()
+        .map(|line| LogLine { level: level_of(line), text: line.to_string() })
+        .collect())
+}
+
+/// `2026-01-01T00:00:00.000000Z  WARN target: message` → WARN
+fn level_of(line: &str) -> Option<Level> {
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tail_reads_levels() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("app.log");
+        assert!(tail(&path, 10).unwrap().is_empty());
+
+        fs::write(&path, concat!(
+            "2026-01-01T00:00:00.000001Z  INFO rest_api_tui::storage: Loaded 3 collections\n",
+            "2026-01-01T00:00:01.000001Z  WARN rest_api_tui::storage: Skipping broken.json: expected value\n",
+            "  at line 1\n",
+            "2026-01-01T00:00:02.000001Z ERROR rest_api_tui::http: Request failed\n",
+        )).unwrap();
+        let lines = tail(&path, 3).unwrap();
+        let levels: Vec<Option<Level>> = lines.iter().map(|l| l.level).collect();
+        assert_eq!(levels, vec![Some(Level::WARN), None, Some(Level::ERROR)]);
+        assert!(lines[0].text.ends_with("Skipping broken.json: expected value"));
+    }
+}
@@ -1,6 +1,11 @@
+use clap::Parser;
+use rest_api_tui::cli::{self, Cli};
 use rest_api_tui::tui;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tui::run_app()?;
+    match Cli::parse().command {
+        Some(command) => std::process::exit(cli::run(command)),
+        None => tui::run_app()?,
+    }
     Ok(())
 }
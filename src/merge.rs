@@ -0,0 +1,215 @@
+// Collection merging
+// Compares a local collection with another version of it (e.g. an export a
+// teammate sent, or a fresh import of the spec it came from) endpoint by
+// endpoint and applies a mine/theirs/both choice per endpoint.
+
+use crate::models::{ApiCollection, ApiEndpoint};
+use chrono::Utc;
+use uuid::Uuid;
+
+/// How an endpoint differs between the two versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Unchanged,
+    Modified,
+    OnlyMine,
+    OnlyTheirs,
+}
+
+/// Which version of an endpoint ends up in the merged collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Mine,
+    Theirs,
+    Both,
+}
+
+/// One endpoint as it appears in either or both versions
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeItem {
+    pub mine: Option<ApiEndpoint>,
+    pub theirs: Option<ApiEndpoint>,
+    pub resolution: Resolution,
+}
+
+impl MergeItem {
+    fn new(mine: Option<ApiEndpoint>, theirs: Option<ApiEndpoint>) -> Self {
+        let mut item = Self { mine, theirs, resolution: Resolution::Mine };
+        // Pick up new endpoints by default; everything else keeps the local version
+        if item.change() == Change::OnlyTheirs {
+            item.resolution = Resolution::Theirs;
+        }
+        item
+    }
+
+    pub fn change(&self) -> Change {
+        match (&self.mine, &self.theirs) {
+            (Some(mine), Some(theirs)) => {
+                // Endpoints matched by name may have different IDs; that alone isn't a change
+                if *mine == (ApiEndpoint { id: mine.id, ..theirs.clone() }) {
+                    Change::Unchanged
+                } else {
+                    Change::Modified
+                }
+            }
+            (Some(_), None) => Change::OnlyMine,
+            _ => Change::OnlyTheirs,
+        }
+    }
+
+    /// Display name, preferring the local one
+    pub fn name(&self) -> &str {
+        self.mine.as_ref().or(self.theirs.as_ref()).map(|e| e.name.as_str()).unwrap_or_default()
+    }
+
+    fn resolved(&self) -> Vec<ApiEndpoint> {
+        match (self.resolution, &self.mine, &self.theirs) {
+            (Resolution::Both, Some(mine), Some(theirs)) if self.change() == Change::Modified => {
+                let mut theirs = theirs.clone();
+                theirs.id = Uuid::new_v4();
+                theirs.name = format!("{} (theirs)", theirs.name);
+                vec![mine.clone(), theirs]
+            }
+            (Resolution::Mine, mine, _) => mine.iter().cloned().collect(),
+            (Resolution::Theirs, _, theirs) => theirs.iter().cloned().collect(),
+            (Resolution::Both, mine, theirs) => mine.as_ref().or(theirs.as_ref()).cloned().into_iter().collect(),
+        }
+    }
+}
+
+/// Pair up endpoints by ID, then by method and name, keeping local order with
+/// endpoints only present in `theirs` at the end
+pub fn diff(mine: &ApiCollection, theirs: &ApiCollection) -> Vec<MergeItem> {
+    let mut unmatched: Vec<&ApiEndpoint> = theirs.endpoints.iter().collect();
+    let mut items = Vec::new();
+
+    for endpoint in &mine.endpoints {
+        let position = unmatched.iter()
+            .position(|e| e.id == endpoint.id)
+            .or_else(|| unmatched.iter().position(|e| e.method == endpoint.method && e.name == endpoint.name));
+        let other = position.map(|i| unmatched.remove(i).clone());
+        items.push(MergeItem::new(Some(endpoint.clone()), other));
+    }
+    for endpoint in unmatched {
+        items.push(MergeItem::new(None, Some(endpoint.clone())));
+    }
+    items
+}
+
+/// Pair up endpoints with a re-imported spec by method and URL, since the
+/// spec's endpoints carry new IDs and local names may have been edited.
+/// Endpoints the spec dropped are marked for removal; matched ones keep their
+/// local ID so history stays attached if the spec's version is taken.
+pub fn diff_for_sync(mine: &ApiCollection, spec: &ApiCollection) -> Vec<MergeItem> {
+    let mut unmatched: Vec<&ApiEndpoint> = spec.endpoints.iter().collect();
+    let mut items = Vec::new();
+
+    for endpoint in &mine.endpoints {
+        let position = unmatched.iter()
+            .position(|e| e.method == endpoint.method && same_url(&e.url, &endpoint.url));
+        let other = position.map(|i| ApiEndpoint { id: endpoint.id, ..unmatched.remove(i).clone() });
+        let mut item = MergeItem::new(Some(endpoint.clone()), other);
+        if item.change() == Change::OnlyMine {
+            item.resolution = Resolution::Theirs;
+        }
+        items.push(item);
+    }
+    for endpoint in unmatched {
+        items.push(MergeItem::new(None, Some(endpoint.clone())));
+    }
+    items
+}
+
+fn same_url(a: &str, b: &str) -> bool {
+    a.trim().trim_end_matches('/') == b.trim().trim_end_matches('/')
+}
+
+/// Build the merged collection; identity and name stay those of `mine`
+pub fn apply(mine: &ApiCollection, items: &[MergeItem]) -> ApiCollection {
+    let mut merged = mine.clone();
+    merged.endpoints = items.iter().flat_map(MergeItem::resolved).collect();
+    merged.updated_at = Utc::now();
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::HttpMethod;
+
+    fn endpoint(name: &str, url: &str) -> ApiEndpoint {
+        ApiEndpoint::new(name.to_string(), HttpMethod::GET, url.to_string())
+    }
+
+    fn versions() -> (ApiCollection, ApiCollection) {
+        let mut mine = ApiCollection::new("API".to_string());
+        mine.add_endpoint(endpoint("same", "https://a"));
+        mine.add_endpoint(endpoint("changed", "https://old"));
+        mine.add_endpoint(endpoint("local", "https://local"));
+
+        let mut theirs = mine.clone();
+        theirs.endpoints[1].url = "https://new".to_string();
+        theirs.endpoints.remove(2);
+        // Re-created on their side, so only the name matches
+        theirs.endpoints[0].id = Uuid::new_v4();
+        theirs.add_endpoint(endpoint("remote", "https://remote"));
+        (mine, theirs)
+    }
+
+    #[test]
+    fn test_diff_classifies_endpoints() {
+        let (mine, theirs) = versions();
+        let items = diff(&mine, &theirs);
+
+        let changes: Vec<(&str, Change, Resolution)> = items.iter()
+            .map(|i| (i.name(), i.change(), i.resolution))
+            .collect();
+        assert_eq!(changes, vec![
+            ("same", Change::Unchanged, Resolution::Mine),
+            ("changed", Change::Modified, Resolution::Mine),
+            ("local", Change::OnlyMine, Resolution::Mine),
+            ("remote", Change::OnlyTheirs, Resolution::Theirs),
+        ]);
+    }
+
+    #[test]
+    fn test_apply_resolutions() {
+        let (mine, theirs) = versions();
+        let mut items = diff(&mine, &theirs);
+        items[1].resolution = Resolution::Both;
+        items[2].resolution = Resolution::Theirs; // Accept their deletion
+
+        let merged = apply(&mine, &items);
+        assert_eq!(merged.id, mine.id);
+        let names: Vec<&str> = merged.endpoints.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["same", "changed", "changed (theirs)", "remote"]);
+        assert_eq!(merged.endpoints[1].url, "https://old");
+        assert_eq!(merged.endpoints[2].url, "https://new");
+        assert_ne!(merged.endpoints[2].id, merged.endpoints[1].id);
+    }
+
+    #[test]
+    fn test_diff_for_sync() {
+        let mut mine = ApiCollection::new("API".to_string());
+        mine.add_endpoint(endpoint("renamed locally", "https://a/users/"));
+        mine.add_endpoint(endpoint("dropped", "https://a/legacy"));
+        let mut spec = ApiCollection::new("API".to_string());
+        spec.add_endpoint(endpoint("List users", "https://a/users"));
+        spec.add_endpoint(endpoint("New", "https://a/orders"));
+
+        let items = diff_for_sync(&mine, &spec);
+        let changes: Vec<(&str, Change, Resolution)> = items.iter()
+            .map(|i| (i.name(), i.change(), i.resolution))
+            .collect();
+        assert_eq!(changes, vec![
+            ("renamed locally", Change::Modified, Resolution::Mine),
+            ("dropped", Change::OnlyMine, Resolution::Theirs),
+            ("New", Change::OnlyTheirs, Resolution::Theirs),
+        ]);
+
+        let synced = apply(&mine, &items);
+        let names: Vec<&str> = synced.endpoints.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["renamed locally", "New"]);
+        assert_eq!(items[0].theirs.as_ref().unwrap().id, mine.endpoints[0].id);
+    }
+}
@@ -1,10 +1,14 @@
+use crate::changelog::EndpointChange;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 use uuid::Uuid;
 
-/// HTTP methods supported by the application
+/// HTTP methods supported by the application. Extension methods (WebDAV's
+/// PROPFIND, a CDN's PURGE, ...) are `Custom`; every method is stored as its name.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
 pub enum HttpMethod {
     GET,
     POST,
@@ -13,6 +17,61 @@ pub enum HttpMethod {
     DELETE,
     HEAD,
     OPTIONS,
+    Custom(String),
+}
+
+impl HttpMethod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            HttpMethod::GET => "GET",
+            HttpMethod::POST => "POST",
+            HttpMethod::PUT => "PUT",
+            HttpMethod::PATCH => "PATCH",
+            HttpMethod::DELETE => "DELETE",
+            HttpMethod::HEAD => "HEAD",
+            HttpMethod::OPTIONS => "OPTIONS",
+            HttpMethod::Custom(name) => name,
+        }
+    }
+
+    /// Whether the name is a valid HTTP method token (RFC 9110)
+    pub fn is_valid(&self) -> bool {
+        let name = self.as_str();
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+    }
+
+    /// Whether the method only reads (GET, HEAD, OPTIONS); anything else may change data
+    pub fn is_safe(&self) -> bool {
+        matches!(self, HttpMethod::GET | HttpMethod::HEAD | HttpMethod::OPTIONS)
+    }
+}
+
+impl From<String> for HttpMethod {
+    fn from(name: String) -> Self {
+        let name = name.trim().to_ascii_uppercase();
+        match name.as_str() {
+            "GET" => HttpMethod::GET,
+            "POST" => HttpMethod::POST,
+            "PUT" => HttpMethod::PUT,
+            "PATCH" => HttpMethod::PATCH,
+            "DELETE" => HttpMethod::DELETE,
+            "HEAD" => HttpMethod::HEAD,
+            "OPTIONS" => HttpMethod::OPTIONS,
+            _ => HttpMethod::Custom(name),
+        }
+    }
+}
+
+impl From<HttpMethod> for String {
+    fn from(method: HttpMethod) -> Self {
+        method.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Location where API key should be placed
@@ -40,6 +99,426 @@ pub enum AuthConfig {
     },
 }
 
+/// SOAP envelope version, which decides how the action is sent
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SoapVersion {
+    V1_1,
+    V1_2,
+}
+
+/// Marks an endpoint as a SOAP operation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SoapConfig {
+    pub action: String,
+    pub version: SoapVersion,
+}
+
+impl SoapConfig {
+    /// Headers SOAP requires: a `SOAPAction` header for 1.1, an `action`
+    /// parameter on the content type for 1.2
+    pub fn headers(&self) -> Vec<(String, String)> {
+        match self.version {
+            SoapVersion::V1_1 => vec![
+                ("Content-Type".to_string(), "text/xml; charset=utf-8".to_string()),
+                ("SOAPAction".to_string(), format!("\"{}\"", self.action)),
+            ],
+            SoapVersion::V1_2 => vec![(
+                "Content-Type".to_string(),
+                format!("application/soap+xml; charset=utf-8; action=\"{}\"", self.action),
+            )],
+        }
+    }
+}
+
+/// What goes on the wire when an endpoint has no body template
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EmptyBody {
+    /// No body and no framing headers
+    #[default]
+    Omit,
+    /// A zero-length chunked body (`Transfer-Encoding: chunked`)
+    Empty,
+    /// No body, with an explicit `Content-Length: 0`
+    ContentLengthZero,
+}
+
+impl EmptyBody {
+    pub fn next(self) -> Self {
+        match self {
+            EmptyBody::Omit => EmptyBody::Empty,
+            EmptyBody::Empty => EmptyBody::ContentLengthZero,
+            EmptyBody::ContentLengthZero => EmptyBody::Omit,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EmptyBody::Omit => "omit body",
+            EmptyBody::Empty => "empty chunked body",
+            EmptyBody::ContentLengthZero => "Content-Length: 0",
+        }
+    }
+}
+
+/// GraphQL mode: the body is built from a query and its variables
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GraphqlBody {
+    pub query: String,
+    #[serde(default)]
+    pub variables: String, // JSON object, may contain {{variables}}; empty = none
+}
+
+impl GraphqlBody {
+    /// The `{"query": ..., "variables": ...}` payload for an already substituted
+    /// query and variables
+    pub fn payload(query: &str, variables: &str) -> Result<String, String> {
+        let mut payload = serde_json::json!({ "query": query });
+        if !variables.trim().is_empty() {
+            let variables: serde_json::Value = serde_json::from_str(variables).map_err(|e| e.to_string())?;
+            if !variables.is_object() {
+                return Err("variables must be a JSON object".to_string());
+            }
+            payload["variables"] = variables;
+        }
+        Ok(payload.to_string())
+    }
+}
+
+/// Protobuf message types for an endpoint's request and response bodies
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProtobufConfig {
+    pub proto_file: String,
+    pub request_message: Option<String>,  // JSON body is encoded as this type
+    pub response_message: Option<String>, // Binary response is decoded as this type
+}
+
+impl ProtobufConfig {
+    /// Parse the form's one-line spec: `file.proto [Request] [-> Response]`
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        let (file, rest) = spec.split_once(char::is_whitespace).unwrap_or((spec, ""));
+        if file.is_empty() {
+            return None;
+        }
+        let (request, response) = rest.split_once("->").unwrap_or((rest, ""));
+        let name = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+        Some(Self {
+            proto_file: file.to_string(),
+            request_message: name(request),
+            response_message: name(response),
+        })
+    }
+}
+
+impl std::fmt::Display for ProtobufConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.proto_file)?;
+        if let Some(request) = &self.request_message {
+            write!(f, " {}", request)?;
+        }
+        if let Some(response) = &self.response_message {
+            write!(f, " -> {}", response)?;
+        }
+        Ok(())
+    }
+}
+
+/// Faults injected client-side into a share of load test requests, to see how
+/// the target copes. Written in the form as `delay 200ms 10%; drop 5%; malformed 2%`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChaosConfig {
+    #[serde(default)]
+    pub delay_ms: u64,
+    #[serde(default)]
+    pub delay_percent: u8, // Requests held back by `delay_ms` before they are sent
+    #[serde(default)]
+    pub drop_percent: u8, // Requests abandoned before the response arrives
+    #[serde(default)]
+    pub malformed_percent: u8, // Requests sent with one malformed header
+}
+
+/// A check applied to every response of an endpoint. Written in the endpoint
+/// form as `status == 200; latency <= 500ms; body contains "ok"; header ETag exists;
+/// .data.id == 42; .tags contains "new"`. Body paths use the visualizer's jq subset.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Assertion {
+    Status { equals: u16 },
+    MaxLatency { ms: u64 },
+    BodyContains { text: String },
+    HeaderPresent { name: String },
+    JsonEquals { path: String, value: serde_json::Value },
+    JsonContains { path: String, text: String },
+}
+
+impl Assertion {
+    /// Parse a `;`-separated list of assertions
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(';')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(Self::parse)
+            .collect()
+    }
+
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        let (subject, rest) = spec.split_once(char::is_whitespace).unwrap_or((spec, ""));
+        let rest = rest.trim();
+        let unquote = |text: &str| text.strip_prefix('"').and_then(|t| t.strip_suffix('"')).unwrap_or(text).to_string();
+        if subject.starts_with('.') {
+            crate::visualize::Transform::parse(subject).map_err(|e| format!("'{}': {}", spec, e))?;
+            let path = subject.to_string();
+            if let Some(value) = rest.strip_prefix("==").map(str::trim).filter(|v| !v.is_empty()) {
+                let value = serde_json::from_str(value).unwrap_or_else(|_| serde_json::Value::String(unquote(value)));
+                return Ok(Assertion::JsonEquals { path, value });
+            }
+            if let Some(text) = rest.strip_prefix("contains").map(str::trim).filter(|t| !t.is_empty()) {
+                return Ok(Assertion::JsonContains { path, text: unquote(text) });
+            }
+            return Err(format!("'{}': expected `{} == value` or `{} contains \"text\"`", spec, path, path));
+        }
+        match subject.to_ascii_lowercase().as_str() {
+            "status" => {
+                let code = rest.trim_start_matches("==").trim();
+                code.parse::<u16>().ok()
+                    .filter(|code| (100..=599).contains(code))
+                    .map(|equals| Assertion::Status { equals })
+                    .ok_or_else(|| format!("'{}': expected a status code like `status == 200`", spec))
+            }
+            "latency" => {
+                let ms = rest.trim_start_matches("<=").trim_start_matches('<').trim();
+                ms.trim_end_matches("ms").trim().parse::<u64>().ok()
+                    .map(|ms| Assertion::MaxLatency { ms })
+                    .ok_or_else(|| format!("'{}': expected a limit like `latency <= 500ms`", spec))
+            }
+            "body" => {
+                let text = rest.strip_prefix("contains").map(str::trim).unwrap_or_default();
+                if text.is_empty() {
+                    return Err(format!("'{}': expected text like `body contains \"ok\"`", spec));
+                }
+                Ok(Assertion::BodyContains { text: unquote(text) })
+            }
+            "header" => {
+                match rest.split_whitespace().collect::<Vec<_>>()[..] {
+                    [name] | [name, "exists"] => Ok(Assertion::HeaderPresent { name: name.to_string() }),
+                    _ => Err(format!("'{}': expected a header name like `header ETag exists`", spec)),
+                }
+            }
+            _ => Err(format!("'{}': assertions start with status, latency, body, header or a .path", spec)),
+        }
+    }
+
+    /// Whether a response passes this check
+    pub fn passes(&self, status: u16, duration: Duration, headers: &HashMap<String, String>, body: &[u8]) -> bool {
+        match self {
+            Assertion::Status { equals } => status == *equals,
+            Assertion::MaxLatency { ms } => duration.as_millis() <= *ms as u128,
+            Assertion::BodyContains { text } => String::from_utf8_lossy(body).contains(text.as_str()),
+            Assertion::HeaderPresent { name } => headers.keys().any(|key| key.eq_ignore_ascii_case(name)),
+            Assertion::JsonEquals { path, value } => json_path_value(path, body).is_ok_and(|found| found.as_ref() == Some(value)),
+            Assertion::JsonContains { path, text } => match json_path_value(path, body) {
+                Ok(Some(serde_json::Value::String(found))) => found.contains(text.as_str()),
+                Ok(Some(serde_json::Value::Array(items))) => items.iter().any(|item| match item {
+                    serde_json::Value::String(item) => item == text,
+                    item => serde_json::from_str::<serde_json::Value>(text).is_ok_and(|expected| *item == expected),
+                }),
+                Ok(Some(found)) => found.to_string().contains(text.as_str()),
+                _ => false,
+            },
+        }
+    }
+
+    /// Write a list back in the form's syntax
+    pub fn format_list(assertions: &[Self]) -> String {
+        assertions.iter().map(|a| a.to_string()).collect::<Vec<_>>().join("; ")
+    }
+}
+
+impl std::fmt::Display for Assertion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Assertion::Status { equals } => write!(f, "status == {}", equals),
+            Assertion::MaxLatency { ms } => write!(f, "latency <= {}ms", ms),
+            Assertion::BodyContains { text } => write!(f, "body contains \"{}\"", text),
+            Assertion::HeaderPresent { name } => write!(f, "header {} exists", name),
+            Assertion::JsonEquals { path, value } => write!(f, "{} == {}", path, value),
+            Assertion::JsonContains { path, text } => write!(f, "{} contains \"{}\"", path, text),
+        }
+    }
+}
+
+/// The first value a jq-style path finds in a JSON body; `None` when it finds nothing
+fn json_path_value(path: &str, body: &[u8]) -> Result<Option<serde_json::Value>, String> {
+    let input: serde_json::Value = serde_json::from_slice(body).map_err(|e| format!("response is not JSON: {}", e))?;
+    let values = crate::visualize::Transform::parse(path)
+        .and_then(|transform| transform.apply(&input))
+        .map_err(|e| e.to_string())?;
+    Ok(values.into_iter().next())
+}
+
+/// A value taken from a response for the requests after it, e.g. a login's
+/// token. Written in the endpoint form as `token = .data.token; etag = header ETag`;
+/// body paths use the visualizer's jq subset.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Extraction {
+    pub variable: String,
+    #[serde(flatten)]
+    pub source: ExtractSource,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "from", rename_all = "snake_case")]
+pub enum ExtractSource {
+    Body { path: String },
+    Header { name: String },
+}
+
+impl Extraction {
+    /// Parse a `;`-separated list of extractions
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(';')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(Self::parse)
+            .collect()
+    }
+
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        let Some((variable, source)) = spec.split_once('=') else {
+            return Err(format!("'{}': expected `name = .path` or `name = header Name`", spec));
+        };
+        let (variable, source) = (variable.trim(), source.trim());
+        if variable.is_empty() || !variable.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+            return Err(format!("'{}': variable names are letters, digits, _ and -", spec));
+        }
+        let source = match source.split_once(char::is_whitespace) {
+            Some((keyword, name)) if keyword.eq_ignore_ascii_case("header") => ExtractSource::Header { name: name.trim().to_string() },
+            _ => {
+                crate::visualize::Transform::parse(source).map_err(|e| format!("'{}': {}", spec, e))?;
+                ExtractSource::Body { path: source.to_string() }
+            }
+        };
+        Ok(Extraction { variable: variable.to_string(), source })
+    }
+
+    /// The value in a response: a header, or the first result of the body path,
+    /// with strings unquoted
+    pub fn value(&self, headers: &HashMap<String, String>, body: &[u8]) -> Result<String, String> {
+        match &self.source {
+            ExtractSource::Header { name } => headers.iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.clone())
+                .ok_or_else(|| format!("no {} header", name)),
+            ExtractSource::Body { path } => {
+                match json_path_value(path, body)? {
+                    None | Some(serde_json::Value::Null) => Err(format!("{} matched nothing", path)),
+                    Some(serde_json::Value::String(text)) => Ok(text),
+                    Some(value) => Ok(value.to_string()),
+                }
+            }
+        }
+    }
+
+    /// Write a list back in the form's syntax
+    pub fn format_list(extractions: &[Self]) -> String {
+        extractions.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; ")
+    }
+}
+
+impl std::fmt::Display for Extraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            ExtractSource::Body { path } => write!(f, "{} = {}", self.variable, path),
+            ExtractSource::Header { name } => write!(f, "{} = header {}", self.variable, name),
+        }
+    }
+}
+
+/// Address family used to reach an endpoint's host
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum IpFamily {
+    /// Whatever the resolver returns first
+    #[default]
+    Any,
+    V4,
+    V6,
+}
+
+/// How an endpoint's requests connect. Written in the endpoint form as `ipv4; close; idle 5s`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConnectionOptions {
+    #[serde(default)]
+    pub ip_family: IpFamily,
+    #[serde(default)]
+    pub close: bool, // Send `Connection: close`
+    #[serde(default)]
+    pub fresh: bool, // Never reuse a pooled connection
+    #[serde(default)]
+    pub idle_timeout_secs: Option<u64>, // How long idle pooled connections are kept
+    #[serde(default)]
+    pub expect_continue: bool, // Send `Expect: 100-continue` and hold the body back
+    #[serde(default)]
+    pub interim: bool, // List 1xx responses before the final one, see `HttpClient::sends_direct`
+}
+
+impl ConnectionOptions {
+    /// Parse a `;`-separated list of options; empty means the defaults
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut options = Self::default();
+        for option in spec.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            match option.to_ascii_lowercase().as_str() {
+                "ipv4" => options.ip_family = IpFamily::V4,
+                "ipv6" => options.ip_family = IpFamily::V6,
+                "close" => options.close = true,
+                "fresh" => options.fresh = true,
+                "expect-continue" => options.expect_continue = true,
+                "interim" => options.interim = true,
+                other => {
+                    let secs = other.strip_prefix("idle")
+                        .map(|rest| rest.trim().trim_end_matches('s'))
+                        .and_then(|secs| secs.parse().ok());
+                    match secs {
+                        Some(secs) => options.idle_timeout_secs = Some(secs),
+                        None => return Err(format!(
+                            "'{}': expected ipv4, ipv6, close, fresh, expect-continue, interim or idle <seconds>s", option)),
+                    }
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+impl std::fmt::Display for ConnectionOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut options = Vec::new();
+        match self.ip_family {
+            IpFamily::Any => {}
+            IpFamily::V4 => options.push("ipv4".to_string()),
+            IpFamily::V6 => options.push("ipv6".to_string()),
+        }
+        if self.close {
+            options.push("close".to_string());
+        }
+        if self.fresh {
+            options.push("fresh".to_string());
+        }
+        if let Some(secs) = self.idle_timeout_secs {
+            options.push(format!("idle {}s", secs));
+        }
+        if self.expect_continue {
+            options.push("expect-continue".to_string());
+        }
+        if self.interim {
+            options.push("interim".to_string());
+        }
+        write!(f, "{}", options.join("; "))
+    }
+}
+
 /// A single API endpoint definition
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ApiEndpoint {
@@ -54,6 +533,26 @@ pub struct ApiEndpoint {
     pub load_test_config: Option<LoadTestConfigData>,
     #[serde(default)]
     pub timeout_secs: Option<u64>, // Request timeout in seconds
+    #[serde(default)]
+    pub tags: Vec<String>, // Free-form labels such as "smoke" or "slow"
+    #[serde(default)]
+    pub archived: bool, // Hidden from lists and collection runs, but kept on disk
+    #[serde(default)]
+    pub soap: Option<SoapConfig>, // SOAP mode: action headers are added on send
+    #[serde(default)]
+    pub protobuf: Option<ProtobufConfig>, // JSON bodies are sent as protobuf
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graphql: Option<GraphqlBody>, // GraphQL mode: sent as a query/variables JSON payload instead of the body
+    #[serde(default)]
+    pub empty_body: EmptyBody, // Used when there is no body template
+    #[serde(default)]
+    pub visualizer: Option<String>, // jq-style transform shown instead of the raw response
+    #[serde(default)]
+    pub assertions: Vec<Assertion>, // Checked against every response, including load test ones
+    #[serde(default)]
+    pub connection: ConnectionOptions, // Address family and other per-endpoint connection settings
+    #[serde(default)]
+    pub extractions: Vec<Extraction>, // Response values saved as variables for later requests
 }
 
 /// Load test configuration data (serializable)
@@ -63,6 +562,24 @@ pub struct LoadTestConfigData {
     pub duration_secs: u64,
     pub ramp_up_secs: Option<u64>,
     pub rate_limit: Option<usize>,
+    #[serde(default)]
+    pub timeout_secs: Option<u64>, // Per-run client timeout (None = inherit)
+    #[serde(default)]
+    pub pool_max_idle_per_host: Option<usize>, // Per-run pool size (None = inherit)
+    #[serde(default)]
+    pub http1_only: bool,
+    #[serde(default)]
+    pub disable_compression: bool,
+    #[serde(default)]
+    pub headers: HashMap<String, String>, // Extra headers for load test requests only
+    #[serde(default)]
+    pub har_samples: usize, // Exchanges sampled into a HAR file, 0 = none
+    #[serde(default)]
+    pub cool_down_secs: u64, // Pause before the next queued run
+    #[serde(default)]
+    pub soak: bool, // Multi-hour run with periodic checkpoints
+    #[serde(default)]
+    pub chaos: ChaosConfig, // Faults injected into a share of requests
 }
 
 /// A collection of related API endpoints
@@ -73,6 +590,31 @@ pub struct ApiCollection {
     pub endpoints: Vec<ApiEndpoint>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub read_only: bool, // Shared/canonical collection; edit a copy instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<CollectionSource>, // Remote spec it was imported from, for syncing
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String, // Markdown setup notes, e.g. "get a token from X first"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scenario: Vec<ScenarioStep>, // Endpoints load tested together, by weight
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changelog: Vec<EndpointChange>, // Edits to the endpoints, oldest first
+}
+
+/// An endpoint of a collection's load test scenario and its share of the requests
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScenarioStep {
+    pub endpoint_id: Uuid,
+    pub weight: u32, // Relative: 7 and 3 send 70% and 30% of the requests
+}
+
+/// Where a collection imported from a URL came from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CollectionSource {
+    pub url: String,
+    #[serde(default)]
+    pub header: String, // Sent along on every fetch; may contain {{variables}}
 }
 
 impl ApiCollection {
@@ -85,9 +627,45 @@ impl ApiCollection {
             endpoints: Vec::new(),
             created_at: now,
             updated_at: now,
+            read_only: false,
+            source: None,
+            notes: String::new(),
+            scenario: Vec::new(),
+            changelog: Vec::new(),
         }
     }
 
+    /// Editable local copy of a (typically read-only) collection, with fresh IDs
+    /// so it never overwrites or shares history with the original
+    pub fn editable_copy(&self) -> Self {
+        self.editable_copy_named(format!("{} (copy)", self.name))
+    }
+
+    /// Like `editable_copy`, but with a chosen name, e.g. when importing
+    pub fn editable_copy_named(&self, name: String) -> Self {
+        let mut copy = Self::new(name);
+        copy.endpoints = self.endpoints.iter()
+            .map(|e| ApiEndpoint { id: Uuid::new_v4(), ..e.clone() })
+            .collect();
+        copy.source = self.source.clone();
+        copy.notes = self.notes.clone();
+        // The scenario follows its endpoints to their new IDs
+        copy.scenario = self.scenario.iter()
+            .filter_map(|step| {
+                let index = self.endpoints.iter().position(|e| e.id == step.endpoint_id)?;
+                Some(ScenarioStep { endpoint_id: copy.endpoints[index].id, weight: step.weight })
+            })
+            .collect();
+        // So does the history, minus endpoints that are gone
+        copy.changelog = self.changelog.iter()
+            .filter_map(|change| {
+                let index = self.endpoints.iter().position(|e| e.id == change.endpoint_id)?;
+                Some(EndpointChange { endpoint_id: copy.endpoints[index].id, ..change.clone() })
+            })
+            .collect();
+        copy
+    }
+
     /// Add an endpoint to the collection
     pub fn add_endpoint(&mut self, endpoint: ApiEndpoint) {
         self.endpoints.push(endpoint);
@@ -104,6 +682,35 @@ impl ApiCollection {
         }
         removed
     }
+
+    /// Index of an endpoint, other than `except`, sending the same method to the same URL.
+    /// A trailing slash and surrounding whitespace don't make URLs different.
+    pub fn find_duplicate(&self, method: &HttpMethod, url: &str, except: Option<usize>) -> Option<usize> {
+        let normalize = |url: &str| url.trim().trim_end_matches('/').to_string();
+        let url = normalize(url);
+        self.endpoints.iter()
+            .enumerate()
+            .find(|&(i, e)| Some(i) != except && &e.method == method && normalize(&e.url) == url)
+            .map(|(i, _)| i)
+    }
+
+    /// The scenario's endpoints that still exist, with their weights; steps weighted 0 are left out
+    pub fn scenario_endpoints(&self) -> Vec<(&ApiEndpoint, u32)> {
+        self.scenario.iter()
+            .filter(|step| step.weight > 0)
+            .filter_map(|step| Some((self.endpoints.iter().find(|e| e.id == step.endpoint_id)?, step.weight)))
+            .collect()
+    }
+
+    /// Every tag used by the collection's endpoints, sorted and de-duplicated
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.endpoints.iter()
+            .flat_map(|e| e.tags.iter().map(|t| t.to_lowercase()))
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
 }
 
 impl ApiEndpoint {
@@ -120,8 +727,34 @@ impl ApiEndpoint {
             description: None,
             load_test_config: None,
             timeout_secs: None, // Use default timeout
+            tags: Vec::new(),
+            archived: false,
+            soap: None,
+            protobuf: None,
+            empty_body: EmptyBody::Omit,
+            visualizer: None,
+            assertions: Vec::new(),
+            connection: ConnectionOptions::default(),
+            extractions: Vec::new(),
+            graphql: None,
         }
     }
+
+    /// Check for a tag, ignoring case
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// A `ws://` or `wss://` URL: the endpoint opens a WebSocket instead of sending a request
+    pub fn is_websocket(&self) -> bool {
+        let url = self.url.trim_start().to_ascii_lowercase();
+        url.starts_with("ws://") || url.starts_with("wss://")
+    }
+
+    /// The assertions a response fails, in the order they are defined
+    pub fn failed_assertions(&self, status: u16, duration: Duration, headers: &HashMap<String, String>, body: &[u8]) -> Vec<&Assertion> {
+        self.assertions.iter().filter(|a| !a.passes(status, duration, headers, body)).collect()
+    }
 }
 
 #[cfg(test)]
@@ -163,6 +796,188 @@ mod tests {
         assert_eq!(collection.endpoints.len(), 0);
     }
 
+    #[test]
+    fn test_collection_tags() {
+        let mut collection = ApiCollection::new("Test".to_string());
+        let mut endpoint = ApiEndpoint::new("A".to_string(), HttpMethod::GET, "https://a".to_string());
+        endpoint.tags = vec!["Smoke".to_string(), "slow".to_string()];
+        collection.add_endpoint(endpoint);
+        let mut endpoint = ApiEndpoint::new("B".to_string(), HttpMethod::GET, "https://b".to_string());
+        endpoint.tags = vec!["auth".to_string(), "smoke".to_string()];
+        collection.add_endpoint(endpoint);
+
+        assert_eq!(collection.tags(), vec!["auth", "slow", "smoke"]);
+        assert!(collection.endpoints[0].has_tag("smoke"));
+        assert!(!collection.endpoints[0].has_tag("auth"));
+    }
+
+    #[test]
+    fn test_find_duplicate() {
+        let mut collection = ApiCollection::new("Test".to_string());
+        collection.add_endpoint(ApiEndpoint::new("A".to_string(), HttpMethod::GET, "https://api/users/".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("B".to_string(), HttpMethod::POST, "https://api/users".to_string()));
+
+        assert_eq!(collection.find_duplicate(&HttpMethod::GET, " https://api/users", None), Some(0));
+        assert_eq!(collection.find_duplicate(&HttpMethod::POST, "https://api/users", None), Some(1));
+        assert_eq!(collection.find_duplicate(&HttpMethod::GET, "https://api/users", Some(0)), None);
+        assert_eq!(collection.find_duplicate(&HttpMethod::GET, "https://api/Users", None), None);
+    }
+
+    #[test]
+    fn test_editable_copy() {
+        let mut collection = ApiCollection::new("Shared".to_string());
+        collection.read_only = true;
+        collection.add_endpoint(ApiEndpoint::new("A".to_string(), HttpMethod::GET, "https://a".to_string()));
+
+        let copy = collection.editable_copy();
+        assert_eq!(copy.name, "Shared (copy)");
+        assert!(!copy.read_only);
+        assert_ne!(copy.id, collection.id);
+        assert_ne!(copy.endpoints[0].id, collection.endpoints[0].id);
+        assert_eq!(copy.endpoints[0].url, collection.endpoints[0].url);
+    }
+
+    #[test]
+    fn test_scenario_endpoints() {
+        let mut collection = ApiCollection::new("Shop".to_string());
+        collection.add_endpoint(ApiEndpoint::new("list".to_string(), HttpMethod::GET, "https://a/items".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("create".to_string(), HttpMethod::POST, "https://a/items".to_string()));
+        let (list, create) = (collection.endpoints[0].id, collection.endpoints[1].id);
+        collection.scenario = vec![
+            ScenarioStep { endpoint_id: list, weight: 7 },
+            ScenarioStep { endpoint_id: Uuid::new_v4(), weight: 5 },
+            ScenarioStep { endpoint_id: create, weight: 3 },
+        ];
+        let names: Vec<(&str, u32)> = collection.scenario_endpoints().iter().map(|(e, w)| (e.name.as_str(), *w)).collect();
+        assert_eq!(names, vec![("list", 7), ("create", 3)]);
+
+        // Copies keep the scenario, pointing at their own endpoints
+        let copy = collection.editable_copy();
+        assert_eq!(copy.scenario, vec![
+            ScenarioStep { endpoint_id: copy.endpoints[0].id, weight: 7 },
+            ScenarioStep { endpoint_id: copy.endpoints[1].id, weight: 3 },
+        ]);
+    }
+
+    #[test]
+    fn test_protobuf_spec() {
+        let config = ProtobufConfig::parse("api/users.proto users.CreateUser -> users.User").unwrap();
+        assert_eq!(config.proto_file, "api/users.proto");
+        assert_eq!(config.request_message.as_deref(), Some("users.CreateUser"));
+        assert_eq!(config.response_message.as_deref(), Some("users.User"));
+        assert_eq!(config.to_string(), "api/users.proto users.CreateUser -> users.User");
+
+        let config = ProtobufConfig::parse("users.proto -> User").unwrap();
+        assert_eq!(config.request_message, None);
+        assert_eq!(ProtobufConfig::parse(&config.to_string()), Some(config));
+        assert_eq!(ProtobufConfig::parse("  "), None);
+    }
+
+    #[test]
+    fn test_assertions_parse_and_check() {
+        let assertions = Assertion::parse_list("status 200; latency < 500ms ;body contains \"ok\"").unwrap();
+        assert_eq!(assertions, vec![
+            Assertion::Status { equals: 200 },
+            Assertion::MaxLatency { ms: 500 },
+            Assertion::BodyContains { text: "ok".to_string() },
+        ]);
+        assert_eq!(Assertion::parse_list(&Assertion::format_list(&assertions)).unwrap(), assertions);
+        assert!(Assertion::parse("status 2000").is_err());
+        assert!(Assertion::parse("headers has x").is_err());
+
+        let mut endpoint = ApiEndpoint::new("e".to_string(), HttpMethod::GET, "/".to_string());
+        endpoint.assertions = assertions;
+        let headers = HashMap::new();
+        assert!(endpoint.failed_assertions(200, Duration::from_millis(20), &headers, b"{\"ok\":1}").is_empty());
+        let failed = endpoint.failed_assertions(200, Duration::from_millis(900), &headers, b"{}");
+        assert_eq!(failed.iter().map(|a| a.to_string()).collect::<Vec<_>>(), vec!["latency <= 500ms", "body contains \"ok\""]);
+    }
+
+    #[test]
+    fn test_header_and_json_path_assertions() {
+        let assertions = Assertion::parse_list("header etag exists; header X-Id; .data.id == 42; .data.name == bob; .tags contains \"new\"; .data.name contains \"o\"").unwrap();
+        assert_eq!(assertions[1], Assertion::HeaderPresent { name: "X-Id".to_string() });
+        assert_eq!(assertions[2], Assertion::JsonEquals { path: ".data.id".to_string(), value: serde_json::json!(42) });
+        assert_eq!(assertions[3].to_string(), ".data.name == \"bob\"");
+        assert_eq!(Assertion::parse_list(&Assertion::format_list(&assertions)).unwrap(), assertions);
+        assert!(Assertion::parse(".data.id").is_err());
+        assert!(Assertion::parse(".a[ == 1").is_err());
+        assert!(Assertion::parse("header").is_err());
+
+        let headers = HashMap::from([("ETag".to_string(), "\"v1\"".to_string())]);
+        let body = br#"{"data": {"id": 42, "name": "bob"}, "tags": ["old", "new"]}"#;
+        let failed: Vec<String> = assertions.iter()
+            .filter(|a| !a.passes(200, Duration::ZERO, &headers, body))
+            .map(|a| a.to_string())
+            .collect();
+        assert_eq!(failed, vec!["header X-Id exists"]);
+        assert!(!Assertion::parse(".data.id == \"42\"").unwrap().passes(200, Duration::ZERO, &headers, body));
+        assert!(!Assertion::parse(".data.id == 42").unwrap().passes(200, Duration::ZERO, &headers, b"not json"));
+    }
+
+    #[test]
+    fn test_extractions_parse_and_read_responses() {
+        let extractions = Extraction::parse_list("token = .data.token; id=.items[0].id ; etag = header ETag").unwrap();
+        assert_eq!(extractions[2], Extraction { variable: "etag".to_string(), source: ExtractSource::Header { name: "ETag".to_string() } });
+        assert_eq!(Extraction::parse_list(&Extraction::format_list(&extractions)).unwrap(), extractions);
+        assert!(Extraction::parse(".data.token").is_err());
+        assert!(Extraction::parse("my token = .a").is_err());
+        assert!(Extraction::parse("t = .a[").is_err());
+
+        let headers = HashMap::from([("etag".to_string(), "\"v1\"".to_string())]);
+        let body = br#"{"data": {"token": "abc"}, "items": [{"id": 7}]}"#;
+        let values: Vec<_> = extractions.iter().map(|e| e.value(&headers, body)).collect();
+        assert_eq!(values, vec![Ok("abc".to_string()), Ok("7".to_string()), Ok("\"v1\"".to_string())]);
+        assert_eq!(Extraction::parse("t = .missing").unwrap().value(&headers, body), Err(".missing matched nothing".to_string()));
+        assert!(Extraction::parse("t = header Location").unwrap().value(&headers, body).is_err());
+
+        let json = serde_json::to_value(&extractions[0]).unwrap();
+        assert_eq!(json, serde_json::json!({"variable": "token", "from": "body", "path": ".data.token"}));
+    }
+
+    #[test]
+    fn test_graphql_payload() {
+        let payload = GraphqlBody::payload("query { me { id } }", "").unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&payload).unwrap(), serde_json::json!({"query": "query { me { id } }"}));
+        let payload = GraphqlBody::payload("query($id: ID!) { user(id: $id) { name } }", r#"{"id": "7"}"#).unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&payload).unwrap()["variables"], serde_json::json!({"id": "7"}));
+        assert!(GraphqlBody::payload("{ a }", "[1]").is_err());
+        assert!(GraphqlBody::payload("{ a }", "{").is_err());
+    }
+
+    #[test]
+    fn test_connection_options() {
+        assert_eq!(ConnectionOptions::parse("").unwrap(), ConnectionOptions::default());
+        let options = ConnectionOptions::parse(" IPv6 ").unwrap();
+        assert_eq!(options.ip_family, IpFamily::V6);
+        assert_eq!(ConnectionOptions::parse(&options.to_string()).unwrap(), options);
+        assert!(ConnectionOptions::parse("ipv5").is_err());
+
+        let options = ConnectionOptions::parse("close; fresh; idle 5s").unwrap();
+        assert!(options.close && options.fresh);
+        assert_eq!(options.idle_timeout_secs, Some(5));
+        assert_eq!(options.to_string(), "close; fresh; idle 5s");
+        assert_eq!(ConnectionOptions::parse("idle 30").unwrap().idle_timeout_secs, Some(30));
+        let options = ConnectionOptions::parse("Expect-Continue").unwrap();
+        assert!(options.expect_continue);
+        assert_eq!(ConnectionOptions::parse(&options.to_string()).unwrap(), options);
+        let options = ConnectionOptions::parse("ipv4; interim").unwrap();
+        assert!(options.interim);
+        assert_eq!(options.to_string(), "ipv4; interim");
+        assert!(ConnectionOptions::parse("idle soon").is_err());
+    }
+
+    #[test]
+    fn test_custom_method_serialization() {
+        assert_eq!(serde_json::to_string(&HttpMethod::GET).unwrap(), "\"GET\"");
+        assert_eq!(serde_json::to_string(&HttpMethod::Custom("PROPFIND".to_string())).unwrap(), "\"PROPFIND\"");
+        let method: HttpMethod = serde_json::from_str("\"purge\"").unwrap();
+        assert_eq!(method, HttpMethod::Custom("PURGE".to_string()));
+        assert_eq!(HttpMethod::from("post".to_string()), HttpMethod::POST);
+        assert!(method.is_valid());
+        assert!(!HttpMethod::Custom("BAD METHOD".to_string()).is_valid());
+    }
+
     #[test]
     fn test_serialization() {
         let collection = ApiCollection::new("Test".to_string());
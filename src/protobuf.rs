@@ -0,0 +1,117 @@
+// Protobuf bodies for endpoints that speak protobuf over HTTP
+// Bodies are authored as JSON (the proto3 JSON mapping) and encoded on send;
+// binary responses are decoded back to JSON for display.
+
+use prost_reflect::prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ProtobufError {
+    #[error("Failed to compile {0}")]
+    Compile(String),
+
+    #[error("Unknown message type: {0}")]
+    UnknownMessage(String),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Decode error: {0}")]
+    Decode(#[from] prost_reflect::prost::DecodeError),
+}
+
+pub type Result<T> = std::result::Result<T, ProtobufError>;
+
+/// Compiled schemas keyed by path, with the mtime they were compiled at
+type SchemaCache = Mutex<HashMap<PathBuf, (Option<SystemTime>, ProtoSchema)>>;
+
+/// Message types available from a compiled .proto file (and its imports)
+#[derive(Debug, Clone)]
+pub struct ProtoSchema {
+    pool: DescriptorPool,
+}
+
+impl ProtoSchema {
+    /// Compile a .proto file; imports are resolved relative to its directory
+    pub fn load(path: &Path) -> Result<Self> {
+        let include = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let mut compiler = protox::Compiler::new([include])
+            .map_err(|e| ProtobufError::Compile(format!("{}: {}", path.display(), e)))?;
+        compiler.open_file(path)
+            .map_err(|e| ProtobufError::Compile(format!("{}: {}", path.display(), e)))?;
+        Ok(Self { pool: compiler.descriptor_pool() })
+    }
+
+    /// Like `load`, but reuses the compiled schema until the file changes on disk
+    pub fn load_cached(path: &Path) -> Result<Self> {
+        static CACHE: OnceLock<SchemaCache> = OnceLock::new();
+        let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        let cache = CACHE.get_or_init(Default::default);
+        if let Some((mtime, schema)) = cache.lock().unwrap().get(path) {
+            if *mtime == modified && modified.is_some() {
+                return Ok(schema.clone());
+            }
+        }
+        let schema = Self::load(path)?;
+        cache.lock().unwrap().insert(path.to_path_buf(), (modified, schema.clone()));
+        Ok(schema)
+    }
+
+    /// Look up a message by its full name (`pkg.User`); a bare name matches any package
+    pub fn message(&self, name: &str) -> Result<MessageDescriptor> {
+        self.pool.get_message_by_name(name)
+            .or_else(|| self.pool.all_messages().find(|m| m.name() == name))
+            .ok_or_else(|| ProtobufError::UnknownMessage(name.to_string()))
+    }
+
+    /// Encode a JSON body as the given message type
+    pub fn encode_json(&self, message: &str, json: &str) -> Result<Vec<u8>> {
+        let descriptor = self.message(message)?;
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let message = DynamicMessage::deserialize(descriptor, &mut deserializer)?;
+        deserializer.end()?;
+        Ok(message.encode_to_vec())
+    }
+
+    /// Decode a binary body as the given message type into pretty-printed JSON
+    pub fn decode_to_json(&self, message: &str, bytes: &[u8]) -> Result<String> {
+        let message = DynamicMessage::decode(self.message(message)?, bytes)?;
+        Ok(serde_json::to_string_pretty(&message)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users.proto");
+        std::fs::write(&path, r#"
+            syntax = "proto3";
+            package users;
+            message User {
+                int64 id = 1;
+                string name = 2;
+                repeated string roles = 3;
+            }
+        "#).unwrap();
+
+        let schema = ProtoSchema::load_cached(&path).unwrap();
+        let bytes = schema.encode_json("users.User", r#"{"id": "42", "name": "bob", "roles": ["admin"]}"#).unwrap();
+        assert_eq!(&bytes[..2], &[0x08, 42]);
+
+        let json: serde_json::Value = serde_json::from_str(&schema.decode_to_json("User", &bytes).unwrap()).unwrap();
+        assert_eq!(json, serde_json::json!({ "id": "42", "name": "bob", "roles": ["admin"] }));
+
+        assert!(matches!(schema.message("Missing"), Err(ProtobufError::UnknownMessage(_))));
+        assert!(schema.encode_json("User", r#"{"nope": 1}"#).is_err());
+        assert!(ProtoSchema::load(&dir.path().join("missing.proto")).is_err());
+    }
+}
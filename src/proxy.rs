@@ -0,0 +1,130 @@
+// Proxy configuration
+// A global proxy and per-collection overrides, kept in proxy.toml next to
+// config.toml. An environment's proxy replaces the global one; a collection's
+// entry replaces both, so one collection can go direct or through a SOCKS
+// tunnel while everything else uses the corporate proxy.
+
+use crate::http::HttpClientConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ProxyError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid proxy.toml: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("TOML error: {0}")]
+    Serialize(#[from] toml::ser::Error),
+
+    #[error("Invalid proxy '{0}': {1}")]
+    Invalid(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, ProxyError>;
+
+/// A proxy and the hosts that bypass it
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxySettings {
+    pub url: Option<String>, // http://, https:// or socks5://; none = connect directly
+    pub no_proxy: Vec<String>, // Hosts, domains (".corp.local"), IPs or CIDR ranges sent directly
+}
+
+impl ProxySettings {
+    /// Replace the proxy in a client configuration with this one
+    pub fn apply(&self, config: &mut HttpClientConfig) {
+        config.proxy = self.url.clone();
+        config.no_proxy = self.no_proxy.clone();
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(url) = &self.url {
+            reqwest::Proxy::all(url).map_err(|e| ProxyError::Invalid(url.clone(), e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+// Plain values must come before the tables for TOML
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxyConfig {
+    pub global: ProxySettings,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub collections: BTreeMap<Uuid, ProxySettings>, // By collection id
+}
+
+impl ProxyConfig {
+    /// Load the proxies; a missing file means connecting directly
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let config: Self = toml::from_str(&fs::read_to_string(path)?)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        self.validate()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.global.validate()?;
+        self.collections.values().try_for_each(ProxySettings::validate)
+    }
+
+    /// The collection's own proxy, if it has one
+    pub fn for_collection(&self, id: Uuid) -> Option<&ProxySettings> {
+        self.collections.get(&id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_and_apply() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("proxy.toml");
+        assert_eq!(ProxyConfig::load(&path).unwrap(), ProxyConfig::default());
+
+        let id = Uuid::new_v4();
+        let mut config = ProxyConfig {
+            global: ProxySettings {
+                url: Some("http://proxy.corp:3128".to_string()),
+                no_proxy: vec!["localhost".to_string(), ".corp.local".to_string()],
+            },
+            collections: BTreeMap::new(),
+        };
+        config.collections.insert(id, ProxySettings { url: Some("socks5://127.0.0.1:1080".to_string()), no_proxy: Vec::new() });
+        config.save(&path).unwrap();
+        assert_eq!(ProxyConfig::load(&path).unwrap(), config);
+
+        let mut client = HttpClientConfig::default();
+        config.global.apply(&mut client);
+        assert_eq!(client.no_proxy, vec!["localhost", ".corp.local"]);
+        config.for_collection(id).unwrap().apply(&mut client);
+        assert_eq!(client.proxy.as_deref(), Some("socks5://127.0.0.1:1080"));
+        assert!(client.no_proxy.is_empty());
+        assert!(config.for_collection(Uuid::new_v4()).is_none());
+
+        config.global.url = Some("not a proxy".to_string());
+        assert!(matches!(config.save(&path), Err(ProxyError::Invalid(..))));
+        fs::write(&path, "[global]\nurl = 5").unwrap();
+        assert!(matches!(ProxyConfig::load(&path), Err(ProxyError::Parse(_))));
+    }
+}
@@ -0,0 +1,468 @@
+// Collection runner for use as a library
+// Loads a collections directory and runs a collection's endpoints one after
+// another, like `R` in the TUI, returning structured results instead of
+// drawing them, so CI scripts can embed it:
+//
+//     let collections = Collections::load("~/.rest-api-tui/collections")?;
+//     let run = collections.get("Users API").unwrap().run(&env, &[]).await?;
+//     std::process::exit(if run.is_success() { 0 } else { 1 });
+//
+// Values an endpoint extracts from its response (`token = .data.token`) are
+// variables for the endpoints after it. A ws:// or wss:// endpoint is a
+// WebSocket step: it sends its body as a message and waits for one that passes
+// its assertions, e.g. POST an order, then await `order.updated`.
+
+use crate::http::{HttpClient, HttpClientConfig, HttpError, HttpResponse, Request, RequestInputs};
+use crate::models::{ApiCollection, ApiEndpoint, Assertion, HttpMethod};
+use crate::storage::{self, expand_home, SkippedFile, StorageError};
+use crate::template;
+use crate::variables::VariableSet;
+use crate::ws::{WsConnection, WsEvent};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum RunnerError {
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    #[error("HTTP error: {0}")]
+    Http(#[from] HttpError),
+}
+
+pub type Result<T> = std::result::Result<T, RunnerError>;
+
+/// How long a WebSocket step waits for its message when the endpoint sets no timeout
+pub const WS_STEP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The collections of a directory, e.g. `~/.rest-api-tui/collections`
+#[derive(Debug, Clone, Default)]
+pub struct Collections {
+    collections: Vec<ApiCollection>,
+    skipped: Vec<SkippedFile>,
+}
+
+impl Collections {
+    /// Load every collection file in `dir`; unreadable files are listed in
+    /// `skipped` rather than failing the load
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = expand_home(&dir.as_ref().to_string_lossy());
+        let (mut collections, skipped) = storage::load_collections_from(&dir)?;
+        collections.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { collections, skipped })
+    }
+
+    /// A collection by name (case-insensitive) or id
+    pub fn get(&self, name_or_id: &str) -> Option<&ApiCollection> {
+        self.collections.iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name_or_id) || c.id.to_string() == name_or_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ApiCollection> {
+        self.collections.iter()
+    }
+
+    pub fn skipped(&self) -> &[SkippedFile] {
+        &self.skipped
+    }
+}
+
+/// Outcome of running a collection
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CollectionRun {
+    pub collection: String,
+    pub environment: String,
+    pub results: Vec<EndpointResult>,
+    pub duration_ms: u64,
+}
+
+impl CollectionRun {
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|r| r.passed()).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.passed()
+    }
+
+    /// Every endpoint passed
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(EndpointResult::passed)
+    }
+}
+
+/// Outcome of one endpoint in a run
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct EndpointResult {
+    pub endpoint_id: Uuid,
+    pub name: String,
+    pub method: HttpMethod,
+    pub url: String, // As sent, or the template if the request couldn't be built
+    pub status: Option<u16>, // None when no response was received
+    pub duration_ms: u64,
+    pub error: Option<String>,
+    pub assertion_failures: Vec<String>,
+    pub extracted: BTreeMap<String, String>, // Variables set for the endpoints after this one
+    pub extraction_failures: Vec<String>,
+}
+
+impl EndpointResult {
+    /// A result for an endpoint that hasn't been sent yet
+    pub fn new(endpoint: &ApiEndpoint, variables: &HashMap<String, String>) -> Self {
+        Self {
+            endpoint_id: endpoint.id,
+            name: endpoint.name.clone(),
+            method: endpoint.method.clone(),
+            url: template::substitute_lenient(&endpoint.url, variables),
+            status: None,
+            duration_ms: 0,
+            error: None,
+            assertion_failures: Vec::new(),
+            extracted: BTreeMap::new(),
+            extraction_failures: Vec::new(),
+        }
+    }
+
+    /// Check a response against the endpoint's assertions plus `assertions`
+    /// (without a status assertion anything but 2xx/3xx fails) and extract its variables
+    pub fn record_response(&mut self, endpoint: &ApiEndpoint, assertions: &[Assertion], response: &HttpResponse) {
+        let status = response.status.as_u16();
+        let checks: Vec<&Assertion> = endpoint.assertions.iter().chain(assertions).collect();
+        self.assertion_failures = checks.iter()
+            .filter(|a| !a.passes(status, response.duration, &response.headers, &response.body))
+            .map(|a| a.to_string())
+            .collect();
+        if !checks.iter().any(|a| matches!(a, Assertion::Status { .. })) && !(200..=399).contains(&status) {
+            self.assertion_failures.push(format!("status {} is not 2xx/3xx", status));
+        }
+        if let Some(mismatch) = response.body_mismatch() {
+            self.error = Some(mismatch.to_string());
+        }
+        if let Some(traffic) = &response.traffic {
+            self.url = traffic.request.url.clone();
+        }
+        (self.extracted, self.extraction_failures) = extract(endpoint, &response.headers, &response.body);
+        self.status = Some(status);
+        self.duration_ms = response.duration.as_millis() as u64;
+    }
+
+    pub fn record_error(&mut self, error: String, duration: Duration) {
+        self.error = Some(error);
+        self.duration_ms = duration.as_millis() as u64;
+    }
+
+    /// Got a response, no assertion failed and every variable was extracted
+    pub fn passed(&self) -> bool {
+        self.status.is_some() && self.error.is_none() && self.assertion_failures.is_empty() && self.extraction_failures.is_empty()
+    }
+}
+
+/// The variables an endpoint extracts from a response's headers and body, and
+/// what couldn't be extracted
+pub fn extract(endpoint: &ApiEndpoint, headers: &HashMap<String, String>, body: &[u8]) -> (BTreeMap<String, String>, Vec<String>) {
+    let mut extracted = BTreeMap::new();
+    let mut failures = Vec::new();
+    for extraction in &endpoint.extractions {
+        match extraction.value(headers, body) {
+            Ok(value) => { extracted.insert(extraction.variable.clone(), value); }
+            Err(e) => failures.push(format!("{}: {}", extraction.variable, e)),
+        }
+    }
+    (extracted, failures)
+}
+
+impl ApiCollection {
+    /// Send every endpoint that isn't archived, one after another, with the
+    /// environment's variables and client defaults. Each response is checked
+    /// against the endpoint's own assertions plus `assertions`; without a status
+    /// assertion, anything but 2xx/3xx fails.
+    pub async fn run(&self, env: &VariableSet, assertions: &[Assertion]) -> Result<CollectionRun> {
+        let mut config = HttpClientConfig::default();
+        env.defaults.apply(&mut config);
+        let client = HttpClient::with_config(config)?;
+        let mut run = self.run_with(&client, &env.variables, assertions).await;
+        run.environment = env.name.clone();
+        Ok(run)
+    }
+
+    /// `run` with a client of your own, e.g. one built from the workspace settings
+    pub async fn run_with(&self, client: &HttpClient, variables: &HashMap<String, String>, assertions: &[Assertion]) -> CollectionRun {
+        let started = Instant::now();
+        let mut variables = variables.clone();
+        let mut results = Vec::new();
+        for endpoint in self.endpoints.iter().filter(|e| !e.archived) {
+            let result = run_endpoint(client, endpoint, &variables, assertions).await;
+            variables.extend(result.extracted.clone());
+            results.push(result);
+        }
+        CollectionRun {
+            collection: self.name.clone(),
+            environment: String::new(),
+            results,
+            duration_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+async fn run_endpoint(client: &HttpClient, endpoint: &ApiEndpoint, variables: &HashMap<String, String>, assertions: &[Assertion]) -> EndpointResult {
+    let mut result = EndpointResult::new(endpoint, variables);
+    let started = Instant::now();
+    let request = Request::from_endpoint(endpoint).vars(variables.clone());
+    if endpoint.is_websocket() {
+        run_websocket(client, endpoint, request.inputs(), assertions, &mut result).await;
+        return result;
+    }
+    match request.send(client).await {
+        Ok(response) => result.record_response(endpoint, assertions, &response),
+        Err(e) => result.record_error(e.to_string(), started.elapsed()),
+    }
+    result
+}
+
+/// Run a ws:// or wss:// endpoint as a step: connect, send its body (if any) as
+/// a message, then wait for the first message that passes the endpoint's
+/// assertions plus `assertions` (any message when there are none). Assertions
+/// see that message as the body and 101 as the status; variables are extracted
+/// from it too. Returns the message, empty if none matched before the endpoint's
+/// timeout (`WS_STEP_TIMEOUT` by default) or the connection closing.
+pub async fn run_websocket(client: &HttpClient, endpoint: &ApiEndpoint, inputs: &RequestInputs, assertions: &[Assertion], result: &mut EndpointResult) -> Vec<u8> {
+    let started = Instant::now();
+    let request = match client.prepare(endpoint, inputs) {
+        Ok(request) => request,
+        Err(e) => {
+            result.record_error(e.to_string(), started.elapsed());
+            return Vec::new();
+        }
+    };
+    result.url = request.url.clone();
+    let connection = match WsConnection::connect(&tokio::runtime::Handle::current(), &request) {
+        Ok(connection) => connection,
+        Err(e) => {
+            result.record_error(e.to_string(), started.elapsed());
+            return Vec::new();
+        }
+    };
+    if let Some(body) = request.body.filter(|body| !body.is_empty()) {
+        connection.send(String::from_utf8_lossy(&body).into_owned());
+    }
+
+    let timeout = endpoint.timeout_secs.map_or(WS_STEP_TIMEOUT, Duration::from_secs);
+    let checks: Vec<&Assertion> = endpoint.assertions.iter().chain(assertions).collect();
+    let no_headers = HashMap::new();
+    let mut last: Option<String> = None;
+    let ended = 'wait: loop {
+        for event in connection.poll() {
+            match event {
+                WsEvent::Connected(status) => result.status = Some(status),
+                WsEvent::Received(text) => {
+                    let elapsed = started.elapsed();
+                    if checks.iter().all(|a| a.passes(101, elapsed, &no_headers, text.as_bytes())) {
+                        (result.extracted, result.extraction_failures) = extract(endpoint, &no_headers, text.as_bytes());
+                        result.duration_ms = elapsed.as_millis() as u64;
+                        connection.close();
+                        return text.into_bytes();
+                    }
+                    last = Some(text);
+                }
+                WsEvent::Sent(_) => {}
+                WsEvent::Closed(_) => break 'wait "the connection closed before a matching message arrived".to_string(),
+                WsEvent::Error(e) => break 'wait e,
+            }
+        }
+        if started.elapsed() >= timeout {
+            break format!("no matching message within {}s", timeout.as_secs_f64());
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+    // Report what the last message failed, if one came
+    if let Some(text) = last {
+        result.assertion_failures = checks.iter()
+            .filter(|a| !a.passes(101, started.elapsed(), &no_headers, text.as_bytes()))
+            .map(|a| a.to_string())
+            .collect();
+    }
+    connection.close();
+    result.record_error(ended, started.elapsed());
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Extraction;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_tungstenite::tungstenite::protocol::Message;
+
+    #[tokio::test]
+    async fn test_load_and_run_collection() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).into_owned();
+                let response: &[u8] = if request.starts_with("GET /health") {
+                    b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok"
+                } else {
+                    b"HTTP/1.1 500 Internal Server Error\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                };
+                socket.write_all(response).await.unwrap();
+            }
+        });
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut collection = ApiCollection::new("Smoke".to_string());
+        let mut health = ApiEndpoint::new("health".to_string(), HttpMethod::GET, "{{host}}/health".to_string());
+        health.assertions = vec![Assertion::BodyContains { text: "ok".to_string() }];
+        collection.add_endpoint(health);
+        collection.add_endpoint(ApiEndpoint::new("crash".to_string(), HttpMethod::POST, "{{host}}/crash".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("user".to_string(), HttpMethod::GET, "{{host}}/users/{{id}}".to_string()));
+        let mut archived = ApiEndpoint::new("old".to_string(), HttpMethod::GET, "{{host}}/old".to_string());
+        archived.archived = true;
+        collection.add_endpoint(archived);
+        std::fs::write(temp_dir.path().join("smoke.json"), serde_json::to_string(&collection).unwrap()).unwrap();
+        std::fs::write(temp_dir.path().join("broken.json"), "{").unwrap();
+
+        let collections = Collections::load(temp_dir.path()).unwrap();
+        assert_eq!(collections.iter().count(), 1);
+        assert_eq!(collections.skipped().len(), 1);
+        let mut env = VariableSet::new("local".to_string());
+        env.set("host".to_string(), format!("http://127.0.0.1:{}", port));
+        let run = collections.get("smoke").unwrap()
+            .run(&env, &[Assertion::MaxLatency { ms: 10_000 }])
+            .await
+            .unwrap();
+        server.await.unwrap();
+
+        assert_eq!(run.environment, "local");
+        assert_eq!(run.results.len(), 3, "archived endpoints don't run");
+        assert!(run.results[0].passed());
+        assert_eq!(run.results[0].url, format!("http://127.0.0.1:{}/health", port));
+        assert_eq!(run.results[1].status, Some(500));
+        assert_eq!(run.results[1].assertion_failures, vec!["status 500 is not 2xx/3xx"]);
+        assert_eq!(run.results[2].status, None);
+        assert!(run.results[2].error.as_deref().unwrap().contains("id"));
+        assert_eq!((run.passed(), run.failed(), run.is_success()), (1, 2, false));
+        assert!(serde_json::to_string(&run).unwrap().contains("\"collection\":\"Smoke\""));
+    }
+
+    #[tokio::test]
+    async fn test_extracted_variables_feed_later_steps() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                requests.push(String::from_utf8_lossy(&buf[..n]).into_owned());
+                let body = r#"{"token": "t-1", "user": {"id": 42}}"#;
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nx-trace: abc\r\nconnection: close\r\n\r\n{}", body.len(), body);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            requests
+        });
+
+        let mut collection = ApiCollection::new("Flow".to_string());
+        let mut login = ApiEndpoint::new("login".to_string(), HttpMethod::POST, "{{host}}/login".to_string());
+        login.extractions = Extraction::parse_list("token = .token; user = .user.id; trace = header X-Trace; gone = .missing").unwrap();
+        collection.add_endpoint(login);
+        let mut profile = ApiEndpoint::new("profile".to_string(), HttpMethod::GET, "{{host}}/users/{{user}}".to_string());
+        profile.headers.insert("Authorization".to_string(), "Bearer {{token}}".to_string());
+        collection.add_endpoint(profile);
+
+        let client = HttpClient::new().unwrap();
+        let variables = HashMap::from([("host".to_string(), format!("http://127.0.0.1:{}", port))]);
+        let run = collection.run_with(&client, &variables, &[]).await;
+        let requests = server.await.unwrap();
+
+        assert_eq!(run.results[0].extracted.get("token").map(String::as_str), Some("t-1"));
+        assert_eq!(run.results[0].extracted.get("trace").map(String::as_str), Some("abc"));
+        assert_eq!(run.results[0].extraction_failures, vec!["gone: .missing matched nothing"]);
+        assert!(!run.results[0].passed());
+        assert!(requests[1].starts_with("GET /users/42 "));
+        assert!(requests[1].to_ascii_lowercase().contains("authorization: bearer t-1"));
+        assert!(run.results[1].passed());
+    }
+
+    #[tokio::test]
+    async fn test_websocket_step_awaits_event_after_http_step() {
+        let http = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let http_port = http.local_addr().unwrap().port();
+        let http_server = tokio::spawn(async move {
+            let (mut socket, _) = http.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            let body = r#"{"id": 7}"#;
+            let response = format!("HTTP/1.1 201 Created\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).into_owned()
+        });
+        let events = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let events_port = events.local_addr().unwrap().port();
+        let events_server = tokio::spawn(async move {
+            let (socket, _) = events.accept().await.unwrap();
+            let mut stream = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let Some(Ok(Message::Text(subscribe))) = stream.next().await else { panic!("no subscribe message") };
+            stream.send(Message::Text(r#"{"type": "order.created", "order": 7}"#.to_string())).await.unwrap();
+            stream.send(Message::Text(r#"{"type": "order.updated", "order": 7, "status": "shipped"}"#.to_string())).await.unwrap();
+            while let Some(Ok(message)) = stream.next().await {
+                if matches!(message, Message::Close(_)) {
+                    break;
+                }
+            }
+            subscribe
+        });
+
+        let mut collection = ApiCollection::new("Orders".to_string());
+        let mut order = ApiEndpoint::new("order".to_string(), HttpMethod::POST, "{{host}}/orders".to_string());
+        order.extractions = Extraction::parse_list("order = .id").unwrap();
+        collection.add_endpoint(order);
+        let mut updated = ApiEndpoint::new("updated".to_string(), HttpMethod::GET, format!("ws://127.0.0.1:{}/orders", events_port));
+        updated.body_template = Some(r#"{"subscribe": {{order}}}"#.to_string());
+        updated.assertions = Assertion::parse_list(r#".type == "order.updated""#).unwrap();
+        updated.extractions = Extraction::parse_list("state = .status").unwrap();
+        collection.add_endpoint(updated);
+
+        let client = HttpClient::new().unwrap();
+        let variables = HashMap::from([("host".to_string(), format!("http://127.0.0.1:{}", http_port))]);
+        let run = collection.run_with(&client, &variables, &[]).await;
+        assert!(http_server.await.unwrap().starts_with("POST /orders "));
+        assert_eq!(events_server.await.unwrap(), r#"{"subscribe": 7}"#);
+
+        assert!(run.is_success(), "{:?}", run.results);
+        assert_eq!(run.results[1].status, Some(101));
+        assert_eq!(run.results[1].url, format!("ws://127.0.0.1:{}/orders", events_port));
+        assert_eq!(run.results[1].extracted.get("state").map(String::as_str), Some("shipped"));
+    }
+
+    #[tokio::test]
+    async fn test_websocket_step_fails_without_matching_message() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut stream = tokio_tungstenite::accept_async(socket).await.unwrap();
+            stream.send(Message::Text("order.created".to_string())).await.unwrap();
+            while stream.next().await.is_some() {}
+        });
+
+        let mut endpoint = ApiEndpoint::new("updated".to_string(), HttpMethod::GET, format!("ws://127.0.0.1:{}/", port));
+        endpoint.assertions = vec![Assertion::BodyContains { text: "order.updated".to_string() }];
+        endpoint.timeout_secs = Some(1);
+        let result = run_endpoint(&HttpClient::new().unwrap(), &endpoint, &HashMap::new(), &[]).await;
+
+        assert!(!result.passed());
+        assert_eq!(result.status, Some(101));
+        assert_eq!(result.error.as_deref(), Some("no matching message within 1s"));
+        assert_eq!(result.assertion_failures, vec![Assertion::BodyContains { text: "order.updated".to_string() }.to_string()]);
+    }
+}
@@ -0,0 +1,149 @@
+// CRUD scaffolding
+// The usual six endpoints for a REST resource from its collection URL:
+// `https://api/v1/users` gives List users, Get user, Create user, Replace user,
+// Update user and Delete user, the item ones at `/users/{{user_id}}`.
+
+use crate::models::{ApiEndpoint, HttpMethod};
+use std::collections::HashMap;
+
+/// Body template for the requests that send one
+const BODY_TEMPLATE: &str = "{\n  \"name\": \"{{f:name}}\"\n}";
+
+/// A resource's collection URL and the names derived from it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resource {
+    /// Collection URL without a trailing slash, query or fragment
+    pub url: String,
+    /// Last path segment, e.g. `users`
+    pub plural: String,
+    /// `user`, for endpoint names and the id variable
+    pub singular: String,
+}
+
+impl Resource {
+    /// Read a resource URL such as `{{base_url}}/users`; None when it has no path to name it by
+    pub fn parse(url: &str) -> Option<Self> {
+        let url = url.trim();
+        let url = url.split(['?', '#']).next().unwrap_or_default().trim_end_matches('/');
+        // `https://api.example.com` has no resource segment, only a host
+        let path_start = match url.find("://") {
+            Some(scheme_end) => scheme_end + 3 + url[scheme_end + 3..].find('/')?,
+            None => url.find('/')?,
+        };
+        let plural = url[path_start..].rsplit('/').next()?;
+        // The URL of one item rather than the collection
+        if plural.is_empty() || plural.contains("{{") {
+            return None;
+        }
+        Some(Self { url: url.to_string(), plural: plural.to_string(), singular: singular(plural) })
+    }
+
+    /// `{{user_id}}`, the variable the item endpoints are addressed by
+    pub fn id_variable(&self) -> String {
+        format!("{}_id", self.singular.to_lowercase().replace(['-', ' ', '.'], "_"))
+    }
+
+    /// List, get, create, replace, update and delete, each with `headers`;
+    /// JSON bodies get a Content-Type unless `headers` has one
+    pub fn endpoints(&self, headers: &HashMap<String, String>) -> Vec<ApiEndpoint> {
+        let item_url = format!("{}/{{{{{}}}}}", self.url, self.id_variable());
+        let specs = [
+            (format!("List {}", self.plural), HttpMethod::GET, &self.url, false),
+            (format!("Get {}", self.singular), HttpMethod::GET, &item_url, false),
+            (format!("Create {}", self.singular), HttpMethod::POST, &self.url, true),
+            (format!("Replace {}", self.singular), HttpMethod::PUT, &item_url, true),
+            (format!("Update {}", self.singular), HttpMethod::PATCH, &item_url, true),
+            (format!("Delete {}", self.singular), HttpMethod::DELETE, &item_url, false),
+        ];
+        specs.into_iter()
+            .map(|(name, method, url, body)| {
+                let mut endpoint = ApiEndpoint::new(name, method, url.clone());
+                endpoint.headers = headers.clone();
+                if !headers.keys().any(|name| name.eq_ignore_ascii_case("accept")) {
+                    endpoint.headers.insert("Accept".to_string(), "application/json".to_string());
+                }
+                if body {
+                    endpoint.body_template = Some(BODY_TEMPLATE.to_string());
+                    if !headers.keys().any(|name| name.eq_ignore_ascii_case("content-type")) {
+                        endpoint.headers.insert("Content-Type".to_string(), "application/json".to_string());
+                    }
+                }
+                endpoint.tags = vec![self.plural.to_lowercase()];
+                endpoint
+            })
+            .collect()
+    }
+}
+
+/// English singular of a resource name: `users` → `user`, `categories` → `category`
+fn singular(plural: &str) -> String {
+    let lower = plural.to_lowercase();
+    if let Some(stem) = lower.strip_suffix("ies").filter(|stem| !stem.is_empty()) {
+        return format!("{}y", &plural[..stem.len()]);
+    }
+    if ["sses", "xes", "ches", "shes", "zzes"].iter().any(|suffix| lower.ends_with(suffix)) {
+        return plural[..plural.len() - 2].to_string();
+    }
+    if lower.ends_with('s') && !lower.ends_with("ss") && !lower.ends_with("us") && plural.len() > 1 {
+        return plural[..plural.len() - 1].to_string();
+    }
+    plural.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_resource() {
+        let users = Resource::parse(" {{base_url}}/v1/users/?page=2 ").unwrap();
+        assert_eq!(users.url, "{{base_url}}/v1/users");
+        assert_eq!((users.plural.as_str(), users.singular.as_str()), ("users", "user"));
+        assert_eq!(users.id_variable(), "user_id");
+
+        let names: Vec<String> = ["categories", "addresses", "boxes", "status", "data", "order-items"]
+            .iter()
+            .map(|plural| singular(plural))
+            .collect();
+        assert_eq!(names, vec!["category", "address", "box", "status", "data", "order-item"]);
+        assert_eq!(Resource::parse("/order-items").unwrap().id_variable(), "order_item_id");
+
+        assert_eq!(Resource::parse("https://api.example.com"), None);
+        assert_eq!(Resource::parse("https://api.example.com/"), None);
+        assert_eq!(Resource::parse("users"), None);
+        assert_eq!(Resource::parse("{{base_url}}/users/{{user_id}}"), None);
+    }
+
+    #[test]
+    fn test_crud_endpoints() {
+        let resource = Resource::parse("https://api.example.com/users").unwrap();
+        let headers = HashMap::from([("X-Tenant".to_string(), "acme".to_string())]);
+        let endpoints = resource.endpoints(&headers);
+        let summary: Vec<(String, String, String)> = endpoints.iter()
+            .map(|e| (e.name.clone(), e.method.to_string(), e.url.clone()))
+            .collect();
+        let item = "https://api.example.com/users/{{user_id}}";
+        assert_eq!(summary, vec![
+            ("List users".to_string(), "GET".to_string(), "https://api.example.com/users".to_string()),
+            ("Get user".to_string(), "GET".to_string(), item.to_string()),
+            ("Create user".to_string(), "POST".to_string(), "https://api.example.com/users".to_string()),
+            ("Replace user".to_string(), "PUT".to_string(), item.to_string()),
+            ("Update user".to_string(), "PATCH".to_string(), item.to_string()),
+            ("Delete user".to_string(), "DELETE".to_string(), item.to_string()),
+        ]);
+        for endpoint in &endpoints {
+            assert_eq!(endpoint.headers.get("X-Tenant").map(String::as_str), Some("acme"));
+            assert_eq!(endpoint.headers.get("Accept").map(String::as_str), Some("application/json"));
+            assert_eq!(endpoint.tags, vec!["users"]);
+            let sends_body = matches!(endpoint.method, HttpMethod::POST | HttpMethod::PUT | HttpMethod::PATCH);
+            assert_eq!(endpoint.body_template.is_some(), sends_body);
+            assert_eq!(endpoint.headers.contains_key("Content-Type"), sends_body);
+        }
+
+        // Shared headers win over the defaults
+        let headers = HashMap::from([("content-type".to_string(), "application/vnd.api+json".to_string())]);
+        let create = &resource.endpoints(&headers)[2];
+        assert_eq!(create.headers.len(), 2);
+        assert_eq!(create.headers.get("content-type").map(String::as_str), Some("application/vnd.api+json"));
+    }
+}
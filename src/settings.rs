@@ -0,0 +1,348 @@
+// Workspace settings
+// Defaults for the HTTP client, the UI, load tests and history, kept in
+// config.toml next to the collections directory. Keys missing from the file
+// keep their built-in defaults, so a hand-written file only needs what changes.
+
+use crate::http::{format_bytes, HttpClientConfig};
+use crate::i18n::Locale;
+#[cfg(feature = "load-test")]
+use crate::load_test::LoadTestConfig;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SettingsError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid config.toml: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("TOML error: {0}")]
+    Serialize(#[from] toml::ser::Error),
+
+    #[error("{0}")]
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, SettingsError>;
+
+/// Colour scheme, for dark or light terminal backgrounds, or without
+/// red/green pairs for colour-blind users
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    #[serde(rename = "high-contrast")]
+    HighContrast,
+}
+
+impl Theme {
+    pub fn next(self) -> Self {
+        match self {
+            Theme::Dark => Theme::Light,
+            Theme::Light => Theme::HighContrast,
+            Theme::HighContrast => Theme::Dark,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::HighContrast => "high contrast",
+        }
+    }
+}
+
+/// What sending an endpoint that already has a request in flight does, so two
+/// responses for it can't arrive out of order
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepeatSend {
+    /// Cancel the request in flight; only the newest one's response shows
+    #[default]
+    Replace,
+    /// Wait for the request in flight, then send
+    Queue,
+}
+
+impl RepeatSend {
+    pub fn next(self) -> Self {
+        match self {
+            RepeatSend::Replace => RepeatSend::Queue,
+            RepeatSend::Queue => RepeatSend::Replace,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RepeatSend::Replace => "replace",
+            RepeatSend::Queue => "queue",
+        }
+    }
+}
+
+/// Prefilled in the load test form for endpoints without a saved configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoadTestDefaults {
+    pub concurrency: usize,
+    pub duration_secs: u64,
+}
+
+impl Default for LoadTestDefaults {
+    fn default() -> Self {
+        Self { concurrency: 10, duration_secs: 30 }
+    }
+}
+
+/// How much request history or load test output is kept; the oldest entries
+/// beyond any limit are pruned on startup and when settings are saved
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Retention {
+    pub max_entries: Option<usize>, // History entries, or load test runs
+    pub max_age_days: Option<u64>,
+    pub max_disk_mb: Option<u64>,
+}
+
+impl Retention {
+    pub fn max_disk_bytes(&self) -> Option<u64> {
+        self.max_disk_mb.map(|mb| mb * 1024 * 1024)
+    }
+
+    /// Entries older than this go
+    pub fn cutoff(&self) -> Option<DateTime<Utc>> {
+        self.max_age_days.map(|days| Utc::now() - chrono::Duration::days(days as i64))
+    }
+
+    fn validate(&self, what: &str) -> Result<()> {
+        if self.max_entries == Some(0) || self.max_age_days == Some(0) || self.max_disk_mb == Some(0) {
+            return Err(SettingsError::Invalid(format!("{} limits must be at least 1 (leave them empty for no limit)", what)));
+        }
+        Ok(())
+    }
+}
+
+/// Soft limits on body sizes: bigger requests and responses still go
+/// through, but are flagged so an accidentally huge payload gets noticed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SizeBudgets {
+    pub request_kb: Option<u64>,
+    pub response_kb: Option<u64>,
+}
+
+impl SizeBudgets {
+    /// A warning when a request body of `size` bytes is over budget
+    pub fn check_request(&self, size: usize) -> Option<String> {
+        Self::check("Request", size, self.request_kb)
+    }
+
+    /// A warning when a response body of `size` bytes is over budget
+    pub fn check_response(&self, size: usize) -> Option<String> {
+        Self::check("Response", size, self.response_kb)
+    }
+
+    fn check(what: &str, size: usize, budget_kb: Option<u64>) -> Option<String> {
+        let budget = budget_kb? * 1024;
+        (size as u64 > budget).then(|| format!("{} body is {}, over the {} budget", what, format_bytes(size as u64), format_bytes(budget)))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.request_kb == Some(0) || self.response_kb == Some(0) {
+            return Err(SettingsError::Invalid("Size budgets must be at least 1 KB (leave them empty for no budget)".to_string()));
+        }
+        Ok(())
+    }
+}
+
+// Plain values must come before the tables for TOML
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub timeout_secs: u64,
+    pub max_redirects: usize, // 0 = don't follow redirects
+    #[serde(skip_serializing)]
+    pub proxy: Option<String>, // Now in proxy.toml; only read to move an old value there
+    pub environment: Option<String>, // Active environment: its variables and client defaults apply
+    pub repeat_send: RepeatSend,
+    pub theme: Theme,
+    pub locale: Locale, // Language of the UI text
+    pub tick_rate_ms: u64, // Redraw interval while something animates
+    pub latency_decimals: usize, // Decimal places of latencies shown in milliseconds
+    pub load_test: LoadTestDefaults,
+    pub history: Retention,
+    pub results: Retention, // Saved load test runs and their HAR samples
+    pub budgets: SizeBudgets,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        let client = HttpClientConfig::default();
+        Self {
+            timeout_secs: client.timeout.as_secs(),
+            max_redirects: client.max_redirects,
+            proxy: None,
+            environment: None,
+            repeat_send: RepeatSend::default(),
+            theme: Theme::default(),
+            locale: Locale::default(),
+            tick_rate_ms: 100,
+            latency_decimals: 1,
+            load_test: LoadTestDefaults::default(),
+            history: Retention::default(),
+            results: Retention::default(),
+            budgets: SizeBudgets::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Load the settings; a missing file means the built-in defaults
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let settings: Self = toml::from_str(&fs::read_to_string(path)?)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        self.validate()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: &str| Err(SettingsError::Invalid(message.to_string()));
+        if self.timeout_secs == 0 || self.timeout_secs > 3600 {
+            return invalid("Timeout must be between 1 and 3600 seconds");
+        }
+        if self.max_redirects > 100 {
+            return invalid("Redirects must be between 0 and 100");
+        }
+        if let Some(proxy) = &self.proxy {
+            reqwest::Proxy::all(proxy).map_err(|e| SettingsError::Invalid(format!("Invalid proxy '{}': {}", proxy, e)))?;
+        }
+        if !(16..=2000).contains(&self.tick_rate_ms) {
+            return invalid("Tick rate must be between 16 and 2000 ms");
+        }
+        if self.latency_decimals > 3 {
+            return invalid("Latency decimals must be between 0 and 3");
+        }
+        self.history.validate("History")?;
+        self.results.validate("Load test result")?;
+        self.budgets.validate()?;
+        #[cfg(feature = "load-test")]
+        self.default_load_test().validate().map_err(SettingsError::Invalid)?;
+        Ok(())
+    }
+
+    /// The client used for interactive requests and inherited by load tests
+    pub fn client_config(&self) -> HttpClientConfig {
+        HttpClientConfig {
+            timeout: Duration::from_secs(self.timeout_secs),
+            max_redirects: self.max_redirects,
+            ..HttpClientConfig::default()
+        }
+    }
+
+    #[cfg(feature = "load-test")]
+    pub fn default_load_test(&self) -> LoadTestConfig {
+        LoadTestConfig::new(self.load_test.concurrency, Duration::from_secs(self.load_test.duration_secs))
+    }
+
+    pub fn tick_rate(&self) -> Duration {
+        Duration::from_millis(self.tick_rate_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_file_keeps_defaults() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.toml");
+        assert_eq!(Settings::load(&path).unwrap(), Settings::default());
+
+        fs::write(&path, "timeout_secs = 5\ntheme = \"light\"\n\n[load_test]\nconcurrency = 50\n").unwrap();
+        let settings = Settings::load(&path).unwrap();
+        assert_eq!((settings.timeout_secs, settings.theme), (5, Theme::Light));
+        assert_eq!(settings.load_test, LoadTestDefaults { concurrency: 50, duration_secs: 30 });
+        assert_eq!(settings.max_redirects, 10);
+
+        let mut changed = settings.clone();
+        changed.environment = Some("staging".to_string());
+        changed.proxy = Some("http://proxy.local:3128".to_string());
+        changed.history.max_entries = Some(500);
+        changed.results = Retention { max_entries: None, max_age_days: Some(30), max_disk_mb: Some(200) };
+        changed.budgets = SizeBudgets { request_kb: Some(512), response_kb: None };
+        changed.save(&path).unwrap();
+        // The proxy moved to proxy.toml, so it's no longer written here
+        assert_eq!(Settings::load(&path).unwrap(), Settings { proxy: None, ..changed.clone() });
+        assert_eq!(changed.client_config().proxy, None);
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.toml");
+        fs::write(&path, "timeout_secs = \"soon\"").unwrap();
+        assert!(matches!(Settings::load(&path), Err(SettingsError::Parse(_))));
+
+        let settings = Settings { tick_rate_ms: 1, ..Settings::default() };
+        assert_eq!(settings.save(&path).unwrap_err().to_string(), "Tick rate must be between 16 and 2000 ms");
+        let settings = Settings { latency_decimals: 4, ..Settings::default() };
+        assert_eq!(settings.validate().unwrap_err().to_string(), "Latency decimals must be between 0 and 3");
+        let settings = Settings { proxy: Some("not a proxy".to_string()), ..Settings::default() };
+        assert!(settings.validate().is_err());
+        #[cfg(feature = "load-test")]
+        {
+            let settings = Settings { load_test: LoadTestDefaults { concurrency: 0, duration_secs: 30 }, ..Settings::default() };
+            assert_eq!(settings.validate().unwrap_err().to_string(), "Concurrency must be between 1 and 1000");
+        }
+        let settings = Settings { results: Retention { max_age_days: Some(0), ..Retention::default() }, ..Settings::default() };
+        assert_eq!(settings.validate().unwrap_err().to_string(), "Load test result limits must be at least 1 (leave them empty for no limit)");
+        let settings = Settings { budgets: SizeBudgets { request_kb: Some(0), response_kb: None }, ..Settings::default() };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_theme_cycles_through_high_contrast() {
+        assert_eq!(Theme::Dark.next().next(), Theme::HighContrast);
+        assert_eq!(Theme::HighContrast.next(), Theme::Dark);
+        let settings: Settings = toml::from_str("theme = \"high-contrast\"\nlocale = \"de\"").unwrap();
+        assert_eq!((settings.theme, settings.locale), (Theme::HighContrast, Locale::De));
+    }
+
+    #[test]
+    fn test_repeat_send() {
+        assert_eq!(Settings::default().repeat_send, RepeatSend::Replace);
+        let settings: Settings = toml::from_str("repeat_send = \"queue\"").unwrap();
+        assert_eq!(settings.repeat_send, RepeatSend::Queue);
+        assert_eq!(RepeatSend::Queue.next(), RepeatSend::Replace);
+    }
+
+    #[test]
+    fn test_size_budgets() {
+        let budgets = SizeBudgets { request_kb: Some(1), response_kb: Some(1024) };
+        assert_eq!(budgets.check_request(1024), None);
+        assert_eq!(budgets.check_request(1536).as_deref(), Some("Request body is 1.5 KB, over the 1.0 KB budget"));
+        assert_eq!(budgets.check_response(3 * 1024 * 1024).as_deref(), Some("Response body is 3.0 MB, over the 1.0 MB budget"));
+        assert_eq!(SizeBudgets::default().check_response(usize::MAX), None);
+    }
+}
@@ -1,6 +1,10 @@
 // Storage layer for persisting collections and load test results
 
+#[cfg(feature = "load-test")]
+use crate::load_test::{LoadTestReport, RunStatus};
 use crate::models::ApiCollection;
+#[cfg(feature = "load-test")]
+use crate::settings::Retention;
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -16,14 +20,98 @@ pub enum StorageError {
     
     #[error("Collection not found: {0}")]
     NotFound(Uuid),
+    
+    #[error("Collection is read-only: {0}")]
+    ReadOnly(Uuid),
 }
 
 pub type Result<T> = std::result::Result<T, StorageError>;
 
+/// A collection file that couldn't be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFile {
+    pub path: PathBuf,
+    pub error: String,
+}
+
+/// Turn a user-typed path into a `PathBuf`, expanding a leading `~/` (or
+/// `~\`, as typed on Windows) or a lone `~`
+pub fn expand_home(path: &str) -> PathBuf {
+    let path = path.trim();
+    match path.strip_prefix("~/").or_else(|| path.strip_prefix("~\\")) {
+        Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+        None if path == "~" => dirs::home_dir().unwrap_or_default(),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Directory the app keeps its files in: `REST_API_TUI_HOME` when set,
+/// otherwise `.rest-api-tui` in the home directory (`%USERPROFILE%` on
+/// Windows, whatever `HOME` a Git Bash or MSYS shell sets)
+pub fn data_dir() -> Option<PathBuf> {
+    match std::env::var_os("REST_API_TUI_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => dirs::home_dir().map(|home| home.join(".rest-api-tui")),
+    }
+}
+
+/// Load every collection file in `dir`, also returning the files skipped as
+/// unreadable. A missing directory has no collections
+pub fn load_collections_from(dir: &Path) -> Result<(Vec<ApiCollection>, Vec<SkippedFile>)> {
+    let mut collections = Vec::new();
+    let mut skipped = Vec::new();
+    
+    // Read all files in the collections directory
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            // Directory doesn't exist yet, return empty vec
+            return Ok((collections, skipped));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        
+        // Only process .json files
+        if path.extension().and_then(|s| s.to_str()) != Some("json") {
+            continue;
+        }
+        
+        // Try to load and parse the collection
+        match load_collection_file(&path) {
+            Ok(collection) => collections.push(collection),
+            Err(e) => {
+                // Log error and skip corrupted files
+                tracing::warn!("Skipping collection {}: {}", path.display(), e);
+                skipped.push(SkippedFile { path, error: e.to_string() });
+                continue;
+            }
+        }
+    }
+    
+    tracing::info!("Loaded {} collections from {}", collections.len(), dir.display());
+    skipped.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok((collections, skipped))
+}
+
+/// Load a single collection file
+pub fn load_collection_file(path: &Path) -> Result<ApiCollection> {
+    let contents = fs::read_to_string(path)?;
+    let mut collection: ApiCollection = serde_json::from_str(&contents)?;
+    
+    // A write-protected file (e.g. checked out from a shared repo) is read-only too
+    if fs::metadata(path)?.permissions().readonly() {
+        collection.read_only = true;
+    }
+    Ok(collection)
+}
+
 /// Manages persistent storage of collections and load test results
 pub struct StorageManager {
     collections_dir: PathBuf,
-    #[allow(dead_code)]
     results_dir: PathBuf,
 }
 
@@ -42,8 +130,7 @@ impl StorageManager {
     
     /// Create a StorageManager with default directories in user's home
     pub fn with_defaults() -> Result<Self> {
-        let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
-        let base_dir = home.join(".rest-api-tui");
+        let base_dir = data_dir().unwrap_or_else(|| PathBuf::from(".rest-api-tui"));
         
         Self::new(
             base_dir.join("collections"),
@@ -51,6 +138,106 @@ impl StorageManager {
         )
     }
     
+    /// Directory for load test output such as sampled HAR files
+    pub fn results_dir(&self) -> &Path {
+        &self.results_dir
+    }
+    
+    #[cfg(feature = "load-test")]
+    /// Save a load test run as its own file in the results directory; a
+    /// checkpoint is overwritten by later ones and the final report
+    pub fn save_load_test_report(&self, report: &LoadTestReport) -> Result<PathBuf> {
+        let name = format!("{}-{}.json", report.started_at.format("%Y%m%d-%H%M%S"), report.id.simple());
+        let path = self.results_dir.join(name);
+        fs::write(&path, serde_json::to_string_pretty(report)?)?;
+        Ok(path)
+    }
+    
+    #[cfg(feature = "load-test")]
+    /// Every saved load test report, oldest first; unreadable files are skipped
+    pub fn load_test_reports(&self) -> Result<Vec<LoadTestReport>> {
+        Ok(self.report_files()?.into_iter().map(|(_, report)| report).collect())
+    }
+    
+    #[cfg(feature = "load-test")]
+    fn report_files(&self) -> Result<Vec<(PathBuf, LoadTestReport)>> {
+        let mut reports = Vec::new();
+        for entry in fs::read_dir(&self.results_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                if let Ok(report) = serde_json::from_str::<LoadTestReport>(&fs::read_to_string(&path)?) {
+                    reports.push((path, report));
+                }
+            }
+        }
+        reports.sort_by_key(|(_, report)| report.started_at);
+        Ok(reports)
+    }
+    
+    #[cfg(feature = "load-test")]
+    /// Delete the oldest load test runs (report and HAR samples) beyond any of
+    /// the retention limits; runs still in progress are left alone. Returns
+    /// how many runs were removed
+    pub fn prune_results(&self, retention: &Retention) -> Result<usize> {
+        let file_size = |path: &Path| fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        let cutoff = retention.cutoff();
+        let budget = retention.max_disk_bytes();
+        let (mut kept, mut used, mut removed) = (0, 0, 0);
+        for (path, report) in self.report_files()?.into_iter().rev() {
+            let size = file_size(&path) + report.har_path.as_deref().map(file_size).unwrap_or(0);
+            let fits = retention.max_entries.is_none_or(|max| kept < max)
+                && cutoff.is_none_or(|c| report.started_at >= c)
+                && budget.is_none_or(|budget| used + size <= budget);
+            if fits || report.status == RunStatus::Running {
+                kept += 1;
+                used += size;
+                continue;
+            }
+            fs::remove_file(&path)?;
+            if let Some(har) = &report.har_path {
+                let _ = fs::remove_file(har);
+            }
+            removed += 1;
+        }
+        if removed > 0 {
+            tracing::info!("Pruned {} old load test runs", removed);
+        }
+        Ok(removed)
+    }
+    
+    /// Bytes on disk in the results directory
+    pub fn results_disk_usage(&self) -> u64 {
+        fs::read_dir(&self.results_dir).into_iter().flatten().flatten()
+            .filter_map(|entry| entry.metadata().ok())
+            .map(|m| m.len())
+            .sum()
+    }
+    
+    /// Workspace-wide host allowlist, next to the collections directory
+    pub fn allowlist_path(&self) -> PathBuf {
+        self.collections_dir.with_file_name("allowlist.json")
+    }
+    
+    /// Application log, next to the collections directory
+    pub fn log_path(&self) -> PathBuf {
+        self.collections_dir.with_file_name("app.log")
+    }
+    
+    /// Journal of imports and edits, next to the collections directory
+    pub fn activity_path(&self) -> PathBuf {
+        self.collections_dir.with_file_name("activity.jsonl")
+    }
+    
+    /// Workspace settings live next to the collections directory too
+    pub fn config_path(&self) -> PathBuf {
+        self.collections_dir.with_file_name("config.toml")
+    }
+    
+    /// Global and per-collection proxies, beside config.toml
+    pub fn proxy_path(&self) -> PathBuf {
+        self.collections_dir.with_file_name("proxy.toml")
+    }
+    
     /// Get the path to a collection file
     fn collection_path(&self, id: &Uuid) -> PathBuf {
         self.collections_dir.join(format!("{}.json", id))
@@ -58,50 +245,73 @@ impl StorageManager {
     
     /// Load all collections from the collections directory
     pub fn load_collections(&self) -> Result<Vec<ApiCollection>> {
-        let mut collections = Vec::new();
-        
-        // Read all files in the collections directory
-        let entries = match fs::read_dir(&self.collections_dir) {
-            Ok(entries) => entries,
-            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-                // Directory doesn't exist yet, return empty vec
-                return Ok(collections);
-            }
-            Err(e) => return Err(e.into()),
-        };
+        Ok(self.load_collections_reporting()?.0)
+    }
+    
+    /// Load all collections, also returning the files skipped as unreadable
+    pub fn load_collections_reporting(&self) -> Result<(Vec<ApiCollection>, Vec<SkippedFile>)> {
+        load_collections_from(&self.collections_dir)
+    }
+
+    /// Unreadable collection files are moved here, out of the way of loading
+    pub fn quarantine_dir(&self) -> PathBuf {
+        self.collections_dir.with_file_name("quarantine")
+    }
+    
+    /// Move a file into the quarantine directory, keeping its name unless that
+    /// is taken already. Returns the new path
+    pub fn quarantine(&self, path: &Path) -> Result<PathBuf> {
+        let dir = self.quarantine_dir();
+        fs::create_dir_all(&dir)?;
+        let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let mut target = dir.join(&name);
+        if target.exists() {
+            target = dir.join(format!("{}.{}", name, chrono::Utc::now().format("%Y%m%d-%H%M%S%.3f")));
+        }
+        fs::rename(path, &target)?;
+        tracing::info!("Quarantined {} as {}", path.display(), target.display());
+        Ok(target)
+    }
+    
+    /// Salvage what still parses from a collection file that doesn't load as a
+    /// whole: endpoints that fail on their own are dropped, and a missing id,
+    /// name or timestamp is filled in. Returns the collection (not saved yet)
+    /// and how many endpoints were dropped
+    pub fn recover_collection(&self, path: &Path) -> Result<(ApiCollection, usize)> {
+        let value: serde_json::Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+        let field = |name: &str| value.get(name).cloned().unwrap_or_default();
+        let fallback_name = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
         
-        for entry in entries {
-            let entry = entry?;
-            let path = entry.path();
-            
-            // Only process .json files
-            if path.extension().and_then(|s| s.to_str()) != Some("json") {
-                continue;
-            }
-            
-            // Try to load and parse the collection
-            match self.load_collection_from_path(&path) {
-                Ok(collection) => collections.push(collection),
-                Err(e) => {
-                    // Log error and skip corrupted files
-                    eprintln!("Warning: Failed to load collection from {:?}: {}", path, e);
-                    continue;
-                }
-            }
+        let mut collection = ApiCollection::new(field("name").as_str().map(str::to_string).unwrap_or(fallback_name));
+        if let Ok(id) = serde_json::from_value(field("id")) {
+            collection.id = id;
+        }
+        if let Ok(created_at) = serde_json::from_value(field("created_at")) {
+            collection.created_at = created_at;
         }
+        collection.read_only = field("read_only").as_bool().unwrap_or(false);
+        collection.source = serde_json::from_value(field("source")).ok();
         
-        Ok(collections)
+        let raw_endpoints = field("endpoints").as_array().cloned().unwrap_or_default();
+        collection.endpoints = raw_endpoints.iter()
+            .filter_map(|endpoint| serde_json::from_value(endpoint.clone()).ok())
+            .collect();
+        let dropped = raw_endpoints.len() - collection.endpoints.len();
+        Ok((collection, dropped))
     }
     
     /// Load a single collection from a file path
-    fn load_collection_from_path(&self, path: &Path) -> Result<ApiCollection> {
-        let contents = fs::read_to_string(path)?;
-        let collection: ApiCollection = serde_json::from_str(&contents)?;
-        Ok(collection)
+    pub fn load_collection_from_path(&self, path: &Path) -> Result<ApiCollection> {
+        load_collection_file(path)
     }
     
-    /// Save a collection to disk using atomic writes
+    /// Save a collection to disk using atomic writes. Read-only collections are refused,
+    /// since the rename would otherwise replace even a write-protected file.
     pub fn save_collection(&self, collection: &ApiCollection) -> Result<()> {
+        if collection.read_only {
+            return Err(StorageError::ReadOnly(collection.id));
+        }
+        
         let path = self.collection_path(&collection.id);
         
         // Serialize to JSON with pretty printing
@@ -111,6 +321,7 @@ impl StorageManager {
         let temp_path = path.with_extension("json.tmp");
         fs::write(&temp_path, json)?;
         fs::rename(&temp_path, &path)?;
+        tracing::debug!("Saved collection '{}' to {}", collection.name, path.display());
         
         Ok(())
     }
@@ -123,7 +334,8 @@ impl StorageManager {
             return Err(StorageError::NotFound(*id));
         }
         
-        fs::remove_file(path)?;
+        fs::remove_file(&path)?;
+        tracing::info!("Deleted collection {}", path.display());
         Ok(())
     }
 }
@@ -133,19 +345,70 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_expand_home() {
+        let home = dirs::home_dir().unwrap_or_default();
+        assert_eq!(expand_home(" ~/certs/ca.pem "), home.join("certs/ca.pem"));
+        assert_eq!(expand_home("~\\certs\\ca.pem"), home.join("certs\\ca.pem"));
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home("~user/x"), PathBuf::from("~user/x"));
+        assert_eq!(expand_home("/tmp/out.json"), PathBuf::from("/tmp/out.json"));
+    }
+
     #[test]
     fn test_storage_manager_creation() {
         let temp_dir = TempDir::new().unwrap();
         let collections_dir = temp_dir.path().join("collections");
         let results_dir = temp_dir.path().join("results");
         
-        let storage = StorageManager::new(collections_dir.clone(), results_dir.clone()).unwrap();
+        let _storage = StorageManager::new(collections_dir.clone(), results_dir.clone()).unwrap();
         
         // Verify directories were created
         assert!(collections_dir.exists());
         assert!(results_dir.exists());
     }
     
+    #[test]
+    #[cfg(feature = "load-test")]
+    fn test_prune_results() {
+        use crate::load_test::{LoadTestConfig, LoadTestMetrics, LoadTestTarget};
+        use std::time::Duration;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = StorageManager::new(temp_dir.path().join("collections"), temp_dir.path().join("results")).unwrap();
+        let target = LoadTestTarget {
+            collection_id: Uuid::new_v4(),
+            endpoint_id: Uuid::new_v4(),
+            endpoint_name: "orders".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/orders".to_string(),
+        };
+        let config = LoadTestConfig::new(5, Duration::from_secs(60));
+        let save = |days_ago: i64, status: RunStatus| {
+            let mut report = LoadTestReport::new(target.clone(), &config, &LoadTestMetrics::new(), Duration::from_secs(60));
+            report.started_at = chrono::Utc::now() - chrono::Duration::days(days_ago);
+            report.status = status;
+            let har = storage.results_dir().join(format!("{}.har", days_ago));
+            fs::write(&har, "{}").unwrap();
+            report.har_path = Some(har);
+            storage.save_load_test_report(&report).unwrap();
+            report
+        };
+        // A checkpoint of a run still going is never pruned, however old
+        let running = save(90, RunStatus::Running);
+        let old = save(60, RunStatus::Completed);
+        save(2, RunStatus::Completed);
+        save(1, RunStatus::Completed);
+
+        assert_eq!(storage.prune_results(&Retention { max_age_days: Some(30), ..Retention::default() }).unwrap(), 1);
+        assert!(!old.har_path.unwrap().exists());
+        assert_eq!(storage.prune_results(&Retention { max_entries: Some(1), ..Retention::default() }).unwrap(), 1);
+        let started: Vec<_> = storage.load_test_reports().unwrap().iter().map(|r| r.started_at).collect();
+        assert_eq!(started.len(), 2);
+        assert_eq!(started[0], running.started_at);
+        assert!(storage.results_disk_usage() > 0);
+    }
+
     #[test]
     fn test_collection_path() {
         let temp_dir = TempDir::new().unwrap();
@@ -191,6 +454,40 @@ mod tests {
         assert_eq!(collections.len(), 0);
     }
     
+    #[test]
+    fn test_recover_and_quarantine_corrupted_collection() {
+        let temp_dir = TempDir::new().unwrap();
+        let collections_dir = temp_dir.path().join("collections");
+        let storage = StorageManager::new(collections_dir.clone(), temp_dir.path().join("results")).unwrap();
+        
+        let mut collection = ApiCollection::new("Users".to_string());
+        collection.add_endpoint(crate::models::ApiEndpoint::new("list".to_string(), crate::models::HttpMethod::GET, "https://api/users".to_string()));
+        let mut value = serde_json::to_value(&collection).unwrap();
+        let good = value["endpoints"][0].clone();
+        value["endpoints"] = serde_json::json!([good, { "name": "broken", "method": "FETCH" }]);
+        let path = collections_dir.join(format!("{}.json", collection.id));
+        fs::write(&path, value.to_string()).unwrap();
+        
+        let (loaded, skipped) = storage.load_collections_reporting().unwrap();
+        assert!(loaded.is_empty());
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].path, path);
+        
+        let (recovered, dropped) = storage.recover_collection(&path).unwrap();
+        assert_eq!((recovered.id, recovered.name.as_str(), dropped), (collection.id, "Users", 1));
+        assert_eq!(recovered.endpoints[0].url, "https://api/users");
+        
+        // Quarantining twice keeps both copies
+        let first = storage.quarantine(&path).unwrap();
+        fs::write(&path, "{").unwrap();
+        assert!(storage.recover_collection(&path).is_err());
+        let second = storage.quarantine(&path).unwrap();
+        assert_ne!(first, second);
+        assert!(first.exists() && second.exists() && !path.exists());
+        let (loaded, skipped) = storage.load_collections_reporting().unwrap();
+        assert!(loaded.is_empty() && skipped.is_empty());
+    }
+    
     #[test]
     fn test_save_and_load_collection() {
         let temp_dir = TempDir::new().unwrap();
@@ -271,4 +568,29 @@ mod tests {
             _ => panic!("Expected NotFound error"),
         }
     }
+    
+    #[test]
+    fn test_write_protected_file_loads_read_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = StorageManager::new(
+            temp_dir.path().join("collections"),
+            temp_dir.path().join("results"),
+        ).unwrap();
+        
+        let collection = ApiCollection::new("Shared".to_string());
+        storage.save_collection(&collection).unwrap();
+        let path = storage.collection_path(&collection.id);
+        let mut permissions = fs::metadata(&path).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&path, permissions).unwrap();
+        
+        let loaded = storage.load_collections().unwrap();
+        assert!(loaded[0].read_only);
+        
+        // Saving must not replace the protected file
+        match storage.save_collection(&loaded[0]) {
+            Err(StorageError::ReadOnly(id)) => assert_eq!(id, collection.id),
+            _ => panic!("Expected ReadOnly error"),
+        }
+    }
 }
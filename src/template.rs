@@ -1,4 +1,8 @@
 use std::collections::HashMap;
+use base64::Engine;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use sha2::{Digest, Sha256};
 use thiserror::Error;
 use crate::faker;
 
@@ -12,16 +16,404 @@ pub enum TemplateError {
     
     #[error("Unknown faker variable: {0}")]
     UnknownFakerVariable(String),
+
+    #[error("Unknown filter: {0} (use base64, urlencode or sha256)")]
+    UnknownFilter(String),
+
+    #[error("{}", .0)]
+    Located(Box<LocatedError>),
 }
 
 pub type Result<T> = std::result::Result<T, TemplateError>;
 
+/// Where in a request a template error happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorLocation {
+    pub field: String, // "URL", "header X-Tenant", "body", ...
+    pub offset: usize, // 1-based character offset into the field
+    pub line: usize,   // 1-based
+    pub column: usize, // 1-based, in characters
+    pub snippet: String, // The line the error is on
+    pub width: usize, // Characters to underline from `column`
+}
+
+#[derive(Debug, Error)]
+#[error("{error} ({}, line {}, column {})", .location.field, .location.line, .location.column)]
+pub struct LocatedError {
+    pub error: TemplateError,
+    pub location: ErrorLocation,
+}
+
+impl ErrorLocation {
+    /// Caret line to print under `snippet`
+    pub fn caret(&self) -> String {
+        format!("{}{}", " ".repeat(self.column - 1), "^".repeat(self.width.max(1)))
+    }
+}
+
+impl TemplateError {
+    /// Attach the position of the offending tag within `template`; errors that
+    /// can't be pinned down keep just the field name at its start
+    pub fn locate(self, field: &str, template: &str) -> Self {
+        if matches!(self, Self::Located(_)) {
+            return self;
+        }
+        let (start, len) = error_span(template, &self).unwrap_or((0, 0));
+        let line_start = template[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = template[start..].find('\n').map_or(template.len(), |i| start + i);
+        let location = ErrorLocation {
+            field: field.to_string(),
+            offset: template[..start].chars().count() + 1,
+            line: template[..start].matches('\n').count() + 1,
+            column: template[line_start..start].chars().count() + 1,
+            snippet: template[line_start..line_end].trim_end_matches('\r').to_string(),
+            width: template[start..(start + len).min(line_end)].chars().count(),
+        };
+        Self::Located(Box::new(LocatedError { error: self, location }))
+    }
+
+    pub fn location(&self) -> Option<&ErrorLocation> {
+        match self {
+            Self::Located(located) => Some(&located.location),
+            _ => None,
+        }
+    }
+}
+
+/// Byte offset and length of the tag an error is about
+fn error_span(template: &str, error: &TemplateError) -> Option<(usize, usize)> {
+    let segments = segments(template);
+    let offset = |text: &str| text.as_ptr() as usize - template.as_ptr() as usize;
+    let tag = segments.iter().find_map(|segment| {
+        let Node::Tag { raw, inner } = segment else { return None };
+        let name = inner.split('|').next().unwrap_or_default().trim();
+        let matches = match error {
+            TemplateError::MissingVariable(variable) => {
+                name == variable || inner.strip_prefix('#').and_then(|b| b.split_whitespace().nth(1)) == Some(variable)
+            }
+            TemplateError::UnknownFakerVariable(variable) => {
+                faker::is_faker_variable(name) && faker::extract_faker_name(name) == variable
+            }
+            TemplateError::UnknownFilter(filter) => inner.split('|').skip(1).any(|f| f.trim() == filter),
+            TemplateError::InvalidSyntax(message) => message.contains(&format!("{{{{{}}}}}", name)),
+            TemplateError::Located(_) => false,
+        };
+        matches.then(|| (offset(raw), raw.len()))
+    });
+    // An unclosed `{{` is left at the end of the segments as text
+    let unclosed = || match (error, segments.last()) {
+        (TemplateError::InvalidSyntax(_), Some(Node::Text(text))) => text.find("{{").map(|i| (offset(text) + i, text.len() - i)),
+        _ => None,
+    };
+    tag.or_else(unclosed)
+}
+
+/// `substitute`, with errors located in the named request field
+pub fn substitute_field(field: &str, template: &str, variables: &HashMap<String, String>) -> Result<String> {
+    substitute(template, variables).map_err(|e| e.locate(field, template))
+}
+
+/// Whether a variable is generated rather than looked up, e.g. `$now+2d:%Y-%m-%d`
+pub fn is_builtin_variable(variable: &str) -> bool {
+    variable.starts_with("$now")
+}
+
+/// Value of a `$now` variable: `$now`, `$now-1h`, `$now+2d:%Y-%m-%d`, `$now:unix`.
+/// Offsets take s, m, h, d or w; the format is `iso` (the default), `unix` or
+/// a strftime pattern. Times are in UTC.
+fn now_value(variable: &str, now: DateTime<Utc>) -> Result<String> {
+    let invalid = |reason: &str| TemplateError::InvalidSyntax(format!("{{{{{}}}}}: {}", variable, reason));
+    let rest = variable.strip_prefix("$now").ok_or_else(|| invalid("expected $now"))?;
+    let (offset, format) = match rest.split_once(':') {
+        Some((offset, format)) => (offset.trim(), format),
+        None => (rest.trim(), "iso"),
+    };
+
+    let time = if offset.is_empty() {
+        now
+    } else {
+        let sign = match offset.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => return Err(invalid("offset must start with + or -")),
+        };
+        let offset = offset[1..].trim();
+        let (amount, unit) = offset.split_at(offset.char_indices().last().map_or(0, |(i, _)| i));
+        let amount: i64 = amount.trim().parse().map_err(|_| invalid("offset must be a whole number like +2d"))?;
+        let step = match unit {
+            "s" => Duration::try_seconds(amount),
+            "m" => Duration::try_minutes(amount),
+            "h" => Duration::try_hours(amount),
+            "d" => Duration::try_days(amount),
+            "w" => Duration::try_weeks(amount),
+            _ => return Err(invalid("offset unit must be s, m, h, d or w")),
+        };
+        step.and_then(|step| now.checked_add_signed(step * sign))
+            .ok_or_else(|| invalid("offset out of range"))?
+    };
+
+    match format {
+        "iso" => Ok(time.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        "unix" => Ok(time.timestamp().to_string()),
+        pattern => {
+            // Formatting an invalid pattern panics, so check it first
+            if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+                return Err(invalid("invalid date format"));
+            }
+            Ok(time.format(pattern).to_string())
+        }
+    }
+}
+
+/// Value of the inside of `{{...}}`: a variable followed by any `| filter`s
+fn resolve(expression: &str, variables: &HashMap<String, String>) -> Result<String> {
+    let mut parts = expression.split('|').map(str::trim);
+    let var_name = parts.next().unwrap_or_default();
+
+    let mut value = if is_builtin_variable(var_name) {
+        now_value(var_name, Utc::now())?
+    } else if faker::is_faker_variable(var_name) {
+        // Faker variable (f:variablename)
+        let faker_name = faker::extract_faker_name(var_name);
+        faker::generate_fake_value(faker_name)
+            .ok_or_else(|| TemplateError::UnknownFakerVariable(faker_name.to_string()))?
+    } else {
+        // Regular user variable
+        variables.get(var_name)
+            .cloned()
+            .ok_or_else(|| TemplateError::MissingVariable(var_name.to_string()))?
+    };
+
+    for filter in parts {
+        value = apply_filter(filter, &value)?;
+    }
+    Ok(value)
+}
+
+/// Transform a value with a filter, as in `{{token | base64}}`
+pub fn apply_filter(filter: &str, value: &str) -> Result<String> {
+    match filter {
+        "base64" => Ok(base64::engine::general_purpose::STANDARD.encode(value)),
+        "urlencode" => Ok(urlencoding::encode(value).into_owned()),
+        "sha256" => Ok(format!("{:x}", Sha256::digest(value.as_bytes()))),
+        _ => Err(TemplateError::UnknownFilter(filter.to_string())),
+    }
+}
+
+// Blocks
+// `{{#if x}}...{{else}}...{{/if}}`, `{{#unless x}}...{{/unless}}` and
+// `{{#each items}}...{{/each}}` are expanded as tags are substituted, in one pass,
+// so a value containing `{{` is never read as a tag.
+// Inside `each`, `{{this}}` is the item, `{{this.field}}` a field of an object
+// item, and `{{@index}}`, `{{@first}}`, `{{@last}}` its position.
+
+/// One piece of a template: text, a plain `{{tag}}` or a block
+#[derive(Debug)]
+enum Node<'a> {
+    Text(&'a str),
+    Tag { raw: &'a str, inner: &'a str },
+    If { name: &'a str, negate: bool, then: Vec<Node<'a>>, otherwise: Vec<Node<'a>> },
+    Each { name: &'a str, body: Vec<Node<'a>> },
+}
+
+/// How a run of nodes ended
+#[derive(Debug, PartialEq, Eq)]
+enum BlockEnd<'a> {
+    Eof,
+    Else,
+    Close(&'a str),
+}
+
+/// Variables only defined inside an `each` block
+fn is_loop_local(name: &str) -> bool {
+    name == "this" || name.starts_with("this.") || name.starts_with('@')
+}
+
+/// Split a template into text and `{{tag}}` segments; an unclosed `{{` stays text
+fn segments(template: &str) -> Vec<Node<'_>> {
+    let mut nodes = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        // Escaped braces stay in the text, for substitution to unescape
+        if rest[..start].ends_with('\\') {
+            nodes.push(Node::Text(&rest[..start + 2]));
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        if start > 0 {
+            nodes.push(Node::Text(&rest[..start]));
+        }
+        let end = start + 2 + len + 2;
+        nodes.push(Node::Tag { raw: &rest[start..end], inner: rest[start + 2..end - 2].trim() });
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        nodes.push(Node::Text(rest));
+    }
+    nodes
+}
+
+fn parse_blocks<'a>(
+    segments: &mut std::vec::IntoIter<Node<'a>>,
+    open: Option<&str>,
+) -> Result<(Vec<Node<'a>>, BlockEnd<'a>)> {
+    let mut nodes = Vec::new();
+    while let Some(node) = segments.next() {
+        let Node::Tag { inner, .. } = node else {
+            nodes.push(node);
+            continue;
+        };
+        if inner == "else" {
+            if !matches!(open, Some("if" | "unless")) {
+                return Err(TemplateError::InvalidSyntax("{{else}} outside an {{#if}} block".to_string()));
+            }
+            return Ok((nodes, BlockEnd::Else));
+        }
+        if let Some(name) = inner.strip_prefix('/') {
+            let name = name.trim();
+            if open != Some(name) {
+                return Err(TemplateError::InvalidSyntax(format!("Unexpected {{{{/{}}}}}", name)));
+            }
+            return Ok((nodes, BlockEnd::Close(name)));
+        }
+        let Some(block) = inner.strip_prefix('#') else {
+            nodes.push(node);
+            continue;
+        };
+
+        let mut words = block.split_whitespace();
+        let (kind, name) = match (words.next(), words.next(), words.next()) {
+            (Some(kind), Some(name), None) => (kind, name),
+            _ => return Err(TemplateError::InvalidSyntax(format!("Expected {{{{#{} name}}}}", block.trim()))),
+        };
+        let unclosed = || TemplateError::InvalidSyntax(format!("Unclosed {{{{#{} {}}}}} block", kind, name));
+        match kind {
+            "if" | "unless" => {
+                let (then, end) = parse_blocks(segments, Some(kind))?;
+                let otherwise = match end {
+                    BlockEnd::Else => match parse_blocks(segments, Some(kind))? {
+                        (otherwise, BlockEnd::Close(_)) => otherwise,
+                        (_, BlockEnd::Else) => return Err(TemplateError::InvalidSyntax("Second {{else}} in one block".to_string())),
+                        (_, BlockEnd::Eof) => return Err(unclosed()),
+                    },
+                    BlockEnd::Close(_) => Vec::new(),
+                    BlockEnd::Eof => return Err(unclosed()),
+                };
+                nodes.push(Node::If { name, negate: kind == "unless", then, otherwise });
+            }
+            "each" => match parse_blocks(segments, Some(kind))? {
+                (body, BlockEnd::Close(_)) => nodes.push(Node::Each { name, body }),
+                _ => return Err(unclosed()),
+            },
+            _ => return Err(TemplateError::InvalidSyntax(format!("Unknown block {{{{#{}}}}}: use #if, #unless or #each", kind))),
+        }
+    }
+    // Callers report an unclosed block, naming its opening tag
+    Ok((nodes, BlockEnd::Eof))
+}
+
+/// Empty, `false`, `0`, `null` and empty JSON collections are false, like a missing variable
+fn is_truthy(value: Option<&String>) -> bool {
+    value.is_some_and(|v| !matches!(v.trim(), "" | "false" | "0" | "null" | "[]" | "{}"))
+}
+
+/// Items of an `each` variable: a JSON array, or else a comma-separated list
+fn each_items(value: &str) -> Vec<serde_json::Value> {
+    match serde_json::from_str(value) {
+        Ok(serde_json::Value::Array(items)) => items,
+        _ => value.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| serde_json::Value::String(item.to_string()))
+            .collect(),
+    }
+}
+
+/// Strings are inserted without their quotes, anything else as JSON
+fn item_text(item: &serde_json::Value) -> String {
+    match item {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Lenient rendering leaves out tags that can't be resolved instead of failing
+fn render_blocks(
+    nodes: &[Node],
+    variables: &HashMap<String, String>,
+    locals: &HashMap<String, String>,
+    strict: bool,
+    out: &mut String,
+) -> Result<()> {
+    let lookup = |name: &str| locals.get(name).or_else(|| variables.get(name));
+    for node in nodes {
+        match node {
+            // Text holds no whole tags, only escapes and unclosed braces
+            Node::Text(text) if strict => out.push_str(&substitute_tags(text, variables)?),
+            Node::Text(text) => out.push_str(&substitute_tags_lenient(text, variables)),
+            Node::Tag { inner, .. } => {
+                let name = inner.split('|').next().unwrap_or_default().trim();
+                let scope = if locals.contains_key(name) { locals } else { variables };
+                match resolve(inner, scope) {
+                    Ok(value) => out.push_str(&value),
+                    Err(e) if strict => return Err(e),
+                    Err(_) => {}
+                }
+            }
+            Node::If { name, negate, then, otherwise } => {
+                let branch = if is_truthy(lookup(name)) != *negate { then } else { otherwise };
+                render_blocks(branch, variables, locals, strict, out)?;
+            }
+            Node::Each { name, body } => {
+                let value = lookup(name).ok_or_else(|| TemplateError::MissingVariable(name.to_string()))?;
+                let items = each_items(value);
+                for (index, item) in items.iter().enumerate() {
+                    let mut locals = locals.clone();
+                    locals.insert("this".to_string(), item_text(item));
+                    if let serde_json::Value::Object(fields) = item {
+                        for (field, value) in fields {
+                            locals.insert(format!("this.{}", field), item_text(value));
+                        }
+                    }
+                    locals.insert("@index".to_string(), index.to_string());
+                    locals.insert("@first".to_string(), (index == 0).to_string());
+                    locals.insert("@last".to_string(), (index + 1 == items.len()).to_string());
+                    render_blocks(body, variables, &locals, strict, out)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn has_blocks(template: &str) -> bool {
+    template.contains("{{#") || template.contains("{{/")
+}
+
+/// Expand `#if`, `#unless` and `#each` blocks and substitute every tag
+fn render(template: &str, variables: &HashMap<String, String>, strict: bool) -> Result<String> {
+    let (nodes, _) = parse_blocks(&mut segments(template).into_iter(), None)?;
+    let mut out = String::with_capacity(template.len());
+    render_blocks(&nodes, variables, &HashMap::new(), strict, &mut out)?;
+    Ok(out)
+}
+
+/// `\{{` stands for a literal `{{`, e.g. in a body sent to another templating service
+fn is_escape(c: char, rest: &std::iter::Peekable<std::str::Chars>) -> bool {
+    c == '\\' && rest.clone().take(2).eq(['{', '{'])
+}
+
 /// Find all template variables in a string (e.g., {{variable_name}})
 pub fn find_variables(template: &str) -> Vec<String> {
     let mut variables = Vec::new();
     let mut chars = template.chars().peekable();
     
     while let Some(c) = chars.next() {
+        if is_escape(c, &chars) {
+            chars.nth(1); // `\{{` is literal text
+            continue;
+        }
         if c == '{' {
             if let Some(&next) = chars.peek() {
                 if next == '{' {
@@ -44,8 +436,16 @@ pub fn find_variables(template: &str) -> Vec<String> {
                         var_name.push(c);
                     }
                     
-                    if found_closing && !var_name.is_empty() {
-                        variables.push(var_name.trim().to_string());
+                    // Filters don't change which variable is needed
+                    let var_name = var_name.split('|').next().unwrap_or_default().trim();
+                    // `{{#if x}}` and `{{#each x}}` need x; closing tags and loop locals need nothing
+                    let var_name = match var_name.strip_prefix('#') {
+                        Some(block) => block.split_whitespace().nth(1).unwrap_or_default(),
+                        None if var_name.starts_with('/') || var_name == "else" || is_loop_local(var_name) => "",
+                        None => var_name,
+                    };
+                    if found_closing && !var_name.is_empty() && !is_loop_local(var_name) {
+                        variables.push(var_name.to_string());
                     }
                 }
             }
@@ -59,10 +459,22 @@ pub fn find_variables(template: &str) -> Vec<String> {
 /// Supports both user variables {{variable}} and faker variables {{f:firstname}}
 /// Returns an error if any variable is missing
 pub fn substitute(template: &str, variables: &HashMap<String, String>) -> Result<String> {
+    if has_blocks(template) {
+        return render(template, variables, true);
+    }
+    substitute_tags(template, variables)
+}
+
+fn substitute_tags(template: &str, variables: &HashMap<String, String>) -> Result<String> {
     let mut result = String::new();
     let mut chars = template.chars().peekable();
     
     while let Some(c) = chars.next() {
+        if is_escape(c, &chars) {
+            chars.nth(1);
+            result.push_str("{{");
+            continue;
+        }
         if c == '{' {
             if let Some(&next) = chars.peek() {
                 if next == '{' {
@@ -86,24 +498,7 @@ pub fn substitute(template: &str, variables: &HashMap<String, String>) -> Result
                     }
                     
                     if found_closing {
-                        let var_name = var_name.trim();
-                        
-                        // Check if it's a faker variable (f:variablename)
-                        if faker::is_faker_variable(var_name) {
-                            let faker_name = faker::extract_faker_name(var_name);
-                            if let Some(fake_value) = faker::generate_fake_value(faker_name) {
-                                result.push_str(&fake_value);
-                            } else {
-                                return Err(TemplateError::UnknownFakerVariable(faker_name.to_string()));
-                            }
-                        } else {
-                            // Regular user variable
-                            if let Some(value) = variables.get(var_name) {
-                                result.push_str(value);
-                            } else {
-                                return Err(TemplateError::MissingVariable(var_name.to_string()));
-                            }
-                        }
+                        result.push_str(&resolve(&var_name, variables)?);
                     } else {
                         // Unclosed template variable
                         return Err(TemplateError::InvalidSyntax(
@@ -127,10 +522,25 @@ pub fn substitute(template: &str, variables: &HashMap<String, String>) -> Result
 /// Substitute template variables, using empty string for missing variables
 /// Supports both user variables {{variable}} and faker variables {{f:firstname}}
 pub fn substitute_lenient(template: &str, variables: &HashMap<String, String>) -> String {
+    if has_blocks(template) {
+        // With unbalanced blocks, the block tags are skipped like unknown variables
+        if let Ok(rendered) = render(template, variables, false) {
+            return rendered;
+        }
+    }
+    substitute_tags_lenient(template, variables)
+}
+
+fn substitute_tags_lenient(template: &str, variables: &HashMap<String, String>) -> String {
     let mut result = String::new();
     let mut chars = template.chars().peekable();
     
     while let Some(c) = chars.next() {
+        if is_escape(c, &chars) {
+            chars.nth(1);
+            result.push_str("{{");
+            continue;
+        }
         if c == '{' {
             if let Some(&next) = chars.peek() {
                 if next == '{' {
@@ -154,21 +564,9 @@ pub fn substitute_lenient(template: &str, variables: &HashMap<String, String>) -
                     }
                     
                     if found_closing {
-                        let var_name = var_name.trim();
-                        
-                        // Check if it's a faker variable (f:variablename)
-                        if faker::is_faker_variable(var_name) {
-                            let faker_name = faker::extract_faker_name(var_name);
-                            if let Some(fake_value) = faker::generate_fake_value(faker_name) {
-                                result.push_str(&fake_value);
-                            }
-                            // If faker variable not found, just skip it (empty string)
-                        } else {
-                            // Regular user variable
-                            if let Some(value) = variables.get(var_name) {
-                                result.push_str(value);
-                            }
-                            // If variable not found, just skip it (empty string)
+                        // Missing variables, unknown filters and the like are skipped (empty string)
+                        if let Ok(value) = resolve(&var_name, variables) {
+                            result.push_str(&value);
                         }
                     } else {
                         // Unclosed template, keep original
@@ -282,6 +680,136 @@ mod tests {
         assert_eq!(result, "value1 and {not a var} and value2");
     }
 
+    #[test]
+    fn test_now_variables() {
+        let now = DateTime::parse_from_rfc3339("2024-03-15T10:30:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(now_value("$now", now).unwrap(), "2024-03-15T10:30:00Z");
+        assert_eq!(now_value("$now+2d:%Y-%m-%d", now).unwrap(), "2024-03-17");
+        assert_eq!(now_value("$now-1h:iso", now).unwrap(), "2024-03-15T09:30:00Z");
+        assert_eq!(now_value("$now + 1w:unix", now).unwrap(), "1711103400");
+        assert_eq!(now_value("$now-30m:%H:%M", now).unwrap(), "10:00");
+        assert!(now_value("$now+2x", now).is_err());
+        assert!(now_value("$now2d", now).is_err());
+        assert!(now_value("$now+2é", now).is_err());
+        assert!(now_value("$now:%Q", now).is_err());
+
+        assert!(is_builtin_variable("$now+2d"));
+        assert!(!is_builtin_variable("now"));
+        let checkin = substitute("{\"checkin\": \"{{$now+1d:%Y}}\"}", &HashMap::new()).unwrap();
+        assert!(checkin.starts_with("{\"checkin\": \"20"));
+        assert_eq!(substitute_lenient("at {{$now+1q}}", &HashMap::new()), "at ");
+    }
+
+    #[test]
+    fn test_filters() {
+        let vars = HashMap::from([
+            ("token".to_string(), "user:pass".to_string()),
+            ("query".to_string(), "a b&c".to_string()),
+        ]);
+        assert_eq!(substitute("Basic {{token | base64}}", &vars).unwrap(), "Basic dXNlcjpwYXNz");
+        assert_eq!(substitute("/search?q={{ query|urlencode }}", &vars).unwrap(), "/search?q=a%20b%26c");
+        assert_eq!(
+            substitute("{{token | sha256}}", &vars).unwrap(),
+            "ef4c914c591698b268db3c64163eafda7209a630f236ebf0eebf045460df723a",
+        );
+        assert_eq!(substitute("{{token | base64 | urlencode}}", &vars).unwrap(), "dXNlcjpwYXNz");
+        assert!(matches!(substitute("{{token | rot13}}", &vars), Err(TemplateError::UnknownFilter(f)) if f == "rot13"));
+        assert_eq!(substitute_lenient("[{{token | rot13}}]", &vars), "[]");
+        assert_eq!(find_variables("{{token | base64}}"), vec!["token"]);
+    }
+
+    #[test]
+    fn test_if_blocks() {
+        let template = r#"{"name": "{{name}}"{{#if email}}, "email": "{{email}}"{{/if}}{{#unless vip}}, "tier": "basic"{{else}}, "tier": "gold"{{/unless}}}"#;
+        let mut vars = HashMap::from([("name".to_string(), "Al".to_string())]);
+        assert_eq!(substitute(template, &vars).unwrap(), r#"{"name": "Al", "tier": "basic"}"#);
+
+        vars.insert("email".to_string(), "al@example.com".to_string());
+        vars.insert("vip".to_string(), "true".to_string());
+        assert_eq!(substitute(template, &vars).unwrap(), r#"{"name": "Al", "email": "al@example.com", "tier": "gold"}"#);
+
+        vars.insert("email".to_string(), "".to_string());
+        vars.insert("vip".to_string(), "false".to_string());
+        assert_eq!(substitute(template, &vars).unwrap(), r#"{"name": "Al", "tier": "basic"}"#);
+    }
+
+    #[test]
+    fn test_each_blocks() {
+        let vars = HashMap::from([
+            ("tags".to_string(), "red, green".to_string()),
+            ("users".to_string(), r#"[{"id": 1, "name": "a b"}, {"id": 2, "name": "c"}]"#.to_string()),
+            ("prefix".to_string(), "u".to_string()),
+        ]);
+        let template = r#"[{{#each tags}}"{{this}}"{{#unless @last}},{{/unless}}{{/each}}]"#;
+        assert_eq!(substitute(template, &vars).unwrap(), r#"["red","green"]"#);
+
+        let template = "{{#each users}}{{@index}}:{{prefix}}{{this.id}}={{this.name | urlencode}};{{/each}}";
+        assert_eq!(substitute(template, &vars).unwrap(), "0:u1=a%20b;1:u2=c;");
+
+        assert!(matches!(substitute("{{#each missing}}x{{/each}}", &vars), Err(TemplateError::MissingVariable(v)) if v == "missing"));
+
+        // Item values are inserted as they are, like any other value
+        let vars = HashMap::from([
+            ("items".to_string(), r#"["{{prefix}}", "a\\{{b", "c\\", "{{nope"]"#.to_string()),
+            ("prefix".to_string(), "u".to_string()),
+        ]);
+        let template = "{{#each items}}{{this}}{{prefix}};{{/each}}";
+        assert_eq!(substitute(template, &vars).unwrap(), "{{prefix}}u;a\\{{bu;c\\u;{{nopeu;");
+        assert_eq!(substitute_lenient(template, &vars), "{{prefix}}u;a\\{{bu;c\\u;{{nopeu;");
+    }
+
+    #[test]
+    fn test_block_errors() {
+        let vars = HashMap::new();
+        assert!(matches!(substitute("{{#if a}}x", &vars), Err(TemplateError::InvalidSyntax(_))));
+        assert!(matches!(substitute("{{#if a}}x{{/each}}", &vars), Err(TemplateError::InvalidSyntax(_))));
+        assert!(matches!(substitute("x{{/if}}", &vars), Err(TemplateError::InvalidSyntax(_))));
+        assert!(matches!(substitute("{{#each a}}{{else}}{{/each}}", &vars), Err(TemplateError::InvalidSyntax(_))));
+        assert!(matches!(substitute("{{#with a}}{{/with}}", &vars), Err(TemplateError::InvalidSyntax(_))));
+        assert_eq!(substitute_lenient("{{#if a}}x", &vars), "x");
+
+        let vars = find_variables("{{#if email}}{{email}}{{/if}}{{#each tags}}{{this}}{{@index}}{{/each}}");
+        assert_eq!(vars, vec!["email", "email", "tags"]);
+    }
+
+    #[test]
+    fn test_error_locations() {
+        let vars = HashMap::from([("name".to_string(), "Al".to_string())]);
+        let body = "{\n  \"name\": \"{{name}}\",\n  \"email\": \"{{ email | base64 }}\"\n}";
+        let error = substitute_field("body", body, &vars).unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column, location.offset), (3, 13, 37));
+        assert_eq!(location.snippet, "  \"email\": \"{{ email | base64 }}\"");
+        assert_eq!(location.caret(), format!("{}{}", " ".repeat(12), "^".repeat(20)));
+        assert_eq!(error.to_string(), "Missing variable: email (body, line 3, column 13)");
+
+        let error = substitute_field("URL", "http://x/{{name | rot13}}", &vars).unwrap_err();
+        assert_eq!(error.location().unwrap().column, 10);
+        let error = substitute_field("URL", "http://x/{{name", &vars).unwrap_err();
+        assert_eq!((error.location().unwrap().column, error.location().unwrap().width), (10, 6));
+        let error = substitute_field("body", "[{{#each name}}1]", &vars).unwrap_err();
+        assert_eq!(error.location().unwrap().column, 2);
+
+        // Errors that can't be pinned to a tag still name the field
+        let error = substitute_field("header X-Id", "{{#if name}}", &vars).unwrap_err();
+        assert_eq!(error.location().unwrap().field, "header X-Id");
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        let vars = HashMap::from([("name".to_string(), "Al".to_string())]);
+        let template = r#"{"template": "Hello \{{name}}", "to": "{{name}}"}"#;
+        assert_eq!(substitute(template, &vars).unwrap(), r#"{"template": "Hello {{name}}", "to": "Al"}"#);
+        assert_eq!(substitute_lenient(template, &HashMap::new()), r#"{"template": "Hello {{name}}", "to": ""}"#);
+        assert_eq!(find_variables(template), vec!["name"]);
+        assert_eq!(find_variables("\\{{only}}"), Vec::<String>::new());
+
+        // An escape without a closing tag is fine, and blocks leave escapes alone
+        assert_eq!(substitute("a \\{{ b", &vars).unwrap(), "a {{ b");
+        assert_eq!(substitute("{{#if name}}\\{{x}}{{/if}}", &vars).unwrap(), "{{x}}");
+        assert_eq!(substitute("\\{ {{name}} \\n", &vars).unwrap(), "\\{ Al \\n");
+    }
+
     #[test]
     fn test_empty_template() {
         let template = "";
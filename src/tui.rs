@@ -1,7 +1,13 @@
 // Terminal UI layer using Ratatui
 
 pub mod app;
+#[cfg(test)]
+pub(crate) mod harness;
+pub mod input;
+pub mod macros;
+pub mod screens;
 pub mod ui;
+pub mod ui_utils;
 
 pub use app::AppState;
 pub use ui::run_app;
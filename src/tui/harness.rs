@@ -0,0 +1,220 @@
+// Test harness for the terminal UI
+// Drives an app on a temporary workspace with synthetic key events, the way
+// the event loop would, and draws it on ratatui's TestBackend so tests can
+// check the screen as text or against a snapshot in src/tui/snapshots/.
+// `UPDATE_SNAPSHOTS=1 cargo test` rewrites the snapshots that differ.
+
+use super::app::AppState;
+use super::input::{self, Keymap};
+use super::ui::draw_ui;
+use crate::models::ApiCollection;
+use crate::tui_app::tests::test_app;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::text::Span;
+use ratatui::Terminal;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+pub struct Harness {
+    pub app: AppState,
+    pub keymap: Keymap,
+    terminal: Terminal<TestBackend>,
+    pub quit: bool, // A key asked the app to quit
+    _dir: TempDir, // Workspace files live as long as the harness
+}
+
+impl Harness {
+    /// An app with an empty workspace on a 100x30 terminal
+    pub fn new() -> Self {
+        Self::with_size(100, 30)
+    }
+
+    pub fn with_size(width: u16, height: u16) -> Self {
+        let dir = TempDir::new().unwrap();
+        Self {
+            app: test_app(&dir),
+            keymap: Keymap::default(),
+            terminal: Terminal::new(TestBackend::new(width, height)).unwrap(),
+            quit: false,
+            _dir: dir,
+        }
+    }
+
+    /// Add a collection, as if it had been loaded at startup
+    pub fn with_collection(mut self, collection: ApiCollection) -> Self {
+        self.app.collections.push(collection);
+        self
+    }
+
+    /// Press a key given as `j`, `G`, `Enter`, `Esc`, `Tab`, `Ctrl+r`, ...
+    pub fn press(&mut self, key: &str) -> &mut Self {
+        self.send(parse_key(key))
+    }
+
+    /// Press several keys in turn
+    pub fn keys(&mut self, keys: &[&str]) -> &mut Self {
+        for key in keys {
+            self.press(key);
+        }
+        self
+    }
+
+    /// Type text one character at a time, e.g. into a form
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            self.send(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        self
+    }
+
+    /// Hand a key event to the app as the event loop does, then wait for any
+    /// request it started, and any macro it played, so the next key sees the response
+    pub fn send(&mut self, key: KeyEvent) -> &mut Self {
+        self.quit |= input::handle_key(&mut self.app, &self.keymap, key);
+        self.app.wait_for_requests();
+        while self.app.macros.is_playing() && !self.quit {
+            self.quit |= input::play_macro_keys(&mut self.app, &self.keymap);
+            self.app.wait_for_requests();
+        }
+        self
+    }
+
+    /// Draw the app and return the buffer
+    pub fn draw(&mut self) -> Buffer {
+        let app = &self.app;
+        self.terminal.draw(|f| draw_ui(f, app)).unwrap().buffer.clone()
+    }
+
+    /// The screen as text, one line per row with trailing blanks trimmed
+    pub fn render(&mut self) -> String {
+        buffer_text(&self.draw())
+    }
+
+    /// Compare the screen with `src/tui/snapshots/<name>.txt`
+    pub fn assert_snapshot(&mut self, name: &str) {
+        let rendered = self.render();
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/tui/snapshots").join(format!("{}.txt", name));
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() || !path.exists() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &rendered).unwrap();
+            return;
+        }
+        let expected = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            rendered == expected,
+            "screen differs from {} (UPDATE_SNAPSHOTS=1 rewrites it)\n--- expected\n{}\n--- rendered\n{}",
+            path.display(), expected, rendered,
+        );
+    }
+}
+
+/// A buffer as a terminal shows it: a wide symbol hides the cell after it
+pub fn buffer_text(buffer: &Buffer) -> String {
+    let mut text = String::new();
+    for y in 0..buffer.area.height {
+        let mut line = String::new();
+        let mut x = 0;
+        while x < buffer.area.width {
+            let symbol = buffer[(x, y)].symbol();
+            line.push_str(symbol);
+            x += Span::raw(symbol).width().max(1) as u16;
+        }
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// `Ctrl+r`, `Shift+Tab`, `Enter` or a single character
+pub fn parse_key(spec: &str) -> KeyEvent {
+    let (modifiers, name) = match spec.rsplit_once('+').filter(|(_, name)| !name.is_empty()) {
+        Some((prefix, name)) => {
+            let modifiers = prefix.split('+').fold(KeyModifiers::NONE, |modifiers, m| modifiers | match m {
+                "Ctrl" => KeyModifiers::CONTROL,
+                "Alt" => KeyModifiers::ALT,
+                "Shift" => KeyModifiers::SHIFT,
+                other => panic!("unknown modifier '{}' in '{}'", other, spec),
+            });
+            (modifiers, name)
+        }
+        None => (KeyModifiers::NONE, spec),
+    };
+    let code = match name {
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "Space" => KeyCode::Char(' '),
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => panic!("unknown key '{}'", spec),
+            }
+        }
+    };
+    KeyEvent::new(code, modifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiEndpoint, HttpMethod};
+
+    fn users() -> ApiCollection {
+        let mut collection = ApiCollection::new("Users".to_string());
+        for (name, method) in [("List users", HttpMethod::GET), ("Create user", HttpMethod::POST), ("Delete user", HttpMethod::DELETE)] {
+            collection.add_endpoint(ApiEndpoint::new(name.to_string(), method, "http://127.0.0.1:9/users".to_string()));
+        }
+        collection
+    }
+
+    #[test]
+    fn test_parse_key() {
+        assert_eq!(parse_key("j"), KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert_eq!(parse_key("+"), KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE));
+        assert_eq!(parse_key("Ctrl+r"), KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert_eq!(parse_key("Shift+Tab"), KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        assert_eq!(parse_key("Ctrl+Alt+@"), KeyEvent::new(KeyCode::Char('@'), KeyModifiers::CONTROL | KeyModifiers::ALT));
+    }
+
+    #[test]
+    fn test_main_screen_snapshots() {
+        let mut harness = Harness::new().with_collection(users());
+        harness.assert_snapshot("main_screen");
+        harness.keys(&["Tab", "j", "j"]);
+        assert_eq!(harness.app.selection.endpoint, 2);
+        harness.assert_snapshot("main_screen_last_endpoint");
+    }
+
+    #[test]
+    fn test_help_opens_and_closes() {
+        let mut harness = Harness::new();
+        harness.press("?");
+        assert!(harness.render().contains("Keyboard Shortcuts"));
+        harness.assert_snapshot("help");
+        harness.press("Esc");
+        assert!(!harness.quit);
+        harness.press("q");
+        assert!(harness.quit);
+    }
+
+    #[test]
+    fn test_new_collection_form() {
+        let mut harness = Harness::new();
+        harness.press("n").type_text("Orders").press("Enter");
+        assert_eq!(harness.app.collections.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["Orders"]);
+        assert!(harness.render().contains("Orders"));
+    }
+}
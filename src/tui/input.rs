@@ -0,0 +1,1533 @@
+// Keymap and action dispatch
+// Key events are translated into `Action`s by a `Keymap`, then applied to the
+// app state by `dispatch`. Forms share one generic text-input path.
+
+use super::app::{
+    AppState, CollectionForm, ContentPreset, EndpointForm, PanelFocus, Screen, VariableForm, VariableInputForm,
+};
+use super::screens::{CollectionStatsScreen, CrudScaffoldScreen, CurlImportScreen, ExportScreen, HistorySearchScreen, ImportScreen, LoadTestConfigForm, LogViewerScreen, OverridesScreen, PastResultsScreen, ProxyScreen, SettingsScreen, TimelineScreen, Transition, VariableTransferScreen};
+use super::macros::{MacroPrompt, Macros, MAX_DEPTH};
+use crate::interop::ExportFormat;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::collections::HashMap;
+
+/// Something the user asked for, independent of the key that triggered it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    // Global
+    Quit,
+    Back,
+    Help,
+
+    // Lists and panels
+    Up,
+    Down,
+    Select,
+    FocusCollections,
+    FocusEndpoints,
+    ToggleFocus,
+    New,
+    Edit,
+    QuickEdit,
+    EndpointChanges,
+    EditGraphql,
+    Delete,
+    LoadTest,
+    Variables,
+    QuickExecute,
+    SendWithOverrides,
+    Stats,
+    Notes,
+    CycleTagFilter,
+    ToggleArchived,
+    ArchivedView,
+    CopyCollection,
+    Merge,
+    Sync,
+    Settings,
+    Proxy,
+    ViewLog,
+    Timeline,
+    PastResults,
+    SwitchEnvironment,
+    Import,
+    ImportCurl,
+    ScaffoldCrud,
+    CopyAsCurl,
+    RecordMacro,
+    PlayMacro,
+    Export,
+    ExportOpenApi,
+    RunCollection,
+    LoadTestScenario,
+    ToggleMark,
+    RunMarked,
+    SplitView,
+    Compare,
+    SearchHistory,
+
+    // Response viewer
+    ToggleTraffic,
+    ToggleHeaders,
+    ToggleWrap,
+    ToggleLineNumbers,
+    ToggleEpochDates,
+    ToggleThousands,
+    ViewAs,
+    ToggleVisualization,
+    TableView,
+    ResponseLinks,
+    ToggleSection,
+    CopyResponse,
+    ScrollUp,
+    ScrollDown,
+    HalfPageUp,
+    HalfPageDown,
+    ScrollTop,
+    ScrollBottom,
+    ScrollLeft,
+    ScrollRight,
+    HeadersUp,
+    HeadersDown,
+    HeadersTop,
+    /// Either key of the `gg` chord
+    ScrollTopChord,
+    GotoLine,
+    Search,
+    SearchNext,
+    SearchPrev,
+    ExpandJson,
+
+    // Forms and prompts
+    InsertChar(char),
+    DeleteChar,
+    NextField,
+    PrevField,
+    Submit,
+    FormatBody,
+
+    // Confirmation dialog
+    Confirm,
+    Cancel,
+}
+
+/// What kind of input the current screen expects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputContext {
+    /// Lists and the response viewer: keys are commands from the keymap
+    Browse,
+    /// Forms: printable keys are text
+    Form,
+    /// Yes/no confirmation dialog
+    Confirm,
+    /// `:123` go-to-line prompt
+    GotoLine,
+    /// `/` search prompt: printable keys are the query
+    Search,
+    /// Response viewer after a search: keys step through matches, the rest browse
+    SearchResults,
+    /// Pick from a list: arrows move, printable keys are choices
+    Menu,
+}
+
+impl InputContext {
+    pub fn for_app(app: &AppState) -> Self {
+        if app.goto_line_input.is_some() {
+            return InputContext::GotoLine;
+        }
+        if app.response_search.as_ref().is_some_and(|s| s.editing) {
+            return InputContext::Search;
+        }
+        match app.current_screen {
+            Screen::ConfirmDelete(_) => InputContext::Confirm,
+            Screen::CollectionEdit(_)
+            | Screen::EndpointEdit(_, _)
+            | Screen::VariableEdit(_)
+            | Screen::VariableInput(_, _) => InputContext::Form,
+            _ if app.search_active() && app.in_response_view() => InputContext::SearchResults,
+            _ => InputContext::Browse,
+        }
+    }
+}
+
+/// A key plus modifiers. Shift is ignored for characters since it is already
+/// reflected in the character itself (`G` vs `g`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = if matches!(code, KeyCode::Char(_)) {
+            modifiers - KeyModifiers::SHIFT
+        } else {
+            modifiers
+        };
+        Self { code, modifiers }
+    }
+
+    pub fn plain(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    pub fn char(c: char) -> Self {
+        Self::plain(KeyCode::Char(c))
+    }
+
+    pub fn ctrl(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    pub fn shift(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::SHIFT)
+    }
+}
+
+impl From<KeyEvent> for KeyBinding {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+/// Undo the ways Windows consoles report keys differently, so bindings and
+/// forms behave the same everywhere: Ctrl combinations sent as raw control
+/// characters (`\x05` for Ctrl+E) or in upper case with Shift or Caps Lock,
+/// and AltGr characters (`@`, `{` on many layouts) arriving as Ctrl+Alt
+pub fn normalize_key(mut key: KeyEvent) -> KeyEvent {
+    let ctrl_alt = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    match key.code {
+        KeyCode::Char(c @ '\u{1}'..='\u{1a}') => {
+            key.code = KeyCode::Char((c as u8 - 1 + b'a') as char);
+            key.modifiers |= KeyModifiers::CONTROL;
+        }
+        KeyCode::Char(c) if key.modifiers.contains(ctrl_alt) && !c.is_ascii_alphabetic() => {
+            key.modifiers -= ctrl_alt;
+        }
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) && c.is_ascii_uppercase() => {
+            key.code = KeyCode::Char(c.to_ascii_lowercase());
+        }
+        _ => {}
+    }
+    key
+}
+
+/// Key bindings for browse screens. Forms, prompts and dialogs use fixed keys.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyBinding, Action>,
+    search_bindings: HashMap<KeyBinding, Action>, // Take precedence while a response search is active
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut keymap = Self { bindings: HashMap::new(), search_bindings: HashMap::new() };
+
+        keymap.bind(KeyBinding::char('q'), Action::Quit);
+        keymap.bind(KeyBinding::plain(KeyCode::Esc), Action::Back);
+        keymap.bind(KeyBinding::char('?'), Action::Help);
+
+        // Navigation
+        keymap.bind(KeyBinding::plain(KeyCode::Up), Action::Up);
+        keymap.bind(KeyBinding::char('k'), Action::Up);
+        keymap.bind(KeyBinding::ctrl('k'), Action::Up);
+        keymap.bind(KeyBinding::plain(KeyCode::Down), Action::Down);
+        keymap.bind(KeyBinding::char('j'), Action::Down);
+        keymap.bind(KeyBinding::ctrl('j'), Action::Down);
+        keymap.bind(KeyBinding::plain(KeyCode::Enter), Action::Select);
+        keymap.bind(KeyBinding::ctrl('h'), Action::FocusCollections);
+        keymap.bind(KeyBinding::ctrl('l'), Action::FocusEndpoints);
+        keymap.bind(KeyBinding::ctrl('i'), Action::ToggleFocus);
+        keymap.bind(KeyBinding::plain(KeyCode::Tab), Action::ToggleFocus);
+
+        // Commands
+        keymap.bind(KeyBinding::char('n'), Action::New);
+        keymap.bind(KeyBinding::char('e'), Action::Edit);
+        keymap.bind(KeyBinding::char('u'), Action::QuickEdit);
+        keymap.bind(KeyBinding::char('h'), Action::EndpointChanges);
+        keymap.bind(KeyBinding::char('Q'), Action::EditGraphql);
+        keymap.bind(KeyBinding::char('d'), Action::Delete);
+        keymap.bind(KeyBinding::char('l'), Action::LoadTest);
+        keymap.bind(KeyBinding::char('v'), Action::Variables);
+        keymap.bind(KeyBinding::char('x'), Action::QuickExecute);
+        keymap.bind(KeyBinding::char('o'), Action::SendWithOverrides);
+        keymap.bind(KeyBinding::char('s'), Action::Stats);
+        keymap.bind(KeyBinding::char('r'), Action::Notes);
+        keymap.bind(KeyBinding::char('#'), Action::CycleTagFilter);
+        keymap.bind(KeyBinding::char('a'), Action::ToggleArchived);
+        keymap.bind(KeyBinding::char('A'), Action::ArchivedView);
+        keymap.bind(KeyBinding::char('c'), Action::CopyCollection);
+        keymap.bind(KeyBinding::char('M'), Action::Merge);
+        keymap.bind(KeyBinding::char('U'), Action::Sync);
+        keymap.bind(KeyBinding::char('S'), Action::Settings);
+        keymap.bind(KeyBinding::char('p'), Action::Proxy);
+        keymap.bind(KeyBinding::char('L'), Action::ViewLog);
+        keymap.bind(KeyBinding::char('W'), Action::Timeline);
+        keymap.bind(KeyBinding::char('B'), Action::PastResults);
+        keymap.bind(KeyBinding::char('I'), Action::Import);
+        keymap.bind(KeyBinding::char('i'), Action::ImportCurl);
+        keymap.bind(KeyBinding::ctrl('n'), Action::ScaffoldCrud);
+        keymap.bind(KeyBinding::char('Y'), Action::CopyAsCurl);
+        keymap.bind(KeyBinding::ctrl('r'), Action::RecordMacro);
+        keymap.bind(KeyBinding::char('@'), Action::PlayMacro);
+        keymap.bind(KeyBinding::char('X'), Action::Export);
+        keymap.bind(KeyBinding::char('O'), Action::ExportOpenApi);
+        keymap.bind(KeyBinding::char('R'), Action::RunCollection);
+        keymap.bind(KeyBinding::char('K'), Action::LoadTestScenario);
+        keymap.bind(KeyBinding::char('m'), Action::ToggleMark);
+        keymap.bind(KeyBinding::char('P'), Action::RunMarked);
+        keymap.bind(KeyBinding::char('|'), Action::SplitView);
+        keymap.bind(KeyBinding::char('C'), Action::Compare);
+        keymap.bind(KeyBinding::ctrl('f'), Action::SearchHistory);
+        keymap.bind(KeyBinding::ctrl('e'), Action::SwitchEnvironment);
+
+        // Response viewer
+        keymap.bind(KeyBinding::char('t'), Action::ToggleTraffic);
+        keymap.bind(KeyBinding::char('H'), Action::ToggleHeaders);
+        keymap.bind(KeyBinding::char('w'), Action::ToggleWrap);
+        keymap.bind(KeyBinding::char('N'), Action::ToggleLineNumbers);
+        keymap.bind(KeyBinding::char('D'), Action::ToggleEpochDates);
+        keymap.bind(KeyBinding::char(','), Action::ToggleThousands);
+        keymap.bind(KeyBinding::char('F'), Action::ViewAs);
+        keymap.bind(KeyBinding::char('V'), Action::ToggleVisualization);
+        keymap.bind(KeyBinding::char('T'), Action::TableView);
+        keymap.bind(KeyBinding::char('b'), Action::ResponseLinks);
+        keymap.bind(KeyBinding::char(' '), Action::ToggleSection);
+        keymap.bind(KeyBinding::char('y'), Action::CopyResponse);
+        keymap.bind(KeyBinding::char(':'), Action::GotoLine);
+        keymap.bind(KeyBinding::char('/'), Action::Search);
+        keymap.bind(KeyBinding::char('E'), Action::ExpandJson);
+        keymap.bind(KeyBinding::char('g'), Action::ScrollTopChord);
+        keymap.bind(KeyBinding::char('G'), Action::ScrollBottom);
+        keymap.bind(KeyBinding::ctrl('d'), Action::HalfPageDown);
+        keymap.bind(KeyBinding::ctrl('u'), Action::HalfPageUp);
+        keymap.bind(KeyBinding::plain(KeyCode::PageUp), Action::ScrollUp);
+        keymap.bind(KeyBinding::plain(KeyCode::PageDown), Action::ScrollDown);
+        keymap.bind(KeyBinding::plain(KeyCode::Home), Action::ScrollTop);
+        keymap.bind(KeyBinding::plain(KeyCode::End), Action::ScrollBottom);
+        keymap.bind(KeyBinding::plain(KeyCode::Left), Action::ScrollLeft);
+        keymap.bind(KeyBinding::plain(KeyCode::Right), Action::ScrollRight);
+        keymap.bind(KeyBinding::shift(KeyCode::PageUp), Action::HeadersUp);
+        keymap.bind(KeyBinding::shift(KeyCode::PageDown), Action::HeadersDown);
+        keymap.bind(KeyBinding::shift(KeyCode::Home), Action::HeadersTop);
+
+        // Response search, as in a pager
+        keymap.bind_search(KeyBinding::char('n'), Action::SearchNext);
+        keymap.bind_search(KeyBinding::char('N'), Action::SearchPrev);
+
+        keymap
+    }
+}
+
+impl Keymap {
+    /// Bind a key in browse screens, replacing any existing binding
+    pub fn bind(&mut self, binding: KeyBinding, action: Action) {
+        self.bindings.insert(binding, action);
+    }
+
+    /// Bind a key while a response search is active; other keys keep their browse binding
+    pub fn bind_search(&mut self, binding: KeyBinding, action: Action) {
+        self.search_bindings.insert(binding, action);
+    }
+
+    /// Translate a key event into an action for the given context
+    pub fn action_for(&self, context: InputContext, key: KeyEvent) -> Option<Action> {
+        match context {
+            InputContext::Browse => self.bindings.get(&KeyBinding::from(key)).copied(),
+            InputContext::SearchResults => {
+                let binding = KeyBinding::from(key);
+                self.search_bindings.get(&binding).or_else(|| self.bindings.get(&binding)).copied()
+            }
+            InputContext::Form => match key.code {
+                KeyCode::Esc => Some(Action::Back),
+                KeyCode::Enter => Some(Action::Submit),
+                KeyCode::Tab => Some(Action::NextField),
+                KeyCode::BackTab => Some(Action::PrevField),
+                KeyCode::Backspace => Some(Action::DeleteChar),
+                KeyCode::Up => Some(Action::Up),
+                KeyCode::Down => Some(Action::Down),
+                KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::FormatBody),
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Some(Action::InsertChar(c))
+                }
+                _ => None,
+            },
+            InputContext::Confirm => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => Some(Action::Confirm),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => Some(Action::Cancel),
+                _ => None,
+            },
+            InputContext::Menu => match key.code {
+                KeyCode::Up => Some(Action::Up),
+                KeyCode::Down => Some(Action::Down),
+                KeyCode::Enter => Some(Action::Submit),
+                KeyCode::Esc => Some(Action::Back),
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Some(Action::InsertChar(c))
+                }
+                _ => None,
+            },
+            InputContext::GotoLine => match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => Some(Action::InsertChar(c)),
+                KeyCode::Backspace => Some(Action::DeleteChar),
+                KeyCode::Enter => Some(Action::Submit),
+                KeyCode::Esc => Some(Action::Cancel),
+                _ => None,
+            },
+            InputContext::Search => match key.code {
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::InsertChar(c)),
+                KeyCode::Backspace => Some(Action::DeleteChar),
+                KeyCode::Enter => Some(Action::Submit),
+                KeyCode::Esc => Some(Action::Cancel),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Handle one key press. Returns true when the app should quit.
+pub fn handle_key(app: &mut AppState, keymap: &Keymap, key: KeyEvent) -> bool {
+    // Windows reports releases too; acting on them would run every key twice
+    if key.kind == KeyEventKind::Release {
+        return false;
+    }
+    let key = normalize_key(key);
+    // Typed keys don't mix with a macro's; Esc stops it and what it sent
+    if app.macros.is_playing() {
+        if keymap.action_for(InputContext::Browse, key) == Some(Action::Back) {
+            if !app.in_flight.is_empty() || !app.fetches.is_empty() {
+                app.cancel_requests();
+            }
+            if let Some(register) = app.macros.stop_playing() {
+                app.status_message = Some(app.tr_with("macro.stopped", &[("register", &register)]));
+            }
+        }
+        return false;
+    }
+    press_key(app, keymap, key)
+}
+
+/// Play the keys of the macro playing until one sends a request; the event
+/// loop calls this on every tick. Returns true when the app should quit.
+pub fn play_macro_keys(app: &mut AppState, keymap: &Keymap) -> bool {
+    while app.in_flight.is_empty() && app.fetches.is_empty() {
+        let Some(key) = app.macros.next_key() else { break };
+        if press_key(app, keymap, key) {
+            app.macros.stop_playing();
+            return true;
+        }
+    }
+    false
+}
+
+/// A typed or played key press
+fn press_key(app: &mut AppState, keymap: &Keymap, key: KeyEvent) -> bool {
+    // The register after `Ctrl+R` or `@`
+    if let Some(prompt) = app.macros.awaiting.take() {
+        macro_register(app, prompt, key);
+        return false;
+    }
+    // Recording starts and stops anywhere; everything else pressed meanwhile is recorded
+    if keymap.action_for(InputContext::Browse, key) == Some(Action::RecordMacro) {
+        match app.macros.stop_recording() {
+            Some((register, count)) => app.status_message = Some(app.tr_with("macro.recorded", &[("count", &count), ("register", &register)])),
+            None => {
+                app.macros.awaiting = Some(MacroPrompt::Record);
+                app.status_message = Some(app.tr("macro.record_prompt").to_string());
+            }
+        }
+        return false;
+    }
+    app.macros.record(key);
+
+    // Switching environments works everywhere, even with a form or dialog open
+    if keymap.action_for(InputContext::Browse, key) == Some(Action::SwitchEnvironment) {
+        app.cycle_environment();
+        return false;
+    }
+    
+    // Screens that own their state get input first. Take the view out while it
+    // runs so it can borrow the rest of the app mutably.
+    if let Some(mut view) = app.view.take() {
+        let transition = match keymap.action_for(view.input_context(), key) {
+            Some(action) => view.handle_action(app, action),
+            None => Transition::Stay,
+        };
+        match transition {
+            // Keep the view unless it opened another one in its place
+            Transition::Stay if app.view.is_none() => app.view = Some(view),
+            Transition::Quit => return true,
+            _ => {}
+        }
+        return false;
+    }
+
+    let context = InputContext::for_app(app);
+    // Two-key chords only survive until the next key press
+    let pending_key = app.pending_key.take();
+    match keymap.action_for(context, key) {
+        Some(action) => dispatch(app, context, action, pending_key),
+        None => false,
+    }
+}
+
+/// Start recording into, or play, the register named by `key`
+fn macro_register(app: &mut AppState, prompt: MacroPrompt, key: KeyEvent) {
+    // `@a` inside a recording replays `a` as part of it
+    if prompt == MacroPrompt::Play {
+        app.macros.record(key);
+    }
+    let register = match key.code {
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => c,
+        _ => {
+            app.status_message = None;
+            return;
+        }
+    };
+    match prompt {
+        MacroPrompt::Record if Macros::is_register(register) => {
+            app.macros.start_recording(register);
+            app.status_message = Some(app.tr_with("macro.recording", &[("register", &register)]));
+        }
+        MacroPrompt::Play => {
+            match app.macros.resolve(register).filter(|&r| Macros::is_register(r)) {
+                Some(register) => play_macro(app, register),
+                None => app.error_message = Some(app.tr("macro.none_played").to_string()),
+            }
+        }
+        MacroPrompt::Record => app.error_message = Some(app.tr_with("macro.not_a_register", &[("register", &register)])),
+    }
+}
+
+/// Replay a register as if its keys were typed, from the next tick on.
+/// Requests finish before the next key, so a macro can send one and then
+/// read the response.
+fn play_macro(app: &mut AppState, register: char) {
+    if app.macros.keys(register).is_none() {
+        app.error_message = Some(app.tr_with("macro.empty", &[("register", &register)]));
+        return;
+    }
+    if app.macros.depth() >= MAX_DEPTH {
+        app.error_message = Some(app.tr_with("macro.too_deep", &[("register", &register), ("depth", &MAX_DEPTH)]));
+        return;
+    }
+    if !app.macros.is_playing() {
+        app.status_message = Some(app.tr_with("macro.playing", &[("register", &register)]));
+    }
+    app.macros.begin_playing(register);
+}
+
+/// Apply an action to the app state. Returns true when the app should quit.
+pub fn dispatch(app: &mut AppState, context: InputContext, action: Action, pending_key: Option<char>) -> bool {
+    match context {
+        InputContext::Browse | InputContext::SearchResults => return dispatch_browse(app, action, pending_key),
+        InputContext::Form => dispatch_form(app, action),
+        InputContext::Confirm => match action {
+            Action::Confirm => app.confirm_delete_action(),
+            Action::Cancel => app.navigate_back(),
+            _ => {}
+        },
+        InputContext::GotoLine => match action {
+            Action::InsertChar(c) => {
+                if let Some(input) = &mut app.goto_line_input {
+                    input.push(c);
+                }
+            }
+            Action::DeleteChar => {
+                if let Some(input) = &mut app.goto_line_input {
+                    input.pop();
+                }
+            }
+            Action::Submit => app.confirm_goto_line(),
+            Action::Cancel => app.cancel_goto_line(),
+            _ => {}
+        },
+        InputContext::Search => match action {
+            Action::InsertChar(c) => {
+                if let Some(search) = &mut app.response_search {
+                    search.query.push(c);
+                    search.current = 0;
+                }
+            }
+            Action::DeleteChar => {
+                if let Some(search) = &mut app.response_search {
+                    search.query.pop();
+                    search.current = 0;
+                }
+            }
+            Action::Submit => app.confirm_search(),
+            Action::Cancel => app.cancel_search(),
+            _ => {}
+        },
+        // Only screens that own their state present menus
+        InputContext::Menu => {}
+    }
+    false
+}
+
+fn dispatch_browse(app: &mut AppState, action: Action, pending_key: Option<char>) -> bool {
+    let has_response_detail = matches!(app.current_screen, Screen::EndpointDetail(_, _))
+        && app.last_response.is_some();
+
+    match action {
+        Action::Quit => return matches!(app.current_screen, Screen::CollectionList),
+        Action::Back if !app.in_flight.is_empty() || !app.fetches.is_empty() => app.cancel_requests(),
+        Action::Back if app.response_search.is_some() => app.cancel_search(),
+        Action::SearchNext => app.search_next(),
+        Action::SearchPrev => app.search_prev(),
+        Action::Back => {
+            if matches!(app.current_screen, Screen::LoadTestRunning(_, _)) {
+                app.stop_load_test();
+            }
+            app.navigate_back();
+        }
+        Action::Help => app.show_help(),
+        Action::Up => app.navigate_up(),
+        Action::Down => app.navigate_down(),
+        Action::Select => app.select(),
+        Action::FocusCollections => app.panel_focus = PanelFocus::Collections,
+        Action::FocusEndpoints => app.panel_focus = PanelFocus::Endpoints,
+        Action::ToggleFocus => app.toggle_panel_focus(),
+        Action::New => new_item(app),
+        Action::Edit => edit_item(app),
+        Action::Delete => delete_item(app),
+        Action::LoadTest => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.start_load_test(coll_idx, ep_idx);
+            }
+        }
+        Action::Variables => app.current_screen = Screen::VariableList,
+        Action::SearchHistory => app.open_view(Box::new(HistorySearchScreen::default())),
+        Action::QuickExecute if app.panel_focus == PanelFocus::Endpoints => {
+            // Execute immediately with saved variable values
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.quick_execute_request(coll_idx, ep_idx);
+            }
+        }
+        Action::Stats if app.selection.collection < app.collections.len() => {
+            let endpoint = selected_endpoint(app).map(|(_, ep_idx)| ep_idx);
+            app.open_view(Box::new(CollectionStatsScreen::new(app.selection.collection).with_endpoint(endpoint)));
+        }
+        Action::Notes if matches!(app.current_screen, Screen::CollectionList) => app.open_notes(app.selection.collection),
+        Action::CycleTagFilter if matches!(app.current_screen, Screen::CollectionList) => app.cycle_tag_filter(),
+        Action::ToggleArchived if matches!(app.current_screen, Screen::CollectionList) && app.panel_focus == PanelFocus::Endpoints => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.toggle_endpoint_archived(coll_idx, ep_idx);
+            }
+        }
+        Action::ArchivedView if matches!(app.current_screen, Screen::CollectionList) => app.toggle_archived_view(),
+        Action::ToggleMark if matches!(app.current_screen, Screen::CollectionList) && app.panel_focus == PanelFocus::Endpoints => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.toggle_endpoint_mark(coll_idx, ep_idx);
+            }
+        }
+        Action::RunMarked if matches!(app.current_screen, Screen::CollectionList) => {
+            app.run_marked(app.selection.collection);
+        }
+        Action::SplitView if matches!(app.current_screen, Screen::CollectionList) => {
+            app.open_split_view(app.selection.collection);
+        }
+        Action::Compare if app.panel_focus == PanelFocus::Endpoints || matches!(app.current_screen, Screen::EndpointDetail(_, _)) => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.start_compare(coll_idx, ep_idx);
+            }
+        }
+        Action::SendWithOverrides if app.panel_focus == PanelFocus::Endpoints || matches!(app.current_screen, Screen::EndpointDetail(_, _)) => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                let screen = OverridesScreen::new(app, coll_idx, ep_idx);
+                app.open_view(Box::new(screen));
+            }
+        }
+        Action::QuickEdit if app.panel_focus == PanelFocus::Endpoints || matches!(app.current_screen, Screen::EndpointDetail(_, _)) => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.open_quick_edit(coll_idx, ep_idx);
+            }
+        }
+        Action::EndpointChanges if app.panel_focus == PanelFocus::Endpoints || matches!(app.current_screen, Screen::EndpointDetail(_, _)) => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.open_endpoint_changes(coll_idx, ep_idx);
+            }
+        }
+        Action::EditGraphql if app.panel_focus == PanelFocus::Endpoints || matches!(app.current_screen, Screen::EndpointDetail(_, _)) => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.open_graphql_editor(coll_idx, ep_idx);
+            }
+        }
+        Action::CopyAsCurl if app.panel_focus == PanelFocus::Endpoints || matches!(app.current_screen, Screen::EndpointDetail(_, _)) => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.open_copy_as_curl(coll_idx, ep_idx);
+            }
+        }
+        Action::PlayMacro => {
+            app.macros.awaiting = Some(MacroPrompt::Play);
+            app.status_message = Some(app.tr("macro.play_prompt").to_string());
+        }
+        Action::CopyCollection if matches!(app.current_screen, Screen::CollectionList) => {
+            app.make_editable_copy(app.selection.collection);
+        }
+        Action::Merge if matches!(app.current_screen, Screen::CollectionList) => {
+            app.start_merge(app.selection.collection);
+        }
+        Action::Sync if matches!(app.current_screen, Screen::CollectionList) => {
+            app.sync_collection(app.selection.collection);
+        }
+        Action::Settings if matches!(app.current_screen, Screen::CollectionList) => {
+            app.open_view(Box::new(SettingsScreen::new(app)));
+        }
+        Action::Proxy if matches!(app.current_screen, Screen::CollectionList) => {
+            app.open_view(Box::new(ProxyScreen::new(app)));
+        }
+        Action::ViewLog if matches!(app.current_screen, Screen::CollectionList) => {
+            let screen = LogViewerScreen::new(app);
+            app.open_view(Box::new(screen));
+        }
+        Action::Timeline if matches!(app.current_screen, Screen::CollectionList) => {
+            let screen = TimelineScreen::new(app, None);
+            app.open_view(Box::new(screen));
+        }
+        Action::PastResults if matches!(app.current_screen, Screen::CollectionList) => {
+            let screen = PastResultsScreen::new(app);
+            app.open_view(Box::new(screen));
+        }
+        Action::Import if matches!(app.current_screen, Screen::VariableList) => {
+            app.open_view(Box::new(VariableTransferScreen::import()));
+        }
+        Action::Export if matches!(app.current_screen, Screen::VariableList) => {
+            app.open_view(Box::new(VariableTransferScreen::export()));
+        }
+        Action::Import if matches!(app.current_screen, Screen::CollectionList) => {
+            let target = (app.selection.collection < app.collections.len()).then_some(app.selection.collection);
+            app.open_view(Box::new(ImportScreen::new(target)));
+        }
+        Action::ImportCurl if app.panel_focus == PanelFocus::Endpoints && app.selection.collection < app.collections.len() => {
+            app.open_view(Box::new(CurlImportScreen::new(app.selection.collection)));
+        }
+        Action::ScaffoldCrud if app.panel_focus == PanelFocus::Endpoints && app.selection.collection < app.collections.len() => {
+            app.open_view(Box::new(CrudScaffoldScreen::new(app.selection.collection)));
+        }
+        Action::Export if matches!(app.current_screen, Screen::CollectionList) => {
+            if let Some(collection) = app.collections.get(app.selection.collection) {
+                let screen = ExportScreen::new(app.selection.collection, &collection.name);
+                app.open_view(Box::new(screen));
+            }
+        }
+        Action::ExportOpenApi if matches!(app.current_screen, Screen::CollectionList) => {
+            if let Some(collection) = app.collections.get(app.selection.collection) {
+                let screen = ExportScreen::new(app.selection.collection, &collection.name).with_format(ExportFormat::OpenApiYaml);
+                app.open_view(Box::new(screen));
+            }
+        }
+        Action::RunCollection if matches!(app.current_screen, Screen::CollectionList) => {
+            app.run_collection(app.selection.collection);
+        }
+        Action::LoadTestScenario if matches!(app.current_screen, Screen::CollectionList) => {
+            app.open_load_test_scenario(app.selection.collection);
+        }
+        Action::ToggleTraffic => app.toggle_network_traffic(),
+        Action::ToggleHeaders => app.toggle_response_headers(),
+        Action::ToggleWrap => app.toggle_response_wrap(),
+        Action::ToggleLineNumbers => app.toggle_line_numbers(),
+        Action::ToggleEpochDates => app.toggle_epoch_dates(),
+        Action::ToggleThousands => app.toggle_thousands_separators(),
+        Action::ViewAs if app.last_response.is_some() => app.cycle_view_as(),
+        Action::ToggleVisualization if app.last_response.is_some() => app.toggle_visualization(),
+        Action::TableView if app.last_response.is_some() => app.open_json_table(),
+        Action::ResponseLinks if app.last_response.is_some() => app.open_response_links(),
+        Action::ToggleSection if has_response_detail => {
+            // Toggle whichever collapsible section is on screen
+            if app.show_response_headers {
+                app.toggle_section_collapsed("response_headers");
+            } else if app.show_network_traffic {
+                app.toggle_section_collapsed("network_traffic");
+            }
+        }
+        Action::CopyResponse if has_response_detail => app.copy_response_to_clipboard(),
+        Action::GotoLine if app.last_response.is_some() => app.start_goto_line(),
+        Action::Search if app.last_response.is_some() => app.start_search(),
+        Action::ExpandJson if app.in_response_view() => app.expand_json(),
+        Action::ScrollTopChord if app.in_response_view() => {
+            if pending_key == Some('g') {
+                app.reset_response_scroll();
+            } else {
+                app.pending_key = Some('g');
+            }
+        }
+        Action::HalfPageUp if app.in_response_view() => app.scroll_response_half_page_up(),
+        Action::HalfPageDown if app.in_response_view() => app.scroll_response_half_page_down(),
+        Action::ScrollUp => app.scroll_response_up(10),
+        Action::ScrollDown => app.scroll_response_down(10),
+        Action::ScrollTop => app.reset_response_scroll(),
+        Action::ScrollBottom => app.scroll_response_to_end(),
+        Action::ScrollLeft => app.scroll_response_left(8),
+        Action::ScrollRight => app.scroll_response_right(8),
+        Action::HeadersUp => app.scroll_headers_up(5),
+        Action::HeadersDown => app.scroll_headers_down(5),
+        Action::HeadersTop => app.reset_headers_scroll(),
+        _ => {}
+    }
+    false
+}
+
+fn dispatch_form(app: &mut AppState, action: Action) {
+    match action {
+        Action::Back => {
+            // Leaving header edit mode comes before leaving the form
+            if let (Screen::EndpointEdit(_, _), Some(form)) = (&app.current_screen, &mut app.endpoint_form) {
+                if form.header_edit_mode {
+                    form.toggle_header_edit_mode();
+                    return;
+                }
+            }
+            app.navigate_back();
+        }
+        Action::Submit => match app.current_screen {
+            Screen::CollectionEdit(_) => app.save_collection(),
+            Screen::EndpointEdit(_, _) => {
+                if app.endpoint_form.as_ref().is_some_and(|f| f.header_edit_mode) {
+                    app.add_header();
+                } else {
+                    app.save_endpoint();
+                }
+            }
+            Screen::VariableEdit(_) => app.save_variable(),
+            Screen::VariableInput(_, _) => app.execute_request_with_variables(),
+            _ => {}
+        },
+        Action::InsertChar(c) => {
+            if let Some(form) = active_form(app) {
+                form.insert_char(c);
+            }
+        }
+        Action::DeleteChar => {
+            if let Some(form) = active_form(app) {
+                form.delete_char();
+            }
+        }
+        Action::NextField => {
+            if let Some(form) = active_form(app) {
+                form.next_field();
+            }
+        }
+        Action::PrevField => {
+            if let Some(form) = active_form(app) {
+                form.prev_field();
+            }
+        }
+        Action::FormatBody => {
+            if let Screen::EndpointEdit(_, _) = app.current_screen {
+                app.format_endpoint_body();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The form being edited on the current screen, if any
+fn active_form(app: &mut AppState) -> Option<&mut dyn FormInput> {
+    match app.current_screen {
+        Screen::CollectionEdit(_) => app.collection_form.as_mut().map(|f| f as &mut dyn FormInput),
+        Screen::EndpointEdit(_, _) => app.endpoint_form.as_mut().map(|f| f as &mut dyn FormInput),
+        Screen::VariableEdit(_) => app.variable_form.as_mut().map(|f| f as &mut dyn FormInput),
+        Screen::VariableInput(_, _) => app.variable_input_form.as_mut().map(|f| f as &mut dyn FormInput),
+        _ => None,
+    }
+}
+
+/// The selected endpoint, unless the tag filter or archived view hides it
+fn selected_endpoint(app: &AppState) -> Option<(usize, usize)> {
+    let coll_idx = app.selection.collection;
+    let ep_idx = app.selection.endpoint;
+    app.visible_endpoints(coll_idx).contains(&ep_idx).then_some((coll_idx, ep_idx))
+}
+
+fn new_item(app: &mut AppState) {
+    if matches!(app.current_screen, Screen::VariableList) {
+        app.start_new_variable();
+        return;
+    }
+    match app.panel_focus {
+        PanelFocus::Collections => app.start_new_collection(),
+        PanelFocus::Endpoints => app.start_new_endpoint(app.selection.collection),
+    }
+}
+
+fn edit_item(app: &mut AppState) {
+    if matches!(app.current_screen, Screen::VariableList) {
+        if let Some(key) = app.variable_manager.keys().get(app.selection.variable).cloned() {
+            app.start_edit_variable(key);
+        }
+        return;
+    }
+    if matches!(app.current_screen, Screen::EndpointDetail(_, _)) {
+        // Execute request with variable detection
+        app.start_variable_input(app.selection.collection, app.selection.endpoint);
+        return;
+    }
+    match app.panel_focus {
+        PanelFocus::Collections => {
+            if app.selection.collection < app.collections.len() {
+                app.start_edit_collection(app.selection.collection);
+            }
+        }
+        PanelFocus::Endpoints => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.start_edit_endpoint(coll_idx, ep_idx);
+            }
+        }
+    }
+}
+
+fn delete_item(app: &mut AppState) {
+    if matches!(app.current_screen, Screen::VariableList) {
+        if let Some(key) = app.variable_manager.keys().get(app.selection.variable).cloned() {
+            app.confirm_delete_variable(key);
+        }
+        return;
+    }
+    match app.panel_focus {
+        PanelFocus::Collections => {
+            if app.selection.collection < app.collections.len() {
+                app.confirm_delete_collection(app.selection.collection);
+            }
+        }
+        PanelFocus::Endpoints => {
+            if let Some((coll_idx, ep_idx)) = selected_endpoint(app) {
+                app.confirm_delete_endpoint(coll_idx, ep_idx);
+            }
+        }
+    }
+}
+
+/// A form field that has to be a number, e.g. `number::<u64>(app, &form.timeout, "field.timeout")`;
+/// `name` is the field's catalog key
+pub fn number<T: std::str::FromStr>(app: &AppState, value: &str, name: &'static str) -> Result<T, String> {
+    value.trim().parse().map_err(|_| app.tr_with("error.not_a_number", &[("field", &app.tr(name))]))
+}
+
+/// A number field that may be left blank for "none" or "inherit"
+pub fn optional_number<T: std::str::FromStr>(app: &AppState, value: &str, name: &'static str) -> Result<Option<T>, String> {
+    match value.trim() {
+        "" => Ok(None),
+        value => number(app, value, name).map(Some),
+    }
+}
+
+/// Text entry shared by every form: where typed characters go and how Tab moves
+pub trait FormInput {
+    /// Text buffer for the focused field, or None for non-text fields
+    fn field_mut(&mut self) -> Option<&mut String>;
+
+    /// Index of the focused field, or None for single-field forms
+    fn current_field_mut(&mut self) -> Option<&mut usize>;
+
+    /// Number of fields Tab cycles through
+    fn field_count(&self) -> usize;
+
+    /// Whether the focused field accepts this character
+    fn accepts(&self, _c: char) -> bool {
+        true
+    }
+
+    fn insert_char(&mut self, c: char) {
+        if self.accepts(c) {
+            if let Some(field) = self.field_mut() {
+                field.push(c);
+            }
+        }
+    }
+
+    fn delete_char(&mut self) {
+        if let Some(field) = self.field_mut() {
+            field.pop();
+        }
+    }
+
+    fn next_field(&mut self) {
+        let count = self.field_count();
+        if let Some(current) = self.current_field_mut().filter(|_| count > 0) {
+            *current = (*current + 1) % count;
+        }
+    }
+
+    fn prev_field(&mut self) {
+        let count = self.field_count();
+        if let Some(current) = self.current_field_mut().filter(|_| count > 0) {
+            *current = (*current + count - 1) % count;
+        }
+    }
+}
+
+impl FormInput for CollectionForm {
+    fn field_mut(&mut self) -> Option<&mut String> {
+        Some(&mut self.name)
+    }
+
+    fn current_field_mut(&mut self) -> Option<&mut usize> {
+        None
+    }
+
+    fn field_count(&self) -> usize {
+        1
+    }
+}
+
+impl FormInput for EndpointForm {
+    fn field_mut(&mut self) -> Option<&mut String> {
+        if self.header_edit_mode {
+            return match self.header_edit_field {
+                0 => Some(&mut self.header_key),
+                _ => Some(&mut self.header_value),
+            };
+        }
+        match self.current_field {
+            0 => Some(&mut self.name),
+            2 => Some(&mut self.url),
+            3 => Some(&mut self.description),
+            5 => Some(&mut self.body_template),
+            6 => Some(&mut self.timeout_secs),
+            7 => Some(&mut self.tags),
+            8 => Some(&mut self.protobuf),
+            10 => Some(&mut self.visualizer),
+            11 => Some(&mut self.assertions),
+            12 => Some(&mut self.connection),
+            13 => Some(&mut self.extractions),
+            _ => None,
+        }
+    }
+
+    fn current_field_mut(&mut self) -> Option<&mut usize> {
+        if self.header_edit_mode {
+            Some(&mut self.header_edit_field)
+        } else {
+            Some(&mut self.current_field)
+        }
+    }
+
+    fn field_count(&self) -> usize {
+        if self.header_edit_mode { 2 } else { 14 }
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        // Only allow digits for timeout
+        self.header_edit_mode || self.current_field != 6 || c.is_ascii_digit()
+    }
+
+    fn insert_char(&mut self, c: char) {
+        // 'm' cycles the method on the method field, 'h' opens header edit mode on the headers field,
+        // j/x/f/t there apply a Content-Type/Accept preset
+        match (self.header_edit_mode, self.current_field, c) {
+            (false, 1, 'm') => self.cycle_method(),
+            (false, 1, c) if c.is_ascii_uppercase() || c == '-' || c == '_' => self.push_method_char(c),
+            (false, 9, ' ') => self.empty_body = self.empty_body.next(),
+            (false, 4, 'h') => self.toggle_header_edit_mode(),
+            (false, 4, c) => {
+                if let Some(preset) = ContentPreset::for_key(c) {
+                    self.apply_content_preset(preset);
+                }
+            }
+            _ => {
+                if self.accepts(c) {
+                    if let Some(field) = self.field_mut() {
+                        field.push(c);
+                    }
+                }
+            }
+        }
+    }
+
+    fn delete_char(&mut self) {
+        if !self.header_edit_mode && self.current_field == 1 {
+            self.pop_method_char();
+        } else if let Some(field) = self.field_mut() {
+            field.pop();
+        }
+    }
+}
+
+impl FormInput for LoadTestConfigForm {
+    fn field_mut(&mut self) -> Option<&mut String> {
+        match self.current_field {
+            0 => Some(&mut self.concurrency),
+            1 => Some(&mut self.duration),
+            2 => Some(&mut self.ramp_up),
+            3 => Some(&mut self.timeout),
+            4 => Some(&mut self.pool_size),
+            7 => Some(&mut self.headers),
+            8 => Some(&mut self.har_samples),
+            10 => Some(&mut self.cool_down),
+            12 => Some(&mut self.chaos),
+            _ => None,
+        }
+    }
+
+    fn current_field_mut(&mut self) -> Option<&mut usize> {
+        Some(&mut self.current_field)
+    }
+
+    fn field_count(&self) -> usize {
+        13
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        // Headers and chaos options are free text; everything else is a number
+        matches!(self.current_field, 7 | 12) || c.is_ascii_digit()
+    }
+
+    fn insert_char(&mut self, c: char) {
+        // Space toggles the client option fields
+        match (self.current_field, c) {
+            (5, ' ') => self.http1_only = !self.http1_only,
+            (6, ' ') => self.disable_compression = !self.disable_compression,
+            (9, ' ') => self.queue = !self.queue,
+            (11, ' ') => self.soak = !self.soak,
+            _ => {
+                if self.accepts(c) {
+                    if let Some(field) = self.field_mut() {
+                        field.push(c);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl FormInput for VariableForm {
+    fn field_mut(&mut self) -> Option<&mut String> {
+        match self.current_field {
+            0 => Some(&mut self.key),
+            1 => Some(&mut self.value),
+            _ => None,
+        }
+    }
+
+    fn current_field_mut(&mut self) -> Option<&mut usize> {
+        Some(&mut self.current_field)
+    }
+
+    fn field_count(&self) -> usize {
+        2
+    }
+}
+
+impl FormInput for VariableInputForm {
+    fn field_mut(&mut self) -> Option<&mut String> {
+        let var_name = self.required_vars.get(self.current_index)?;
+        self.variables.get_mut(var_name)
+    }
+
+    fn current_field_mut(&mut self) -> Option<&mut usize> {
+        Some(&mut self.current_index)
+    }
+
+    fn field_count(&self) -> usize {
+        self.required_vars.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EmptyBody, HttpMethod};
+    use crate::tui_app::tests::test_app;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn endpoint_form() -> EndpointForm {
+        EndpointForm {
+            name: String::new(),
+            method: HttpMethod::GET,
+            url: String::new(),
+            description: String::new(),
+            headers: HashMap::new(),
+            body_template: String::new(),
+            timeout_secs: String::new(),
+            tags: String::new(),
+            protobuf: String::new(),
+            empty_body: EmptyBody::Omit,
+            visualizer: String::new(),
+            assertions: String::new(),
+            extractions: String::new(),
+            connection: String::new(),
+            collection_index: 0,
+            editing_index: None,
+            current_field: 0,
+            header_edit_mode: false,
+            header_key: String::new(),
+            header_value: String::new(),
+            header_edit_field: 0,
+        }
+    }
+
+    #[test]
+    fn test_keymap_context_decides_meaning() {
+        let keymap = Keymap::default();
+        for c in ['q', '?', 'j', 'k', 'm'] {
+            assert_eq!(
+                keymap.action_for(InputContext::Form, key(KeyCode::Char(c))),
+                Some(Action::InsertChar(c))
+            );
+        }
+        assert_eq!(keymap.action_for(InputContext::Browse, key(KeyCode::Char('q'))), Some(Action::Quit));
+        assert_eq!(keymap.action_for(InputContext::Browse, key(KeyCode::Char('j'))), Some(Action::Down));
+        assert_eq!(keymap.action_for(InputContext::GotoLine, key(KeyCode::Char('q'))), None);
+        assert_eq!(keymap.action_for(InputContext::Confirm, key(KeyCode::Esc)), Some(Action::Cancel));
+    }
+
+    #[test]
+    fn test_keymap_modifiers() {
+        let keymap = Keymap::default();
+        let shift_g = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.action_for(InputContext::Browse, shift_g), Some(Action::ScrollBottom));
+
+        let shift_pgup = KeyEvent::new(KeyCode::PageUp, KeyModifiers::SHIFT);
+        assert_eq!(keymap.action_for(InputContext::Browse, shift_pgup), Some(Action::HeadersUp));
+
+        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action_for(InputContext::Browse, ctrl_d), Some(Action::HalfPageDown));
+        assert_eq!(keymap.action_for(InputContext::Form, ctrl_d), None);
+    }
+
+    #[test]
+    fn test_normalize_windows_keys() {
+        let keymap = Keymap::default();
+        let browse = |key: KeyEvent| keymap.action_for(InputContext::Browse, normalize_key(key));
+        assert_eq!(browse(KeyEvent::new(KeyCode::Char('\u{5}'), KeyModifiers::NONE)), Some(Action::SwitchEnvironment));
+        assert_eq!(browse(KeyEvent::new(KeyCode::Char('R'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)), Some(Action::RecordMacro));
+        assert_eq!(browse(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT)), Some(Action::ScrollBottom));
+
+        // AltGr text goes into forms; Ctrl+Alt+letter stays a combination
+        let alt_gr = |c| normalize_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL | KeyModifiers::ALT));
+        assert_eq!(keymap.action_for(InputContext::Form, alt_gr('@')), Some(Action::InsertChar('@')));
+        assert_eq!(keymap.action_for(InputContext::Form, alt_gr('{')), Some(Action::InsertChar('{')));
+        assert_eq!(keymap.action_for(InputContext::Form, alt_gr('e')), None);
+    }
+
+    #[test]
+    fn test_keymap_rebind() {
+        let mut keymap = Keymap::default();
+        keymap.bind(KeyBinding::char('Q'), Action::Quit);
+        let shift_q = KeyEvent::new(KeyCode::Char('Q'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.action_for(InputContext::Browse, shift_q), Some(Action::Quit));
+    }
+
+    #[test]
+    fn test_search_keys_rebind_apart_from_browse_keys() {
+        let mut keymap = Keymap::default();
+        let n = key(KeyCode::Char('n'));
+        assert_eq!(keymap.action_for(InputContext::SearchResults, n), Some(Action::SearchNext));
+        assert_eq!(keymap.action_for(InputContext::SearchResults, key(KeyCode::Char('j'))), Some(Action::Down));
+
+        // Moving `new` elsewhere leaves n for search, and search moves on its own
+        keymap.bind(KeyBinding::char('+'), Action::New);
+        keymap.bind_search(KeyBinding::char('.'), Action::SearchNext);
+        assert_eq!(keymap.action_for(InputContext::SearchResults, n), Some(Action::SearchNext));
+        assert_eq!(keymap.action_for(InputContext::SearchResults, key(KeyCode::Char('.'))), Some(Action::SearchNext));
+        assert_eq!(keymap.action_for(InputContext::Browse, key(KeyCode::Char('.'))), None);
+    }
+
+    #[test]
+    fn test_load_test_form_input() {
+        let mut form = LoadTestConfigForm {
+            concurrency: String::new(),
+            duration: String::new(),
+            ramp_up: String::new(),
+            timeout: String::new(),
+            pool_size: String::new(),
+            http1_only: false,
+            disable_compression: false,
+            headers: String::new(),
+            har_samples: String::new(),
+            queue: false,
+            cool_down: String::new(),
+            soak: false,
+            chaos: String::new(),
+            current_field: 0,
+            collection_index: 0,
+            endpoint_index: 0,
+            scenario: false,
+        };
+        form.insert_char('5');
+        form.insert_char('a');
+        assert_eq!(form.concurrency, "5");
+
+        form.prev_field();
+        assert_eq!(form.current_field, 12);
+        form.insert_char('d');
+        assert_eq!(form.chaos, "d");
+
+        form.prev_field();
+        assert_eq!(form.current_field, 11);
+        form.insert_char(' ');
+        assert!(form.soak);
+
+        form.prev_field();
+        assert_eq!(form.current_field, 10);
+        form.insert_char('5');
+        assert_eq!(form.cool_down, "5");
+
+        form.prev_field();
+        assert_eq!(form.current_field, 9);
+        form.insert_char(' ');
+        assert!(form.queue);
+
+        form.prev_field();
+        assert_eq!(form.current_field, 8);
+        form.insert_char('2');
+        form.insert_char('x');
+        assert_eq!(form.har_samples, "2");
+
+        form.prev_field();
+        assert_eq!(form.current_field, 7);
+        form.insert_char('X');
+        form.insert_char(':');
+        form.insert_char(' ');
+        assert_eq!(form.headers, "X: ");
+
+        form.prev_field();
+        assert_eq!(form.current_field, 6);
+        form.insert_char(' ');
+        assert!(form.disable_compression);
+
+        for _ in 0..7 {
+            form.next_field();
+        }
+        form.delete_char();
+        assert_eq!(form.concurrency, "");
+    }
+
+    #[test]
+    fn test_endpoint_form_input() {
+        let mut form = endpoint_form();
+        form.insert_char('m');
+        assert_eq!(form.name, "m");
+
+        form.next_field();
+        form.insert_char('m');
+        assert_eq!(form.method, HttpMethod::POST);
+
+        for c in "PURGEm".chars() {
+            form.insert_char(c);
+        }
+        assert_eq!(form.method, HttpMethod::GET);
+        for c in "PROPFINDX".chars() {
+            form.insert_char(c);
+        }
+        form.delete_char();
+        assert_eq!(form.method, HttpMethod::Custom("PROPFIND".to_string()));
+
+        form.current_field = 9;
+        form.insert_char(' ');
+        assert_eq!(form.empty_body, EmptyBody::Empty);
+
+        form.current_field = 6;
+        form.insert_char('x');
+        form.insert_char('9');
+        assert_eq!(form.timeout_secs, "9");
+
+        form.current_field = 4;
+        form.insert_char('h');
+        assert!(form.header_edit_mode);
+        form.insert_char('h');
+        form.next_field();
+        form.insert_char('v');
+        assert_eq!((form.header_key.as_str(), form.header_value.as_str()), ("h", "v"));
+    }
+
+    #[test]
+    fn test_content_presets_replace_headers() {
+        let mut form = endpoint_form();
+        form.headers.insert("content-type".to_string(), "text/csv".to_string());
+        form.current_field = 4;
+        form.insert_char('x');
+        form.insert_char('j');
+        form.insert_char('q');
+        assert_eq!(form.headers.len(), 2);
+        assert_eq!(form.headers.get("Content-Type").map(String::as_str), Some("application/json"));
+        assert_eq!(form.headers.get("Accept").map(String::as_str), Some("application/json"));
+    }
+
+    #[test]
+    fn test_view_gets_input_before_screen() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let keymap = Keymap::default();
+
+        assert!(!handle_key(&mut app, &keymap, key(KeyCode::Char('?'))));
+        assert!(app.view.is_some());
+
+        // 'q' closes help instead of quitting, and the screen underneath is untouched
+        assert!(!handle_key(&mut app, &keymap, key(KeyCode::Down)));
+        assert!(app.view.is_some());
+        assert!(!handle_key(&mut app, &keymap, key(KeyCode::Char('q'))));
+        assert!(app.view.is_none());
+        assert_eq!(app.current_screen, Screen::CollectionList);
+        assert!(handle_key(&mut app, &keymap, key(KeyCode::Char('q'))));
+    }
+
+    #[test]
+    fn test_search_response_with_slash_and_n() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let keymap = Keymap::default();
+        let body: String = (0..100).map(|i| if i % 40 == 0 { format!("\"Id\": {}\n", i) } else { "x\n".to_string() }).collect();
+        app.last_response = Some(crate::http::HttpResponse {
+            status: reqwest::StatusCode::OK,
+            headers: std::collections::HashMap::new(),
+            body: body.clone().into_bytes(),
+            duration: std::time::Duration::ZERO,
+            traffic: None,
+        });
+        app.last_response_formatted = Some(body);
+        app.response_view_height.set(10);
+
+        for c in "/idq".chars() {
+            assert!(!handle_key(&mut app, &keymap, key(KeyCode::Char(c))), "'q' is part of the query");
+        }
+        handle_key(&mut app, &keymap, key(KeyCode::Backspace));
+        assert_eq!(InputContext::for_app(&app), InputContext::Search);
+        handle_key(&mut app, &keymap, key(KeyCode::Enter));
+        assert!(app.search_active());
+        let lines: Vec<usize> = app.search_matches().iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![0, 40, 80], "lowercase queries ignore case");
+        assert_eq!(app.response_scroll_offset, 0);
+
+        handle_key(&mut app, &keymap, key(KeyCode::Char('n')));
+        assert_eq!((app.response_search.as_ref().unwrap().current, app.response_scroll_offset), (1, 35));
+        assert!(!app.show_line_numbers);
+        handle_key(&mut app, &keymap, key(KeyCode::Char('N')));
+        handle_key(&mut app, &keymap, key(KeyCode::Char('N')));
+        assert_eq!(app.response_search.as_ref().unwrap().current, 2);
+        assert_eq!(app.status_message.as_deref(), Some("Search wrapped around"));
+        assert!(!app.show_line_numbers, "N steps back instead of toggling line numbers");
+
+        handle_key(&mut app, &keymap, key(KeyCode::Esc));
+        assert!(app.response_search.is_none());
+        handle_key(&mut app, &keymap, key(KeyCode::Char('N')));
+        assert!(app.show_line_numbers);
+
+        for c in "/nope".chars() {
+            handle_key(&mut app, &keymap, key(KeyCode::Char(c)));
+        }
+        handle_key(&mut app, &keymap, key(KeyCode::Enter));
+        assert_eq!(app.error_message.as_deref(), Some("Pattern not found: nope"));
+    }
+
+    #[test]
+    fn test_ctrl_e_cycles_environments_anywhere() {
+        use crate::variables::VariableSet;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let keymap = Keymap::default();
+        let ctrl_e = KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL);
+
+        handle_key(&mut app, &keymap, ctrl_e);
+        assert!(app.error_message.as_deref().unwrap().starts_with("No environments yet"));
+        for name in ["prod", "staging"] {
+            app.variable_manager.save_environment(&VariableSet::new(name.to_string())).unwrap();
+        }
+
+        // Also while a form is open, which keeps its input
+        app.start_new_variable();
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            handle_key(&mut app, &keymap, ctrl_e);
+            seen.push(app.settings.environment.clone());
+        }
+        assert_eq!(seen, vec![Some("prod".to_string()), Some("staging".to_string()), None]);
+        assert!(matches!(app.current_screen, Screen::VariableEdit(None)));
+        assert_eq!(app.status_message.as_deref(), Some("No environment: requests use the saved variables"));
+
+        handle_key(&mut app, &keymap, ctrl_e);
+        let saved = crate::settings::Settings::load(&app.storage.config_path()).unwrap();
+        assert_eq!(saved.environment.as_deref(), Some("prod"));
+    }
+
+    #[test]
+    fn test_variable_input_form_without_variables() {
+        let mut form = VariableInputForm {
+            variables: HashMap::new(),
+            required_vars: Vec::new(),
+            current_index: 0,
+            collection_index: 0,
+            endpoint_index: 0,
+        };
+        form.next_field();
+        form.insert_char('a');
+        assert_eq!(form.current_index, 0);
+    }
+
+    #[test]
+    fn test_record_and_play_macros() {
+        use crate::models::{ApiCollection, ApiEndpoint};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        for i in 0..3 {
+            collection.add_endpoint(ApiEndpoint::new(format!("ep{}", i), HttpMethod::GET, "http://127.0.0.1:9/".to_string()));
+        }
+        app.collections.push(collection);
+        app.panel_focus = PanelFocus::Endpoints;
+        let keymap = Keymap::default();
+        let record = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        let type_keys = |app: &mut AppState, keys: &str| {
+            for c in keys.chars() {
+                handle_key(app, &keymap, key(KeyCode::Char(c)));
+            }
+        };
+        // Event loop ticks until the macro is done
+        let play = |app: &mut AppState| {
+            while app.macros.is_playing() {
+                app.wait_for_requests();
+                play_macro_keys(app, &keymap);
+            }
+        };
+
+        // Next endpoint, then quick execute it
+        handle_key(&mut app, &keymap, record);
+        type_keys(&mut app, "ajx");
+        assert_eq!(app.macros.recording(), Some('a'));
+        handle_key(&mut app, &keymap, record);
+        assert_eq!(app.status_message.as_deref(), Some("Recorded 2 keys into @a"));
+        app.wait_for_requests();
+        assert_eq!((app.selection.endpoint, app.history.entries().len()), (1, 1));
+
+        // Keys play on the ticks after, each waiting for the request before it
+        type_keys(&mut app, "@a");
+        assert_eq!(app.status_message.as_deref(), Some("Playing @a... (Esc stops)"));
+        assert!(app.is_animating());
+        assert_eq!(app.selection.endpoint, 1);
+        play(&mut app);
+        assert!(app.in_flight.is_empty());
+        assert_eq!((app.selection.endpoint, app.history.entries().len()), (2, 2));
+        type_keys(&mut app, "@@");
+        play(&mut app);
+        assert_eq!(app.history.entries().len(), 3);
+
+        // Typed keys wait out playback, and Esc stops it
+        type_keys(&mut app, "@ak");
+        handle_key(&mut app, &keymap, key(KeyCode::Esc));
+        assert!(!app.macros.is_playing());
+        assert_eq!(app.status_message.as_deref(), Some("Stopped @a"));
+        assert_eq!((app.selection.endpoint, app.history.entries().len()), (2, 3));
+
+        // A macro can play another, and one that plays itself stops
+        handle_key(&mut app, &keymap, record);
+        type_keys(&mut app, "bk@a");
+        play(&mut app);
+        handle_key(&mut app, &keymap, record);
+        assert_eq!(app.macros.keys('b').map(<[KeyEvent]>::len), Some(3));
+        handle_key(&mut app, &keymap, record);
+        type_keys(&mut app, "c@c");
+        handle_key(&mut app, &keymap, record);
+        type_keys(&mut app, "@c");
+        play(&mut app);
+        assert_eq!(app.error_message.as_deref(), Some("@c stopped: macros nested more than 8 deep"));
+        type_keys(&mut app, "@z");
+        assert_eq!(app.error_message.as_deref(), Some("Nothing recorded in @z"));
+    }
+}
@@ -0,0 +1,153 @@
+// Keyboard macros
+// `Ctrl+R` and a letter records the keys that follow into that register until
+// `Ctrl+R` again; `@` and the letter plays them back as if typed, `@@` repeats
+// the last one played. Registers last for the session, like vim's. Playback
+// runs from the event loop's tick, waiting out requests the keys send, so the
+// screen keeps drawing and Esc stops it.
+
+use crossterm::event::KeyEvent;
+use std::collections::{HashMap, VecDeque};
+
+/// How deep macros may play other macros (or themselves)
+pub const MAX_DEPTH: usize = 8;
+
+/// What the next key names a register for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacroPrompt {
+    Record,
+    Play,
+}
+
+#[derive(Debug, Default)]
+pub struct Macros {
+    registers: HashMap<char, Vec<KeyEvent>>,
+    recording: Option<(char, Vec<KeyEvent>)>,
+    pub awaiting: Option<MacroPrompt>, // `Ctrl+R` or `@` was pressed, the register comes next
+    last_played: Option<char>,
+    playing: Vec<(char, VecDeque<KeyEvent>)>, // Registers playing and their keys still to come, innermost last
+}
+
+impl Macros {
+    /// Letters and digits name registers
+    pub fn is_register(c: char) -> bool {
+        c.is_ascii_alphanumeric()
+    }
+
+    pub fn start_recording(&mut self, register: char) {
+        self.recording = Some((register, Vec::new()));
+    }
+
+    /// Save what was recorded; the register and how many keys it holds
+    pub fn stop_recording(&mut self) -> Option<(char, usize)> {
+        let (register, keys) = self.recording.take()?;
+        let count = keys.len();
+        self.registers.insert(register, keys);
+        Some((register, count))
+    }
+
+    pub fn recording(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    /// Add a key to the macro being recorded. Keys a macro plays aren't
+    /// recorded again; the `@` that started it already was.
+    pub fn record(&mut self, key: KeyEvent) {
+        if self.is_playing() {
+            return;
+        }
+        if let Some((_, keys)) = &mut self.recording {
+            keys.push(key);
+        }
+    }
+
+    /// `@` names the last macro played
+    pub fn resolve(&self, register: char) -> Option<char> {
+        if register == '@' { self.last_played } else { Some(register) }
+    }
+
+    pub fn keys(&self, register: char) -> Option<&[KeyEvent]> {
+        self.registers.get(&register).map(Vec::as_slice)
+    }
+
+    /// Macros playing right now, nested ones included
+    pub fn depth(&self) -> usize {
+        self.playing.len()
+    }
+
+    pub fn is_playing(&self) -> bool {
+        !self.playing.is_empty()
+    }
+
+    /// Play a register's keys before the rest of whatever is playing now
+    pub fn begin_playing(&mut self, register: char) {
+        let keys = self.registers.get(&register).cloned().unwrap_or_default();
+        self.last_played = Some(register);
+        self.playing.push((register, keys.into()));
+    }
+
+    /// The next key to play. A register stays playing until the key after its
+    /// last one is asked for, so a macro its last key plays counts as nested.
+    pub fn next_key(&mut self) -> Option<KeyEvent> {
+        while let Some((_, keys)) = self.playing.last_mut() {
+            if let Some(key) = keys.pop_front() {
+                return Some(key);
+            }
+            self.playing.pop();
+        }
+        None
+    }
+
+    /// Drop the keys still to play; the register that was started
+    pub fn stop_playing(&mut self) -> Option<char> {
+        let register = self.playing.first().map(|(register, _)| *register);
+        self.playing.clear();
+        register
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_record_and_resolve() {
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        let mut macros = Macros::default();
+        macros.record(key('x'));
+        assert_eq!(macros.stop_recording(), None);
+
+        macros.start_recording('a');
+        macros.record(key('j'));
+        macros.begin_playing('b');
+        macros.record(key('e')); // played, not typed
+        assert_eq!(macros.stop_playing(), Some('b'));
+        macros.record(key('x'));
+        assert_eq!(macros.recording(), Some('a'));
+        assert_eq!(macros.stop_recording(), Some(('a', 2)));
+        assert_eq!(macros.keys('a'), Some(&[key('j'), key('x')][..]));
+        assert_eq!(macros.resolve('@'), Some('b'));
+        assert!(Macros::is_register('z') && !Macros::is_register('@'));
+    }
+
+    #[test]
+    fn test_nested_macros_play_before_the_rest() {
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        let mut macros = Macros::default();
+        for (register, keys) in [('a', "xy"), ('b', "z")] {
+            macros.start_recording(register);
+            keys.chars().for_each(|c| macros.record(key(c)));
+            macros.stop_recording();
+        }
+
+        macros.begin_playing('a');
+        assert_eq!(macros.next_key(), Some(key('x')));
+        macros.begin_playing('b');
+        assert_eq!(macros.depth(), 2);
+        assert_eq!(macros.next_key(), Some(key('z')));
+        assert_eq!(macros.next_key(), Some(key('y')));
+        assert_eq!(macros.depth(), 1);
+        assert_eq!(macros.next_key(), None);
+        assert!(!macros.is_playing());
+    }
+}
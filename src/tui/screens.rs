@@ -0,0 +1,113 @@
+// Screens that own their state
+// A screen implementing `ScreenView` keeps its form, scroll position and other
+// view state to itself, handles its own actions and draws itself. Opening one
+// only requires `AppState::open_view`; no new `Screen` variant, AppState field
+// or ui.rs match arm is needed.
+
+pub mod collection_run;
+pub mod collection_stats;
+pub mod compare;
+pub mod confirm_host;
+pub mod confirm_protected;
+pub mod copy_curl;
+pub mod crud_scaffold;
+pub mod curl_import;
+pub mod diagnostics;
+pub mod duplicate_endpoint;
+pub mod endpoint_changes;
+pub mod graphql;
+pub mod help;
+pub mod history_search;
+pub mod json_table;
+pub mod load_test_compare;
+pub mod load_test_config;
+pub mod load_test_report;
+pub mod load_test_scenario;
+pub mod log_viewer;
+pub mod merge;
+pub mod notes;
+pub mod overrides;
+pub mod past_results;
+pub mod proxy;
+pub mod quick_edit;
+pub mod response_links;
+pub mod run_selected;
+pub mod settings;
+pub mod split_view;
+pub mod template_error;
+pub mod timeline;
+pub mod transfer;
+pub mod variable_transfer;
+pub mod websocket;
+
+pub use collection_run::CollectionRunScreen;
+pub use collection_stats::CollectionStatsScreen;
+pub use compare::CompareScreen;
+pub use confirm_host::ConfirmHostScreen;
+pub use confirm_protected::ConfirmProtectedScreen;
+pub use copy_curl::CopyCurlScreen;
+pub use crud_scaffold::CrudScaffoldScreen;
+pub use curl_import::CurlImportScreen;
+pub use diagnostics::DiagnosticsScreen;
+pub use duplicate_endpoint::DuplicateEndpointScreen;
+pub use endpoint_changes::EndpointChangesScreen;
+pub use graphql::GraphqlEditorScreen;
+pub use help::HelpScreen;
+pub use history_search::HistorySearchScreen;
+pub use json_table::JsonTableScreen;
+pub use load_test_compare::LoadTestCompareScreen;
+pub use load_test_config::{LoadTestConfigForm, LoadTestConfigScreen};
+pub use load_test_report::LoadTestReportScreen;
+pub use load_test_scenario::LoadTestScenarioScreen;
+pub use log_viewer::LogViewerScreen;
+pub use merge::MergeScreen;
+pub use notes::NotesScreen;
+pub use overrides::OverridesScreen;
+pub use past_results::PastResultsScreen;
+pub use proxy::ProxyScreen;
+pub use quick_edit::QuickEditScreen;
+pub use response_links::ResponseLinksScreen;
+pub use run_selected::RunSelectedScreen;
+pub use settings::SettingsScreen;
+pub use split_view::SplitViewScreen;
+pub use template_error::TemplateErrorScreen;
+pub use timeline::TimelineScreen;
+pub use transfer::{ExportScreen, ImportScreen};
+pub use variable_transfer::VariableTransferScreen;
+pub use websocket::WebSocketScreen;
+
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{layout::Rect, Frame};
+
+/// What should happen to a screen after it handled an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// Keep the screen open
+    Stay,
+    /// Close the screen and return to whatever was underneath
+    Close,
+    /// Quit the application
+    Quit,
+}
+
+pub trait ScreenView {
+    /// How key presses are interpreted while this screen is open
+    fn input_context(&self) -> InputContext {
+        InputContext::Browse
+    }
+
+    /// Apply an action to the screen's own state and, if needed, to shared app state
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition;
+
+    /// Draw the screen into the main content area
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState);
+
+    /// Whether the screen changes on its own (e.g. waits on background work)
+    fn is_animating(&self) -> bool {
+        false
+    }
+
+    /// Called on every redraw tick while animating
+    fn tick(&mut self, _app: &mut AppState) {}
+}
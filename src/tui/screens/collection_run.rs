@@ -0,0 +1,373 @@
+// Sequential run of a whole collection
+// Endpoints are sent one after another on the app's background runtime, each
+// with the variables the ones before it extracted; a step starts on the tick
+// after the one before it finished. Rows fill in with pass/fail and
+// timing as steps finish; the selected row shows why it failed and what it set.
+// WebSocket endpoints are steps too: they send their body and wait for a
+// message that passes their assertions.
+
+use super::{ScreenView, Transition};
+use crate::tui::ui_utils::fmt_duration;
+use crate::http::{HttpClient, RequestInputs};
+use crate::models::ApiEndpoint;
+use crate::runner::{self, EndpointResult};
+use crate::tui::app::AppState;
+use crate::tui::input::Action;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A finished step with the body for history
+#[derive(Debug, Clone)]
+pub struct Step {
+    pub result: EndpointResult,
+    pub body: Vec<u8>,
+}
+
+struct Row {
+    endpoint_index: usize,
+    step: Option<Step>,
+}
+
+pub struct CollectionRunScreen {
+    collection_index: usize,
+    scope: String, // e.g. " tagged 'smoke'", for the summary
+    rows: Vec<Row>,
+    jobs: Vec<ApiEndpoint>, // As they were when the run started
+    available: HashMap<String, String>, // Saved variables plus what earlier steps extracted
+    header_variables: HashMap<String, String>,
+    client: HttpClient,
+    current: Option<(usize, u64)>, // Row of the step in flight and its request
+    step: Arc<Mutex<Option<Step>>>, // Filled in when the step in flight finishes
+    started: Instant,
+    finished: Option<Duration>,
+    selected: usize,
+}
+
+impl CollectionRunScreen {
+    /// Start sending `endpoints` in order. An endpoint whose variables can't be
+    /// resolved from the saved ones and earlier extractions fails without a
+    /// request and the run goes on.
+    pub fn start(app: &mut AppState, collection_index: usize, endpoints: Vec<usize>, scope: String) -> Self {
+        let mut screen = Self {
+            collection_index,
+            scope,
+            jobs: endpoints.iter().map(|&i| app.collections[collection_index].endpoints[i].clone()).collect(),
+            rows: endpoints.into_iter().map(|endpoint_index| Row { endpoint_index, step: None }).collect(),
+            available: app.request_variables(),
+            header_variables: app.default_header_variables(),
+            client: app.client_for(collection_index).clone(),
+            current: None,
+            step: Arc::new(Mutex::new(None)),
+            started: Instant::now(),
+            finished: None,
+            selected: 0,
+        };
+        screen.next_step(app, 0);
+        screen
+    }
+
+    /// Send the step in row `slot`, or record why it can't be sent and move on
+    fn next_step(&mut self, app: &mut AppState, slot: usize) {
+        let Some(endpoint) = self.jobs.get(slot).cloned() else { return };
+        let mut result = EndpointResult::new(&endpoint, &self.available);
+        let mut variables = match app.variables_from(&endpoint, &self.available) {
+            Ok(variables) => variables,
+            Err(e) => {
+                result.record_error(e, Duration::ZERO);
+                return self.finish_step(app, slot, Step { result, body: Vec::new() });
+            }
+        };
+        for (name, value) in &self.header_variables {
+            variables.entry(name.clone()).or_insert_with(|| value.clone());
+        }
+        let label = format!("{} {}", endpoint.method, endpoint.name);
+        let (client, shared) = (self.client.clone(), self.step.clone());
+        let id = app.spawn_view_request(self.collection_index, self.rows[slot].endpoint_index, label, async move {
+            let inputs = RequestInputs { variables, ..Default::default() };
+            let mut body = Vec::new();
+            if endpoint.is_websocket() {
+                body = runner::run_websocket(&client, &endpoint, &inputs, &[], &mut result).await;
+            } else {
+                let started = Instant::now();
+                match client.execute(&endpoint, &inputs).await {
+                    Ok(response) => {
+                        result.record_response(&endpoint, &[], &response);
+                        body = response.body;
+                    }
+                    Err(e) => result.record_error(e.to_string(), started.elapsed()),
+                }
+            }
+            *shared.lock().unwrap() = Some(Step { result, body });
+        });
+        self.current = Some((slot, id));
+    }
+
+    /// Fill in a row, record it in history and start the step after it
+    fn finish_step(&mut self, app: &mut AppState, slot: usize, step: Step) {
+        let row = &mut self.rows[slot];
+        app.record_history(
+            self.collection_index,
+            row.endpoint_index,
+            step.result.status,
+            Duration::from_millis(step.result.duration_ms),
+            step.result.error.clone(),
+            &step.body,
+        );
+        self.available.extend(step.result.extracted.clone());
+        row.step = Some(step);
+        self.next_step(app, slot + 1);
+    }
+
+    fn completed(&self) -> usize {
+        self.rows.iter().filter(|row| row.step.is_some()).count()
+    }
+
+    fn passed(&self) -> usize {
+        self.rows.iter().filter(|row| row.step.as_ref().is_some_and(|s| s.result.passed())).count()
+    }
+
+    /// Endpoint assertions checked so far across the run, and how many failed
+    fn assertion_totals(&self, app: &AppState) -> (usize, usize) {
+        let Some(collection) = app.collections.get(self.collection_index) else { return (0, 0) };
+        self.rows.iter()
+            .filter_map(|row| Some((collection.endpoints.get(row.endpoint_index)?, row.step.as_ref()?)))
+            .filter(|(_, step)| step.result.status.is_some())
+            .fold((0, 0), |(checked, failed), (endpoint, step)| {
+                (checked + endpoint.assertions.len(), failed + failed_assertions(endpoint, &step.result))
+            })
+    }
+
+    /// Why the selected step failed and the variables it set
+    fn details(&self, app: &AppState) -> Vec<Line<'static>> {
+        let Some(step) = self.rows.get(self.selected).and_then(|row| row.step.as_ref()) else {
+            return vec![Line::from(Span::styled(app.tr("collection_run.waiting"), Style::default().fg(Color::DarkGray)))];
+        };
+        let result = &step.result;
+        let mut lines = vec![Line::from(Span::styled(result.url.clone(), Style::default().fg(Color::DarkGray)))];
+        if let Some(error) = &result.error {
+            lines.push(Line::from(Span::styled(format!("✗ {}", error), Style::default().fg(Color::Red))));
+        }
+        for failure in result.assertion_failures.iter().chain(&result.extraction_failures) {
+            lines.push(Line::from(Span::styled(format!("✗ {}", failure), Style::default().fg(Color::Red))));
+        }
+        for (name, value) in &result.extracted {
+            lines.push(Line::from(vec![
+                Span::styled(format!("🧲 {} = ", name), Style::default().fg(Color::Yellow)),
+                Span::raw(value.clone()),
+            ]));
+        }
+        lines
+    }
+}
+
+/// How many of the endpoint's own assertions a step failed, leaving out the
+/// implicit 2xx/3xx check
+fn failed_assertions(endpoint: &ApiEndpoint, result: &EndpointResult) -> usize {
+    let own: Vec<String> = endpoint.assertions.iter().map(|a| a.to_string()).collect();
+    result.assertion_failures.iter().filter(|failure| own.contains(failure)).count()
+}
+
+impl ScreenView for CollectionRunScreen {
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.selected = self.selected.saturating_sub(1),
+            Action::Down => self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1)),
+            Action::Quit => return Transition::Quit,
+            Action::Back => {
+                // The step in flight is cancelled and the rest are never sent
+                if let Some((_, id)) = self.current.take() {
+                    app.cancel_request(id);
+                }
+                return Transition::Close;
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn is_animating(&self) -> bool {
+        self.finished.is_none()
+    }
+
+    /// Move the finished step into its row and start the next one
+    fn tick(&mut self, app: &mut AppState) {
+        let finished = self.step.lock().unwrap().take();
+        if let Some(step) = finished {
+            if let Some((slot, _)) = self.current.take() {
+                self.finish_step(app, slot, step);
+            }
+        }
+        if self.finished.is_none() && self.completed() == self.rows.len() {
+            self.finished = Some(self.started.elapsed());
+            let failed = self.rows.len() - self.passed();
+            app.status_message = Some(app.tr_with("collection_run.ran", &[
+                ("count", &self.rows.len()),
+                ("scope", &self.scope),
+                ("passed", &self.passed()),
+                ("failed", &failed),
+            ]));
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(collection) = app.collections.get(self.collection_index) else { return };
+        let dim = Style::default().fg(Color::DarkGray);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(6), Constraint::Length(8)])
+            .split(area);
+
+        let mut progress = match self.finished {
+            Some(elapsed) => app.tr_with("collection_run.done", &[
+                ("elapsed", &fmt_duration(elapsed, app.settings.latency_decimals)),
+                ("passed", &self.passed()),
+                ("failed", &(self.rows.len() - self.passed())),
+            ]),
+            None => app.tr_with("collection_run.running", &[
+                ("step", &(self.completed() + 1).min(self.rows.len())),
+                ("total", &self.rows.len()),
+            ]),
+        };
+        let (checked, failed_checks) = self.assertion_totals(app);
+        if checked > 0 {
+            progress.push_str(&app.tr_with("collection_run.assertions", &[("failed", &failed_checks), ("checked", &checked)]));
+        }
+        let mut lines = vec![
+            Line::from(Span::styled(progress, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from(Span::styled(format!(
+                "  {:<3} {:>3} {:<8} {:<32} {:>6} {:>10} {:>10}",
+                "", "#", app.tr("common.method"), app.tr("common.name"), app.tr("common.status"), app.tr("common.time"), app.tr("collection_run.checks"),
+            ), dim)),
+        ];
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let Some(endpoint) = collection.endpoints.get(row.endpoint_index) else { continue };
+            let name: String = endpoint.name.chars().take(32).collect();
+            let (marker, color, status, time, checks) = match &row.step {
+                None => ("…", Color::DarkGray, "—".to_string(), "—".to_string(), "—".to_string()),
+                Some(step) => (
+                    if step.result.passed() { "✓" } else { "✗" },
+                    if step.result.passed() { Color::Green } else { Color::Red },
+                    step.result.status.map(|s| s.to_string()).unwrap_or_else(|| app.tr("common.error").to_string()),
+                    fmt_duration(Duration::from_millis(step.result.duration_ms), app.settings.latency_decimals),
+                    match (endpoint.assertions.len(), step.result.status) {
+                        (0, _) | (_, None) => "—".to_string(),
+                        (total, Some(_)) => format!("{}/{}", total.saturating_sub(failed_assertions(endpoint, &step.result)), total),
+                    },
+                ),
+            };
+            let style = if i == self.selected {
+                Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<3} ", marker), Style::default().fg(color)),
+                Span::styled(format!("{:>3} {:<8} {:<32} {:>6} {:>10} {:>10}", i + 1, endpoint.method.to_string(), name, status, time, checks), style),
+            ]));
+        }
+
+        // Keep the selected row on screen
+        let visible = chunks[0].height.saturating_sub(5) as usize;
+        let scroll = self.selected.saturating_sub(visible.saturating_sub(1)) as u16;
+        let paragraph = Paragraph::new(lines)
+            .scroll((scroll, 0))
+            .block(Block::default()
+                .title(app.tr_with("collection_run.title", &[("name", &collection.name), ("scope", &self.scope)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, chunks[0]);
+
+        let details = Paragraph::new(self.details(app))
+            .wrap(Wrap { trim: false })
+            .block(Block::default()
+                .title(app.tr("collection_run.step_details"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(dim));
+        f.render_widget(details, chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, Extraction, HttpMethod};
+    use crate::tui_app::tests::test_app;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn test_steps_run_in_order_with_extracted_variables() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).unwrap();
+                requests.push(String::from_utf8_lossy(&buf[..n]).into_owned());
+                let body = r#"{"token": "t-1"}"#;
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", body.len(), body);
+                socket.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+
+        let base = format!("http://127.0.0.1:{}", port);
+        let mut collection = ApiCollection::new("Flow".to_string());
+        let mut login = ApiEndpoint::new("login".to_string(), HttpMethod::GET, format!("{}/login", base));
+        login.extractions = Extraction::parse_list("token = .token").unwrap();
+        collection.add_endpoint(login);
+        collection.add_endpoint(ApiEndpoint::new("me".to_string(), HttpMethod::GET, format!("{}/me/{{{{token}}}}", base)));
+        collection.add_endpoint(ApiEndpoint::new("other".to_string(), HttpMethod::GET, "{{host}}/x".to_string()));
+        app.collections.push(collection);
+
+        app.run_collection(0);
+        while app.is_animating() {
+            std::thread::sleep(Duration::from_millis(10));
+            app.tick();
+        }
+        let requests = server.join().unwrap();
+        assert!(requests[1].starts_with("GET /me/t-1 "));
+        assert_eq!(app.history.entries().len(), 3);
+        assert!(app.history.entries()[2].error.as_deref().unwrap().contains("host"));
+        assert_eq!(app.status_message.as_deref(), Some("Ran 3 endpoints: 2 passed, 1 failed"));
+    }
+
+    #[test]
+    fn test_going_back_cancels_the_step_in_flight() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        // Accepts connections but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut collection = ApiCollection::new("Flow".to_string());
+        for name in ["one", "two"] {
+            collection.add_endpoint(ApiEndpoint::new(name.to_string(), HttpMethod::GET, format!("http://{}/x", listener.local_addr().unwrap())));
+        }
+        app.collections.push(collection);
+
+        app.run_collection(0);
+        app.tick();
+        assert_eq!(app.in_flight.iter().map(|r| r.label.as_str()).collect::<Vec<_>>(), vec!["GET one"]);
+
+        let mut view = app.view.take().unwrap();
+        assert!(matches!(view.handle_action(&mut app, Action::Back), Transition::Close));
+        assert!(app.in_flight.is_empty());
+        app.tick();
+        assert!(app.history.entries().is_empty());
+    }
+}
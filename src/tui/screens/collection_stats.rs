@@ -0,0 +1,229 @@
+// Collection statistics screen
+// Per-collection overview built from the request history: endpoint count by
+// method, last-run status, average latency and failing assertions.
+
+use super::{ScreenView, Transition};
+use crate::tui::ui_utils::fmt_duration;
+use crate::history::LatencyBucket;
+use crate::models::{ApiCollection, HttpMethod};
+use crate::tui::app::AppState;
+use crate::tui::input::Action;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+const METHODS: [HttpMethod; 7] = [
+    HttpMethod::GET,
+    HttpMethod::POST,
+    HttpMethod::PUT,
+    HttpMethod::PATCH,
+    HttpMethod::DELETE,
+    HttpMethod::HEAD,
+    HttpMethod::OPTIONS,
+];
+
+/// Bars in the latency histogram
+const HISTOGRAM_BUCKETS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+#[derive(Debug)]
+pub struct CollectionStatsScreen {
+    collection_index: usize,
+    endpoint_index: Option<usize>, // Endpoint whose latency histogram is shown
+    scroll: u16,
+}
+
+impl CollectionStatsScreen {
+    pub fn new(collection_index: usize) -> Self {
+        Self { collection_index, endpoint_index: None, scroll: 0 }
+    }
+
+    pub fn with_endpoint(mut self, endpoint_index: Option<usize>) -> Self {
+        self.endpoint_index = endpoint_index;
+        self
+    }
+}
+
+/// Histogram lines for one endpoint's answered requests
+fn histogram_lines(histogram: &[LatencyBucket]) -> Vec<Line<'static>> {
+    let most = histogram.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+    histogram.iter()
+        .map(|bucket| {
+            let bar = (bucket.count * HISTOGRAM_WIDTH).div_ceil(most);
+            Line::from(vec![
+                Span::raw(format!("  {:>7}–{:<7} ", format!("{}ms", bucket.lower_ms), format!("{}ms", bucket.upper_ms))),
+                Span::styled("█".repeat(bar), Style::default().fg(Color::Cyan)),
+                Span::raw(format!(" {}", bucket.count)),
+            ])
+        })
+        .collect()
+}
+
+/// Number of endpoints per method, skipping methods the collection doesn't use.
+/// Custom methods follow the standard ones in order of first use.
+pub fn method_counts(collection: &ApiCollection) -> Vec<(HttpMethod, usize)> {
+    let mut methods = METHODS.to_vec();
+    for endpoint in &collection.endpoints {
+        if !methods.contains(&endpoint.method) {
+            methods.push(endpoint.method.clone());
+        }
+    }
+    methods
+        .into_iter()
+        .map(|method| {
+            let count = collection.endpoints.iter().filter(|e| e.method == method).count();
+            (method, count)
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect()
+}
+
+impl ScreenView for CollectionStatsScreen {
+    fn handle_action(&mut self, _app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.scroll = self.scroll.saturating_sub(1),
+            Action::Down => self.scroll = self.scroll.saturating_add(1),
+            Action::ScrollUp => self.scroll = self.scroll.saturating_sub(10),
+            Action::ScrollDown => self.scroll = self.scroll.saturating_add(10),
+            Action::ScrollTop => self.scroll = 0,
+            // Any other key closes the dashboard
+            _ => return Transition::Close,
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(collection) = app.collections.get(self.collection_index) else {
+            return;
+        };
+        let heading = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let dim = Style::default().fg(Color::DarkGray);
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(app.tr("collection_stats.by_method"), heading)]),
+        ];
+        let counts = method_counts(collection);
+        if counts.is_empty() {
+            lines.push(Line::from(Span::styled(app.tr("collection_stats.no_endpoints"), dim)));
+        } else {
+            lines.push(Line::from(
+                counts.iter()
+                    .map(|(method, count)| format!("  {}: {}", method, count))
+                    .collect::<Vec<_>>()
+                    .join("  "),
+            ));
+        }
+        lines.push(Line::from(""));
+
+        lines.push(Line::from(vec![Span::styled(app.tr("collection_stats.endpoints"), heading)]));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {:<3} {:<8} {:<28} {:>6} {:>10} {:>5}  {}",
+                "", app.tr("common.method"), app.tr("common.name"), app.tr("collection_stats.last"),
+                app.tr("collection_stats.avg"), app.tr("collection_stats.runs"), app.tr("collection_stats.failing_assertions"),
+            ),
+            dim,
+        )));
+
+        let mut total_runs = 0;
+        let mut total_failures = 0;
+        let mut failing_endpoints = 0;
+        for endpoint in &collection.endpoints {
+            let stats = app.history.endpoint_stats(endpoint.id);
+            total_runs += stats.runs;
+            total_failures += stats.failures;
+
+            let (marker, color, last) = match stats.last {
+                None => ("—", Color::DarkGray, app.tr("common.never").to_string()),
+                Some(entry) => {
+                    let last = entry.status.map(|s| s.to_string()).unwrap_or_else(|| app.tr("common.error").to_string());
+                    if entry.is_success() {
+                        ("✓", Color::Green, last)
+                    } else {
+                        failing_endpoints += 1;
+                        ("✗", Color::Red, last)
+                    }
+                }
+            };
+            let avg = stats.avg_latency
+                .map(|d| fmt_duration(d, app.settings.latency_decimals))
+                .unwrap_or_else(|| "—".to_string());
+            let assertions = stats.last
+                .map(|entry| entry.assertion_failures.join(", "))
+                .unwrap_or_default();
+            let name: String = endpoint.name.chars().take(28).collect();
+
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<3} ", marker), Style::default().fg(color)),
+                Span::raw(format!("{:<8} {:<28} {:>6} {:>10} {:>5}  ", endpoint.method.to_string(), name, last, avg, stats.runs)),
+                Span::styled(assertions, Style::default().fg(Color::Red)),
+            ]));
+        }
+        lines.push(Line::from(""));
+
+        if let Some(endpoint) = self.endpoint_index.and_then(|i| collection.endpoints.get(i)) {
+            lines.push(Line::from(vec![Span::styled(app.tr_with("collection_stats.histogram", &[("name", &endpoint.name)]), heading)]));
+            let histogram = app.history.latency_histogram(endpoint.id, HISTOGRAM_BUCKETS);
+            if histogram.is_empty() {
+                lines.push(Line::from(Span::styled(app.tr("collection_stats.no_answers"), dim)));
+            } else {
+                lines.extend(histogram_lines(&histogram));
+            }
+            lines.push(Line::from(""));
+        }
+
+        lines.push(Line::from(vec![Span::styled(app.tr("collection_stats.summary"), heading)]));
+        lines.push(Line::from(app.tr_with("collection_stats.totals", &[
+            ("endpoints", &collection.endpoints.len()),
+            ("failing", &failing_endpoints),
+            ("runs", &total_runs),
+            ("failed", &total_failures),
+        ])));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(app.tr("collection_stats.keys"), dim)));
+
+        let max_scroll = (lines.len() as u16).saturating_sub(area.height.saturating_sub(2));
+        let paragraph = Paragraph::new(lines)
+            .scroll((self.scroll.min(max_scroll), 0))
+            .block(Block::default()
+                .title(app.tr_with("collection_stats.title", &[("name", &collection.name)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiEndpoint;
+
+    #[test]
+    fn test_method_counts_skip_unused_methods() {
+        let mut collection = ApiCollection::new("API".to_string());
+        for method in [HttpMethod::GET, HttpMethod::POST, HttpMethod::GET] {
+            collection.add_endpoint(ApiEndpoint::new("ep".to_string(), method, "https://example.com".to_string()));
+        }
+
+        assert_eq!(method_counts(&collection), vec![(HttpMethod::GET, 2), (HttpMethod::POST, 1)]);
+    }
+
+    #[test]
+    fn test_histogram_bars_scale_to_busiest_bucket() {
+        let histogram = [
+            LatencyBucket { lower_ms: 10, upper_ms: 20, count: 8 },
+            LatencyBucket { lower_ms: 20, upper_ms: 30, count: 0 },
+            LatencyBucket { lower_ms: 30, upper_ms: 40, count: 1 },
+        ];
+        let bars: Vec<usize> = histogram_lines(&histogram).iter()
+            .map(|line| line.spans[1].content.chars().count())
+            .collect();
+        assert_eq!(bars, vec![HISTOGRAM_WIDTH, 0, HISTOGRAM_WIDTH / 8]);
+    }
+}
@@ -0,0 +1,301 @@
+// Environment comparison screen
+// Sends one endpoint to two environments at once on the app's background
+// runtime and shows both responses side by side: status and latency, then
+// every path where the bodies differ.
+
+use super::{ScreenView, Transition};
+use crate::tui::ui_utils::{fmt_duration, status_symbol};
+use crate::compare::{self, Difference};
+use crate::http::RequestInputs;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// One environment's response, or why there isn't one
+type SideResult = std::result::Result<Captured, String>;
+
+#[derive(Debug, Clone)]
+struct Captured {
+    status: u16,
+    duration: Duration,
+    body: Vec<u8>,
+}
+
+pub struct CompareScreen {
+    collection_index: usize,
+    endpoint_index: usize,
+    environments: Vec<String>,
+    /// Environment index shown on the left and on the right
+    sides: [usize; 2],
+    results: Arc<Mutex<[Option<SideResult>; 2]>>, // Filled in by the background tasks
+    requests: Vec<u64>, // In flight on the app's runtime; re-running or closing cancels them
+    shown: [Option<SideResult>; 2],
+    differences: Option<Vec<Difference>>,
+    scroll: u16,
+}
+
+impl CompareScreen {
+    /// Compare the first two environments; `environments` must hold at least two
+    pub fn start(app: &mut AppState, collection_index: usize, endpoint_index: usize, environments: Vec<String>) -> Self {
+        let mut screen = Self {
+            collection_index,
+            endpoint_index,
+            environments,
+            sides: [0, 1],
+            results: Arc::new(Mutex::new([None, None])),
+            requests: Vec::new(),
+            shown: [None, None],
+            differences: None,
+            scroll: 0,
+        };
+        screen.run(app);
+        screen
+    }
+
+    /// Send the request to both environments; an earlier run still in flight is cancelled
+    fn run(&mut self, app: &mut AppState) {
+        self.cancel(app);
+        let Some(endpoint) = app.collections.get(self.collection_index)
+            .and_then(|c| c.endpoints.get(self.endpoint_index))
+            .cloned() else { return };
+
+        let results = Arc::new(Mutex::new([None, None]));
+        let mut requests = Vec::new();
+        for (side, &env) in self.sides.iter().enumerate() {
+            let name = &self.environments[env];
+            let variables = app.variable_manager.variables_for(Some(name))
+                .map_err(|e| e.to_string())
+                .and_then(|available| app.variables_from(&endpoint, &available));
+            // Switching sides doesn't prompt, so hosts outside the allowlist just fail
+            let variables = variables.and_then(|variables| match app.unapproved_host(&endpoint, &variables) {
+                Some(host) => Err(app.tr_with("compare.not_allowed", &[("host", &host)])),
+                None => Ok(variables),
+            });
+            match variables {
+                Ok(variables) => requests.push((side, variables)),
+                Err(e) => results.lock().unwrap()[side] = Some(Err(format!("{}: {}", name, e))),
+            }
+        }
+
+        let client = app.client_for(self.collection_index).clone();
+        for (side, variables) in requests {
+            let label = format!("{} {} ({})", endpoint.method, endpoint.name, self.environments[self.sides[side]]);
+            let (client, shared, endpoint) = (client.clone(), results.clone(), endpoint.clone());
+            self.requests.push(app.spawn_view_request(self.collection_index, self.endpoint_index, label, async move {
+                let inputs = RequestInputs { variables, ..Default::default() };
+                let result = client.execute(&endpoint, &inputs).await
+                    .map(|response| Captured {
+                        status: response.status.as_u16(),
+                        duration: response.duration,
+                        body: response.body,
+                    })
+                    .map_err(|e| e.to_string());
+                shared.lock().unwrap()[side] = Some(result);
+            }));
+        }
+
+        self.results = results;
+        self.shown = [None, None];
+        self.differences = None;
+        self.scroll = 0;
+    }
+
+    fn cancel(&mut self, app: &mut AppState) {
+        for id in self.requests.drain(..) {
+            app.cancel_request(id);
+        }
+    }
+
+    /// Point one side at the next environment and re-run
+    fn cycle_side(&mut self, app: &mut AppState, side: usize) {
+        self.sides[side] = (self.sides[side] + 1) % self.environments.len();
+        self.run(app);
+    }
+
+    fn draw_side(&self, f: &mut Frame, area: Rect, side: usize, app: &AppState) {
+        let name = &self.environments[self.sides[side]];
+        let other = self.shown[1 - side].as_ref().and_then(|r| r.as_ref().ok());
+        let lines = match &self.shown[side] {
+            None => vec![Line::from(Span::styled(app.tr("common.sending"), Style::default().fg(Color::DarkGray)))],
+            Some(Err(e)) => vec![Line::from(Span::styled(e.clone(), Style::default().fg(Color::Red)))],
+            Some(Ok(captured)) => {
+                let status_color = if (200..400).contains(&captured.status) { Color::Green } else { Color::Red };
+                let mut latency = vec![Span::raw(app.tr_with("compare.latency", &[("duration", &fmt_duration(captured.duration, app.settings.latency_decimals))]))];
+                if let Some(other) = other {
+                    let delta = captured.duration.as_millis() as i128 - other.duration.as_millis() as i128;
+                    let (arrow, color) = if delta > 0 { ("▲", Color::Red) } else { ("▼", Color::Green) };
+                    latency.push(Span::styled(format!(" ({} {:+}ms)", arrow, delta), Style::default().fg(color)));
+                }
+                let mut lines = vec![
+                    Line::from(Span::styled(format!("{}: {} {}", app.tr("common.status"), status_symbol(captured.status), captured.status), Style::default().fg(status_color))),
+                    Line::from(latency),
+                    Line::from(app.tr_with("compare.size", &[("bytes", &captured.body.len())])),
+                ];
+                if other.is_some_and(|other| other.status != captured.status) {
+                    lines.push(Line::from(Span::styled(app.tr("compare.status_differs"), Style::default().fg(Color::Yellow))));
+                }
+                lines
+            }
+        };
+        let key = if side == 0 { '1' } else { '2' };
+        let paragraph = Paragraph::new(lines).block(Block::default()
+            .title(app.tr_with("compare.side_title", &[("name", name), ("key", &key)]))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+impl ScreenView for CompareScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.scroll = self.scroll.saturating_sub(1),
+            Action::Down => self.scroll = self.scroll.saturating_add(1),
+            Action::InsertChar('1') => self.cycle_side(app, 0),
+            Action::InsertChar('2') => self.cycle_side(app, 1),
+            Action::InsertChar('r') | Action::Submit => self.run(app),
+            Action::InsertChar('q') | Action::Back => {
+                self.cancel(app);
+                return Transition::Close;
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn is_animating(&self) -> bool {
+        self.differences.is_none()
+    }
+
+    /// Pick up finished responses and diff the bodies once both are in
+    fn tick(&mut self, _app: &mut AppState) {
+        self.shown = self.results.lock().unwrap().clone();
+        if let [Some(left), Some(right)] = &self.shown {
+            self.differences = Some(match (left, right) {
+                (Ok(left), Ok(right)) => compare::diff_bodies(&left.body, &right.body),
+                _ => Vec::new(),
+            });
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(endpoint) = app.collections.get(self.collection_index)
+            .and_then(|c| c.endpoints.get(self.endpoint_index)) else { return };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(6), Constraint::Min(3)])
+            .split(area);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+        self.draw_side(f, columns[0], 0, app);
+        self.draw_side(f, columns[1], 1, app);
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let both_ok = matches!(&self.shown, [Some(Ok(_)), Some(Ok(_))]);
+        let lines: Vec<Line> = match &self.differences {
+            None => vec![Line::from(Span::styled(app.tr("compare.waiting"), dim))],
+            Some(_) if !both_ok => vec![Line::from(Span::styled(app.tr("compare.request_failed"), dim))],
+            Some(differences) if differences.is_empty() => vec![Line::from(Span::styled(
+                app.tr("compare.identical"),
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            ))],
+            Some(differences) => {
+                // Path column, then the two values side by side
+                let inner = chunks[1].width.saturating_sub(4) as usize;
+                let path_width = differences.iter().map(|d| d.path.chars().count()).max().unwrap_or(0).min(inner / 3);
+                let value_width = inner.saturating_sub(path_width + 2) / 2;
+                let cell = |value: &Option<String>| match value {
+                    Some(value) => value.chars().take(value_width.saturating_sub(1)).collect::<String>(),
+                    None => app.tr("common.missing").to_string(),
+                };
+                let mut lines = vec![Line::from(Span::styled(
+                    app.tr_with(if differences.len() == 1 { "compare.difference" } else { "compare.differences" }, &[("count", &differences.len())]),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ))];
+                for difference in differences {
+                    let path: String = difference.path.chars().take(path_width).collect();
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{:<width$}  ", path, width = path_width), Style::default().fg(Color::Cyan)),
+                        Span::styled(format!("{:<width$}", cell(&difference.left), width = value_width), Style::default().fg(Color::Red)),
+                        Span::styled(cell(&difference.right), Style::default().fg(Color::Green)),
+                    ]));
+                }
+                lines
+            }
+        };
+
+        let max_scroll = (lines.len() as u16).saturating_sub(chunks[1].height.saturating_sub(2));
+        let paragraph = Paragraph::new(lines)
+            .scroll((self.scroll.min(max_scroll), 0))
+            .block(Block::default()
+                .title(app.tr_with("compare.title", &[("method", &endpoint.method), ("name", &endpoint.name)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+    use crate::variables::VariableSet;
+    use std::time::Instant;
+
+    #[test]
+    fn test_each_side_resolves_its_own_environment() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("one".to_string(), HttpMethod::GET, "{{host}}/x".to_string()));
+        app.collections.push(collection);
+
+        app.start_compare(0, 0);
+        assert!(app.view.is_none());
+        assert!(app.error_message.as_deref().unwrap().contains("two environments"));
+
+        // Nothing listens on port 1, so prod fails fast with a connection error
+        let mut prod = VariableSet::new("prod".to_string());
+        prod.set("host".to_string(), "http://127.0.0.1:1".to_string());
+        app.variable_manager.save_environment(&prod).unwrap();
+        app.variable_manager.save_environment(&VariableSet::new("staging".to_string())).unwrap();
+
+        let environments = app.variable_manager.environment_names();
+        let mut screen = CompareScreen::start(&mut app, 0, 0, environments);
+        assert_eq!(app.in_flight.iter().map(|r| r.label.as_str()).collect::<Vec<_>>(), vec!["GET one (prod)"]);
+        let started = Instant::now();
+        while screen.is_animating() && started.elapsed() < Duration::from_secs(10) {
+            std::thread::sleep(Duration::from_millis(10));
+            screen.tick(&mut app);
+        }
+        assert_eq!(screen.differences, Some(Vec::new()));
+        let [Some(Err(prod)), Some(Err(staging))] = &screen.shown else { panic!("both sides should fail") };
+        assert!(!prod.contains("host"));
+        assert!(staging.starts_with("staging:") && staging.contains("host"));
+
+        // Re-running replaces the request in flight; closing cancels it
+        screen.handle_action(&mut app, Action::Submit);
+        assert_eq!(app.in_flight.len(), 1);
+        screen.handle_action(&mut app, Action::Back);
+        assert!(app.in_flight.is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+// Host allowlist confirmation
+// Shown before a request goes to a host outside the workspace allowlist. The
+// request that was stopped runs once the hosts are approved.
+
+use super::{ScreenView, Transition};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+/// What to do once the hosts are approved
+pub type AfterConfirm = Box<dyn FnOnce(&mut AppState)>;
+
+pub struct ConfirmHostScreen {
+    hosts: Vec<String>,
+    then: Option<AfterConfirm>,
+}
+
+impl ConfirmHostScreen {
+    pub fn new(hosts: Vec<String>, then: AfterConfirm) -> Self {
+        Self { hosts, then: Some(then) }
+    }
+
+    fn proceed(&mut self, app: &mut AppState) -> Transition {
+        app.approved_hosts.extend(self.hosts.iter().cloned());
+        if let Some(then) = self.then.take() {
+            then(app);
+        }
+        Transition::Close
+    }
+}
+
+impl ScreenView for ConfirmHostScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            // Once: approved until the app restarts
+            Action::InsertChar('y') | Action::InsertChar('Y') => self.proceed(app),
+            Action::InsertChar('a') | Action::InsertChar('A') => {
+                for host in &self.hosts {
+                    app.allow_host(host);
+                }
+                self.proceed(app)
+            }
+            Action::InsertChar('n') | Action::InsertChar('N') | Action::Back => {
+                app.status_message = Some(app.tr("confirm_host.cancelled").to_string());
+                Transition::Close
+            }
+            _ => Transition::Stay,
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let mut lines = vec![
+            Line::from(Span::styled(
+                app.tr("confirm_host.warning"),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        lines.extend(self.hosts.iter().map(|host| Line::from(Span::styled(
+            format!("  {}", host),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ))));
+        lines.push(Line::from(""));
+        lines.push(Line::from(app.tr("confirm_host.keys")));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr("confirm_host.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Red)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_blocked_host_runs_after_approval() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        app.allowlist.enabled = true;
+
+        let mut collection = ApiCollection::new("A".to_string());
+        let endpoint = ApiEndpoint::new("one".to_string(), HttpMethod::GET, "http://Prod.example.com/x".to_string());
+        assert_eq!(app.unapproved_host(&endpoint, &Default::default()).as_deref(), Some("prod.example.com"));
+        collection.add_endpoint(endpoint);
+        app.collections.push(collection);
+
+        // Marking and running stops at the prompt without sending anything
+        app.toggle_endpoint_mark(0, 0);
+        app.run_marked(0);
+        let mut prompt = app.view.take().unwrap();
+        assert_eq!(prompt.handle_action(&mut app, Action::Back), Transition::Close);
+        assert!(app.view.is_none());
+
+        app.run_marked(0);
+        let mut prompt = app.view.take().unwrap();
+        assert_eq!(prompt.handle_action(&mut app, Action::InsertChar('a')), Transition::Close);
+        assert!(app.view.is_some(), "the parallel run opens once the host is approved");
+        assert!(app.allowlist.allows("prod.example.com"));
+        let saved = crate::allowlist::HostAllowlist::load(&app.storage.allowlist_path()).unwrap();
+        assert_eq!(saved.hosts, vec!["prod.example.com"]);
+    }
+}
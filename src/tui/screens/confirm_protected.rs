@@ -0,0 +1,168 @@
+// Protected environment confirmation
+// Shown before requests that change data (POST, PUT, PATCH, DELETE, ...) go
+// to an environment marked `"protected": true`. Typing the environment's name
+// sends them; a single keypress never does. A screen that asked for itself
+// is shown again once the prompt closes.
+
+use super::confirm_host::AfterConfirm;
+use super::{ScreenView, Transition};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// How many of the requests to list before summing up the rest
+const MAX_LISTED: usize = 8;
+
+pub struct ConfirmProtectedScreen {
+    environment: String,
+    requests: Vec<String>, // `DELETE https://...`
+    typed: String,
+    then: Option<AfterConfirm>,
+    behind: Option<Box<dyn ScreenView>>, // Screen to go back to either way
+}
+
+impl ConfirmProtectedScreen {
+    pub fn new(environment: String, requests: Vec<String>, then: AfterConfirm) -> Self {
+        Self { environment, requests, typed: String::new(), then: Some(then), behind: None }
+    }
+
+    /// Return to `view` once the prompt closes; `then` runs with it open
+    pub fn over(mut self, view: Box<dyn ScreenView>) -> Self {
+        self.behind = Some(view);
+        self
+    }
+}
+
+impl ScreenView for ConfirmProtectedScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(c) => self.typed.push(c),
+            Action::DeleteChar => { self.typed.pop(); }
+            Action::Submit if self.typed.trim() == self.environment => {
+                tracing::warn!("Confirmed {} request(s) to protected environment '{}'", self.requests.len(), self.environment);
+                app.error_message = None;
+                // Only what runs now is confirmed, not later requests
+                app.protected_confirmed = true;
+                app.view = self.behind.take();
+                if let Some(then) = self.then.take() {
+                    then(app);
+                }
+                app.protected_confirmed = false;
+                return Transition::Close;
+            }
+            Action::Submit => {
+                app.error_message = Some(app.tr_with("confirm_protected.type_to_send", &[("environment", &self.environment)]));
+            }
+            Action::Back => {
+                app.error_message = None;
+                app.status_message = Some(app.tr_with("confirm_protected.cancelled", &[("environment", &self.environment)]));
+                app.view = self.behind.take();
+                return Transition::Close;
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let mut lines = vec![
+            Line::from(Span::styled(
+                app.tr_with("confirm_protected.warning", &[("environment", &self.environment)]),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        lines.extend(self.requests.iter().take(MAX_LISTED).map(|request| Line::from(Span::styled(
+            format!("  {}", request),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ))));
+        if self.requests.len() > MAX_LISTED {
+            lines.push(Line::from(app.tr_with("confirm_protected.more", &[("count", &(self.requests.len() - MAX_LISTED))])));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::raw(app.tr("confirm_protected.type")),
+            Span::styled(&self.environment, Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            Span::raw(app.tr("confirm_protected.to_send")),
+            Span::styled(&self.typed, Style::default().fg(Color::Yellow)),
+            Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(app.tr("confirm_protected.keys"), Style::default().fg(Color::DarkGray))));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr("confirm_protected.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Red)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+    use crate::variables::VariableSet;
+
+    #[test]
+    fn test_writes_to_protected_environment_need_its_name() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut prod = VariableSet::new("prod".to_string());
+        prod.set("host".to_string(), "http://127.0.0.1:9".to_string());
+        prod.protected = true;
+        app.variable_manager.save_environment(&prod).unwrap();
+        assert!(app.set_environment(Some("prod".to_string())));
+
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("list".to_string(), HttpMethod::GET, "{{host}}/users".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("drop".to_string(), HttpMethod::DELETE, "{{host}}/users".to_string()));
+        app.collections.push(collection);
+
+        // Reads go straight out
+        app.quick_execute_request(0, 0);
+        app.wait_for_requests();
+        assert!(app.view.is_none());
+        assert_eq!(app.history.entries().len(), 1);
+
+        app.quick_execute_request(0, 1);
+        let mut prompt = app.view.take().unwrap();
+        assert_eq!(app.history.entries().len(), 1, "nothing is sent before confirming");
+        for c in "Prod".chars() {
+            prompt.handle_action(&mut app, Action::InsertChar(c));
+        }
+        assert_eq!(prompt.handle_action(&mut app, Action::Submit), Transition::Stay);
+        assert_eq!(app.error_message.as_deref(), Some("Type 'prod' to send, or Esc to cancel"));
+        assert_eq!(prompt.handle_action(&mut app, Action::Back), Transition::Close);
+        assert_eq!(app.history.entries().len(), 1);
+
+        app.quick_execute_request(0, 1);
+        let mut prompt = app.view.take().unwrap();
+        for c in "prod".chars() {
+            prompt.handle_action(&mut app, Action::InsertChar(c));
+        }
+        assert_eq!(prompt.handle_action(&mut app, Action::Submit), Transition::Close);
+        app.wait_for_requests();
+        assert_eq!(app.history.entries().len(), 2);
+        assert!(!app.protected_confirmed);
+
+        // A collection run asks once for all of its writes
+        app.run_collection(0);
+        assert!(app.view.take().is_some());
+        assert_eq!(app.history.entries().len(), 2);
+    }
+}
@@ -0,0 +1,89 @@
+// Copy as curl screen
+// Shows the request an endpoint would send, with variables filled in and auth
+// applied, as a curl command. Tab switches between copying it to the clipboard
+// and saving it to a file.
+
+use super::{ScreenView, Transition};
+use crate::storage::expand_home;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+pub struct CopyCurlScreen {
+    command: String,
+    path: String,
+    to_file: bool,
+}
+
+impl CopyCurlScreen {
+    pub fn new(endpoint_name: &str, command: String) -> Self {
+        let stem: String = endpoint_name.chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        Self { command, path: format!("~/{}.sh", stem), to_file: false }
+    }
+}
+
+impl ScreenView for CopyCurlScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::NextField | Action::PrevField => self.to_file = !self.to_file,
+            Action::InsertChar(c) if self.to_file => self.path.push(c),
+            Action::DeleteChar if self.to_file => { self.path.pop(); }
+            Action::Submit => {
+                let done = if self.to_file {
+                    app.write_curl_command(&expand_home(&self.path), &self.command)
+                } else {
+                    app.copy_to_clipboard(self.command.clone(), app.tr("copy_curl.what"))
+                };
+                if done {
+                    return Transition::Close;
+                }
+            }
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let mut text: Vec<Line> = self.command.lines()
+            .map(|line| Line::from(Span::styled(line.to_string(), Style::default().fg(Color::Yellow))))
+            .collect();
+        text.push(Line::from(""));
+        let destination = if self.to_file {
+            vec![
+                Span::styled(app.tr("copy_curl.save_to"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(self.path.clone(), Style::default().fg(Color::Yellow)),
+                Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+            ]
+        } else {
+            vec![
+                Span::styled(app.tr("copy_curl.copy_to"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::raw(app.tr("copy_curl.clipboard")),
+            ]
+        };
+        text.push(Line::from(destination));
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(app.tr("copy_curl.keys"), Style::default().fg(Color::DarkGray))));
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default()
+                .title(app.tr("copy_curl.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    }
+}
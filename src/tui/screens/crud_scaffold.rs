@@ -0,0 +1,162 @@
+// CRUD scaffold screen
+// Type a resource's collection URL, e.g. `{{base_url}}/users`, and optionally
+// headers every endpoint should send, and add list, get, create, replace,
+// update and delete endpoints for it to the selected collection in one go.
+
+use super::{ScreenView, Transition};
+use crate::load_test;
+use crate::scaffold::Resource;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+pub struct CrudScaffoldScreen {
+    collection_index: usize,
+    url: String,
+    headers: String, // `Name: value; Other: value`, sent by every endpoint
+    editing_headers: bool,
+}
+
+impl CrudScaffoldScreen {
+    pub fn new(collection_index: usize) -> Self {
+        Self { collection_index, url: String::new(), headers: String::new(), editing_headers: false }
+    }
+
+    /// The endpoints that would be added, or why there are none
+    fn preview(&self, app: &AppState) -> Vec<Line<'static>> {
+        if self.url.trim().is_empty() {
+            return vec![Line::from(Span::styled(app.tr("crud_scaffold.type_url"), Style::default().fg(Color::DarkGray)))];
+        }
+        let Some(resource) = Resource::parse(&self.url) else {
+            return vec![Line::from(Span::styled(format!("  ✗ {}", app.tr("crud_scaffold.needs_path")), Style::default().fg(Color::Red)))];
+        };
+        let headers = match load_test::parse_headers(&self.headers) {
+            Ok(headers) => headers,
+            Err(e) => return vec![Line::from(Span::styled(format!("  ✗ {}", e), Style::default().fg(Color::Red)))],
+        };
+        resource.endpoints(&headers).into_iter()
+            .map(|endpoint| Line::from(vec![
+                Span::styled(format!("  {:<7} ", endpoint.method.to_string()), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{:<24} ", endpoint.name)),
+                Span::styled(endpoint.url, Style::default().fg(Color::DarkGray)),
+            ]))
+            .collect()
+    }
+
+    fn add(&self, app: &mut AppState) -> bool {
+        let Some(resource) = Resource::parse(&self.url) else {
+            app.error_message = Some(app.tr("crud_scaffold.needs_path").to_string());
+            return false;
+        };
+        match load_test::parse_headers(&self.headers) {
+            Ok(headers) => app.add_crud_endpoints(self.collection_index, &resource, &headers),
+            Err(e) => {
+                app.error_message = Some(e);
+                false
+            }
+        }
+    }
+}
+
+impl ScreenView for CrudScaffoldScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let field = if self.editing_headers { &mut self.headers } else { &mut self.url };
+        match action {
+            Action::InsertChar(c) => field.push(c),
+            Action::DeleteChar => { field.pop(); }
+            Action::NextField | Action::PrevField | Action::Up | Action::Down => self.editing_headers = !self.editing_headers,
+            // Stay open on errors so the URL or headers can be fixed
+            Action::Submit if self.add(app) => return Transition::Close,
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let name = app.collections.get(self.collection_index).map(|c| c.name.as_str()).unwrap_or_default();
+        let field = |label: &str, value: &str, active: bool| {
+            let (style, cursor) = if active {
+                (Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD), "_")
+            } else {
+                (Style::default().fg(Color::White), "")
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<16}", label), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{}{}", value, cursor), style),
+            ])
+        };
+        let mut text = vec![
+            Line::from(app.tr_with("crud_scaffold.intro", &[("name", &name)])),
+            Line::from(""),
+            field(app.tr("crud_scaffold.resource_url"), &self.url, !self.editing_headers),
+            field(app.tr("crud_scaffold.shared_headers"), &self.headers, self.editing_headers),
+            Line::from(""),
+            Line::from(Span::styled(app.tr("crud_scaffold.preview"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+        ];
+        text.extend(self.preview(app));
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(
+            app.tr("crud_scaffold.hint"),
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default()
+                .title(app.tr("crud_scaffold.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui::harness::Harness;
+
+    #[test]
+    fn test_scaffold_a_resource() {
+        let mut collection = ApiCollection::new("Shop".to_string());
+        collection.add_endpoint(ApiEndpoint::new("users".to_string(), HttpMethod::GET, "{{base_url}}/users/".to_string()));
+        let mut harness = Harness::new().with_collection(collection);
+
+        harness.keys(&["Tab", "Ctrl+n"]).type_text("{{base_url}}/users");
+        let screen = harness.render();
+        assert!(screen.contains("New CRUD Endpoints"), "{}", screen);
+        assert!(screen.contains("PATCH   Update user"), "{}", screen);
+        assert!(screen.contains("{{base_url}}/users/{{user_id}}"), "{}", screen);
+
+        harness.press("Tab").type_text("X-Tenant acme");
+        harness.press("Enter");
+        assert!(harness.app.error_message.as_deref().unwrap().contains("Name: value"));
+        for _ in 0.." acme".len() {
+            harness.press("Backspace");
+        }
+        harness.type_text(": acme").press("Enter");
+        assert!(harness.app.view.is_none());
+        assert_eq!(harness.app.status_message.as_deref(), Some("Added 5 endpoints for users (1 already there) to 'Shop'"));
+
+        let saved = harness.app.storage.load_collections().unwrap();
+        let names: Vec<&str> = saved[0].endpoints.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["users", "Get user", "Create user", "Replace user", "Update user", "Delete user"]);
+        assert!(saved[0].endpoints[1..].iter().all(|e| e.headers.get("X-Tenant").map(String::as_str) == Some("acme")));
+
+        // A second time there's nothing left to add
+        harness.keys(&["Ctrl+n"]).type_text("{{base_url}}/users").press("Enter");
+        assert!(harness.app.view.is_some());
+        assert_eq!(harness.app.error_message.as_deref(), Some("'Shop' already has every endpoint for users"));
+    }
+}
@@ -0,0 +1,161 @@
+// Import from curl screen
+// Paste a curl command (e.g. from a browser's "Copy as cURL") and see the
+// endpoint it becomes before adding it to the selected collection. Enter after
+// a trailing `\` or inside an open quote starts a new line, so multi-line
+// commands can be pasted as they are.
+
+use super::{ScreenView, Transition};
+use crate::interop::curl;
+use crate::models::AuthConfig;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Body lines shown in the preview
+const BODY_PREVIEW_LINES: usize = 5;
+
+pub struct CurlImportScreen {
+    collection_index: usize,
+    command: String,
+}
+
+impl CurlImportScreen {
+    pub fn new(collection_index: usize) -> Self {
+        Self { collection_index, command: String::new() }
+    }
+
+    /// What the command turns into, or why it can't be imported
+    fn preview(&self, app: &AppState) -> Vec<Line<'static>> {
+        let label = |text: &str| Span::styled(format!("  {:<9}", text), Style::default().fg(Color::Cyan));
+        if self.command.trim().is_empty() {
+            return vec![Line::from(Span::styled(app.tr("curl_import.paste_to_preview"), Style::default().fg(Color::DarkGray)))];
+        }
+        let endpoint = match curl::parse(&self.command) {
+            Ok(endpoint) => endpoint,
+            Err(e) => return vec![Line::from(Span::styled(format!("  ✗ {}", e), Style::default().fg(Color::Red)))],
+        };
+        let mut lines = vec![Line::from(vec![
+            Span::styled(format!("  {} ", endpoint.method), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::raw(endpoint.url.clone()),
+        ])];
+        let mut headers: Vec<_> = endpoint.headers.iter().collect();
+        headers.sort();
+        for (name, value) in headers {
+            lines.push(Line::from(vec![label(app.tr("curl_import.header")), Span::raw(format!("{}: {}", name, value))]));
+        }
+        let auth = match &endpoint.auth {
+            Some(AuthConfig::Basic { username, .. }) => Some(app.tr_with("curl_import.basic", &[("username", username)])),
+            Some(AuthConfig::Bearer { .. }) => Some(app.tr("curl_import.bearer").to_string()),
+            Some(AuthConfig::ApiKey { name, .. }) => Some(app.tr_with("curl_import.api_key", &[("name", name)])),
+            None => None,
+        };
+        if let Some(auth) = auth {
+            lines.push(Line::from(vec![label(app.tr("curl_import.auth")), Span::raw(auth)]));
+        }
+        if let Some(timeout) = endpoint.timeout_secs {
+            lines.push(Line::from(vec![label(app.tr("curl_import.timeout")), Span::raw(format!("{}s", timeout))]));
+        }
+        if let Some(body) = &endpoint.body_template {
+            for (i, line) in body.lines().take(BODY_PREVIEW_LINES).enumerate() {
+                lines.push(Line::from(vec![label(if i == 0 { app.tr("curl_import.body") } else { "" }), Span::raw(line.to_string())]));
+            }
+            let more = body.lines().count().saturating_sub(BODY_PREVIEW_LINES);
+            if more > 0 {
+                lines.push(Line::from(vec![label(""), Span::styled(app.tr_with("curl_import.more_lines", &[("count", &more)]), Style::default().fg(Color::DarkGray))]));
+            }
+        }
+        lines
+    }
+}
+
+impl ScreenView for CurlImportScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(c) => self.command.push(c),
+            Action::DeleteChar => { self.command.pop(); }
+            Action::Submit if curl::is_incomplete(&self.command) => self.command.push('\n'),
+            Action::Submit if app.import_curl(self.collection_index, &self.command) => return Transition::Close,
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let name = app.collections.get(self.collection_index).map(|c| c.name.as_str()).unwrap_or_default();
+        let mut text = vec![
+            Line::from(app.tr_with("curl_import.intro", &[("name", &name)])),
+            Line::from(""),
+        ];
+        let mut command_lines: Vec<&str> = self.command.split('\n').collect();
+        let last = command_lines.pop().unwrap_or_default();
+        for line in command_lines {
+            text.push(Line::from(Span::styled(line.to_string(), Style::default().fg(Color::Yellow))));
+        }
+        text.push(Line::from(vec![
+            Span::styled(last.to_string(), Style::default().fg(Color::Yellow)),
+            Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(app.tr("crud_scaffold.preview"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))));
+        text.extend(self.preview(app));
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(app.tr("curl_import.keys"), Style::default().fg(Color::DarkGray))));
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default()
+                .title(app.tr("curl_import.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, HttpMethod};
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_multi_line_paste_imports_on_last_enter() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        app.collections.push(ApiCollection::new("A".to_string()));
+
+        let mut screen = CurlImportScreen::new(0);
+        let paste = |screen: &mut CurlImportScreen, app: &mut AppState, text: &str| {
+            let mut transition = Transition::Stay;
+            for c in text.chars() {
+                transition = screen.handle_action(app, if c == '\n' { Action::Submit } else { Action::InsertChar(c) });
+            }
+            transition
+        };
+        assert_eq!(paste(&mut screen, &mut app, "curl -X POST https://example.com/users \\\n  -d '{\n\"name\": \"bob\"}'"), Transition::Stay);
+        assert_eq!(screen.command.lines().count(), 3);
+        assert!(screen.preview(&app)[0].to_string().contains("POST https://example.com/users"));
+        assert_eq!(paste(&mut screen, &mut app, "\n"), Transition::Close);
+
+        let endpoint = &app.collections[0].endpoints[0];
+        assert_eq!(endpoint.method, HttpMethod::POST);
+        assert_eq!(endpoint.body_template.as_deref(), Some("{\n\"name\": \"bob\"}"));
+        assert_eq!(app.status_message.as_deref(), Some("Added 'POST /users' to 'A'"));
+
+        let mut screen = CurlImportScreen::new(0);
+        paste(&mut screen, &mut app, "curl -F a=@b https://example.com");
+        assert_eq!(paste(&mut screen, &mut app, "\n"), Transition::Stay);
+        assert!(app.error_message.as_deref().unwrap().starts_with("Failed to parse curl command"));
+    }
+}
@@ -0,0 +1,210 @@
+// Startup diagnostics screen
+// Lists collection files that failed to load, instead of skipping them
+// silently. Each one can be viewed as raw JSON, partially recovered (broken
+// endpoints dropped) or moved to the quarantine directory.
+
+use super::{ScreenView, Transition};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Raw contents of the selected file, shown instead of the list
+struct RawView {
+    lines: Vec<String>,
+    error_line: Option<usize>, // 1-based, from the parse error
+    scroll: usize,
+}
+
+#[derive(Default)]
+pub struct DiagnosticsScreen {
+    selected: usize,
+    raw: Option<RawView>,
+}
+
+impl DiagnosticsScreen {
+    fn open_raw(&mut self, app: &mut AppState) {
+        let Some(skipped) = app.skipped_collections.get(self.selected) else { return };
+        match std::fs::read(&skipped.path) {
+            Ok(bytes) => {
+                let lines: Vec<String> = String::from_utf8_lossy(&bytes).lines().map(str::to_string).collect();
+                let error_line = error_line(&skipped.error);
+                // Open a few lines above where parsing failed
+                let scroll = error_line.map(|line| line.saturating_sub(4)).unwrap_or(0);
+                self.raw = Some(RawView { lines, error_line, scroll });
+            }
+            Err(e) => app.error_message = Some(app.tr_with("error.read_file", &[("path", &skipped.path.display()), ("error", &e)])),
+        }
+    }
+
+    /// Called after the selected entry went away
+    fn after_removal(&mut self, app: &AppState) -> Transition {
+        if app.skipped_collections.is_empty() {
+            return Transition::Close;
+        }
+        self.selected = self.selected.min(app.skipped_collections.len() - 1);
+        Transition::Stay
+    }
+}
+
+/// `expected value at line 3 column 5` → 3
+fn error_line(error: &str) -> Option<usize> {
+    let (_, rest) = error.rsplit_once(" at line ")?;
+    rest.split_whitespace().next()?.parse().ok()
+}
+
+impl ScreenView for DiagnosticsScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        if let Some(raw) = &mut self.raw {
+            match action {
+                Action::Up => raw.scroll = raw.scroll.saturating_sub(1),
+                Action::Down => raw.scroll = (raw.scroll + 1).min(raw.lines.len().saturating_sub(1)),
+                Action::InsertChar('v') | Action::Submit | Action::Back => self.raw = None,
+                _ => {}
+            }
+            return Transition::Stay;
+        }
+
+        let count = app.skipped_collections.len();
+        match action {
+            Action::Up => self.selected = self.selected.saturating_sub(1),
+            Action::Down => self.selected = (self.selected + 1).min(count.saturating_sub(1)),
+            Action::InsertChar('v') | Action::Submit => self.open_raw(app),
+            Action::InsertChar('r') => {
+                app.recover_skipped(self.selected);
+                if app.skipped_collections.len() < count {
+                    return self.after_removal(app);
+                }
+            }
+            Action::InsertChar('m') => {
+                app.quarantine_skipped(self.selected);
+                if app.skipped_collections.len() < count {
+                    return self.after_removal(app);
+                }
+            }
+            Action::InsertChar('q') | Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let block = |title: String, color: Color| Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(color));
+
+        if let Some(raw) = &self.raw {
+            let name = app.skipped_collections.get(self.selected)
+                .map(|s| s.path.display().to_string())
+                .unwrap_or_default();
+            let lines: Vec<Line> = raw.lines.iter().enumerate().skip(raw.scroll).map(|(i, line)| {
+                let style = if Some(i + 1) == raw.error_line {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:>5} ", i + 1), Style::default().fg(Color::DarkGray)),
+                    Span::styled(line.as_str(), style),
+                ])
+            }).collect();
+            let paragraph = Paragraph::new(lines)
+                .block(block(app.tr_with("diagnostics.raw_title", &[("name", &name)]), Color::Cyan));
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(5), Constraint::Length(8)])
+            .split(area);
+
+        let lines: Vec<Line> = app.skipped_collections.iter().enumerate().map(|(i, skipped)| {
+            let style = if i == self.selected {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let name = skipped.path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            Line::from(vec![
+                Span::styled(if i == self.selected { " ▶ " } else { "   " }, style),
+                Span::styled(name, style),
+            ])
+        }).collect();
+        let visible = chunks[0].height.saturating_sub(2) as usize;
+        let scroll = self.selected.saturating_sub(visible.saturating_sub(1)) as u16;
+        let list = Paragraph::new(lines)
+            .scroll((scroll, 0))
+            .block(block(app.tr_with("diagnostics.title", &[("count", &app.skipped_collections.len())]), Color::Red));
+        f.render_widget(list, chunks[0]);
+
+        let mut details = Vec::new();
+        if let Some(skipped) = app.skipped_collections.get(self.selected) {
+            details.push(Line::from(Span::styled(skipped.path.display().to_string(), Style::default().fg(Color::Cyan))));
+            details.push(Line::from(Span::styled(skipped.error.as_str(), Style::default().fg(Color::Red))));
+            details.push(Line::from(""));
+        }
+        details.push(Line::from(app.tr_with("diagnostics.keys", &[("dir", &app.storage.quarantine_dir().display())])));
+        let paragraph = Paragraph::new(details)
+            .wrap(Wrap { trim: false })
+            .block(block(app.tr("diagnostics.details").to_string(), Color::Cyan));
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::HistoryStore;
+    use crate::models::ApiCollection;
+    use crate::storage::StorageManager;
+    use crate::variables::VariableManager;
+
+    #[test]
+    fn test_skipped_files_open_diagnostics() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let collections_dir = temp_dir.path().join("collections");
+        let storage = StorageManager::new(collections_dir.clone(), temp_dir.path().join("results")).unwrap();
+        let collection = ApiCollection::new("Half written".to_string());
+        let json = serde_json::to_string_pretty(&collection).unwrap().replace("\"endpoints\": []", "\"endpoints\": {}");
+        std::fs::write(collections_dir.join("a.json"), json).unwrap();
+        std::fs::write(collections_dir.join("b.json"), "{\n  \"name\": \"Truncated\",\n  \"endpoints\": [").unwrap();
+
+        let variables = VariableManager::with_path(temp_dir.path().join("variables.json")).unwrap();
+        let history = HistoryStore::with_path(temp_dir.path().join("history.jsonl")).unwrap();
+        let mut app = AppState::with_storage(storage, variables, history).unwrap();
+        assert_eq!(app.skipped_collections.len(), 2);
+        assert!(app.view.take().is_some());
+        let mut screen = DiagnosticsScreen::default();
+
+        // The raw view opens near the line that failed to parse
+        screen.handle_action(&mut app, Action::Down);
+        screen.handle_action(&mut app, Action::InsertChar('v'));
+        assert_eq!(screen.raw.as_ref().unwrap().error_line, Some(3));
+        screen.handle_action(&mut app, Action::Back);
+        assert!(screen.raw.is_none());
+
+        // Truncated JSON can't be recovered, only moved away
+        screen.handle_action(&mut app, Action::InsertChar('r'));
+        assert!(app.error_message.as_deref().unwrap().starts_with("Nothing to recover"));
+        assert_eq!(screen.handle_action(&mut app, Action::InsertChar('m')), Transition::Stay);
+        assert!(app.storage.quarantine_dir().join("b.json").exists());
+
+        assert_eq!(screen.handle_action(&mut app, Action::InsertChar('r')), Transition::Close);
+        assert_eq!(app.collections.len(), 1);
+        assert_eq!(app.collections[0].name, "Half written");
+        assert!(app.status_message.as_deref().unwrap().starts_with("Recovered 'Half written' with 0 endpoints"));
+        assert_eq!(app.storage.load_collections_reporting().unwrap().1, Vec::new());
+    }
+}
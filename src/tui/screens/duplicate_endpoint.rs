@@ -0,0 +1,125 @@
+// Duplicate endpoint warning
+// Shown when a saved endpoint sends the same method to the same URL as another
+// endpoint of its collection. The form stays open unless the user saves anyway
+// or jumps to the endpoint that already exists.
+
+use super::{ScreenView, Transition};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+pub struct DuplicateEndpointScreen {
+    collection_index: usize,
+    existing_index: usize,
+}
+
+impl DuplicateEndpointScreen {
+    pub fn new(collection_index: usize, existing_index: usize) -> Self {
+        Self { collection_index, existing_index }
+    }
+}
+
+impl ScreenView for DuplicateEndpointScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar('s') | Action::InsertChar('S') => {
+                app.save_endpoint_anyway();
+                Transition::Close
+            }
+            Action::InsertChar('j') | Action::InsertChar('J') => {
+                app.jump_to_endpoint(self.collection_index, self.existing_index);
+                Transition::Close
+            }
+            Action::InsertChar('n') | Action::InsertChar('N') | Action::Back => Transition::Close,
+            _ => Transition::Stay,
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let existing = app.collections.get(self.collection_index)
+            .and_then(|c| c.endpoints.get(self.existing_index));
+        let mut lines = vec![
+            Line::from(Span::styled(
+                app.tr("duplicate_endpoint.warning"),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        if let Some(endpoint) = existing {
+            lines.push(Line::from(Span::styled(
+                format!("  {} {} {}", endpoint.name, endpoint.method, endpoint.url),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(app.tr("duplicate_endpoint.keys")));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr("duplicate_endpoint.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Yellow)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui::app::{PanelFocus, Screen};
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_duplicate_save_asks_first() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("other".to_string(), HttpMethod::POST, "https://api/users".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("users".to_string(), HttpMethod::GET, "https://api/users".to_string()));
+        app.collections.push(collection);
+
+        let start_duplicate = |app: &mut AppState| {
+            app.start_new_endpoint(0);
+            let form = app.endpoint_form.as_mut().unwrap();
+            form.name = "list users".to_string();
+            form.url = "https://api/users/".to_string();
+            app.save_endpoint();
+            app.view.take().expect("duplicate warning")
+        };
+
+        // Keep editing: nothing saved, form still open
+        let mut warning = start_duplicate(&mut app);
+        assert_eq!(warning.handle_action(&mut app, Action::Back), Transition::Close);
+        assert_eq!(app.collections[0].endpoints.len(), 2);
+        assert!(app.endpoint_form.is_some());
+
+        // Jump to the existing endpoint, dropping the form
+        let mut warning = start_duplicate(&mut app);
+        assert_eq!(warning.handle_action(&mut app, Action::InsertChar('j')), Transition::Close);
+        assert!(app.endpoint_form.is_none());
+        assert_eq!((app.selection.collection, app.selection.endpoint), (0, 1));
+        assert_eq!((app.current_screen.clone(), app.panel_focus), (Screen::CollectionList, PanelFocus::Endpoints));
+
+        let mut warning = start_duplicate(&mut app);
+        assert_eq!(warning.handle_action(&mut app, Action::InsertChar('s')), Transition::Close);
+        assert_eq!(app.collections[0].endpoints.len(), 3);
+
+        // Editing an endpoint doesn't count it as its own duplicate
+        app.start_edit_endpoint(0, 0);
+        app.save_endpoint();
+        assert!(app.view.is_none());
+        assert!(app.endpoint_form.is_none());
+    }
+}
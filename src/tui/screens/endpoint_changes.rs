@@ -0,0 +1,149 @@
+// Endpoint changelog viewer
+// The selected endpoint's recorded edits, newest first: when, who, which field
+// and what it went from and to. `a` widens it to every endpoint of the
+// collection, deleted ones included.
+
+use super::{ScreenView, Transition};
+use crate::changelog::EndpointChange;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+use uuid::Uuid;
+
+pub struct EndpointChangesScreen {
+    collection_index: usize,
+    endpoint_id: Uuid,
+    all: bool,
+    scroll: u16,
+}
+
+impl EndpointChangesScreen {
+    pub fn new(collection_index: usize, endpoint_id: Uuid) -> Self {
+        Self { collection_index, endpoint_id, all: false, scroll: 0 }
+    }
+
+    /// The changes shown, newest first
+    fn changes<'a>(&self, app: &'a AppState) -> Vec<&'a EndpointChange> {
+        let Some(collection) = app.collections.get(self.collection_index) else { return Vec::new() };
+        collection.changelog.iter()
+            .rev()
+            .filter(|change| self.all || change.endpoint_id == self.endpoint_id)
+            .collect()
+    }
+}
+
+/// Empty values read as "(none)" so an added or removed field is clear
+fn value(text: &str, app: &AppState) -> Span<'static> {
+    if text.is_empty() {
+        Span::styled(app.tr("detail.none"), Style::default().fg(Color::DarkGray))
+    } else {
+        Span::raw(text.replace('\n', "⏎"))
+    }
+}
+
+impl ScreenView for EndpointChangesScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, _app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.scroll = self.scroll.saturating_sub(1),
+            Action::Down => self.scroll = self.scroll.saturating_add(1),
+            Action::InsertChar('a') => {
+                self.all = !self.all;
+                self.scroll = 0;
+            }
+            Action::InsertChar('q') | Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(collection) = app.collections.get(self.collection_index) else { return };
+        let dim = Style::default().fg(Color::DarkGray);
+        let changes = self.changes(app);
+        let subject = match collection.endpoints.iter().find(|e| e.id == self.endpoint_id) {
+            _ if self.all => app.tr_with("endpoint_changes.every_endpoint", &[("name", &collection.name)]),
+            Some(endpoint) => endpoint.name.clone(),
+            None => app.tr("endpoint_changes.deleted").to_string(),
+        };
+
+        let mut lines = Vec::new();
+        if changes.is_empty() {
+            lines.push(Line::from(Span::styled(app.tr("endpoint_changes.none"), dim)));
+        }
+        for change in &changes {
+            let when = change.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S");
+            let mut header = vec![
+                Span::styled(format!("{}  ", when), dim),
+                Span::styled(change.author.clone(), Style::default().fg(Color::Magenta)),
+                Span::raw("  "),
+            ];
+            if self.all {
+                header.push(Span::styled(format!("{} · ", change.endpoint), Style::default().fg(Color::White)));
+            }
+            header.push(Span::styled(change.field.clone(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+            lines.push(Line::from(header));
+            lines.push(Line::from(vec![Span::raw("    "), value(&change.old, app), Span::styled(" → ", Style::default().fg(Color::Yellow)), value(&change.new, app)]));
+        }
+
+        let max_scroll = (lines.len() as u16).saturating_sub(area.height.saturating_sub(2));
+        let scope = if self.all { app.tr("endpoint_changes.this_endpoint") } else { app.tr("endpoint_changes.all_endpoints") };
+        let paragraph = Paragraph::new(lines)
+            .scroll((self.scroll.min(max_scroll), 0))
+            .block(Block::default()
+                .title(app.tr_with("endpoint_changes.title", &[("subject", &subject), ("count", &changes.len()), ("scope", &scope)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Magenta)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::changelog;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui::harness::Harness;
+
+    #[test]
+    fn test_url_change_shows_in_changelog() {
+        let mut collection = ApiCollection::new("Shop".to_string());
+        collection.add_endpoint(ApiEndpoint::new("Orders".to_string(), HttpMethod::GET, "https://api.example.com/orders".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("Users".to_string(), HttpMethod::GET, "https://api.example.com/users".to_string()));
+        let mut harness = Harness::new().with_collection(collection);
+
+        harness.press("Tab").press("h");
+        assert!(harness.render().contains("No changes recorded yet"));
+
+        // Point the URL at staging with the quick editor
+        harness.press("Esc").press("u").press("Enter");
+        for _ in 0.."https://api.example.com/orders".len() {
+            harness.press("Backspace");
+        }
+        harness.type_text("https://staging.example.com/orders").press("Enter");
+
+        harness.press("h");
+        let screen = harness.render();
+        assert!(screen.contains("Changes: Orders (1)"), "{}", screen);
+        assert!(screen.contains(&changelog::author()), "{}", screen);
+        assert!(screen.contains("url"), "{}", screen);
+        assert!(screen.contains("https://api.example.com/orders → https://staging.example.com/orders"), "{}", screen);
+
+        // The history is saved with the collection
+        let saved = harness.app.storage.load_collections().unwrap();
+        assert_eq!(saved[0].changelog.len(), 1);
+        assert_eq!(saved[0].changelog[0].field, "url");
+
+        harness.press("a");
+        assert!(harness.render().contains("every endpoint of Shop (1)"));
+    }
+}
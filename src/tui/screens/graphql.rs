@@ -0,0 +1,209 @@
+// GraphQL editor
+// Edits an endpoint's GraphQL query and its JSON variables, with the query
+// highlighted. On send they are wrapped into one `{"query", "variables"}`
+// payload. Esc saves; an empty query turns GraphQL mode off again.
+
+use super::{ScreenView, Transition};
+use crate::formatter;
+use crate::models::GraphqlBody;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+const KEYWORDS: &[&str] = &["query", "mutation", "subscription", "fragment", "on", "true", "false", "null"];
+
+/// Color one line of a GraphQL document: keywords, `$variables`,
+/// `@directives`, strings and `#` comments
+pub fn highlight_graphql(line: &str) -> Line<'static> {
+    let mut spans = Vec::new();
+    let chars: Vec<char> = line.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+        let style = if c == '#' {
+            i = chars.len();
+            Style::default().fg(Color::DarkGray)
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(chars.len());
+            Style::default().fg(Color::Green)
+        } else if c == '$' || c == '@' || c.is_alphanumeric() || c == '_' {
+            i += 1;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            match c {
+                '$' => Style::default().fg(Color::Yellow),
+                '@' => Style::default().fg(Color::Cyan),
+                _ if KEYWORDS.contains(&word.as_str()) => Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                _ => Style::default(),
+            }
+        } else {
+            i += 1;
+            while i < chars.len() && !(chars[i] == '#' || chars[i] == '"' || chars[i] == '$' || chars[i] == '@' || chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::styled(chars[start..i].iter().collect::<String>(), style));
+    }
+    Line::from(spans)
+}
+
+pub struct GraphqlEditorScreen {
+    collection_index: usize,
+    endpoint_index: usize,
+    query: String,
+    variables: String,
+    field: usize, // 0=query, 1=variables
+}
+
+impl GraphqlEditorScreen {
+    pub fn new(app: &AppState, collection_index: usize, endpoint_index: usize) -> Self {
+        let graphql = app.collections.get(collection_index)
+            .and_then(|c| c.endpoints.get(endpoint_index))
+            .and_then(|e| e.graphql.clone())
+            .unwrap_or_default();
+        Self { collection_index, endpoint_index, query: graphql.query, variables: graphql.variables, field: 0 }
+    }
+
+    fn current(&mut self) -> &mut String {
+        if self.field == 0 { &mut self.query } else { &mut self.variables }
+    }
+
+    fn draw_field(&self, f: &mut Frame, area: Rect, field: usize, app: &AppState) {
+        let (title, text) = match field {
+            0 => (app.tr("graphql.query"), &self.query),
+            _ => (app.tr("graphql.variables"), &self.variables),
+        };
+        let focused = field == self.field;
+        let mut lines: Vec<Line> = text.split('\n')
+            .map(|line| if field == 0 { highlight_graphql(line) } else { Line::from(line.to_string()) })
+            .collect();
+        if focused {
+            if let Some(last) = lines.last_mut() {
+                last.spans.push(Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)));
+            }
+        }
+        // Keep the last line, where typing happens, in view
+        let scroll = (lines.len() as u16).saturating_sub(area.height.saturating_sub(2));
+        let paragraph = Paragraph::new(lines)
+            .scroll((scroll, 0))
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(if focused { Color::Cyan } else { Color::DarkGray })));
+        f.render_widget(paragraph, area);
+    }
+}
+
+impl ScreenView for GraphqlEditorScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(c) => self.current().push(c),
+            Action::DeleteChar => { self.current().pop(); }
+            Action::Submit => self.current().push('\n'),
+            Action::NextField | Action::PrevField => self.field = 1 - self.field,
+            Action::FormatBody => match formatter::format_json_template(&self.variables) {
+                Ok(formatted) => self.variables = formatted,
+                Err(e) => app.error_message = Some(app.tr_with("graphql.variables_error", &[("error", &e)])),
+            },
+            Action::Back => {
+                let graphql = GraphqlBody { query: self.query.trim_end().to_string(), variables: self.variables.trim().to_string() };
+                if app.set_endpoint_graphql(self.collection_index, self.endpoint_index, graphql) {
+                    return Transition::Close;
+                }
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let name = app.collections.get(self.collection_index)
+            .and_then(|c| c.endpoints.get(self.endpoint_index))
+            .map(|e| e.name.as_str())
+            .unwrap_or_default();
+        let block = Block::default()
+            .title(app.tr_with("graphql.title", &[("name", &name)]))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Magenta));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(inner);
+        self.draw_field(f, chunks[0], 0, app);
+        self.draw_field(f, chunks[1], 1, app);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_highlight_graphql() {
+        let line = highlight_graphql("query Q($id: ID!) { user(id: $id) @cached { name } } # \"x\"");
+        let styled = |text: &str| line.spans.iter().find(|s| s.content == text).map(|s| s.style.fg);
+        assert_eq!(styled("query"), Some(Some(Color::Magenta)));
+        assert_eq!(styled("$id"), Some(Some(Color::Yellow)));
+        assert_eq!(styled("@cached"), Some(Some(Color::Cyan)));
+        assert_eq!(styled("user"), Some(None));
+        assert_eq!(styled("# \"x\""), Some(Some(Color::DarkGray)));
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "query Q($id: ID!) { user(id: $id) @cached { name } } # \"x\"");
+    }
+
+    #[test]
+    fn test_save_turns_graphql_mode_on_and_off() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("users".to_string(), HttpMethod::GET, "https://api/graphql".to_string()));
+        app.collections.push(collection);
+
+        let mut screen = GraphqlEditorScreen::new(&app, 0, 0);
+        for c in "{ users { id } }".chars() {
+            screen.handle_action(&mut app, Action::InsertChar(c));
+        }
+        screen.handle_action(&mut app, Action::NextField);
+        for c in "{\"first\": ".chars() {
+            screen.handle_action(&mut app, Action::InsertChar(c));
+        }
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Stay);
+        assert!(app.error_message.as_deref().unwrap().contains("variables"));
+        for c in "{{limit}}}".chars() {
+            screen.handle_action(&mut app, Action::InsertChar(c));
+        }
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Close);
+        let endpoint = &app.storage.load_collections().unwrap()[0].endpoints[0];
+        assert_eq!(endpoint.method, HttpMethod::POST);
+        assert_eq!(endpoint.graphql, Some(GraphqlBody { query: "{ users { id } }".to_string(), variables: "{\"first\": {{limit}}}".to_string() }));
+
+        let mut screen = GraphqlEditorScreen::new(&app, 0, 0);
+        screen.query.clear();
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Close);
+        assert_eq!(app.collections[0].endpoints[0].graphql, None);
+    }
+}
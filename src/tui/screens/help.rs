@@ -0,0 +1,156 @@
+// Help screen
+// Keyboard reference; scrolls so it stays readable on small terminals.
+
+use super::{ScreenView, Transition};
+use crate::tui::app::AppState;
+use crate::tui::input::Action;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+#[derive(Debug, Default)]
+pub struct HelpScreen {
+    scroll: u16,
+}
+
+impl ScreenView for HelpScreen {
+    fn handle_action(&mut self, _app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.scroll = self.scroll.saturating_sub(1),
+            Action::Down => self.scroll = self.scroll.saturating_add(1),
+            Action::ScrollUp => self.scroll = self.scroll.saturating_sub(10),
+            Action::ScrollDown => self.scroll = self.scroll.saturating_add(10),
+            Action::ScrollTop => self.scroll = 0,
+            // Any other key closes help
+            _ => return Transition::Close,
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let tr = |key| app.tr(key);
+        let heading = |key| Line::from(vec![Span::styled(tr(key), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]);
+        let entry = |keys: &str, key| Line::from(format!("  {:<10} - {}", keys, tr(key)));
+        let note = |key| Line::from(format!("             {}", tr(key)));
+        let help_text = vec![
+            Line::from(vec![Span::styled(tr("help.title"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
+            Line::from(""),
+            heading("help.navigation"),
+            entry("↑/k", "help.move_up"),
+            entry("↓/j", "help.move_down"),
+            entry("Enter", "help.select"),
+            entry("Esc", "help.back"),
+            entry("q", "help.quit"),
+            Line::from(""),
+            heading("help.collections"),
+            entry("n", "help.new"),
+            entry("e", "help.edit"),
+            entry("d", "help.delete"),
+            entry("c", "help.copy_collection"),
+            entry("M", "help.merge"),
+            entry("U", "help.sync"),
+            entry("r", "help.notes"),
+            entry("S", "help.settings"),
+            entry("p", "help.proxy"),
+            entry("L", "help.log"),
+            entry("W", "help.timeline"),
+            entry("B", "help.past_results"),
+            entry("I", "help.import"),
+            entry("i", "help.import_curl"),
+            entry("Ctrl+N", "help.scaffold_crud"),
+            entry("X", "help.export"),
+            entry("O", "help.export_openapi"),
+            Line::from(""),
+            heading("help.endpoint_actions"),
+            entry("e", "help.execute"),
+            entry("u", "help.quick_edit"),
+            entry("h", "help.endpoint_changes"),
+            entry("Q", "help.graphql"),
+            entry("x", "help.quick_execute"),
+            entry("o", "help.send_with_overrides"),
+            note("help.websocket"),
+            entry("l", "help.load_test"),
+            entry("K", "help.load_test_scenario"),
+            entry("s", "help.statistics"),
+            entry("#", "help.tag_filter"),
+            entry("R", "help.run"),
+            entry("m", "help.mark"),
+            entry("P", "help.parallel"),
+            entry("|", "help.side_by_side"),
+            entry("C", "help.compare"),
+            entry("Y", "help.copy_curl"),
+            entry("Ctrl+F", "help.search_history"),
+            entry("Ctrl+E", "help.switch_environment"),
+            entry("a", "help.archive"),
+            entry("A", "help.archived_view"),
+            Line::from(""),
+            heading("help.variables"),
+            entry("v", "help.variable_list"),
+            entry("n", "help.variable_new"),
+            entry("e", "help.variable_edit"),
+            entry("d", "help.variable_delete"),
+            entry("I / X", "help.variable_import"),
+            Line::from(""),
+            heading("help.view"),
+            entry("t", "help.traffic"),
+            entry("H", "help.headers"),
+            entry("Space", "help.collapse"),
+            entry("w", "help.wrap"),
+            entry("N", "help.line_numbers"),
+            entry("D", "help.dates"),
+            entry(",", "help.separators"),
+            entry("F", "help.view_as"),
+            entry("V", "help.visualizer"),
+            entry("T", "help.table"),
+            entry("b", "help.response_links"),
+            entry(":123", "help.goto_line"),
+            entry("/text", "help.search"),
+            entry("E", "help.expand"),
+            entry("gg / G", "help.top_bottom"),
+            entry("Ctrl+D/U", "help.half_page"),
+            Line::from(""),
+            heading("help.clipboard"),
+            entry("y", "help.copy_response"),
+            Line::from(""),
+            heading("help.scrolling"),
+            entry("PgUp/PgDn", "help.scroll_body"),
+            entry("Shift+PgUp/PgDn", "help.scroll_headers"),
+            entry("Home", "help.top"),
+            entry("Shift+Home", "help.top_headers"),
+            entry("End", "help.bottom"),
+            entry("←/→", "help.sideways"),
+            Line::from(""),
+            heading("help.form"),
+            entry("Tab", "help.next_field"),
+            entry("m", "help.cycle_method"),
+            entry("j/x/f/t", "help.presets"),
+            entry("Ctrl+F", "help.format_json"),
+            entry("Backspace", "help.delete_char"),
+            entry("Enter", "help.save"),
+            Line::from(""),
+            heading("help.other"),
+            entry("?", "help.help"),
+            entry("Ctrl+R a", "help.record_macro"),
+            entry("@a / @@", "help.play_macro"),
+            Line::from(""),
+            Line::from(vec![Span::styled(tr("help.footer"), Style::default().fg(Color::DarkGray))]),
+        ];
+
+        // Clamp here like the response panel does, since only drawing knows the height
+        let max_scroll = (help_text.len() as u16).saturating_sub(area.height.saturating_sub(2));
+        let paragraph = Paragraph::new(help_text)
+            .scroll((self.scroll.min(max_scroll), 0))
+            .block(Block::default()
+                .title(tr("help.window"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Yellow)))
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(paragraph, area);
+    }
+}
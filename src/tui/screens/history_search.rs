@@ -0,0 +1,116 @@
+// History search screen
+// Finds past requests whose URL, error or stored response body contain some
+// text (e.g. an order ID). Enter on a result shows that response again.
+
+use super::{ScreenView, Transition};
+use crate::history::SearchHit;
+use crate::tui::app::AppState;
+use crate::tui::ui_utils::status_symbol;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+#[derive(Debug, Default)]
+pub struct HistorySearchScreen {
+    query: String,
+    /// Results for `searched`, which lags `query` until Enter is pressed
+    hits: Vec<SearchHit>,
+    searched: Option<String>,
+    selected: usize,
+}
+
+impl HistorySearchScreen {
+    fn search(&mut self, app: &AppState) {
+        self.hits = app.history.search(&self.query);
+        self.searched = Some(self.query.clone());
+        self.selected = 0;
+    }
+}
+
+impl ScreenView for HistorySearchScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(c) => self.query.push(c),
+            Action::DeleteChar => { self.query.pop(); }
+            Action::Up => self.selected = self.selected.saturating_sub(1),
+            Action::Down => self.selected = (self.selected + 1).min(self.hits.len().saturating_sub(1)),
+            // Enter searches after an edit and opens the selected result otherwise
+            Action::Submit if self.searched.as_deref() != Some(self.query.as_str()) => self.search(app),
+            Action::Submit => {
+                if let Some(hit) = self.hits.get(self.selected) {
+                    app.open_history_entry(hit.index);
+                    return Transition::Close;
+                }
+            }
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(app.tr("history_search.find"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::raw(self.query.as_str()),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(""),
+        ];
+
+        match &self.searched {
+            None => lines.push(Line::from(Span::styled(
+                app.tr("history_search.hint"),
+                dim,
+            ))),
+            Some(query) if self.hits.is_empty() => {
+                lines.push(Line::from(Span::styled(app.tr_with("history_search.no_matches", &[("query", query)]), dim)));
+            }
+            Some(_) => {
+                lines.push(Line::from(Span::styled(app.tr_with("history_search.matches", &[("count", &self.hits.len())]), dim)));
+                // Keep the selected result on screen; each takes two lines
+                let visible = (area.height.saturating_sub(6) / 2) as usize;
+                let first = self.selected.saturating_sub(visible.saturating_sub(1));
+                for (i, hit) in self.hits.iter().enumerate().skip(first).take(visible) {
+                    let entry = &app.history.entries()[hit.index];
+                    let status = match entry.status {
+                        Some(status) => format!("{} {}", status_symbol(status), status),
+                        None => "✗ ERR".to_string(),
+                    };
+                    let status_color = if entry.is_success() { Color::Green } else { Color::Red };
+                    let when = entry.timestamp.with_timezone(&chrono::Local).format("%m-%d %H:%M:%S");
+                    let marker = if i == self.selected { "▶ " } else { "  " };
+                    let style = if i == self.selected { Style::default().add_modifier(Modifier::BOLD) } else { Style::default() };
+                    lines.push(Line::from(vec![
+                        Span::styled(marker, Style::default().fg(Color::Yellow)),
+                        Span::styled(format!("{} ", when), dim),
+                        Span::styled(format!("{:<6}", status), Style::default().fg(status_color)),
+                        Span::styled(format!("{} {}", entry.method, entry.url), style),
+                    ]));
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("    {}: ", hit.location), dim),
+                        Span::raw(hit.snippet.clone()),
+                    ]));
+                }
+            }
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr("history_search.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,135 @@
+// JSON table screen
+// Shows a response that is an array of flat objects as a table: one column
+// per key, one row per item, sortable by any column.
+
+use super::{ScreenView, Transition};
+use crate::tui::app::AppState;
+use crate::tui::input::Action;
+use crate::visualize::JsonTable;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+pub struct JsonTableScreen {
+    table: JsonTable,
+    /// Column the cursor is on; Enter sorts by it
+    column: usize,
+    selected: usize,
+    sort: Option<(usize, bool)>, // (column, descending)
+}
+
+impl JsonTableScreen {
+    pub fn new(table: JsonTable) -> Self {
+        Self { table, column: 0, selected: 0, sort: None }
+    }
+
+    /// Sort by the current column, flipping direction when it is already sorted
+    fn sort(&mut self) {
+        let descending = matches!(self.sort, Some((column, false)) if column == self.column);
+        self.table.sort_by(self.column, descending);
+        self.sort = Some((self.column, descending));
+    }
+}
+
+impl ScreenView for JsonTableScreen {
+    fn handle_action(&mut self, _app: &mut AppState, action: Action) -> Transition {
+        let last_row = self.table.rows.len().saturating_sub(1);
+        match action {
+            Action::Up => self.selected = self.selected.saturating_sub(1),
+            Action::Down => self.selected = (self.selected + 1).min(last_row),
+            Action::ScrollUp => self.selected = self.selected.saturating_sub(10),
+            Action::ScrollDown => self.selected = (self.selected + 10).min(last_row),
+            Action::ScrollTop => self.selected = 0,
+            Action::ScrollBottom => self.selected = last_row,
+            Action::ScrollLeft => self.column = self.column.saturating_sub(1),
+            Action::ScrollRight => self.column = (self.column + 1).min(self.table.columns.len().saturating_sub(1)),
+            Action::Select => self.sort(),
+            Action::Quit => return Transition::Quit,
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let columns = &self.table.columns;
+        let headers: Vec<String> = columns.iter().enumerate()
+            .map(|(i, name)| match self.sort {
+                Some((column, descending)) if column == i => format!("{} {}", name, if descending { "▼" } else { "▲" }),
+                _ => name.clone(),
+            })
+            .collect();
+        let widths: Vec<usize> = (0..columns.len())
+            .map(|c| {
+                let cells = (0..self.table.rows.len()).map(|r| self.table.cell(r, c).chars().count());
+                cells.chain([headers[c].chars().count()]).max().unwrap_or(0)
+            })
+            .collect();
+
+        // Scroll sideways so the cursor column stays visible
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let mut first_column = 0;
+        while first_column < self.column
+            && widths[first_column..=self.column].iter().map(|w| w + 3).sum::<usize>() > inner_width
+        {
+            first_column += 1;
+        }
+
+        let header_line = Line::from(headers.iter().enumerate().skip(first_column)
+            .flat_map(|(i, header)| {
+                let mut style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+                if i == self.column {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                [Span::styled(format!("{:<width$}", header, width = widths[i]), style), Span::raw(" │ ")]
+            })
+            .collect::<Vec<_>>());
+        let separator = widths[first_column..].iter().map(|w| "─".repeat(*w)).collect::<Vec<_>>().join("─┼─");
+        let mut lines = vec![header_line, Line::from(Span::styled(separator, Style::default().fg(Color::DarkGray)))];
+
+        // Keep the selected row on screen below the two header lines
+        let visible = area.height.saturating_sub(4) as usize;
+        let first_row = self.selected.saturating_sub(visible.saturating_sub(1));
+        for row in (first_row..self.table.rows.len()).take(visible) {
+            let text = (first_column..columns.len())
+                .map(|c| format!("{:<width$}", self.table.cell(row, c), width = widths[c]))
+                .collect::<Vec<_>>()
+                .join(" │ ");
+            let style = if row == self.selected {
+                Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(Span::styled(text, style)));
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr_with("json_table.title", &[("rows", &self.table.rows.len())]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enter_flips_sort_direction() {
+        let table = JsonTable::from_body(br#"[{"id": 2}, {"id": 1}, {"id": 3}]"#).unwrap();
+        let mut screen = JsonTableScreen::new(table);
+        screen.sort();
+        assert_eq!(screen.sort, Some((0, false)));
+        assert_eq!(screen.table.cell(0, 0), "1");
+        screen.sort();
+        assert_eq!(screen.sort, Some((0, true)));
+        assert_eq!(screen.table.cell(0, 0), "3");
+    }
+}
@@ -0,0 +1,114 @@
+// Load test comparison screen
+// Two saved runs side by side: throughput, error rate and every latency
+// percentile of the later run against the baseline, with ▲/▼ coloured by
+// whether the change is an improvement or a regression.
+
+use super::{ScreenView, Transition};
+use crate::load_test::{compare_reports, LoadTestReport, MetricDelta, Trend, UNCHANGED_PERCENT};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use chrono::Local;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+pub struct LoadTestCompareScreen {
+    before: LoadTestReport,
+    after: LoadTestReport,
+    deltas: Vec<MetricDelta>,
+}
+
+impl LoadTestCompareScreen {
+    /// Compare two runs; the older one is the baseline whichever way round they come
+    pub fn new(first: LoadTestReport, second: LoadTestReport) -> Self {
+        let (before, after) = if first.started_at <= second.started_at { (first, second) } else { (second, first) };
+        let deltas = compare_reports(&before, &after);
+        Self { before, after, deltas }
+    }
+
+    fn run_line(label: &str, report: &LoadTestReport, app: &AppState) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("{:<8}", label), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw(format!("{} {} ", report.target.method, report.target.endpoint_name)),
+            Span::styled(
+                app.tr_with("load_test_compare.run", &[
+                    ("started", &report.started_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")),
+                    ("workers", &report.concurrency),
+                    ("seconds", &format!("{:.0}", report.duration_secs)),
+                    ("requests", &report.total_requests),
+                ]),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])
+    }
+
+    fn delta_line(delta: &MetricDelta, app: &AppState) -> Line<'static> {
+        let (arrow, color) = match (delta.trend(), delta.change() > 0.0) {
+            (Trend::Unchanged, _) => ("=", Color::DarkGray),
+            (Trend::Improved, true) => ("▲", Color::Green),
+            (Trend::Improved, false) => ("▼", Color::Green),
+            (Trend::Regressed, true) => ("▲", Color::Red),
+            (Trend::Regressed, false) => ("▼", Color::Red),
+        };
+        let value = |v: f64| format!("{:.1}{}", v, delta.unit);
+        let percent = match delta.percent() {
+            Some(percent) => format!("{:+.1}%", percent),
+            None => app.tr("load_test_compare.new").to_string(),
+        };
+        let percent = if delta.trend() == Trend::Unchanged { String::new() } else { percent };
+        Line::from(vec![
+            Span::styled(format!("  {:<13}", delta.name), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{:>12}", value(delta.before))),
+            Span::raw(format!("{:>12}", value(delta.after))),
+            Span::styled(format!("  {} {:>+10.1}{} {:>8}", arrow, delta.change(), delta.unit, percent), Style::default().fg(color)),
+        ])
+    }
+}
+
+impl ScreenView for LoadTestCompareScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, _app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar('q') | Action::Back | Action::Submit => Transition::Close,
+            _ => Transition::Stay,
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines = vec![
+            Self::run_line(app.tr("load_test_compare.before_label"), &self.before, app),
+            Self::run_line(app.tr("load_test_compare.after_label"), &self.after, app),
+            Line::from(""),
+            Line::from(Span::styled(format!(
+                "  {:<13}{:>12}{:>12}  {:>13}",
+                "", app.tr("load_test_compare.before"), app.tr("load_test_compare.after"), app.tr("load_test_compare.change"),
+            ), dim)),
+        ];
+        lines.extend(self.deltas.iter().map(|delta| Self::delta_line(delta, app)));
+        if self.before.target.endpoint_id != self.after.target.endpoint_id {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(app.tr("load_test_compare.different_endpoints"), Style::default().fg(Color::Yellow))));
+        }
+        if (self.before.concurrency, self.before.planned_duration_secs) != (self.after.concurrency, self.after.planned_duration_secs) {
+            lines.push(Line::from(Span::styled(app.tr("load_test_compare.different_settings"), Style::default().fg(Color::Yellow))));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(app.tr_with("load_test_compare.legend", &[("percent", &UNCHANGED_PERCENT)]), dim)));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr("load_test_compare.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Magenta)));
+        f.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,288 @@
+// Load test configuration screen
+// Owns the configuration form; the app only sees it when the test starts.
+
+use super::{ScreenView, Transition};
+use crate::load_test;
+use crate::models::ApiEndpoint;
+use crate::settings::LoadTestDefaults;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, FormInput, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+#[derive(Debug, Clone)]
+pub struct LoadTestConfigForm {
+    pub concurrency: String,
+    pub duration: String,
+    pub ramp_up: String,
+    pub timeout: String, // Empty = inherit the interactive client's timeout
+    pub pool_size: String, // Empty = inherit the interactive client's pool size
+    pub http1_only: bool,
+    pub disable_compression: bool,
+    pub headers: String, // `Name: value; Other: value`, sent with this run's requests only
+    pub har_samples: String, // Empty = no HAR capture
+    pub queue: bool, // Add to the load test queue instead of starting now
+    pub cool_down: String, // Seconds to wait before the next queued run
+    pub soak: bool, // Allow multi-hour durations and checkpoint progress to disk
+    pub chaos: String, // `delay 200ms 10%; drop 5%; malformed 2%`, empty = none
+    pub current_field: usize, // 0=concurrency, 1=duration, 2=ramp_up, 3=timeout, 4=pool_size, 5=http1_only, 6=disable_compression, 7=headers, 8=har_samples, 9=queue, 10=cool_down, 11=soak, 12=chaos
+    pub collection_index: usize,
+    pub endpoint_index: usize,
+    pub scenario: bool, // Run the collection's scenario; the endpoint only prefills the form
+}
+
+impl LoadTestConfigForm {
+    /// Prefill from the endpoint's saved configuration, or use the workspace defaults
+    pub fn for_endpoint(endpoint: &ApiEndpoint, defaults: &LoadTestDefaults, collection_index: usize, endpoint_index: usize) -> Self {
+        let saved = endpoint.load_test_config.as_ref();
+        Self {
+            concurrency: saved.map(|c| c.concurrency).unwrap_or(defaults.concurrency).to_string(),
+            duration: saved.map(|c| c.duration_secs).unwrap_or(defaults.duration_secs).to_string(),
+            ramp_up: saved.and_then(|c| c.ramp_up_secs).map(|s| s.to_string()).unwrap_or_default(),
+            timeout: saved.and_then(|c| c.timeout_secs).map(|s| s.to_string()).unwrap_or_default(),
+            pool_size: saved.and_then(|c| c.pool_max_idle_per_host).map(|s| s.to_string()).unwrap_or_default(),
+            http1_only: saved.map(|c| c.http1_only).unwrap_or(false),
+            disable_compression: saved.map(|c| c.disable_compression).unwrap_or(false),
+            headers: saved.map(|c| load_test::format_headers(&c.headers)).unwrap_or_default(),
+            har_samples: saved.map(|c| c.har_samples).filter(|&n| n > 0).map(|n| n.to_string()).unwrap_or_default(),
+            queue: false,
+            soak: saved.is_some_and(|c| c.soak),
+            chaos: saved.map(|c| c.chaos.to_string()).unwrap_or_default(),
+            cool_down: saved.map(|c| c.cool_down_secs).filter(|&s| s > 0).map(|s| s.to_string()).unwrap_or_default(),
+            current_field: 0,
+            collection_index,
+            endpoint_index,
+            scenario: false,
+        }
+    }
+}
+
+pub struct LoadTestConfigScreen {
+    pub form: LoadTestConfigForm,
+}
+
+impl LoadTestConfigScreen {
+    pub fn new(form: LoadTestConfigForm) -> Self {
+        Self { form }
+    }
+}
+
+impl ScreenView for LoadTestConfigScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Back => return Transition::Close,
+            // Stay open on validation errors so the values can be fixed
+            Action::Submit if app.execute_load_test(&self.form) => return Transition::Close,
+            Action::InsertChar(c) => self.form.insert_char(c),
+            Action::DeleteChar => self.form.delete_char(),
+            Action::NextField => self.form.next_field(),
+            Action::PrevField => self.form.prev_field(),
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let form = &self.form;
+        let title = app.tr_with("load_test_config.title", &[
+            ("form", &app.tr(if form.scenario { "load_test_config.scenario_form" } else { "load_test_config.form" })),
+            ("submit", &app.tr(if form.queue { "load_test_config.add_to_queue" } else { "load_test_config.start" })),
+        ]);
+        
+        let concurrency_style = if form.current_field == 0 {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        
+        let duration_style = if form.current_field == 1 {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        
+        let ramp_up_style = if form.current_field == 2 {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        
+        let field_style = |field_num: usize| {
+            if form.current_field == field_num {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            }
+        };
+        
+        let checkbox = |checked: bool| if checked { "[x]" } else { "[ ]" };
+        
+        let cursor = if form.current_field == 0 && !form.concurrency.is_empty() 
+            || form.current_field == 1 && !form.duration.is_empty()
+            || form.current_field == 2 && !form.ramp_up.is_empty()
+            || form.current_field == 3 && !form.timeout.is_empty()
+            || form.current_field == 4 && !form.pool_size.is_empty()
+            || form.current_field == 7 && !form.headers.is_empty()
+            || form.current_field == 8 && !form.har_samples.is_empty()
+            || form.current_field == 10 && !form.cool_down.is_empty()
+            || form.current_field == 12 && !form.chaos.is_empty() {
+            ""
+        } else {
+            "_"
+        };
+        
+        let mut text = vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.configure"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.concurrency"), Style::default().fg(Color::Cyan)),
+                Span::styled(&form.concurrency, concurrency_style),
+                Span::styled(if form.current_field == 0 { cursor } else { "" }, concurrency_style.add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.concurrency_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.duration"), Style::default().fg(Color::Cyan)),
+                Span::styled(&form.duration, duration_style),
+                Span::styled(if form.current_field == 1 { cursor } else { "" }, duration_style.add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    if form.soak { app.tr("load_test_config.duration_soak_hint") } else { app.tr("load_test_config.duration_hint") },
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.ramp_up"), Style::default().fg(Color::Cyan)),
+                Span::styled(if form.ramp_up.is_empty() { app.tr("load_test_config.optional") } else { &form.ramp_up }, ramp_up_style),
+                Span::styled(if form.current_field == 2 { cursor } else { "" }, ramp_up_style.add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.ramp_up_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.client"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.timeout"), Style::default().fg(Color::Cyan)),
+                Span::styled(if form.timeout.is_empty() { app.tr("load_test_config.inherit") } else { &form.timeout }, field_style(3)),
+                Span::styled(if form.current_field == 3 { cursor } else { "" }, field_style(3).add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.pool_size"), Style::default().fg(Color::Cyan)),
+                Span::styled(if form.pool_size.is_empty() { app.tr("load_test_config.inherit") } else { &form.pool_size }, field_style(4)),
+                Span::styled(if form.current_field == 4 { cursor } else { "" }, field_style(4).add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.http1_only"), Style::default().fg(Color::Cyan)),
+                Span::styled(checkbox(form.http1_only), field_style(5)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.disable_compression"), Style::default().fg(Color::Cyan)),
+                Span::styled(checkbox(form.disable_compression), field_style(6)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.client_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.headers"), Style::default().fg(Color::Cyan)),
+                Span::styled(if form.headers.is_empty() && form.current_field != 7 { app.tr("load_test_config.none") } else { &form.headers }, field_style(7)),
+                Span::styled(if form.current_field == 7 { cursor } else { "" }, field_style(7).add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.headers_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.har_samples"), Style::default().fg(Color::Cyan)),
+                Span::styled(if form.har_samples.is_empty() && form.current_field != 8 { app.tr("load_test_config.off") } else { &form.har_samples }, field_style(8)),
+                Span::styled(if form.current_field == 8 { cursor } else { "" }, field_style(8).add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.har_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.queue"), Style::default().fg(Color::Cyan)),
+                Span::styled(checkbox(form.queue), field_style(9)),
+                Span::styled(app.tr_with("load_test_config.queued", &[("count", &app.load_test_queue.len())]), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.cool_down"), Style::default().fg(Color::Cyan)),
+                Span::styled(if form.cool_down.is_empty() && form.current_field != 10 { "0" } else { &form.cool_down }, field_style(10)),
+                Span::styled(if form.current_field == 10 { cursor } else { "" }, field_style(10).add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.queue_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.soak"), Style::default().fg(Color::Cyan)),
+                Span::styled(checkbox(form.soak), field_style(11)),
+                Span::styled(app.tr("load_test_config.soak_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.chaos"), Style::default().fg(Color::Cyan)),
+                Span::styled(if form.chaos.is_empty() && form.current_field != 12 { app.tr("load_test_config.off") } else { &form.chaos }, field_style(12)),
+                Span::styled(if form.current_field == 12 { cursor } else { "" }, field_style(12).add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test_config.chaos_hint"), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(""),
+        ];
+        
+        if let Some(collection) = app.collections.get(form.collection_index).filter(|_| form.scenario) {
+            let endpoints: Vec<_> = collection.scenario_endpoints().into_iter().map(|(e, weight)| (e.clone(), weight)).collect();
+            text.insert(2, Line::from(vec![
+                Span::styled(app.tr("load_test_config.scenario"), Style::default().fg(Color::Cyan)),
+                Span::raw(load_test::describe_mix(&endpoints)),
+            ]));
+        }
+        
+        // Show preview
+        let concurrency_val = form.concurrency.parse::<usize>().unwrap_or(10);
+        let duration_val = form.duration.parse::<u64>().unwrap_or(30);
+        let ramp_up_val = if form.ramp_up.is_empty() {
+            None
+        } else {
+            form.ramp_up.parse::<u64>().ok()
+        };
+        
+        text.push(Line::from(vec![
+            Span::styled(app.tr("load_test_config.preview"), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+        ]));
+        text.push(Line::from(app.tr_with("load_test_config.preview_workers", &[("workers", &concurrency_val), ("seconds", &duration_val)])));
+        
+        if let Some(ramp_up) = ramp_up_val {
+            text.push(Line::from(app.tr_with("load_test_config.preview_ramp_up", &[("seconds", &ramp_up)])));
+            text.push(Line::from(app.tr_with("load_test_config.preview_total", &[("total", &(concurrency_val * (duration_val - ramp_up / 2) as usize))])));
+        } else {
+            text.push(Line::from(app.tr_with("load_test_config.preview_total", &[("total", &(concurrency_val * duration_val as usize))])));
+        }
+        
+        let paragraph = Paragraph::new(text)
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Magenta)))
+            .wrap(Wrap { trim: true });
+        
+        f.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,174 @@
+// Saved load test report screen
+// Shows a run from the results directory: totals, latency summary and the
+// error and assertion breakdowns and the run's time series. Used for runs
+// recovered from a checkpoint and opened from the past results list.
+
+use super::{ScreenView, Transition};
+use crate::load_test::{LoadTestReport, RunStatus};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+use std::collections::HashMap;
+
+pub struct LoadTestReportScreen {
+    report: LoadTestReport,
+    scroll: u16,
+}
+
+impl LoadTestReportScreen {
+    pub fn new(report: LoadTestReport) -> Self {
+        Self { report, scroll: 0 }
+    }
+
+    fn lines(&self, app: &AppState) -> Vec<Line<'static>> {
+        let report = &self.report;
+        let label = |text: &str| Span::styled(text.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+        let dim = Style::default().fg(Color::DarkGray);
+        let percent = |count: u64| if report.total_requests > 0 {
+            format!(" ({:.1}%)", count as f64 / report.total_requests as f64 * 100.0)
+        } else {
+            String::new()
+        };
+
+        let (status, status_color) = match report.status {
+            RunStatus::Completed => (app.tr("load_test_report.completed"), Color::Green),
+            RunStatus::Running => (app.tr("load_test_report.running"), Color::Yellow),
+            RunStatus::Interrupted => (app.tr("load_test_report.interrupted"), Color::Red),
+        };
+        let started = report.started_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S");
+        let mut lines = vec![
+            Line::from(vec![
+                label(app.tr("load_test_report.endpoint")),
+                Span::raw(format!("{} {} ({})", report.target.method, report.target.endpoint_name, report.target.url)),
+            ]),
+            Line::from(vec![label(app.tr("load_test_report.status")), Span::styled(status, Style::default().fg(status_color))]),
+            Line::from(vec![
+                label(app.tr("load_test_report.started")),
+                Span::raw(started.to_string()),
+                Span::styled(
+                    app.tr_with("load_test_report.ran", &[
+                        ("seconds", &format!("{:.0}", report.duration_secs)),
+                        ("planned", &report.planned_duration_secs),
+                        ("workers", &report.concurrency),
+                    ]),
+                    dim,
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![label(app.tr("load_test_report.total")), Span::raw(report.total_requests.to_string()), Span::styled(format!("  {:.2} req/s", report.avg_rps), dim)]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test.successful"), Style::default().fg(Color::Green)),
+                Span::raw(report.successful_requests.to_string()),
+                Span::styled(percent(report.successful_requests), dim),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test.failed"), Style::default().fg(Color::Red)),
+                Span::raw(report.failed_requests.to_string()),
+                Span::styled(percent(report.failed_requests), dim),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test.assertions_failed"), Style::default().fg(Color::LightRed)),
+                Span::raw(report.assertion_failed_requests.to_string()),
+                Span::styled(percent(report.assertion_failed_requests), dim),
+            ]),
+            Line::from(""),
+            Line::from(label(app.tr("load_test_report.latency"))),
+        ];
+        let latency = &report.latency_ms;
+        lines.push(Line::from(format!(
+            "  avg {:.1}  min {:.1}  p50 {:.1}  p90 {:.1}  p95 {:.1}  p99 {:.1}  max {:.1}",
+            latency.avg, latency.min, latency.p50, latency.p90, latency.p95, latency.p99, latency.max,
+        )));
+
+        if !report.endpoints.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(label(app.tr("load_test_report.per_endpoint"))));
+            lines.push(Line::from(Span::styled(app.tr("load_test_report.per_endpoint_columns"), dim)));
+            for endpoint in &report.endpoints {
+                let share = endpoint.total_requests as f64 / report.total_requests.max(1) as f64 * 100.0;
+                lines.push(Line::from(format!(
+                    "  {:>10} {:>6.1}% {:>8} {:>8} {:>8} {:>9.1} {:>9.1}  {} {} {}",
+                    endpoint.total_requests, share, endpoint.successful_requests, endpoint.failed_requests,
+                    endpoint.assertion_failed_requests, endpoint.latency_ms.p50, endpoint.latency_ms.p95,
+                    endpoint.method, endpoint.name, app.tr_with("load_test_report.weight", &[("weight", &endpoint.weight)]),
+                )));
+            }
+        }
+
+        if !report.time_series.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(label(app.tr("load_test_report.over_time"))));
+            lines.push(Line::from(Span::styled(app.tr("load_test_report.over_time_columns"), dim)));
+            for point in &report.time_series {
+                lines.push(Line::from(format!(
+                    "  {:>8}s {:>9.1} {:>9.1} {:>9.1} {:>9.1} {:>9}",
+                    point.elapsed_secs, point.rps, point.p50_ms, point.p95_ms, point.p99_ms, point.request_count,
+                )));
+            }
+        }
+
+        let sections = [
+            (app.tr("load_test_report.errors"), &report.error_counts),
+            (app.tr("load_test_report.failing_assertions"), &report.assertion_failure_counts),
+            (app.tr("load_test_report.injected"), &report.chaos_counts),
+        ];
+        for (title, counts) in sections {
+            if counts.is_empty() {
+                continue;
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(label(title)));
+            for (name, count) in by_count(counts) {
+                lines.push(Line::from(vec![Span::styled(format!("  {:>8}  ", count), dim), Span::raw(name)]));
+            }
+        }
+
+        if let Some(path) = &report.har_path {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![label(app.tr("load_test.har_samples")), Span::raw(path.display().to_string())]));
+        }
+        lines
+    }
+}
+
+/// Most frequent first
+fn by_count(counts: &HashMap<String, u64>) -> Vec<(String, u64)> {
+    let mut counts: Vec<(String, u64)> = counts.iter().map(|(name, count)| (name.clone(), *count)).collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    counts
+}
+
+impl ScreenView for LoadTestReportScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, _app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.scroll = self.scroll.saturating_sub(1),
+            Action::Down => self.scroll = self.scroll.saturating_add(1),
+            Action::InsertChar('q') | Action::Back | Action::Submit => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let lines = self.lines(app);
+        let max_scroll = (lines.len() as u16).saturating_sub(area.height.saturating_sub(2));
+        let paragraph = Paragraph::new(lines)
+            .scroll((self.scroll.min(max_scroll), 0))
+            .block(Block::default()
+                .title(app.tr("load_test_report.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Magenta)));
+        f.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,178 @@
+// Load test scenario screen
+// Give a collection's endpoints weights to load test them together: with
+// List items at 7 and Create item at 3, seven in ten requests list and three
+// create. The weights are saved with the collection; Enter goes on to the
+// run's configuration.
+
+use super::{ScreenView, Transition};
+use crate::load_test;
+use crate::models::ScenarioStep;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+use uuid::Uuid;
+
+pub struct LoadTestScenarioScreen {
+    collection_index: usize,
+    weights: Vec<(Uuid, String)>, // Every endpoint of the collection, blank when left out
+    selected: usize,
+}
+
+impl LoadTestScenarioScreen {
+    pub fn new(app: &AppState, collection_index: usize) -> Self {
+        let weights = app.collections.get(collection_index)
+            .map(|collection| collection.endpoints.iter()
+                .map(|endpoint| {
+                    let weight = collection.scenario.iter()
+                        .find(|step| step.endpoint_id == endpoint.id && step.weight > 0)
+                        .map(|step| step.weight.to_string())
+                        .unwrap_or_default();
+                    (endpoint.id, weight)
+                })
+                .collect())
+            .unwrap_or_default();
+        Self { collection_index, weights, selected: 0 }
+    }
+
+    /// The endpoints with a weight, in the collection's order
+    pub fn scenario(&self) -> Vec<ScenarioStep> {
+        self.weights.iter()
+            .filter_map(|(endpoint_id, weight)| {
+                let weight = weight.parse::<u32>().ok().filter(|&w| w > 0)?;
+                Some(ScenarioStep { endpoint_id: *endpoint_id, weight })
+            })
+            .collect()
+    }
+
+    /// Save the weights if they changed and configure the run
+    fn submit(&self, app: &mut AppState) -> bool {
+        let scenario = self.scenario();
+        if scenario.is_empty() {
+            app.error_message = Some(app.tr("load_test_scenario.no_weights").to_string());
+            return false;
+        }
+        let saved = app.collections.get(self.collection_index).map(|c| &c.scenario);
+        if saved != Some(&scenario) && !app.set_load_test_scenario(self.collection_index, scenario) {
+            return false;
+        }
+        app.start_scenario_load_test(self.collection_index);
+        true
+    }
+}
+
+impl ScreenView for LoadTestScenarioScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let count = self.weights.len().max(1);
+        match action {
+            Action::Back => return Transition::Close,
+            // Stay open when there's nothing to run or the collection is read-only
+            Action::Submit if self.submit(app) => return Transition::Close,
+            Action::InsertChar(c) if c.is_ascii_digit() => {
+                if let Some((_, weight)) = self.weights.get_mut(self.selected) {
+                    // Weights past four digits only make the shares harder to read
+                    if weight.len() < 4 {
+                        weight.push(c);
+                    }
+                }
+            }
+            Action::DeleteChar => {
+                if let Some((_, weight)) = self.weights.get_mut(self.selected) {
+                    weight.pop();
+                }
+            }
+            Action::NextField | Action::Down => self.selected = (self.selected + 1) % count,
+            Action::PrevField | Action::Up => self.selected = (self.selected + count - 1) % count,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(collection) = app.collections.get(self.collection_index) else { return };
+        let dim = Style::default().fg(Color::DarkGray);
+        let total: u32 = self.scenario().iter().map(|step| step.weight).sum();
+        let height = area.height.saturating_sub(6) as usize;
+        let start = (self.selected + 1).saturating_sub(height);
+
+        let mut lines = vec![
+            Line::from(Span::styled(app.tr("load_test_scenario.columns"), dim)),
+        ];
+        for (i, (endpoint, (_, weight))) in collection.endpoints.iter().zip(&self.weights).enumerate().skip(start).take(height) {
+            let active = i == self.selected;
+            let weight_style = if active {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let share = weight.parse::<u32>().ok().filter(|&w| w > 0)
+                .map(|w| format!("{:>4.0}%", load_test::share(w, total)))
+                .unwrap_or_else(|| "    -".to_string());
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:>6}", format!("{}{}", weight, if active { "_" } else { "" })), weight_style),
+                Span::styled(format!("  {}  ", share), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{:<7} ", endpoint.method.to_string()), Style::default().fg(Color::Green)),
+                Span::raw(endpoint.name.clone()),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(app.tr("load_test_scenario.hint"), dim)));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr_with("load_test_scenario.title", &[("name", &collection.name)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Magenta)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod, ScenarioStep};
+    use crate::tui::harness::Harness;
+
+    #[test]
+    fn test_weigh_endpoints_and_configure_a_run() {
+        let mut collection = ApiCollection::new("Shop".to_string());
+        collection.add_endpoint(ApiEndpoint::new("List items".to_string(), HttpMethod::GET, "https://api.example.com/items".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("Get item".to_string(), HttpMethod::GET, "https://api.example.com/items/1".to_string()));
+        collection.add_endpoint(ApiEndpoint::new("Create item".to_string(), HttpMethod::POST, "https://api.example.com/items".to_string()));
+        let ids: Vec<_> = collection.endpoints.iter().map(|e| e.id).collect();
+        let mut harness = Harness::new().with_collection(collection);
+
+        harness.press("K").press("Enter");
+        assert_eq!(harness.app.error_message.as_deref(), Some("Give at least one endpoint a weight"));
+
+        harness.type_text("7").keys(&["Down", "Down"]).type_text("3");
+        let screen = harness.render();
+        assert!(screen.contains("Load Test Scenario: Shop"), "{}", screen);
+        assert!(screen.contains("7    70%  GET     List items"), "{}", screen);
+        assert!(screen.contains("-  GET     Get item"), "{}", screen);
+        assert!(screen.contains("3_    30%  POST    Create item"), "{}", screen);
+
+        harness.press("Enter");
+        let screen = harness.render();
+        assert!(screen.contains("Scenario Load Test Configuration"), "{}", screen);
+        assert!(screen.contains("Scenario: 70% List items, 30% Create item"), "{}", screen);
+        let saved = harness.app.storage.load_collections().unwrap();
+        assert_eq!(saved[0].scenario, vec![
+            ScenarioStep { endpoint_id: ids[0], weight: 7 },
+            ScenarioStep { endpoint_id: ids[2], weight: 3 },
+        ]);
+
+        // The saved weights come back next time
+        harness.press("Esc").press("K");
+        assert!(harness.render().contains("3    30%  POST    Create item"));
+    }
+}
@@ -0,0 +1,154 @@
+// Application log viewer
+// Shows the tail of app.log, newest at the bottom, optionally only warnings
+// and errors, so storage or HTTP trouble can be looked into without leaving
+// the TUI.
+
+use super::{ScreenView, Transition};
+use crate::logging::{self, LogLine};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+use tracing::Level;
+
+/// How much of the log is read
+const TAIL_LINES: usize = 2000;
+
+pub struct LogViewerScreen {
+    lines: Vec<LogLine>,
+    min_level: Option<Level>, // None = everything
+    scroll: usize, // Lines scrolled up from the bottom
+}
+
+impl LogViewerScreen {
+    pub fn new(app: &mut AppState) -> Self {
+        let mut screen = Self { lines: Vec::new(), min_level: None, scroll: 0 };
+        screen.reload(app);
+        screen
+    }
+
+    fn reload(&mut self, app: &mut AppState) {
+        let path = app.storage.log_path();
+        match logging::tail(&path, TAIL_LINES) {
+            Ok(lines) => self.lines = lines,
+            Err(e) => app.error_message = Some(app.tr_with("error.read_file", &[("path", &path.display()), ("error", &e)])),
+        }
+        self.scroll = 0;
+    }
+
+    /// Lines at or above the chosen level; continuation lines follow their entry
+    fn visible(&self) -> Vec<&LogLine> {
+        let Some(min_level) = self.min_level else { return self.lines.iter().collect() };
+        let mut shown = false;
+        self.lines.iter().filter(|line| {
+            if let Some(level) = line.level {
+                // Level orders by verbosity: ERROR < WARN < INFO
+                shown = level <= min_level;
+            }
+            shown
+        }).collect()
+    }
+
+    fn cycle_level(&mut self) {
+        self.min_level = match self.min_level {
+            None => Some(Level::INFO),
+            Some(Level::INFO) => Some(Level::WARN),
+            Some(Level::WARN) => Some(Level::ERROR),
+            _ => None,
+        };
+        self.scroll = 0;
+    }
+}
+
+impl ScreenView for LogViewerScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let count = self.visible().len();
+        match action {
+            Action::Up => self.scroll = (self.scroll + 1).min(count.saturating_sub(1)),
+            Action::Down => self.scroll = self.scroll.saturating_sub(1),
+            Action::InsertChar('g') => self.scroll = count.saturating_sub(1),
+            Action::InsertChar('G') => self.scroll = 0,
+            Action::InsertChar('l') => self.cycle_level(),
+            Action::InsertChar('r') => self.reload(app),
+            Action::InsertChar('q') | Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let visible = self.visible();
+        let height = area.height.saturating_sub(2) as usize;
+        let end = visible.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(height);
+
+        let lines: Vec<Line> = if visible.is_empty() {
+            vec![Line::from(Span::styled(
+                app.tr_with("log_viewer.empty", &[("path", &app.storage.log_path().display())]),
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            visible[start..end].iter().map(|line| {
+                let color = match line.level {
+                    Some(Level::ERROR) => Color::Red,
+                    Some(Level::WARN) => Color::Yellow,
+                    Some(Level::INFO) => Color::White,
+                    Some(_) => Color::DarkGray,
+                    None => Color::Gray,
+                };
+                Line::from(Span::styled(line.text.clone(), Style::default().fg(color)))
+            }).collect()
+        };
+
+        let level = match self.min_level {
+            None => app.tr("log_viewer.all").to_string(),
+            Some(level) => format!("{}+", level),
+        };
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr_with("log_viewer.title", &[("level", &level), ("lines", &visible.len())]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_level_filter_keeps_continuations() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        std::fs::write(app.storage.log_path(), concat!(
+            "2026-01-01T00:00:00.000001Z  INFO rest_api_tui::storage: Loaded 3 collections\n",
+            "2026-01-01T00:00:01.000001Z  WARN rest_api_tui::storage: Skipping broken.json\n",
+            "  at line 1\n",
+            "2026-01-01T00:00:02.000001Z DEBUG rest_api_tui::storage: Saved collection\n",
+        )).unwrap();
+
+        let mut screen = LogViewerScreen::new(&mut app);
+        assert_eq!(screen.visible().len(), 4);
+        screen.handle_action(&mut app, Action::InsertChar('l'));
+        assert_eq!(screen.visible().len(), 3);
+        screen.handle_action(&mut app, Action::InsertChar('l'));
+        let warnings: Vec<&str> = screen.visible().iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(warnings, vec!["2026-01-01T00:00:01.000001Z  WARN rest_api_tui::storage: Skipping broken.json", "  at line 1"]);
+
+        screen.handle_action(&mut app, Action::InsertChar('g'));
+        assert_eq!(screen.scroll, 1);
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Close);
+    }
+}
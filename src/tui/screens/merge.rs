@@ -0,0 +1,229 @@
+// Collection merge screen
+// Asks for the path of another version of the selected collection, then lists
+// every endpoint with how it differs and lets the user take mine/theirs/both.
+// Syncing with a remote spec opens straight on the list.
+
+use super::{ScreenView, Transition};
+use crate::activity::{ActivityEntry, ActivityKind};
+use crate::changelog;
+use crate::merge::{self, Change, MergeItem, Resolution};
+use crate::models::ApiEndpoint;
+use crate::storage::expand_home;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+pub struct MergeScreen {
+    collection_index: usize,
+    path: String,
+    items: Option<Vec<MergeItem>>, // None until the other file has been loaded
+    theirs_name: String,
+    selected: usize,
+}
+
+impl MergeScreen {
+    pub fn new(collection_index: usize) -> Self {
+        Self {
+            collection_index,
+            path: String::new(),
+            items: None,
+            theirs_name: String::new(),
+            selected: 0,
+        }
+    }
+
+    /// Review an already computed diff, e.g. against a re-fetched spec
+    pub fn reviewing(collection_index: usize, theirs_name: String, items: Vec<MergeItem>) -> Self {
+        Self {
+            items: Some(items),
+            theirs_name,
+            ..Self::new(collection_index)
+        }
+    }
+
+    fn load(&mut self, app: &mut AppState) {
+        let Some(mine) = app.collections.get(self.collection_index) else { return };
+        let path = expand_home(&self.path);
+        match app.storage.load_collection_from_path(&path) {
+            Ok(theirs) => {
+                let items = merge::diff(mine, &theirs);
+                app.status_message = Some(app.tr_with("merge.differ", &[
+                    ("count", &items.iter().filter(|i| i.change() != Change::Unchanged).count()),
+                    ("total", &items.len()),
+                ]));
+                app.error_message = None;
+                self.theirs_name = theirs.name;
+                self.items = Some(items);
+            }
+            Err(e) => app.error_message = Some(app.tr_with("error.load_file", &[("path", &path.display()), ("error", &e)])),
+        }
+    }
+
+    fn save(&self, app: &mut AppState, items: &[MergeItem]) -> Transition {
+        let Some(mine) = app.collections.get(self.collection_index) else { return Transition::Close };
+        let mut merged = merge::apply(mine, items);
+        changelog::record(mine, &mut merged);
+        match app.storage.save_collection(&merged) {
+            Ok(_) => {
+                app.record_activity(ActivityEntry::new(ActivityKind::Edit, &merged, format!("Merged '{}'", self.theirs_name)));
+                app.status_message = Some(app.tr_with("merge.merged", &[("theirs", &self.theirs_name), ("name", &merged.name)]));
+                app.error_message = None;
+                app.collections[self.collection_index] = merged;
+                app.clamp_selection();
+                Transition::Close
+            }
+            Err(e) => {
+                app.error_message = Some(app.tr_with("error.save_collection", &[("error", &e)]));
+                Transition::Stay
+            }
+        }
+    }
+}
+
+impl ScreenView for MergeScreen {
+    fn input_context(&self) -> InputContext {
+        if self.items.is_some() { InputContext::Menu } else { InputContext::Form }
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let Some(items) = &mut self.items else {
+            // Choosing the file to compare against
+            match action {
+                Action::InsertChar(c) => self.path.push(c),
+                Action::DeleteChar => { self.path.pop(); }
+                Action::Submit => self.load(app),
+                Action::Back => return Transition::Close,
+                _ => {}
+            }
+            return Transition::Stay;
+        };
+
+        let resolution = match action {
+            Action::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            Action::Down => {
+                self.selected = (self.selected + 1).min(items.len().saturating_sub(1));
+                None
+            }
+            Action::InsertChar('m') => Some(Resolution::Mine),
+            Action::InsertChar('t') => Some(Resolution::Theirs),
+            Action::InsertChar('b') => Some(Resolution::Both),
+            Action::Submit => {
+                let items = items.clone();
+                return self.save(app, &items);
+            }
+            Action::Back => return Transition::Close,
+            _ => None,
+        };
+        if let (Some(resolution), Some(item)) = (resolution, items.get_mut(self.selected)) {
+            item.resolution = resolution;
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(mine) = app.collections.get(self.collection_index) else { return };
+        let block = |title: String| Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let Some(items) = &self.items else {
+            let text = vec![
+                Line::from(app.tr_with("merge.intro", &[("name", &mine.name)])),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled(app.tr("merge.file"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    Span::styled(&self.path, Style::default().fg(Color::Yellow)),
+                    Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+                ]),
+                Line::from(""),
+                Line::from(Span::styled(app.tr("merge.keys"), Style::default().fg(Color::DarkGray))),
+            ];
+            let paragraph = Paragraph::new(text)
+                .block(block(app.tr_with("merge.title", &[("name", &mine.name)])))
+                .wrap(Wrap { trim: true });
+            f.render_widget(paragraph, area);
+            return;
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(area);
+
+        let lines: Vec<Line> = items.iter().enumerate().map(|(i, item)| {
+            let (marker, color) = match item.change() {
+                Change::Unchanged => ("=", Color::DarkGray),
+                Change::Modified => ("~", Color::Yellow),
+                Change::OnlyMine => ("-", Color::Red),
+                Change::OnlyTheirs => ("+", Color::Green),
+            };
+            let resolution = match item.resolution {
+                Resolution::Mine => app.tr("merge.mine"),
+                Resolution::Theirs => app.tr("merge.theirs"),
+                Resolution::Both => app.tr("merge.both"),
+            };
+            let style = if i == self.selected {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::styled(format!(" {} ", marker), Style::default().fg(color)),
+                Span::styled(format!("{:<40}", item.name()), style),
+                Span::styled(format!("[{}]", resolution), Style::default().fg(Color::Cyan)),
+            ])
+        }).collect();
+
+        // Keep the selected row on screen
+        let visible = chunks[0].height.saturating_sub(2) as usize;
+        let scroll = self.selected.saturating_sub(visible.saturating_sub(1)) as u16;
+        let list = Paragraph::new(lines)
+            .scroll((scroll, 0))
+            .block(block(app.tr_with("merge.items_title", &[("name", &mine.name), ("theirs", &self.theirs_name)])));
+        f.render_widget(list, chunks[0]);
+
+        let mut detail = Vec::new();
+        if let Some(item) = items.get(self.selected) {
+            describe(&mut detail, app.tr("merge.mine_label"), item.mine.as_ref(), app);
+            detail.push(Line::from(""));
+            describe(&mut detail, app.tr("merge.theirs_label"), item.theirs.as_ref(), app);
+        }
+        let paragraph = Paragraph::new(detail)
+            .block(block(app.tr("diagnostics.details").to_string()))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
+
+fn describe(lines: &mut Vec<Line<'static>>, label: &str, endpoint: Option<&ApiEndpoint>, app: &AppState) {
+    let heading = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let Some(endpoint) = endpoint else {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", label), heading),
+            Span::styled(app.tr("merge.not_present"), Style::default().fg(Color::DarkGray)),
+        ]));
+        return;
+    };
+    lines.push(Line::from(vec![
+        Span::styled(format!("{}: ", label), heading),
+        Span::raw(format!("{} {}", endpoint.method, endpoint.url)),
+    ]));
+    lines.push(Line::from(app.tr_with("merge.summary", &[
+        ("headers", &endpoint.headers.len()),
+        ("body", &endpoint.body_template.as_ref()
+            .map(|b| app.tr_with("merge.chars", &[("count", &b.len())]))
+            .unwrap_or_else(|| app.tr("merge.none").to_string())),
+        ("timeout", &endpoint.timeout_secs.map(|t| format!("{}s", t)).unwrap_or_else(|| app.tr("merge.default").to_string())),
+    ])));
+}
@@ -0,0 +1,168 @@
+// Collection notes
+// A markdown document kept with each collection for setup steps such as "get
+// a token from X first". Shown read-only with headings, bullets and code
+// blocks styled; `e` edits it in place and Esc saves.
+
+use super::{ScreenView, Transition};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the markdown subset notes are written in: `#` headings, `-`/`*`
+/// bullets and fenced code blocks; everything else is plain text
+pub fn markdown_lines(text: &str) -> Vec<Line<'static>> {
+    let mut in_code = false;
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            lines.push(Line::from(Span::styled(format!("  {}", line), Style::default().fg(Color::Green))));
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            lines.push(Line::from(Span::styled(heading.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))));
+        } else if let Some(item) = line.trim_start().strip_prefix("- ").or_else(|| line.trim_start().strip_prefix("* ")) {
+            let indent = line.len() - line.trim_start().len();
+            lines.push(Line::from(format!("{}• {}", " ".repeat(indent), item)));
+        } else {
+            lines.push(Line::from(line.to_string()));
+        }
+    }
+    lines
+}
+
+pub struct NotesScreen {
+    collection_index: usize,
+    /// The text being edited, if in edit mode
+    editing: Option<String>,
+    scroll: u16,
+}
+
+impl NotesScreen {
+    pub fn new(collection_index: usize) -> Self {
+        Self { collection_index, editing: None, scroll: 0 }
+    }
+}
+
+impl ScreenView for NotesScreen {
+    fn input_context(&self) -> InputContext {
+        if self.editing.is_some() { InputContext::Form } else { InputContext::Browse }
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let Some(text) = &mut self.editing else {
+            match action {
+                Action::Up | Action::ScrollUp => self.scroll = self.scroll.saturating_sub(1),
+                Action::Down | Action::ScrollDown => self.scroll = self.scroll.saturating_add(1),
+                Action::ScrollTop => self.scroll = 0,
+                Action::Edit if app.ensure_editable(self.collection_index) => {
+                    self.editing = app.collections.get(self.collection_index).map(|c| c.notes.clone());
+                }
+                Action::Quit => return Transition::Quit,
+                Action::Back => return Transition::Close,
+                _ => {}
+            }
+            return Transition::Stay;
+        };
+        match action {
+            Action::InsertChar(c) => text.push(c),
+            Action::DeleteChar => { text.pop(); }
+            Action::Submit => text.push('\n'),
+            Action::Back if app.set_collection_notes(self.collection_index, text) => self.editing = None,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(collection) = app.collections.get(self.collection_index) else { return };
+        let (lines, title) = match &self.editing {
+            Some(text) => {
+                let mut lines: Vec<Line> = text.split('\n').map(|line| Line::from(line.to_string())).collect();
+                if let Some(last) = lines.last_mut() {
+                    last.spans.push(Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)));
+                }
+                (lines, app.tr_with("notes.editing_title", &[("name", &collection.name)]))
+            }
+            None if collection.notes.trim().is_empty() => (
+                vec![Line::from(Span::styled(
+                    app.tr("notes.empty"),
+                    Style::default().fg(Color::DarkGray),
+                ))],
+                app.tr_with("notes.empty_title", &[("name", &collection.name)]),
+            ),
+            None => (
+                markdown_lines(&collection.notes),
+                app.tr_with("notes.title", &[("name", &collection.name)]),
+            ),
+        };
+        // Keep the cursor in view while typing
+        let visible = area.height.saturating_sub(2);
+        let scroll = match self.editing {
+            Some(_) => (lines.len() as u16).saturating_sub(visible),
+            None => self.scroll.min((lines.len() as u16).saturating_sub(visible)),
+        };
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((scroll, 0))
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiCollection;
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_markdown_lines() {
+        let lines = markdown_lines("# Setup\n- get a token\n  * from X\n```\n# not a heading\n```\nplain");
+        let text: Vec<String> = lines.iter().map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect()).collect();
+        assert_eq!(text, vec!["Setup", "• get a token", "  • from X", "  # not a heading", "plain"]);
+    }
+
+    #[test]
+    fn test_edit_and_save_notes() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        app.collections.push(ApiCollection::new("A".to_string()));
+
+        let mut screen = NotesScreen::new(0);
+        screen.handle_action(&mut app, Action::Edit);
+        assert_eq!(screen.input_context(), InputContext::Form);
+        for c in "# Auth".chars() {
+            screen.handle_action(&mut app, Action::InsertChar(c));
+        }
+        screen.handle_action(&mut app, Action::Submit);
+        for c in "login first".chars() {
+            screen.handle_action(&mut app, Action::InsertChar(c));
+        }
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Stay);
+        assert_eq!(screen.input_context(), InputContext::Browse);
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Close);
+
+        let saved = app.storage.load_collections().unwrap();
+        assert_eq!(saved[0].notes, "# Auth\nlogin first");
+
+        // Read-only collections keep their notes
+        app.collections[0].read_only = true;
+        let mut screen = NotesScreen::new(0);
+        screen.handle_action(&mut app, Action::Edit);
+        assert_eq!(screen.input_context(), InputContext::Browse);
+        assert!(app.error_message.as_deref().unwrap().contains("read-only"));
+    }
+}
@@ -0,0 +1,243 @@
+// One-off overrides for a single send
+// Change the query string, the headers or the variables for this execution
+// only, like unsaved changes in a request tab; the stored endpoint is left as
+// it is. Fields start from what a plain send would use.
+
+use super::{ScreenView, Transition};
+use crate::load_test;
+use crate::tui::app::{AppState, RequestOverrides};
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::collections::HashMap;
+
+/// Fields before the variables
+const QUERY: usize = 0;
+const HEADERS: usize = 1;
+
+pub struct OverridesScreen {
+    collection_index: usize,
+    endpoint_index: usize,
+    query: String,
+    headers: String, // `Name: value; Other: value`
+    variables: Vec<(String, String)>, // The endpoint's variables, by name
+    original: (String, String), // Query and headers as stored, to tell whether they changed
+    current_field: usize,
+}
+
+impl OverridesScreen {
+    pub fn new(app: &AppState, collection_index: usize, endpoint_index: usize) -> Self {
+        let endpoint = app.collections.get(collection_index).and_then(|c| c.endpoints.get(endpoint_index));
+        let query = endpoint.and_then(|e| e.url.split_once('?')).map(|(_, query)| query.to_string()).unwrap_or_default();
+        let headers = endpoint.map(|e| load_test::format_headers(&e.headers)).unwrap_or_default();
+        let available = app.request_variables();
+        let variables = endpoint
+            .map(AppState::endpoint_variables)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|name| {
+                let value = available.get(&name).cloned().unwrap_or_default();
+                (name, value)
+            })
+            .collect();
+        Self {
+            collection_index,
+            endpoint_index,
+            original: (query.clone(), headers.clone()),
+            query,
+            headers,
+            variables,
+            current_field: QUERY,
+        }
+    }
+
+    fn field_count(&self) -> usize {
+        HEADERS + 1 + self.variables.len()
+    }
+
+    fn field_mut(&mut self) -> &mut String {
+        match self.current_field {
+            QUERY => &mut self.query,
+            HEADERS => &mut self.headers,
+            i => &mut self.variables[i - HEADERS - 1].1,
+        }
+    }
+
+    /// The changes to send with, or why they can't be used
+    pub fn overrides(&self) -> Result<RequestOverrides, String> {
+        let headers = if self.headers == self.original.1 {
+            None
+        } else {
+            Some(load_test::parse_headers(&self.headers)?)
+        };
+        let query = Some(self.query.trim().to_string()).filter(|_| self.query != self.original.0);
+        Ok(RequestOverrides { query, headers, ..Default::default() })
+    }
+
+    /// Send once with the overrides; the endpoint itself isn't saved
+    fn send(&self, app: &mut AppState) -> bool {
+        let overrides = match self.overrides() {
+            Ok(overrides) => overrides,
+            Err(e) => {
+                app.error_message = Some(e);
+                return false;
+            }
+        };
+        let variables: HashMap<String, String> = self.variables.iter().cloned().collect();
+        app.send_with_overrides(self.collection_index, self.endpoint_index, variables, overrides);
+        true
+    }
+}
+
+impl ScreenView for OverridesScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Back => return Transition::Close,
+            // Stay open on invalid headers so they can be fixed
+            Action::Submit if self.send(app) => return Transition::Close,
+            Action::InsertChar(c) => self.field_mut().push(c),
+            Action::DeleteChar => { self.field_mut().pop(); }
+            Action::NextField | Action::Down => self.current_field = (self.current_field + 1) % self.field_count(),
+            Action::PrevField | Action::Up => self.current_field = (self.current_field + self.field_count() - 1) % self.field_count(),
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(endpoint) = app.collections.get(self.collection_index).and_then(|c| c.endpoints.get(self.endpoint_index)) else { return };
+        let dim = Style::default().fg(Color::DarkGray);
+        let field = |i: usize, label: &str, value: &str| {
+            let (style, cursor) = if i == self.current_field {
+                (Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD), "_")
+            } else {
+                (Style::default().fg(Color::White), "")
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<18}", label), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{}{}", value, cursor), style),
+            ])
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled(format!("{} {}", endpoint.method, endpoint.url), dim)),
+            Line::from(""),
+            field(QUERY, app.tr("overrides.query"), &self.query),
+            field(HEADERS, app.tr("overrides.headers"), &self.headers),
+        ];
+        if !self.variables.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(app.tr("overrides.variables"), Style::default().add_modifier(Modifier::BOLD))));
+            for (i, (name, value)) in self.variables.iter().enumerate() {
+                lines.push(field(HEADERS + 1 + i, &format!("  {}:", name), value));
+            }
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(app.tr("overrides.hint"), dim)));
+        lines.push(Line::from(Span::styled(app.tr("overrides.not_saved"), dim)));
+
+        let width = area.width.saturating_sub(4).min(90);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let dialog = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default()
+                .title(app.tr_with("overrides.title", &[("name", &endpoint.name)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(Clear, dialog);
+        f.render_widget(paragraph, dialog);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui::harness::Harness;
+    use std::io::{Read, Write};
+
+    /// Answers every request with the request's head, so tests can see what was sent
+    fn echo_server() -> u16 {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for mut socket in listener.incoming().flatten() {
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).unwrap_or(0);
+                let head = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", head.len(), head);
+                let _ = socket.write_all(response.as_bytes());
+            }
+        });
+        port
+    }
+
+    #[test]
+    fn test_send_once_with_overrides() {
+        let port = echo_server();
+        let mut collection = ApiCollection::new("Shop".to_string());
+        let mut endpoint = ApiEndpoint::new("items".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/items/{{{{id}}}}?page=1", port));
+        endpoint.headers.insert("Accept".to_string(), "application/json".to_string());
+        collection.add_endpoint(endpoint.clone());
+        let mut harness = Harness::new().with_collection(collection);
+
+        harness.keys(&["Tab", "o"]);
+        let screen = harness.render();
+        assert!(screen.contains("Send once: items"), "{}", screen);
+        assert!(screen.contains("page=1_"), "{}", screen);
+        assert!(screen.contains("Accept: application/json"), "{}", screen);
+        assert!(screen.contains("id:"), "{}", screen);
+
+        // Invalid headers keep the form open
+        harness.keys(&["Tab"]).type_text("; oops");
+        harness.press("Enter");
+        assert!(harness.app.error_message.as_deref().unwrap().contains("Name: value"));
+        for _ in 0.."; oops".len() {
+            harness.press("Backspace");
+        }
+
+        harness.type_text("; X-Debug: 1").keys(&["Shift+Tab", "Backspace"]).type_text("2&limit=5");
+        harness.keys(&["Tab", "Tab"]).type_text("42").press("Enter");
+        assert!(harness.app.view.is_none());
+        let body = String::from_utf8_lossy(&harness.app.last_response.as_ref().unwrap().body).into_owned();
+        assert!(body.starts_with("get /items/42?page=2&limit=5 "), "{}", body);
+        assert!(body.contains("x-debug: 1") && body.contains("accept: application/json"), "{}", body);
+
+        // Only that send used them
+        assert_eq!(harness.app.collections[0].endpoints[0], endpoint);
+    }
+
+    #[test]
+    fn test_unchanged_fields_are_not_overridden() {
+        let mut collection = ApiCollection::new("Shop".to_string());
+        let mut endpoint = ApiEndpoint::new("items".to_string(), HttpMethod::GET, "https://api.example.com/items?q=a;b".to_string());
+        endpoint.headers.insert("Content-Type".to_string(), "text/plain; charset=utf-8".to_string());
+        collection.add_endpoint(endpoint);
+        let harness = Harness::new().with_collection(collection);
+
+        let mut screen = OverridesScreen::new(&harness.app, 0, 0);
+        assert_eq!(screen.overrides(), Ok(RequestOverrides::default()));
+        screen.query = String::new();
+        assert_eq!(screen.overrides(), Ok(RequestOverrides { query: Some(String::new()), ..Default::default() }));
+
+        let mut endpoint = harness.app.collections[0].endpoints[0].clone();
+        screen.overrides().unwrap().apply(&mut endpoint);
+        assert_eq!(endpoint.url, "https://api.example.com/items");
+    }
+}
@@ -0,0 +1,250 @@
+// Past load test results
+// Every run saved in the results directory, newest first, with its headline
+// numbers. Enter re-opens a run's full report; `m` marks a run as the baseline
+// and `c` compares the selected one against it. Esc comes back to the list.
+
+use super::{LoadTestCompareScreen, LoadTestReportScreen, ScreenView, Transition};
+use crate::load_test::{LoadTestReport, RunStatus};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use crate::tui::ui_utils::fmt_duration;
+use chrono::Local;
+use std::time::Duration;
+use uuid::Uuid;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+pub struct PastResultsScreen {
+    reports: Vec<LoadTestReport>, // Newest first
+    selected: usize,
+    baseline: Option<Uuid>, // Run marked with `m` to compare others against
+    open: Option<Box<dyn ScreenView>>, // A run's report or a comparison
+}
+
+impl PastResultsScreen {
+    pub fn new(app: &mut AppState) -> Self {
+        let mut reports = app.storage.load_test_reports().unwrap_or_else(|e| {
+            app.error_message = Some(app.tr_with("error.read_load_test_results", &[("error", &e)]));
+            Vec::new()
+        });
+        reports.reverse();
+        Self { reports, selected: 0, baseline: None, open: None }
+    }
+
+    pub fn selected(&self) -> Option<&LoadTestReport> {
+        self.reports.get(self.selected)
+    }
+
+    fn row(&self, report: &LoadTestReport, app: &AppState) -> Vec<Span<'static>> {
+        let dim = Style::default().fg(Color::DarkGray);
+        let failed = report.failed_requests + report.assertion_failed_requests;
+        let failed_percent = if report.total_requests > 0 { failed as f64 / report.total_requests as f64 * 100.0 } else { 0.0 };
+        let (status, status_color) = match report.status {
+            RunStatus::Completed if failed == 0 => ("✓", Color::Green),
+            RunStatus::Completed => ("✗", Color::Red),
+            RunStatus::Running => ("…", Color::Yellow),
+            RunStatus::Interrupted => ("⚠", Color::Red),
+        };
+        let baseline = if self.baseline == Some(report.id) { "◆ " } else { "  " };
+        vec![
+            Span::styled(baseline, Style::default().fg(Color::Magenta)),
+            Span::styled(format!("{} ", status), Style::default().fg(status_color)),
+            Span::styled(format!("{} ", report.started_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")), dim),
+            Span::raw(format!("{:<28} ", truncate(&format!("{} {}", report.target.method, report.target.endpoint_name), 28))),
+            Span::raw(format!("{:>5}×{:<4}", report.concurrency, format!("{:.0}s", report.duration_secs))),
+            Span::raw(format!("{:>9} {} ", report.total_requests, app.tr("past_results.req"))),
+            Span::styled(
+                format!("{:>6.1}% {} ", failed_percent, app.tr("past_results.failed")),
+                Style::default().fg(if failed > 0 { Color::Red } else { Color::Green }),
+            ),
+            Span::raw(format!("{:>8.1} {} ", report.avg_rps, app.tr("past_results.rps"))),
+            Span::raw(format!("p95 {}", fmt_duration(Duration::from_secs_f64(report.latency_ms.p95.max(0.0) / 1000.0), app.settings.latency_decimals))),
+        ]
+    }
+}
+
+impl PastResultsScreen {
+    /// Open the selected run against the baseline
+    fn compare(&mut self, app: &mut AppState) {
+        let baseline = self.baseline.and_then(|id| self.reports.iter().find(|r| r.id == id));
+        match (baseline, self.selected()) {
+            (Some(baseline), Some(selected)) if baseline.id != selected.id => {
+                self.open = Some(Box::new(LoadTestCompareScreen::new(baseline.clone(), selected.clone())));
+            }
+            (Some(_), _) => app.error_message = Some(app.tr("past_results.select_another").to_string()),
+            (None, _) => app.error_message = Some(app.tr("past_results.mark_baseline").to_string()),
+        }
+    }
+}
+
+/// At most `width` characters, with an ellipsis when cut
+pub(crate) fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(width - 1).collect();
+    format!("{}…", kept)
+}
+
+impl ScreenView for PastResultsScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        if let Some(open) = &mut self.open {
+            if open.handle_action(app, action) == Transition::Close {
+                self.open = None;
+            }
+            return Transition::Stay;
+        }
+        match action {
+            Action::Up => self.selected = self.selected.saturating_sub(1),
+            Action::Down => self.selected = (self.selected + 1).min(self.reports.len().saturating_sub(1)),
+            Action::Submit => {
+                if let Some(report) = self.selected() {
+                    self.open = Some(Box::new(LoadTestReportScreen::new(report.clone())));
+                }
+            }
+            Action::InsertChar('m') => {
+                let selected = self.selected().map(|r| r.id);
+                self.baseline = if self.baseline == selected { None } else { selected };
+            }
+            Action::InsertChar('c') => self.compare(app),
+            Action::InsertChar('q') | Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        if let Some(open) = &self.open {
+            open.draw(f, area, app);
+            return;
+        }
+        let height = area.height.saturating_sub(2) as usize;
+        let start = (self.selected + 1).saturating_sub(height);
+        let lines: Vec<Line> = if self.reports.is_empty() {
+            vec![Line::from(Span::styled(
+                app.tr_with("past_results.empty", &[("path", &app.storage.results_dir().display())]),
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            self.reports.iter().enumerate().skip(start).take(height).map(|(i, report)| {
+                let line = Line::from(self.row(report, app));
+                if i == self.selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            }).collect()
+        };
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr_with("past_results.title", &[("count", &self.reports.len())]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Magenta)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_test::{LoadTestConfig, LoadTestMetrics, LoadTestTarget};
+    use crate::tui::harness::Harness;
+    use std::time::Duration;
+
+    fn report(name: &str, failures: u64, minutes_ago: i64) -> LoadTestReport {
+        let target = LoadTestTarget {
+            collection_id: uuid::Uuid::new_v4(),
+            endpoint_id: uuid::Uuid::new_v4(),
+            endpoint_name: name.to_string(),
+            method: "GET".to_string(),
+            url: "https://api.example.com/items".to_string(),
+        };
+        let mut metrics = LoadTestMetrics::new();
+        for _ in 0..10 {
+            metrics.record_success(Duration::from_millis(20));
+        }
+        for _ in 0..failures {
+            metrics.record_failure("Timeout".to_string(), Duration::from_millis(500));
+        }
+        let mut report = LoadTestReport::new(target, &LoadTestConfig::new(5, Duration::from_secs(10)), &metrics, Duration::from_secs(10));
+        report.started_at -= chrono::Duration::minutes(minutes_ago);
+        report
+    }
+
+    #[test]
+    fn test_browse_and_reopen_runs() {
+        let mut harness = Harness::new();
+        assert!(harness.app.storage.load_test_reports().unwrap().is_empty());
+        for report in [report("list items", 0, 60), report("get item", 5, 5)] {
+            harness.app.storage.save_load_test_report(&report).unwrap();
+        }
+
+        let screen = PastResultsScreen::new(&mut harness.app);
+        harness.app.open_view(Box::new(screen));
+        let screen = harness.render();
+        assert!(screen.contains("Past Load Test Results (2)"));
+        let get = screen.find("GET get item").unwrap();
+        let list = screen.find("GET list items").unwrap();
+        assert!(get < list, "newest first:\n{}", screen);
+        assert!(screen.contains("33.3% failed"), "{}", screen);
+        // p95 follows the latency precision setting
+        let list = PastResultsScreen::new(&mut harness.app);
+        let mut row = |decimals| -> String {
+            harness.app.settings.latency_decimals = decimals;
+            list.row(&list.reports[1], &harness.app).iter().map(|span| span.content.as_ref()).collect()
+        };
+        let (whole, hundredths) = (row(0), row(2));
+        assert!(whole.ends_with("p95 20ms"), "{}", whole);
+        assert!(hundredths.ends_with("p95 20.00ms"), "{}", hundredths);
+
+        // Enter opens the full report; Esc comes back to the list
+        harness.keys(&["Down", "Enter"]);
+        let screen = harness.render();
+        assert!(screen.contains("Load Test Results"), "{}", screen);
+        assert!(screen.contains("list items"));
+        harness.press("Esc");
+        assert!(harness.render().contains("Past Load Test Results (2)"));
+        harness.press("Esc");
+        assert!(harness.app.view.is_none());
+    }
+
+    #[test]
+    fn test_compare_against_baseline() {
+        let mut harness = Harness::new();
+        let (before, after) = (report("list items", 0, 60), report("list items", 5, 5));
+        for report in [&before, &after] {
+            harness.app.storage.save_load_test_report(report).unwrap();
+        }
+        let screen = PastResultsScreen::new(&mut harness.app);
+        harness.app.open_view(Box::new(screen));
+
+        harness.press("c");
+        assert_eq!(harness.app.error_message.as_deref(), Some("Mark a baseline run with m first"));
+        harness.press("m");
+        assert!(harness.render().contains("◆"));
+        harness.press("c");
+        assert_eq!(harness.app.error_message.as_deref(), Some("Select another run to compare with the baseline"));
+
+        // The older run is the baseline even when the newer one was marked
+        harness.keys(&["Down", "c"]);
+        let screen = harness.render();
+        assert!(screen.contains("Compare Load Test Runs"), "{}", screen);
+        let error_rate = screen.lines().find(|l| l.contains("Error rate")).unwrap();
+        assert!(error_rate.contains("0.0%") && error_rate.contains("33.3%") && error_rate.contains("▲"), "{}", error_rate);
+        let p50 = screen.lines().find(|l| l.contains("Latency p50")).unwrap();
+        assert!(p50.contains("="), "{}", p50);
+        harness.press("Esc");
+        assert!(harness.render().contains("Past Load Test Results (2)"));
+    }
+}
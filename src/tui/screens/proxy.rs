@@ -0,0 +1,309 @@
+// Proxy settings screen
+// Edits proxy.toml: the workspace proxy and the selected collection's own.
+// Enter saves and rebuilds the clients; requests already in flight finish on
+// the old ones.
+
+use super::{ScreenView, Transition};
+use crate::proxy::{ProxyConfig, ProxySettings};
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, FormInput, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+use uuid::Uuid;
+
+/// Fields in Tab order; the collection's mode (field 2) is cycled with Space
+const URL: usize = 0;
+const NO_PROXY: usize = 1;
+const MODE: usize = 2;
+const COLLECTION_URL: usize = 3;
+const COLLECTION_NO_PROXY: usize = 4;
+
+/// How a collection's requests pick their proxy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionProxy {
+    Global, // Whatever the workspace or environment uses
+    Direct, // No proxy at all
+    Custom,
+}
+
+impl CollectionProxy {
+    fn next(self) -> Self {
+        match self {
+            CollectionProxy::Global => CollectionProxy::Direct,
+            CollectionProxy::Direct => CollectionProxy::Custom,
+            CollectionProxy::Custom => CollectionProxy::Global,
+        }
+    }
+
+    fn label(self, app: &AppState) -> &'static str {
+        match self {
+            CollectionProxy::Global => app.tr("proxy.global"),
+            CollectionProxy::Direct => app.tr("proxy.direct"),
+            CollectionProxy::Custom => app.tr("proxy.custom"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProxyForm {
+    pub url: String, // Empty = connect directly
+    pub no_proxy: String, // Comma-separated
+    pub collection: Option<(Uuid, String)>, // The collection selected when opened
+    pub mode: CollectionProxy,
+    pub collection_url: String,
+    pub collection_no_proxy: String,
+    pub current_field: usize,
+}
+
+fn host_list(text: &str) -> Vec<String> {
+    text.split(',').map(str::trim).filter(|h| !h.is_empty()).map(str::to_string).collect()
+}
+
+fn url_or_none(text: &str) -> Option<String> {
+    Some(text.trim().to_string()).filter(|u| !u.is_empty())
+}
+
+impl ProxyForm {
+    pub fn new(proxies: &ProxyConfig, collection: Option<(Uuid, String)>) -> Self {
+        let own = collection.as_ref().and_then(|(id, _)| proxies.for_collection(*id));
+        let mode = match own {
+            None => CollectionProxy::Global,
+            Some(ProxySettings { url: None, .. }) => CollectionProxy::Direct,
+            Some(_) => CollectionProxy::Custom,
+        };
+        Self {
+            url: proxies.global.url.clone().unwrap_or_default(),
+            no_proxy: proxies.global.no_proxy.join(", "),
+            collection,
+            mode,
+            collection_url: own.and_then(|p| p.url.clone()).unwrap_or_default(),
+            collection_no_proxy: own.map(|p| p.no_proxy.join(", ")).unwrap_or_default(),
+            current_field: URL,
+        }
+    }
+
+    /// The app's proxies with this form's changes; other collections keep theirs
+    pub fn to_config(&self, app: &AppState) -> Result<ProxyConfig, String> {
+        let mut config = app.proxies.clone();
+        config.global = ProxySettings { url: url_or_none(&self.url), no_proxy: host_list(&self.no_proxy) };
+        if let Some((id, name)) = &self.collection {
+            match self.mode {
+                CollectionProxy::Global => {
+                    config.collections.remove(id);
+                }
+                CollectionProxy::Direct => {
+                    config.collections.insert(*id, ProxySettings::default());
+                }
+                CollectionProxy::Custom => {
+                    let url = url_or_none(&self.collection_url)
+                        .ok_or_else(|| app.tr_with("proxy.url_needed", &[("name", name)]))?;
+                    config.collections.insert(*id, ProxySettings { url: Some(url), no_proxy: host_list(&self.collection_no_proxy) });
+                }
+            }
+        }
+        config.validate().map_err(|e| e.to_string())?;
+        Ok(config)
+    }
+}
+
+impl FormInput for ProxyForm {
+    fn field_mut(&mut self) -> Option<&mut String> {
+        match self.current_field {
+            URL => Some(&mut self.url),
+            NO_PROXY => Some(&mut self.no_proxy),
+            COLLECTION_URL => Some(&mut self.collection_url),
+            COLLECTION_NO_PROXY => Some(&mut self.collection_no_proxy),
+            _ => None,
+        }
+    }
+
+    fn current_field_mut(&mut self) -> Option<&mut usize> {
+        Some(&mut self.current_field)
+    }
+
+    fn field_count(&self) -> usize {
+        match (&self.collection, self.mode) {
+            (None, _) => 2,
+            (Some(_), CollectionProxy::Custom) => 5,
+            (Some(_), _) => 3,
+        }
+    }
+
+    fn insert_char(&mut self, c: char) {
+        if self.current_field == MODE {
+            if c == ' ' {
+                self.mode = self.mode.next();
+            }
+        } else if let Some(field) = self.field_mut() {
+            field.push(c);
+        }
+    }
+}
+
+pub struct ProxyScreen {
+    pub form: ProxyForm,
+}
+
+impl ProxyScreen {
+    /// Edit the workspace proxy and that of the selected collection, if any
+    pub fn new(app: &AppState) -> Self {
+        let collection = app.collections.get(app.selection.collection).map(|c| (c.id, c.name.clone()));
+        Self { form: ProxyForm::new(&app.proxies, collection) }
+    }
+}
+
+impl ScreenView for ProxyScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Back => return Transition::Close,
+            // Stay open on errors so the values can be fixed
+            Action::Submit => match self.form.to_config(app) {
+                Ok(proxies) => {
+                    if app.apply_proxies(proxies) {
+                        return Transition::Close;
+                    }
+                }
+                Err(e) => app.error_message = Some(e),
+            },
+            Action::InsertChar(c) => self.form.insert_char(c),
+            Action::DeleteChar => self.form.delete_char(),
+            Action::NextField => self.form.next_field(),
+            Action::PrevField => self.form.prev_field(),
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let form = &self.form;
+        let field_style = |field: usize| {
+            if form.current_field == field {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            }
+        };
+        let heading = |text: String| Line::from(Span::styled(text, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+        let hint = |text: &'static str| Line::from(Span::styled(text, Style::default().fg(Color::DarkGray)));
+        let field = |label: &'static str, field: usize, value: &str, empty: &'static str| {
+            let focused = form.current_field == field;
+            let shown = if value.is_empty() && !focused { empty } else { value };
+            Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Cyan)),
+                Span::styled(shown.to_string(), field_style(field)),
+                Span::styled(if focused { "_" } else { "" }, field_style(field).add_modifier(Modifier::SLOW_BLINK)),
+            ])
+        };
+
+        let mut text = vec![
+            Line::from(""),
+            heading(app.tr("proxy.workspace").to_string()),
+            field(app.tr("proxy.proxy"), URL, &form.url, app.tr("proxy.direct_value")),
+            hint(app.tr("proxy.url_hint")),
+            field(app.tr("proxy.no_proxy"), NO_PROXY, &form.no_proxy, app.tr("detail.none")),
+            hint(app.tr("proxy.no_proxy_hint")),
+            hint(app.tr("proxy.environment_hint")),
+        ];
+        match &form.collection {
+            None => text.extend([Line::from(""), hint(app.tr("proxy.no_collection"))]),
+            Some((_, name)) => {
+                text.extend([
+                    Line::from(""),
+                    heading(app.tr_with("proxy.collection", &[("name", name)])),
+                    Line::from(vec![
+                        Span::styled(app.tr("proxy.uses"), Style::default().fg(Color::Cyan)),
+                        Span::styled(form.mode.label(app), field_style(MODE)),
+                        Span::styled(app.tr("proxy.cycles"), Style::default().fg(Color::DarkGray)),
+                    ]),
+                ]);
+                if form.mode == CollectionProxy::Custom {
+                    text.extend([
+                        field(app.tr("proxy.proxy"), COLLECTION_URL, &form.collection_url, ""),
+                        field(app.tr("proxy.no_proxy"), COLLECTION_NO_PROXY, &form.collection_no_proxy, app.tr("detail.none")),
+                    ]);
+                }
+            }
+        }
+        text.extend([
+            Line::from(""),
+            hint(app.tr("proxy.websocket")),
+            hint(app.tr("proxy.saved_to")),
+            Line::from(Span::styled(format!("   {}", app.storage.proxy_path().display()), Style::default().fg(Color::DarkGray))),
+        ]);
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default()
+                .title(app.tr("proxy.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::HistoryStore;
+    use crate::models::ApiCollection;
+    use crate::storage::StorageManager;
+    use crate::variables::VariableManager;
+
+    #[test]
+    fn test_proxies_are_saved_and_applied() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let storage = StorageManager::new(temp_dir.path().join("collections"), temp_dir.path().join("results")).unwrap();
+        let variables = VariableManager::with_path(temp_dir.path().join("variables.json")).unwrap();
+        let history = HistoryStore::with_path(temp_dir.path().join("history.jsonl")).unwrap();
+        // An old config.toml proxy becomes the workspace proxy
+        std::fs::write(storage.config_path(), "proxy = \"http://old.proxy:8080\"\n").unwrap();
+        let mut app = AppState::with_storage(storage, variables, history).unwrap();
+        assert_eq!(app.proxies.global.url.as_deref(), Some("http://old.proxy:8080"));
+        assert_eq!(app.http_client.config().proxy.as_deref(), Some("http://old.proxy:8080"));
+        app.collections.push(ApiCollection::new("Internal".to_string()));
+        app.collections.push(ApiCollection::new("Public".to_string()));
+
+        let mut screen = ProxyScreen::new(&app);
+        let type_text = |screen: &mut ProxyScreen, app: &mut AppState, text: &str| {
+            for c in text.chars() {
+                screen.handle_action(app, Action::InsertChar(c));
+            }
+        };
+        screen.handle_action(&mut app, Action::NextField);
+        type_text(&mut screen, &mut app, "localhost, .corp.local");
+        screen.handle_action(&mut app, Action::NextField);
+        type_text(&mut screen, &mut app, "  ");
+        assert_eq!(screen.form.mode, CollectionProxy::Custom);
+        // A custom proxy needs a URL
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Stay);
+        assert!(app.error_message.as_deref().unwrap().starts_with("Enter a proxy URL for 'Internal'"));
+        screen.handle_action(&mut app, Action::NextField);
+        type_text(&mut screen, &mut app, "socks5://127.0.0.1:1080");
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Close);
+
+        assert_eq!(app.client_for(0).config().proxy.as_deref(), Some("socks5://127.0.0.1:1080"));
+        assert_eq!(app.client_for(1).config().proxy.as_deref(), Some("http://old.proxy:8080"));
+        assert_eq!(app.client_for(1).config().no_proxy, vec!["localhost", ".corp.local"]);
+        let saved = ProxyConfig::load(&app.storage.proxy_path()).unwrap();
+        assert_eq!(saved, app.proxies);
+
+        // Back to the workspace proxy drops the collection's entry
+        let mut screen = ProxyScreen::new(&app);
+        assert_eq!(screen.form.mode, CollectionProxy::Custom);
+        screen.form.current_field = MODE;
+        type_text(&mut screen, &mut app, " ");
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Close);
+        assert!(app.proxies.collections.is_empty());
+        assert_eq!(app.client_for(0).config().proxy.as_deref(), Some("http://old.proxy:8080"));
+    }
+}
@@ -0,0 +1,245 @@
+// Single-field editor for the selected endpoint
+// Pick the URL, the body or one header from the definition and change just
+// that, without going through the full endpoint form. `Name: value` adds a
+// header; an empty header value removes it.
+
+use super::{ScreenView, Transition};
+use crate::formatter;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Part of an endpoint the quick editor can change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EndpointField {
+    Url,
+    Body,
+    Header(String),
+    /// A header that doesn't exist yet, typed as `Name: value`
+    NewHeader,
+}
+
+impl EndpointField {
+    /// The field's name in the activity log
+    pub fn label(&self) -> String {
+        match self {
+            EndpointField::Url => "URL".to_string(),
+            EndpointField::Body => "Body".to_string(),
+            EndpointField::Header(name) => format!("Header {}", name),
+            EndpointField::NewHeader => "New header".to_string(),
+        }
+    }
+
+    /// The field's name on screen
+    pub fn name(&self, app: &AppState) -> String {
+        match self {
+            EndpointField::Url => app.tr("quick_edit.url").to_string(),
+            EndpointField::Body => app.tr("quick_edit.body").to_string(),
+            EndpointField::Header(name) => app.tr_with("quick_edit.header", &[("name", name)]),
+            EndpointField::NewHeader => app.tr("quick_edit.new_header").to_string(),
+        }
+    }
+}
+
+pub struct QuickEditScreen {
+    collection_index: usize,
+    endpoint_index: usize,
+    fields: Vec<EndpointField>,
+    selected: usize,
+    /// The text being edited once a field is picked
+    editing: Option<String>,
+}
+
+impl QuickEditScreen {
+    pub fn new(app: &AppState, collection_index: usize, endpoint_index: usize) -> Self {
+        let mut fields = vec![EndpointField::Url];
+        if let Some(endpoint) = app.collections.get(collection_index).and_then(|c| c.endpoints.get(endpoint_index)) {
+            let mut headers: Vec<_> = endpoint.headers.keys().cloned().collect();
+            headers.sort();
+            fields.extend(headers.into_iter().map(EndpointField::Header));
+        }
+        fields.push(EndpointField::NewHeader);
+        fields.push(EndpointField::Body);
+        Self { collection_index, endpoint_index, fields, selected: 0, editing: None }
+    }
+
+    /// Start editing a field with its current value
+    fn pick(&mut self, app: &AppState) {
+        let Some(endpoint) = app.collections.get(self.collection_index).and_then(|c| c.endpoints.get(self.endpoint_index)) else { return };
+        self.editing = Some(match &self.fields[self.selected] {
+            EndpointField::Url => endpoint.url.clone(),
+            EndpointField::Body => endpoint.body_template.clone().unwrap_or_default(),
+            EndpointField::Header(name) => endpoint.headers.get(name).cloned().unwrap_or_default(),
+            EndpointField::NewHeader => String::new(),
+        });
+    }
+}
+
+impl ScreenView for QuickEditScreen {
+    fn input_context(&self) -> InputContext {
+        if self.editing.is_some() { InputContext::Form } else { InputContext::Menu }
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let Some(text) = &mut self.editing else {
+            match action {
+                Action::Up => self.selected = self.selected.saturating_sub(1),
+                Action::Down => self.selected = (self.selected + 1).min(self.fields.len() - 1),
+                Action::Submit => self.pick(app),
+                Action::Back => return Transition::Close,
+                _ => {}
+            }
+            return Transition::Stay;
+        };
+        match action {
+            Action::InsertChar(c) => text.push(c),
+            Action::DeleteChar => { text.pop(); }
+            Action::FormatBody if self.fields[self.selected] == EndpointField::Body => {
+                match formatter::format_json_template(text) {
+                    Ok(formatted) => *text = formatted,
+                    Err(e) => app.error_message = Some(app.tr_with("quick_edit.cant_format", &[("error", &e)])),
+                }
+            }
+            Action::Submit => {
+                let field = self.fields[self.selected].clone();
+                if app.update_endpoint_field(self.collection_index, self.endpoint_index, &field, text) {
+                    return Transition::Close;
+                }
+            }
+            Action::Back => self.editing = None,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(endpoint) = app.collections.get(self.collection_index).and_then(|c| c.endpoints.get(self.endpoint_index)) else { return };
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines = Vec::new();
+        let hint = match &self.editing {
+            None => {
+                for (i, field) in self.fields.iter().enumerate() {
+                    let style = if i == self.selected {
+                        Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+                    } else {
+                        Style::default()
+                    };
+                    let current = match field {
+                        EndpointField::Url => endpoint.url.clone(),
+                        EndpointField::Body => endpoint.body_template.as_deref().unwrap_or(app.tr("quick_edit.none")).lines().next().unwrap_or_default().to_string(),
+                        EndpointField::Header(name) => endpoint.headers.get(name).cloned().unwrap_or_default(),
+                        EndpointField::NewHeader => String::new(),
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(format!(" {:<24} ", field.name(app)), style),
+                        Span::styled(current, dim),
+                    ]));
+                }
+                app.tr("quick_edit.keys")
+            }
+            Some(text) => {
+                lines.push(Line::from(Span::styled(self.fields[self.selected].name(app), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))));
+                lines.push(Line::from(""));
+                let mut text_lines: Vec<&str> = text.split('\n').collect();
+                let last = text_lines.pop().unwrap_or_default();
+                lines.extend(text_lines.into_iter().map(|line| Line::from(Span::styled(line.to_string(), Style::default().fg(Color::Yellow)))));
+                lines.push(Line::from(vec![
+                    Span::styled(last.to_string(), Style::default().fg(Color::Yellow)),
+                    Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+                ]));
+                app.tr(match self.fields[self.selected] {
+                    EndpointField::NewHeader => "quick_edit.new_header_keys",
+                    EndpointField::Header(_) => "quick_edit.header_keys",
+                    EndpointField::Body => "quick_edit.body_keys",
+                    EndpointField::Url => "quick_edit.url_keys",
+                })
+            }
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(hint, dim)));
+
+        let width = area.width.saturating_sub(4).min(90);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let dialog = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default()
+                .title(app.tr_with("quick_edit.title", &[("name", &endpoint.name)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(Clear, dialog);
+        f.render_widget(paragraph, dialog);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+
+    fn type_text(screen: &mut dyn ScreenView, app: &mut AppState, text: &str) {
+        for c in text.chars() {
+            screen.handle_action(app, Action::InsertChar(c));
+        }
+    }
+
+    #[test]
+    fn test_edit_one_field_and_save() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        let mut endpoint = ApiEndpoint::new("users".to_string(), HttpMethod::GET, "https://api/v1/users".to_string());
+        endpoint.headers.insert("Accept".to_string(), "text/plain".to_string());
+        collection.add_endpoint(endpoint);
+        app.collections.push(collection);
+
+        // URL: pick, replace, save
+        let mut screen = QuickEditScreen::new(&app, 0, 0);
+        assert_eq!(screen.fields, vec![EndpointField::Url, EndpointField::Header("Accept".to_string()), EndpointField::NewHeader, EndpointField::Body]);
+        screen.handle_action(&mut app, Action::Submit);
+        assert_eq!(screen.input_context(), InputContext::Form);
+        for _ in 0.."users".len() + 3 {
+            screen.handle_action(&mut app, Action::DeleteChar);
+        }
+        type_text(&mut screen, &mut app, "v2/users");
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Close);
+        assert_eq!(app.collections[0].endpoints[0].url, "https://api/v2/users");
+
+        // A new header needs a name
+        let mut screen = QuickEditScreen::new(&app, 0, 0);
+        screen.selected = 2;
+        screen.handle_action(&mut app, Action::Submit);
+        type_text(&mut screen, &mut app, "no colon");
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Stay);
+        assert!(app.error_message.as_deref().unwrap().contains("Name: value"));
+        screen.editing = Some(String::new());
+        type_text(&mut screen, &mut app, "X-Trace: on");
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Close);
+
+        // Emptying a header removes it
+        let mut screen = QuickEditScreen::new(&app, 0, 0);
+        screen.selected = 1;
+        screen.handle_action(&mut app, Action::Submit);
+        screen.editing = Some(String::new());
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Close);
+
+        let saved = app.storage.load_collections().unwrap();
+        let endpoint = &saved[0].endpoints[0];
+        assert_eq!(endpoint.url, "https://api/v2/users");
+        assert_eq!(endpoint.headers.get("X-Trace").map(String::as_str), Some("on"));
+        assert!(!endpoint.headers.contains_key("Accept"));
+    }
+}
@@ -0,0 +1,141 @@
+// Links in the last response
+// Lists the URLs the response points at (`Link` and `Location` headers, HAL
+// and JSON:API links, absolute URLs in the body) and sends a GET to the one
+// picked, with the selected endpoint's auth and headers, to walk a hypermedia
+// API without creating an endpoint per page.
+
+use super::{ScreenView, Transition};
+use crate::links::ResponseLink;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+pub struct ResponseLinksScreen {
+    collection_index: usize,
+    endpoint_index: usize,
+    links: Vec<ResponseLink>,
+    selected: usize,
+}
+
+impl ResponseLinksScreen {
+    /// Links to follow with the auth and headers of the given endpoint
+    pub fn new(collection_index: usize, endpoint_index: usize, links: Vec<ResponseLink>) -> Self {
+        Self { collection_index, endpoint_index, links, selected: 0 }
+    }
+}
+
+impl ScreenView for ResponseLinksScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.selected = self.selected.saturating_sub(1),
+            Action::Down => self.selected = (self.selected + 1).min(self.links.len().saturating_sub(1)),
+            Action::Submit => {
+                if let Some(link) = self.links.get(self.selected) {
+                    app.follow_link(self.collection_index, self.endpoint_index, link.url.clone());
+                    return Transition::Close;
+                }
+            }
+            Action::InsertChar('q') | Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let dim = Style::default().fg(Color::DarkGray);
+        let via = app.collections.get(self.collection_index)
+            .and_then(|c| c.endpoints.get(self.endpoint_index))
+            .map(|e| e.name.clone())
+            .unwrap_or_default();
+        let width = self.links.iter().map(|l| l.source.chars().count()).max().unwrap_or(0).min(30);
+        let height = area.height.saturating_sub(4) as usize;
+        let start = (self.selected + 1).saturating_sub(height);
+        let mut lines: Vec<Line> = self.links.iter().enumerate().skip(start).take(height).map(|(i, link)| {
+            let line = Line::from(vec![
+                Span::styled(format!(" {:<width$}  ", link.source, width = width), Style::default().fg(Color::Cyan)),
+                Span::raw(link.url.clone()),
+            ]);
+            if i == self.selected {
+                line.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        }).collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(app.tr_with("response_links.sent_as", &[("endpoint", &via)]), dim)));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr_with("response_links.title", &[("count", &self.links.len())]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui::harness::Harness;
+    use std::io::{Read, Write};
+
+    /// `/orders` is a HAL page; anything else echoes the request head
+    fn hal_server() -> u16 {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for mut socket in listener.incoming().flatten() {
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).unwrap_or(0);
+                let head = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+                let body = if head.starts_with("get /orders ") {
+                    r#"{"_links": {"self": {"href": "/orders"}, "next": {"href": "/orders?page=2"}}, "total": 40}"#.to_string()
+                } else {
+                    head
+                };
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", body.len(), body);
+                let _ = socket.write_all(response.as_bytes());
+            }
+        });
+        port
+    }
+
+    #[test]
+    fn test_follow_a_link_with_the_endpoints_headers() {
+        let port = hal_server();
+        let mut collection = ApiCollection::new("Shop".to_string());
+        let mut endpoint = ApiEndpoint::new("orders".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/orders", port));
+        endpoint.headers.insert("X-Api-Key".to_string(), "secret".to_string());
+        collection.add_endpoint(endpoint);
+        let mut harness = Harness::new().with_collection(collection);
+
+        harness.keys(&["Tab", "x", "b"]);
+        let screen = harness.render();
+        assert!(screen.contains("Response Links (2)"), "{}", screen);
+        assert!(screen.contains(&format!("$._links.next.href  http://127.0.0.1:{}/orders?page=2", port)), "{}", screen);
+
+        harness.press("Enter");
+        assert!(harness.app.view.is_none());
+        let body = String::from_utf8_lossy(&harness.app.last_response.as_ref().unwrap().body).into_owned();
+        assert!(body.starts_with("get /orders?page=2 "), "{}", body);
+        assert!(body.contains("x-api-key: secret"), "{}", body);
+        assert_eq!(harness.app.status_message, Some(format!("Followed GET http://127.0.0.1:{}/orders?page=2", port)));
+        assert_eq!(harness.app.history.entries().len(), 1, "only the endpoint's own request is in its history");
+
+        // Nothing to follow in that response
+        harness.press("b");
+        assert!(harness.app.view.is_none());
+        assert_eq!(harness.app.status_message.as_deref(), Some("No links in this response"));
+    }
+}
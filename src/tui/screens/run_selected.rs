@@ -0,0 +1,276 @@
+// Parallel run of the marked endpoints
+// Every marked endpoint is sent at once on the app's background runtime. Rows fill in
+// with status and latency as responses arrive and each result goes to history.
+
+use super::{ScreenView, Transition};
+use crate::tui::ui_utils::fmt_duration;
+use crate::http::RequestInputs;
+use crate::tui::app::AppState;
+use crate::tui::input::Action;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// An endpoint index with its saved variables, or why they couldn't be resolved
+pub type RunJob = (usize, std::result::Result<HashMap<String, String>, String>);
+
+/// How one request of the run ended
+#[derive(Debug, Clone)]
+pub struct RunOutcome {
+    pub status: Option<u16>,
+    pub duration: Duration,
+    pub error: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl RunOutcome {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && self.status.is_some_and(|s| (200..400).contains(&s))
+    }
+}
+
+struct Row {
+    endpoint_index: usize,
+    outcome: Option<RunOutcome>,
+}
+
+pub struct RunSelectedScreen {
+    collection_index: usize,
+    rows: Vec<Row>,
+    results: Arc<Mutex<Vec<Option<RunOutcome>>>>, // Filled in by the background tasks
+    requests: Vec<u64>, // In flight on the app's runtime; closing the screen cancels them
+    started: Instant,
+    finished: Option<Duration>,
+    selected: usize,
+}
+
+impl RunSelectedScreen {
+    /// Start sending; a job whose variables couldn't be resolved fails without a request
+    pub fn start(app: &mut AppState, collection_index: usize, jobs: Vec<RunJob>) -> Self {
+        let results = Arc::new(Mutex::new(vec![None; jobs.len()]));
+        let client = app.client_for(collection_index).clone();
+        let mut requests = Vec::new();
+        for (slot, (endpoint_index, variables)) in jobs.iter().enumerate() {
+            let variables = match variables {
+                Ok(variables) => variables.clone(),
+                Err(e) => {
+                    results.lock().unwrap()[slot] = Some(RunOutcome {
+                        status: None,
+                        duration: Duration::ZERO,
+                        error: Some(e.clone()),
+                        body: Vec::new(),
+                    });
+                    continue;
+                }
+            };
+            let endpoint = app.collections[collection_index].endpoints[*endpoint_index].clone();
+            let label = format!("{} {}", endpoint.method, endpoint.name);
+            let (client, shared) = (client.clone(), results.clone());
+            requests.push(app.spawn_view_request(collection_index, *endpoint_index, label, async move {
+                let inputs = RequestInputs { variables, ..Default::default() };
+                let started = Instant::now();
+                let outcome = match client.execute(&endpoint, &inputs).await {
+                    Ok(response) => RunOutcome {
+                        status: Some(response.status.as_u16()),
+                        duration: response.duration,
+                        error: None,
+                        body: response.body,
+                    },
+                    Err(e) => RunOutcome {
+                        status: None,
+                        duration: started.elapsed(),
+                        error: Some(e.to_string()),
+                        body: Vec::new(),
+                    },
+                };
+                shared.lock().unwrap()[slot] = Some(outcome);
+            }));
+        }
+
+        Self {
+            collection_index,
+            rows: jobs.into_iter().map(|(endpoint_index, _)| Row { endpoint_index, outcome: None }).collect(),
+            results,
+            requests,
+            started: Instant::now(),
+            finished: None,
+            selected: 0,
+        }
+    }
+
+    fn completed(&self) -> usize {
+        self.rows.iter().filter(|row| row.outcome.is_some()).count()
+    }
+
+    fn passed(&self) -> usize {
+        self.rows.iter().filter(|row| row.outcome.as_ref().is_some_and(RunOutcome::is_success)).count()
+    }
+}
+
+impl ScreenView for RunSelectedScreen {
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Up => self.selected = self.selected.saturating_sub(1),
+            Action::Down => self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1)),
+            Action::Quit => return Transition::Quit,
+            Action::Back => {
+                // Responses still outstanding are never shown or recorded
+                for &id in &self.requests {
+                    app.cancel_request(id);
+                }
+                return Transition::Close;
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn is_animating(&self) -> bool {
+        self.finished.is_none()
+    }
+
+    /// Move finished results into the rows and record them in history
+    fn tick(&mut self, app: &mut AppState) {
+        let results = self.results.lock().unwrap().clone();
+        for (row, result) in self.rows.iter_mut().zip(results) {
+            if let (None, Some(outcome)) = (&row.outcome, result) {
+                app.record_history(
+                    self.collection_index,
+                    row.endpoint_index,
+                    outcome.status,
+                    outcome.duration,
+                    outcome.error.clone(),
+                    &outcome.body,
+                );
+                row.outcome = Some(outcome);
+            }
+        }
+        if self.finished.is_none() && self.completed() == self.rows.len() {
+            self.finished = Some(self.started.elapsed());
+            let failed = self.rows.len() - self.passed();
+            app.status_message = Some(app.tr_with("run_selected.ran", &[
+                ("count", &self.rows.len()), ("passed", &self.passed()), ("failed", &failed),
+            ]));
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let Some(collection) = app.collections.get(self.collection_index) else { return };
+        let dim = Style::default().fg(Color::DarkGray);
+
+        let progress = match self.finished {
+            Some(elapsed) => app.tr_with("run_selected.done", &[
+                ("duration", &fmt_duration(elapsed, app.settings.latency_decimals)),
+                ("passed", &self.passed()),
+                ("failed", &(self.rows.len() - self.passed())),
+            ]),
+            None => app.tr_with("run_selected.running", &[("completed", &self.completed()), ("total", &self.rows.len())]),
+        };
+        let mut lines = vec![
+            Line::from(Span::styled(progress, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from(Span::styled(format!("  {:<3} {:<8} {:<32} {:>6} {:>10}  {}", "", app.tr("common.method"), app.tr("common.name"), app.tr("common.status"), app.tr("run_selected.latency"), app.tr("run_selected.error")), dim)),
+        ];
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let Some(endpoint) = collection.endpoints.get(row.endpoint_index) else { continue };
+            let name: String = endpoint.name.chars().take(32).collect();
+            let (marker, color, status, latency, error) = match &row.outcome {
+                None => ("…", Color::DarkGray, "—".to_string(), "—".to_string(), String::new()),
+                Some(outcome) => (
+                    if outcome.is_success() { "✓" } else { "✗" },
+                    if outcome.is_success() { Color::Green } else { Color::Red },
+                    outcome.status.map(|s| s.to_string()).unwrap_or_else(|| app.tr("common.error").to_string()),
+                    fmt_duration(outcome.duration, app.settings.latency_decimals),
+                    outcome.error.clone().unwrap_or_default(),
+                ),
+            };
+            let style = if i == self.selected {
+                Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<3} ", marker), Style::default().fg(color)),
+                Span::styled(format!("{:<8} {:<32} {:>6} {:>10}  ", endpoint.method.to_string(), name, status, latency), style),
+                Span::styled(error, Style::default().fg(Color::Red)),
+            ]));
+        }
+
+        // Keep the selected row on screen
+        let visible = area.height.saturating_sub(5) as usize;
+        let scroll = self.selected.saturating_sub(visible.saturating_sub(1)) as u16;
+        let paragraph = Paragraph::new(lines)
+            .scroll((scroll, 0))
+            .block(Block::default()
+                .title(app.tr_with("run_selected.title", &[("name", &collection.name)]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_unresolved_variables_fail_without_a_request() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+
+        let mut collection = ApiCollection::new("A".to_string());
+        for name in ["one", "two"] {
+            collection.add_endpoint(ApiEndpoint::new(name.to_string(), HttpMethod::GET, "{{host}}/x".to_string()));
+        }
+        app.collections.push(collection);
+        app.toggle_endpoint_mark(0, 0);
+        app.toggle_endpoint_mark(0, 1);
+        app.toggle_endpoint_mark(0, 1);
+        assert_eq!(app.marked_in(0), vec![0]);
+
+        app.run_marked(0);
+        assert!(app.is_animating());
+        app.tick();
+        assert!(!app.is_animating());
+        assert_eq!(app.history.entries().len(), 1);
+        assert!(app.history.entries()[0].error.as_deref().unwrap().contains("host"));
+        assert_eq!(app.status_message.as_deref(), Some("Ran 1 endpoints in parallel: 0 passed, 1 failed"));
+    }
+
+    #[test]
+    fn test_closing_the_screen_cancels_requests_in_flight() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        // Accepts connections but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut collection = ApiCollection::new("A".to_string());
+        for name in ["one", "two"] {
+            collection.add_endpoint(ApiEndpoint::new(name.to_string(), HttpMethod::GET, format!("http://{}/x", listener.local_addr().unwrap())));
+        }
+        app.collections.push(collection);
+        app.toggle_endpoint_mark(0, 0);
+        app.toggle_endpoint_mark(0, 1);
+
+        app.run_marked(0);
+        assert_eq!(app.in_flight.iter().map(|r| r.label.as_str()).collect::<Vec<_>>(), vec!["GET one", "GET two"]);
+        app.tick();
+        assert_eq!(app.in_flight.len(), 2);
+
+        let mut view = app.view.take().unwrap();
+        assert!(matches!(view.handle_action(&mut app, Action::Back), Transition::Close));
+        assert!(app.in_flight.is_empty());
+        app.tick();
+        assert!(app.history.entries().is_empty());
+    }
+}
@@ -0,0 +1,378 @@
+// Workspace settings screen
+// Edits the defaults kept in config.toml; Enter saves and applies them
+// without a restart.
+
+use super::{ScreenView, Transition};
+use crate::http::format_bytes;
+use crate::i18n::Locale;
+use crate::settings::{LoadTestDefaults, RepeatSend, Retention, Settings, SizeBudgets, Theme};
+use crate::tui::app::AppState;
+use crate::tui::input::{number, optional_number, Action, FormInput, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Text fields, in Tab order; repeat sends, the theme and language (fields
+/// 3 to 5) are cycled with Space
+const TIMEOUT: usize = 0;
+const REDIRECTS: usize = 1;
+const ENVIRONMENT: usize = 2;
+const REPEAT_SEND: usize = 3;
+const THEME: usize = 4;
+const LOCALE: usize = 5;
+const TICK_RATE: usize = 6;
+const LATENCY_DECIMALS: usize = 7;
+const CONCURRENCY: usize = 8;
+const DURATION: usize = 9;
+const HISTORY: usize = 10;
+const HISTORY_AGE: usize = 11;
+const HISTORY_DISK: usize = 12;
+const RESULTS: usize = 13;
+const RESULTS_AGE: usize = 14;
+const RESULTS_DISK: usize = 15;
+const REQUEST_BUDGET: usize = 16;
+const RESPONSE_BUDGET: usize = 17;
+
+#[derive(Debug, Clone)]
+pub struct SettingsForm {
+    pub timeout: String,
+    pub redirects: String,
+    pub environment: String, // Empty = saved variables only
+    pub repeat_send: RepeatSend,
+    pub theme: Theme,
+    pub locale: Locale,
+    pub tick_rate: String,
+    pub latency_decimals: String,
+    pub concurrency: String,
+    pub duration: String,
+    pub history: String, // Retention limits: empty = no limit
+    pub history_age: String,
+    pub history_disk: String,
+    pub results: String,
+    pub results_age: String,
+    pub results_disk: String,
+    pub request_budget: String, // Size budgets in KB: empty = none
+    pub response_budget: String,
+    pub current_field: usize,
+}
+
+impl SettingsForm {
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            timeout: settings.timeout_secs.to_string(),
+            redirects: settings.max_redirects.to_string(),
+            environment: settings.environment.clone().unwrap_or_default(),
+            repeat_send: settings.repeat_send,
+            theme: settings.theme,
+            locale: settings.locale,
+            tick_rate: settings.tick_rate_ms.to_string(),
+            latency_decimals: settings.latency_decimals.to_string(),
+            concurrency: settings.load_test.concurrency.to_string(),
+            duration: settings.load_test.duration_secs.to_string(),
+            history: limit_text(settings.history.max_entries),
+            history_age: limit_text(settings.history.max_age_days),
+            history_disk: limit_text(settings.history.max_disk_mb),
+            results: limit_text(settings.results.max_entries),
+            results_age: limit_text(settings.results.max_age_days),
+            results_disk: limit_text(settings.results.max_disk_mb),
+            request_budget: limit_text(settings.budgets.request_kb),
+            response_budget: limit_text(settings.budgets.response_kb),
+            current_field: 0,
+        }
+    }
+
+    pub fn to_settings(&self, app: &AppState) -> Result<Settings, String> {
+        let settings = Settings {
+            timeout_secs: number(app, &self.timeout, "field.timeout")?,
+            max_redirects: number(app, &self.redirects, "field.redirects")?,
+            proxy: None,
+            environment: Some(self.environment.trim().to_string()).filter(|e| !e.is_empty()),
+            repeat_send: self.repeat_send,
+            theme: self.theme,
+            locale: self.locale,
+            tick_rate_ms: number(app, &self.tick_rate, "field.tick_rate")?,
+            latency_decimals: number(app, &self.latency_decimals, "field.latency_decimals")?,
+            load_test: LoadTestDefaults {
+                concurrency: number(app, &self.concurrency, "field.concurrency")?,
+                duration_secs: number(app, &self.duration, "field.duration")?,
+            },
+            history: Retention {
+                max_entries: optional_number(app, &self.history, "field.history_entries")?,
+                max_age_days: optional_number(app, &self.history_age, "field.history_age")?,
+                max_disk_mb: optional_number(app, &self.history_disk, "field.history_size")?,
+            },
+            results: Retention {
+                max_entries: optional_number(app, &self.results, "field.result_runs")?,
+                max_age_days: optional_number(app, &self.results_age, "field.result_age")?,
+                max_disk_mb: optional_number(app, &self.results_disk, "field.result_size")?,
+            },
+            budgets: SizeBudgets {
+                request_kb: optional_number(app, &self.request_budget, "field.request_budget")?,
+                response_kb: optional_number(app, &self.response_budget, "field.response_budget")?,
+            },
+        };
+        settings.validate().map_err(|e| e.to_string())?;
+        Ok(settings)
+    }
+}
+
+impl FormInput for SettingsForm {
+    fn field_mut(&mut self) -> Option<&mut String> {
+        match self.current_field {
+            TIMEOUT => Some(&mut self.timeout),
+            REDIRECTS => Some(&mut self.redirects),
+            ENVIRONMENT => Some(&mut self.environment),
+            TICK_RATE => Some(&mut self.tick_rate),
+            LATENCY_DECIMALS => Some(&mut self.latency_decimals),
+            CONCURRENCY => Some(&mut self.concurrency),
+            DURATION => Some(&mut self.duration),
+            HISTORY => Some(&mut self.history),
+            HISTORY_AGE => Some(&mut self.history_age),
+            HISTORY_DISK => Some(&mut self.history_disk),
+            RESULTS => Some(&mut self.results),
+            RESULTS_AGE => Some(&mut self.results_age),
+            RESULTS_DISK => Some(&mut self.results_disk),
+            REQUEST_BUDGET => Some(&mut self.request_budget),
+            RESPONSE_BUDGET => Some(&mut self.response_budget),
+            _ => None,
+        }
+    }
+
+    fn current_field_mut(&mut self) -> Option<&mut usize> {
+        Some(&mut self.current_field)
+    }
+
+    fn field_count(&self) -> usize {
+        18
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        self.current_field == ENVIRONMENT || c.is_ascii_digit()
+    }
+
+    fn insert_char(&mut self, c: char) {
+        if self.current_field == REPEAT_SEND {
+            if c == ' ' {
+                self.repeat_send = self.repeat_send.next();
+            }
+        } else if self.current_field == THEME {
+            if c == ' ' {
+                self.theme = self.theme.next();
+            }
+        } else if self.current_field == LOCALE {
+            if c == ' ' {
+                self.locale = self.locale.next();
+            }
+        } else if self.accepts(c) {
+            if let Some(field) = self.field_mut() {
+                field.push(c);
+            }
+        }
+    }
+}
+
+fn limit_text<T: ToString>(limit: Option<T>) -> String {
+    limit.map(|n| n.to_string()).unwrap_or_default()
+}
+
+pub struct SettingsScreen {
+    pub form: SettingsForm,
+    disk_usage: (u64, u64), // History, load test results; measured when opened
+}
+
+impl SettingsScreen {
+    pub fn new(app: &AppState) -> Self {
+        Self {
+            form: SettingsForm::new(&app.settings),
+            disk_usage: (app.history.disk_usage(), app.storage.results_disk_usage()),
+        }
+    }
+}
+
+impl ScreenView for SettingsScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::Back => return Transition::Close,
+            // Stay open on validation errors so the values can be fixed
+            Action::Submit => match self.form.to_settings(app) {
+                Ok(settings) => {
+                    if app.apply_settings(settings) {
+                        return Transition::Close;
+                    }
+                }
+                Err(e) => app.error_message = Some(e),
+            },
+            Action::InsertChar(c) => self.form.insert_char(c),
+            Action::DeleteChar => self.form.delete_char(),
+            Action::NextField => self.form.next_field(),
+            Action::PrevField => self.form.prev_field(),
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let form = &self.form;
+        let field_style = |field: usize| {
+            if form.current_field == field {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            }
+        };
+        let tr = |key| app.tr(key);
+        let heading = |key| Line::from(Span::styled(tr(key), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+        let hint = |key| Line::from(Span::styled(format!("      {}", tr(key)), Style::default().fg(Color::DarkGray)));
+        // The icon keeps its own spacing, since emoji differ in width
+        let field = |icon: &str, key, field: usize, value: &str, empty| {
+            let focused = form.current_field == field;
+            let shown = if value.is_empty() && !focused { tr(empty) } else { value };
+            Line::from(vec![
+                Span::styled(format!("   {}{}: ", icon, tr(key)), Style::default().fg(Color::Cyan)),
+                Span::styled(shown.to_string(), field_style(field)),
+                Span::styled(if focused { "_" } else { "" }, field_style(field).add_modifier(Modifier::SLOW_BLINK)),
+            ])
+        };
+
+        let text = vec![
+            Line::from(""),
+            heading("settings.requests"),
+            field("⏳ ", "settings.timeout", TIMEOUT, &form.timeout, ""),
+            field("↪️  ", "settings.redirects", REDIRECTS, &form.redirects, ""),
+            hint("settings.redirects_hint"),
+            field("🌍 ", "settings.environment", ENVIRONMENT, &form.environment, "settings.none"),
+            hint("settings.environment_hint"),
+            hint("settings.proxy_hint"),
+            Line::from(vec![
+                Span::styled(format!("   🔁 {}: ", tr("settings.repeat_send")), Style::default().fg(Color::Cyan)),
+                Span::styled(form.repeat_send.label(), field_style(REPEAT_SEND)),
+                Span::styled(format!("  {}", tr("settings.repeat_send_hint")), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(""),
+            heading("settings.display"),
+            Line::from(vec![
+                Span::styled(format!("   🌓 {}: ", tr("settings.theme")), Style::default().fg(Color::Cyan)),
+                Span::styled(form.theme.label(), field_style(THEME)),
+                Span::styled(format!("  {}", tr("settings.theme_hint")), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(format!("   🗣️  {}: ", tr("settings.language")), Style::default().fg(Color::Cyan)),
+                Span::styled(form.locale.label(), field_style(LOCALE)),
+                Span::styled(format!("  {}", tr("settings.language_hint")), Style::default().fg(Color::DarkGray)),
+            ]),
+            field("⏱️  ", "settings.tick_rate", TICK_RATE, &form.tick_rate, ""),
+            hint("settings.tick_rate_hint"),
+            field("🔢 ", "settings.latency_decimals", LATENCY_DECIMALS, &form.latency_decimals, ""),
+            hint("settings.latency_decimals_hint"),
+            Line::from(""),
+            heading("settings.load_test"),
+            field("👥 ", "settings.concurrency", CONCURRENCY, &form.concurrency, ""),
+            field("⏱️  ", "settings.duration", DURATION, &form.duration, ""),
+            hint("settings.load_test_hint"),
+            Line::from(""),
+            heading("settings.history"),
+            field("🗄️  ", "settings.keep_entries", HISTORY, &form.history, "settings.all"),
+            field("📅 ", "settings.max_age", HISTORY_AGE, &form.history_age, "settings.no_limit"),
+            field("💾 ", "settings.max_size", HISTORY_DISK, &form.history_disk, "settings.no_limit"),
+            hint("settings.history_hint"),
+            Line::from(""),
+            heading("settings.results"),
+            field("🗄️  ", "settings.keep_runs", RESULTS, &form.results, "settings.all"),
+            field("📅 ", "settings.max_age", RESULTS_AGE, &form.results_age, "settings.no_limit"),
+            field("💾 ", "settings.max_size", RESULTS_DISK, &form.results_disk, "settings.no_limit"),
+            hint("settings.results_hint"),
+            Line::from(""),
+            heading("settings.budgets"),
+            field("📤 ", "settings.request_body", REQUEST_BUDGET, &form.request_budget, "settings.none"),
+            field("📥 ", "settings.response_body", RESPONSE_BUDGET, &form.response_budget, "settings.none"),
+            hint("settings.budgets_hint"),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(tr("settings.disk_usage"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::raw(format!(
+                    "{} {} · {} {}",
+                    tr("settings.disk_history"), format_bytes(self.disk_usage.0),
+                    tr("settings.disk_results"), format_bytes(self.disk_usage.1),
+                )),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(tr("settings.saved_to"), Style::default().fg(Color::DarkGray))),
+            Line::from(Span::styled(format!("   {}", app.storage.config_path().display()), Style::default().fg(Color::DarkGray))),
+        ];
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default()
+                .title(tr("settings.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::{HistoryEntry, HistoryStore};
+    use crate::models::HttpMethod;
+    use crate::storage::StorageManager;
+    use crate::tui_app::tests::test_app;
+    use crate::variables::VariableManager;
+
+    #[test]
+    fn test_settings_are_saved_and_applied() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let storage = StorageManager::new(temp_dir.path().join("collections"), temp_dir.path().join("results")).unwrap();
+        let variables = VariableManager::with_path(temp_dir.path().join("variables.json")).unwrap();
+        let mut history = HistoryStore::with_path(temp_dir.path().join("history.jsonl")).unwrap();
+        for _ in 0..3 {
+            history.record(HistoryEntry::new(uuid::Uuid::new_v4(), uuid::Uuid::new_v4(), HttpMethod::GET, "https://a".to_string())).unwrap();
+        }
+        let mut app = AppState::with_storage(storage, variables, history).unwrap();
+        let mut screen = SettingsScreen::new(&app);
+        let type_text = |screen: &mut SettingsScreen, app: &mut AppState, text: &str| {
+            for c in text.chars() {
+                screen.handle_action(app, Action::InsertChar(c));
+            }
+        };
+
+        // Timeout "5x" keeps only the digits; a missing environment keeps the screen open
+        screen.form.timeout.clear();
+        type_text(&mut screen, &mut app, "5x");
+        screen.form.current_field = ENVIRONMENT;
+        type_text(&mut screen, &mut app, "nowhere");
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Stay);
+        assert!(app.error_message.as_deref().unwrap().contains("nowhere"));
+
+        screen.form.environment.clear();
+        screen.form.current_field = REPEAT_SEND;
+        type_text(&mut screen, &mut app, " ");
+        screen.form.current_field = THEME;
+        type_text(&mut screen, &mut app, " ");
+        screen.form.current_field = LOCALE;
+        type_text(&mut screen, &mut app, " ");
+        screen.form.current_field = HISTORY;
+        type_text(&mut screen, &mut app, "2");
+        assert_eq!(screen.handle_action(&mut app, Action::Submit), Transition::Close);
+        // Saved in the language just picked
+        assert_eq!(app.status_message.as_deref(), Some("Configuración guardada; se depuraron 1 entradas del historial y 0 ejecuciones de carga"));
+        assert_eq!(app.http_client.timeout(), std::time::Duration::from_secs(5));
+        assert_eq!(app.history.entries().len(), 2);
+        assert_eq!(app.tr("panel.response"), "📨 Respuesta");
+
+        // Picked up on the next start
+        let app = test_app(&temp_dir);
+        assert_eq!((app.settings.timeout_secs, app.settings.theme, app.settings.locale), (5, Theme::Light, Locale::Es));
+        assert_eq!(app.settings.history.max_entries, Some(2));
+        assert_eq!(app.settings.repeat_send, RepeatSend::Queue);
+    }
+}
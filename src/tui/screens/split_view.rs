@@ -0,0 +1,353 @@
+// Two endpoints side by side
+// Each pane shows an endpoint's definition above its latest response, e.g. v1
+// and v2 of the same call. Tab moves focus between panes; `x` sends the focused
+// endpoint and Enter sends both, after the protected environment prompt when
+// they change data. Responses go to history like any other.
+
+use super::confirm_host::AfterConfirm;
+use super::{ConfirmProtectedScreen, ScreenView, Transition};
+use crate::tui::ui_utils::{fmt_duration, status_symbol};
+use crate::formatter;
+use crate::http::RequestInputs;
+use crate::tui::app::AppState;
+use crate::tui::input::Action;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+struct Captured {
+    status: Option<u16>,
+    duration: Duration,
+    error: Option<String>,
+    body: Vec<u8>,
+}
+
+#[derive(Default)]
+struct Pane {
+    endpoint_index: usize,
+    sending: bool,
+    response: Option<Captured>,
+    formatted: Vec<String>, // Body lines, formatted once when the response arrives
+    scroll: u16,
+}
+
+pub struct SplitViewScreen {
+    collection_index: usize,
+    panes: [Pane; 2],
+    results: Arc<Mutex<[Option<Captured>; 2]>>, // Filled in by the background tasks
+    requests: [Option<u64>; 2], // In flight for each pane
+    focus: usize,
+}
+
+impl SplitViewScreen {
+    pub fn new(collection_index: usize, endpoints: [usize; 2]) -> Self {
+        Self {
+            collection_index,
+            panes: endpoints.map(|endpoint_index| Pane { endpoint_index, ..Default::default() }),
+            results: Arc::new(Mutex::new([None, None])),
+            requests: [None, None],
+            focus: 0,
+        }
+    }
+
+    /// Send the endpoints of the panes on `sides` with saved variables; hosts
+    /// outside the allowlist just fail since the screen doesn't prompt. Writes
+    /// to a protected environment are confirmed first, then `action` is replayed.
+    fn send(&mut self, app: &mut AppState, sides: &[usize], action: Action) {
+        let mut jobs = Vec::new();
+        for &side in sides {
+            let Some(endpoint) = app.collections.get(self.collection_index)
+                .and_then(|c| c.endpoints.get(self.panes[side].endpoint_index))
+                .cloned() else { continue };
+            let variables = app.saved_variables(&endpoint).and_then(|variables| match app.unapproved_host(&endpoint, &variables) {
+                Some(host) => Err(app.tr_with("compare.not_allowed", &[("host", &host)])),
+                None => Ok(variables),
+            });
+            jobs.push((side, endpoint, variables));
+        }
+
+        let writes: Vec<String> = jobs.iter()
+            .filter_map(|(_, endpoint, variables)| AppState::write_request(endpoint, variables.as_ref().ok()?))
+            .collect();
+        if let Some(environment) = app.settings.environment.clone().filter(|environment| app.needs_protected_confirmation(environment, &writes)) {
+            let placeholder = Self::new(self.collection_index, self.panes.each_ref().map(|pane| pane.endpoint_index));
+            let screen = std::mem::replace(self, placeholder);
+            let replay: AfterConfirm = Box::new(move |app| {
+                if let Some(mut view) = app.view.take() {
+                    view.handle_action(app, action);
+                    app.view.get_or_insert(view);
+                }
+            });
+            app.open_view(Box::new(ConfirmProtectedScreen::new(environment, writes, replay).over(Box::new(screen))));
+            return;
+        }
+
+        for (side, endpoint, variables) in jobs {
+            if let Some(id) = self.requests[side].take() {
+                app.cancel_request(id);
+            }
+            self.panes[side].sending = true;
+            self.results.lock().unwrap()[side] = None;
+            let variables = match variables {
+                Ok(variables) => variables,
+                Err(e) => {
+                    self.results.lock().unwrap()[side] = Some(Captured { status: None, duration: Duration::ZERO, error: Some(e), body: Vec::new() });
+                    continue;
+                }
+            };
+
+            let client = app.client_for(self.collection_index).clone();
+            let shared = self.results.clone();
+            let label = format!("{} {}", endpoint.method, endpoint.name);
+            let id = app.spawn_view_request(self.collection_index, self.panes[side].endpoint_index, label, async move {
+                let inputs = RequestInputs { variables, ..Default::default() };
+                let started = Instant::now();
+                let captured = match client.execute(&endpoint, &inputs).await {
+                    Ok(response) => Captured {
+                        status: Some(response.status.as_u16()),
+                        duration: response.duration,
+                        error: None,
+                        body: response.body,
+                    },
+                    Err(e) => Captured { status: None, duration: started.elapsed(), error: Some(e.to_string()), body: Vec::new() },
+                };
+                shared.lock().unwrap()[side] = Some(captured);
+            });
+            self.requests[side] = Some(id);
+        }
+    }
+
+    fn draw_pane(&self, f: &mut Frame, area: Rect, app: &AppState, side: usize) {
+        let pane = &self.panes[side];
+        let Some(endpoint) = app.collections.get(self.collection_index)
+            .and_then(|c| c.endpoints.get(pane.endpoint_index)) else { return };
+        let dim = Style::default().fg(Color::DarkGray);
+        let label = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        let border = if side == self.focus { Color::Cyan } else { Color::DarkGray };
+
+        let mut definition = vec![Line::from(vec![
+            Span::styled(format!("{} ", endpoint.method), label),
+            Span::raw(endpoint.url.clone()),
+        ])];
+        let mut headers: Vec<_> = endpoint.headers.iter().collect();
+        headers.sort();
+        for (name, value) in headers {
+            definition.push(Line::from(vec![Span::styled(format!("{}: ", name), dim), Span::raw(value.clone())]));
+        }
+        if let Some(body) = endpoint.body_template.as_deref().filter(|b| !b.trim().is_empty()) {
+            definition.push(Line::from(""));
+            definition.extend(body.lines().map(|line| Line::from(line.to_string())));
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(area);
+        let paragraph = Paragraph::new(definition)
+            .wrap(Wrap { trim: false })
+            .block(Block::default()
+                .title(format!("{} {}", if side == 0 { "◧" } else { "◨" }, endpoint.name))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(border)));
+        f.render_widget(paragraph, chunks[0]);
+
+        let mut lines = Vec::new();
+        let title = match (&pane.response, pane.sending) {
+            (None, true) => {
+                lines.push(Line::from(Span::styled(app.tr("common.sending"), dim)));
+                app.tr("split_view.response").to_string()
+            }
+            (None, false) => {
+                lines.push(Line::from(Span::styled(app.tr("split_view.not_sent"), dim)));
+                app.tr("split_view.response").to_string()
+            }
+            (Some(captured), _) => {
+                if let Some(error) = &captured.error {
+                    lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))));
+                }
+                lines.extend(pane.formatted.iter().map(|line| Line::from(line.clone())));
+                match captured.status {
+                    Some(status) => app.tr_with("split_view.response_status", &[
+                        ("symbol", &status_symbol(status)),
+                        ("status", &status),
+                        ("duration", &fmt_duration(captured.duration, app.settings.latency_decimals)),
+                        ("bytes", &captured.body.len()),
+                    ]),
+                    None => app.tr("split_view.response_failed").to_string(),
+                }
+            }
+        };
+        let status_color = match pane.response.as_ref().and_then(|c| c.status) {
+            Some(status) if (200..400).contains(&status) => Color::Green,
+            Some(_) => Color::Red,
+            None => border,
+        };
+        let max_scroll = (lines.len() as u16).saturating_sub(chunks[1].height.saturating_sub(2));
+        let paragraph = Paragraph::new(lines)
+            .scroll((pane.scroll.min(max_scroll), 0))
+            .block(Block::default()
+                .title(Span::styled(title, Style::default().fg(status_color)))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(border)));
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
+
+impl ScreenView for SplitViewScreen {
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let pane = &mut self.panes[self.focus];
+        match action {
+            Action::ToggleFocus | Action::FocusCollections | Action::FocusEndpoints => {
+                self.focus = match action {
+                    Action::FocusCollections => 0,
+                    Action::FocusEndpoints => 1,
+                    _ => 1 - self.focus,
+                };
+            }
+            Action::Up | Action::ScrollUp => pane.scroll = pane.scroll.saturating_sub(1),
+            Action::Down | Action::ScrollDown => pane.scroll = pane.scroll.saturating_add(1),
+            Action::ScrollTop => pane.scroll = 0,
+            Action::QuickExecute => self.send(app, &[self.focus], action),
+            Action::Select => self.send(app, &[0, 1], action),
+            Action::Quit => return Transition::Quit,
+            Action::Back => {
+                for id in self.requests.iter_mut().filter_map(Option::take) {
+                    app.cancel_request(id);
+                }
+                return Transition::Close;
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn is_animating(&self) -> bool {
+        self.panes.iter().any(|pane| pane.sending)
+    }
+
+    /// Show responses that arrived and record them in history
+    fn tick(&mut self, app: &mut AppState) {
+        let results = self.results.lock().unwrap().clone();
+        for ((pane, request), result) in self.panes.iter_mut().zip(&mut self.requests).zip(results) {
+            let Some(captured) = result.filter(|_| pane.sending) else { continue };
+            *request = None;
+            app.record_history(
+                self.collection_index,
+                pane.endpoint_index,
+                captured.status,
+                captured.duration,
+                captured.error.clone(),
+                &captured.body,
+            );
+            pane.formatted = match formatter::format_auto(&captured.body) {
+                Ok(text) => text.lines().map(str::to_string).collect(),
+                Err(_) => formatter::decode_text(&captured.body, None).lines().map(str::to_string).collect(),
+            };
+            pane.scroll = 0;
+            pane.sending = false;
+            pane.response = Some(captured);
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        self.draw_pane(f, columns[0], app, 0);
+        self.draw_pane(f, columns[1], app, 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+    use crate::variables::VariableSet;
+
+    #[test]
+    fn test_split_needs_two_marked_endpoints_and_sends_the_focused_one() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+
+        let mut collection = ApiCollection::new("A".to_string());
+        for name in ["v1", "v2"] {
+            collection.add_endpoint(ApiEndpoint::new(name.to_string(), HttpMethod::GET, format!("{{{{host}}}}/{}/users", name)));
+        }
+        app.collections.push(collection);
+
+        app.open_split_view(0);
+        assert!(app.view.is_none());
+        assert!(app.error_message.as_deref().unwrap().contains("two endpoints"));
+
+        app.toggle_endpoint_mark(0, 0);
+        app.toggle_endpoint_mark(0, 1);
+        app.open_split_view(0);
+        let mut screen = app.view.take().unwrap();
+        assert!(!screen.is_animating());
+
+        screen.handle_action(&mut app, Action::ToggleFocus);
+        screen.handle_action(&mut app, Action::QuickExecute);
+        assert!(screen.is_animating());
+        screen.tick(&mut app);
+        assert!(!screen.is_animating());
+        assert_eq!(app.history.entries().len(), 1);
+        assert_eq!(app.history.entries()[0].url, "{{host}}/v2/users");
+        assert!(app.history.entries()[0].error.as_deref().unwrap().contains("host"));
+    }
+
+    #[test]
+    fn test_every_send_to_a_protected_environment_is_confirmed() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        // Accepts connections but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut prod = VariableSet::new("prod".to_string());
+        prod.set("host".to_string(), format!("http://{}", listener.local_addr().unwrap()));
+        prod.protected = true;
+        app.variable_manager.save_environment(&prod).unwrap();
+        assert!(app.set_environment(Some("prod".to_string())));
+
+        let mut collection = ApiCollection::new("A".to_string());
+        for name in ["v1", "v2"] {
+            collection.add_endpoint(ApiEndpoint::new(name.to_string(), HttpMethod::DELETE, format!("{{{{host}}}}/{}/users", name)));
+        }
+        app.collections.push(collection);
+        app.toggle_endpoint_mark(0, 0);
+        app.toggle_endpoint_mark(0, 1);
+        app.open_split_view(0);
+
+        for attempt in 0..2 {
+            let mut screen = app.view.take().unwrap();
+            screen.handle_action(&mut app, Action::Select);
+            assert!(app.in_flight.is_empty(), "nothing is sent before confirming");
+            let mut prompt = app.view.take().unwrap();
+            if attempt == 0 {
+                // Cancelling goes back to the split view
+                assert_eq!(prompt.handle_action(&mut app, Action::Back), Transition::Close);
+                continue;
+            }
+            for c in "prod".chars() {
+                prompt.handle_action(&mut app, Action::InsertChar(c));
+            }
+            assert_eq!(prompt.handle_action(&mut app, Action::Submit), Transition::Close);
+        }
+        assert_eq!(app.in_flight.iter().map(|r| r.label.as_str()).collect::<Vec<_>>(), vec!["DELETE v1", "DELETE v2"]);
+        assert!(!app.protected_confirmed);
+
+        let mut screen = app.view.take().unwrap();
+        assert!(screen.is_animating());
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Close);
+        assert!(app.in_flight.is_empty());
+    }
+}
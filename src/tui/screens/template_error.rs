@@ -0,0 +1,70 @@
+// Template error panel
+// Opened when a request can't be built because a template failed: names the
+// field, the line and column, and underlines the tag in the offending line.
+
+use super::{ScreenView, Transition};
+use crate::template::ErrorLocation;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+pub struct TemplateErrorScreen {
+    message: String,
+    location: ErrorLocation,
+}
+
+impl TemplateErrorScreen {
+    pub fn new(message: String, location: ErrorLocation) -> Self {
+        Self { message, location }
+    }
+}
+
+impl ScreenView for TemplateErrorScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, _app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar('q') | Action::Back | Action::Submit => Transition::Close,
+            _ => Transition::Stay,
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let location = &self.location;
+        let label = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let gutter = format!("{:>4} │ ", location.line);
+        let lines = vec![
+            Line::from(Span::styled(self.message.clone(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from(vec![Span::styled(app.tr("template_error.field"), label), Span::raw(location.field.clone())]),
+            Line::from(vec![
+                Span::styled(app.tr("template_error.position"), label),
+                Span::raw(app.tr_with("template_error.position_value", &[("line", &location.line), ("column", &location.column), ("offset", &location.offset)])),
+            ]),
+            Line::from(""),
+            Line::from(vec![Span::styled(gutter.clone(), Style::default().fg(Color::DarkGray)), Span::raw(location.snippet.clone())]),
+            Line::from(vec![
+                Span::raw(" ".repeat(gutter.chars().count())),
+                Span::styled(location.caret(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(app.tr("template_error.keys"), Style::default().fg(Color::DarkGray))),
+        ];
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr("template_error.title"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Red)));
+        f.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,321 @@
+// Activity timeline
+// Executed requests, load test runs, imports and edits merged into one list
+// with timestamps, newest at the bottom, as a "what did I do today" record.
+// Filter by day and collection; `y` copies what's shown as plain text.
+
+use super::{ScreenView, Transition};
+use crate::activity::ActivityKind;
+use crate::load_test::RunStatus;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Request,
+    LoadTest,
+    Import,
+    Edit,
+    Delete,
+}
+
+impl EventKind {
+    fn label(self, app: &AppState) -> &'static str {
+        match self {
+            EventKind::Request => app.tr("timeline.request"),
+            EventKind::LoadTest => app.tr("timeline.load_test"),
+            EventKind::Import => app.tr("timeline.import"),
+            EventKind::Edit => app.tr("timeline.edit"),
+            EventKind::Delete => app.tr("timeline.delete"),
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            EventKind::Request => Color::Cyan,
+            EventKind::LoadTest => Color::Magenta,
+            EventKind::Import => Color::Blue,
+            EventKind::Edit => Color::Yellow,
+            EventKind::Delete => Color::Red,
+        }
+    }
+}
+
+/// One thing that happened, from whichever record it came from
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEvent {
+    pub timestamp: DateTime<Utc>,
+    pub kind: EventKind,
+    pub collection_id: Uuid,
+    pub collection: String,
+    pub text: String,
+    pub failed: bool,
+}
+
+impl TimelineEvent {
+    fn local_date(&self) -> NaiveDate {
+        self.timestamp.with_timezone(&Local).date_naive()
+    }
+}
+
+/// Request history, saved load test runs and the activity journal, oldest first
+pub fn timeline(app: &AppState) -> Vec<TimelineEvent> {
+    let collection_name = |id: Uuid| app.collections.iter()
+        .find(|c| c.id == id)
+        .map(|c| c.name.clone())
+        .unwrap_or_else(|| app.tr("timeline.deleted").to_string());
+
+    let mut events: Vec<TimelineEvent> = app.history.entries().iter().map(|entry| {
+        let endpoint = app.collections.iter()
+            .flat_map(|c| c.endpoints.iter())
+            .find(|e| e.id == entry.endpoint_id)
+            .map(|e| format!("{}: ", e.name))
+            .unwrap_or_default();
+        let outcome = match (entry.status, &entry.error) {
+            (Some(status), _) => app.tr_with("timeline.status", &[("status", &status), ("ms", &entry.duration_ms)]),
+            (None, error) => error.clone().unwrap_or_else(|| app.tr("timeline.no_response").to_string()),
+        };
+        let mut text = format!("{}{} {} → {}", endpoint, entry.method, entry.url, outcome);
+        if !entry.assertion_failures.is_empty() {
+            text.push_str(&app.tr_with("timeline.assertions_failed", &[("count", &entry.assertion_failures.len())]));
+        }
+        TimelineEvent {
+            timestamp: entry.timestamp,
+            kind: EventKind::Request,
+            collection_id: entry.collection_id,
+            collection: collection_name(entry.collection_id),
+            text,
+            failed: !entry.is_success(),
+        }
+    }).collect();
+
+    // Reports that can't be read just leave load tests out of the timeline
+    for report in app.storage.load_test_reports().unwrap_or_default() {
+        let status = match report.status {
+            RunStatus::Completed => "",
+            RunStatus::Running => app.tr("timeline.running"),
+            RunStatus::Interrupted => app.tr("timeline.interrupted"),
+        };
+        events.push(TimelineEvent {
+            timestamp: report.started_at,
+            kind: EventKind::LoadTest,
+            collection_id: report.target.collection_id,
+            collection: collection_name(report.target.collection_id),
+            text: app.tr_with("timeline.load_test_run", &[
+                ("endpoint", &report.target.endpoint_name),
+                ("requests", &report.total_requests),
+                ("failed", &report.failed_requests),
+                ("rps", &format!("{:.1}", report.avg_rps)),
+                ("seconds", &format!("{:.0}", report.duration_secs)),
+                ("status", &status),
+            ]),
+            failed: report.failed_requests > 0 || report.status == RunStatus::Interrupted,
+        });
+    }
+
+    for entry in app.activity.entries() {
+        events.push(TimelineEvent {
+            timestamp: entry.timestamp,
+            kind: match entry.kind {
+                ActivityKind::Import => EventKind::Import,
+                ActivityKind::Edit => EventKind::Edit,
+                ActivityKind::Delete => EventKind::Delete,
+            },
+            collection_id: entry.collection_id,
+            collection: entry.collection.clone(),
+            text: entry.summary.clone(),
+            failed: false,
+        });
+    }
+
+    events.sort_by_key(|e| e.timestamp);
+    events
+}
+
+/// The events as plain text, one per line, ready to paste into a report
+pub fn report_text(heading: &str, events: &[&TimelineEvent], app: &AppState) -> String {
+    let mut text = format!("{}\n", heading);
+    for event in events {
+        text.push_str(&format!(
+            "{}  [{}] {}: {}\n",
+            event.timestamp.with_timezone(&Local).format("%H:%M:%S"),
+            event.kind.label(app),
+            event.collection,
+            event.text,
+        ));
+    }
+    text
+}
+
+pub struct TimelineScreen {
+    events: Vec<TimelineEvent>,
+    day: Option<NaiveDate>, // None = every day
+    collection: Option<Uuid>, // None = every collection
+    scroll: usize, // Lines scrolled up from the bottom
+}
+
+impl TimelineScreen {
+    /// Start with today's activity, limited to `collection` if given
+    pub fn new(app: &AppState, collection: Option<Uuid>) -> Self {
+        Self { events: timeline(app), day: Some(Local::now().date_naive()), collection, scroll: 0 }
+    }
+
+    fn visible(&self) -> Vec<&TimelineEvent> {
+        self.events.iter()
+            .filter(|e| self.day.is_none_or(|day| e.local_date() == day))
+            .filter(|e| self.collection.is_none_or(|id| e.collection_id == id))
+            .collect()
+    }
+
+    /// Every collection with events, then back to all of them
+    fn cycle_collection(&mut self) {
+        let mut ids: Vec<(Uuid, &str)> = Vec::new();
+        for event in &self.events {
+            if !ids.iter().any(|(id, _)| *id == event.collection_id) {
+                ids.push((event.collection_id, &event.collection));
+            }
+        }
+        ids.sort_by_key(|(_, name)| name.to_lowercase());
+        self.collection = match self.collection {
+            None => ids.first().map(|(id, _)| *id),
+            Some(current) => ids.iter()
+                .position(|(id, _)| *id == current)
+                .and_then(|i| ids.get(i + 1))
+                .map(|(id, _)| *id),
+        };
+        self.scroll = 0;
+    }
+
+    fn heading(&self, app: &AppState) -> String {
+        let day = match self.day {
+            Some(day) => app.tr_with("timeline.activity_on", &[("day", &day.format("%Y-%m-%d"))]),
+            None => app.tr("timeline.all_activity").to_string(),
+        };
+        let collection = self.collection
+            .and_then(|id| self.events.iter().find(|e| e.collection_id == id))
+            .map(|e| app.tr_with("timeline.in_collection", &[("name", &e.collection)]))
+            .unwrap_or_default();
+        day + &collection
+    }
+}
+
+impl ScreenView for TimelineScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Menu
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        let count = self.visible().len();
+        match action {
+            Action::Up => self.scroll = (self.scroll + 1).min(count.saturating_sub(1)),
+            Action::Down => self.scroll = self.scroll.saturating_sub(1),
+            Action::InsertChar('c') => self.cycle_collection(),
+            Action::InsertChar('d') => {
+                self.day = match self.day {
+                    Some(_) => None,
+                    None => Some(Local::now().date_naive()),
+                };
+                self.scroll = 0;
+            }
+            Action::InsertChar('y') => {
+                let text = report_text(&self.heading(app), &self.visible(), app);
+                app.copy_to_clipboard(text, &app.tr_with("timeline.what", &[("count", &count)]));
+            }
+            Action::InsertChar('q') | Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let visible = self.visible();
+        let height = area.height.saturating_sub(2) as usize;
+        let end = visible.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(height);
+        let dim = Style::default().fg(Color::DarkGray);
+
+        let lines: Vec<Line> = if visible.is_empty() {
+            vec![Line::from(Span::styled(app.tr("timeline.empty"), dim))]
+        } else {
+            visible[start..end].iter().map(|event| {
+                let when = match self.day {
+                    Some(_) => event.timestamp.with_timezone(&Local).format("%H:%M:%S").to_string(),
+                    None => event.timestamp.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string(),
+                };
+                let (mark, text_style) = if event.failed { ("✗ ", Style::default().fg(Color::Red)) } else { ("", Style::default()) };
+                Line::from(vec![
+                    Span::styled(format!("{} ", when), dim),
+                    Span::styled(format!("{:<10}", event.kind.label(app)), Style::default().fg(event.kind.color())),
+                    Span::styled(format!("{}: ", event.collection), dim),
+                    Span::styled(format!("{}{}", mark, event.text), text_style),
+                ])
+            }).collect()
+        };
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title(app.tr_with("timeline.title", &[("heading", &self.heading(app)), ("count", &visible.len())]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::HistoryEntry;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_timeline_merges_and_filters() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+
+        app.collection_form = Some(crate::tui::app::CollectionForm { name: "Shop".to_string(), editing_index: None });
+        app.save_collection();
+        let mut other = ApiCollection::new("Admin".to_string());
+        let endpoint = ApiEndpoint::new("users".to_string(), HttpMethod::GET, "https://admin/users".to_string());
+        let mut request = HistoryEntry::new(other.id, endpoint.id, HttpMethod::GET, endpoint.url.clone());
+        request.status = Some(500);
+        other.add_endpoint(endpoint);
+        app.collections.push(other);
+        app.history.record(request).unwrap();
+        app.delete_endpoint(1, 0);
+
+        let mut screen = TimelineScreen::new(&app, None);
+        let kinds: Vec<EventKind> = screen.visible().iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, vec![EventKind::Edit, EventKind::Request, EventKind::Delete]);
+        assert!(screen.visible()[1].failed);
+        assert_eq!(screen.visible()[1].text, "GET https://admin/users → 500 in 0 ms");
+        assert_eq!(screen.visible()[2].text, "Deleted endpoint 'users'");
+
+        // Admin sorts first, then Shop, then everything again
+        screen.handle_action(&mut app, Action::InsertChar('c'));
+        assert_eq!(screen.visible().len(), 2);
+        screen.handle_action(&mut app, Action::InsertChar('c'));
+        assert_eq!(screen.visible()[0].text, "Created collection");
+        let report = report_text(&screen.heading(&app), &screen.visible(), &app);
+        assert!(report.starts_with("Activity on "));
+        assert!(report.lines().next().unwrap().ends_with(" in Shop"));
+        assert!(report.lines().nth(1).unwrap().ends_with("[edit] Shop: Created collection"));
+        screen.handle_action(&mut app, Action::InsertChar('c'));
+        assert_eq!(screen.visible().len(), 3);
+
+        // The journal survives a restart
+        screen.handle_action(&mut app, Action::InsertChar('d'));
+        assert_eq!(screen.heading(&app), "All activity");
+        let reloaded = crate::activity::ActivityLog::load(&app.storage.activity_path()).unwrap();
+        assert_eq!(reloaded.entries().len(), 2);
+    }
+}
@@ -0,0 +1,254 @@
+// Import and export screens
+// Both are a single prompt; export also lets Tab pick the file format. The
+// import prompt takes a file path, a URL (with an optional request header on a
+// second line) or an HTTPie or curl command line, which is added as an
+// endpoint of the selected collection.
+
+use super::{ScreenView, Transition};
+use crate::interop::{self, curl, httpie, ExportFormat};
+use crate::storage::expand_home;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+#[derive(Debug, Default)]
+pub struct ImportScreen {
+    path: String,
+    header: String, // Sent when downloading from a URL, e.g. `Authorization: Bearer {{token}}`
+    editing_header: bool,
+    collection_index: Option<usize>, // Where HTTPie and curl commands are added
+}
+
+impl ImportScreen {
+    pub fn new(collection_index: Option<usize>) -> Self {
+        Self { collection_index, ..Self::default() }
+    }
+
+    fn field_mut(&mut self) -> &mut String {
+        if self.editing_header { &mut self.header } else { &mut self.path }
+    }
+
+    fn import(&self, app: &mut AppState) -> bool {
+        if interop::is_url(&self.path) {
+            return app.import_url(&self.path, &self.header);
+        }
+        let is_curl = curl::is_curl(&self.path);
+        if !is_curl && !httpie::is_httpie(&self.path) {
+            return app.import_file(&expand_home(&self.path));
+        }
+        match self.collection_index {
+            Some(index) if is_curl => app.import_curl(index, &self.path),
+            Some(index) => app.import_httpie(index, &self.path),
+            None => {
+                app.error_message = Some(app.tr("transfer.no_collection").to_string());
+                false
+            }
+        }
+    }
+}
+
+impl ScreenView for ImportScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(c) => self.field_mut().push(c),
+            Action::DeleteChar => { self.field_mut().pop(); }
+            // The header only applies to downloads
+            Action::NextField | Action::PrevField if interop::is_url(&self.path) => {
+                self.editing_header = !self.editing_header;
+            }
+            Action::Submit if self.import(app) => return Transition::Close,
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let mut text = vec![
+            Line::from(app.tr("transfer.import_formats")),
+            Line::from(""),
+        ];
+        if interop::is_url(&self.path) {
+            let cursor = |active: bool| if active { "_" } else { "" };
+            text.push(Line::from(vec![
+                Span::styled(app.tr("transfer.url"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(&self.path, Style::default().fg(Color::Yellow)),
+                Span::styled(cursor(!self.editing_header), Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+            ]));
+            text.push(Line::from(vec![
+                Span::styled(app.tr("transfer.header"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(&self.header, Style::default().fg(Color::Yellow)),
+                Span::styled(cursor(self.editing_header), Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+            ]));
+            text.push(Line::from(Span::styled(
+                app.tr("transfer.header_hint"),
+                Style::default().fg(Color::DarkGray),
+            )));
+            text.push(Line::from(""));
+            text.push(Line::from(Span::styled(app.tr("transfer.download_keys"), Style::default().fg(Color::DarkGray))));
+        } else {
+            text.push(path_line(&self.path, app));
+            text.push(Line::from(""));
+            text.push(Line::from(Span::styled(app.tr("transfer.import_keys"), Style::default().fg(Color::DarkGray))));
+        }
+        render(f, area, app.tr("transfer.import_title"), text);
+    }
+}
+
+pub struct ExportScreen {
+    collection_index: usize,
+    path: String,
+    format: ExportFormat,
+}
+
+impl ExportScreen {
+    /// Suggest `~/<collection name>.<ext>` as the destination
+    pub fn new(collection_index: usize, collection_name: &str) -> Self {
+        let format = ExportFormat::Native;
+        let stem: String = collection_name.chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        Self {
+            collection_index,
+            path: format!("~/{}.{}", stem, format.extension()),
+            format,
+        }
+    }
+
+    /// Start on another format, e.g. straight to OpenAPI
+    pub fn with_format(mut self, format: ExportFormat) -> Self {
+        while self.format != format {
+            self.cycle_format();
+        }
+        self
+    }
+
+    fn cycle_format(&mut self) {
+        let next = self.format.next();
+        // Keep the extension in step with the format if the user hasn't changed it
+        if let Some(stem) = self.path.strip_suffix(&format!(".{}", self.format.extension())) {
+            self.path = format!("{}.{}", stem, next.extension());
+        }
+        self.format = next;
+    }
+}
+
+impl ScreenView for ExportScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(c) => self.path.push(c),
+            Action::DeleteChar => { self.path.pop(); }
+            Action::NextField | Action::PrevField => self.cycle_format(),
+            Action::Submit if app.export_collection(self.collection_index, &expand_home(&self.path), self.format) => {
+                return Transition::Close;
+            }
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let name = app.collections.get(self.collection_index).map(|c| c.name.as_str()).unwrap_or_default();
+        let text = vec![
+            Line::from(app.tr_with("transfer.export_name", &[("name", &name)])),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(app.tr("transfer.format"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::raw(self.format.label()),
+            ]),
+            path_line(&self.path, app),
+            Line::from(""),
+            Line::from(Span::styled(app.tr("transfer.export_keys"), Style::default().fg(Color::DarkGray))),
+        ];
+        render(f, area, app.tr("transfer.export_title"), text);
+    }
+}
+
+fn path_line<'a>(path: &'a str, app: &AppState) -> Line<'a> {
+    let label = if httpie::is_httpie(path) {
+        "🥧 HTTPie: "
+    } else if curl::is_curl(path) {
+        "🌀 curl: "
+    } else {
+        app.tr("transfer.file")
+    };
+    Line::from(vec![
+        Span::styled(label, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Span::styled(path, Style::default().fg(Color::Yellow)),
+        Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+    ])
+}
+
+fn render(f: &mut Frame, area: Rect, title: &str, text: Vec<Line>) {
+    let paragraph = Paragraph::new(text)
+        .block(Block::default()
+            .title(title.to_string())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan)))
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_import_header_only_for_urls() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut screen = ImportScreen::new(None);
+        let type_text = |screen: &mut ImportScreen, app: &mut AppState, text: &str| {
+            for c in text.chars() {
+                screen.handle_action(app, Action::InsertChar(c));
+            }
+        };
+
+        // Tab does nothing for a file path
+        type_text(&mut screen, &mut app, "~/spec");
+        screen.handle_action(&mut app, Action::NextField);
+        assert!(!screen.editing_header);
+
+        screen.path.clear();
+        type_text(&mut screen, &mut app, "https://ci/openapi.json");
+        screen.handle_action(&mut app, Action::NextField);
+        type_text(&mut screen, &mut app, "Authorization: Bearer x");
+        assert_eq!(screen.header, "Authorization: Bearer x");
+        assert_eq!(screen.path, "https://ci/openapi.json");
+    }
+
+    #[test]
+    fn test_export_path_follows_format() {
+        let mut screen = ExportScreen::new(0, "My API");
+        assert_eq!(screen.path, "~/My_API.json");
+        screen.cycle_format();
+        assert_eq!(screen.format, ExportFormat::Insomnia);
+        assert_eq!(screen.path, "~/My_API.insomnia.json");
+        screen.cycle_format();
+        assert_eq!(screen.path, "~/My_API.openapi.json");
+        screen.cycle_format();
+        assert_eq!(screen.path, "~/My_API.openapi.yaml");
+        screen.cycle_format();
+        assert_eq!(screen.path, "~/My_API.json");
+
+        let screen = ExportScreen::new(0, "My API").with_format(ExportFormat::OpenApiYaml);
+        assert_eq!(screen.path, "~/My_API.openapi.yaml");
+    }
+}
@@ -0,0 +1,179 @@
+// Variable import and export screen
+// Moves the saved variables or an environment to and from dotenv (.env) or
+// JSON files. Exports leave secrets empty unless asked to include them;
+// imports merge into what's there unless asked to replace it.
+
+use super::{ScreenView, Transition};
+use crate::storage::expand_home;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use crate::variables::{ImportMode, VariableFormat};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+const PATH: usize = 0;
+const ENVIRONMENT: usize = 1;
+const OPTION: usize = 2;
+
+pub struct VariableTransferScreen {
+    export: bool,
+    path: String,
+    environment: String, // Empty for the saved variables
+    option: bool, // Import: replace instead of merging; export: include secrets
+    field: usize,
+}
+
+impl VariableTransferScreen {
+    pub fn import() -> Self {
+        Self { export: false, path: String::new(), environment: String::new(), option: false, field: PATH }
+    }
+
+    pub fn export() -> Self {
+        Self { export: true, path: "~/variables.env".to_string(), ..Self::import() }
+    }
+
+    fn submit(&self, app: &mut AppState) -> bool {
+        let path = expand_home(&self.path);
+        let environment = Some(self.environment.trim()).filter(|name| !name.is_empty());
+        if self.export {
+            app.export_variables(&path, environment, self.option)
+        } else {
+            let mode = if self.option { ImportMode::Replace } else { ImportMode::Merge };
+            app.import_variables(&path, environment, mode)
+        }
+    }
+}
+
+impl ScreenView for VariableTransferScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(' ') if self.field == OPTION => self.option = !self.option,
+            Action::InsertChar(c) if self.field == PATH => self.path.push(c),
+            Action::InsertChar(c) if self.field == ENVIRONMENT => self.environment.push(c),
+            Action::DeleteChar if self.field == PATH => { self.path.pop(); }
+            Action::DeleteChar if self.field == ENVIRONMENT => { self.environment.pop(); }
+            Action::NextField => self.field = (self.field + 1) % 3,
+            Action::PrevField => self.field = (self.field + 2) % 3,
+            Action::Submit if self.submit(app) => return Transition::Close,
+            Action::Back => return Transition::Close,
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let label = |field: usize, text: &'static str| {
+            let style = if self.field == field {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Cyan)
+            };
+            Span::styled(text, style)
+        };
+        let cursor = |field: usize| Span::styled(
+            if self.field == field { "_" } else { "" },
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK),
+        );
+        let hint = |text: String| Line::from(Span::styled(text, Style::default().fg(Color::DarkGray)));
+
+        let format = match VariableFormat::from_path(&expand_home(&self.path)) {
+            VariableFormat::Dotenv => "dotenv",
+            VariableFormat::Json => "JSON",
+        };
+        let environments = app.variable_manager.environment_names();
+        let (option, option_hint) = match (self.export, self.option) {
+            (true, false) => (app.tr("variable_transfer.leave_secrets"), app.tr("variable_transfer.leave_secrets_hint")),
+            (true, true) => (app.tr("variable_transfer.include_secrets"), app.tr("variable_transfer.include_secrets_hint")),
+            (false, false) => (app.tr("variable_transfer.merge"), app.tr("variable_transfer.merge_hint")),
+            (false, true) => (app.tr("variable_transfer.replace"), app.tr("variable_transfer.replace_hint")),
+        };
+
+        let text = vec![
+            Line::from(vec![
+                label(PATH, app.tr("transfer.file")),
+                Span::styled(&self.path, Style::default().fg(Color::Yellow)),
+                cursor(PATH),
+            ]),
+            hint(app.tr_with("variable_transfer.format_hint", &[("format", &format)])),
+            Line::from(""),
+            Line::from(vec![
+                label(ENVIRONMENT, app.tr("variable_transfer.environment")),
+                Span::styled(&self.environment, Style::default().fg(Color::Yellow)),
+                cursor(ENVIRONMENT),
+            ]),
+            hint(if environments.is_empty() {
+                app.tr("variable_transfer.environment_hint").to_string()
+            } else {
+                app.tr_with("variable_transfer.environment_choices", &[("environments", &environments.join(", "))])
+            }),
+            Line::from(""),
+            Line::from(vec![
+                label(OPTION, if self.export { app.tr("variable_transfer.secrets") } else { app.tr("variable_transfer.mode") }),
+                Span::raw(option),
+            ]),
+            hint(option_hint.to_string()),
+            Line::from(""),
+            hint(app.tr("variable_transfer.keys").to_string()),
+        ];
+        let title = if self.export { app.tr("variable_transfer.export_title") } else { app.tr("variable_transfer.import_title") };
+        let paragraph = Paragraph::new(text)
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Magenta)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui_app::tests::test_app;
+
+    #[test]
+    fn test_export_then_import_into_environment() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        app.variable_manager.set("host".to_string(), "http://localhost".to_string()).unwrap();
+        app.variable_manager.set("password".to_string(), "hunter2".to_string()).unwrap();
+        let file = temp_dir.path().join("team.env");
+        let type_text = |screen: &mut VariableTransferScreen, app: &mut AppState, text: &str| {
+            for c in text.chars() {
+                screen.handle_action(app, Action::InsertChar(c));
+            }
+        };
+
+        let mut export = VariableTransferScreen::export();
+        export.path = file.display().to_string();
+        assert_eq!(export.handle_action(&mut app, Action::Submit), Transition::Close);
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "host=http://localhost\npassword=\n");
+
+        let mut import = VariableTransferScreen::import();
+        type_text(&mut import, &mut app, &file.display().to_string());
+        import.handle_action(&mut app, Action::NextField);
+        type_text(&mut import, &mut app, "dev box");
+        import.handle_action(&mut app, Action::NextField);
+        import.handle_action(&mut app, Action::InsertChar(' '));
+        assert!(import.option);
+        assert_eq!(import.handle_action(&mut app, Action::Submit), Transition::Close);
+        let dev = app.variable_manager.environment("dev box").unwrap();
+        assert_eq!(dev.get("host").map(String::as_str), Some("http://localhost"));
+        assert_eq!(app.status_message.as_deref(), Some("Imported 2 variables into environment 'dev box' (2 added or changed)"));
+
+        let mut missing = VariableTransferScreen::import();
+        missing.path = temp_dir.path().join("nope.env").display().to_string();
+        assert_eq!(missing.handle_action(&mut app, Action::Submit), Transition::Stay);
+        assert!(app.error_message.as_deref().unwrap().starts_with("Failed to import"));
+    }
+}
@@ -0,0 +1,299 @@
+// WebSocket client
+// Opened instead of sending a request for endpoints with a ws:// or wss://
+// URL. Shows a live, scrolling log of messages sent and received; the
+// endpoint's body, if any, is offered as the first message to send.
+
+use super::{ScreenView, Transition};
+use crate::formatter;
+use crate::http::RequestDetails;
+use crate::tui::app::AppState;
+use crate::tui::input::{Action, InputContext};
+use crate::ws::{WsConnection, WsEvent};
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+/// Older messages are dropped beyond this many
+const MAX_LOG: usize = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogKind {
+    Sent,
+    Received,
+    Info,
+    Error,
+}
+
+struct LogEntry {
+    at: DateTime<Local>,
+    kind: LogKind,
+    text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConnectionState {
+    Connecting,
+    Open,
+    Closed,
+}
+
+pub struct WebSocketScreen {
+    collection_index: usize,
+    endpoint_index: usize,
+    request: RequestDetails, // Kept to reconnect
+    connection: Option<WsConnection>,
+    state: ConnectionState,
+    log: Vec<LogEntry>,
+    input: String,
+    scroll: usize, // Lines scrolled up from the bottom
+}
+
+impl WebSocketScreen {
+    /// Connect right away to the prepared request's URL and headers
+    pub fn new(app: &AppState, collection_index: usize, endpoint_index: usize, request: RequestDetails) -> Self {
+        let input = request.body.as_deref().map(|b| String::from_utf8_lossy(b).into_owned()).unwrap_or_default();
+        let mut screen = Self {
+            collection_index,
+            endpoint_index,
+            request,
+            connection: None,
+            state: ConnectionState::Closed,
+            log: Vec::new(),
+            input,
+            scroll: 0,
+        };
+        screen.connect(app);
+        screen
+    }
+
+    fn connect(&mut self, app: &AppState) {
+        self.push(LogKind::Info, app.tr_with("websocket.connecting_to", &[("url", &self.request.url)]));
+        match WsConnection::connect(app.runtime.handle(), &self.request) {
+            Ok(connection) => {
+                self.connection = Some(connection);
+                self.state = ConnectionState::Connecting;
+            }
+            Err(e) => self.push(LogKind::Error, e.to_string()),
+        }
+    }
+
+    fn push(&mut self, kind: LogKind, text: String) {
+        self.log.push(LogEntry { at: Local::now(), kind, text });
+        if self.log.len() > MAX_LOG {
+            self.log.remove(0);
+        }
+        // Stay on the same lines while scrolled back
+        if self.scroll > 0 {
+            self.scroll += 1;
+        }
+    }
+
+    fn apply(&mut self, event: WsEvent, app: &AppState) {
+        match event {
+            WsEvent::Connected(status) => {
+                self.state = ConnectionState::Open;
+                self.push(LogKind::Info, app.tr_with("websocket.connected", &[("status", &status)]));
+            }
+            WsEvent::Sent(text) => self.push(LogKind::Sent, text),
+            WsEvent::Received(text) => self.push(LogKind::Received, text),
+            WsEvent::Closed(reason) => {
+                self.state = ConnectionState::Closed;
+                self.push(LogKind::Info, match reason {
+                    Some(reason) if !reason.is_empty() => app.tr_with("websocket.closed_because", &[("reason", &reason)]),
+                    _ => app.tr("websocket.closed").to_string(),
+                });
+            }
+            WsEvent::Error(e) => {
+                self.state = ConnectionState::Closed;
+                self.push(LogKind::Error, e);
+            }
+        }
+    }
+}
+
+impl ScreenView for WebSocketScreen {
+    fn input_context(&self) -> InputContext {
+        InputContext::Form
+    }
+
+    fn handle_action(&mut self, app: &mut AppState, action: Action) -> Transition {
+        match action {
+            Action::InsertChar(c) => self.input.push(c),
+            Action::DeleteChar => { self.input.pop(); }
+            Action::FormatBody => match formatter::format_json_template(&self.input) {
+                Ok(formatted) => self.input = formatted,
+                Err(e) => app.error_message = Some(app.tr_with("websocket.invalid_message", &[("error", &e)])),
+            },
+            Action::Up => self.scroll = (self.scroll + 1).min(self.log.len().saturating_sub(1)),
+            Action::Down => self.scroll = self.scroll.saturating_sub(1),
+            // Enter on a closed connection reconnects
+            Action::Submit if self.state == ConnectionState::Closed => self.connect(app),
+            Action::Submit if self.state == ConnectionState::Connecting => {
+                app.status_message = Some(app.tr("websocket.still_connecting").to_string());
+            }
+            Action::Submit if !self.input.is_empty() => {
+                let sent = self.connection.as_ref().is_some_and(|c| c.send(self.input.clone()));
+                if sent {
+                    self.input.clear();
+                    self.scroll = 0;
+                } else {
+                    app.error_message = Some(app.tr("websocket.connection_closed").to_string());
+                }
+            }
+            Action::Back => {
+                if let Some(connection) = self.connection.take() {
+                    connection.close();
+                }
+                return Transition::Close;
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+
+    fn is_animating(&self) -> bool {
+        self.connection.is_some()
+    }
+
+    /// Pick up messages and connection changes
+    fn tick(&mut self, app: &mut AppState) {
+        let Some(connection) = &self.connection else { return };
+        let finished = connection.is_finished();
+        let events = connection.poll();
+        for event in events {
+            self.apply(event, app);
+        }
+        // Every event is sent before the task ends, so none are missed
+        if finished {
+            self.connection = None;
+            self.state = ConnectionState::Closed;
+        }
+    }
+
+    fn draw(&self, f: &mut Frame, area: Rect, app: &AppState) {
+        let name = app.collections.get(self.collection_index)
+            .and_then(|c| c.endpoints.get(self.endpoint_index))
+            .map(|e| e.name.as_str())
+            .unwrap_or_default();
+        let (state, state_color) = match self.state {
+            ConnectionState::Connecting => (app.tr("websocket.state_connecting"), Color::Yellow),
+            ConnectionState::Open => (app.tr("websocket.state_open"), Color::Green),
+            ConnectionState::Closed => (app.tr("websocket.state_closed"), Color::Red),
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(area);
+
+        let height = chunks[0].height.saturating_sub(2) as usize;
+        let end = self.log.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(height);
+        let lines: Vec<Line> = self.log[start..end].iter().map(|entry| {
+            let (arrow, color) = match entry.kind {
+                LogKind::Sent => ("→ ", Color::Green),
+                LogKind::Received => ("← ", Color::Cyan),
+                LogKind::Info => ("• ", Color::Yellow),
+                LogKind::Error => ("✗ ", Color::Red),
+            };
+            Line::from(vec![
+                Span::styled(format!("{} ", entry.at.format("%H:%M:%S%.3f")), Style::default().fg(Color::DarkGray)),
+                Span::styled(arrow, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(entry.text.clone(), Style::default().fg(if entry.kind == LogKind::Received { Color::White } else { color })),
+            ])
+        }).collect();
+        let log = Paragraph::new(lines)
+            .block(Block::default()
+                .title(Line::from(vec![
+                    Span::raw(format!("🔌 WebSocket: {} ", name)),
+                    Span::styled(format!("[{}]", state), Style::default().fg(state_color).add_modifier(Modifier::BOLD)),
+                    Span::raw(app.tr_with("websocket.title", &[("url", &self.request.url), ("count", &self.log.len())])),
+                ]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(log, chunks[0]);
+
+        let hint = match self.state {
+            ConnectionState::Closed => app.tr("websocket.reconnect_hint"),
+            _ => app.tr("websocket.send_hint"),
+        };
+        let input = Paragraph::new(Line::from(vec![
+            Span::raw(self.input.replace('\n', " ")),
+            Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
+        ]))
+            .block(Block::default()
+                .title(hint)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Yellow)));
+        f.render_widget(input, chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui_app::tests::test_app;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::protocol::Message;
+
+    #[test]
+    fn test_send_and_receive() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+
+        let listener = app.runtime.block_on(tokio::net::TcpListener::bind("127.0.0.1:0")).unwrap();
+        let url = format!("ws://{}/feed", listener.local_addr().unwrap());
+        app.runtime.spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut stream = tokio_tungstenite::accept_async(socket).await.unwrap();
+                    while let Some(Ok(Message::Text(text))) = stream.next().await {
+                        stream.send(Message::Text(text.to_uppercase())).await.unwrap();
+                    }
+                });
+            }
+        });
+        let mut endpoint = ApiEndpoint::new("feed".to_string(), HttpMethod::GET, url);
+        endpoint.body_template = Some("{\"subscribe\": \"{{topic}}\"}".to_string());
+        let mut collection = ApiCollection::new("Live".to_string());
+        collection.add_endpoint(endpoint);
+        app.collections.push(collection);
+        app.variable_manager.set("topic".to_string(), "prices".to_string()).unwrap();
+
+        // Executing the endpoint opens the screen rather than sending a request
+        app.quick_execute_request(0, 0);
+        assert!(app.view.take().is_some());
+        assert!(app.in_flight.is_empty());
+
+        let inputs = crate::http::RequestInputs { variables: app.request_variables(), ..Default::default() };
+        let request = app.http_client.prepare(&app.collections[0].endpoints[0], &inputs).unwrap();
+        let mut screen = WebSocketScreen::new(&app, 0, 0, request);
+        let settle = |screen: &mut WebSocketScreen, app: &mut AppState, until: usize| {
+            for _ in 0..300 {
+                screen.tick(app);
+                if screen.log.len() >= until {
+                    break;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+        };
+        settle(&mut screen, &mut app, 2);
+        assert_eq!(screen.state, ConnectionState::Open);
+        assert_eq!(screen.input, "{\"subscribe\": \"prices\"}");
+        screen.handle_action(&mut app, Action::Submit);
+        assert!(screen.input.is_empty());
+        settle(&mut screen, &mut app, 4);
+        let log: Vec<(LogKind, &str)> = screen.log.iter().map(|e| (e.kind, e.text.as_str())).collect();
+        assert_eq!(log[2..], [(LogKind::Sent, "{\"subscribe\": \"prices\"}"), (LogKind::Received, "{\"SUBSCRIBE\": \"PRICES\"}")]);
+        assert_eq!(screen.handle_action(&mut app, Action::Back), Transition::Close);
+        assert!(!screen.is_animating());
+    }
+}
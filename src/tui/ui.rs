@@ -1,6 +1,13 @@
 use super::app::{AppState, Screen};
+use super::input::{self, Keymap};
+use super::ui_utils::{fmt_duration, method_icon, status_symbol};
+use crate::formatter;
+use crate::i18n::{self, Locale};
+use crate::models::{Assertion, Extraction, SoapVersion};
+use crate::settings::Theme;
 use ratatui::{
     backend::Backend,
+    buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -8,7 +15,7 @@ use ratatui::{
     Frame, Terminal,
 };
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -32,11 +39,12 @@ fn get_pulse_color(elapsed_millis: u128) -> Color {
     }
 }
 
-/// Colorize JSON text with syntax highlighting and rainbow bracket matching
-fn colorize_json(json_text: &str) -> Vec<Line<'_>> {
+/// Colorize JSON text with syntax highlighting and rainbow bracket matching.
+/// `depth` is how many brackets are open before the text, for a window of a
+/// larger document.
+pub fn colorize_json(json_text: &str, depth: usize) -> Vec<Line<'_>> {
     let mut lines = Vec::new();
     let mut current_line = Vec::new();
-    let mut brace_stack: Vec<Color> = Vec::new();
     let brace_colors = [
         Color::Cyan,
         Color::Yellow,
@@ -47,6 +55,7 @@ fn colorize_json(json_text: &str) -> Vec<Line<'_>> {
         Color::LightYellow,
         Color::LightMagenta,
     ];
+    let mut brace_stack: Vec<Color> = (0..depth).map(|d| brace_colors[d % brace_colors.len()]).collect();
     
     let mut chars = json_text.chars().peekable();
     let mut in_string = false;
@@ -89,8 +98,8 @@ fn colorize_json(json_text: &str) -> Vec<Line<'_>> {
                 }
                 in_string = true;
                 // Check if this is a key (followed by :)
-                let mut temp_chars = chars.clone();
-                while let Some(next_ch) = temp_chars.next() {
+                let temp_chars = chars.clone();
+                for next_ch in temp_chars {
                     if next_ch == ':' {
                         in_key = true;
                         break;
@@ -179,6 +188,12 @@ fn colorize_json(json_text: &str) -> Vec<Line<'_>> {
     lines
 }
 
+/// Brackets open before a line of 2-space indented JSON
+fn json_depth_at(line: &str) -> usize {
+    let trimmed = line.trim_start();
+    (line.len() - trimmed.len()) / 2 + usize::from(trimmed.starts_with(['}', ']']))
+}
+
 pub fn run_app() -> Result<(), Box<dyn std::error::Error>> {
     // Setup terminal
     enable_raw_mode()?;
@@ -189,6 +204,7 @@ pub fn run_app() -> Result<(), Box<dyn std::error::Error>> {
 
     // Create app state
     let mut app = AppState::new()?;
+    app.recover_interrupted_load_tests();
     
     // Run app loop
     let res = run_app_loop(&mut terminal, &mut app);
@@ -209,735 +225,111 @@ pub fn run_app() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// How long to wait for input when nothing changes on its own
+const IDLE_TICK: Duration = Duration::from_secs(1);
+
 fn run_app_loop<B: Backend>(
     terminal: &mut Terminal<B>,
     app: &mut AppState,
 ) -> io::Result<()> {
+    let keymap = Keymap::default();
+    let mut needs_redraw = true;
     loop {
-        terminal.draw(|f| draw_ui(f, app))?;
-
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                // Handle confirmation dialog first
-                if matches!(app.current_screen, Screen::ConfirmDelete(_)) {
-                    match key.code {
-                        KeyCode::Char('y') | KeyCode::Char('Y') => {
-                            app.confirm_delete_action();
-                        }
-                        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                            app.navigate_back();
-                        }
-                        _ => {}
-                    }
-                    continue;
-                }
-                
-                // Handle edit screens - they need character input
-                let in_edit_screen = matches!(app.current_screen, Screen::CollectionEdit(_) | Screen::EndpointEdit(_, _));
-                
-                // Handle Ctrl+ijkl for panel navigation (not in edit screens)
-                if !in_edit_screen && key.modifiers.contains(KeyModifiers::CONTROL) {
-                    match key.code {
-                        KeyCode::Char('k') => {
-                            // Ctrl+k: Navigate up in current panel
-                            let max = match app.panel_focus {
-                                crate::tui_app::PanelFocus::Collections => app.collections.len(),
-                                crate::tui_app::PanelFocus::Endpoints => {
-                                    app.collections.get(app.selected_collection_index)
-                                        .map(|c| c.endpoints.len())
-                                        .unwrap_or(0)
-                                }
-                            };
-                            if max > 0 {
-                                app.navigate_up();
-                            }
-                            continue;
-                        }
-                        KeyCode::Char('j') => {
-                            // Ctrl+j: Navigate down in current panel
-                            let max = match app.panel_focus {
-                                crate::tui_app::PanelFocus::Collections => app.collections.len(),
-                                crate::tui_app::PanelFocus::Endpoints => {
-                                    app.collections.get(app.selected_collection_index)
-                                        .map(|c| c.endpoints.len())
-                                        .unwrap_or(0)
-                                }
-                            };
-                            if max > 0 {
-                                app.navigate_down(max);
-                            }
-                            continue;
-                        }
-                        KeyCode::Char('h') => {
-                            // Ctrl+h: Switch to collections panel (left)
-                            app.panel_focus = crate::tui_app::PanelFocus::Collections;
-                            continue;
-                        }
-                        KeyCode::Char('l') => {
-                            // Ctrl+l: Switch to endpoints panel (right)
-                            app.panel_focus = crate::tui_app::PanelFocus::Endpoints;
-                            continue;
-                        }
-                        KeyCode::Char('i') => {
-                            // Ctrl+i: Toggle between panels (like Tab)
-                            app.toggle_panel_focus();
-                            continue;
-                        }
-                        _ => {}
-                    }
-                }
-                
-                match key.code {
-                    KeyCode::Char('q') => {
-                        if !in_edit_screen && matches!(app.current_screen, Screen::CollectionList) {
-                            return Ok(());
-                        } else if in_edit_screen {
-                            // In edit screen, 'q' is just a character
-                            match &app.current_screen {
-                                Screen::CollectionEdit(_) => {
-                                    if let Some(form) = &mut app.collection_form {
-                                        form.name.push('q');
-                                    }
-                                }
-                                Screen::EndpointEdit(_, _) => {
-                                    if let Some(form) = &mut app.endpoint_form {
-                                        match form.current_field {
-                                            0 => form.name.push('q'),
-                                            2 => form.url.push('q'),
-                                            3 => form.description.push('q'),
-                                            5 => form.body_template.push('q'),
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                    KeyCode::Esc => {
-                        // Check if in header edit mode first
-                        if matches!(app.current_screen, Screen::EndpointEdit(_, _)) {
-                            if let Some(form) = &app.endpoint_form {
-                                if form.header_edit_mode {
-                                    app.toggle_header_edit_mode();
-                                    continue;
-                                }
-                            }
-                        }
-                        
-                        if matches!(app.current_screen, Screen::LoadTestRunning(_, _)) {
-                            app.stop_load_test();
-                            app.navigate_back();
-                        } else {
-                            app.navigate_back();
-                        }
-                    }
-                    KeyCode::Char('?') => {
-                        if !in_edit_screen {
-                            app.current_screen = Screen::Help;
-                        } else {
-                            // In edit screen, '?' is just a character
-                            match &app.current_screen {
-                                Screen::CollectionEdit(_) => {
-                                    if let Some(form) = &mut app.collection_form {
-                                        form.name.push('?');
-                                    }
-                                }
-                                Screen::EndpointEdit(_, _) => {
-                                    if let Some(form) = &mut app.endpoint_form {
-                                        match form.current_field {
-                                            0 => form.name.push('?'),
-                                            2 => form.url.push('?'),
-                                            3 => form.description.push('?'),
-                                            5 => form.body_template.push('?'),
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        if !in_edit_screen {
-                            // Handle variable list navigation
-                            if matches!(app.current_screen, Screen::VariableList) {
-                                let max = app.variable_manager.len();
-                                if max > 0 && app.selected_index > 0 {
-                                    app.selected_index -= 1;
-                                }
-                            } else {
-                                let max = match app.panel_focus {
-                                    crate::tui_app::PanelFocus::Collections => app.collections.len(),
-                                    crate::tui_app::PanelFocus::Endpoints => {
-                                        app.collections.get(app.selected_collection_index)
-                                            .map(|c| c.endpoints.len())
-                                            .unwrap_or(0)
-                                    }
-                                };
-                                if max > 0 {
-                                    app.navigate_up();
-                                }
-                            }
-                        } else if key.code == KeyCode::Char('k') {
-                            // In edit screen, 'k' is just a character
-                            match &app.current_screen {
-                                Screen::CollectionEdit(_) => {
-                                    if let Some(form) = &mut app.collection_form {
-                                        form.name.push('k');
-                                    }
-                                }
-                                Screen::EndpointEdit(_, _) => {
-                                    if let Some(form) = &mut app.endpoint_form {
-                                        match form.current_field {
-                                            0 => form.name.push('k'),
-                                            2 => form.url.push('k'),
-                                            3 => form.description.push('k'),
-                                            5 => form.body_template.push('k'),
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        if !in_edit_screen {
-                            // Handle variable list navigation
-                            if matches!(app.current_screen, Screen::VariableList) {
-                                let max = app.variable_manager.len();
-                                if max > 0 && app.selected_index < max - 1 {
-                                    app.selected_index += 1;
-                                }
-                            } else {
-                                let max = match app.panel_focus {
-                                    crate::tui_app::PanelFocus::Collections => app.collections.len(),
-                                    crate::tui_app::PanelFocus::Endpoints => {
-                                        app.collections.get(app.selected_collection_index)
-                                            .map(|c| c.endpoints.len())
-                                            .unwrap_or(0)
-                                    }
-                                };
-                                if max > 0 {
-                                    app.navigate_down(max);
-                                }
-                            }
-                        } else if key.code == KeyCode::Char('j') {
-                            // In edit screen, 'j' is just a character
-                            match &app.current_screen {
-                                Screen::CollectionEdit(_) => {
-                                    if let Some(form) = &mut app.collection_form {
-                                        form.name.push('j');
-                                    }
-                                }
-                                Screen::EndpointEdit(_, _) => {
-                                    if let Some(form) = &mut app.endpoint_form {
-                                        match form.current_field {
-                                            0 => form.name.push('j'),
-                                            2 => form.url.push('j'),
-                                            3 => form.description.push('j'),
-                                            5 => form.body_template.push('j'),
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                    KeyCode::Enter => {
-                        match &app.current_screen {
-                            Screen::CollectionEdit(_) => {
-                                app.save_collection();
-                            }
-                            Screen::EndpointEdit(_, _) => {
-                                // Check if in header edit mode
-                                if let Some(form) = &app.endpoint_form {
-                                    if form.header_edit_mode {
-                                        app.add_header();
-                                    } else {
-                                        app.save_endpoint();
-                                    }
-                                } else {
-                                    app.save_endpoint();
-                                }
-                            }
-                            Screen::LoadTestConfig(_, _) => {
-                                // Start load test with configured parameters
-                                app.execute_load_test();
-                            }
-                            Screen::VariableEdit(_) => {
-                                // Save variable
-                                app.save_variable();
-                            }
-                            Screen::VariableInput(_, _) => {
-                                // Execute request with variables
-                                app.execute_request_with_variables();
-                            }
-                            Screen::CollectionList => {
-                                // In new layout, Enter selects endpoint to view
-                                if app.panel_focus == crate::tui_app::PanelFocus::Endpoints {
-                                    if let Some(collection) = app.collections.get(app.selected_collection_index) {
-                                        if app.selected_endpoint_index < collection.endpoints.len() {
-                                            app.current_screen = Screen::EndpointDetail(
-                                                app.selected_collection_index,
-                                                app.selected_endpoint_index
-                                            );
-                                        }
-                                    }
-                                } else {
-                                    // Switch to endpoints panel
-                                    app.panel_focus = crate::tui_app::PanelFocus::Endpoints;
-                                }
-                            }
-                            _ => {
-                                app.select();
-                            }
-                        }
-                    }
-                    KeyCode::Char(c) => {
-                        // Check if in LoadTestConfig screen - handle numeric input
-                        if matches!(app.current_screen, Screen::LoadTestConfig(_, _)) {
-                            if let Some(form) = &mut app.load_test_config_form {
-                                // Only allow digits for numeric fields
-                                if c.is_ascii_digit() {
-                                    match form.current_field {
-                                        0 => form.concurrency.push(c),
-                                        1 => form.duration.push(c),
-                                        2 => form.ramp_up.push(c),
-                                        _ => {}
-                                    }
-                                }
-                            }
-                            continue;
-                        }
-                        
-                        // Check if in VariableEdit screen - handle text input
-                        if matches!(app.current_screen, Screen::VariableEdit(_)) {
-                            if let Some(form) = &mut app.variable_form {
-                                match form.current_field {
-                                    0 => form.key.push(c),
-                                    1 => form.value.push(c),
-                                    _ => {}
-                                }
-                            }
-                            continue;
-                        }
-                        
-                        // Check if in VariableInput screen - handle text input
-                        if matches!(app.current_screen, Screen::VariableInput(_, _)) {
-                            if let Some(form) = &mut app.variable_input_form {
-                                if let Some(var_name) = form.required_vars.get(form.current_index) {
-                                    if let Some(value) = form.variables.get_mut(var_name) {
-                                        value.push(c);
-                                    }
-                                }
-                            }
-                            continue;
-                        }
-                        
-                        // Check if in header edit mode
-                        if matches!(app.current_screen, Screen::EndpointEdit(_, _)) {
-                            if let Some(form) = &app.endpoint_form {
-                                if form.header_edit_mode {
-                                    // In header edit mode, handle text input
-                                    if let Some(form) = &mut app.endpoint_form {
-                                        match form.header_edit_field {
-                                            0 => form.header_key.push(c),
-                                            1 => form.header_value.push(c),
-                                            _ => {}
-                                        }
-                                    }
-                                    continue;
-                                }
-                            }
-                        }
-                        
-                        // Special handling for 'h' in endpoint edit - toggle header mode ONLY on headers field
-                        if c == 'h' && matches!(app.current_screen, Screen::EndpointEdit(_, _)) {
-                            if let Some(form) = &app.endpoint_form {
-                                // Only toggle header mode if on the headers field (field 4) and not already in header mode
-                                if !form.header_edit_mode && form.current_field == 4 {
-                                    app.toggle_header_edit_mode();
-                                    continue;
-                                }
-                            }
-                        }
-                        
-                        // Special handling for 'm' in endpoint edit - cycle method ONLY on method field
-                        if c == 'm' && matches!(app.current_screen, Screen::EndpointEdit(_, _)) {
-                            if let Some(form) = &app.endpoint_form {
-                                // Only cycle method if we're on the method field (field 1)
-                                if form.current_field == 1 {
-                                    app.cycle_http_method();
-                                } else {
-                                    // Otherwise, treat 'm' as regular text input
-                                    if let Some(form) = &mut app.endpoint_form {
-                                        match form.current_field {
-                                            0 => form.name.push(c),
-                                            2 => form.url.push(c),
-                                            3 => form.description.push(c),
-                                            5 => form.body_template.push(c),
-                                            6 => {
-                                                // Only allow digits for timeout
-                                                if c.is_ascii_digit() {
-                                                    form.timeout_secs.push(c);
-                                                }
-                                            }
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        // In edit screens, all other characters are input
-                        else if in_edit_screen {
-                            match &app.current_screen {
-                                Screen::CollectionEdit(_) => {
-                                    if let Some(form) = &mut app.collection_form {
-                                        form.name.push(c);
-                                    }
-                                }
-                                Screen::EndpointEdit(_, _) => {
-                                    if let Some(form) = &mut app.endpoint_form {
-                                        match form.current_field {
-                                            0 => form.name.push(c),
-                                            2 => form.url.push(c),
-                                            3 => form.description.push(c),
-                                            5 => form.body_template.push(c),
-                                            6 => {
-                                                // Only allow digits for timeout
-                                                if c.is_ascii_digit() {
-                                                    form.timeout_secs.push(c);
-                                                }
-                                            }
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
-                        } else {
-                            // Not in edit screen, handle as commands
-                            match c {
-                                'n' => {
-                                    // New collection, endpoint, or variable based on screen
-                                    if matches!(app.current_screen, Screen::VariableList) {
-                                        app.start_new_variable();
-                                    } else {
-                                        // New collection or endpoint based on panel focus
-                                        match app.panel_focus {
-                                            crate::tui_app::PanelFocus::Collections => {
-                                                app.start_new_collection();
-                                            }
-                                            crate::tui_app::PanelFocus::Endpoints => {
-                                                app.start_new_endpoint(app.selected_collection_index);
-                                            }
-                                        }
-                                    }
-                                }
-                                'e' => {
-                                    // Edit or Execute based on context
-                                    if matches!(app.current_screen, Screen::VariableList) {
-                                        // Edit variable
-                                        let keys = app.variable_manager.keys();
-                                        if app.selected_index < keys.len() {
-                                            let key = keys[app.selected_index].clone();
-                                            app.start_edit_variable(key);
-                                        }
-                                    } else if matches!(app.current_screen, Screen::EndpointDetail(_, _)) {
-                                        // Execute request with variable detection
-                                        app.start_variable_input(
-                                            app.selected_collection_index,
-                                            app.selected_endpoint_index
-                                        );
-                                    } else {
-                                        // Edit collection or endpoint based on panel focus
-                                        match app.panel_focus {
-                                            crate::tui_app::PanelFocus::Collections => {
-                                                if app.selected_collection_index < app.collections.len() {
-                                                    app.start_edit_collection(app.selected_collection_index);
-                                                }
-                                            }
-                                            crate::tui_app::PanelFocus::Endpoints => {
-                                                if let Some(collection) = app.collections.get(app.selected_collection_index) {
-                                                    if app.selected_endpoint_index < collection.endpoints.len() {
-                                                        app.start_edit_endpoint(
-                                                            app.selected_collection_index,
-                                                            app.selected_endpoint_index
-                                                        );
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                                'd' => {
-                                    // Delete collection, endpoint, or variable based on screen
-                                    if matches!(app.current_screen, Screen::VariableList) {
-                                        // Delete variable
-                                        let keys = app.variable_manager.keys();
-                                        if app.selected_index < keys.len() {
-                                            let key = keys[app.selected_index].clone();
-                                            app.confirm_delete_variable(key);
-                                        }
-                                    } else {
-                                        // Delete collection or endpoint based on panel focus
-                                        match app.panel_focus {
-                                            crate::tui_app::PanelFocus::Collections => {
-                                                if app.selected_collection_index < app.collections.len() {
-                                                    app.confirm_delete_collection(app.selected_collection_index);
-                                                }
-                                            }
-                                            crate::tui_app::PanelFocus::Endpoints => {
-                                                if let Some(collection) = app.collections.get(app.selected_collection_index) {
-                                                    if app.selected_endpoint_index < collection.endpoints.len() {
-                                                        app.confirm_delete_endpoint(
-                                                            app.selected_collection_index,
-                                                            app.selected_endpoint_index
-                                                        );
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                                'l' => {
-                                    // Start load test if endpoint is selected
-                                    if let Some(collection) = app.collections.get(app.selected_collection_index) {
-                                        if app.selected_endpoint_index < collection.endpoints.len() {
-                                            app.start_load_test(
-                                                app.selected_collection_index,
-                                                app.selected_endpoint_index
-                                            );
-                                        }
-                                    }
-                                }
-                                'v' => {
-                                    // Open variable list
-                                    app.current_screen = Screen::VariableList;
-                                }
-                                'x' => {
-                                    // Quick execute - execute endpoint directly from main screen
-                                    if app.panel_focus == crate::tui_app::PanelFocus::Endpoints {
-                                        if let Some(collection) = app.collections.get(app.selected_collection_index) {
-                                            if app.selected_endpoint_index < collection.endpoints.len() {
-                                                // Execute immediately with saved variable values
-                                                app.quick_execute_request(
-                                                    app.selected_collection_index,
-                                                    app.selected_endpoint_index
-                                                );
-                                            }
-                                        }
-                                    }
-                                }
-                                't' => {
-                                    // Toggle network traffic display
-                                    app.toggle_network_traffic();
-                                }
-                                'H' => {
-                                    // Toggle response headers display
-                                    app.toggle_response_headers();
-                                }
-                                'y' => {
-                                    // Copy response to clipboard
-                                    if matches!(app.current_screen, Screen::EndpointDetail(_, _)) && app.last_response.is_some() {
-                                        app.copy_response_to_clipboard();
-                                    }
-                                }
-                                ' ' => {
-                                    // Toggle collapsible sections (Space key)
-                                    // Only works when viewing endpoint details with response
-                                    if matches!(app.current_screen, Screen::EndpointDetail(_, _)) && app.last_response.is_some() {
-                                        // Determine which section to toggle based on context
-                                        // If response headers are shown, toggle headers section
-                                        // If network traffic is shown, toggle network traffic section
-                                        if app.show_response_headers {
-                                            app.toggle_section_collapsed("response_headers");
-                                        } else if app.show_network_traffic {
-                                            app.toggle_section_collapsed("network_traffic");
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                    KeyCode::Backspace => {
-                        // Delete character in forms
-                        match &app.current_screen {
-                            Screen::LoadTestConfig(_, _) => {
-                                if let Some(form) = &mut app.load_test_config_form {
-                                    match form.current_field {
-                                        0 => { form.concurrency.pop(); }
-                                        1 => { form.duration.pop(); }
-                                        2 => { form.ramp_up.pop(); }
-                                        _ => {}
-                                    }
-                                }
-                            }
-                            Screen::VariableEdit(_) => {
-                                if let Some(form) = &mut app.variable_form {
-                                    match form.current_field {
-                                        0 => { form.key.pop(); }
-                                        1 => { form.value.pop(); }
-                                        _ => {}
-                                    }
-                                }
-                            }
-                            Screen::VariableInput(_, _) => {
-                                if let Some(form) = &mut app.variable_input_form {
-                                    if let Some(var_name) = form.required_vars.get(form.current_index) {
-                                        if let Some(value) = form.variables.get_mut(var_name) {
-                                            value.pop();
-                                        }
-                                    }
-                                }
-                            }
-                            Screen::CollectionEdit(_) => {
-                                if let Some(form) = &mut app.collection_form {
-                                    form.name.pop();
-                                }
-                            }
-                            Screen::EndpointEdit(_, _) => {
-                                if let Some(form) = &mut app.endpoint_form {
-                                    if form.header_edit_mode {
-                                        // In header edit mode
-                                        match form.header_edit_field {
-                                            0 => { form.header_key.pop(); }
-                                            1 => { form.header_value.pop(); }
-                                            _ => {}
-                                        }
-                                    } else {
-                                        // Normal field editing
-                                        match form.current_field {
-                                            0 => { form.name.pop(); }
-                                            2 => { form.url.pop(); }
-                                            3 => { form.description.pop(); }
-                                            5 => { form.body_template.pop(); }
-                                            6 => { form.timeout_secs.pop(); }
-                                            _ => {}
-                                        }
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                    KeyCode::Tab => {
-                        // Move to next field in forms
-                        if let Screen::EndpointEdit(_, _) = app.current_screen {
-                            if let Some(form) = &mut app.endpoint_form {
-                                if form.header_edit_mode {
-                                    // In header edit mode, cycle between key and value
-                                    app.cycle_header_field();
-                                } else {
-                                    // Normal field navigation
-                                    form.current_field = (form.current_field + 1) % 7;
-                                }
-                            }
-                        } else if let Screen::LoadTestConfig(_, _) = app.current_screen {
-                            if let Some(form) = &mut app.load_test_config_form {
-                                form.current_field = (form.current_field + 1) % 3;
-                            }
-                        } else if let Screen::VariableEdit(_) = app.current_screen {
-                            if let Some(form) = &mut app.variable_form {
-                                form.current_field = (form.current_field + 1) % 2;
-                            }
-                        } else if let Screen::VariableInput(_, _) = app.current_screen {
-                            if let Some(form) = &mut app.variable_input_form {
-                                form.current_index = (form.current_index + 1) % form.required_vars.len();
-                            }
-                        }
-                    }
-                    KeyCode::BackTab => {
-                        // Move to previous field in forms (Shift+Tab)
-                        if let Screen::EndpointEdit(_, _) = app.current_screen {
-                            if let Some(form) = &mut app.endpoint_form {
-                                if form.header_edit_mode {
-                                    // In header edit mode, cycle between key and value (backwards)
-                                    form.header_edit_field = if form.header_edit_field == 0 { 1 } else { 0 };
-                                } else {
-                                    // Normal field navigation
-                                    form.current_field = if form.current_field == 0 {
-                                        6
-                                    } else {
-                                        form.current_field - 1
-                                    };
-                                }
-                            }
-                        } else if let Screen::LoadTestConfig(_, _) = app.current_screen {
-                            if let Some(form) = &mut app.load_test_config_form {
-                                form.current_field = if form.current_field == 0 {
-                                    2
-                                } else {
-                                    form.current_field - 1
-                                };
-                            }
-                        } else if let Screen::VariableEdit(_) = app.current_screen {
-                            if let Some(form) = &mut app.variable_form {
-                                form.current_field = if form.current_field == 0 { 1 } else { 0 };
-                            }
-                        } else if let Screen::VariableInput(_, _) = app.current_screen {
-                            if let Some(form) = &mut app.variable_input_form {
-                                form.current_index = if form.current_index == 0 {
-                                    form.required_vars.len() - 1
-                                } else {
-                                    form.current_index - 1
-                                };
-                            }
-                        }
-                    }
-                    KeyCode::PageUp => {
-                        // Shift+PageUp: Scroll headers up (5 lines)
-                        // PageUp: Scroll response up (10 lines)
-                        if !in_edit_screen {
-                            if key.modifiers.contains(KeyModifiers::SHIFT) {
-                                app.scroll_headers_up(5);
-                            } else {
-                                app.scroll_response_up(10);
-                            }
-                        }
-                    }
-                    KeyCode::PageDown => {
-                        // Shift+PageDown: Scroll headers down (5 lines)
-                        // PageDown: Scroll response down (10 lines)
-                        if !in_edit_screen {
-                            if key.modifiers.contains(KeyModifiers::SHIFT) {
-                                app.scroll_headers_down(5);
-                            } else {
-                                app.scroll_response_down(10);
-                            }
-                        }
-                    }
-                    KeyCode::Home => {
-                        // Shift+Home: Scroll to top of headers
-                        // Home: Scroll to top of response
-                        if !in_edit_screen {
-                            if key.modifiers.contains(KeyModifiers::SHIFT) {
-                                app.reset_headers_scroll();
-                            } else {
-                                app.reset_response_scroll();
-                            }
-                        }
-                    }
-                    KeyCode::End => {
-                        // End: Scroll to bottom of response (headers don't need End)
-                        if !in_edit_screen {
-                            app.scroll_response_to_end();
-                        }
+        // Only redraw after input, a resize, or an animation tick
+        if needs_redraw {
+            terminal.draw(|f| draw_ui(f, app))?;
+            needs_redraw = false;
+        }
+
+        let animating = app.is_animating();
+        // Redraw interval while something on screen animates (load test progress and charts,
+        // requests in flight, a macro playing)
+        let tick = if animating { app.settings.tick_rate() } else { IDLE_TICK };
+        if event::poll(tick)? {
+            match event::read()? {
+                Event::Key(key) => {
+                    if input::handle_key(app, &keymap, key) {
+                        return Ok(());
                     }
-                    _ => {}
+                    needs_redraw = true;
                 }
+                Event::Resize(_, _) => needs_redraw = true,
+                _ => {}
             }
+        } else if animating {
+            app.tick();
+            if input::play_macro_keys(app, &keymap) {
+                return Ok(());
+            }
+            needs_redraw = true;
         }
     }
 }
 
-fn draw_ui(f: &mut Frame, app: &AppState) {
+pub(super) fn draw_ui(f: &mut Frame, app: &AppState) {
+    draw_screen(f, app);
+    match app.settings.theme {
+        Theme::Dark => {}
+        // Screens are drawn for dark backgrounds; swap the colours that vanish on light ones
+        Theme::Light => for cell in f.buffer_mut().content.iter_mut() {
+            cell.fg = light_color(cell.fg);
+        },
+        // Red and green look alike to many colour-blind users; show them as orange and blue
+        Theme::HighContrast => for cell in f.buffer_mut().content.iter_mut() {
+            if matches!(cell.fg, Color::Green | Color::LightGreen | Color::Red | Color::LightRed) {
+                cell.modifier |= Modifier::BOLD;
+            }
+            cell.fg = high_contrast_color(cell.fg);
+        },
+    }
+    if NARROW_EMOJI {
+        narrow_emoji(f.buffer_mut());
+    }
+}
+
+/// ConPTY draws an emoji made wide by a variation selector (`✏️`, `🗑️`) one
+/// column wide where ratatui counts two, pushing the rest of the line left
+const NARROW_EMOJI: bool = cfg!(windows);
+
+/// Drop the emoji variation selectors so those symbols are drawn as text, one
+/// column wide; the column ratatui reserved after them becomes a blank
+fn narrow_emoji(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if cell.symbol().contains('\u{fe0f}') {
+            let narrow = cell.symbol().replace('\u{fe0f}', "");
+            cell.set_symbol(&narrow);
+        }
+    }
+}
+
+fn light_color(color: Color) -> Color {
+    match color {
+        Color::White => Color::Black,
+        Color::Gray => Color::DarkGray,
+        Color::DarkGray => Color::Gray,
+        Color::Yellow | Color::LightYellow => Color::Magenta,
+        Color::Cyan | Color::LightCyan => Color::Blue,
+        Color::LightGreen => Color::Green,
+        Color::LightRed => Color::Red,
+        other => other,
+    }
+}
+
+/// Okabe-Ito colours, which stay apart with red-green colour blindness
+fn high_contrast_color(color: Color) -> Color {
+    match color {
+        Color::Green | Color::LightGreen => Color::Rgb(86, 180, 233),
+        Color::Red | Color::LightRed => Color::Rgb(230, 159, 0),
+        Color::Yellow | Color::LightYellow => Color::Rgb(240, 228, 66),
+        Color::DarkGray => Color::Gray,
+        Color::Gray => Color::White,
+        other => other,
+    }
+}
+
+fn draw_screen(f: &mut Frame, app: &AppState) {
     // For full-screen modes (edit, help, dialogs), use old layout
-    let use_split_layout = matches!(
+    let use_split_layout = app.view.is_none() && matches!(
         app.current_screen,
         Screen::CollectionList | Screen::EndpointList(_) | Screen::EndpointDetail(_, _) | Screen::ResponseView(_, _)
     );
@@ -953,18 +345,22 @@ fn draw_ui(f: &mut Frame, app: &AppState) {
             ])
             .split(f.area());
 
-        draw_title(f, chunks[0]);
+        draw_title(f, chunks[0], app);
+        
+        if let Some(view) = &app.view {
+            view.draw(f, chunks[1], app);
+            draw_footer(f, chunks[2], app);
+            return;
+        }
         
         match &app.current_screen {
             Screen::CollectionEdit(_) => draw_collection_edit(f, chunks[1], app),
             Screen::EndpointEdit(coll_idx, _) => draw_endpoint_edit(f, chunks[1], app, *coll_idx),
-            Screen::LoadTestConfig(_, _) => draw_load_test_config(f, chunks[1], app),
             Screen::LoadTestRunning(coll_idx, ep_idx) => draw_load_test(f, chunks[1], app, *coll_idx, *ep_idx),
             Screen::VariableList => draw_variable_list(f, chunks[1], app),
             Screen::VariableEdit(_) => draw_variable_edit(f, chunks[1], app),
             Screen::VariableInput(_, _) => draw_variable_input(f, chunks[1], app),
             Screen::ConfirmDelete(_) => draw_confirm_delete(f, chunks[1], app),
-            Screen::Help => draw_help(f, chunks[1]),
             _ => {}
         }
         
@@ -982,7 +378,7 @@ fn draw_ui(f: &mut Frame, app: &AppState) {
         ])
         .split(f.area());
 
-    draw_title(f, main_chunks[0]);
+    draw_title(f, main_chunks[0], app);
 
     // Split main area horizontally: left (definition) and right (collections)
     let horizontal_chunks = Layout::default()
@@ -1010,20 +406,59 @@ fn draw_ui(f: &mut Frame, app: &AppState) {
     draw_footer(f, main_chunks[2], app);
 }
 
-fn draw_title(f: &mut Frame, area: Rect) {
-    let title = Paragraph::new("🚀 REST API TUI - Terminal API Testing Tool ⚡")
+fn draw_title(f: &mut Frame, area: Rect, app: &AppState) {
+    // The active environment is always in sight, in its own colour
+    let (badge, accent) = match &app.settings.environment {
+        Some(name) => {
+            let accent = environment_color(name);
+            (Span::styled(format!(" 🌍 {} ", name), Style::default().fg(Color::Black).bg(accent).add_modifier(Modifier::BOLD)), accent)
+        }
+        None => (Span::styled(format!(" {} ", app.tr("title.no_environment")), Style::default().fg(Color::DarkGray)), Color::Cyan),
+    };
+    let title = Paragraph::new(Line::from(vec![
+        Span::raw(app.tr("title.app")),
+        badge,
+        Span::styled(app.tr("title.switch"), Style::default().fg(Color::DarkGray).remove_modifier(Modifier::BOLD)),
+    ]))
         .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
         .block(Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
-            .border_style(Style::default().fg(Color::Cyan)));
+            .border_style(Style::default().fg(accent)));
     f.render_widget(title, area);
 }
 
+/// Production is red, staging yellow, local/dev green; others get a stable colour of their own
+fn environment_color(name: &str) -> Color {
+    let lower = name.to_lowercase();
+    if lower.contains("stag") || lower.contains("uat") || lower.contains("preprod") {
+        Color::Yellow
+    } else if lower.contains("prod") || lower == "live" {
+        Color::Red
+    } else if lower.contains("dev") || lower.contains("local") || lower.contains("test") {
+        Color::Green
+    } else {
+        let palette = [Color::Magenta, Color::Blue, Color::LightCyan, Color::LightMagenta];
+        palette[name.bytes().map(usize::from).sum::<usize>() % palette.len()]
+    }
+}
+
 fn draw_footer(f: &mut Frame, area: Rect, app: &AppState) {
-    let text = if let Some(err) = &app.error_message {
+    let mut text = if let Some(input) = &app.goto_line_input {
+        Line::from(vec![
+            Span::styled(":", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", input), Style::default().fg(Color::Yellow)),
+            Span::styled(app.tr("footer.goto_line"), Style::default().fg(Color::DarkGray)),
+        ])
+    } else if let Some(search) = app.response_search.as_ref().filter(|s| s.editing) {
         Line::from(vec![
-            Span::styled("✗ Error: ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            Span::styled("/", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", search.query), Style::default().fg(Color::Yellow)),
+            Span::styled(app.tr("footer.search"), Style::default().fg(Color::DarkGray)),
+        ])
+    } else if let Some(err) = &app.error_message {
+        Line::from(vec![
+            Span::styled(app.tr("footer.error"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
             Span::styled(err, Style::default().fg(Color::Red)),
         ])
     } else if let Some(status) = &app.status_message {
@@ -1032,8 +467,11 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &AppState) {
             Span::styled(status, Style::default().fg(Color::Green)),
         ])
     } else {
-        Line::from("⌨ Ctrl+h/l: panels | Ctrl+j/k: nav | x: quick exec | PgUp/PgDn: scroll | ?: help")
+        Line::from(app.tr("footer.keys"))
     };
+    if let Some(register) = app.macros.recording() {
+        text.spans.insert(0, Span::styled(app.tr_with("footer.recording", &[("register", &register)]), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)));
+    }
     
     let footer = Paragraph::new(text)
         .block(Block::default()
@@ -1050,13 +488,14 @@ fn draw_collection_list(f: &mut Frame, area: Rect, app: &AppState) {
         .iter()
         .enumerate()
         .map(|(i, collection)| {
-            let style = if i == app.selected_index {
+            let style = if i == app.selection.collection {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             };
             
-            let content = format!("📁 {} ({} endpoints)", collection.name, collection.endpoints.len());
+            let icon = if collection.read_only { "🔒" } else { "📁" };
+            let content = format!("{} {} ({})", icon, collection.name, app.tr_with("panel.endpoint_count", &[("count", &collection.endpoints.len())]));
             ListItem::new(content).style(style)
         })
         .collect();
@@ -1077,8 +516,9 @@ fn draw_endpoint_list(f: &mut Frame, area: Rect, app: &AppState, coll_idx: usize
             .endpoints
             .iter()
             .enumerate()
+            .filter(|(_, endpoint)| app.endpoint_visible(endpoint))
             .map(|(i, endpoint)| {
-                let style = if i == app.selected_index {
+                let style = if i == app.selection.endpoint {
                     Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
@@ -1092,8 +532,10 @@ fn draw_endpoint_list(f: &mut Frame, area: Rect, app: &AppState, coll_idx: usize
                     _ => Color::White,
                 };
                 
+                let mark = if app.marked_endpoints.contains(&endpoint.id) { "● " } else { "" };
                 let content = Line::from(vec![
-                    Span::styled(format!("{:?} ", endpoint.method), Style::default().fg(method_color).add_modifier(Modifier::BOLD)),
+                    Span::styled(mark, Style::default().fg(Color::Magenta)),
+                    Span::styled(format!("{} {} ", method_icon(&endpoint.method), endpoint.method), Style::default().fg(method_color).add_modifier(Modifier::BOLD)),
                     Span::raw(&endpoint.name),
                 ]);
                 
@@ -1118,7 +560,7 @@ fn draw_endpoint_detail(f: &mut Frame, area: Rect, app: &AppState, coll_idx: usi
             let text = vec![
                 Line::from(vec![
                     Span::styled("Method: ", Style::default().fg(Color::Gray)),
-                    Span::styled(format!("{:?}", endpoint.method), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    Span::styled(endpoint.method.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 ]),
                 Line::from(vec![
                     Span::styled("URL: ", Style::default().fg(Color::Gray)),
@@ -1171,11 +613,11 @@ fn draw_response_view(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usiz
         let metadata = vec![
             Line::from(vec![
                 Span::styled("Status: ", Style::default().fg(Color::Gray)),
-                Span::styled(format!("{}", response.status), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{} {}", status_symbol(response.status.as_u16()), response.status), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(vec![
                 Span::styled("Duration: ", Style::default().fg(Color::Gray)),
-                Span::raw(format!("{:?}", response.duration)),
+                Span::raw(fmt_duration(response.duration, app.settings.latency_decimals)),
             ]),
             Line::from(vec![
                 Span::styled("Size: ", Style::default().fg(Color::Gray)),
@@ -1212,7 +654,7 @@ fn draw_load_test(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize, _
         let _progress = if let Some(engine) = &app.load_test_engine {
             let elapsed = engine.elapsed();
             let elapsed_secs = elapsed.as_secs_f64();
-            let total = app.load_test_config.duration.as_secs_f64();
+            let total = engine.config().duration.as_secs_f64();
             let percent = (elapsed_secs / total * 100.0).min(100.0) as u16;
             
             // Animated spinner and pulse color
@@ -1221,8 +663,11 @@ fn draw_load_test(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize, _
             
             // Create gradient progress bar
             let elapsed_str = format!("{}s", elapsed.as_secs());
-            let total_str = format!("{}s", app.load_test_config.duration.as_secs());
-            let title = format!("🚀 {} Load Test Progress - {} / {} ⚡", spinner, elapsed_str, total_str);
+            let total_str = format!("{}s", engine.config().duration.as_secs());
+            let mut title = app.tr_with("load_test.progress", &[("spinner", &spinner), ("elapsed", &elapsed_str), ("total", &total_str)]);
+            if let Some(queue) = app.load_test_queue_status() {
+                title.push_str(&format!(" [🗂️ {}]", queue));
+            }
             
             let gauge = Gauge::default()
                 .block(Block::default()
@@ -1261,58 +706,98 @@ fn draw_load_test(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize, _
             0.0
         };
 
+        let assertion_rate = if metrics.total_requests > 0 {
+            (metrics.assertion_failed_requests as f64 / metrics.total_requests as f64) * 100.0
+        } else {
+            0.0
+        };
+
         // Stats with percentiles, icons, and percentages
         let mut stats_text = vec![
             Line::from(vec![
-                Span::styled("📨 Total Requests: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("load_test.total"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled(format!("{}", metrics.total_requests), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(vec![
-                Span::styled("✓ Successful: ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("load_test.successful"), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
                 Span::styled(format!("{}", metrics.successful_requests), Style::default().fg(Color::Green)),
                 Span::styled(format!(" ({:.1}%)", success_rate), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(vec![
-                Span::styled("✗ Failed: ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("load_test.failed"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
                 Span::styled(format!("{}", metrics.failed_requests), Style::default().fg(Color::Red)),
                 Span::styled(format!(" ({:.1}%)", failure_rate), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(vec![
-                Span::styled("⚡ Current RPS: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("load_test.assertions_failed"), Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}", metrics.assertion_failed_requests), Style::default().fg(Color::LightRed)),
+                Span::styled(format!(" ({:.1}%)", assertion_rate), Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(vec![
+                Span::styled(app.tr("load_test.current_rps"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                 Span::styled(format!("{:.2}", metrics.current_rps), Style::default().fg(Color::Yellow)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("📊 Latency Percentiles:", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("load_test.percentiles"), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(vec![
-                Span::styled("  Avg: ", Style::default().fg(Color::Gray)),
-                Span::styled(format!("{:?}", avg_latency), Style::default().fg(Color::White)),
+                Span::styled(format!("  {}: ", app.tr("load_test.avg")), Style::default().fg(Color::Gray)),
+                Span::styled(fmt_duration(avg_latency, app.settings.latency_decimals), Style::default().fg(Color::White)),
                 Span::raw("  "),
                 Span::styled("p50: ", Style::default().fg(Color::Gray)),
-                Span::styled(format!("{:?}", percentiles.p50), Style::default().fg(Color::Green)),
+                Span::styled(fmt_duration(percentiles.p50, app.settings.latency_decimals), Style::default().fg(Color::Green)),
             ]),
             Line::from(vec![
                 Span::styled("  p90: ", Style::default().fg(Color::Gray)),
-                Span::styled(format!("{:?}", percentiles.p90), Style::default().fg(Color::Yellow)),
+                Span::styled(fmt_duration(percentiles.p90, app.settings.latency_decimals), Style::default().fg(Color::Yellow)),
                 Span::raw("  "),
                 Span::styled("p95: ", Style::default().fg(Color::Gray)),
-                Span::styled(format!("{:?}", percentiles.p95), Style::default().fg(Color::Magenta)),
+                Span::styled(fmt_duration(percentiles.p95, app.settings.latency_decimals), Style::default().fg(Color::Magenta)),
             ]),
             Line::from(vec![
                 Span::styled("  p99: ", Style::default().fg(Color::Gray)),
-                Span::styled(format!("{:?}", percentiles.p99), Style::default().fg(Color::Red)),
+                Span::styled(fmt_duration(percentiles.p99, app.settings.latency_decimals), Style::default().fg(Color::Red)),
                 Span::raw("  "),
-                Span::styled("Max: ", Style::default().fg(Color::Gray)),
-                Span::styled(format!("{:?}", percentiles.max), Style::default().fg(Color::Red)),
+                Span::styled(format!("{}: ", app.tr("load_test.max")), Style::default().fg(Color::Gray)),
+                Span::styled(fmt_duration(percentiles.max, app.settings.latency_decimals), Style::default().fg(Color::Red)),
             ]),
         ];
 
+        // Which assertions failed, so a 200 with the wrong body is easy to spot
+        let breakdown = metrics.assertion_breakdown();
+        if !breakdown.is_empty() {
+            stats_text.push(Line::from(""));
+            stats_text.push(Line::from(Span::styled(app.tr("load_test.failing_assertions"), Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD))));
+            for (assertion, count) in breakdown.iter().take(5) {
+                stats_text.push(Line::from(vec![
+                    Span::styled(format!("  {:>6}  ", count), Style::default().fg(Color::LightRed)),
+                    Span::raw(assertion.to_string()),
+                ]));
+            }
+        }
+
+        if !metrics.chaos_counts.is_empty() {
+            let mut faults: Vec<String> = metrics.chaos_counts.iter().map(|(kind, count)| format!("{} {}", count, kind)).collect();
+            faults.sort();
+            stats_text.push(Line::from(vec![
+                Span::styled(app.tr("load_test.injected"), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+                Span::raw(faults.join(", ")),
+            ]));
+        }
+
+        if let Some(path) = app.load_test_engine.as_ref().and_then(|e| e.har_path()) {
+            stats_text.push(Line::from(vec![
+                Span::styled(app.tr("load_test.har_samples"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr_with("load_test.har_written", &[("path", &path.display())]), Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+
         // Add time-series charts if we have data
         if !metrics.time_series.is_empty() {
             stats_text.push(Line::from(""));
             stats_text.push(Line::from(vec![
-                Span::styled("📈 Trends (5s intervals):", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("load_test.trends"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             ]));
         }
 
@@ -1328,7 +813,7 @@ fn draw_load_test(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize, _
         // Draw text stats on the left with rounded border
         let stats_paragraph = Paragraph::new(stats_text)
             .block(Block::default()
-                .title("📊 Statistics")
+                .title(app.tr("load_test.statistics"))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(Color::Magenta)));
@@ -1378,17 +863,18 @@ fn draw_load_test(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize, _
                 f.render_widget(y_axis, p95_layout[0]);
                 
                 // Determine color based on latency
-                let (sparkline_style, border_color) = if max_p95 < 100 {
-                    (Style::default().fg(Color::Green), Color::Green)
+                // ...and a word, for when the colour can't be told apart
+                let (sparkline_style, border_color, level) = if max_p95 < 100 {
+                    (Style::default().fg(Color::Green), Color::Green, app.tr("load_test.fast"))
                 } else if max_p95 < 200 {
-                    (Style::default().fg(Color::Yellow), Color::Yellow)
+                    (Style::default().fg(Color::Yellow), Color::Yellow, app.tr("load_test.slow"))
                 } else {
-                    (Style::default().fg(Color::Red), Color::Red)
+                    (Style::default().fg(Color::Red), Color::Red, app.tr("load_test.very_slow"))
                 };
                 
                 let p95_sparkline = Sparkline::default()
                     .block(Block::default()
-                        .title("📈 p95 Latency")
+                        .title(app.tr_with("load_test.p95_chart", &[("level", &level)]))
                         .borders(Borders::ALL)
                         .border_type(BorderType::Rounded)
                         .border_style(Style::default().fg(border_color)))
@@ -1443,13 +929,14 @@ fn draw_load_test(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize, _
 
         // Enhanced Chart with icons and percentages
         let data = vec![
-            ("✓ Success", metrics.successful_requests),
-            ("✗ Failed", metrics.failed_requests),
+            (app.tr("load_test.bar_success"), metrics.successful_requests),
+            (app.tr("load_test.bar_failed"), metrics.failed_requests),
+            (app.tr("load_test.bar_assertion"), metrics.assertion_failed_requests),
         ];
 
         let chart = BarChart::default()
             .block(Block::default()
-                .title("📊 Results")
+                .title(app.tr("load_test.results"))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(Color::Green)))
@@ -1457,28 +944,68 @@ fn draw_load_test(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize, _
             .bar_width(15)
             .bar_style(Style::default().fg(Color::Green))
             .value_style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD));
-        f.render_widget(chart, chunks[2]);
+        if metrics.endpoints.is_empty() {
+            f.render_widget(chart, chunks[2]);
+            return;
+        }
+
+        // A scenario run: how each of its endpoints is doing
+        let results_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(chunks[2]);
+        f.render_widget(chart, results_chunks[0]);
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut rows = vec![Line::from(Span::styled(
+            format!(
+                "{:<26} {:>8} {:>6} {:>8} {:>8} {:>8} {:>9}",
+                app.tr("load_test.col_endpoint"), app.tr("load_test.col_requests"), app.tr("load_test.col_share"),
+                app.tr("load_test.col_ok"), app.tr("load_test.col_failed"), app.tr("load_test.col_assert"), "p95",
+            ),
+            dim,
+        ))];
+        for endpoint in &metrics.endpoints {
+            let share = endpoint.total_requests as f64 / metrics.total_requests.max(1) as f64 * 100.0;
+            let p95 = crate::load_test::calculate_percentiles(&endpoint.latencies).p95;
+            let name = format!("{} {}", endpoint.method, endpoint.name);
+            rows.push(Line::from(vec![
+                Span::styled(format!("{:<26} ", crate::tui::screens::past_results::truncate(&name, 26)), Style::default().fg(Color::Cyan)),
+                Span::raw(format!("{:>8} ", endpoint.total_requests)),
+                Span::styled(format!("{:>5.0}% ", share), dim),
+                Span::styled(format!("{:>8} ", endpoint.successful_requests), Style::default().fg(Color::Green)),
+                Span::styled(format!("{:>8} ", endpoint.failed_requests), Style::default().fg(Color::Red)),
+                Span::styled(format!("{:>8} ", endpoint.assertion_failed_requests), Style::default().fg(Color::LightRed)),
+                Span::raw(format!("{:>9}", fmt_duration(p95, app.settings.latency_decimals))),
+            ]));
+        }
+        let per_endpoint = Paragraph::new(rows)
+            .block(Block::default()
+                .title(app.tr("load_test.per_endpoint"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)));
+        f.render_widget(per_endpoint, results_chunks[1]);
     }
 }
 
 fn draw_collection_edit(f: &mut Frame, area: Rect, app: &AppState) {
     if let Some(form) = &app.collection_form {
         let title = if form.editing_index.is_some() {
-            "✏️ Edit Collection [Enter: save | Esc: cancel]"
+            app.tr("collection_form.edit_title")
         } else {
-            "➕ New Collection [Enter: save | Esc: cancel]"
+            app.tr("collection_form.new_title")
         };
         
         let text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("📁 Collection Name: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("collection_form.name"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled(&form.name, Style::default().fg(Color::Yellow)),
                 Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("⌨️  Type to enter name, press Enter to save", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("collection_form.hint"), Style::default().fg(Color::DarkGray)),
             ]),
         ];
         
@@ -1497,9 +1024,9 @@ fn draw_collection_edit(f: &mut Frame, area: Rect, app: &AppState) {
 fn draw_endpoint_edit(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usize) {
     if let Some(form) = &app.endpoint_form {
         let title = if form.editing_index.is_some() {
-            "✏️ Edit Endpoint [Tab: next field | Enter: save | Esc: cancel]"
+            app.tr("endpoint_form.edit_title")
         } else {
-            "➕ New Endpoint [Tab: next field | Enter: save | Esc: cancel]"
+            app.tr("endpoint_form.new_title")
         };
         
         let field_style = |field_num: usize| {
@@ -1518,37 +1045,28 @@ fn draw_endpoint_edit(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usiz
             }
         };
         
-        let method_icon = match form.method {
-            crate::models::HttpMethod::GET => "📥",
-            crate::models::HttpMethod::POST => "📤",
-            crate::models::HttpMethod::PUT => "✏️",
-            crate::models::HttpMethod::DELETE => "🗑️",
-            crate::models::HttpMethod::PATCH => "🔧",
-            _ => "📨",
-        };
-        
         let mut text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("📝 Name: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("endpoint_form.name"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled(&form.name, field_style(0)),
                 Span::styled(cursor(0), field_style(0).add_modifier(Modifier::SLOW_BLINK)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled(format!("{} Method: ", method_icon), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{:?}", form.method), field_style(1)),
-                Span::styled(" (press 'm' to cycle)", Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("{} {}: ", method_icon(&form.method), app.tr("endpoint_form.method")), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(form.method.to_string(), field_style(1)),
+                Span::styled(app.tr("endpoint_form.method_hint"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("🌐 URL: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("endpoint_form.url"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled(&form.url, field_style(2)),
                 Span::styled(cursor(2), field_style(2).add_modifier(Modifier::SLOW_BLINK)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("📄 Description: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("endpoint_form.description"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled(&form.description, field_style(3)),
                 Span::styled(cursor(3), field_style(3).add_modifier(Modifier::SLOW_BLINK)),
             ]),
@@ -1559,32 +1077,32 @@ fn draw_endpoint_edit(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usiz
         if form.header_edit_mode {
             // Header edit mode - show input fields
             text.push(Line::from(vec![
-                Span::styled("📋 Headers (Edit Mode): ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("endpoint_form.headers_editing"), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
             ]));
             text.push(Line::from(""));
             text.push(Line::from(vec![
-                Span::styled("  🔑 Key: ", Style::default().fg(Color::Cyan)),
+                Span::styled(app.tr("endpoint_form.header_key"), Style::default().fg(Color::Cyan)),
                 Span::styled(&form.header_key, if form.header_edit_field == 0 { field_style(4) } else { Style::default() }),
                 Span::styled(if form.header_edit_field == 0 { "_" } else { "" }, field_style(4).add_modifier(Modifier::SLOW_BLINK)),
             ]));
             text.push(Line::from(vec![
-                Span::styled("  💎 Value: ", Style::default().fg(Color::Cyan)),
+                Span::styled(app.tr("endpoint_form.header_value"), Style::default().fg(Color::Cyan)),
                 Span::styled(&form.header_value, if form.header_edit_field == 1 { field_style(4) } else { Style::default() }),
                 Span::styled(if form.header_edit_field == 1 { "_" } else { "" }, field_style(4).add_modifier(Modifier::SLOW_BLINK)),
             ]));
             text.push(Line::from(""));
             text.push(Line::from(vec![
-                Span::styled("  ⌨️  Tab: switch field | Enter: add | Esc: cancel", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("endpoint_form.header_keys"), Style::default().fg(Color::DarkGray)),
             ]));
         } else {
             // Normal mode - show existing headers
             text.push(Line::from(vec![
-                Span::styled("📋 Headers: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("endpoint_form.headers"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::styled(format!("({}) ", form.headers.len()), Style::default().fg(Color::DarkGray)),
                 if form.current_field == 4 {
-                    Span::styled("[press 'h' to add]", field_style(4))
+                    Span::styled(app.tr("endpoint_form.headers_focused"), field_style(4))
                 } else {
-                    Span::styled("[Tab to this field, then 'h' to add]", Style::default().fg(Color::DarkGray))
+                    Span::styled(app.tr("endpoint_form.headers_hint"), Style::default().fg(Color::DarkGray))
                 },
             ]));
             if !form.headers.is_empty() {
@@ -1599,26 +1117,105 @@ fn draw_endpoint_edit(f: &mut Frame, area: Rect, app: &AppState, _coll_idx: usiz
             }
         }
         
+        text.push(Line::from(""));
+        // JSON bodies are checked as they are typed; multi-line bodies get a line each
+        let is_json = formatter::looks_like_json(&form.body_template);
+        let body_issue = is_json.then(|| formatter::validate_json_template(&form.body_template).err()).flatten();
+        let body_style = if body_issue.is_some() { field_style(5).fg(Color::Red) } else { field_style(5) };
+        let mut body_lines: Vec<Line> = form.body_template.split('\n').enumerate().map(|(i, line)| {
+            let label = if i == 0 { app.tr("endpoint_form.body") } else { "   " };
+            Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(line, body_style),
+            ])
+        }).collect();
+        if let Some(last) = body_lines.last_mut() {
+            last.push_span(Span::styled(cursor(5), field_style(5).add_modifier(Modifier::SLOW_BLINK)));
+        }
+        text.extend(body_lines);
+        if let Some(issue) = &body_issue {
+            text.push(Line::from(Span::styled(app.tr_with("endpoint_form.invalid_json", &[("issue", issue)]), Style::default().fg(Color::Red))));
+        } else if is_json {
+            text.push(Line::from(Span::styled(app.tr("endpoint_form.valid_json"), Style::default().fg(Color::Green))));
+        }
         text.push(Line::from(""));
         text.push(Line::from(vec![
-            Span::styled("📦 Body Template: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-            Span::styled(&form.body_template, field_style(5)),
-            Span::styled(cursor(5), field_style(5).add_modifier(Modifier::SLOW_BLINK)),
+            Span::styled(app.tr("endpoint_form.timeout"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(if form.timeout_secs.is_empty() { app.tr("endpoint_form.timeout_default") } else { &form.timeout_secs }, field_style(6)),
+            Span::styled(cursor(6), field_style(6).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.timeout_hint"), Style::default().fg(Color::DarkGray)),
         ]));
         text.push(Line::from(""));
         text.push(Line::from(vec![
-            Span::styled("⏱️  Timeout (seconds): ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-            Span::styled(if form.timeout_secs.is_empty() { "(default: 30s)" } else { &form.timeout_secs }, field_style(6)),
-            Span::styled(cursor(6), field_style(6).add_modifier(Modifier::SLOW_BLINK)),
+            Span::styled(app.tr("endpoint_form.tags"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(&form.tags, field_style(7)),
+            Span::styled(cursor(7), field_style(7).add_modifier(Modifier::SLOW_BLINK)),
         ]));
         text.push(Line::from(vec![
-            Span::styled("   Leave empty for default timeout", Style::default().fg(Color::DarkGray)),
+            Span::styled(app.tr("endpoint_form.tags_hint"), Style::default().fg(Color::DarkGray)),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.protobuf"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(&form.protobuf, field_style(8)),
+            Span::styled(cursor(8), field_style(8).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.protobuf_hint"), Style::default().fg(Color::DarkGray)),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.empty_body"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(app.tr(match form.empty_body {
+                crate::models::EmptyBody::Omit => "endpoint_form.omit_body",
+                crate::models::EmptyBody::Empty => "endpoint_form.empty_chunked_body",
+                crate::models::EmptyBody::ContentLengthZero => "endpoint_form.content_length_zero",
+            }), field_style(9)),
+            Span::styled(app.tr("endpoint_form.space_cycles"), Style::default().fg(Color::DarkGray)),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.visualizer"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(&form.visualizer, field_style(10)),
+            Span::styled(cursor(10), field_style(10).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.visualizer_hint"), Style::default().fg(Color::DarkGray)),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.assertions"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(&form.assertions, field_style(11)),
+            Span::styled(cursor(11), field_style(11).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.assertions_hint"), Style::default().fg(Color::DarkGray)),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.connection"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(&form.connection, field_style(12)),
+            Span::styled(cursor(12), field_style(12).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.connection_hint"), Style::default().fg(Color::DarkGray)),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.extract"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(&form.extractions, field_style(13)),
+            Span::styled(cursor(13), field_style(13).add_modifier(Modifier::SLOW_BLINK)),
+        ]));
+        text.push(Line::from(vec![
+            Span::styled(app.tr("endpoint_form.extract_hint"), Style::default().fg(Color::DarkGray)),
         ]));
         text.push(Line::from(""));
         
         if !form.header_edit_mode {
             text.push(Line::from(vec![
-                Span::styled("⌨️  Tab: next field | h: add header | Enter: save", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("endpoint_form.keys"), Style::default().fg(Color::DarkGray)),
             ]));
         }
         
@@ -1655,18 +1252,18 @@ fn draw_confirm_delete(f: &mut Frame, area: Rect, app: &AppState) {
         let text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("⚠️  CONFIRM DELETE", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("confirm.delete_title"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from(message.clone()),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::Gray)),
+                Span::styled(app.tr("confirm.press"), Style::default().fg(Color::Gray)),
                 Span::styled("Y", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-                Span::styled(" to confirm or ", Style::default().fg(Color::Gray)),
+                Span::styled(app.tr("confirm.to_confirm"), Style::default().fg(Color::Gray)),
                 Span::styled("N/Esc", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-                Span::styled(" to cancel", Style::default().fg(Color::Gray)),
+                Span::styled(app.tr("confirm.to_cancel"), Style::default().fg(Color::Gray)),
             ]),
         ];
         
@@ -1683,170 +1280,6 @@ fn draw_confirm_delete(f: &mut Frame, area: Rect, app: &AppState) {
     }
 }
 
-fn draw_help(f: &mut Frame, area: Rect) {
-    let help_text = vec![
-        Line::from(vec![Span::styled("⌨️  Keyboard Shortcuts", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-        Line::from(""),
-        Line::from(vec![Span::styled("🧭 Navigation:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  ↑/k        - Move up"),
-        Line::from("  ↓/j        - Move down"),
-        Line::from("  Enter      - Select item"),
-        Line::from("  Esc        - Go back"),
-        Line::from("  q          - Quit (from main screen)"),
-        Line::from(""),
-        Line::from(vec![Span::styled("📁 Collection Management:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  n          - New collection/endpoint"),
-        Line::from("  e          - Edit collection/endpoint"),
-        Line::from("  d          - Delete collection/endpoint"),
-        Line::from(""),
-        Line::from(vec![Span::styled("🚀 Endpoint Actions:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  e          - Execute request (from detail)"),
-        Line::from("  x          - Quick execute (from main screen)"),
-        Line::from("  l          - Start load test"),
-        Line::from(""),
-        Line::from(vec![Span::styled("🔧 Variable Management:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  v          - Open variable list"),
-        Line::from("  n          - New variable (in variable list)"),
-        Line::from("  e          - Edit variable (in variable list)"),
-        Line::from("  d          - Delete variable (in variable list)"),
-        Line::from(""),
-        Line::from(vec![Span::styled("👁️ View Options:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  t          - Toggle network traffic"),
-        Line::from("  H          - Toggle response headers"),
-        Line::from("  Space      - Collapse/expand sections"),
-        Line::from(""),
-        Line::from(vec![Span::styled("📋 Clipboard:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  y          - Copy response to clipboard"),
-        Line::from(""),
-        Line::from(vec![Span::styled("📜 Scrolling:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  PgUp/PgDn  - Scroll response body"),
-        Line::from("  Shift+PgUp/PgDn - Scroll headers"),
-        Line::from("  Home       - Top of response"),
-        Line::from("  Shift+Home - Top of headers"),
-        Line::from("  End        - Bottom of response"),
-        Line::from(""),
-        Line::from(vec![Span::styled("✏️ Form Editing:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  Tab        - Next field"),
-        Line::from("  m          - Cycle HTTP method"),
-        Line::from("  Backspace  - Delete character"),
-        Line::from("  Enter      - Save"),
-        Line::from(""),
-        Line::from(vec![Span::styled("🔧 Other:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  ?          - Show this help"),
-        Line::from(""),
-        Line::from(vec![Span::styled("Press any key to close help", Style::default().fg(Color::DarkGray))]),
-    ];
-
-    let paragraph = Paragraph::new(help_text)
-        .block(Block::default()
-            .title("❓ Help")
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Yellow)))
-        .wrap(Wrap { trim: true });
-
-    f.render_widget(paragraph, area);
-}
-
-fn draw_load_test_config(f: &mut Frame, area: Rect, app: &AppState) {
-    if let Some(form) = &app.load_test_config_form {
-        let title = "⚙️ Load Test Configuration [Tab: next field | Enter: start | Esc: cancel]";
-        
-        let concurrency_style = if form.current_field == 0 {
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::White)
-        };
-        
-        let duration_style = if form.current_field == 1 {
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::White)
-        };
-        
-        let ramp_up_style = if form.current_field == 2 {
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::White)
-        };
-        
-        let cursor = if form.current_field == 0 && !form.concurrency.is_empty() 
-            || form.current_field == 1 && !form.duration.is_empty()
-            || form.current_field == 2 && !form.ramp_up.is_empty() {
-            ""
-        } else {
-            "_"
-        };
-        
-        let mut text = vec![
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("🔧 Configure load test parameters:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("👥 Concurrency (workers): ", Style::default().fg(Color::Cyan)),
-                Span::styled(&form.concurrency, concurrency_style),
-                Span::styled(if form.current_field == 0 { cursor } else { "" }, concurrency_style.add_modifier(Modifier::SLOW_BLINK)),
-            ]),
-            Line::from(vec![
-                Span::styled("   Number of concurrent workers (1-1000)", Style::default().fg(Color::DarkGray)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("⏱️  Duration (seconds): ", Style::default().fg(Color::Cyan)),
-                Span::styled(&form.duration, duration_style),
-                Span::styled(if form.current_field == 1 { cursor } else { "" }, duration_style.add_modifier(Modifier::SLOW_BLINK)),
-            ]),
-            Line::from(vec![
-                Span::styled("   Total test duration (1-3600)", Style::default().fg(Color::DarkGray)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("📈 Ramp-up (seconds): ", Style::default().fg(Color::Cyan)),
-                Span::styled(if form.ramp_up.is_empty() { "(optional)" } else { &form.ramp_up }, ramp_up_style),
-                Span::styled(if form.current_field == 2 { cursor } else { "" }, ramp_up_style.add_modifier(Modifier::SLOW_BLINK)),
-            ]),
-            Line::from(vec![
-                Span::styled("   Gradually increase load over this period", Style::default().fg(Color::DarkGray)),
-            ]),
-            Line::from(""),
-            Line::from(""),
-        ];
-        
-        // Show preview
-        let concurrency_val = form.concurrency.parse::<usize>().unwrap_or(10);
-        let duration_val = form.duration.parse::<u64>().unwrap_or(30);
-        let ramp_up_val = if form.ramp_up.is_empty() {
-            None
-        } else {
-            form.ramp_up.parse::<u64>().ok()
-        };
-        
-        text.push(Line::from(vec![
-            Span::styled("👁️  Preview:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-        ]));
-        text.push(Line::from(format!("   {} workers will execute requests for {} seconds", concurrency_val, duration_val)));
-        
-        if let Some(ramp_up) = ramp_up_val {
-            text.push(Line::from(format!("   Load will ramp up over {} seconds", ramp_up)));
-            text.push(Line::from(format!("   Expected total requests: ~{}", concurrency_val * (duration_val - ramp_up / 2) as usize)));
-        } else {
-            text.push(Line::from(format!("   Expected total requests: ~{}", concurrency_val * duration_val as usize)));
-        }
-        
-        let paragraph = Paragraph::new(text)
-            .block(Block::default()
-                .title(title)
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Color::Magenta)))
-            .wrap(Wrap { trim: true });
-        
-        f.render_widget(paragraph, area);
-    }
-}
-
 
 // New split-panel drawing functions for Option B layout
 
@@ -1860,27 +1293,34 @@ fn draw_definition_panel(f: &mut Frame, area: Rect, app: &AppState) {
         Style::default().fg(Color::DarkGray)
     };
     
+    // The collection's notes stand in while the collection rather than an endpoint is selected
+    if let Some(collection) = app.collections.get(app.selection.collection) {
+        let endpoint_selected = app.panel_focus == PanelFocus::Endpoints && collection.endpoints.get(app.selection.endpoint).is_some();
+        if !collection.notes.trim().is_empty() && (!endpoint_selected || collection.endpoints.is_empty()) {
+            let paragraph = Paragraph::new(super::screens::notes::markdown_lines(&collection.notes))
+                .block(Block::default()
+                    .title(app.tr_with("detail.notes_title", &[("name", &collection.name)]))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(border_style))
+                .wrap(Wrap { trim: false });
+            f.render_widget(paragraph, area);
+            return;
+        }
+    }
+    
     // Check if an endpoint is selected
-    if let Some(collection) = app.collections.get(app.selected_collection_index) {
-        if let Some(endpoint) = collection.endpoints.get(app.selected_endpoint_index) {
+    if let Some(collection) = app.collections.get(app.selection.collection) {
+        if let Some(endpoint) = collection.endpoints.get(app.selection.endpoint) {
             // Show endpoint details
-            let method_icon = match endpoint.method {
-                crate::models::HttpMethod::GET => "📥",
-                crate::models::HttpMethod::POST => "📤",
-                crate::models::HttpMethod::PUT => "✏️",
-                crate::models::HttpMethod::DELETE => "🗑️",
-                crate::models::HttpMethod::PATCH => "🔧",
-                _ => "📨",
-            };
-            
             let mut text = vec![
                 Line::from(""),
                 Line::from(vec![
-                    Span::styled(format!("{} Method: ", method_icon), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                    Span::styled(format!("{:?}", endpoint.method), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::styled(format!("{} {}: ", method_icon(&endpoint.method), app.tr("endpoint_form.method")), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    Span::styled(endpoint.method.to_string(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                 ]),
                 Line::from(vec![
-                    Span::styled("🌐 URL: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    Span::styled(app.tr("endpoint_form.url"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                     Span::raw(&endpoint.url),
                 ]),
                 Line::from(""),
@@ -1888,18 +1328,18 @@ fn draw_definition_panel(f: &mut Frame, area: Rect, app: &AppState) {
             
             if let Some(desc) = &endpoint.description {
                 text.push(Line::from(vec![
-                    Span::styled("📄 Description: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    Span::styled(app.tr("endpoint_form.description"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                     Span::raw(desc),
                 ]));
                 text.push(Line::from(""));
             }
             
             text.push(Line::from(vec![
-                Span::styled("📋 Headers:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("detail.headers"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             ]));
             
             if endpoint.headers.is_empty() {
-                text.push(Line::from("  (none)"));
+                text.push(Line::from(format!("  {}", app.tr("detail.none"))));
             } else {
                 for (key, value) in &endpoint.headers {
                     text.push(Line::from(format!("  {}: {}", key, value)));
@@ -1909,15 +1349,89 @@ fn draw_definition_panel(f: &mut Frame, area: Rect, app: &AppState) {
             if let Some(auth) = &endpoint.auth {
                 text.push(Line::from(""));
                 text.push(Line::from(vec![
-                    Span::styled("🔐 Authentication: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::styled(app.tr("detail.auth"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                     Span::raw(format!("{:?}", auth)),
                 ]));
             }
             
-            if let Some(body) = &endpoint.body_template {
+            if endpoint.is_websocket() {
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled("🔌 WebSocket: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(app.tr("detail.websocket")),
+                ]));
+            }
+            
+            if let Some(soap) = &endpoint.soap {
+                let version = match soap.version {
+                    SoapVersion::V1_1 => "1.1",
+                    SoapVersion::V1_2 => "1.2",
+                };
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled(format!("🧼 SOAP {}: ", version), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(if soap.action.is_empty() { app.tr("detail.no_action").to_string() } else { soap.action.clone() }),
+                ]));
+            }
+            
+            if let Some(protobuf) = &endpoint.protobuf {
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled(app.tr("endpoint_form.protobuf"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(protobuf.to_string()),
+                ]));
+            }
+            
+            if let Some(visualizer) = &endpoint.visualizer {
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled(app.tr("endpoint_form.visualizer"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(visualizer.clone()),
+                ]));
+            }
+            
+            if !endpoint.assertions.is_empty() {
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled(app.tr("endpoint_form.assertions"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(Assertion::format_list(&endpoint.assertions)),
+                ]));
+            }
+            
+            if !endpoint.extractions.is_empty() {
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled(app.tr("endpoint_form.extract"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(Extraction::format_list(&endpoint.extractions)),
+                ]));
+            }
+            
+            if endpoint.connection != Default::default() {
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled(app.tr("endpoint_form.connection"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(endpoint.connection.to_string()),
+                ]));
+            }
+            
+            if let Some(graphql) = &endpoint.graphql {
                 text.push(Line::from(""));
                 text.push(Line::from(vec![
-                    Span::styled("📦 Body:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::styled("◈ GraphQL: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::styled(app.tr("detail.graphql_edit"), Style::default().fg(Color::DarkGray)),
+                ]));
+                for line in graphql.query.lines() {
+                    let mut highlighted = super::screens::graphql::highlight_graphql(line);
+                    highlighted.spans.insert(0, Span::raw("  "));
+                    text.push(highlighted);
+                }
+                if !graphql.variables.trim().is_empty() {
+                    text.push(Line::from(app.tr_with("detail.graphql_variables", &[("variables", &graphql.variables)])));
+                }
+            } else if let Some(body) = &endpoint.body_template {
+                text.push(Line::from(""));
+                text.push(Line::from(vec![
+                    Span::styled(app.tr("detail.body"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                 ]));
                 text.push(Line::from(format!("  {}", body)));
             }
@@ -1925,14 +1439,18 @@ fn draw_definition_panel(f: &mut Frame, area: Rect, app: &AppState) {
             text.push(Line::from(""));
             text.push(Line::from(""));
             text.push(Line::from(vec![
-                Span::styled("🚀 Actions:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("detail.actions"), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
             ]));
-            text.push(Line::from("  [e] Execute Request"));
-            text.push(Line::from("  [l] Start Load Test"));
+            if endpoint.is_websocket() {
+                text.push(Line::from(app.tr("detail.connect")));
+            } else {
+                text.push(Line::from(app.tr("detail.execute")));
+                text.push(Line::from(app.tr("detail.load_test")));
+            }
 
             let paragraph = Paragraph::new(text)
                 .block(Block::default()
-                    .title(format!("📍 Endpoint: {}", endpoint.name))
+                    .title(app.tr_with("detail.endpoint_title", &[("name", &endpoint.name)]))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(border_style))
@@ -1948,24 +1466,24 @@ fn draw_definition_panel(f: &mut Frame, area: Rect, app: &AppState) {
         Line::from(""),
         Line::from(""),
         Line::from(vec![
-            Span::styled("📭 No endpoint selected", Style::default().fg(Color::DarkGray)),
+            Span::styled(app.tr("detail.no_endpoint"), Style::default().fg(Color::DarkGray)),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Select a collection and endpoint from the right panel", Style::default().fg(Color::DarkGray)),
+            Span::styled(app.tr("detail.select_hint"), Style::default().fg(Color::DarkGray)),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("⌨️  Use Ctrl+h/l to switch panels", Style::default().fg(Color::DarkGray)),
+            Span::styled(app.tr("detail.switch_panels"), Style::default().fg(Color::DarkGray)),
         ]),
         Line::from(vec![
-            Span::styled("⌨️  Use Ctrl+j/k to navigate", Style::default().fg(Color::DarkGray)),
+            Span::styled(app.tr("detail.navigate"), Style::default().fg(Color::DarkGray)),
         ]),
     ];
     
     let paragraph = Paragraph::new(text)
         .block(Block::default()
-            .title("📍 API Definition")
+            .title(app.tr("panel.definition"))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(border_style))
@@ -1975,33 +1493,68 @@ fn draw_definition_panel(f: &mut Frame, area: Rect, app: &AppState) {
 }
 
 fn draw_response_panel(f: &mut Frame, area: Rect, app: &AppState) {
-    if let Some(response) = &app.last_response {
-        // Show response with optional network traffic
-        let traffic_toggle = if app.show_network_traffic { "hide" } else { "show" };
-        let status_icon = if response.status.is_success() {
-            "✓"
-        } else if response.status.is_client_error() || response.status.is_server_error() {
-            "✗"
+    // The body and traffic panels each raise it to their own limit
+    app.response_h_scroll_max.set(0);
+    if let Some(request) = app.in_flight.last() {
+        draw_request_in_flight(f, area, request, app);
+    } else if let Some(response) = &app.last_response {
+        // The endpoint's assertions sit above everything else
+        let area = if app.response_assertions.is_empty() {
+            area
         } else {
-            "ℹ"
+            let height = (app.response_assertions.len() as u16 + 2).min(area.height / 3).max(3);
+            let sections = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(height), Constraint::Min(0)])
+                .split(area);
+            draw_response_assertions(f, sections[0], app);
+            sections[1]
         };
         
+        // Show response with optional network traffic
+        let traffic_toggle = if app.show_network_traffic { app.tr("response.hide") } else { app.tr("response.show") };
+        let status_icon = status_symbol(response.status.as_u16());
+        
         // Check if response is JSON
-        let is_json = response.headers.iter()
-            .any(|(k, v)| k.to_lowercase() == "content-type" && v.to_lowercase().contains("json"));
+        // A visualizer table is plain text even for JSON responses
+        let is_json = match app.response_view_as {
+            formatter::ViewAs::Auto => !app.showing_visualization && response.headers.iter()
+                .any(|(k, v)| k.to_lowercase() == "content-type" && v.to_lowercase().contains("json")),
+            view => view == formatter::ViewAs::Json,
+        };
         
-        let json_indicator = if is_json { " 🎨 JSON" } else { "" };
-        let headers_toggle = if app.show_response_headers { "hide" } else { "show" };
+        let mut json_indicator = if app.showing_visualization {
+            app.tr("response.visualized")
+        } else if is_json {
+            " 🎨 JSON"
+        } else {
+            ""
+        }.to_string();
+        if app.response_view_as != formatter::ViewAs::Auto {
+            json_indicator.push_str(&app.tr_with("response.viewed_as", &[("view", &app.response_view_as.label())]));
+        }
+        if app.response_has_table {
+            json_indicator.push_str(app.tr("response.table"));
+        }
+        let charset = response.charset();
+        if charset.is_notable() {
+            json_indicator.push_str(&format!(" 🔤 {}", charset));
+        }
+        let headers_toggle = if app.show_response_headers { app.tr("response.hide") } else { app.tr("response.show") };
+        let wrap_toggle = if app.response_wrap { app.tr("response.nowrap") } else { app.tr("response.wrap") };
         
         let header_text = format!(
-            "{} Response: {} - {:?} - {} bytes{} [t: {} traffic | H: {} headers | PgUp/PgDn: scroll]",
+            "{} {}",
             status_icon,
-            response.status,
-            response.duration,
-            response.body.len(),
-            json_indicator,
-            traffic_toggle,
-            headers_toggle
+            app.tr_with("response.title", &[
+                ("status", &response.status),
+                ("duration", &fmt_duration(response.duration, app.settings.latency_decimals)),
+                ("bytes", &response.body.len()),
+                ("indicators", &json_indicator),
+                ("traffic", &traffic_toggle),
+                ("headers", &headers_toggle),
+                ("wrap", &wrap_toggle),
+            ])
         );
         
         if app.show_network_traffic && response.traffic.is_some() {
@@ -2015,9 +1568,8 @@ fn draw_response_panel(f: &mut Frame, area: Rect, app: &AppState) {
                 .split(area);
             
             // Draw response body with scrolling
-            let formatted_body = app.last_response_formatted.as_ref()
-                .map(|s| s.as_str())
-                .unwrap_or("(unable to format response)");
+            let formatted_body = app.last_response_formatted.as_deref()
+                .unwrap_or(app.tr("response.unformattable"));
             
             // If headers are shown, split the top section further
             if app.show_response_headers {
@@ -2050,9 +1602,8 @@ fn draw_response_panel(f: &mut Frame, area: Rect, app: &AppState) {
             draw_network_traffic(f, sections[1], response, app);
         } else {
             // Show only response body with optional headers
-            let formatted_body = app.last_response_formatted.as_ref()
-                .map(|s| s.as_str())
-                .unwrap_or("(unable to format response)");
+            let formatted_body = app.last_response_formatted.as_deref()
+                .unwrap_or(app.tr("response.unformattable"));
             
             if app.show_response_headers {
                 let is_headers_collapsed = app.is_section_collapsed("response_headers");
@@ -2086,21 +1637,21 @@ fn draw_response_panel(f: &mut Frame, area: Rect, app: &AppState) {
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("📭 No response yet", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("response.none_yet"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Execute a request to see the response here", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("response.execute_hint"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("⌨️  Press 't' to toggle network traffic view", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("response.traffic_hint"), Style::default().fg(Color::DarkGray)),
             ]),
         ];
         
         let paragraph = Paragraph::new(text)
             .block(Block::default()
-                .title("📨 Response")
+                .title(app.tr("panel.response"))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(Color::DarkGray)))
@@ -2110,6 +1661,71 @@ fn draw_response_panel(f: &mut Frame, area: Rect, app: &AppState) {
     }
 }
 
+/// Pass/fail of each of the endpoint's assertions, failures first
+fn draw_response_assertions(f: &mut Frame, area: Rect, app: &AppState) {
+    let failed = app.response_assertions.iter().filter(|(_, passed)| !passed).count();
+    let mut results: Vec<&(String, bool)> = app.response_assertions.iter().collect();
+    results.sort_by_key(|(_, passed)| *passed);
+    let lines: Vec<Line> = results.into_iter()
+        .map(|(assertion, passed)| match passed {
+            true => Line::from(Span::styled(format!("✓ {}", assertion), Style::default().fg(Color::Green))),
+            false => Line::from(Span::styled(format!("✗ {}", assertion), Style::default().fg(Color::Red))),
+        })
+        .collect();
+    let (title, color) = match failed {
+        0 => (app.tr_with("response.assertions_passed", &[("total", &app.response_assertions.len())]), Color::Green),
+        n => (app.tr_with("response.assertions_failed", &[("failed", &n), ("total", &app.response_assertions.len())]), Color::Red),
+    };
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(color)));
+    f.render_widget(paragraph, area);
+}
+
+/// Spinner, then a download gauge, shown in place of the response while the
+/// newest request is in flight
+fn draw_request_in_flight(f: &mut Frame, area: Rect, request: &crate::tui_app::InFlightRequest, app: &AppState) {
+    let elapsed = request.started.elapsed();
+    let progress = request.progress.snapshot();
+    let phase = if progress.is_some() { app.tr("response.downloading") } else { app.tr("response.waiting") };
+    let block = Block::default()
+        .title(format!("📨 {} - {} {}", app.tr("response.response"), get_spinner(elapsed.as_millis()), phase))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1), Constraint::Length(1), Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+    let center = |text: String| Paragraph::new(text).alignment(ratatui::layout::Alignment::Center);
+    f.render_widget(center(format!("{} · {:.1}s", request.label, elapsed.as_secs_f64())), chunks[1]);
+    if let Some(progress) = progress {
+        match progress.ratio() {
+            Some(ratio) => {
+                let gauge = Gauge::default()
+                    .gauge_style(Style::default().fg(Color::Green).bg(Color::DarkGray))
+                    .ratio(ratio)
+                    .label(progress.label());
+                f.render_widget(gauge, chunks[2]);
+            }
+            // No Content-Length, so there is nothing to fill the gauge against
+            None => f.render_widget(center(progress.label()), chunks[2]),
+        }
+    }
+    let hint = match (app.in_flight.len(), app.queued_requests.len()) {
+        (1, 0) => app.tr("response.cancel").to_string(),
+        (n, 0) => app.tr_with("response.cancel_all", &[("count", &n)]),
+        (n, queued) => app.tr_with("response.queued_cancel_all", &[("queued", &queued), ("count", &(n + queued))]),
+    };
+    f.render_widget(center(hint).style(Style::default().fg(Color::DarkGray)), chunks[3]);
+}
+
 fn draw_response_headers(f: &mut Frame, area: Rect, response: &crate::http::HttpResponse, app: &AppState) {
     let is_collapsed = app.is_section_collapsed("response_headers");
     let collapse_indicator = if is_collapsed { "▶" } else { "▼" };
@@ -2128,17 +1744,13 @@ fn draw_response_headers(f: &mut Frame, area: Rect, response: &crate::http::Http
     // Calculate visible lines with scrolling
     let total_lines = all_header_lines.len();
     let visible_height = area.height.saturating_sub(2) as usize; // Subtract 2 for borders
-    let max_scroll = if total_lines > visible_height {
-        total_lines - visible_height
-    } else {
-        0
-    };
+    let max_scroll = total_lines.saturating_sub(visible_height);
     let scroll_offset = app.headers_scroll_offset.min(max_scroll);
     
     // Build final lines with title and scrolled content
     let mut header_lines = vec![
         Line::from(vec![
-            Span::styled(format!("{} 📋 Response Headers [Space: collapse/expand]", collapse_indicator), 
+            Span::styled(format!("{} {}", collapse_indicator, app.tr("response.headers_title")), 
                 Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
         ]),
     ];
@@ -2154,11 +1766,11 @@ fn draw_response_headers(f: &mut Frame, area: Rect, response: &crate::http::Http
         if total_lines > visible_height {
             header_lines.push(Line::from(vec![
                 Span::styled(
-                    format!("  [Showing {}-{} of {} headers | ↑/↓ to scroll]", 
-                        scroll_offset + 1, 
-                        (scroll_offset + visible_height).min(total_lines),
-                        total_lines
-                    ),
+                    app.tr_with("response.headers_scroll", &[
+                        ("first", &(scroll_offset + 1)),
+                        ("last", &(scroll_offset + visible_height).min(total_lines)),
+                        ("total", &total_lines),
+                    ]),
                     Style::default().fg(Color::DarkGray)
                 ),
             ]));
@@ -2184,71 +1796,274 @@ fn draw_response_body(
     is_json: bool,
     header_text: &str,
 ) {
-    // Get visible lines with optional JSON colorization
-    let visible_lines = if is_json {
-        let colored_lines = colorize_json(formatted_body);
-        let total_lines = colored_lines.len();
-        let visible_height = area.height.saturating_sub(2) as usize;
-        let max_scroll = if total_lines > visible_height {
-            total_lines - visible_height
-        } else {
-            0
-        };
-        let scroll_offset = app.response_scroll_offset.min(max_scroll);
-        
-        colored_lines.into_iter()
-            .skip(scroll_offset)
-            .take(visible_height)
-            .collect::<Vec<Line>>()
+    // Warn above the body when a huge JSON payload was cut short
+    let area = if !app.response_json_cuts.is_empty() && !app.showing_visualization && area.height > 4 {
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        let banner = format!("{} · {}", formatter::JsonLimits::default().describe(&app.response_json_cuts), app.tr("response.expand_cuts"));
+        f.render_widget(
+            Paragraph::new(banner).style(Style::default().fg(Color::Black).bg(Color::Yellow)),
+            sections[0],
+        );
+        sections[1]
     } else {
-        let lines: Vec<&str> = formatted_body.lines().collect();
-        let total_lines = lines.len();
-        let visible_height = area.height.saturating_sub(2) as usize;
-        let max_scroll = if total_lines > visible_height {
-            total_lines - visible_height
-        } else {
-            0
-        };
-        let scroll_offset = app.response_scroll_offset.min(max_scroll);
-        
-        lines.iter()
-            .skip(scroll_offset)
-            .take(visible_height)
-            .map(|line| Line::from(*line))
-            .collect()
+        area
     };
     
-    // Calculate total lines for scroll indicator
-    let total_lines = if is_json {
-        colorize_json(formatted_body).len()
+    // Only the visible window is colorized, so huge bodies draw as fast as small ones
+    let total_lines = formatted_body.lines().count();
+    let visible_height = area.height.saturating_sub(2) as usize;
+    app.response_view_height.set(visible_height);
+    let max_scroll = total_lines.saturating_sub(visible_height);
+    let scroll_offset = app.response_scroll_offset.min(max_scroll);
+    let window: Vec<&str> = formatted_body.lines().skip(scroll_offset).take(visible_height).collect();
+    let window_text = window.join("\n");
+    
+    let humanized = if is_json && app.number_display.is_active() {
+        formatter::humanize_numbers(&window_text, app.number_display)
     } else {
-        formatted_body.lines().count()
+        String::new()
     };
-    let visible_height = area.height.saturating_sub(2) as usize;
-    let scroll_offset = app.response_scroll_offset.min(if total_lines > visible_height {
-        total_lines - visible_height
+    let visible_lines: Vec<Line> = if is_json {
+        let depth = window.first().map_or(0, |line| json_depth_at(line));
+        colorize_json(if humanized.is_empty() { &window_text } else { &humanized }, depth)
     } else {
-        0
+        window.iter().map(|line| Line::from(*line)).collect()
+    };
+    
+    // Highlight search matches; the current one is the `current`-th on its line
+    let search = app.response_search.as_ref().filter(|s| !s.query.is_empty());
+    let matches = if search.is_some() { app.search_matches() } else { Vec::new() };
+    let current = search.and_then(|s| {
+        let index = s.current.min(matches.len().checked_sub(1)?);
+        let line = matches[index].0;
+        Some((line, index - matches.iter().position(|(l, _)| *l == line)?))
     });
+    let visible_lines: Vec<Line> = match search {
+        Some(search) => visible_lines.into_iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let current = current.filter(|(l, _)| *l == scroll_offset + i).map(|(_, nth)| nth);
+                highlight_matches(line, &search.query, current)
+            })
+            .collect(),
+        None => visible_lines,
+    };
     
     // Add scroll indicator if needed
-    let title_with_scroll = if total_lines > visible_height {
+    let mut title_with_scroll = if total_lines > visible_height {
         format!("{} [{}-{}/{}]", header_text, scroll_offset + 1, (scroll_offset + visible_height).min(total_lines), total_lines)
     } else {
         header_text.to_string()
     };
     
+    // Clamp horizontal scroll to the widest visible line
+    let gutter_width = if app.show_line_numbers { total_lines.max(1).to_string().len() + 1 } else { 0 };
+    let visible_width = (area.width.saturating_sub(2) as usize).saturating_sub(gutter_width);
+    let h_offset = clamp_h_scroll(app, &visible_lines, visible_width);
+    if h_offset > 0 {
+        title_with_scroll.push_str(&format!(" [→{}]", h_offset));
+    }
+    if is_json && app.number_display.epoch_dates {
+        title_with_scroll.push_str(app.tr("response.dates"));
+    }
+    if is_json && app.number_display.thousands_separators {
+        title_with_scroll.push_str(" [1,000]");
+    }
+    if let Some(search) = search {
+        match current {
+            Some(_) => title_with_scroll.push_str(&format!(
+                " 🔍 '{}' {}/{}", search.query, search.current.min(matches.len() - 1) + 1, matches.len()
+            )),
+            None => title_with_scroll.push_str(&app.tr_with("response.no_matches", &[("query", &search.query)])),
+        }
+    }
+    
+    let visible_lines: Vec<Line> = if app.show_line_numbers {
+        // Scroll the content ourselves so the gutter stays put
+        visible_lines.into_iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let mut spans = vec![Span::styled(
+                    format!("{:>width$} ", scroll_offset + i + 1, width = gutter_width - 1),
+                    Style::default().fg(Color::DarkGray),
+                )];
+                spans.extend(skip_columns(line, h_offset).spans);
+                Line::from(spans)
+            })
+            .collect()
+    } else {
+        visible_lines
+    };
+    
     let paragraph = Paragraph::new(visible_lines)
         .block(Block::default()
             .title(title_with_scroll)
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Green)))
-        .wrap(Wrap { trim: false });
+            .border_style(Style::default().fg(Color::Green)));
+    let paragraph = if app.response_wrap {
+        paragraph.wrap(Wrap { trim: false })
+    } else if app.show_line_numbers {
+        paragraph
+    } else {
+        paragraph.scroll((0, h_offset as u16))
+    };
 
     f.render_widget(paragraph, area);
 }
 
+/// Give each match of `query` a highlight, the `current`-th one brighter,
+/// keeping the styles of the text around them
+fn highlight_matches<'a>(line: Line<'a>, query: &str, current: Option<usize>) -> Line<'a> {
+    let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+    let ranges = formatter::find_matches(&text, query);
+    if ranges.is_empty() {
+        return line;
+    }
+    let match_style = Style::default().fg(Color::Black).bg(Color::Yellow);
+    let current_style = Style::default().fg(Color::Black).bg(Color::LightRed).add_modifier(Modifier::BOLD);
+    let mut spans = Vec::new();
+    let mut at = 0;
+    for span in line.spans {
+        let end = at + span.content.len();
+        let mut from = at;
+        // Cut the span wherever a match starts or ends inside it
+        for (nth, range) in ranges.iter().enumerate().filter(|(_, r)| r.start < end && r.end > at) {
+            let (start, stop) = (range.start.max(at), range.end.min(end));
+            if start > from {
+                spans.push(Span::styled(span.content[from - at..start - at].to_string(), span.style));
+            }
+            let style = if current == Some(nth) { current_style } else { match_style };
+            spans.push(Span::styled(span.content[start - at..stop - at].to_string(), span.style.patch(style)));
+            from = stop;
+        }
+        if from < end {
+            spans.push(Span::styled(span.content[from - at..].to_string(), span.style));
+        }
+        at = end;
+    }
+    Line::from(spans).style(line.style)
+}
+
+/// Drop the first `columns` characters from a line, keeping span styles
+fn skip_columns(line: Line<'_>, columns: usize) -> Line<'_> {
+    if columns == 0 {
+        return line;
+    }
+    let mut remaining = columns;
+    let mut spans = Vec::new();
+    for span in line.spans {
+        let len = span.content.chars().count();
+        if remaining >= len {
+            remaining -= len;
+            continue;
+        }
+        let content: String = span.content.chars().skip(remaining).collect();
+        remaining = 0;
+        spans.push(Span::styled(content, span.style));
+    }
+    Line::from(spans)
+}
+
+/// Effective horizontal scroll for the response/traffic panels, clamped so the
+/// widest line stays partially visible. Always 0 while wrapping is enabled.
+/// Records how far the panel can scroll, so Right stops at the widest panel drawn.
+fn clamp_h_scroll(app: &AppState, lines: &[Line], visible_width: usize) -> usize {
+    if app.response_wrap {
+        return 0;
+    }
+    let max_width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+    let max_offset = max_width.saturating_sub(visible_width).min(u16::MAX as usize);
+    app.response_h_scroll_max.set(app.response_h_scroll_max.get().max(max_offset));
+    app.response_h_scroll_offset.min(max_offset)
+}
+
+/// A timing phase's name in the UI language
+fn phase_name(locale: Locale, label: &str) -> &'static str {
+    i18n::tr(locale, match label {
+        "DNS Lookup" => "traffic.dns_lookup",
+        "TCP Connect" => "traffic.tcp_connect",
+        "TLS Handshake" => "traffic.tls_handshake",
+        "Request Sent" => "traffic.request_sent",
+        "Time to First Byte" => "traffic.first_byte",
+        _ => "traffic.content_download",
+    })
+}
+
+/// Devtools-style waterfall: one row per phase, bars offset by when the phase started
+fn timing_waterfall(timing: &crate::http::NetworkTiming, panel_width: usize, decimals: usize, locale: Locale) -> Vec<Line<'static>> {
+    const LABEL_WIDTH: usize = 20;
+    const DURATION_WIDTH: usize = 12;
+    let bar_width = panel_width.saturating_sub(LABEL_WIDTH + DURATION_WIDTH + 6).clamp(10, 80);
+    let phases = timing.phases();
+    let scale = phases.last().map(|p| p.start + p.duration).unwrap_or_default().max(timing.total);
+
+    let mut lines: Vec<Line<'static>> = phases.iter().map(|phase| {
+        let (icon, color) = match phase.label {
+            "DNS Lookup" => ("🔍", Color::Cyan),
+            "TCP Connect" => ("🔌", Color::Yellow),
+            "TLS Handshake" => ("🔐", Color::Magenta),
+            "Request Sent" => ("📤", Color::Blue),
+            "Time to First Byte" => ("⏳", Color::LightYellow),
+            _ => ("📥", Color::Green),
+        };
+        let (offset, length) = phase.cells(scale, bar_width);
+        Line::from(vec![
+            Span::raw(format!("  {} {:<w$}", icon, phase_name(locale, phase.label), w = LABEL_WIDTH - 3)),
+            Span::styled("│", Style::default().fg(Color::DarkGray)),
+            Span::raw(" ".repeat(offset)),
+            Span::styled("█".repeat(length), Style::default().fg(color)),
+            Span::raw(" ".repeat(bar_width - offset - length)),
+            Span::styled("│", Style::default().fg(Color::DarkGray)),
+            Span::raw(format!(" {:>w$}", fmt_duration(phase.duration, decimals), w = DURATION_WIDTH)),
+        ])
+    }).collect();
+    let unmeasured = timing.unmeasured();
+    if !unmeasured.is_empty() {
+        lines.push(Line::from(Span::styled(
+            i18n::tr_with(locale, "traffic.not_measured", &[
+                ("phases", &unmeasured.iter().map(|label| phase_name(locale, label)).collect::<Vec<_>>().join(", ")),
+            ]),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines
+}
+
+/// Sent headers in order with their source; values they overrode are listed
+/// under them, in red when they differ
+fn sent_header_lines(headers: &crate::http::MergedHeaders, locale: Locale) -> Vec<Line<'static>> {
+    let shorten = |value: &str| match value.char_indices().nth(50) {
+        Some((end, _)) => format!("{}...", &value[..end]),
+        None => value.to_string(),
+    };
+    let mut lines = Vec::new();
+    let conflicts = headers.conflicts().count();
+    if conflicts > 0 {
+        lines.push(Line::from(Span::styled(
+            i18n::tr_with(locale, "traffic.header_conflicts", &[("count", &conflicts)]),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+    }
+    for (i, header) in headers.iter().enumerate() {
+        lines.push(Line::from(vec![
+            Span::raw(format!("    {}. {}: {} ", i + 1, header.name, shorten(&header.value))),
+            Span::styled(format!("({})", header.source), Style::default().fg(Color::DarkGray)),
+        ]));
+        for (source, value) in &header.overridden {
+            let color = if *value == header.value { Color::DarkGray } else { Color::Red };
+            lines.push(Line::from(Span::styled(
+                i18n::tr_with(locale, "traffic.overrides", &[("source", source), ("value", &shorten(value))]),
+                Style::default().fg(color),
+            )));
+        }
+    }
+    lines
+}
+
 fn draw_network_traffic(f: &mut Frame, area: Rect, response: &crate::http::HttpResponse, app: &AppState) {
     if let Some(traffic) = &response.traffic {
         let is_collapsed = app.is_section_collapsed("network_traffic");
@@ -2256,95 +2071,109 @@ fn draw_network_traffic(f: &mut Frame, area: Rect, response: &crate::http::HttpR
         
         let mut lines = vec![
             Line::from(vec![
-                Span::styled(format!("{} 📡 Network Traffic [Space: collapse/expand] ", collapse_indicator), 
+                Span::styled(format!("{} {} ", collapse_indicator, app.tr("traffic.heading")), 
                     Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::styled("(Wireshark-style)", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("traffic.wireshark"), Style::default().fg(Color::DarkGray)),
             ]),
         ];
         
         if !is_collapsed {
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
-                Span::styled("⏱️  Timing Breakdown:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("traffic.timing"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             ]));
             
-            // Timing information
-            if let Some(dns) = traffic.timing.dns_lookup {
-                lines.push(Line::from(format!("  🔍 DNS Lookup:        {:?}", dns)));
-            }
-            if let Some(tcp) = traffic.timing.tcp_connect {
-                lines.push(Line::from(format!("  🔌 TCP Connect:       {:?}", tcp)));
-            }
-            if let Some(tls) = traffic.timing.tls_handshake {
-                lines.push(Line::from(format!("  🔐 TLS Handshake:     {:?}", tls)));
-            }
-            lines.push(Line::from(format!("  📤 Request Sent:      {:?}", traffic.timing.request_sent)));
-            lines.push(Line::from(format!("  ⏳ Waiting (TTFB):    {:?}", traffic.timing.waiting)));
-            lines.push(Line::from(format!("  📥 Content Download:  {:?}", traffic.timing.content_download)));
+            lines.extend(timing_waterfall(&traffic.timing, area.width as usize, app.settings.latency_decimals, app.settings.locale));
             lines.push(Line::from(vec![
-                Span::styled("  ⚡ Total:             ", Style::default()),
-                Span::styled(format!("{:?}", traffic.timing.total), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("traffic.total"), Style::default()),
+                Span::styled(fmt_duration(traffic.timing.total, app.settings.latency_decimals), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
             ]));
+            match traffic.timing.expect_continue {
+                Some(crate::http::ExpectContinue::AnsweredEarly) => lines.push(Line::from(
+                    app.tr("traffic.expect_answered"))),
+                Some(crate::http::ExpectContinue::BodySent(waited)) => lines.push(Line::from(app.tr_with(
+                    "traffic.expect_body_sent",
+                    &[("after", &fmt_duration(waited, app.settings.latency_decimals))]))),
+                None => {}
+            }
             
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
-                Span::styled("📤 Request:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("traffic.request"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             ]));
             lines.push(Line::from(format!("  {} {}", traffic.request.method, traffic.request.url)));
-            lines.push(Line::from(format!("  📋 Headers: {} ({} bytes)", 
-                traffic.request.headers.len(),
-                traffic.request.headers.iter().map(|(k, v)| k.len() + v.len() + 4).sum::<usize>()
-            )));
-            
-            // Show first few headers
-            let mut header_count = 0;
-            for (key, value) in &traffic.request.headers {
-                if header_count < 3 {
-                    let display_value = if value.len() > 50 {
-                        format!("{}...", &value[..50])
-                    } else {
-                        value.clone()
-                    };
-                    lines.push(Line::from(format!("    {}: {}", key, display_value)));
-                    header_count += 1;
-                }
-            }
-            if traffic.request.headers.len() > 3 {
-                lines.push(Line::from(format!("    ... and {} more", traffic.request.headers.len() - 3)));
-            }
+            lines.push(Line::from(app.tr_with("traffic.headers", &[
+                ("count", &traffic.request.headers.len()),
+                ("bytes", &traffic.request.headers.iter().map(|(k, v)| k.len() + v.len() + 4).sum::<usize>()),
+            ])));
+            lines.extend(sent_header_lines(&traffic.request.sent_headers, app.settings.locale));
             
-            lines.push(Line::from(format!("  📦 Body: {} bytes", traffic.request.body_size)));
+            lines.push(Line::from(app.tr_with("traffic.body", &[("bytes", &traffic.request.body_size)])));
             
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
-                Span::styled("📥 Response:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("traffic.response"), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
             ]));
-            lines.push(Line::from(format!("  ✓ Status: {}", response.status)));
-            lines.push(Line::from(format!("  📋 Headers: {} ({} bytes)", 
-                response.headers.len(),
-                traffic.response_headers_size
-            )));
-            lines.push(Line::from(format!("  📦 Body: {} bytes", traffic.response_body_size)));
+            for interim in &traffic.interim {
+                lines.push(Line::from(Span::styled(
+                    app.tr_with("traffic.interim", &[
+                        ("status", &interim.status),
+                        ("after", &fmt_duration(interim.elapsed, app.settings.latency_decimals)),
+                    ]),
+                    Style::default().fg(Color::Cyan),
+                )));
+                for (key, value) in &interim.headers {
+                    lines.push(Line::from(format!("    {}: {}", key, value)));
+                }
+            }
+            lines.push(Line::from(app.tr_with("traffic.status", &[("status", &response.status)])));
+            if let Some(addr) = traffic.remote_addr {
+                let family = if addr.is_ipv4() { "IPv4" } else { "IPv6" };
+                lines.push(Line::from(app.tr_with("traffic.remote", &[("addr", &addr), ("family", &family)])));
+            }
+            lines.push(Line::from(app.tr_with("traffic.headers", &[
+                ("count", &response.headers.len()),
+                ("bytes", &traffic.response_headers_size),
+            ])));
+            lines.push(Line::from(app.tr_with("traffic.body", &[("bytes", &traffic.response_body_size)])));
+            if let Some(mismatch) = &traffic.body_mismatch {
+                lines.push(Line::from(Span::styled(
+                    format!("  ⚠ {}", mismatch),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )));
+            }
+            if !traffic.trailers.is_empty() {
+                lines.push(Line::from(app.tr_with("traffic.trailers", &[("count", &traffic.trailers.len())])));
+                for (key, value) in &traffic.trailers {
+                    lines.push(Line::from(format!("    {}: {}", key, value)));
+                }
+            }
             
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
-                Span::styled("📊 Total Transfer: ", Style::default().fg(Color::Gray)),
+                Span::styled(app.tr("traffic.total_transfer"), Style::default().fg(Color::Gray)),
                 Span::styled(
-                    format!("{} bytes", 
-                        traffic.request.body_size + traffic.response_headers_size + traffic.response_body_size
-                    ),
+                    app.tr_with("traffic.bytes", &[
+                        ("bytes", &(traffic.request.body_size + traffic.response_headers_size + traffic.response_body_size)),
+                    ]),
                     Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
                 ),
             ]));
         }
         
+        let h_offset = clamp_h_scroll(app, &lines, area.width.saturating_sub(2) as usize);
+        
         let paragraph = Paragraph::new(lines)
             .block(Block::default()
-                .title("📡 Network Traffic")
+                .title(app.tr("traffic.title"))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Color::Cyan)))
-            .wrap(Wrap { trim: true });
+                .border_style(Style::default().fg(Color::Cyan)));
+        let paragraph = if app.response_wrap {
+            paragraph.wrap(Wrap { trim: true })
+        } else {
+            paragraph.scroll((0, h_offset as u16))
+        };
 
         f.render_widget(paragraph, area);
     }
@@ -2375,23 +2204,24 @@ fn draw_collections_panel(f: &mut Frame, area: Rect, app: &AppState) {
         .iter()
         .enumerate()
         .map(|(i, collection)| {
-            let style = if i == app.selected_collection_index && collections_focused {
+            let style = if i == app.selection.collection && collections_focused {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-            } else if i == app.selected_collection_index {
+            } else if i == app.selection.collection {
                 Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             };
             
-            let content = format!("📁 {} ({} endpoints)", collection.name, collection.endpoints.len());
+            let icon = if collection.read_only { "🔒" } else { "📁" };
+            let content = format!("{} {} ({})", icon, collection.name, app.tr_with("panel.endpoint_count", &[("count", &collection.endpoints.len())]));
             ListItem::new(content).style(style)
         })
         .collect();
 
     let collections_title = if collections_focused {
-        "📁 Collections [n: new | e: edit | d: delete]"
+        format!("{} [{}]", app.tr("panel.collections"), app.tr("panel.collections_keys"))
     } else {
-        "📁 Collections"
+        app.tr("panel.collections").to_string()
     };
     
     let collections_list = List::new(collection_items)
@@ -2413,42 +2243,61 @@ fn draw_collections_panel(f: &mut Frame, area: Rect, app: &AppState) {
         Style::default().fg(Color::DarkGray)
     };
     
-    if let Some(collection) = app.collections.get(app.selected_collection_index) {
+    if let Some(collection) = app.collections.get(app.selection.collection) {
         let endpoint_items: Vec<ListItem> = collection
             .endpoints
             .iter()
             .enumerate()
+            .filter(|(_, endpoint)| app.endpoint_visible(endpoint))
             .map(|(i, endpoint)| {
-                let style = if i == app.selected_endpoint_index && endpoints_focused {
+                let style = if i == app.selection.endpoint && endpoints_focused {
                     Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-                } else if i == app.selected_endpoint_index {
+                } else if i == app.selection.endpoint {
                     Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 };
                 
-                let (method_icon, method_color) = match endpoint.method {
-                    crate::models::HttpMethod::GET => ("📥", Color::Green),
-                    crate::models::HttpMethod::POST => ("📤", Color::Blue),
-                    crate::models::HttpMethod::PUT => ("✏️", Color::Yellow),
-                    crate::models::HttpMethod::DELETE => ("🗑️", Color::Red),
-                    crate::models::HttpMethod::PATCH => ("🔧", Color::Magenta),
-                    _ => ("📨", Color::White),
+                let method_color = match endpoint.method {
+                    crate::models::HttpMethod::GET => Color::Green,
+                    crate::models::HttpMethod::POST => Color::Blue,
+                    crate::models::HttpMethod::PUT => Color::Yellow,
+                    crate::models::HttpMethod::DELETE => Color::Red,
+                    crate::models::HttpMethod::PATCH => Color::Magenta,
+                    _ => Color::White,
                 };
                 
-                let content = Line::from(vec![
-                    Span::styled(format!("{} {:?} ", method_icon, endpoint.method), Style::default().fg(method_color).add_modifier(Modifier::BOLD)),
+                let mark = if app.marked_endpoints.contains(&endpoint.id) { "● " } else { "" };
+                let mut spans = vec![
+                    Span::styled(mark, Style::default().fg(Color::Magenta)),
+                    Span::styled(format!("{} {} ", method_icon(&endpoint.method), endpoint.method), Style::default().fg(method_color).add_modifier(Modifier::BOLD)),
                     Span::raw(&endpoint.name),
-                ]);
+                ];
+                for tag in &endpoint.tags {
+                    spans.push(Span::styled(format!(" #{}", tag), Style::default().fg(Color::DarkGray)));
+                }
+                // Sending, and how many repeat sends wait behind it
+                match app.endpoint_requests(app.selection.collection, i) {
+                    (0, 0) => {}
+                    (_, 0) => spans.push(Span::styled(" ⟳", Style::default().fg(Color::Cyan))),
+                    (_, queued) => spans.push(Span::styled(format!(" ⟳ +{}", queued), Style::default().fg(Color::Cyan))),
+                }
+                let content = Line::from(spans);
                 
                 ListItem::new(content).style(style)
             })
             .collect();
 
+        let mut filter = app.tag_filter.as_ref()
+            .map(|tag| format!(" #{}", tag))
+            .unwrap_or_default();
+        if app.show_archived {
+            filter.push_str(app.tr("panel.archived"));
+        }
         let endpoints_title = if endpoints_focused {
-            format!("🔗 Endpoints - {} [n: new | e: edit | d: delete]", collection.name)
+            format!("{} - {}{} [{}]", app.tr("panel.endpoints"), collection.name, filter, app.tr("panel.endpoints_keys"))
         } else {
-            format!("🔗 Endpoints - {}", collection.name)
+            format!("{} - {}{}", app.tr("panel.endpoints"), collection.name, filter)
         };
         
         let endpoints_list = List::new(endpoint_items)
@@ -2466,17 +2315,17 @@ fn draw_collections_panel(f: &mut Frame, area: Rect, app: &AppState) {
         let text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("📭 No collections", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("panel.no_collections"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Press 'n' to create a new collection", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("panel.create_collection"), Style::default().fg(Color::DarkGray)),
             ]),
         ];
         
         let paragraph = Paragraph::new(text)
             .block(Block::default()
-                .title("🔗 Endpoints")
+                .title(app.tr("panel.endpoints"))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(endpoints_border_style))
@@ -2495,7 +2344,7 @@ fn draw_variable_list(f: &mut Frame, area: Rect, app: &AppState) {
         .iter()
         .enumerate()
         .map(|(i, key)| {
-            let style = if i == app.selected_index {
+            let style = if i == app.selection.variable {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
@@ -2519,9 +2368,9 @@ fn draw_variable_list(f: &mut Frame, area: Rect, app: &AppState) {
         .collect();
     
     let title = if keys.is_empty() {
-        "🔧 Variables [n: new | Esc: back]"
+        app.tr("variables.title_empty")
     } else {
-        "🔧 Variables [n: new | e: edit | d: delete | Esc: back]"
+        app.tr("variables.title")
     };
     
     let list = List::new(items)
@@ -2547,18 +2396,18 @@ fn draw_variable_list(f: &mut Frame, area: Rect, app: &AppState) {
         let help_text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("📭 No variables defined", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("variables.none"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Variables allow you to use placeholders in your requests", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("variables.explain"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(vec![
-                Span::styled("Use {{variable_name}} syntax in URLs, headers, and body", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("variables.syntax"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Press 'n' to create your first variable", Style::default().fg(Color::Green)),
+                Span::styled(app.tr("variables.create_first"), Style::default().fg(Color::Green)),
             ]),
         ];
         
@@ -2573,9 +2422,9 @@ fn draw_variable_edit(f: &mut Frame, area: Rect, app: &AppState) {
     if let Some(form) = &app.variable_form {
         let is_new = form.editing_key.is_none();
         let title = if is_new {
-            "✏️ New Variable [Tab: next field | Enter: save | Esc: cancel]"
+            app.tr("variables.new_title")
         } else {
-            "✏️ Edit Variable [Tab: next field | Enter: save | Esc: cancel]"
+            app.tr("variables.edit_title")
         };
         
         let key_style = if form.current_field == 0 {
@@ -2595,40 +2444,40 @@ fn draw_variable_edit(f: &mut Frame, area: Rect, app: &AppState) {
         let text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("🔧 Define a variable for use in requests:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("variables.define"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("🏷️  Key: ", Style::default().fg(Color::Cyan)),
+                Span::styled(app.tr("variables.key"), Style::default().fg(Color::Cyan)),
                 Span::styled(&form.key, key_style),
                 Span::styled(if form.current_field == 0 { cursor } else { "" }, key_style.add_modifier(Modifier::SLOW_BLINK)),
             ]),
             Line::from(vec![
-                Span::styled("   Variable name (e.g., API_URL, AUTH_TOKEN)", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("variables.key_hint"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("💾 Value: ", Style::default().fg(Color::Cyan)),
+                Span::styled(app.tr("variables.value"), Style::default().fg(Color::Cyan)),
                 Span::styled(&form.value, value_style),
                 Span::styled(if form.current_field == 1 { cursor } else { "" }, value_style.add_modifier(Modifier::SLOW_BLINK)),
             ]),
             Line::from(vec![
-                Span::styled("   Variable value (e.g., https://api.example.com)", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.tr("variables.value_hint"), Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("💡 Usage:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("variables.usage"), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(vec![
-                Span::styled("   Use ", Style::default().fg(Color::Gray)),
+                Span::styled(app.tr("variables.use"), Style::default().fg(Color::Gray)),
                 Span::styled("{{", Style::default().fg(Color::Yellow)),
                 Span::styled(&form.key, Style::default().fg(Color::Cyan)),
                 Span::styled("}}", Style::default().fg(Color::Yellow)),
-                Span::styled(" in URLs, headers, or body", Style::default().fg(Color::Gray)),
+                Span::styled(app.tr("variables.use_where"), Style::default().fg(Color::Gray)),
             ]),
             Line::from(vec![
-                Span::styled("   Example: ", Style::default().fg(Color::Gray)),
+                Span::styled(app.tr("variables.example"), Style::default().fg(Color::Gray)),
                 Span::styled("{{API_URL}}/users", Style::default().fg(Color::White)),
             ]),
         ];
@@ -2647,12 +2496,12 @@ fn draw_variable_edit(f: &mut Frame, area: Rect, app: &AppState) {
 
 fn draw_variable_input(f: &mut Frame, area: Rect, app: &AppState) {
     if let Some(form) = &app.variable_input_form {
-        let title = "🔧 Provide Variable Values [Tab: next | Enter: execute | Esc: cancel]";
+        let title = app.tr("variables.input_title");
         
         let mut text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("📝 This request requires the following variables:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(app.tr("variables.required"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
         ];
@@ -2685,13 +2534,13 @@ fn draw_variable_input(f: &mut Frame, area: Rect, app: &AppState) {
         text.push(Line::from(""));
         text.push(Line::from(""));
         text.push(Line::from(vec![
-            Span::styled("💡 Tip:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+            Span::styled(app.tr("variables.tip"), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
         ]));
         text.push(Line::from(vec![
-            Span::styled("   Values are pre-filled from saved variables", Style::default().fg(Color::Gray)),
+            Span::styled(app.tr("variables.prefilled"), Style::default().fg(Color::Gray)),
         ]));
         text.push(Line::from(vec![
-            Span::styled("   Edit as needed and press Enter to execute", Style::default().fg(Color::Gray)),
+            Span::styled(app.tr("variables.edit_execute"), Style::default().fg(Color::Gray)),
         ]));
         
         let paragraph = Paragraph::new(text)
@@ -2705,3 +2554,197 @@ fn draw_variable_input(f: &mut Frame, area: Rect, app: &AppState) {
         f.render_widget(paragraph, area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
+    use crate::tui::harness::Harness;
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+
+    fn harness() -> Harness {
+        let mut collection = ApiCollection::new("Users".to_string());
+        for (name, method) in [("List", HttpMethod::GET), ("Replace", HttpMethod::PUT), ("Remove", HttpMethod::DELETE)] {
+            collection.add_endpoint(ApiEndpoint::new(name.to_string(), method, "http://127.0.0.1:9/users".to_string()));
+        }
+        Harness::new().with_collection(collection)
+    }
+
+    /// A key as Windows reports it: a press followed by a release
+    fn windows_key(code: KeyCode, modifiers: KeyModifiers) -> [KeyEvent; 2] {
+        [KeyEventKind::Press, KeyEventKind::Release].map(|kind| KeyEvent::new_with_kind_and_state(code, modifiers, kind, KeyEventState::NONE))
+    }
+
+    #[test]
+    fn test_emoji_columns_add_up_when_narrowed() {
+        let mut harness = harness();
+        // Columns ConPTY fills per row: ratatui skips the cell after a wide symbol, while
+        // ConPTY ignores the variation selector
+        let conpty_widths = |buffer: &Buffer| -> Vec<usize> {
+            (0..buffer.area.height).map(|y| {
+                let (mut x, mut width) = (0, 0);
+                while x < buffer.area.width {
+                    let symbol = buffer[(x, y)].symbol();
+                    width += Span::raw(symbol.replace('\u{fe0f}', "")).width();
+                    x += Span::raw(symbol).width().max(1) as u16;
+                }
+                width
+            }).collect()
+        };
+
+        // Rows with ✏️ or 🗑️ come out a column short
+        let mut buffer = harness.draw();
+        assert!(buffer.content.iter().any(|cell| cell.symbol() == "✏️"));
+        assert!(conpty_widths(&buffer).iter().any(|&width| width < 100));
+
+        // Narrowed, every row fills exactly the terminal's width
+        narrow_emoji(&mut buffer);
+        assert!(buffer.content.iter().all(|cell| !cell.symbol().contains('\u{fe0f}')));
+        assert!(buffer.content.iter().any(|cell| cell.symbol() == "✏"));
+        assert_eq!(conpty_widths(&buffer), vec![100; 30]);
+    }
+
+    #[test]
+    fn test_waterfall_draws_only_measured_phases() {
+        let timing = crate::http::NetworkTiming {
+            dns_lookup: None,
+            tcp_connect: None,
+            tls_handshake: None,
+            request_sent: None,
+            waiting: std::time::Duration::from_millis(40),
+            content_download: std::time::Duration::from_millis(10),
+            total: std::time::Duration::from_millis(50),
+            expect_continue: None,
+        };
+        let text: Vec<String> = timing_waterfall(&timing, 100, 1, Locale::En).iter().map(|line| line.to_string()).collect();
+        assert_eq!(text.len(), 3);
+        assert!(text[0].contains("Time to First Byte") && text[0].contains("40.0ms"));
+        assert!(text[1].contains("Content Download"));
+        assert_eq!(text[2], "  Not measured (included in Time to First Byte): DNS Lookup, TCP Connect, TLS Handshake, Request Sent");
+        // Durations follow the latency precision setting
+        assert!(timing_waterfall(&timing, 100, 0, Locale::En)[0].to_string().ends_with(" 40ms"));
+    }
+
+    #[test]
+    fn test_traffic_panel_scrolls_past_a_narrow_body() {
+        // Tall enough for the traffic panel to reach the request URL
+        let mut harness = Harness::with_size(100, 80).with_collection(harness().app.collections.remove(0));
+        let url = format!("http://127.0.0.1:9/users?filter={}", "x".repeat(100));
+        let request = crate::http::RequestDetails {
+            method: "GET".to_string(),
+            url,
+            headers: Default::default(),
+            sent_headers: Default::default(),
+            body: None,
+            body_size: 0,
+        };
+        harness.app.last_response = Some(crate::http::HttpResponse {
+            status: reqwest::StatusCode::OK,
+            headers: Default::default(),
+            body: b"ok".to_vec(),
+            duration: std::time::Duration::from_millis(5),
+            traffic: Some(crate::http::NetworkTraffic {
+                timing: crate::http::NetworkTiming {
+                    dns_lookup: None,
+                    tcp_connect: None,
+                    tls_handshake: None,
+                    request_sent: None,
+                    waiting: std::time::Duration::from_millis(5),
+                    content_download: std::time::Duration::ZERO,
+                    total: std::time::Duration::from_millis(5),
+                    expect_continue: None,
+                },
+                request,
+                remote_addr: None,
+                interim: vec![crate::http::InterimResponse {
+                    status: reqwest::StatusCode::from_u16(103).unwrap(),
+                    headers: vec![("link".to_string(), "</app.css>; rel=preload".to_string())],
+                    elapsed: std::time::Duration::from_millis(2),
+                }],
+                trailers: Vec::new(),
+                response_headers_size: 0,
+                response_body_size: 2,
+                body_mismatch: None,
+            }),
+        });
+        harness.app.last_response_formatted = Some("ok".to_string());
+        harness.app.show_network_traffic = true;
+        harness.app.response_wrap = false;
+        let screen = harness.render();
+        assert!(screen.contains("⏳ Interim: 103 Early Hints after 2.0ms"), "{}", screen);
+        assert!(screen.contains("link: </app.css>; rel=preload"));
+
+        // The body has nothing to scroll, the URL in the traffic panel does
+        let filter = "x".repeat(50);
+        assert!(!harness.render().contains(&filter));
+        for _ in 0..4 {
+            harness.app.scroll_response_right(8);
+        }
+        assert_eq!(harness.app.response_h_scroll_offset, 32);
+        assert!(harness.render().contains(&filter));
+    }
+
+    #[test]
+    fn test_hidden_endpoint_is_not_acted_on() {
+        let mut harness = harness();
+        // The only tagged endpoint is archived, so filtering to its tag shows nothing
+        harness.app.collections[0].endpoints[1].tags = vec!["smoke".to_string()];
+        harness.app.collections[0].endpoints[1].archived = true;
+
+        harness.keys(&["Tab", "#"]);
+        assert_eq!(harness.app.tag_filter.as_deref(), Some("smoke"));
+        assert!(harness.app.visible_endpoints(0).is_empty());
+        harness.keys(&["d", "x", "e"]);
+        assert_eq!(harness.app.current_screen, Screen::CollectionList);
+        assert!(harness.app.in_flight.is_empty() && harness.app.history.entries().is_empty());
+        assert!(harness.app.endpoint_form.is_none());
+    }
+
+    #[test]
+    fn test_empty_archived_view_selects_nothing() {
+        let mut harness = harness();
+
+        harness.keys(&["Tab", "A"]);
+        assert!(harness.app.visible_endpoints(0).is_empty());
+        assert_eq!(harness.app.selection.endpoint, 3);
+        harness.keys(&["a", "d", "l"]);
+        assert_eq!(harness.app.current_screen, Screen::CollectionList);
+        assert!(harness.app.collections[0].endpoints.iter().all(|e| !e.archived));
+        assert!(!harness.app.status_message.as_deref().unwrap_or_default().starts_with("Archived"));
+
+        // Back in the active view an endpoint is selected again
+        harness.press("A");
+        assert_eq!(harness.app.selection.endpoint, 2);
+    }
+
+    #[test]
+    fn test_windows_key_events() {
+        let mut harness = harness();
+        let press = |harness: &mut Harness, code, modifiers| {
+            for key in windows_key(code, modifiers) {
+                harness.send(key);
+            }
+        };
+
+        // Releases don't count: one step down, and Ctrl+R starts a recording rather than
+        // starting and stopping it
+        press(&mut harness, KeyCode::Tab, KeyModifiers::NONE);
+        press(&mut harness, KeyCode::Char('R'), KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+        press(&mut harness, KeyCode::Char('a'), KeyModifiers::NONE);
+        press(&mut harness, KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(harness.app.selection.endpoint, 1);
+        assert_eq!(harness.app.macros.recording(), Some('a'));
+        press(&mut harness, KeyCode::Char('\u{12}'), KeyModifiers::NONE);
+        assert_eq!(harness.app.status_message.as_deref(), Some("Recorded 1 keys into @a"));
+
+        // AltGr+Q types @ into a form on a German layout
+        harness.app.start_new_variable();
+        press(&mut harness, KeyCode::Tab, KeyModifiers::NONE);
+        for c in "ops@".chars() {
+            let modifiers = if c == '@' { KeyModifiers::CONTROL | KeyModifiers::ALT } else { KeyModifiers::NONE };
+            press(&mut harness, KeyCode::Char(c), modifiers);
+        }
+        assert_eq!(harness.app.variable_form.as_ref().unwrap().value, "ops@");
+        assert!(harness.render().contains("ops@"));
+    }
+}
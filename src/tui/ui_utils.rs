@@ -0,0 +1,63 @@
+// Display formatting shared by the UI
+// Values such as latencies are shown the same way on every screen, and
+// success/failure carries a symbol as well as a colour.
+
+use crate::models::HttpMethod;
+use std::time::Duration;
+
+/// Durations from here up are shown in seconds
+const SECONDS_FROM: Duration = Duration::from_secs(10);
+
+/// A duration for display: milliseconds with `decimals` decimal places, or
+/// seconds from 10s up, e.g. `123.5ms` or `12.3s`
+pub fn fmt_duration(duration: Duration, decimals: usize) -> String {
+    if duration >= SECONDS_FROM {
+        format!("{:.*}s", decimals, duration.as_secs_f64())
+    } else {
+        format!("{:.*}ms", decimals, duration.as_secs_f64() * 1000.0)
+    }
+}
+
+/// ✓ for 2xx, ✗ for 4xx/5xx and ℹ otherwise, so a status reads without its colour
+pub fn status_symbol(status: u16) -> &'static str {
+    match status {
+        200..=299 => "✓",
+        400..=599 => "✗",
+        _ => "ℹ",
+    }
+}
+
+/// Icon shown before a method name, so methods differ by more than colour
+pub fn method_icon(method: &HttpMethod) -> &'static str {
+    match method {
+        HttpMethod::GET => "📥",
+        HttpMethod::POST => "📤",
+        HttpMethod::PUT => "✏️",
+        HttpMethod::DELETE => "🗑️",
+        HttpMethod::PATCH => "🔧",
+        _ => "📨",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt_duration() {
+        assert_eq!(fmt_duration(Duration::from_nanos(123_456_789), 1), "123.5ms");
+        assert_eq!(fmt_duration(Duration::from_micros(850), 1), "0.8ms");
+        assert_eq!(fmt_duration(Duration::from_micros(850), 3), "0.850ms");
+        assert_eq!(fmt_duration(Duration::from_millis(9_999), 0), "9999ms");
+        assert_eq!(fmt_duration(Duration::from_millis(12_345), 1), "12.3s");
+        assert_eq!(fmt_duration(Duration::ZERO, 1), "0.0ms");
+    }
+
+    #[test]
+    fn test_status_symbol() {
+        assert_eq!(status_symbol(204), "✓");
+        assert_eq!(status_symbol(302), "ℹ");
+        assert_eq!(status_symbol(404), "✗");
+        assert_eq!(status_symbol(503), "✗");
+    }
+}
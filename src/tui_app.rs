@@ -1,15 +1,36 @@
 // Complete TUI application
 
-use crate::models::{ApiCollection, ApiEndpoint, HttpMethod};
-use crate::storage::StorageManager;
-use crate::http::{HttpClient, RequestInputs, HttpResponse};
+use crate::models::{ApiCollection, ApiEndpoint, Assertion, CollectionSource, ConnectionOptions, EmptyBody, Extraction, GraphqlBody, HttpMethod, ProtobufConfig, ScenarioStep};
+use crate::protobuf::ProtoSchema;
+use crate::scaffold::Resource;
+use crate::visualize::{JsonTable, Transform};
+use crate::storage::{expand_home, SkippedFile, StorageManager};
+use crate::http::{DownloadProgress, HttpClient, HttpClientConfig, HttpError, RequestInputs, HttpResponse};
 use crate::formatter;
-use crate::load_test::{LoadTestEngine, LoadTestConfig, LoadTestMetrics};
-use crate::variables::VariableManager;
-use crate::template;
-use std::sync::{Arc, Mutex};
+use crate::load_test::{self, ChaosConfig, LoadTestEngine, LoadTestConfig, LoadTestMetrics, LoadTestTarget, RunStatus};
+use crate::variables::{ImportMode, VariableError, VariableFormat, VariableManager};
+use crate::activity::{ActivityEntry, ActivityKind, ActivityLog};
+use crate::changelog;
+use crate::history::{HistoryEntry, HistoryStore};
+use crate::i18n;
+use crate::links;
+use crate::settings::{RepeatSend, Settings};
+use crate::proxy::{ProxyConfig, ProxySettings};
+use crate::logging;
+use crate::allowlist::{self, HostAllowlist};
+use crate::interop::{self, ExportFormat};
+use crate::merge::{self, Change};
+use crate::template::{self, TemplateError};
+use crate::tui::input::{number, optional_number};
+use crate::tui::macros::Macros;
+use crate::tui::screens::confirm_host::AfterConfirm;
+use crate::tui::screens::quick_edit::EndpointField;
+use crate::tui::screens::{CollectionRunScreen, CompareScreen, ConfirmHostScreen, ConfirmProtectedScreen, CopyCurlScreen, DiagnosticsScreen, DuplicateEndpointScreen, EndpointChangesScreen, GraphqlEditorScreen, HelpScreen, JsonTableScreen, LoadTestConfigForm, LoadTestConfigScreen, LoadTestReportScreen, LoadTestScenarioScreen, MergeScreen, NotesScreen, QuickEditScreen, ResponseLinksScreen, RunSelectedScreen, ScreenView, SplitViewScreen, TemplateErrorScreen, WebSocketScreen};
+use std::sync::mpsc;
+use std::cell::Cell;
+use std::path::Path;
 use std::time::Duration;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Screen {
@@ -19,13 +40,11 @@ pub enum Screen {
     EndpointEdit(usize, Option<usize>), // collection index, None for new, Some(idx) for edit
     EndpointDetail(usize, usize), // collection index, endpoint index
     ResponseView(usize, usize), // collection index, endpoint index
-    LoadTestConfig(usize, usize), // collection index, endpoint index
     LoadTestRunning(usize, usize), // collection index, endpoint index
     VariableList, // Variable management screen
     VariableEdit(Option<String>), // None for new, Some(key) for edit
     VariableInput(usize, usize), // Prompt for variables before request (collection index, endpoint index)
     ConfirmDelete(DeleteTarget), // confirmation dialog
-    Help,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -35,6 +54,15 @@ pub enum DeleteTarget {
     Variable(String), // variable key
 }
 
+/// A load test waiting in the queue
+#[derive(Debug, Clone)]
+pub struct QueuedLoadTest {
+    pub collection_index: usize,
+    pub endpoint_index: usize,
+    pub scenario: bool, // Run the collection's scenario rather than the endpoint
+    pub config: LoadTestConfig,
+}
+
 #[derive(Debug, Clone)]
 pub struct CollectionForm {
     pub name: String,
@@ -50,23 +78,105 @@ pub struct EndpointForm {
     pub headers: HashMap<String, String>,
     pub body_template: String,
     pub timeout_secs: String, // Timeout in seconds (empty = use default)
+    pub tags: String, // Comma-separated tags
+    pub protobuf: String, // `file.proto [Request] [-> Response]`, empty = plain body
+    pub empty_body: EmptyBody, // What to send when the body is empty
+    pub visualizer: String, // jq-style transform for responses, empty = raw body
+    pub assertions: String, // `status == 200; latency <= 500ms`, empty = none
+    pub connection: String, // `ipv4; close; fresh; idle 5s; expect-continue; interim`, empty = defaults
+    pub extractions: String, // `token = .data.token; etag = header ETag`, empty = none
     pub collection_index: usize,
     pub editing_index: Option<usize>,
-    pub current_field: usize, // 0=name, 1=method, 2=url, 3=description, 4=headers, 5=body, 6=timeout
+    pub current_field: usize, // 0=name, 1=method, 2=url, 3=description, 4=headers, 5=body, 6=timeout, 7=tags, 8=protobuf, 9=empty body, 10=visualizer, 11=assertions, 12=connection, 13=extractions
     pub header_edit_mode: bool, // true when editing headers
     pub header_key: String, // current header key being edited
     pub header_value: String, // current header value being edited
     pub header_edit_field: usize, // 0=key, 1=value
 }
 
-#[derive(Debug, Clone)]
-pub struct LoadTestConfigForm {
-    pub concurrency: String,
-    pub duration: String,
-    pub ramp_up: String,
-    pub current_field: usize, // 0=concurrency, 1=duration, 2=ramp_up
-    pub collection_index: usize,
-    pub endpoint_index: usize,
+/// Content-Type/Accept pairs the endpoint form can set with one key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentPreset {
+    Json,
+    Xml,
+    Form,
+    Plain,
+}
+
+impl ContentPreset {
+    /// Preset for a key pressed on the headers field
+    pub fn for_key(c: char) -> Option<Self> {
+        match c {
+            'j' => Some(Self::Json),
+            'x' => Some(Self::Xml),
+            'f' => Some(Self::Form),
+            't' => Some(Self::Plain),
+            _ => None,
+        }
+    }
+
+    /// (Content-Type, Accept)
+    pub fn headers(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Json => ("application/json", "application/json"),
+            Self::Xml => ("application/xml", "application/xml"),
+            Self::Form => ("application/x-www-form-urlencoded", "*/*"),
+            Self::Plain => ("text/plain", "text/plain"),
+        }
+    }
+}
+
+impl EndpointForm {
+    /// Set Content-Type and Accept, replacing any existing spelling of either
+    pub fn apply_content_preset(&mut self, preset: ContentPreset) {
+        let (content_type, accept) = preset.headers();
+        self.headers.retain(|k, _| !k.eq_ignore_ascii_case("content-type") && !k.eq_ignore_ascii_case("accept"));
+        self.headers.insert("Content-Type".to_string(), content_type.to_string());
+        self.headers.insert("Accept".to_string(), accept.to_string());
+    }
+    
+    pub fn cycle_method(&mut self) {
+        self.method = match self.method {
+            HttpMethod::GET => HttpMethod::POST,
+            HttpMethod::POST => HttpMethod::PUT,
+            HttpMethod::PUT => HttpMethod::PATCH,
+            HttpMethod::PATCH => HttpMethod::DELETE,
+            HttpMethod::DELETE => HttpMethod::HEAD,
+            HttpMethod::HEAD => HttpMethod::OPTIONS,
+            HttpMethod::OPTIONS | HttpMethod::Custom(_) => HttpMethod::GET,
+        };
+    }
+    
+    /// Typing on the method field spells out an extension method such as PROPFIND
+    pub fn push_method_char(&mut self, c: char) {
+        let mut name = match &self.method {
+            HttpMethod::Custom(name) => name.clone(),
+            _ => String::new(),
+        };
+        name.push(c);
+        self.method = HttpMethod::Custom(name);
+    }
+    
+    pub fn pop_method_char(&mut self) {
+        if let HttpMethod::Custom(name) = &mut self.method {
+            name.pop();
+            if name.is_empty() {
+                self.method = HttpMethod::GET;
+            }
+        }
+    }
+    
+    pub fn toggle_header_edit_mode(&mut self) {
+        if self.current_field == 4 { // Headers field
+            self.header_edit_mode = !self.header_edit_mode;
+            if self.header_edit_mode {
+                // Entering header edit mode
+                self.header_key = String::new();
+                self.header_value = String::new();
+                self.header_edit_field = 0;
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -92,31 +202,214 @@ pub enum PanelFocus {
     Endpoints,
 }
 
+/// The list that currently receives up/down navigation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusedList {
+    Collections,
+    Endpoints,
+    Variables,
+}
+
+/// Selected row in every list the UI shows. This is the single source of truth
+/// for selection: screens, navigation and deletion all read and write it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub collection: usize,
+    pub endpoint: usize,
+    pub variable: usize,
+}
+
+impl Selection {
+    pub fn get(&self, list: FocusedList) -> usize {
+        match list {
+            FocusedList::Collections => self.collection,
+            FocusedList::Endpoints => self.endpoint,
+            FocusedList::Variables => self.variable,
+        }
+    }
+    
+    pub fn move_up(&mut self, list: FocusedList) {
+        match list {
+            FocusedList::Collections => {
+                if self.collection > 0 {
+                    self.collection -= 1;
+                    self.endpoint = 0; // Reset endpoint selection
+                }
+            }
+            FocusedList::Endpoints => self.endpoint = self.endpoint.saturating_sub(1),
+            FocusedList::Variables => self.variable = self.variable.saturating_sub(1),
+        }
+    }
+    
+    pub fn move_down(&mut self, list: FocusedList, len: usize) {
+        match list {
+            FocusedList::Collections => {
+                if self.collection + 1 < len {
+                    self.collection += 1;
+                    self.endpoint = 0; // Reset endpoint selection
+                }
+            }
+            FocusedList::Endpoints => {
+                if self.endpoint + 1 < len {
+                    self.endpoint += 1;
+                }
+            }
+            FocusedList::Variables => {
+                if self.variable + 1 < len {
+                    self.variable += 1;
+                }
+            }
+        }
+    }
+    
+    /// Keep an index inside a list of `len` items, e.g. after its last item was deleted
+    pub fn clamp(&mut self, list: FocusedList, len: usize) {
+        let max = len.saturating_sub(1);
+        match list {
+            FocusedList::Collections => self.collection = self.collection.min(max),
+            FocusedList::Endpoints => self.endpoint = self.endpoint.min(max),
+            FocusedList::Variables => self.variable = self.variable.min(max),
+        }
+    }
+}
+
+/// A `/` search in the response body
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseSearch {
+    pub query: String,
+    pub editing: bool, // Still typing the query in the footer
+    pub current: usize, // Index into the body's matches for n/N
+}
+
+/// A request sent from the UI, running on the background runtime
+pub struct InFlightRequest {
+    pub id: u64,
+    pub collection_index: usize,
+    pub endpoint_index: usize,
+    pub label: String, // `GET users`
+    pub started: std::time::Instant,
+    pub progress: DownloadProgress,
+    pub follow_up: bool, // A link followed from a response; the endpoint's checks and history don't apply
+    pub for_view: bool, // Sent by a screen such as the split view, which shows the result itself
+    task: tokio::task::AbortHandle,
+}
+
+/// A send waiting for its endpoint's request in flight to finish
+pub struct QueuedRequest {
+    pub collection_index: usize,
+    pub endpoint_index: usize,
+    variables: HashMap<String, String>,
+    overrides: RequestOverrides,
+}
+
+/// One-off changes for a single send; the stored endpoint stays as it is
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestOverrides {
+    pub query: Option<String>, // Replaces the URL's query string
+    pub headers: Option<HashMap<String, String>>, // Replaces the endpoint's headers
+    pub follow: Option<String>, // A link from a response, sent as a GET with the endpoint's auth and headers
+}
+
+impl RequestOverrides {
+    /// The endpoint as this send sees it
+    pub fn apply(&self, endpoint: &mut ApiEndpoint) {
+        if let Some(query) = &self.query {
+            let base = endpoint.url.split_once('?').map_or(endpoint.url.as_str(), |(base, _)| base).to_string();
+            endpoint.url = if query.is_empty() { base } else { format!("{}?{}", base, query) };
+        }
+        if let Some(headers) = &self.headers {
+            endpoint.headers = headers.clone();
+        }
+        if let Some(link) = &self.follow {
+            endpoint.method = HttpMethod::GET;
+            endpoint.url = link.clone();
+            endpoint.body_template = None;
+            endpoint.graphql = None;
+            endpoint.protobuf = None;
+            endpoint.soap = None;
+        }
+    }
+}
+
+/// A collection or spec downloading for an import or a sync
+pub struct Fetch {
+    id: u64,
+    pub source: CollectionSource,
+    pub syncing: Option<uuid::Uuid>, // The collection being synced; None for an import
+    task: tokio::task::AbortHandle,
+}
+
+/// A background task's outcome, sent back to the UI thread
+#[allow(clippy::large_enum_variant)] // Mostly responses, which are moved on as they are
+enum Finished {
+    Request(u64, crate::http::Result<HttpResponse>),
+    Fetch(u64, interop::Result<Vec<ApiCollection>>),
+}
+
+/// How long `wait_for_requests` blocks between checks for finished tasks
+const REQUEST_WAIT_INTERVAL: Duration = Duration::from_millis(50);
+
 pub struct AppState {
     pub collections: Vec<ApiCollection>,
+    pub skipped_collections: Vec<SkippedFile>, // Collection files that failed to load, for the diagnostics screen
     pub current_screen: Screen,
     pub previous_screen: Option<Screen>,
-    pub selected_collection_index: usize,
-    pub selected_endpoint_index: usize,
-    pub selected_index: usize, // For backward compatibility
+    pub selection: Selection,
     pub panel_focus: PanelFocus,
+    pub tag_filter: Option<String>, // Only show endpoints carrying this tag
+    pub show_archived: bool, // List archived endpoints instead of active ones
+    pub marked_endpoints: HashSet<uuid::Uuid>, // Endpoints picked for a parallel run
+    pub allowlist: HostAllowlist,
+    pub approved_hosts: HashSet<String>, // Hosts confirmed once this session despite the allowlist
+    pub protected_confirmed: bool, // Set while the requests confirmed for a protected environment run
     pub show_network_traffic: bool, // Toggle for network traffic display
     pub show_response_headers: bool, // Toggle for response headers display
     pub collapsed_sections: HashSet<String>, // Track collapsed sections
     pub response_scroll_offset: usize, // Vertical scroll offset for response panel
     pub headers_scroll_offset: usize, // Vertical scroll offset for headers panel
+    pub response_wrap: bool, // Soft-wrap long lines in the response and traffic panels
+    pub response_h_scroll_offset: usize, // Horizontal scroll offset when wrapping is off
+    pub show_line_numbers: bool, // Show line numbers in the response body
+    pub number_display: formatter::NumberDisplay, // Epoch dates / thousands separators in JSON responses
+    pub response_view_as: formatter::ViewAs, // Manual body format for responses with a wrong Content-Type
+    pub goto_line_input: Option<String>, // Pending `:123` go-to-line command
+    pub response_search: Option<ResponseSearch>, // Kept across responses, like a pager's last search
+    pub pending_key: Option<char>, // First key of a two-key sequence such as `gg`
+    pub macros: Macros, // Key sequences recorded with `Ctrl+R`, played with `@`
+    pub response_view_height: Cell<usize>, // Visible response body lines, updated on each draw
+    pub response_h_scroll_max: Cell<usize>, // Furthest the body or traffic panel can scroll right, updated on each draw
     pub storage: StorageManager,
     pub http_client: HttpClient,
+    pub collection_clients: HashMap<uuid::Uuid, HttpClient>, // For collections with a proxy of their own
+    pub proxies: ProxyConfig,
     pub variable_manager: VariableManager,
+    pub history: HistoryStore,
+    pub activity: ActivityLog, // Imports and edits, for the timeline
     pub last_response: Option<HttpResponse>,
     pub last_response_formatted: Option<String>,
+    pub response_json_cuts: Vec<formatter::JsonCut>, // Parts of a huge JSON body the viewer left out
+    pub json_expanded: HashSet<String>, // JSON pointers `E` expanded past the viewer's limits
+    pub runtime: tokio::runtime::Runtime, // Sends requests in the background so the UI keeps drawing
+    pub in_flight: Vec<InFlightRequest>, // Oldest first, at most one per endpoint; Esc cancels them
+    pub queued_requests: VecDeque<QueuedRequest>, // Repeat sends waiting on their endpoint, with `repeat_send = "queue"`
+    pub fetches: Vec<Fetch>, // Imports and syncs downloading; Esc cancels them too
+    finished_tx: mpsc::Sender<Finished>,
+    finished_rx: mpsc::Receiver<Finished>,
+    next_request_id: u64,
+    pub response_alternate: Option<String>, // Raw body while the visualizer view is shown, and vice versa
+    pub showing_visualization: bool,
+    pub response_has_table: bool, // The response is an array of flat objects (T: table view)
+    pub response_assertions: Vec<(String, bool)>, // The endpoint's assertions against the last response, and whether each passed
     pub load_test_engine: Option<LoadTestEngine>,
     pub load_test_config: LoadTestConfig,
+    pub load_test_queue: VecDeque<QueuedLoadTest>, // Runs waiting for the current one to finish
+    queue_next_at: Option<std::time::Instant>, // When the next queued run starts (cool-down)
     pub error_message: Option<String>,
     pub status_message: Option<String>,
+    pub settings: Settings,
     pub collection_form: Option<CollectionForm>,
     pub endpoint_form: Option<EndpointForm>,
-    pub load_test_config_form: Option<LoadTestConfigForm>,
+    pub view: Option<Box<dyn ScreenView>>, // Screen that owns its state; drawn and given input ahead of `current_screen`
     pub variable_form: Option<VariableForm>,
     pub variable_input_form: Option<VariableInputForm>,
 }
@@ -124,35 +417,130 @@ pub struct AppState {
 impl AppState {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let storage = StorageManager::with_defaults()?;
-        let collections = storage.load_collections()?;
-        let http_client = HttpClient::new()?;
-        let variable_manager = VariableManager::new()?;
+        // First, so problems loading the workspace end up in the log
+        logging::init(&storage.log_path())?;
+        tracing::info!("Starting rest-api-tui {}", env!("CARGO_PKG_VERSION"));
+        Self::with_storage(storage, VariableManager::new()?, HistoryStore::new()?)
+    }
+    
+    /// Build the app state on top of explicit storage, e.g. a temporary directory in tests
+    pub fn with_storage(
+        storage: StorageManager,
+        variable_manager: VariableManager,
+        history: HistoryStore,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let (collections, skipped_collections) = storage.load_collections_reporting()?;
+        // A broken config.toml shouldn't keep the app from starting
+        let (mut settings, mut settings_error) = match Settings::load(&storage.config_path()) {
+            Ok(settings) => (settings, None),
+            Err(e) => {
+                tracing::warn!("Ignoring {}: {}", storage.config_path().display(), e);
+                (Settings::default(), Some(i18n::tr_with(i18n::Locale::default(), "error.config_ignored", &[("error", &e)])))
+            }
+        };
+        let proxies = match ProxyConfig::load(&storage.proxy_path()) {
+            Ok(proxies) => proxies,
+            Err(e) => {
+                tracing::warn!("Ignoring {}: {}", storage.proxy_path().display(), e);
+                settings_error = Some(i18n::tr_with(settings.locale, "error.proxy_ignored", &[("error", &e)]));
+                ProxyConfig::default()
+            }
+        };
+        // The proxy used to be a plain setting; it moves to proxy.toml the first time round
+        let proxies = match settings.proxy.take() {
+            Some(url) if !storage.proxy_path().exists() => {
+                let moved = ProxyConfig { global: ProxySettings { url: Some(url), no_proxy: Vec::new() }, ..proxies };
+                moved.save(&storage.proxy_path())?;
+                tracing::info!("Moved the proxy from config.toml to {}", storage.proxy_path().display());
+                moved
+            }
+            _ => proxies,
+        };
+        let (http_client, collection_clients) = match Self::build_clients(&settings, &variable_manager, &proxies) {
+            Ok(clients) => clients,
+            // Nor should an environment that's gone or has a bad certificate
+            Err(e) => {
+                let name = settings.environment.take().unwrap_or_default();
+                tracing::warn!("Not activating environment '{}': {}", name, e);
+                settings_error = Some(i18n::tr_with(settings.locale, "error.environment_not_activated", &[("name", &name), ("error", &e)]));
+                (HttpClient::with_config(settings.client_config())?, HashMap::new())
+            }
+        };
+        let allowlist = HostAllowlist::load(&storage.allowlist_path())?;
+        let activity = ActivityLog::load(&storage.activity_path())?;
+        let mut history = history;
+        history.prune(&settings.history)?;
+        storage.prune_results(&settings.results)?;
         
+        // Unreadable collection files are shown first thing rather than dropped silently
+        let view: Option<Box<dyn ScreenView>> = if skipped_collections.is_empty() {
+            None
+        } else {
+            Some(Box::new(DiagnosticsScreen::default()))
+        };
+        
+        let (finished_tx, finished_rx) = mpsc::channel();
         Ok(Self {
             collections,
+            skipped_collections,
             current_screen: Screen::CollectionList,
             previous_screen: None,
-            selected_collection_index: 0,
-            selected_endpoint_index: 0,
-            selected_index: 0,
+            selection: Selection::default(),
             panel_focus: PanelFocus::Collections,
+            tag_filter: None,
+            show_archived: false,
+            marked_endpoints: HashSet::new(),
+            allowlist,
+            approved_hosts: HashSet::new(),
+            protected_confirmed: false,
             show_network_traffic: false, // Disabled by default
             show_response_headers: false, // Disabled by default
             collapsed_sections: HashSet::new(), // No sections collapsed by default
             response_scroll_offset: 0,
             headers_scroll_offset: 0,
+            response_wrap: true, // Wrap long lines by default
+            response_h_scroll_offset: 0,
+            show_line_numbers: false,
+            number_display: formatter::NumberDisplay::default(),
+            response_view_as: formatter::ViewAs::Auto,
+            goto_line_input: None,
+            response_search: None,
+            pending_key: None,
+            macros: Macros::default(),
+            response_view_height: Cell::new(20),
+            response_h_scroll_max: Cell::new(0),
             storage,
             http_client,
+            collection_clients,
+            proxies,
             variable_manager,
+            history,
+            activity,
             last_response: None,
             last_response_formatted: None,
+            response_json_cuts: Vec::new(),
+            json_expanded: HashSet::new(),
+            runtime: tokio::runtime::Runtime::new()?,
+            in_flight: Vec::new(),
+            queued_requests: VecDeque::new(),
+            fetches: Vec::new(),
+            finished_tx,
+            finished_rx,
+            next_request_id: 0,
+            response_alternate: None,
+            showing_visualization: false,
+            response_has_table: false,
+            response_assertions: Vec::new(),
             load_test_engine: None,
-            load_test_config: LoadTestConfig::new(10, Duration::from_secs(30)),
-            error_message: None,
+            load_test_config: settings.default_load_test(),
+            load_test_queue: VecDeque::new(),
+            queue_next_at: None,
+            error_message: settings_error,
             status_message: None,
+            settings,
             collection_form: None,
             endpoint_form: None,
-            load_test_config_form: None,
+            view,
             variable_form: None,
             variable_input_form: None,
         })
@@ -190,11 +578,214 @@ impl AppState {
         self.response_scroll_offset = 0;
     }
     
+    /// Scroll down by half the visible response height (Ctrl+D)
+    pub fn scroll_response_half_page_down(&mut self) {
+        let half = (self.response_view_height.get() / 2).max(1);
+        self.scroll_response_down(half);
+    }
+    
+    /// Scroll up by half the visible response height (Ctrl+U)
+    pub fn scroll_response_half_page_up(&mut self) {
+        let half = (self.response_view_height.get() / 2).max(1);
+        self.scroll_response_up(half);
+    }
+    
     pub fn scroll_response_to_end(&mut self) {
         // Set to a very large number, will be clamped in draw function
         self.response_scroll_offset = usize::MAX;
     }
     
+    pub fn toggle_line_numbers(&mut self) {
+        self.show_line_numbers = !self.show_line_numbers;
+    }
+    
+    pub fn toggle_epoch_dates(&mut self) {
+        self.number_display.epoch_dates = !self.number_display.epoch_dates;
+    }
+    
+    pub fn toggle_thousands_separators(&mut self) {
+        self.number_display.thousands_separators = !self.number_display.thousands_separators;
+    }
+    
+    /// Show the body as the next format (auto, JSON, XML, text, hex, image)
+    pub fn cycle_view_as(&mut self) {
+        if self.last_response.is_none() {
+            return;
+        }
+        let view = self.response_view_as.next();
+        self.response_view_as = view;
+        // The override replaces any visualizer or decoded view
+        if let Err(e) = self.format_response() {
+            self.error_message = Some(self.tr_with("error.not_valid_as", &[("view", &view.label()), ("error", &e)]));
+        }
+        self.response_alternate = None;
+        self.showing_visualization = false;
+        self.response_has_table = self.response_table().is_some();
+        self.response_scroll_offset = 0;
+        self.response_h_scroll_offset = 0;
+    }
+    
+    /// Format `last_response` for the viewer as `response_view_as`. Huge JSON
+    /// is cut short, except where `E` expanded it; bodies that don't parse as
+    /// the chosen format are shown as text.
+    fn format_response(&mut self) -> formatter::Result<()> {
+        let Some(response) = &self.last_response else { return Ok(()) };
+        let formatted = formatter::format_body_limited(
+            &response.body,
+            response.content_type(),
+            self.response_view_as,
+            formatter::JsonLimits::default(),
+            &self.json_expanded,
+        );
+        let (text, cuts, result) = match formatted {
+            Ok(body) => (body.text, body.cuts, Ok(())),
+            Err(e) => (response.text(), Vec::new(), Err(e)),
+        };
+        self.last_response_formatted = Some(text);
+        self.response_json_cuts = cuts;
+        result
+    }
+    
+    /// Lay out in full the parts of a huge JSON body that were cut short on
+    /// screen (`E`); nested folds open one level per press
+    pub fn expand_json(&mut self) {
+        if self.showing_visualization {
+            return;
+        }
+        let top = self.response_scroll_offset;
+        let bottom = top.saturating_add(self.response_view_height.get());
+        let visible: Vec<String> = self.response_json_cuts.iter()
+            .filter(|cut| (top..bottom).contains(&cut.line))
+            .map(|cut| cut.pointer.clone())
+            .collect();
+        if visible.is_empty() {
+            self.status_message = Some(self.tr("status.nothing_cut_short").to_string());
+            return;
+        }
+        let count = visible.len();
+        self.json_expanded.extend(visible);
+        // Only JSON bodies have cuts, so this parses as before
+        let _ = self.format_response();
+        self.status_message = Some(self.tr_with("status.expanded_parts", &[("count", &count)]));
+    }
+    
+    pub fn start_goto_line(&mut self) {
+        self.goto_line_input = Some(String::new());
+    }
+    
+    pub fn cancel_goto_line(&mut self) {
+        self.goto_line_input = None;
+    }
+    
+    /// Finish a `:123` command, scrolling the response so that line is at the top.
+    /// Line numbers are 1-based; the draw function clamps past-the-end values.
+    pub fn confirm_goto_line(&mut self) {
+        if let Some(input) = self.goto_line_input.take() {
+            match input.parse::<usize>() {
+                Ok(line) if line > 0 => self.response_scroll_offset = line - 1,
+                _ => self.error_message = Some(self.tr_with("error.invalid_line_number", &[("input", &input)])),
+            }
+        }
+    }
+    
+    pub fn start_search(&mut self) {
+        self.response_search = Some(ResponseSearch { editing: true, ..Default::default() });
+    }
+    
+    pub fn cancel_search(&mut self) {
+        self.response_search = None;
+    }
+    
+    /// Finish typing a `/` search and jump to the first match at or below the
+    /// top of the response panel
+    pub fn confirm_search(&mut self) {
+        let Some(search) = &mut self.response_search else { return };
+        if search.query.is_empty() {
+            self.response_search = None;
+            return;
+        }
+        search.editing = false;
+        let matches = self.search_matches();
+        if matches.is_empty() {
+            let query = self.response_search.as_ref().map(|s| s.query.clone()).unwrap_or_default();
+            self.error_message = Some(self.tr_with("error.pattern_not_found", &[("query", &query)]));
+            return;
+        }
+        let top = self.response_scroll_offset;
+        let first = matches.iter().position(|(line, _)| *line >= top).unwrap_or(0);
+        self.jump_to_match(first, &matches);
+    }
+    
+    /// A confirmed search, so n/N step through its matches
+    pub fn search_active(&self) -> bool {
+        self.response_search.as_ref().is_some_and(|s| !s.editing)
+    }
+    
+    /// Line and byte range of every match in the formatted response body
+    pub fn search_matches(&self) -> Vec<(usize, std::ops::Range<usize>)> {
+        let (Some(search), Some(body)) = (&self.response_search, &self.last_response_formatted) else {
+            return Vec::new();
+        };
+        body.lines()
+            .enumerate()
+            .flat_map(|(i, line)| formatter::find_matches(line, &search.query).into_iter().map(move |range| (i, range)))
+            .collect()
+    }
+    
+    pub fn search_next(&mut self) {
+        self.step_search(1);
+    }
+    
+    pub fn search_prev(&mut self) {
+        self.step_search(-1);
+    }
+    
+    /// Move to the next (1) or previous (-1) match, wrapping around the body
+    fn step_search(&mut self, direction: isize) {
+        let matches = self.search_matches();
+        let Some(search) = &self.response_search else { return };
+        if matches.is_empty() {
+            self.error_message = Some(self.tr_with("error.pattern_not_found", &[("query", &search.query)]));
+            return;
+        }
+        let current = search.current.min(matches.len() - 1) as isize;
+        let next = (current + direction).rem_euclid(matches.len() as isize) as usize;
+        if (direction > 0 && next < current as usize) || (direction < 0 && next > current as usize) {
+            self.status_message = Some(self.tr("status.search_wrapped").to_string());
+        }
+        self.jump_to_match(next, &matches);
+    }
+    
+    /// Make a match current and scroll it into view
+    fn jump_to_match(&mut self, index: usize, matches: &[(usize, std::ops::Range<usize>)]) {
+        if let Some(search) = &mut self.response_search {
+            search.current = index;
+        }
+        let line = matches[index].0;
+        let height = self.response_view_height.get().max(1);
+        if line < self.response_scroll_offset || line >= self.response_scroll_offset.saturating_add(height) {
+            self.response_scroll_offset = line.saturating_sub(height / 2);
+        }
+    }
+    
+    pub fn toggle_response_wrap(&mut self) {
+        self.response_wrap = !self.response_wrap;
+        self.response_h_scroll_offset = 0;
+    }
+    
+    pub fn scroll_response_left(&mut self, columns: usize) {
+        // Start from where the view actually is, in case the content narrowed since
+        self.response_h_scroll_offset = self.response_h_scroll_offset.min(self.response_h_scroll_max.get()).saturating_sub(columns);
+    }
+    
+    pub fn scroll_response_right(&mut self, columns: usize) {
+        // Horizontal scrolling only makes sense when lines are not wrapped
+        if !self.response_wrap {
+            // Stop once the widest panel's lines end in view, so Left moves the view straight away
+            self.response_h_scroll_offset = self.response_h_scroll_offset.saturating_add(columns).min(self.response_h_scroll_max.get());
+        }
+    }
+    
     pub fn scroll_headers_up(&mut self, lines: usize) {
         self.headers_scroll_offset = self.headers_scroll_offset.saturating_sub(lines);
     }
@@ -207,43 +798,331 @@ impl AppState {
         self.headers_scroll_offset = 0;
     }
     
-    pub fn navigate_up(&mut self) {
-        match self.panel_focus {
-            PanelFocus::Collections => {
-                if self.selected_collection_index > 0 {
-                    self.selected_collection_index -= 1;
-                    self.selected_endpoint_index = 0; // Reset endpoint selection
-                }
+    /// Whether the screen changes without user input and needs periodic redraws
+    pub fn is_animating(&self) -> bool {
+        matches!(self.current_screen, Screen::LoadTestRunning(_, _))
+            || !self.load_test_queue.is_empty()
+            || self.load_test_engine.as_ref().is_some_and(|engine| !engine.is_reported())
+            || self.view.as_ref().is_some_and(|view| view.is_animating())
+            || !self.in_flight.is_empty()
+            || !self.fetches.is_empty()
+            || self.macros.is_playing()
+    }
+    
+    /// Let an open view advance on its own, e.g. pick up background results
+    pub fn tick(&mut self) {
+        self.poll_requests();
+        self.advance_load_tests();
+        if let Some(mut view) = self.view.take() {
+            view.tick(self);
+            if self.view.is_none() {
+                self.view = Some(view);
             }
-            PanelFocus::Endpoints => {
-                if self.selected_endpoint_index > 0 {
-                    self.selected_endpoint_index -= 1;
-                }
+        }
+    }
+    
+    /// Whether the response panel is visible, so response scroll keys apply
+    pub fn in_response_view(&self) -> bool {
+        self.last_response.is_some() && matches!(
+            self.current_screen,
+            Screen::CollectionList | Screen::EndpointList(_) | Screen::EndpointDetail(_, _) | Screen::ResponseView(_, _)
+        )
+    }
+    
+    /// Which list up/down navigation applies to on the current screen
+    pub fn focused_list(&self) -> FocusedList {
+        match (&self.current_screen, self.panel_focus) {
+            (Screen::VariableList, _) => FocusedList::Variables,
+            (Screen::EndpointList(_), _) => FocusedList::Endpoints,
+            (_, PanelFocus::Collections) => FocusedList::Collections,
+            (_, PanelFocus::Endpoints) => FocusedList::Endpoints,
+        }
+    }
+    
+    /// Indices of the endpoints shown for a collection under the current tag filter.
+    /// Archived endpoints only show up in the archived view, and then exclusively.
+    pub fn visible_endpoints(&self, coll_idx: usize) -> Vec<usize> {
+        let Some(collection) = self.collections.get(coll_idx) else {
+            return Vec::new();
+        };
+        collection.endpoints.iter()
+            .enumerate()
+            .filter(|(_, e)| self.endpoint_visible(e))
+            .map(|(i, _)| i)
+            .collect()
+    }
+    
+    pub fn endpoint_visible(&self, endpoint: &ApiEndpoint) -> bool {
+        endpoint.archived == self.show_archived
+            && self.tag_filter.as_ref().is_none_or(|tag| endpoint.has_tag(tag))
+    }
+    
+    /// Switch the endpoints panel between active and archived endpoints
+    pub fn toggle_archived_view(&mut self) {
+        self.show_archived = !self.show_archived;
+        let message = if self.show_archived { "status.showing_archived" } else { "status.showing_active" };
+        self.status_message = Some(self.tr(message).to_string());
+        self.clamp_selection();
+    }
+    
+    /// Edit an endpoint's GraphQL query and variables
+    pub fn open_graphql_editor(&mut self, collection_index: usize, endpoint_index: usize) {
+        if !self.ensure_editable(collection_index) {
+            return;
+        }
+        let screen = GraphqlEditorScreen::new(self, collection_index, endpoint_index);
+        self.open_view(Box::new(screen));
+    }
+    
+    /// Save an endpoint's GraphQL query and variables; GraphQL is sent as a POST.
+    /// An empty query turns GraphQL mode off.
+    pub fn set_endpoint_graphql(&mut self, collection_index: usize, endpoint_index: usize, graphql: GraphqlBody) -> bool {
+        let variables = graphql.variables.trim();
+        if !variables.is_empty() {
+            let checked = match formatter::validate_json_template(variables) {
+                Ok(()) if !variables.starts_with('{') => Err(self.tr("error.not_a_json_object").to_string()),
+                checked => checked.map_err(|e| e.to_string()),
+            };
+            if let Err(e) = checked {
+                self.error_message = Some(self.tr_with("error.invalid_graphql_variables", &[("error", &e)]));
+                return false;
+            }
+        }
+        if !self.ensure_editable(collection_index) {
+            return false;
+        }
+        let Some(collection) = self.collections.get_mut(collection_index) else { return false };
+        let before = collection.clone();
+        let Some(endpoint) = collection.endpoints.get_mut(endpoint_index) else { return false };
+        let name = endpoint.name.clone();
+        let (message, status) = if graphql.query.trim().is_empty() {
+            endpoint.graphql = None;
+            (format!("'{}' sends its body again", name), "status.graphql_off")
+        } else {
+            endpoint.graphql = Some(graphql);
+            endpoint.method = HttpMethod::POST;
+            (format!("Saved GraphQL query for '{}'", name), "status.graphql_saved")
+        };
+        changelog::record(&before, collection);
+        collection.updated_at = chrono::Utc::now();
+        
+        match self.storage.save_collection(collection) {
+            Ok(_) => {
+                let entry = ActivityEntry::new(ActivityKind::Edit, collection, &message);
+                self.record_activity(entry);
+                self.status_message = Some(self.tr_with(status, &[("name", &name)]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                false
             }
         }
-        // Backward compatibility
-        if self.selected_index > 0 {
-            self.selected_index -= 1;
+    }
+    
+    /// Read or edit a collection's notes
+    pub fn open_notes(&mut self, collection_index: usize) {
+        if collection_index < self.collections.len() {
+            self.open_view(Box::new(NotesScreen::new(collection_index)));
         }
     }
     
-    pub fn navigate_down(&mut self, max: usize) {
-        match self.panel_focus {
-            PanelFocus::Collections => {
-                if self.selected_collection_index < max.saturating_sub(1) {
-                    self.selected_collection_index += 1;
-                    self.selected_endpoint_index = 0; // Reset endpoint selection
-                }
+    /// Replace a collection's notes and save it
+    pub fn set_collection_notes(&mut self, collection_index: usize, notes: &str) -> bool {
+        if !self.ensure_editable(collection_index) {
+            return false;
+        }
+        let Some(collection) = self.collections.get_mut(collection_index) else { return false };
+        collection.notes = notes.trim_end().to_string();
+        collection.updated_at = chrono::Utc::now();
+        
+        match self.storage.save_collection(collection) {
+            Ok(_) => {
+                let name = collection.name.clone();
+                let entry = ActivityEntry::new(ActivityKind::Edit, collection, "Edited notes");
+                self.record_activity(entry);
+                self.status_message = Some(self.tr_with("status.saved_notes", &[("name", &name)]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Pick one field of an endpoint to change in place
+    pub fn open_quick_edit(&mut self, collection_index: usize, endpoint_index: usize) {
+        if !self.ensure_editable(collection_index) {
+            return;
+        }
+        let screen = QuickEditScreen::new(self, collection_index, endpoint_index);
+        self.open_view(Box::new(screen));
+    }
+    
+    /// Show the recorded edits of an endpoint
+    pub fn open_endpoint_changes(&mut self, collection_index: usize, endpoint_index: usize) {
+        let Some(endpoint) = self.collections.get(collection_index).and_then(|c| c.endpoints.get(endpoint_index)) else { return };
+        let screen = EndpointChangesScreen::new(collection_index, endpoint.id);
+        self.open_view(Box::new(screen));
+    }
+    
+    /// Change one field of an endpoint and save its collection. A header set to
+    /// an empty value is removed; a new header is written as `Name: value`.
+    pub fn update_endpoint_field(&mut self, collection_index: usize, endpoint_index: usize, field: &EndpointField, value: &str) -> bool {
+        if !self.ensure_editable(collection_index) {
+            return false;
+        }
+        let Some(collection) = self.collections.get_mut(collection_index) else { return false };
+        let before = collection.clone();
+        let Some(endpoint) = collection.endpoints.get_mut(endpoint_index) else { return false };
+        match field {
+            EndpointField::Url if value.trim().is_empty() => {
+                self.error_message = Some(self.tr("error.url_required").to_string());
+                return false;
             }
-            PanelFocus::Endpoints => {
-                if self.selected_endpoint_index < max.saturating_sub(1) {
-                    self.selected_endpoint_index += 1;
+            EndpointField::Url => endpoint.url = value.trim().to_string(),
+            EndpointField::Body => endpoint.body_template = Some(value.to_string()).filter(|b| !b.trim().is_empty()),
+            EndpointField::Header(name) if value.trim().is_empty() => { endpoint.headers.remove(name); }
+            EndpointField::Header(name) => { endpoint.headers.insert(name.clone(), value.trim().to_string()); }
+            EndpointField::NewHeader => match value.split_once(':') {
+                Some((name, header_value)) if !name.trim().is_empty() => {
+                    endpoint.headers.insert(name.trim().to_string(), header_value.trim().to_string());
                 }
+                _ => {
+                    self.error_message = Some(self.tr("error.header_format").to_string());
+                    return false;
+                }
+            },
+        }
+        let name = endpoint.name.clone();
+        let message = format!("Updated {} of '{}'", field.label(), name);
+        changelog::record(&before, collection);
+        collection.updated_at = chrono::Utc::now();
+        
+        match self.storage.save_collection(collection) {
+            Ok(_) => {
+                let entry = ActivityEntry::new(ActivityKind::Edit, collection, &message);
+                self.record_activity(entry);
+                self.status_message = Some(self.tr_with("status.updated_field", &[("field", &field.name(self)), ("name", &name)]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Archive an active endpoint or restore an archived one
+    pub fn toggle_endpoint_archived(&mut self, collection_index: usize, endpoint_index: usize) {
+        if !self.ensure_editable(collection_index) {
+            return;
+        }
+        let Some(collection) = self.collections.get_mut(collection_index) else { return };
+        let before = collection.clone();
+        let Some(endpoint) = collection.endpoints.get_mut(endpoint_index) else { return };
+        endpoint.archived = !endpoint.archived;
+        let name = endpoint.name.clone();
+        let (message, status) = if endpoint.archived {
+            (format!("Archived '{}' (A: view archived)", name), "status.archived")
+        } else {
+            (format!("Restored '{}'", name), "status.restored")
+        };
+        changelog::record(&before, collection);
+        
+        match self.storage.save_collection(collection) {
+            Ok(_) => {
+                let entry = ActivityEntry::new(ActivityKind::Edit, collection, &message);
+                self.record_activity(entry);
+                self.status_message = Some(self.tr_with(status, &[("name", &name)]));
+                self.error_message = None;
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+            }
+        }
+        self.clamp_selection();
+    }
+    
+    /// Step the tag filter through the selected collection's tags, then back to none
+    pub fn cycle_tag_filter(&mut self) {
+        let tags = self.collections.get(self.selection.collection)
+            .map(|c| c.tags())
+            .unwrap_or_default();
+        self.tag_filter = match &self.tag_filter {
+            None => tags.first().cloned(),
+            Some(current) => tags.iter()
+                .position(|t| t == current)
+                .and_then(|i| tags.get(i + 1))
+                .cloned(),
+        };
+        self.status_message = Some(match &self.tag_filter {
+            Some(tag) => self.tr_with("status.showing_tag", &[("tag", tag)]),
+            None if tags.is_empty() => self.tr("status.no_tags").to_string(),
+            None => self.tr("status.showing_all").to_string(),
+        });
+        self.clamp_selection();
+    }
+    
+    /// Move the endpoint selection by one visible row; `selection.endpoint` stays an
+    /// index into `collection.endpoints` so everything else can ignore the filter
+    fn step_endpoint_selection(&mut self, forward: bool) {
+        let visible = self.visible_endpoints(self.selection.collection);
+        let next = match visible.iter().position(|&i| i == self.selection.endpoint) {
+            Some(pos) if forward => visible.get(pos + 1),
+            Some(pos) => visible.get(pos.saturating_sub(1)),
+            None => visible.first(),
+        };
+        if let Some(&next) = next {
+            self.selection.endpoint = next;
+        }
+    }
+    
+    /// Number of items in the given list
+    pub fn list_len(&self, list: FocusedList) -> usize {
+        match list {
+            FocusedList::Collections => self.collections.len(),
+            FocusedList::Endpoints => self.collections.get(self.selection.collection)
+                .map(|c| c.endpoints.len())
+                .unwrap_or(0),
+            FocusedList::Variables => self.variable_manager.len(),
+        }
+    }
+    
+    pub fn navigate_up(&mut self) {
+        match self.focused_list() {
+            FocusedList::Endpoints => self.step_endpoint_selection(false),
+            list => self.selection.move_up(list),
+        }
+        self.clamp_selection();
+    }
+    
+    pub fn navigate_down(&mut self) {
+        match self.focused_list() {
+            FocusedList::Endpoints => self.step_endpoint_selection(true),
+            list => {
+                let len = self.list_len(list);
+                self.selection.move_down(list, len);
             }
         }
-        // Backward compatibility
-        if self.selected_index < max.saturating_sub(1) {
-            self.selected_index += 1;
+        self.clamp_selection();
+    }
+    
+    /// Pull every selection index back inside its list after items were removed,
+    /// and off any endpoint hidden by the tag filter
+    pub fn clamp_selection(&mut self) {
+        self.selection.clamp(FocusedList::Collections, self.collections.len());
+        self.selection.clamp(FocusedList::Endpoints, self.list_len(FocusedList::Endpoints));
+        self.selection.clamp(FocusedList::Variables, self.variable_manager.len());
+        
+        // With nothing shown, point past the end rather than at a hidden endpoint
+        let visible = self.visible_endpoints(self.selection.collection);
+        if !visible.contains(&self.selection.endpoint) {
+            self.selection.endpoint = visible.first().copied().unwrap_or(self.list_len(FocusedList::Endpoints));
         }
     }
     
@@ -267,10 +1146,6 @@ impl AppState {
             }
             Screen::EndpointDetail(coll_idx, _) => Screen::EndpointList(*coll_idx),
             Screen::ResponseView(coll_idx, _) => Screen::EndpointList(*coll_idx),
-            Screen::LoadTestConfig(_, _) => {
-                self.load_test_config_form = None;
-                Screen::CollectionList
-            }
             Screen::LoadTestRunning(coll_idx, _) => Screen::EndpointList(*coll_idx),
             Screen::VariableList => Screen::CollectionList,
             Screen::VariableEdit(_) => {
@@ -285,11 +1160,10 @@ impl AppState {
                 // Go back to previous screen
                 self.previous_screen.clone().unwrap_or(Screen::CollectionList)
             }
-            Screen::Help => Screen::CollectionList,
             _ => Screen::CollectionList,
         };
         self.current_screen = new_screen;
-        self.selected_index = 0;
+        self.clamp_selection();
     }
     
     pub fn select(&mut self) {
@@ -299,14 +1173,14 @@ impl AppState {
                 if self.panel_focus == PanelFocus::Collections {
                     // Switch focus to endpoints panel
                     self.panel_focus = PanelFocus::Endpoints;
-                    self.selected_endpoint_index = 0;
+                    self.selection.endpoint = 0;
                 } else if self.panel_focus == PanelFocus::Endpoints {
                     // Select the endpoint to view details
-                    if let Some(collection) = self.collections.get(self.selected_collection_index) {
-                        if self.selected_endpoint_index < collection.endpoints.len() {
+                    if let Some(collection) = self.collections.get(self.selection.collection) {
+                        if self.selection.endpoint < collection.endpoints.len() {
                             self.current_screen = Screen::EndpointDetail(
-                                self.selected_collection_index,
-                                self.selected_endpoint_index
+                                self.selection.collection,
+                                self.selection.endpoint
                             );
                         }
                     }
@@ -314,8 +1188,8 @@ impl AppState {
             }
             Screen::EndpointList(coll_idx) => {
                 if let Some(collection) = self.collections.get(*coll_idx) {
-                    if self.selected_index < collection.endpoints.len() {
-                        self.current_screen = Screen::EndpointDetail(*coll_idx, self.selected_index);
+                    if self.selection.endpoint < collection.endpoints.len() {
+                        self.current_screen = Screen::EndpointDetail(*coll_idx, self.selection.endpoint);
                     }
                 }
             }
@@ -326,212 +1200,676 @@ impl AppState {
     pub async fn execute_request(&mut self, coll_idx: usize, ep_idx: usize) {
         if let Some(collection) = self.collections.get(coll_idx) {
             if let Some(endpoint) = collection.endpoints.get(ep_idx) {
-                self.status_message = Some("Executing request...".to_string());
+                self.status_message = Some(self.tr("status.executing").to_string());
                 
                 let inputs = RequestInputs::default();
-                match self.http_client.execute(endpoint, &inputs).await {
+                let started = std::time::Instant::now();
+                match self.client_for(coll_idx).execute(endpoint, &inputs).await {
                     Ok(response) => {
-                        // Format response
-                        let formatted = formatter::format_auto(&response.body)
-                            .unwrap_or_else(|_| String::from_utf8_lossy(&response.body).to_string());
-                        
+                        let (status, duration) = (response.status.as_u16(), response.duration);
                         self.last_response = Some(response);
-                        self.last_response_formatted = Some(formatted);
                         self.response_scroll_offset = 0; // Reset scroll on new response
                         self.headers_scroll_offset = 0; // Reset headers scroll on new response
+                        self.response_h_scroll_offset = 0; // Reset horizontal scroll on new response
+                        self.response_view_as = formatter::ViewAs::Auto;
+                        self.json_expanded.clear();
+                        let _ = self.format_response();
                         // Stay on the same screen in new layout
-                        self.status_message = Some("Request completed successfully".to_string());
-                        self.error_message = None;
+                        self.status_message = Some(self.tr("status.request_completed").to_string());
+                        // Cut-short bodies are shown, but flagged
+                        self.error_message = self.last_response.as_ref()
+                            .and_then(|r| r.body_mismatch())
+                            .map(|mismatch| format!("⚠ {}", mismatch));
+                        self.check_soap_response(coll_idx, ep_idx);
+                        self.check_assertions(coll_idx, ep_idx);
+                        self.decode_protobuf_response(coll_idx, ep_idx);
+                        self.visualize_response(coll_idx, ep_idx);
+                        self.response_has_table = self.response_table().is_some();
+                        let body = self.last_response.as_ref().map(|r| r.body.clone()).unwrap_or_default();
+                        self.record_history(coll_idx, ep_idx, Some(status), duration, None, &body);
                     }
                     Err(e) => {
-                        self.error_message = Some(format!("Request failed: {}", e));
+                        self.error_message = Some(self.tr_with("error.request_failed", &[("error", &e)]));
                         self.status_message = None;
+                        self.record_history(coll_idx, ep_idx, None, started.elapsed(), Some(e.to_string()), &[]);
+                        self.open_template_error(&e);
                     }
                 }
             }
         }
     }
     
+    /// SOAP endpoints must answer with well-formed XML; surface faults and parse errors
+    fn check_soap_response(&mut self, coll_idx: usize, ep_idx: usize) {
+        let is_soap = self.collections.get(coll_idx)
+            .and_then(|c| c.endpoints.get(ep_idx))
+            .is_some_and(|e| e.soap.is_some());
+        let Some(response) = self.last_response.as_ref().filter(|_| is_soap) else { return };
+        if let Err(e) = formatter::validate_xml(&response.body) {
+            self.error_message = Some(self.tr_with("error.invalid_soap", &[("error", &e)]));
+        } else if let Some(fault) = formatter::soap_fault(&response.body) {
+            self.error_message = Some(self.tr_with("error.soap_fault", &[("fault", &fault)]));
+        }
+    }
+    
+    /// Check the last response against the endpoint's assertions for the response panel
+    fn check_assertions(&mut self, coll_idx: usize, ep_idx: usize) {
+        let assertions = self.collections.get(coll_idx)
+            .and_then(|c| c.endpoints.get(ep_idx))
+            .map(|e| e.assertions.clone())
+            .unwrap_or_default();
+        let Some(response) = &self.last_response else { return };
+        self.response_assertions = assertions.iter()
+            .map(|a| (a.to_string(), a.passes(response.status.as_u16(), response.duration, &response.headers, &response.body)))
+            .collect();
+    }
+    
+    /// Show protobuf responses as JSON when the endpoint names a response type
+    fn decode_protobuf_response(&mut self, coll_idx: usize, ep_idx: usize) {
+        let Some(config) = self.collections.get(coll_idx)
+            .and_then(|c| c.endpoints.get(ep_idx))
+            .and_then(|e| e.protobuf.clone()) else { return };
+        let (Some(message), Some(response)) = (&config.response_message, &self.last_response) else { return };
+        let decoded = ProtoSchema::load_cached(&expand_home(&config.proto_file))
+            .and_then(|schema| schema.decode_to_json(message, &response.body));
+        match decoded {
+            Ok(json) => {
+                self.last_response_formatted = Some(json);
+                self.response_json_cuts.clear();
+            }
+            Err(e) => self.error_message = Some(self.tr_with("error.decode", &[("message", message), ("error", &e)])),
+        }
+    }
+    
+    /// Show the endpoint's visualizer output in place of the body; `V` swaps back
+    fn visualize_response(&mut self, coll_idx: usize, ep_idx: usize) {
+        self.response_alternate = None;
+        self.showing_visualization = false;
+        let Some(expression) = self.collections.get(coll_idx)
+            .and_then(|c| c.endpoints.get(ep_idx))
+            .and_then(|e| e.visualizer.clone()) else { return };
+        let Some(formatted) = &self.last_response_formatted else { return };
+        // Run on the formatted text so decoded protobuf responses work too,
+        // unless it is a cut-short view of a huge body
+        let source = match &self.last_response {
+            Some(response) if !self.response_json_cuts.is_empty() => response.text(),
+            _ => formatted.clone(),
+        };
+        let rendered = Transform::parse(&expression).and_then(|t| t.render(source.as_bytes()));
+        match rendered {
+            Ok(view) => {
+                self.response_alternate = self.last_response_formatted.replace(view);
+                self.showing_visualization = true;
+            }
+            Err(e) => self.error_message = Some(self.tr_with("error.visualizer", &[("error", &e)])),
+        }
+    }
+    
+    /// Switch between the visualizer view and the raw response
+    pub fn toggle_visualization(&mut self) {
+        let Some(alternate) = self.response_alternate.take() else {
+            self.status_message = Some(self.tr("status.no_visualizer").to_string());
+            return;
+        };
+        self.response_alternate = self.last_response_formatted.replace(alternate);
+        self.showing_visualization = !self.showing_visualization;
+        self.response_scroll_offset = 0;
+        self.response_h_scroll_offset = 0;
+    }
+    
+    /// The response as a table, when it is an array of flat objects
+    fn response_table(&self) -> Option<JsonTable> {
+        // The formatted text covers decoded protobuf; skip it while it holds a visualizer view
+        let formatted = if self.showing_visualization { &self.response_alternate } else { &self.last_response_formatted };
+        formatted.as_ref()
+            .and_then(|text| JsonTable::from_body(text.as_bytes()))
+            .or_else(|| self.last_response.as_ref().and_then(|r| JsonTable::from_body(&r.body)))
+    }
+    
+    pub fn open_json_table(&mut self) {
+        match self.response_table() {
+            Some(table) => self.open_view(Box::new(JsonTableScreen::new(table))),
+            None => self.status_message = Some(self.tr("status.no_table").to_string()),
+        }
+    }
+    
+    /// Pick a link in the last response to GET with the selected endpoint's auth
+    pub fn open_response_links(&mut self) {
+        let links = self.last_response.as_ref().map(links::response_links).unwrap_or_default();
+        let endpoint = self.collections.get(self.selection.collection).filter(|c| self.selection.endpoint < c.endpoints.len());
+        match endpoint {
+            _ if links.is_empty() => self.status_message = Some(self.tr("status.no_links").to_string()),
+            Some(_) => self.open_view(Box::new(ResponseLinksScreen::new(self.selection.collection, self.selection.endpoint, links))),
+            None => self.error_message = Some(self.tr("error.links_need_endpoint").to_string()),
+        }
+    }
+    
+    /// Append an executed request and its response body to the persistent history
+    pub(crate) fn record_history(&mut self, coll_idx: usize, ep_idx: usize, status: Option<u16>, duration: Duration, error: Option<String>, body: &[u8]) {
+        let Some(collection) = self.collections.get(coll_idx) else { return };
+        let Some(endpoint) = collection.endpoints.get(ep_idx) else { return };
+        
+        let mut entry = HistoryEntry::new(collection.id, endpoint.id, endpoint.method.clone(), endpoint.url.clone());
+        entry.status = status;
+        entry.duration_ms = duration.as_millis() as u64;
+        entry.error = error;
+        // The template URL, so substituted secrets stay out of the log
+        match (&entry.status, &entry.error) {
+            (Some(status), _) => tracing::info!("{} {} -> {} in {} ms", entry.method, entry.url, status, entry.duration_ms),
+            (None, error) => tracing::warn!("{} {} failed: {}", entry.method, entry.url, error.as_deref().unwrap_or("no response")),
+        }
+        if let Err(e) = self.history.record_with_body(entry, body) {
+            tracing::error!("Failed to save history: {}", e);
+            self.error_message = Some(self.tr_with("error.save_history", &[("error", &e)]));
+        }
+    }
+    
+    /// Note an import or edit in the activity journal; a failure is only logged
+    pub(crate) fn record_activity(&mut self, entry: ActivityEntry) {
+        if let Err(e) = self.activity.record(entry) {
+            tracing::error!("Failed to save activity: {}", e);
+        }
+    }
+    
+    /// Show a past response from history in the response panel, selecting its endpoint
+    pub fn open_history_entry(&mut self, index: usize) {
+        let Some(entry) = self.history.entries().get(index).cloned() else { return };
+        let location = self.collections.iter().enumerate()
+            .find(|(_, c)| c.id == entry.collection_id)
+            .and_then(|(ci, c)| c.endpoints.iter().position(|e| e.id == entry.endpoint_id).map(|ei| (ci, ei)));
+        if let Some((coll_idx, ep_idx)) = location {
+            self.selection.collection = coll_idx;
+            self.selection.endpoint = ep_idx;
+            self.panel_focus = PanelFocus::Endpoints;
+        }
+        self.current_screen = Screen::CollectionList;
+        
+        let when = entry.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S");
+        let status = entry.status.and_then(|s| reqwest::StatusCode::from_u16(s).ok());
+        let Some(status) = status else {
+            let error = entry.error.as_deref().unwrap_or(self.tr("error.no_response"));
+            self.error_message = Some(self.tr_with("error.history_entry", &[("when", &when), ("error", &error)]));
+            return;
+        };
+        let body = self.history.body(&entry).unwrap_or_default();
+        self.last_response = Some(HttpResponse {
+            status,
+            headers: HashMap::new(),
+            body,
+            duration: entry.duration(),
+            traffic: None,
+        });
+        self.response_scroll_offset = 0;
+        self.headers_scroll_offset = 0;
+        self.response_h_scroll_offset = 0;
+        self.response_view_as = formatter::ViewAs::Auto;
+        self.json_expanded.clear();
+        let _ = self.format_response();
+        self.showing_visualization = false;
+        self.response_alternate = None;
+        // Headers aren't kept in history, so its responses aren't checked
+        self.response_assertions.clear();
+        if let Some((coll_idx, ep_idx)) = location {
+            self.visualize_response(coll_idx, ep_idx);
+        }
+        self.response_has_table = self.response_table().is_some();
+        self.error_message = None;
+        self.status_message = Some(self.tr_with("status.history_entry", &[("method", &entry.method), ("url", &entry.url), ("when", &when)]));
+    }
+    
+    /// The endpoint's host when the allowlist would stop it and it wasn't approved this session
+    pub fn unapproved_host(&self, endpoint: &ApiEndpoint, variables: &HashMap<String, String>) -> Option<String> {
+        let host = allowlist::host_of(&template::substitute_lenient(&endpoint.url, variables))?;
+        (!self.allowlist.allows(&host) && !self.approved_hosts.contains(&host)).then_some(host)
+    }
+    
+    /// Ask before sending to hosts outside the allowlist; `then` runs once they are approved.
+    /// Returns true when there is nothing to ask and the caller can go ahead.
+    pub fn confirm_hosts(&mut self, mut hosts: Vec<String>, then: AfterConfirm) -> bool {
+        hosts.sort();
+        hosts.dedup();
+        if hosts.is_empty() {
+            return true;
+        }
+        self.open_view(Box::new(ConfirmHostScreen::new(hosts, then)));
+        false
+    }
+    
+    /// `DELETE https://...` when the endpoint may change data, for the protected environment prompt
+    pub fn write_request(endpoint: &ApiEndpoint, variables: &HashMap<String, String>) -> Option<String> {
+        (!endpoint.method.is_safe())
+            .then(|| format!("{} {}", endpoint.method, template::substitute_lenient(&endpoint.url, variables)))
+    }
+    
+    /// Ask for the environment's name before sending requests that change data
+    /// to a protected environment; `then` runs once it has been typed.
+    /// Returns true when there is nothing to ask and the caller can go ahead.
+    pub fn confirm_protected(&mut self, environment: Option<String>, requests: Vec<String>, then: AfterConfirm) -> bool {
+        let Some(environment) = environment.filter(|environment| self.needs_protected_confirmation(environment, &requests)) else {
+            return true;
+        };
+        self.open_view(Box::new(ConfirmProtectedScreen::new(environment, requests, then)));
+        false
+    }
+    
+    /// Whether sending `requests` to `environment` has to be confirmed first
+    pub fn needs_protected_confirmation(&self, environment: &str, requests: &[String]) -> bool {
+        !requests.is_empty()
+            && !self.protected_confirmed
+            && self.variable_manager.environment(environment).is_ok_and(|set| set.protected)
+    }
+    
+    /// Add a host to the workspace allowlist for good
+    pub fn allow_host(&mut self, host: &str) {
+        self.allowlist.add(host);
+        if let Err(e) = self.allowlist.save(&self.storage.allowlist_path()) {
+            self.error_message = Some(self.tr_with("error.save_allowlist", &[("error", &e)]));
+        }
+    }
+    
+    /// Open a screen that owns its state on top of the current one
+    pub fn open_view(&mut self, view: Box<dyn ScreenView>) {
+        self.view = Some(view);
+    }
+    
+    pub fn show_help(&mut self) {
+        self.open_view(Box::new(HelpScreen::default()));
+    }
+    
     pub fn start_load_test(&mut self, coll_idx: usize, ep_idx: usize) {
         // Show configuration form first
         if let Some(collection) = self.collections.get(coll_idx) {
             if let Some(endpoint) = collection.endpoints.get(ep_idx) {
-                // Load existing config or use defaults
-                let (concurrency, duration, ramp_up) = if let Some(config) = &endpoint.load_test_config {
-                    (
-                        config.concurrency.to_string(),
-                        config.duration_secs.to_string(),
-                        config.ramp_up_secs.map(|s| s.to_string()).unwrap_or_default(),
-                    )
-                } else {
-                    ("10".to_string(), "30".to_string(), String::new())
-                };
-                
-                self.load_test_config_form = Some(LoadTestConfigForm {
-                    concurrency,
-                    duration,
-                    ramp_up,
-                    current_field: 0,
-                    collection_index: coll_idx,
-                    endpoint_index: ep_idx,
-                });
-                
-                self.current_screen = Screen::LoadTestConfig(coll_idx, ep_idx);
+                let form = LoadTestConfigForm::for_endpoint(endpoint, &self.settings.load_test, coll_idx, ep_idx);
+                // Every worker sends the same payload, so flag a huge one up front
+                if let Some(over) = self.saved_variables(endpoint).ok().and_then(|variables| self.request_budget_warning(endpoint, &variables)) {
+                    self.error_message = Some(format!("⚠ {}", over));
+                }
+                self.open_view(Box::new(LoadTestConfigScreen::new(form)));
             }
         }
     }
     
-    pub fn execute_load_test(&mut self) {
-        if let Some(form) = &self.load_test_config_form {
-            let coll_idx = form.collection_index;
-            let ep_idx = form.endpoint_index;
-            
-            // Parse configuration
-            let concurrency = form.concurrency.parse::<usize>().unwrap_or(10);
-            let duration_secs = form.duration.parse::<u64>().unwrap_or(30);
-            let ramp_up_secs = if form.ramp_up.is_empty() {
-                None
-            } else {
-                form.ramp_up.parse::<u64>().ok()
-            };
-            
-            // Create config
-            let mut config = LoadTestConfig::new(concurrency, Duration::from_secs(duration_secs));
-            if let Some(ramp_up) = ramp_up_secs {
-                config = config.with_ramp_up(Duration::from_secs(ramp_up));
+    /// Weigh the collection's endpoints for a scenario run
+    pub fn open_load_test_scenario(&mut self, coll_idx: usize) {
+        match self.collections.get(coll_idx) {
+            Some(collection) if collection.endpoints.is_empty() => {
+                self.error_message = Some(self.tr_with("error.nothing_to_load_test", &[("name", &collection.name)]));
             }
-            
-            // Validate
-            if let Err(e) = config.validate() {
+            Some(_) => self.open_view(Box::new(LoadTestScenarioScreen::new(self, coll_idx))),
+            None => {}
+        }
+    }
+    
+    /// Replace a collection's scenario and save it
+    pub fn set_load_test_scenario(&mut self, coll_idx: usize, scenario: Vec<ScenarioStep>) -> bool {
+        if !self.ensure_editable(coll_idx) {
+            return false;
+        }
+        let Some(collection) = self.collections.get_mut(coll_idx) else { return false };
+        collection.scenario = scenario;
+        collection.updated_at = chrono::Utc::now();
+        match self.storage.save_collection(collection) {
+            Ok(_) => {
+                let entry = ActivityEntry::new(ActivityKind::Edit, collection, "Edited the load test scenario");
+                self.record_activity(entry);
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Configure a run of the collection's saved scenario
+    pub fn start_scenario_load_test(&mut self, coll_idx: usize) {
+        let Some(collection) = self.collections.get(coll_idx) else { return };
+        let Some((first, _)) = collection.scenario_endpoints().first().copied() else {
+            self.error_message = Some(self.tr_with("error.no_scenario", &[("name", &collection.name)]));
+            return;
+        };
+        let ep_idx = collection.endpoints.iter().position(|e| e.id == first.id).unwrap_or_default();
+        let mut form = LoadTestConfigForm::for_endpoint(first, &self.settings.load_test, coll_idx, ep_idx);
+        form.scenario = true;
+        self.open_view(Box::new(LoadTestConfigScreen::new(form)));
+    }
+    
+    /// What a run sends: the scenario's endpoints by weight, or the one endpoint
+    fn load_test_endpoints(&self, coll_idx: usize, ep_idx: usize, scenario: bool) -> Vec<(ApiEndpoint, u32)> {
+        let Some(collection) = self.collections.get(coll_idx) else { return Vec::new() };
+        if scenario {
+            collection.scenario_endpoints().into_iter().map(|(endpoint, weight)| (endpoint.clone(), weight)).collect()
+        } else {
+            collection.endpoints.get(ep_idx).map(|endpoint| vec![(endpoint.clone(), 1)]).unwrap_or_default()
+        }
+    }
+    
+    /// Validate the form and start the load test. Returns false when the
+    /// configuration is invalid or the test could not be started.
+    pub fn execute_load_test(&mut self, form: &LoadTestConfigForm) -> bool {
+        let coll_idx = form.collection_index;
+        let ep_idx = form.endpoint_index;
+        
+        // Parse configuration; blank client overrides inherit, blank ramp-up, cool-down and HAR samples mean none
+        let fields = (|| Ok::<_, String>((
+            number::<usize>(self, &form.concurrency, "field.concurrency")?,
+            number::<u64>(self, &form.duration, "field.duration")?,
+            optional_number::<u64>(self, &form.ramp_up, "field.ramp_up")?,
+            optional_number::<u64>(self, &form.timeout, "field.request_timeout")?,
+            optional_number::<usize>(self, &form.pool_size, "field.pool_size")?,
+            optional_number::<u64>(self, &form.cool_down, "field.cool_down")?.unwrap_or(0),
+            optional_number::<usize>(self, &form.har_samples, "field.har_samples")?.unwrap_or(0),
+        )))();
+        let (concurrency, duration_secs, ramp_up_secs, timeout_secs, pool_max_idle_per_host, cool_down_secs, har_samples) = match fields {
+            Ok(fields) => fields,
+            Err(e) => {
                 self.error_message = Some(e);
-                return;
+                return false;
             }
-            
-            // Save config to endpoint
-            if let Some(collection) = self.collections.get_mut(coll_idx) {
-                if let Some(endpoint) = collection.endpoints.get_mut(ep_idx) {
-                    endpoint.load_test_config = Some(crate::models::LoadTestConfigData {
-                        concurrency,
-                        duration_secs,
-                        ramp_up_secs,
-                        rate_limit: None,
-                    });
-                    let _ = self.storage.save_collection(collection);
+        };
+        let http1_only = form.http1_only;
+        let disable_compression = form.disable_compression;
+        let soak = form.soak;
+        let chaos = match ChaosConfig::parse(&form.chaos) {
+            Ok(chaos) => chaos,
+            Err(e) => {
+                self.error_message = Some(e);
+                return false;
+            }
+        };
+        let headers = match load_test::parse_headers(&form.headers) {
+            Ok(headers) => headers,
+            Err(e) => {
+                self.error_message = Some(e);
+                return false;
+            }
+        };
+        
+        // Create config
+        let mut config = LoadTestConfig::new(concurrency, Duration::from_secs(duration_secs))
+            .with_headers(headers.clone())
+            .with_har_samples(har_samples)
+            .with_cool_down(Duration::from_secs(cool_down_secs))
+            .with_soak(soak)
+            .with_chaos(chaos.clone());
+        if let Some(ramp_up) = ramp_up_secs {
+            config = config.with_ramp_up(Duration::from_secs(ramp_up));
+        }
+        
+        // Client overrides apply to this run only; anything left blank inherits the interactive client
+        if timeout_secs.is_some() || pool_max_idle_per_host.is_some() || http1_only || disable_compression {
+            let defaults = self.client_for(coll_idx).config();
+            config = config.with_client_config(HttpClientConfig {
+                timeout: timeout_secs.map(Duration::from_secs).unwrap_or(defaults.timeout),
+                pool_max_idle_per_host: pool_max_idle_per_host.unwrap_or(defaults.pool_max_idle_per_host),
+                http1_only,
+                disable_compression,
+                ..defaults.clone()
+            });
+        }
+        
+        // Validate
+        if let Err(e) = config.validate() {
+            self.error_message = Some(e);
+            return false;
+        }
+        
+        // Save config to endpoint; a scenario's run isn't any one endpoint's
+        if let Some(collection) = self.collections.get_mut(coll_idx).filter(|_| !form.scenario) {
+            if let Some(endpoint) = collection.endpoints.get_mut(ep_idx) {
+                endpoint.load_test_config = Some(crate::models::LoadTestConfigData {
+                    concurrency,
+                    duration_secs,
+                    ramp_up_secs,
+                    rate_limit: None,
+                    timeout_secs,
+                    pool_max_idle_per_host,
+                    http1_only,
+                    disable_compression,
+                    headers,
+                    har_samples,
+                    cool_down_secs,
+                    soak,
+                    chaos,
+                });
+                let _ = self.storage.save_collection(collection);
+            }
+        }
+        
+        if form.queue {
+            self.queue_load_test(coll_idx, ep_idx, form.scenario, config);
+            return true;
+        }
+        
+        self.begin_load_test(coll_idx, ep_idx, form.scenario, config);
+        matches!(self.current_screen, Screen::LoadTestRunning(_, _))
+    }
+    
+    /// Start the actual load test; anything queued before runs first
+    fn begin_load_test(&mut self, coll_idx: usize, ep_idx: usize, scenario: bool, config: LoadTestConfig) {
+        if !self.confirm_load_test_writes(coll_idx, ep_idx, scenario, &config, move |app, retry| app.begin_load_test(coll_idx, ep_idx, scenario, retry)) {
+            return;
+        }
+        if !self.load_test_queue.is_empty() {
+            self.load_test_queue.push_back(QueuedLoadTest { collection_index: coll_idx, endpoint_index: ep_idx, scenario, config });
+            self.start_next_queued_load_test();
+        } else {
+            self.execute_load_test_with_config(coll_idx, ep_idx, scenario, config);
+        }
+    }
+    
+    /// Ask before load testing endpoints that change data in a protected
+    /// environment. Asked when the run is started or queued, so the queue runs unattended.
+    fn confirm_load_test_writes(
+        &mut self,
+        coll_idx: usize,
+        ep_idx: usize,
+        scenario: bool,
+        config: &LoadTestConfig,
+        retry: impl FnOnce(&mut AppState, LoadTestConfig) + 'static,
+    ) -> bool {
+        let variables = self.request_variables();
+        let writes = self.load_test_endpoints(coll_idx, ep_idx, scenario).iter()
+            .filter_map(|(endpoint, _)| Self::write_request(endpoint, &variables))
+            .collect();
+        let config = config.clone();
+        self.confirm_protected(self.settings.environment.clone(), writes, Box::new(move |app| retry(app, config)))
+    }
+    
+    /// Hosts of a run's endpoints that need approving first
+    fn load_test_hosts(&self, endpoints: &[(ApiEndpoint, u32)]) -> Vec<String> {
+        let variables = self.request_variables();
+        endpoints.iter().filter_map(|(endpoint, _)| self.unapproved_host(endpoint, &variables)).collect()
+    }
+    
+    /// "'List items'", or "'Shop' scenario" for a scenario run
+    fn load_test_name(&self, coll_idx: usize, ep_idx: usize, scenario: bool) -> String {
+        let collection = self.collections.get(coll_idx);
+        match scenario {
+            true => self.tr_with("status.scenario_name", &[("name", &collection.map(|c| c.name.as_str()).unwrap_or_default())]),
+            false => format!("'{}'", collection.and_then(|c| c.endpoints.get(ep_idx)).map(|e| e.name.as_str()).unwrap_or_default()),
+        }
+    }
+    
+    /// Add a run to the queue. Hosts are confirmed now so the queue can run unattended.
+    fn queue_load_test(&mut self, coll_idx: usize, ep_idx: usize, scenario: bool, config: LoadTestConfig) {
+        let endpoints = self.load_test_endpoints(coll_idx, ep_idx, scenario);
+        if endpoints.is_empty() {
+            return;
+        }
+        let hosts = self.load_test_hosts(&endpoints);
+        let retry = config.clone();
+        if !self.confirm_hosts(hosts, Box::new(move |app| app.queue_load_test(coll_idx, ep_idx, scenario, retry))) {
+            return;
+        }
+        if !self.confirm_load_test_writes(coll_idx, ep_idx, scenario, &config, move |app, retry| app.queue_load_test(coll_idx, ep_idx, scenario, retry)) {
+            return;
+        }
+        self.load_test_queue.push_back(QueuedLoadTest { collection_index: coll_idx, endpoint_index: ep_idx, scenario, config });
+        self.status_message = Some(self.tr_with("status.load_test_queued", &[
+            ("name", &self.load_test_name(coll_idx, ep_idx, scenario)),
+            ("waiting", &self.load_test_queue.len()),
+        ]));
+        self.error_message = None;
+    }
+    
+    fn start_next_queued_load_test(&mut self) {
+        self.queue_next_at = None;
+        if let Some(next) = self.load_test_queue.pop_front() {
+            self.execute_load_test_with_config(next.collection_index, next.endpoint_index, next.scenario, next.config);
+        }
+    }
+    
+    /// Save the report of a finished run and start the next queued one once its cool-down is over
+    fn advance_load_tests(&mut self) {
+        let Some(engine) = &mut self.load_test_engine else { return };
+        let (checkpoint, report) = (engine.take_checkpoint(), engine.take_report());
+        // Soak runs write their progress now and then so a crash or disconnect keeps most of it
+        if let Some(checkpoint) = checkpoint {
+            if let Err(e) = self.storage.save_load_test_report(&checkpoint) {
+                tracing::error!("Failed to checkpoint load test: {}", e);
+                self.error_message = Some(self.tr_with("error.checkpoint_load_test", &[("error", &e)]));
+            }
+        }
+        if let Some(report) = report {
+            match self.storage.save_load_test_report(&report) {
+                Ok(path) => {
+                    tracing::info!("Load test of '{}' finished: {} requests, {} failed; saved to {}",
+                        report.target.endpoint_name, report.total_requests, report.failed_requests, path.display());
+                    self.status_message = Some(self.tr_with("status.load_test_finished", &[("path", &path.display())]));
+                }
+                Err(e) => {
+                    tracing::error!("Failed to save load test results: {}", e);
+                    self.error_message = Some(self.tr_with("error.save_load_test_results", &[("error", &e)]));
                 }
             }
-            
-            // Clear form
-            self.load_test_config_form = None;
-            
-            // Start the actual load test
-            self.execute_load_test_with_config(coll_idx, ep_idx, config);
+        }
+        let Some(engine) = &self.load_test_engine else { return };
+        if !engine.is_reported() || self.load_test_queue.is_empty() {
+            return;
+        }
+        let cool_down = engine.config().cool_down;
+        let next_at = *self.queue_next_at.get_or_insert_with(|| std::time::Instant::now() + cool_down);
+        if std::time::Instant::now() >= next_at {
+            self.start_next_queued_load_test();
         }
     }
     
-    fn execute_load_test_with_config(&mut self, coll_idx: usize, ep_idx: usize, config: LoadTestConfig) {
-        if let Some(collection) = self.collections.get(coll_idx) {
-            if let Some(endpoint) = collection.endpoints.get(ep_idx) {
-                let endpoint = endpoint.clone();
-                let http_client = self.http_client.clone();
-                
-                match LoadTestEngine::new(config.clone()) {
-                    Ok(engine) => {
-                        let collector = engine.collector();
-                        let is_running = Arc::new(Mutex::new(true));
-                        let is_running_clone = is_running.clone();
-                        
-                        // Set engine state
-                        engine.set_start_time(std::time::Instant::now());
-                        engine.set_running(true);
-                        
-                        // Store engine before spawning thread
-                        self.load_test_engine = Some(engine);
-                        self.current_screen = Screen::LoadTestRunning(coll_idx, ep_idx);
-                        self.status_message = Some("Load test started...".to_string());
-                        self.error_message = None;
-                        
-                        // Spawn background thread for load test execution
-                        std::thread::spawn(move || {
-                            let runtime = tokio::runtime::Runtime::new().unwrap();
-                            runtime.block_on(async {
-                                let start = std::time::Instant::now();
-                                let mut handles = vec![];
-                                
-                                // Spawn concurrent tasks based on ramp-up
-                                for worker_id in 0..config.concurrency {
-                                    let endpoint = endpoint.clone();
-                                    let http_client = http_client.clone();
-                                    let collector = collector.clone();
-                                    let is_running = is_running_clone.clone();
-                                    let duration = config.duration;
-                                    let ramp_up = config.ramp_up;
-                                    
-                                    let handle = tokio::spawn(async move {
-                                        // Calculate delay for this worker based on ramp-up
-                                        if let Some(ramp_up_duration) = ramp_up {
-                                            let worker_delay = ramp_up_duration.as_secs_f64() 
-                                                * (worker_id as f64 / config.concurrency as f64);
-                                            tokio::time::sleep(tokio::time::Duration::from_secs_f64(worker_delay)).await;
-                                        }
-                                        
-                                        while start.elapsed() < duration && *is_running.lock().unwrap() {
-                                            let req_start = std::time::Instant::now();
-                                            let inputs = RequestInputs::default();
-                                            
-                                            match http_client.execute(&endpoint, &inputs).await {
-                                                Ok(response) => {
-                                                    collector.record_success(response.duration);
-                                                }
-                                                Err(e) => {
-                                                    collector.record_failure(
-                                                        e.to_string(),
-                                                        req_start.elapsed()
-                                                    );
-                                                }
-                                            }
-                                            
-                                            // Small delay to prevent overwhelming the server
-                                            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
-                                        }
-                                    });
-                                    
-                                    handles.push(handle);
-                                }
-                                
-                                // Periodically update RPS
-                                let collector_for_rps = collector.clone();
-                                let is_running_for_rps = is_running_clone.clone();
-                                tokio::spawn(async move {
-                                    while *is_running_for_rps.lock().unwrap() {
-                                        collector_for_rps.update_rps(Duration::from_secs(1));
-                                        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                                    }
-                                });
-                                
-                                // Periodically collect time-series data (every 5 seconds)
-                                let collector_for_timeseries = collector.clone();
-                                let is_running_for_timeseries = is_running_clone.clone();
-                                tokio::spawn(async move {
-                                    while *is_running_for_timeseries.lock().unwrap() {
-                                        collector_for_timeseries.add_time_series_point(start);
-                                        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                                    }
-                                });
-                                
-                                // Wait for all tasks to complete
-                                for handle in handles {
-                                    let _ = handle.await;
-                                }
-                                
-                                // Mark as stopped
-                                *is_running_clone.lock().unwrap() = false;
-                            });
-                        });
-                    }
-                    Err(e) => {
-                        self.error_message = Some(format!("Failed to start load test: {}", e));
-                    }
+    /// Mark runs whose last checkpoint says "running" as interrupted (the app
+    /// exited mid-run) and show the most recent one
+    pub fn recover_interrupted_load_tests(&mut self) {
+        let Ok(reports) = self.storage.load_test_reports() else { return };
+        let mut latest = None;
+        for mut report in reports.into_iter().filter(|r| r.status == RunStatus::Running) {
+            report.status = RunStatus::Interrupted;
+            if let Err(e) = self.storage.save_load_test_report(&report) {
+                self.error_message = Some(self.tr_with("error.update_interrupted", &[("error", &e)]));
+            }
+            latest = Some(report);
+        }
+        if let Some(report) = latest {
+            self.status_message = Some(self.tr_with("status.recovered_load_test", &[("name", &report.target.endpoint_name)]));
+            self.open_view(Box::new(LoadTestReportScreen::new(report)));
+        }
+    }
+    
+    /// Queue progress for the load test screen, e.g. "2 queued, next in 12s"
+    pub fn load_test_queue_status(&self) -> Option<String> {
+        if self.load_test_queue.is_empty() {
+            return None;
+        }
+        let queued = self.load_test_queue.len();
+        Some(match self.queue_next_at {
+            Some(at) => self.tr_with("status.queued_next_in", &[
+                ("queued", &queued),
+                ("seconds", &at.saturating_duration_since(std::time::Instant::now()).as_secs()),
+            ]),
+            None => self.tr_with("status.queued", &[("queued", &queued)]),
+        })
+    }
+    
+    fn execute_load_test_with_config(&mut self, coll_idx: usize, ep_idx: usize, scenario: bool, config: LoadTestConfig) {
+        let Some(collection_id) = self.collections.get(coll_idx).map(|c| c.id) else { return };
+        let endpoints = self.load_test_endpoints(coll_idx, ep_idx, scenario);
+        let Some((endpoint, _)) = endpoints.first().cloned() else {
+            if scenario {
+                self.error_message = Some(self.tr("error.scenario_empty").to_string());
+            }
+            return;
+        };
+        let hosts = self.load_test_hosts(&endpoints);
+        let retry = config.clone();
+        if !self.confirm_hosts(hosts, Box::new(move |app| app.execute_load_test_with_config(coll_idx, ep_idx, scenario, retry))) {
+            return;
+        }
+        let http_client = match &config.client_config {
+            Some(client_config) => match HttpClient::with_config(client_config.clone()) {
+                Ok(client) => client,
+                Err(e) => {
+                    self.error_message = Some(self.tr_with("error.load_test_client", &[("error", &e)]));
+                    return;
+                }
+            },
+            None => self.client_for(coll_idx).clone(),
+        };
+        
+        let target = if scenario {
+            // Scenario runs are told apart by their collection, so they compare with each other
+            let name = self.collections[coll_idx].name.clone();
+            LoadTestTarget {
+                collection_id,
+                endpoint_id: collection_id,
+                endpoint_name: format!("{} scenario", name),
+                method: "MIX".to_string(),
+                url: load_test::describe_mix(&endpoints),
+            }
+        } else {
+            LoadTestTarget {
+                collection_id,
+                endpoint_id: endpoint.id,
+                endpoint_name: endpoint.name.clone(),
+                method: endpoint.method.to_string(),
+                url: endpoint.url.clone(),
+            }
+        };
+        match LoadTestEngine::new(config.clone()) {
+            Ok(mut engine) => {
+                let har_path = (config.har_samples > 0).then(|| {
+                    let name: String = target.endpoint_name.chars()
+                        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+                        .collect();
+                    self.storage.results_dir()
+                        .join(format!("{}-{}.har", name, chrono::Local::now().format("%Y%m%d-%H%M%S")))
+                });
+                if let Some(path) = har_path {
+                    engine.set_har_path(path);
                 }
+                let name = target.endpoint_name.clone();
+                engine.set_target(target);
+                
+                // Set engine state
+                engine.set_start_time(std::time::Instant::now());
+                engine.set_running(true);
+                // Workers poll the engine's flag, so stopping the engine stops them
+                let workers = engine.scenario_workers(endpoints, http_client, HashMap::new());
+                
+                // Store engine before spawning thread
+                self.load_test_engine = Some(engine);
+                self.current_screen = Screen::LoadTestRunning(coll_idx, ep_idx);
+                tracing::info!("Load test of '{}' started: {} workers for {}s", name, config.concurrency, config.duration.as_secs());
+                self.status_message = Some(self.tr("status.load_test_started").to_string());
+                self.error_message = None;
+                
+                // Spawn background thread for load test execution
+                std::thread::spawn(move || {
+                    let runtime = tokio::runtime::Runtime::new().unwrap();
+                    runtime.block_on(workers);
+                });
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.start_load_test", &[("error", &e)]));
             }
         }
     }
@@ -539,8 +1877,16 @@ impl AppState {
     pub fn stop_load_test(&mut self) {
         if let Some(engine) = &self.load_test_engine {
             engine.stop();
-            self.status_message = Some("Load test stopped".to_string());
+            self.status_message = Some(self.tr("status.load_test_stopped").to_string());
         }
+        // Stopping gives up on the rest of the queue too
+        if !self.load_test_queue.is_empty() {
+            self.status_message = Some(self.tr_with("status.load_test_stopped_queue", &[("count", &self.load_test_queue.len())]));
+            self.load_test_queue.clear();
+            self.queue_next_at = None;
+        }
+        // Keep the partial run's results
+        self.advance_load_tests();
     }
     
     pub fn get_load_test_metrics(&self) -> Option<LoadTestMetrics> {
@@ -557,80 +1903,637 @@ impl AppState {
         self.current_screen = Screen::CollectionEdit(None);
     }
     
-    pub fn start_edit_collection(&mut self, index: usize) {
-        if let Some(collection) = self.collections.get(index) {
-            self.collection_form = Some(CollectionForm {
-                name: collection.name.clone(),
-                editing_index: Some(index),
-            });
-            self.current_screen = Screen::CollectionEdit(Some(index));
+    /// Refuse to modify a read-only collection, pointing at the copy command instead
+    pub(crate) fn ensure_editable(&mut self, index: usize) -> bool {
+        match self.collections.get(index) {
+            Some(collection) if collection.read_only => {
+                self.error_message = Some(self.tr_with("error.read_only", &[("name", &collection.name)]));
+                false
+            }
+            _ => true,
         }
     }
     
-    pub fn save_collection(&mut self) {
-        if let Some(form) = &self.collection_form {
-            if form.name.trim().is_empty() {
-                self.error_message = Some("Collection name cannot be empty".to_string());
-                return;
+    /// Save an editable copy of a collection next to the original and select it
+    pub fn make_editable_copy(&mut self, index: usize) {
+        let Some(collection) = self.collections.get(index) else { return };
+        let copy = collection.editable_copy();
+        match self.storage.save_collection(&copy) {
+            Ok(_) => {
+                self.record_activity(ActivityEntry::new(ActivityKind::Edit, &copy, "Created as an editable copy"));
+                self.status_message = Some(self.tr_with("status.editable_copy", &[("name", &copy.name)]));
+                self.error_message = None;
+                self.collections.push(copy);
+                self.selection.collection = self.collections.len() - 1;
+                self.selection.endpoint = 0;
+                self.clamp_selection();
             }
-            
-            match form.editing_index {
-                Some(index) => {
-                    // Edit existing collection
-                    if let Some(collection) = self.collections.get_mut(index) {
-                        collection.name = form.name.clone();
-                        match self.storage.save_collection(collection) {
-                            Ok(_) => {
-                                self.status_message = Some("Collection updated successfully".to_string());
-                                self.error_message = None;
-                                self.current_screen = Screen::CollectionList;
-                                self.collection_form = None;
-                            }
-                            Err(e) => {
-                                self.error_message = Some(format!("Failed to save collection: {}", e));
-                            }
-                        }
-                    }
-                }
-                None => {
-                    // Create new collection
-                    let collection = ApiCollection::new(form.name.clone());
-                    match self.storage.save_collection(&collection) {
-                        Ok(_) => {
-                            self.collections.push(collection);
-                            self.status_message = Some("Collection created successfully".to_string());
-                            self.error_message = None;
-                            self.current_screen = Screen::CollectionList;
-                            self.collection_form = None;
-                        }
-                        Err(e) => {
-                            self.error_message = Some(format!("Failed to create collection: {}", e));
-                        }
-                    }
-                }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.copy_collection", &[("error", &e)]));
             }
         }
     }
     
-    pub fn confirm_delete_collection(&mut self, index: usize) {
-        self.previous_screen = Some(self.current_screen.clone());
-        self.current_screen = Screen::ConfirmDelete(DeleteTarget::Collection(index));
+    /// Import every collection found in an Insomnia or native export file
+    pub fn import_file(&mut self, path: &Path) -> bool {
+        let imported = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| interop::import_any(&contents).map_err(|e| e.to_string()));
+        self.add_imported(&path.display().to_string(), imported)
     }
     
-    pub fn delete_collection(&mut self, index: usize) {
-        if let Some(collection) = self.collections.get(index) {
-            match self.storage.delete_collection(&collection.id) {
-                Ok(_) => {
-                    self.collections.remove(index);
-                    self.status_message = Some("Collection deleted successfully".to_string());
-                    self.error_message = None;
-                    if self.selected_index >= self.collections.len() && self.selected_index > 0 {
-                        self.selected_index -= 1;
-                    }
-                    self.current_screen = Screen::CollectionList;
-                }
+    /// Start downloading a collection or spec to import. `header` is an optional
+    /// `Name: value` line, e.g. `Authorization: Bearer {{ci_token}}`, filled in
+    /// from saved variables. The collections are added when the download ends.
+    pub fn import_url(&mut self, url: &str, header: &str) -> bool {
+        let source = CollectionSource { url: url.trim().to_string(), header: header.trim().to_string() };
+        self.fetch_collections(source, None)
+    }
+
+    /// Download and parse `source` on the background runtime; `finish_fetch`
+    /// picks up the result. False when the header can't be used.
+    fn fetch_collections(&mut self, source: CollectionSource, syncing: Option<uuid::Uuid>) -> bool {
+        let header = template::substitute_lenient(&source.header, &self.request_variables());
+        let header = match interop::parse_header(&header) {
+            Ok(header) => header,
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.download", &[("url", &source.url), ("error", &e)]));
+                return false;
+            }
+        };
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        let (url, finished) = (source.url.clone(), self.finished_tx.clone());
+        let task = self.runtime.spawn(async move {
+            let collections = match interop::fetch(&url, header).await {
+                Ok(contents) => interop::import_any(&contents),
+                Err(e) => Err(e),
+            };
+            let _ = finished.send(Finished::Fetch(id, collections));
+        });
+        self.error_message = None;
+        self.status_message = Some(self.tr_with("status.downloading", &[("url", &source.url)]));
+        self.fetches.push(Fetch { id, source, syncing, task: task.abort_handle() });
+        true
+    }
+
+    fn finish_fetch(&mut self, id: u64, result: interop::Result<Vec<ApiCollection>>) {
+        // Cancelled downloads may still deliver
+        let Some(index) = self.fetches.iter().position(|f| f.id == id) else { return };
+        let Fetch { source, syncing, .. } = self.fetches.remove(index);
+        match syncing {
+            Some(collection_id) => self.finish_sync(collection_id, source, result),
+            None => {
+                let imported = result.map(|collections| {
+                    // Remembered so the collection can be synced with the spec later
+                    collections.into_iter().map(|c| ApiCollection { source: Some(source.clone()), ..c }).collect()
+                });
+                self.add_imported(&source.url, imported.map_err(|e| e.to_string()));
+            }
+        }
+    }
+
+    /// Re-fetch the spec a collection was imported from, then review how it
+    /// changed before applying additions and removals
+    pub fn sync_collection(&mut self, index: usize) {
+        let Some(source) = self.collections.get(index).and_then(|c| c.source.clone()) else {
+            self.error_message = Some(self.tr("error.sync_needs_source").to_string());
+            return;
+        };
+        if !self.ensure_editable(index) {
+            return;
+        }
+        let collection_id = self.collections[index].id;
+        self.fetch_collections(source, Some(collection_id));
+    }
+
+    fn finish_sync(&mut self, collection_id: uuid::Uuid, source: CollectionSource, result: interop::Result<Vec<ApiCollection>>) {
+        // The collection may have been deleted while downloading
+        let Some(index) = self.collections.iter().position(|c| c.id == collection_id) else { return };
+        let spec = match result {
+            Ok(collections) if collections.len() == 1 => collections.into_iter().next().unwrap(),
+            Ok(collections) => {
+                self.error_message = Some(self.tr_with("error.sync_collection_count", &[("url", &source.url), ("count", &collections.len())]));
+                return;
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.sync", &[("url", &source.url), ("error", &e)]));
+                return;
+            }
+        };
+
+        let items = merge::diff_for_sync(&self.collections[index], &spec);
+        let count = |change| items.iter().filter(|i| i.change() == change).count();
+        let (added, removed, modified) = (count(Change::OnlyTheirs), count(Change::OnlyMine), count(Change::Modified));
+        self.error_message = None;
+        if added + removed + modified == 0 {
+            self.status_message = Some(self.tr_with("status.up_to_date", &[("name", &self.collections[index].name), ("url", &source.url)]));
+            return;
+        }
+        self.status_message = Some(self.tr_with("status.sync_changes", &[("added", &added), ("removed", &removed), ("modified", &modified)]));
+        self.open_view(Box::new(MergeScreen::reviewing(index, source.url, items)));
+    }
+    
+    fn add_imported(&mut self, source: &str, imported: std::result::Result<Vec<ApiCollection>, String>) -> bool {
+        let collections = match imported {
+            Ok(collections) => collections,
+            Err(e) => {
+                tracing::warn!("Failed to import {}: {}", source, e);
+                self.error_message = Some(self.tr_with("error.import", &[("source", &source), ("error", &e)]));
+                return false;
+            }
+        };
+        
+        let mut endpoints = 0;
+        for collection in collections.iter() {
+            if let Err(e) = self.storage.save_collection(collection) {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                return false;
+            }
+            endpoints += collection.endpoints.len();
+            let summary = format!("Imported {} endpoints from {}", collection.endpoints.len(), source);
+            let entry = ActivityEntry::new(ActivityKind::Import, collection, summary);
+            self.record_activity(entry);
+        }
+        self.status_message = Some(self.tr_with("status.imported", &[("collections", &collections.len()), ("endpoints", &endpoints)]));
+        self.error_message = None;
+        self.collections.extend(collections);
+        true
+    }
+    
+    /// Add an endpoint parsed from an HTTPie command line to a collection
+    pub fn import_httpie(&mut self, index: usize, command: &str) -> bool {
+        self.import_command(index, "HTTPie", interop::httpie::parse(command))
+    }
+    
+    /// Add an endpoint parsed from a curl command line to a collection
+    pub fn import_curl(&mut self, index: usize, command: &str) -> bool {
+        self.import_command(index, "curl", interop::curl::parse(command))
+    }
+    
+    /// The request an endpoint would send with the saved variables, written
+    /// out as a curl command
+    pub fn curl_command(&self, coll_idx: usize, ep_idx: usize) -> std::result::Result<String, String> {
+        let endpoint = self.collections.get(coll_idx).and_then(|c| c.endpoints.get(ep_idx)).ok_or(self.tr("error.no_endpoint_selected"))?;
+        let inputs = RequestInputs {
+            variables: self.saved_variables(endpoint)?,
+            ..Default::default()
+        };
+        let request = self.http_client.prepare(endpoint, &inputs).map_err(|e| e.to_string())?;
+        Ok(interop::curl::export(&request))
+    }
+    
+    /// Show an endpoint as a curl command, ready to copy or save
+    pub fn open_copy_as_curl(&mut self, coll_idx: usize, ep_idx: usize) {
+        match self.curl_command(coll_idx, ep_idx) {
+            Ok(command) => {
+                let name = self.collections[coll_idx].endpoints[ep_idx].name.clone();
+                self.open_view(Box::new(CopyCurlScreen::new(&name, command)));
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.build_request", &[("error", &e)]));
+                self.status_message = None;
+            }
+        }
+    }
+    
+    /// Save a curl command as a shell script
+    pub fn write_curl_command(&mut self, path: &Path, command: &str) -> bool {
+        match std::fs::write(path, format!("{}\n", command)) {
+            Ok(_) => {
+                self.status_message = Some(self.tr_with("status.wrote_curl", &[("path", &path.display())]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.write_file", &[("path", &path.display()), ("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    fn import_command(&mut self, index: usize, tool: &str, parsed: interop::Result<ApiEndpoint>) -> bool {
+        if !self.ensure_editable(index) {
+            return false;
+        }
+        let endpoint = match parsed {
+            Ok(endpoint) => endpoint,
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.parse_command", &[("tool", &tool), ("error", &e)]));
+                return false;
+            }
+        };
+        let Some(collection) = self.collections.get_mut(index) else { return false };
+        let name = endpoint.name.clone();
+        let before = collection.clone();
+        collection.add_endpoint(endpoint);
+        changelog::record(&before, collection);
+        match self.storage.save_collection(collection) {
+            Ok(_) => {
+                let collection_name = collection.name.clone();
+                let entry = ActivityEntry::new(ActivityKind::Import, collection, format!("Imported '{}' from a {} command", name, tool));
+                self.record_activity(entry);
+                self.status_message = Some(self.tr_with("status.added_endpoint", &[("name", &name), ("collection", &collection_name)]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Add the list/get/create/replace/update/delete endpoints for a resource,
+    /// skipping any the collection already has
+    pub fn add_crud_endpoints(&mut self, index: usize, resource: &Resource, headers: &HashMap<String, String>) -> bool {
+        if !self.ensure_editable(index) {
+            return false;
+        }
+        let Some(collection) = self.collections.get_mut(index) else { return false };
+        let before = collection.clone();
+        let name = collection.name.clone();
+        let (mut added, mut existing) = (0, 0);
+        for endpoint in resource.endpoints(headers) {
+            if collection.find_duplicate(&endpoint.method, &endpoint.url, None).is_some() {
+                existing += 1;
+            } else {
+                collection.add_endpoint(endpoint);
+                added += 1;
+            }
+        }
+        if added == 0 {
+            self.error_message = Some(self.tr_with("error.crud_complete", &[("name", &name), ("resource", &resource.plural)]));
+            return false;
+        }
+        changelog::record(&before, collection);
+        match self.storage.save_collection(collection) {
+            Ok(_) => {
+                let skipped = if existing > 0 { format!(" ({} already there)", existing) } else { String::new() };
+                let message = format!("Added {} endpoints for {}{}", added, resource.plural, skipped);
+                let entry = ActivityEntry::new(ActivityKind::Edit, collection, &message);
+                let args: [(&str, &dyn std::fmt::Display); 4] = [("added", &added), ("resource", &resource.plural), ("name", &name), ("existing", &existing)];
+                self.status_message = Some(match existing {
+                    0 => self.tr_with("status.crud_added", &args),
+                    _ => self.tr_with("status.crud_added_skipped", &args),
+                });
+                self.record_activity(entry);
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Write a collection to `path` in the given format
+    pub fn export_collection(&mut self, index: usize, path: &Path, format: ExportFormat) -> bool {
+        let Some(collection) = self.collections.get(index) else { return false };
+        let written = interop::export(collection, format)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| std::fs::write(path, contents).map_err(|e| e.to_string()));
+        match written {
+            Ok(_) => {
+                self.status_message = Some(self.tr_with("status.exported", &[("name", &collection.name), ("path", &path.display())]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.export", &[("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Import a dotenv or JSON file into the saved variables (None) or an environment
+    pub fn import_variables(&mut self, path: &Path, environment: Option<&str>, mode: ImportMode) -> bool {
+        let imported = std::fs::read_to_string(path)
+            .map_err(VariableError::from)
+            .and_then(|contents| VariableFormat::from_path(path).parse(&contents))
+            .and_then(|variables| {
+                let count = variables.len();
+                Ok((count, self.variable_manager.import_into(environment, variables, mode)?))
+            });
+        match imported {
+            Ok((count, changed)) => {
+                let target = match environment {
+                    Some(name) => self.tr_with("status.environment_name", &[("name", &name)]),
+                    None => self.tr("status.saved_variables").to_string(),
+                };
+                self.status_message = Some(self.tr_with("status.imported_variables", &[("count", &count), ("target", &target), ("changed", &changed)]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.import", &[("source", &path.display()), ("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Write the saved variables (None) or an environment to a dotenv or JSON file
+    pub fn export_variables(&mut self, path: &Path, environment: Option<&str>, include_secrets: bool) -> bool {
+        let written = self.variable_manager.variable_set(environment)
+            .and_then(|set| set.export(VariableFormat::from_path(path), include_secrets))
+            .and_then(|contents| Ok(std::fs::write(path, contents)?));
+        match written {
+            Ok(_) => {
+                let message = if include_secrets { "status.exported_variables" } else { "status.exported_variables_no_secrets" };
+                self.status_message = Some(self.tr_with(message, &[("path", &path.display())]));
+                self.error_message = None;
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.export", &[("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// The interactive client: the settings and global proxy, with the active
+    /// environment's defaults on top; then a client for each collection with a
+    /// proxy of its own
+    fn build_clients(
+        settings: &Settings,
+        variable_manager: &VariableManager,
+        proxies: &ProxyConfig,
+    ) -> std::result::Result<(HttpClient, HashMap<uuid::Uuid, HttpClient>), String> {
+        let mut config = settings.client_config();
+        proxies.global.apply(&mut config);
+        if let Some(name) = &settings.environment {
+            variable_manager.environment(name).map_err(|e| e.to_string())?.defaults.apply(&mut config);
+        }
+        let mut collection_clients = HashMap::new();
+        for (id, proxy) in &proxies.collections {
+            let mut config = config.clone();
+            proxy.apply(&mut config);
+            collection_clients.insert(*id, HttpClient::with_config(config).map_err(|e| e.to_string())?);
+        }
+        Ok((HttpClient::with_config(config).map_err(|e| e.to_string())?, collection_clients))
+    }
+    
+    /// The client requests from a collection are sent with
+    pub fn client_for(&self, collection_index: usize) -> &HttpClient {
+        self.collections.get(collection_index)
+            .and_then(|c| self.collection_clients.get(&c.id))
+            .unwrap_or(&self.http_client)
+    }
+    
+    /// Save new proxy settings and apply them right away
+    pub fn apply_proxies(&mut self, proxies: ProxyConfig) -> bool {
+        let applied = Self::build_clients(&self.settings, &self.variable_manager, &proxies)
+            .and_then(|clients| proxies.save(&self.storage.proxy_path()).map(|_| clients).map_err(|e| e.to_string()));
+        match applied {
+            Ok((http_client, collection_clients)) => {
+                self.http_client = http_client;
+                self.collection_clients = collection_clients;
+                self.proxies = proxies;
+                self.error_message = None;
+                self.status_message = Some(self.tr("status.proxy_saved").to_string());
+                true
+            }
+            Err(e) => {
+                self.error_message = Some(e);
+                false
+            }
+        }
+    }
+    
+    /// Values requests are sent with: saved variables, overridden by the active environment's
+    pub fn request_variables(&self) -> HashMap<String, String> {
+        let environment = self.settings.environment.as_deref();
+        self.variable_manager.variables_for(environment).unwrap_or_else(|e| {
+            tracing::warn!("Using saved variables only: {}", e);
+            self.variable_manager.get_all().clone()
+        })
+    }
+    
+    /// Save new workspace settings and apply them right away
+    pub fn apply_settings(&mut self, settings: Settings) -> bool {
+        let applied = Self::build_clients(&settings, &self.variable_manager, &self.proxies)
+            .and_then(|clients| settings.save(&self.storage.config_path()).map(|_| clients).map_err(|e| e.to_string()));
+        let (http_client, collection_clients) = match applied {
+            Ok(clients) => clients,
+            Err(e) => {
+                self.error_message = Some(e);
+                return false;
+            }
+        };
+        let pruned = self.history.prune(&settings.history).map_err(|e| e.to_string())
+            .and_then(|entries| Ok((entries, self.storage.prune_results(&settings.results).map_err(|e| e.to_string())?)));
+        let (entries, runs) = match pruned {
+            Ok(pruned) => pruned,
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.prune_history", &[("error", &e)]));
+                return false;
+            }
+        };
+        
+        self.http_client = http_client;
+        self.collection_clients = collection_clients;
+        self.load_test_config = settings.default_load_test();
+        self.settings = settings;
+        self.error_message = None;
+        self.status_message = Some(match (entries, runs) {
+            (0, 0) => self.tr("status.settings_saved").to_string(),
+            (entries, runs) => self.tr_with("status.settings_saved_pruned", &[("entries", &entries), ("runs", &runs)]),
+        });
+        true
+    }
+    
+    /// UI text in the language picked in settings
+    pub fn tr(&self, key: &'static str) -> &'static str {
+        i18n::tr(self.settings.locale, key)
+    }
+    
+    /// `tr` with its `{name}` placeholders filled in
+    pub fn tr_with(&self, key: &'static str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+        i18n::tr_with(self.settings.locale, key, args)
+    }
+    
+    /// Make the next environment active: none, then each one by name, then none again
+    pub fn cycle_environment(&mut self) {
+        let names = self.variable_manager.environment_names();
+        if names.is_empty() {
+            self.error_message = Some(self.tr_with("error.no_environments", &[("dir", &self.variable_manager.environments_dir().display())]));
+            return;
+        }
+        let next = match &self.settings.environment {
+            None => names.first().cloned(),
+            Some(current) => names.iter().position(|name| name == current).and_then(|i| names.get(i + 1)).cloned(),
+        };
+        self.set_environment(next);
+    }
+    
+    /// Switch the active environment, remembering it in config.toml
+    pub fn set_environment(&mut self, environment: Option<String>) -> bool {
+        let settings = Settings { environment, ..self.settings.clone() };
+        let applied = Self::build_clients(&settings, &self.variable_manager, &self.proxies)
+            .and_then(|clients| settings.save(&self.storage.config_path()).map(|_| clients).map_err(|e| e.to_string()));
+        match applied {
+            Ok((http_client, collection_clients)) => {
+                tracing::info!("Active environment: {}", settings.environment.as_deref().unwrap_or("none"));
+                self.status_message = Some(match &settings.environment {
+                    Some(name) => self.tr_with("status.environment", &[("name", name)]),
+                    None => self.tr("status.no_environment").to_string(),
+                });
+                self.error_message = None;
+                self.http_client = http_client;
+                self.collection_clients = collection_clients;
+                self.settings = settings;
+                true
+            }
+            Err(e) => {
+                let name = settings.environment.unwrap_or_default();
+                self.error_message = Some(self.tr_with("error.switch_environment", &[("name", &name), ("error", &e)]));
+                false
+            }
+        }
+    }
+    
+    /// Salvage what still parses from a skipped collection file; the original
+    /// goes to quarantine and the recovered collection is saved in its place
+    pub fn recover_skipped(&mut self, index: usize) {
+        let Some(skipped) = self.skipped_collections.get(index) else { return };
+        let path = skipped.path.clone();
+        let (mut collection, dropped) = match self.storage.recover_collection(&path) {
+            Ok(recovered) => recovered,
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.nothing_to_recover", &[("path", &path.display()), ("error", &e)]));
+                return;
+            }
+        };
+        if self.collections.iter().any(|c| c.id == collection.id) {
+            collection.id = uuid::Uuid::new_v4();
+        }
+        // Saved read-only collections would be refused; the recovered copy is for fixing up
+        collection.read_only = false;
+        let saved = self.storage.quarantine(&path)
+            .and_then(|quarantined| self.storage.save_collection(&collection).map(|_| quarantined));
+        match saved {
+            Ok(quarantined) => {
+                tracing::info!("Recovered collection '{}' from {}", collection.name, path.display());
+                self.status_message = Some(self.tr_with("status.recovered_collection", &[
+                    ("name", &collection.name),
+                    ("endpoints", &collection.endpoints.len()),
+                    ("dropped", &dropped),
+                    ("path", &quarantined.display()),
+                ]));
+                self.error_message = None;
+                self.skipped_collections.remove(index);
+                self.collections.push(collection);
+            }
+            Err(e) => self.error_message = Some(self.tr_with("error.recover", &[("path", &path.display()), ("error", &e)])),
+        }
+    }
+    
+    /// Move a skipped collection file out of the collections directory
+    pub fn quarantine_skipped(&mut self, index: usize) {
+        let Some(skipped) = self.skipped_collections.get(index) else { return };
+        match self.storage.quarantine(&skipped.path) {
+            Ok(quarantined) => {
+                self.status_message = Some(self.tr_with("status.quarantined", &[("path", &skipped.path.display()), ("to", &quarantined.display())]));
+                self.error_message = None;
+                self.skipped_collections.remove(index);
+            }
+            Err(e) => self.error_message = Some(self.tr_with("error.quarantine", &[("path", &skipped.path.display()), ("error", &e)])),
+        }
+    }
+    
+    /// Compare a collection with another version of it and merge endpoint by endpoint
+    pub fn start_merge(&mut self, index: usize) {
+        if index < self.collections.len() && self.ensure_editable(index) {
+            self.open_view(Box::new(MergeScreen::new(index)));
+        }
+    }
+    
+    pub fn start_edit_collection(&mut self, index: usize) {
+        if !self.ensure_editable(index) {
+            return;
+        }
+        if let Some(collection) = self.collections.get(index) {
+            self.collection_form = Some(CollectionForm {
+                name: collection.name.clone(),
+                editing_index: Some(index),
+            });
+            self.current_screen = Screen::CollectionEdit(Some(index));
+        }
+    }
+    
+    pub fn save_collection(&mut self) {
+        if let Some(form) = &self.collection_form {
+            if form.name.trim().is_empty() {
+                self.error_message = Some(self.tr("error.collection_name_empty").to_string());
+                return;
+            }
+            
+            match form.editing_index {
+                Some(index) => {
+                    // Edit existing collection
+                    if let Some(collection) = self.collections.get_mut(index) {
+                        let renamed = collection.name != form.name;
+                        collection.name = form.name.clone();
+                        match self.storage.save_collection(collection) {
+                            Ok(_) => {
+                                if renamed {
+                                    let entry = ActivityEntry::new(ActivityKind::Edit, collection, "Renamed collection");
+                                    self.record_activity(entry);
+                                }
+                                self.status_message = Some(self.tr("status.collection_updated").to_string());
+                                self.error_message = None;
+                                self.current_screen = Screen::CollectionList;
+                                self.collection_form = None;
+                            }
+                            Err(e) => {
+                                self.error_message = Some(self.tr_with("error.save_collection", &[("error", &e)]));
+                            }
+                        }
+                    }
+                }
+                None => {
+                    // Create new collection
+                    let collection = ApiCollection::new(form.name.clone());
+                    match self.storage.save_collection(&collection) {
+                        Ok(_) => {
+                            self.record_activity(ActivityEntry::new(ActivityKind::Edit, &collection, "Created collection"));
+                            self.collections.push(collection);
+                            self.status_message = Some(self.tr("status.collection_created").to_string());
+                            self.error_message = None;
+                            self.current_screen = Screen::CollectionList;
+                            self.collection_form = None;
+                        }
+                        Err(e) => {
+                            self.error_message = Some(self.tr_with("error.create_collection", &[("error", &e)]));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    pub fn confirm_delete_collection(&mut self, index: usize) {
+        if !self.ensure_editable(index) {
+            return;
+        }
+        self.previous_screen = Some(self.current_screen.clone());
+        self.current_screen = Screen::ConfirmDelete(DeleteTarget::Collection(index));
+    }
+    
+    pub fn delete_collection(&mut self, index: usize) {
+        if let Some(collection) = self.collections.get(index) {
+            match self.storage.delete_collection(&collection.id) {
+                Ok(_) => {
+                    let collection = self.collections.remove(index);
+                    self.record_activity(ActivityEntry::new(ActivityKind::Delete, &collection, "Deleted collection"));
+                    self.status_message = Some(self.tr("status.collection_deleted").to_string());
+                    self.error_message = None;
+                    self.current_screen = Screen::CollectionList;
+                    self.clamp_selection();
+                }
                 Err(e) => {
-                    self.error_message = Some(format!("Failed to delete collection: {}", e));
+                    self.error_message = Some(self.tr_with("error.delete_collection", &[("error", &e)]));
                     self.navigate_back();
                 }
             }
@@ -640,6 +2543,9 @@ impl AppState {
     // Endpoint Management
     
     pub fn start_new_endpoint(&mut self, collection_index: usize) {
+        if !self.ensure_editable(collection_index) {
+            return;
+        }
         self.endpoint_form = Some(EndpointForm {
             name: String::new(),
             method: HttpMethod::GET,
@@ -648,6 +2554,13 @@ impl AppState {
             headers: HashMap::new(),
             body_template: String::new(),
             timeout_secs: String::new(), // Empty = use default
+            tags: String::new(),
+            protobuf: String::new(),
+            empty_body: EmptyBody::Omit,
+            visualizer: String::new(),
+            assertions: String::new(),
+            connection: String::new(),
+            extractions: String::new(),
             collection_index,
             editing_index: None,
             current_field: 0,
@@ -660,6 +2573,9 @@ impl AppState {
     }
     
     pub fn start_edit_endpoint(&mut self, collection_index: usize, endpoint_index: usize) {
+        if !self.ensure_editable(collection_index) {
+            return;
+        }
         if let Some(collection) = self.collections.get(collection_index) {
             if let Some(endpoint) = collection.endpoints.get(endpoint_index) {
                 self.endpoint_form = Some(EndpointForm {
@@ -670,6 +2586,13 @@ impl AppState {
                     headers: endpoint.headers.clone(),
                     body_template: endpoint.body_template.clone().unwrap_or_default(),
                     timeout_secs: endpoint.timeout_secs.map(|t| t.to_string()).unwrap_or_default(),
+                    tags: endpoint.tags.join(", "),
+                    protobuf: endpoint.protobuf.as_ref().map(|p| p.to_string()).unwrap_or_default(),
+                    empty_body: endpoint.empty_body,
+                    visualizer: endpoint.visualizer.clone().unwrap_or_default(),
+                    assertions: Assertion::format_list(&endpoint.assertions),
+                    connection: endpoint.connection.to_string(),
+                    extractions: Extraction::format_list(&endpoint.extractions),
                     collection_index,
                     editing_index: Some(endpoint_index),
                     current_field: 0,
@@ -683,34 +2606,124 @@ impl AppState {
         }
     }
     
+    /// Pretty-print the endpoint form's JSON body, keeping `{{placeholders}}` as typed
+    pub fn format_endpoint_body(&mut self) {
+        let Some(form) = &mut self.endpoint_form else { return };
+        if !formatter::looks_like_json(&form.body_template) {
+            self.error_message = Some(self.tr("error.body_not_json").to_string());
+            return;
+        }
+        match formatter::format_json_template(&form.body_template) {
+            Ok(body) => {
+                form.body_template = body;
+                self.status_message = Some(self.tr("status.body_formatted").to_string());
+                self.error_message = None;
+            }
+            Err(issue) => self.error_message = Some(self.tr_with("error.invalid_json_body", &[("issue", &issue)])),
+        }
+    }
+    
+    /// Save the endpoint form, warning first if the request duplicates another endpoint
     pub fn save_endpoint(&mut self) {
+        let duplicate = self.endpoint_form.as_ref().and_then(|form| {
+            let collection = self.collections.get(form.collection_index)?;
+            let method = HttpMethod::from(form.method.to_string());
+            collection.find_duplicate(&method, &form.url, form.editing_index).map(|i| (form.collection_index, i))
+        });
+        match duplicate {
+            Some((coll_idx, existing)) => self.open_view(Box::new(DuplicateEndpointScreen::new(coll_idx, existing))),
+            None => self.save_endpoint_anyway(),
+        }
+    }
+    
+    /// Discard the endpoint form and select another endpoint
+    pub fn jump_to_endpoint(&mut self, coll_idx: usize, ep_idx: usize) {
+        self.endpoint_form = None;
+        self.selection.collection = coll_idx;
+        self.selection.endpoint = ep_idx;
+        self.panel_focus = PanelFocus::Endpoints;
+        self.current_screen = Screen::CollectionList;
+    }
+    
+    /// Save the endpoint form even if it duplicates another endpoint
+    pub fn save_endpoint_anyway(&mut self) {
         if let Some(form) = &self.endpoint_form {
             if form.name.trim().is_empty() {
-                self.error_message = Some("Endpoint name cannot be empty".to_string());
+                self.error_message = Some(self.tr("error.endpoint_name_empty").to_string());
                 return;
             }
             
             if form.url.trim().is_empty() {
-                self.error_message = Some("Endpoint URL cannot be empty".to_string());
+                self.error_message = Some(self.tr("error.endpoint_url_empty").to_string());
                 return;
             }
             
-            if let Some(collection) = self.collections.get_mut(form.collection_index) {
-                // Parse timeout from form
-                let timeout_secs = if form.timeout_secs.trim().is_empty() {
-                    None
-                } else {
-                    form.timeout_secs.trim().parse::<u64>().ok()
-                };
+            if !form.method.is_valid() {
+                self.error_message = Some(self.tr_with("error.invalid_method", &[("method", &form.method)]));
+                return;
+            }
+            
+            // Catch a wrong .proto path or message name now rather than on send
+            if let Some(config) = ProtobufConfig::parse(&form.protobuf) {
+                let checked = ProtoSchema::load(&expand_home(&config.proto_file)).and_then(|schema| {
+                    for message in config.request_message.iter().chain(&config.response_message) {
+                        schema.message(message)?;
+                    }
+                    Ok(())
+                });
+                if let Err(e) = checked {
+                    self.error_message = Some(self.tr_with("error.invalid_protobuf", &[("error", &e)]));
+                    return;
+                }
+            }
+            
+            if !form.visualizer.trim().is_empty() {
+                if let Err(e) = Transform::parse(&form.visualizer) {
+                    self.error_message = Some(self.tr_with("error.invalid_visualizer", &[("error", &e)]));
+                    return;
+                }
+            }
+            
+            let assertions = match Assertion::parse_list(&form.assertions) {
+                Ok(assertions) => assertions,
+                Err(e) => {
+                    self.error_message = Some(self.tr_with("error.invalid_assertion", &[("error", &e)]));
+                    return;
+                }
+            };
+            
+            let connection = match ConnectionOptions::parse(&form.connection) {
+                Ok(connection) => connection,
+                Err(e) => {
+                    self.error_message = Some(self.tr_with("error.invalid_connection_option", &[("error", &e)]));
+                    return;
+                }
+            };
+            
+            let extractions = match Extraction::parse_list(&form.extractions) {
+                Ok(extractions) => extractions,
+                Err(e) => {
+                    self.error_message = Some(self.tr_with("error.invalid_extraction", &[("error", &e)]));
+                    return;
+                }
+            };
+            
+            if let Some(collection) = self.collections.get_mut(form.collection_index) {
+                // Parse timeout from form
+                let timeout_secs = if form.timeout_secs.trim().is_empty() {
+                    None
+                } else {
+                    form.timeout_secs.trim().parse::<u64>().ok()
+                };
                 
                 let endpoint = ApiEndpoint {
                     id: if let Some(idx) = form.editing_index {
-                        collection.endpoints.get(idx).map(|e| e.id).unwrap_or_else(|| uuid::Uuid::new_v4())
+                        collection.endpoints.get(idx).map(|e| e.id).unwrap_or_else(uuid::Uuid::new_v4)
                     } else {
                         uuid::Uuid::new_v4()
                     },
                     name: form.name.clone(),
-                    method: form.method.clone(),
+                    method: HttpMethod::from(form.method.to_string()), // A typed "POST" is just POST
                     url: form.url.clone(),
                     description: if form.description.is_empty() { None } else { Some(form.description.clone()) },
                     headers: form.headers.clone(),
@@ -722,8 +2735,32 @@ impl AppState {
                         None
                     },
                     timeout_secs,
+                    tags: form.tags.split(',')
+                        .map(|t| t.trim().to_string())
+                        .filter(|t| !t.is_empty())
+                        .collect(),
+                    archived: form.editing_index
+                        .and_then(|idx| collection.endpoints.get(idx))
+                        .is_some_and(|e| e.archived),
+                    soap: form.editing_index
+                        .and_then(|idx| collection.endpoints.get(idx))
+                        .and_then(|e| e.soap.clone()),
+                    protobuf: ProtobufConfig::parse(&form.protobuf),
+                    empty_body: form.empty_body,
+                    visualizer: Some(form.visualizer.trim().to_string()).filter(|v| !v.is_empty()),
+                    assertions,
+                    connection,
+                    extractions,
+                    graphql: form.editing_index
+                        .and_then(|idx| collection.endpoints.get(idx))
+                        .and_then(|e| e.graphql.clone()),
                 };
                 
+                let summary = match form.editing_index {
+                    Some(_) => format!("Saved endpoint '{}'", endpoint.name),
+                    None => format!("Added endpoint '{}'", endpoint.name),
+                };
+                let before = collection.clone();
                 match form.editing_index {
                     Some(index) => {
                         // Edit existing endpoint
@@ -736,16 +2773,19 @@ impl AppState {
                         collection.add_endpoint(endpoint);
                     }
                 }
+                changelog::record(&before, collection);
                 
                 match self.storage.save_collection(collection) {
                     Ok(_) => {
-                        self.status_message = Some("Endpoint saved successfully".to_string());
+                        let entry = ActivityEntry::new(ActivityKind::Edit, collection, summary);
+                        self.status_message = Some(self.tr("status.endpoint_saved").to_string());
                         self.error_message = None;
                         self.current_screen = Screen::EndpointList(form.collection_index);
                         self.endpoint_form = None;
+                        self.record_activity(entry);
                     }
                     Err(e) => {
-                        self.error_message = Some(format!("Failed to save endpoint: {}", e));
+                        self.error_message = Some(self.tr_with("error.save_endpoint", &[("error", &e)]));
                     }
                 }
             }
@@ -753,6 +2793,9 @@ impl AppState {
     }
     
     pub fn confirm_delete_endpoint(&mut self, collection_index: usize, endpoint_index: usize) {
+        if !self.ensure_editable(collection_index) {
+            return;
+        }
         self.previous_screen = Some(self.current_screen.clone());
         self.current_screen = Screen::ConfirmDelete(DeleteTarget::Endpoint(collection_index, endpoint_index));
     }
@@ -761,19 +2804,22 @@ impl AppState {
         if let Some(collection) = self.collections.get_mut(collection_index) {
             if let Some(endpoint) = collection.endpoints.get(endpoint_index) {
                 let endpoint_id = endpoint.id;
+                let summary = format!("Deleted endpoint '{}'", endpoint.name);
+                let before = collection.clone();
                 collection.remove_endpoint(&endpoint_id);
+                changelog::record(&before, collection);
                 
                 match self.storage.save_collection(collection) {
                     Ok(_) => {
-                        self.status_message = Some("Endpoint deleted successfully".to_string());
+                        let entry = ActivityEntry::new(ActivityKind::Delete, collection, summary);
+                        self.record_activity(entry);
+                        self.status_message = Some(self.tr("status.endpoint_deleted").to_string());
                         self.error_message = None;
-                        if self.selected_index >= collection.endpoints.len() && self.selected_index > 0 {
-                            self.selected_index -= 1;
-                        }
                         self.current_screen = Screen::EndpointList(collection_index);
+                        self.clamp_selection();
                     }
                     Err(e) => {
-                        self.error_message = Some(format!("Failed to delete endpoint: {}", e));
+                        self.error_message = Some(self.tr_with("error.delete_endpoint", &[("error", &e)]));
                         self.navigate_back();
                     }
                 }
@@ -783,15 +2829,7 @@ impl AppState {
     
     pub fn cycle_http_method(&mut self) {
         if let Some(form) = &mut self.endpoint_form {
-            form.method = match form.method {
-                HttpMethod::GET => HttpMethod::POST,
-                HttpMethod::POST => HttpMethod::PUT,
-                HttpMethod::PUT => HttpMethod::PATCH,
-                HttpMethod::PATCH => HttpMethod::DELETE,
-                HttpMethod::DELETE => HttpMethod::HEAD,
-                HttpMethod::HEAD => HttpMethod::OPTIONS,
-                HttpMethod::OPTIONS => HttpMethod::GET,
-            };
+            form.cycle_method();
         }
     }
     
@@ -801,21 +2839,19 @@ impl AppState {
                 match target {
                     DeleteTarget::Collection(idx) => {
                         self.collections.get(*idx).map(|c| {
-                            format!("Delete collection '{}'?\n\nThis will permanently delete the collection and all {} endpoint(s).", 
-                                c.name, c.endpoints.len())
+                            self.tr_with("confirm.delete_collection", &[("name", &c.name), ("endpoints", &c.endpoints.len())])
                         })
                     }
                     DeleteTarget::Endpoint(coll_idx, ep_idx) => {
                         self.collections.get(*coll_idx).and_then(|c| {
                             c.endpoints.get(*ep_idx).map(|e| {
-                                format!("Delete endpoint '{}'?\n\n{:?} {}", 
-                                    e.name, e.method, e.url)
+                                self.tr_with("confirm.delete_endpoint", &[("name", &e.name), ("method", &format!("{:?}", e.method)), ("url", &e.url)])
                             })
                         })
                     }
                     DeleteTarget::Variable(key) => {
                         self.variable_manager.get(key).map(|value| {
-                            format!("Delete variable '{}'?\n\nValue: {}", key, value)
+                            self.tr_with("confirm.delete_variable", &[("name", key), ("value", &value)])
                         })
                     }
                 }
@@ -844,15 +2880,7 @@ impl AppState {
     
     pub fn toggle_header_edit_mode(&mut self) {
         if let Some(form) = &mut self.endpoint_form {
-            if form.current_field == 4 { // Headers field
-                form.header_edit_mode = !form.header_edit_mode;
-                if form.header_edit_mode {
-                    // Entering header edit mode
-                    form.header_key = String::new();
-                    form.header_value = String::new();
-                    form.header_edit_field = 0;
-                }
-            }
+            form.toggle_header_edit_mode();
         }
     }
     
@@ -863,7 +2891,7 @@ impl AppState {
                 form.header_key = String::new();
                 form.header_value = String::new();
                 form.header_edit_field = 0;
-                self.status_message = Some("Header added".to_string());
+                self.status_message = Some(self.tr("status.header_added").to_string());
             }
         }
     }
@@ -871,7 +2899,7 @@ impl AppState {
     pub fn remove_header(&mut self, key: &str) {
         if let Some(form) = &mut self.endpoint_form {
             form.headers.remove(key);
-            self.status_message = Some(format!("Header '{}' removed", key));
+            self.status_message = Some(self.tr_with("status.header_removed", &[("name", &key)]));
         }
     }
     
@@ -886,31 +2914,46 @@ impl AppState {
     // Clipboard operations
     
     pub fn copy_response_to_clipboard(&mut self) {
-        if let Some(formatted) = &self.last_response_formatted {
-            match arboard::Clipboard::new() {
-                Ok(mut clipboard) => {
-                    match clipboard.set_text(formatted.clone()) {
-                        Ok(_) => {
-                            self.status_message = Some("Response copied to clipboard".to_string());
-                            self.error_message = None;
-                        }
-                        Err(e) => {
-                            self.error_message = Some(format!("Failed to copy to clipboard: {}", e));
-                            self.status_message = None;
-                        }
-                    }
-                }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to access clipboard: {}", e));
-                    self.status_message = None;
-                }
+        // Copy all of a huge body, not just what the viewer laid out
+        let full = match &self.last_response {
+            Some(response) if !self.response_json_cuts.is_empty() && !self.showing_visualization => {
+                formatter::format_body(&response.body, response.content_type(), self.response_view_as).ok()
             }
+            _ => None,
+        };
+        if let Some(formatted) = full.or_else(|| self.last_response_formatted.clone()) {
+            self.copy_to_clipboard(formatted, self.tr("status.response"));
         } else {
-            self.error_message = Some("No response to copy".to_string());
+            self.error_message = Some(self.tr("error.no_response_to_copy").to_string());
             self.status_message = None;
         }
     }
     
+    /// Put `text` on the system clipboard, reporting it as `what` (already translated)
+    pub fn copy_to_clipboard(&mut self, text: String, what: &str) -> bool {
+        match arboard::Clipboard::new() {
+            Ok(mut clipboard) => {
+                match clipboard.set_text(text) {
+                    Ok(_) => {
+                        self.status_message = Some(self.tr_with("status.copied", &[("what", &what)]));
+                        self.error_message = None;
+                        true
+                    }
+                    Err(e) => {
+                        self.error_message = Some(self.tr_with("error.copy_to_clipboard", &[("error", &e)]));
+                        self.status_message = None;
+                        false
+                    }
+                }
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.access_clipboard", &[("error", &e)]));
+                self.status_message = None;
+                false
+            }
+        }
+    }
+    
     // Variable Management
     
     pub fn start_new_variable(&mut self) {
@@ -938,7 +2981,7 @@ impl AppState {
     pub fn save_variable(&mut self) {
         if let Some(form) = &self.variable_form {
             if form.key.trim().is_empty() {
-                self.error_message = Some("Variable key cannot be empty".to_string());
+                self.error_message = Some(self.tr("error.variable_key_empty").to_string());
                 return;
             }
             
@@ -952,13 +2995,13 @@ impl AppState {
             // Set new/updated variable
             match self.variable_manager.set(form.key.clone(), form.value.clone()) {
                 Ok(_) => {
-                    self.status_message = Some("Variable saved successfully".to_string());
+                    self.status_message = Some(self.tr("status.variable_saved").to_string());
                     self.error_message = None;
                     self.current_screen = Screen::VariableList;
                     self.variable_form = None;
                 }
                 Err(e) => {
-                    self.error_message = Some(format!("Failed to save variable: {}", e));
+                    self.error_message = Some(self.tr_with("error.save_variable", &[("error", &e)]));
                 }
             }
         }
@@ -972,12 +3015,13 @@ impl AppState {
     pub fn delete_variable(&mut self, key: &str) {
         match self.variable_manager.remove(key) {
             Ok(_) => {
-                self.status_message = Some("Variable deleted successfully".to_string());
+                self.status_message = Some(self.tr("status.variable_deleted").to_string());
                 self.error_message = None;
                 self.current_screen = Screen::VariableList;
+                self.clamp_selection();
             }
             Err(e) => {
-                self.error_message = Some(format!("Failed to delete variable: {}", e));
+                self.error_message = Some(self.tr_with("error.delete_variable", &[("error", &e)]));
                 self.navigate_back();
             }
         }
@@ -1037,13 +3081,17 @@ impl AppState {
                     }
                 }
                 
-                let mut required_vars: Vec<String> = required_vars.into_iter().collect();
+                // `$now` variables are generated, there's nothing to ask for
+                let mut required_vars: Vec<String> = required_vars.into_iter()
+                    .filter(|var| !template::is_builtin_variable(var))
+                    .collect();
                 required_vars.sort();
                 
                 // Pre-fill with existing variable values
+                let available = self.request_variables();
                 let mut variables = HashMap::new();
                 for var in &required_vars {
-                    if let Some(value) = self.variable_manager.get(var) {
+                    if let Some(value) = available.get(var) {
                         variables.insert(var.clone(), value.clone());
                     } else {
                         variables.insert(var.clone(), String::new());
@@ -1052,8 +3100,7 @@ impl AppState {
                 
                 if required_vars.is_empty() {
                     // No variables needed, execute directly
-                    let runtime = tokio::runtime::Runtime::new().unwrap();
-                    runtime.block_on(self.execute_request(coll_idx, ep_idx));
+                    self.send_request(coll_idx, ep_idx, HashMap::new());
                 } else {
                     // Show variable input form
                     self.variable_input_form = Some(VariableInputForm {
@@ -1080,119 +3127,1399 @@ impl AppState {
             self.current_screen = Screen::EndpointDetail(coll_idx, ep_idx);
             
             // Execute request with variables
-            let runtime = tokio::runtime::Runtime::new().unwrap();
-            runtime.block_on(self.execute_request_with_vars(coll_idx, ep_idx, variables));
+            self.send_request(coll_idx, ep_idx, variables);
         }
     }
     
-    async fn execute_request_with_vars(&mut self, coll_idx: usize, ep_idx: usize, variables: HashMap<String, String>) {
-        if let Some(collection) = self.collections.get(coll_idx) {
-            if let Some(endpoint) = collection.endpoints.get(ep_idx) {
-                self.status_message = Some("Executing request...".to_string());
-                
-                let mut inputs = RequestInputs::default();
-                inputs.variables = variables;
-                
-                match self.http_client.execute(endpoint, &inputs).await {
-                    Ok(response) => {
-                        // Format response
-                        let formatted = formatter::format_auto(&response.body)
-                            .unwrap_or_else(|_| String::from_utf8_lossy(&response.body).to_string());
-                        
-                        self.last_response = Some(response);
-                        self.last_response_formatted = Some(formatted);
-                        self.response_scroll_offset = 0;
-                        self.headers_scroll_offset = 0;
-                        self.status_message = Some("Request completed successfully".to_string());
-                        self.error_message = None;
-                    }
-                    Err(e) => {
-                        self.error_message = Some(format!("Request failed: {}", e));
-                        self.status_message = None;
-                    }
+    /// Point at the tag when a request couldn't be built from its templates
+    fn open_template_error(&mut self, error: &HttpError) {
+        if let HttpError::Template(TemplateError::Located(located)) = error {
+            self.open_view(Box::new(TemplateErrorScreen::new(located.error.to_string(), located.location.clone())));
+        }
+    }
+    
+    /// Send a request in the background, asking first if the host isn't allowlisted
+    /// or it would change data in a protected environment
+    fn send_request(&mut self, coll_idx: usize, ep_idx: usize, variables: HashMap<String, String>) {
+        self.send_with_overrides(coll_idx, ep_idx, variables, RequestOverrides::default());
+    }
+    
+    /// `send_request` with one-off changes to the query string and headers
+    pub fn send_with_overrides(&mut self, coll_idx: usize, ep_idx: usize, mut variables: HashMap<String, String>, overrides: RequestOverrides) {
+        let Some(mut endpoint) = self.collections.get(coll_idx).and_then(|c| c.endpoints.get(ep_idx)).cloned() else { return };
+        overrides.apply(&mut endpoint);
+        self.add_default_header_variables(&mut variables);
+        if let Some(host) = self.unapproved_host(&endpoint, &variables) {
+            let (retry, retry_overrides) = (variables.clone(), overrides.clone());
+            self.confirm_hosts(vec![host], Box::new(move |app| app.send_with_overrides(coll_idx, ep_idx, retry, retry_overrides)));
+            return;
+        }
+        let writes = Self::write_request(&endpoint, &variables).into_iter().collect();
+        let (retry, retry_overrides) = (variables.clone(), overrides.clone());
+        if !self.confirm_protected(self.settings.environment.clone(), writes, Box::new(move |app| app.send_with_overrides(coll_idx, ep_idx, retry, retry_overrides))) {
+            return;
+        }
+        if endpoint.is_websocket() {
+            self.open_websocket(coll_idx, ep_idx, &endpoint, variables);
+        } else {
+            self.start_request(coll_idx, ep_idx, variables, overrides);
+        }
+    }
+    
+    /// Connect to a ws:// or wss:// endpoint and show its live message log
+    fn open_websocket(&mut self, coll_idx: usize, ep_idx: usize, endpoint: &ApiEndpoint, variables: HashMap<String, String>) {
+        let inputs = RequestInputs { variables, ..Default::default() };
+        match self.http_client.prepare(endpoint, &inputs) {
+            Ok(request) => {
+                self.error_message = None;
+                let screen = WebSocketScreen::new(self, coll_idx, ep_idx, request);
+                self.open_view(Box::new(screen));
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.connect", &[("error", &e)]));
+                self.open_template_error(&e);
+            }
+        }
+    }
+    
+    /// Warn when the body an endpoint would send is over the workspace's request budget
+    pub fn request_budget_warning(&self, endpoint: &ApiEndpoint, variables: &HashMap<String, String>) -> Option<String> {
+        self.settings.budgets.request_kb?;
+        let inputs = RequestInputs { variables: variables.clone(), ..Default::default() };
+        let request = self.http_client.prepare(endpoint, &inputs).ok()?;
+        self.settings.budgets.check_request(request.body_size)
+    }
+    
+    /// Send a request on the background runtime; `poll_requests` picks up the result
+    fn start_request(&mut self, coll_idx: usize, ep_idx: usize, variables: HashMap<String, String>, overrides: RequestOverrides) {
+        let Some(mut endpoint) = self.collections.get(coll_idx).and_then(|c| c.endpoints.get(ep_idx)).cloned() else { return };
+        overrides.apply(&mut endpoint);
+        let follow_up = overrides.follow.is_some();
+        let label = if follow_up { format!("GET {}", endpoint.url) } else { format!("{} {}", endpoint.method, endpoint.name) };
+        // One request per endpoint at a time, so responses can't land out of order
+        if let Some(index) = self.in_flight.iter().position(|r| !r.for_view && (r.collection_index, r.endpoint_index) == (coll_idx, ep_idx)) {
+            match self.settings.repeat_send {
+                RepeatSend::Queue => {
+                    self.queued_requests.push_back(QueuedRequest { collection_index: coll_idx, endpoint_index: ep_idx, variables, overrides });
+                    let (_, queued) = self.endpoint_requests(coll_idx, ep_idx);
+                    self.status_message = Some(self.tr_with("status.request_queued", &[("label", &label), ("waiting", &queued)]));
+                    return;
+                }
+                RepeatSend::Replace => {
+                    let replaced = self.in_flight.remove(index);
+                    replaced.task.abort();
+                    tracing::info!("Replaced {} still in flight", replaced.label);
                 }
             }
         }
+        self.status_message = Some(self.tr_with("status.sending", &[("label", &label)]));
+        if let Some(over) = self.request_budget_warning(&endpoint, &variables) {
+            tracing::warn!("{}: {}", label, over);
+            self.error_message = Some(format!("⚠ {}", over));
+        }
+        let progress = DownloadProgress::default();
+        let inputs = RequestInputs {
+            variables,
+            progress: Some(progress.clone()),
+            ..Default::default()
+        };
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        let client = self.client_for(coll_idx).clone();
+        let finished = self.finished_tx.clone();
+        let task = self.runtime.spawn(async move {
+            // The receiver lives as long as the app, and the runtime with it
+            let _ = finished.send(Finished::Request(id, client.execute(&endpoint, &inputs).await));
+        });
+        self.in_flight.push(InFlightRequest {
+            id,
+            collection_index: coll_idx,
+            endpoint_index: ep_idx,
+            label,
+            started: std::time::Instant::now(),
+            progress,
+            follow_up,
+            for_view: false,
+            task: task.abort_handle(),
+        });
+    }
+    
+    /// Run a request for a screen that collects the result itself on the background
+    /// runtime. It counts as in flight until it ends; `cancel_request` drops it.
+    pub fn spawn_view_request(&mut self, coll_idx: usize, ep_idx: usize, label: String, request: impl std::future::Future<Output = ()> + Send + 'static) -> u64 {
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        let task = self.runtime.spawn(request);
+        self.in_flight.push(InFlightRequest {
+            id,
+            collection_index: coll_idx,
+            endpoint_index: ep_idx,
+            label,
+            started: std::time::Instant::now(),
+            progress: DownloadProgress::default(),
+            follow_up: false,
+            for_view: true,
+            task: task.abort_handle(),
+        });
+        id
+    }
+    
+    /// Abandon one request in flight, e.g. when the screen waiting for it closes
+    pub fn cancel_request(&mut self, id: u64) {
+        if let Some(index) = self.in_flight.iter().position(|r| r.id == id) {
+            let request = self.in_flight.remove(index);
+            request.task.abort();
+            tracing::info!("Cancelled {}", request.label);
+        }
+    }
+    
+    /// Apply the responses that arrived since the last tick
+    pub fn poll_requests(&mut self) {
+        // Tasks that ended before draining the channel must have sent by then
+        let ended: Vec<u64> = self.in_flight.iter().filter(|r| r.task.is_finished()).map(|r| r.id).collect();
+        let ended_fetches: Vec<u64> = self.fetches.iter().filter(|f| f.task.is_finished()).map(|f| f.id).collect();
+        while let Ok(finished) = self.finished_rx.try_recv() {
+            self.apply_finished(finished);
+        }
+        for id in ended_fetches {
+            if let Some(index) = self.fetches.iter().position(|f| f.id == id) {
+                let fetch = self.fetches.remove(index);
+                self.error_message = Some(self.tr_with("error.download_stopped", &[("url", &fetch.source.url)]));
+            }
+        }
+        for id in ended {
+            if let Some(index) = self.in_flight.iter().position(|r| r.id == id) {
+                let request = self.in_flight.remove(index);
+                // Screens pick up their own results
+                if request.for_view {
+                    continue;
+                }
+                self.error_message = Some(self.tr_with("error.request_stopped", &[("label", &request.label)]));
+                self.start_queued_request(request.collection_index, request.endpoint_index);
+            }
+        }
+    }
+    
+    /// Requests in flight and queued for an endpoint
+    pub fn endpoint_requests(&self, coll_idx: usize, ep_idx: usize) -> (usize, usize) {
+        let this = |c: usize, e: usize| (c, e) == (coll_idx, ep_idx);
+        (
+            self.in_flight.iter().filter(|r| this(r.collection_index, r.endpoint_index)).count(),
+            self.queued_requests.iter().filter(|r| this(r.collection_index, r.endpoint_index)).count(),
+        )
+    }
+    
+    /// Send the next queued request for an endpoint whose request just ended
+    fn start_queued_request(&mut self, coll_idx: usize, ep_idx: usize) {
+        let next = self.queued_requests.iter().position(|r| (r.collection_index, r.endpoint_index) == (coll_idx, ep_idx));
+        if let Some(request) = next.and_then(|index| self.queued_requests.remove(index)) {
+            self.start_request(coll_idx, ep_idx, request.variables, request.overrides);
+        }
+    }
+    
+    /// Block until every request in flight has finished, for runs that need
+    /// each result before sending the next request
+    pub fn wait_for_requests(&mut self) {
+        while !self.in_flight.is_empty() || !self.fetches.is_empty() {
+            if let Ok(finished) = self.finished_rx.recv_timeout(REQUEST_WAIT_INTERVAL) {
+                self.apply_finished(finished);
+            }
+            self.poll_requests();
+        }
+    }
+    
+    /// Abandon every request in flight; their responses are never shown
+    pub fn cancel_requests(&mut self) {
+        let cancelled = self.in_flight.len() + self.queued_requests.len() + self.fetches.len();
+        for request in self.in_flight.drain(..) {
+            request.task.abort();
+            tracing::info!("Cancelled {}", request.label);
+        }
+        for fetch in self.fetches.drain(..) {
+            fetch.task.abort();
+            tracing::info!("Cancelled download of {}", fetch.source.url);
+        }
+        self.queued_requests.clear();
+        self.status_message = Some(match cancelled {
+            1 => self.tr("status.request_cancelled").to_string(),
+            n => self.tr_with("status.requests_cancelled", &[("count", &n)]),
+        });
+    }
+    
+    /// Something about a response worth a warning in the status bar
+    fn response_warning(&self, response: &HttpResponse) -> Option<String> {
+        if let Some(mismatch) = response.body_mismatch() {
+            return Some(format!("⚠ {}", mismatch));
+        }
+        let budgets = &self.settings.budgets;
+        let request_size = response.traffic.as_ref().map_or(0, |traffic| traffic.request.body_size);
+        if let Some(over) = budgets.check_request(request_size).or_else(|| budgets.check_response(response.body.len())) {
+            return Some(format!("⚠ {}", over));
+        }
+        let traffic = response.traffic.as_ref()?;
+        let header = traffic.request.sent_headers.conflicts().next()?;
+        let dropped: Vec<String> = header.overridden.iter()
+            .filter(|(_, value)| *value != header.value)
+            .map(|(source, _)| source.to_string())
+            .collect();
+        Some(self.tr_with("error.conflicting_headers", &[
+            ("name", &header.name),
+            ("source", &header.source),
+            ("dropped", &dropped.join(self.tr("error.conflicting_headers_and"))),
+        ]))
+    }
+    
+    fn apply_finished(&mut self, finished: Finished) {
+        match finished {
+            Finished::Request(id, result) => self.finish_request(id, result),
+            Finished::Fetch(id, result) => self.finish_fetch(id, result),
+        }
+    }
+    
+    fn finish_request(&mut self, id: u64, result: crate::http::Result<HttpResponse>) {
+        // Cancelled requests may still deliver
+        let Some(index) = self.in_flight.iter().position(|r| r.id == id) else { return };
+        let request = self.in_flight.remove(index);
+        let (coll_idx, ep_idx) = (request.collection_index, request.endpoint_index);
+        match result {
+            Ok(response) => {
+                let (status, duration) = (response.status.as_u16(), response.duration);
+                self.last_response = Some(response);
+                self.response_scroll_offset = 0;
+                self.headers_scroll_offset = 0;
+                self.response_h_scroll_offset = 0;
+                self.response_view_as = formatter::ViewAs::Auto;
+                self.json_expanded.clear();
+                let _ = self.format_response();
+                self.status_message = Some(self.tr("status.request_completed").to_string());
+                // Cut-short bodies and conflicting headers are shown, but flagged
+                self.error_message = self.last_response.as_ref().and_then(|response| self.response_warning(response));
+                // A followed link is another resource; the endpoint's checks would only fail on it
+                if request.follow_up {
+                    self.status_message = Some(self.tr_with("status.followed", &[("label", &request.label)]));
+                    self.response_assertions.clear();
+                    self.response_alternate = None;
+                    self.showing_visualization = false;
+                } else {
+                    self.check_soap_response(coll_idx, ep_idx);
+                    self.check_assertions(coll_idx, ep_idx);
+                    self.decode_protobuf_response(coll_idx, ep_idx);
+                    self.visualize_response(coll_idx, ep_idx);
+                }
+                self.response_has_table = self.response_table().is_some();
+                if !request.follow_up {
+                    let body = self.last_response.as_ref().map(|r| r.body.clone()).unwrap_or_default();
+                    self.record_history(coll_idx, ep_idx, Some(status), duration, None, &body);
+                }
+            }
+            Err(e) => {
+                self.error_message = Some(self.tr_with("error.request_failed", &[("error", &e)]));
+                self.status_message = None;
+                if !request.follow_up {
+                    self.record_history(coll_idx, ep_idx, None, request.started.elapsed(), Some(e.to_string()), &[]);
+                }
+                self.open_template_error(&e);
+            }
+        }
+        self.start_queued_request(coll_idx, ep_idx);
     }
 }
 
 impl AppState {
-    // Quick execute - execute immediately with saved variable values
-    pub fn quick_execute_request(&mut self, coll_idx: usize, ep_idx: usize) {
-        if let Some(collection) = self.collections.get(coll_idx) {
-            if let Some(endpoint) = collection.endpoints.get(ep_idx) {
-                // Find all variables needed for this endpoint
-                let mut required_vars = HashSet::new();
-                
-                // Check URL
-                for var in template::find_variables(&endpoint.url) {
-                    required_vars.insert(var);
+    /// Run every visible endpoint of a collection in order with saved variable values
+    /// and the variables earlier endpoints extracted, showing the results as they come.
+    /// With a tag filter active only the endpoints carrying that tag run; archived
+    /// endpoints never run.
+    pub fn run_collection(&mut self, coll_idx: usize) {
+        let endpoints: Vec<usize> = self.visible_endpoints(coll_idx)
+            .into_iter()
+            .filter(|&i| !self.collections[coll_idx].endpoints[i].archived)
+            .collect();
+        if endpoints.is_empty() {
+            self.error_message = Some(self.tr("error.nothing_to_run").to_string());
+            return;
+        }
+        
+        // Ask about every blocked host once up front rather than in the middle of the run
+        let hosts = endpoints.iter()
+            .filter_map(|&i| {
+                let endpoint = &self.collections[coll_idx].endpoints[i];
+                self.unapproved_host(endpoint, &self.saved_variables(endpoint).ok()?)
+            })
+            .collect();
+        if !self.confirm_hosts(hosts, Box::new(move |app| app.run_collection(coll_idx))) {
+            return;
+        }
+        let writes = endpoints.iter()
+            .filter_map(|&i| {
+                let endpoint = &self.collections[coll_idx].endpoints[i];
+                Self::write_request(endpoint, &self.saved_variables(endpoint).ok()?)
+            })
+            .collect();
+        if !self.confirm_protected(self.settings.environment.clone(), writes, Box::new(move |app| app.run_collection(coll_idx))) {
+            return;
+        }
+        
+        let scope = match &self.tag_filter {
+            Some(tag) => self.tr_with("collection_run.scope", &[("tag", tag)]),
+            None => String::new(),
+        };
+        let screen = CollectionRunScreen::start(self, coll_idx, endpoints, scope);
+        self.error_message = None;
+        self.open_view(Box::new(screen));
+    }
+    
+    /// Saved values for every variable the endpoint uses; faker variables are
+    /// left out since they are generated during substitution
+    pub fn saved_variables(&self, endpoint: &ApiEndpoint) -> std::result::Result<HashMap<String, String>, String> {
+        let mut variables = self.variables_from(endpoint, &self.request_variables())?;
+        self.add_default_header_variables(&mut variables);
+        Ok(variables)
+    }
+    
+    /// The environment's default headers may use variables the endpoint doesn't
+    fn add_default_header_variables(&self, variables: &mut HashMap<String, String>) {
+        for (var, saved) in self.default_header_variables() {
+            variables.entry(var).or_insert(saved);
+        }
+    }
+    
+    /// Saved values for the variables the environment's default headers use
+    pub fn default_header_variables(&self) -> HashMap<String, String> {
+        let available = self.request_variables();
+        let mut variables = HashMap::new();
+        for value in self.http_client.config().default_headers.values() {
+            for var in template::find_variables(value) {
+                if let Some(saved) = available.get(&var) {
+                    variables.insert(var, saved.clone());
                 }
-                
-                // Check headers
-                for value in endpoint.headers.values() {
-                    for var in template::find_variables(value) {
+            }
+        }
+        variables
+    }
+    
+    /// Values from `available` for every variable the endpoint uses
+    pub fn variables_from(&self, endpoint: &ApiEndpoint, available: &HashMap<String, String>) -> std::result::Result<HashMap<String, String>, String> {
+        // Build variables map from saved values
+        let mut variables = HashMap::new();
+        for var in Self::endpoint_variables(endpoint) {
+            // Check if user variable exists
+            if let Some(value) = available.get(&var) {
+                variables.insert(var.clone(), value.clone());
+            } else {
+                return Err(self.tr_with("error.variable_not_defined", &[("name", &var)]));
+            }
+        }
+        Ok(variables)
+    }
+    
+    /// Names of the variables an endpoint needs values for, sorted; faker and
+    /// `$now` variables are left out since they are generated during substitution
+    pub fn endpoint_variables(endpoint: &ApiEndpoint) -> Vec<String> {
+        // Find all variables needed for this endpoint
+        let mut required_vars = HashSet::new();
+        
+        // Check URL
+        for var in template::find_variables(&endpoint.url) {
+            required_vars.insert(var);
+        }
+        
+        // Check headers
+        for value in endpoint.headers.values() {
+            for var in template::find_variables(value) {
+                required_vars.insert(var);
+            }
+        }
+        
+        // Check body template
+        if let Some(body) = &endpoint.body_template {
+            for var in template::find_variables(body) {
+                required_vars.insert(var);
+            }
+        }
+        
+        // Check auth
+        if let Some(auth) = &endpoint.auth {
+            match auth {
+                crate::models::AuthConfig::Bearer { token } => {
+                    for var in template::find_variables(token) {
                         required_vars.insert(var);
                     }
                 }
-                
-                // Check body template
-                if let Some(body) = &endpoint.body_template {
-                    for var in template::find_variables(body) {
+                crate::models::AuthConfig::Basic { username, password } => {
+                    for var in template::find_variables(username) {
                         required_vars.insert(var);
                     }
-                }
-                
-                // Check auth
-                if let Some(auth) = &endpoint.auth {
-                    match auth {
-                        crate::models::AuthConfig::Bearer { token } => {
-                            for var in template::find_variables(token) {
-                                required_vars.insert(var);
-                            }
-                        }
-                        crate::models::AuthConfig::Basic { username, password } => {
-                            for var in template::find_variables(username) {
-                                required_vars.insert(var);
-                            }
-                            for var in template::find_variables(password) {
-                                required_vars.insert(var);
-                            }
-                        }
-                        crate::models::AuthConfig::ApiKey { name, value, .. } => {
-                            for var in template::find_variables(name) {
-                                required_vars.insert(var);
-                            }
-                            for var in template::find_variables(value) {
-                                required_vars.insert(var);
-                            }
-                        }
+                    for var in template::find_variables(password) {
+                        required_vars.insert(var);
                     }
                 }
-                
-                // Build variables map from saved values (skip faker variables)
-                let mut variables = HashMap::new();
-                for var in required_vars {
-                    // Skip faker variables - they'll be generated during substitution
-                    if crate::faker::is_faker_variable(&var) {
-                        continue;
+                crate::models::AuthConfig::ApiKey { name, value, .. } => {
+                    for var in template::find_variables(name) {
+                        required_vars.insert(var);
                     }
-                    
-                    // Check if user variable exists
-                    if let Some(value) = self.variable_manager.get(&var) {
-                        variables.insert(var.clone(), value.clone());
-                    } else {
-                        // Variable not found - show error
-                        self.error_message = Some(format!("Variable '{}' not defined. Press 'v' to manage variables.", var));
+                    for var in template::find_variables(value) {
+                        required_vars.insert(var);
+                    }
+                }
+            }
+        }
+        
+        let mut required_vars: Vec<String> = required_vars.into_iter()
+            .filter(|var| !crate::faker::is_faker_variable(var) && !template::is_builtin_variable(var))
+            .collect();
+        required_vars.sort();
+        required_vars
+    }
+    
+    /// Mark or unmark an endpoint for a parallel run with `P`
+    pub fn toggle_endpoint_mark(&mut self, coll_idx: usize, ep_idx: usize) {
+        let Some(endpoint) = self.collections.get(coll_idx).and_then(|c| c.endpoints.get(ep_idx)) else { return };
+        if !self.marked_endpoints.remove(&endpoint.id) {
+            self.marked_endpoints.insert(endpoint.id);
+        }
+        let marked = self.marked_in(coll_idx).len();
+        self.status_message = Some(match marked {
+            1 => self.tr("status.one_marked").to_string(),
+            _ => self.tr_with("status.marked", &[("count", &marked)]),
+        });
+    }
+    
+    /// Marked endpoints of a collection, in list order
+    pub fn marked_in(&self, coll_idx: usize) -> Vec<usize> {
+        self.visible_endpoints(coll_idx)
+            .into_iter()
+            .filter(|&i| self.marked_endpoints.contains(&self.collections[coll_idx].endpoints[i].id))
+            .collect()
+    }
+    
+    /// Send an endpoint to two environments and diff the responses
+    pub fn start_compare(&mut self, coll_idx: usize, ep_idx: usize) {
+        if self.collections.get(coll_idx).and_then(|c| c.endpoints.get(ep_idx)).is_none() {
+            return;
+        }
+        let environments = self.variable_manager.environment_names();
+        if environments.len() < 2 {
+            self.error_message = Some(self.tr_with("error.compare_needs_environments", &[("dir", &self.variable_manager.environments_dir().display())]));
+            return;
+        }
+        let endpoint = &self.collections[coll_idx].endpoints[ep_idx];
+        let hosts = environments[..2].iter()
+            .filter_map(|name| {
+                let available = self.variable_manager.variables_for(Some(name)).ok()?;
+                self.unapproved_host(endpoint, &self.variables_from(endpoint, &available).ok()?)
+            })
+            .collect();
+        if !self.confirm_hosts(hosts, Box::new(move |app| app.start_compare(coll_idx, ep_idx))) {
+            return;
+        }
+        // Either side may be protected; the first one that is asks
+        let endpoint = &self.collections[coll_idx].endpoints[ep_idx];
+        let protected = environments[..2].iter()
+            .find(|name| self.variable_manager.environment(name).is_ok_and(|set| set.protected))
+            .cloned();
+        let variables = protected.as_deref()
+            .and_then(|name| self.variable_manager.variables_for(Some(name)).ok())
+            .unwrap_or_default();
+        let writes = Self::write_request(endpoint, &variables).into_iter().collect();
+        if !self.confirm_protected(protected, writes, Box::new(move |app| app.start_compare(coll_idx, ep_idx))) {
+            return;
+        }
+        let screen = CompareScreen::start(self, coll_idx, ep_idx, environments);
+        self.error_message = None;
+        self.open_view(Box::new(screen));
+    }
+    
+    /// Send every marked endpoint at once and watch the results come in
+    pub fn run_marked(&mut self, coll_idx: usize) {
+        let marked = self.marked_in(coll_idx);
+        if marked.is_empty() {
+            self.error_message = Some(self.tr("error.nothing_marked").to_string());
+            return;
+        }
+        let jobs: Vec<_> = marked.into_iter()
+            .map(|ep_idx| {
+                let endpoint = &self.collections[coll_idx].endpoints[ep_idx];
+                (ep_idx, self.saved_variables(endpoint))
+            })
+            .collect();
+        let hosts = jobs.iter()
+            .filter_map(|(ep_idx, variables)| {
+                let variables = variables.as_ref().ok()?;
+                self.unapproved_host(&self.collections[coll_idx].endpoints[*ep_idx], variables)
+            })
+            .collect();
+        if !self.confirm_hosts(hosts, Box::new(move |app| app.run_marked(coll_idx))) {
+            return;
+        }
+        let writes = jobs.iter()
+            .filter_map(|(ep_idx, variables)| {
+                Self::write_request(&self.collections[coll_idx].endpoints[*ep_idx], variables.as_ref().ok()?)
+            })
+            .collect();
+        if !self.confirm_protected(self.settings.environment.clone(), writes, Box::new(move |app| app.run_marked(coll_idx))) {
+            return;
+        }
+        let screen = RunSelectedScreen::start(self, coll_idx, jobs);
+        self.error_message = None;
+        self.open_view(Box::new(screen));
+    }
+    
+    /// Show the two marked endpoints side by side, e.g. v1 and v2 of a call;
+    /// sends to a protected environment are confirmed by the screen as they happen
+    pub fn open_split_view(&mut self, coll_idx: usize) {
+        let marked = self.marked_in(coll_idx);
+        let [first, second] = marked[..] else {
+            self.error_message = Some(self.tr_with("error.split_needs_two", &[("count", &marked.len())]));
+            return;
+        };
+        self.error_message = None;
+        self.open_view(Box::new(SplitViewScreen::new(coll_idx, [first, second])));
+    }
+    
+    // Quick execute - execute immediately with saved variable values
+    pub fn quick_execute_request(&mut self, coll_idx: usize, ep_idx: usize) {
+        if let Some(collection) = self.collections.get(coll_idx) {
+            if let Some(endpoint) = collection.endpoints.get(ep_idx) {
+                let variables = match self.saved_variables(endpoint) {
+                    Ok(variables) => variables,
+                    Err(e) => {
+                        self.error_message = Some(e);
                         self.status_message = None;
                         return;
                     }
-                }
+                };
                 
                 // Execute request with variables (faker variables will be generated during substitution)
-                let runtime = tokio::runtime::Runtime::new().unwrap();
-                runtime.block_on(self.execute_request_with_vars(coll_idx, ep_idx, variables));
+                self.send_request(coll_idx, ep_idx, variables);
             }
         }
     }
+    
+    /// GET a link found in the last response with an endpoint's auth, headers
+    /// and the saved variables they use
+    pub fn follow_link(&mut self, coll_idx: usize, ep_idx: usize, url: String) {
+        let Some(mut endpoint) = self.collections.get(coll_idx).and_then(|c| c.endpoints.get(ep_idx)).cloned() else { return };
+        let overrides = RequestOverrides { follow: Some(url), ..Default::default() };
+        // Only what the link's request uses; the endpoint's own URL and body don't matter
+        overrides.apply(&mut endpoint);
+        let variables = match self.saved_variables(&endpoint) {
+            Ok(variables) => variables,
+            Err(e) => {
+                self.error_message = Some(e);
+                return;
+            }
+        };
+        self.send_with_overrides(coll_idx, ep_idx, variables, overrides);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// An app on an empty workspace in `temp_dir`
+    pub(crate) fn test_app(temp_dir: &TempDir) -> AppState {
+        let storage = StorageManager::new(
+            temp_dir.path().join("collections"),
+            temp_dir.path().join("results"),
+        ).unwrap();
+        let variable_manager = VariableManager::with_path(temp_dir.path().join("variables.json")).unwrap();
+        let history = HistoryStore::with_path(temp_dir.path().join("history.jsonl")).unwrap();
+        AppState::with_storage(storage, variable_manager, history).unwrap()
+    }
+
+    fn add_collection(app: &mut AppState, name: &str, endpoints: usize) {
+        let mut collection = ApiCollection::new(name.to_string());
+        for i in 0..endpoints {
+            collection.add_endpoint(ApiEndpoint::new(
+                format!("ep{}", i),
+                HttpMethod::GET,
+                "https://example.com".to_string(),
+            ));
+        }
+        app.storage.save_collection(&collection).unwrap();
+        app.collections.push(collection);
+    }
+
+    #[test]
+    fn test_selection_clamp() {
+        let mut selection = Selection { collection: 3, endpoint: 5, variable: 2 };
+        selection.clamp(FocusedList::Collections, 3);
+        selection.clamp(FocusedList::Endpoints, 0);
+        selection.clamp(FocusedList::Variables, 4);
+        assert_eq!(selection, Selection { collection: 2, endpoint: 0, variable: 2 });
+    }
+
+    #[test]
+    fn test_navigation_stays_in_bounds() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 2);
+        add_collection(&mut app, "b", 0);
+
+        app.navigate_down();
+        app.navigate_down();
+        assert_eq!(app.selection.collection, 1);
+
+        app.navigate_up();
+        app.panel_focus = PanelFocus::Endpoints;
+        app.navigate_down();
+        app.navigate_down();
+        assert_eq!(app.selection.endpoint, 1);
+    }
+
+    #[test]
+    fn test_tag_filter_skips_untagged_endpoints() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 4);
+        app.collections[0].endpoints[1].tags = vec!["smoke".to_string()];
+        app.collections[0].endpoints[3].tags = vec!["Smoke".to_string(), "slow".to_string()];
+        app.panel_focus = PanelFocus::Endpoints;
+
+        app.cycle_tag_filter();
+        assert_eq!(app.tag_filter.as_deref(), Some("slow"));
+        assert_eq!(app.selection.endpoint, 3);
+
+        app.cycle_tag_filter();
+        assert_eq!(app.tag_filter.as_deref(), Some("smoke"));
+        assert_eq!(app.visible_endpoints(0), vec![1, 3]);
+        app.navigate_up();
+        assert_eq!(app.selection.endpoint, 1);
+        app.navigate_up();
+        assert_eq!(app.selection.endpoint, 1);
+
+        app.cycle_tag_filter();
+        assert_eq!(app.tag_filter, None);
+        assert_eq!(app.visible_endpoints(0).len(), 4);
+    }
+
+    #[test]
+    fn test_archive_and_restore_endpoint() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 3);
+        app.selection.endpoint = 1;
+
+        app.toggle_endpoint_archived(0, 1);
+        assert!(app.storage.load_collections().unwrap()[0].endpoints[1].archived);
+        assert_eq!(app.visible_endpoints(0), vec![0, 2]);
+        assert_ne!(app.selection.endpoint, 1);
+
+        app.toggle_archived_view();
+        assert_eq!(app.visible_endpoints(0), vec![1]);
+        assert_eq!(app.selection.endpoint, 1);
+
+        app.toggle_endpoint_archived(0, 1);
+        assert!(app.visible_endpoints(0).is_empty());
+        app.toggle_archived_view();
+        assert_eq!(app.visible_endpoints(0), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_read_only_collection_requires_copy() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "shared", 1);
+        app.collections[0].read_only = true;
+
+        app.start_edit_endpoint(0, 0);
+        assert!(app.endpoint_form.is_none());
+        assert!(app.error_message.as_deref().unwrap().contains("read-only"));
+
+        app.make_editable_copy(0);
+        assert_eq!(app.collections.len(), 2);
+        assert_eq!(app.selection.collection, 1);
+        app.start_edit_endpoint(1, 0);
+        assert!(app.endpoint_form.is_some());
+    }
+
+    #[test]
+    fn test_export_then_import_insomnia() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "api", 2);
+        let path = temp_dir.path().join("api.insomnia.json");
+
+        assert!(app.export_collection(0, &path, ExportFormat::Insomnia));
+        assert!(app.import_file(&path));
+        assert_eq!(app.collections.len(), 2);
+        assert_eq!(app.collections[1].name, "api");
+        assert_eq!(app.collections[1].endpoints.len(), 2);
+        assert_eq!(app.storage.load_collections().unwrap().len(), 2);
+
+        assert!(!app.import_file(&temp_dir.path().join("missing.json")));
+        assert!(app.error_message.is_some());
+    }
+
+    #[test]
+    fn test_copy_as_curl_resolves_the_request() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "api", 0);
+        let mut endpoint = ApiEndpoint::new("create".to_string(), HttpMethod::POST, "{{host}}/users".to_string());
+        endpoint.auth = Some(crate::models::AuthConfig::Bearer { token: "{{token}}".to_string() });
+        endpoint.body_template = Some(r#"{"name":"{{name}}"}"#.to_string());
+        app.collections[0].add_endpoint(endpoint);
+
+        assert!(app.curl_command(0, 0).is_err(), "variables are missing");
+        app.variable_manager.set("host".to_string(), "https://api.example.com".to_string()).unwrap();
+        app.variable_manager.set("token".to_string(), "t0k".to_string()).unwrap();
+        app.variable_manager.set("name".to_string(), "bob".to_string()).unwrap();
+        let command = app.curl_command(0, 0).unwrap();
+        assert!(command.starts_with("curl 'https://api.example.com/users' \\\n"));
+        assert!(command.contains("-H 'Authorization: Bearer t0k'"));
+        assert!(command.ends_with(r#"--data-raw '{"name":"bob"}'"#));
+
+        let path = temp_dir.path().join("create.sh");
+        assert!(app.write_curl_command(&path, &command));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), format!("{}\n", command));
+    }
+
+    #[test]
+    fn test_format_endpoint_body() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "A", 0);
+        app.start_new_endpoint(0);
+
+        let form = app.endpoint_form.as_mut().unwrap();
+        form.body_template = r#"{"id":{{id}}"#.to_string();
+        app.format_endpoint_body();
+        assert!(app.error_message.as_deref().unwrap().starts_with("Invalid JSON body: line 1"));
+
+        app.endpoint_form.as_mut().unwrap().body_template.push('}');
+        app.format_endpoint_body();
+        assert_eq!(app.endpoint_form.as_ref().unwrap().body_template, "{\n  \"id\": {{id}}\n}");
+        assert_eq!(app.error_message, None);
+    }
+
+    #[test]
+    fn test_visualizer_view_toggles_with_raw_body() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "A", 1);
+        app.collections[0].endpoints[0].visualizer = Some(".items[] | {id}".to_string());
+        let raw = "{\n  \"items\": [{ \"id\": 1 }]\n}".to_string();
+        app.last_response_formatted = Some(raw.clone());
+
+        app.visualize_response(0, 0);
+        assert!(app.showing_visualization);
+        assert_eq!(app.last_response_formatted.as_deref(), Some("Field │ Value\n──────┼──────\nid    │ 1"));
+        app.toggle_visualization();
+        assert!(!app.showing_visualization);
+        assert_eq!(app.last_response_formatted, Some(raw));
+
+        app.collections[0].endpoints[0].visualizer = Some(".items.id".to_string());
+        app.visualize_response(0, 0);
+        assert!(!app.showing_visualization);
+        assert_eq!(app.error_message.as_deref(), Some("Visualizer: Cannot index array with \"id\""));
+    }
+
+    #[test]
+    fn test_view_as_cycles_through_formats() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "A", 1);
+        // History replays have no headers, so nothing says this is JSON
+        app.record_history(0, 0, Some(200), Duration::from_millis(5), None, b"{\"a\":1}");
+        app.open_history_entry(0);
+
+        app.cycle_view_as();
+        assert_eq!(app.response_view_as, formatter::ViewAs::Json);
+        assert_eq!(app.last_response_formatted.as_deref(), Some("{\n  \"a\": 1\n}"));
+        app.cycle_view_as();
+        assert_eq!(app.response_view_as, formatter::ViewAs::Xml);
+        app.cycle_view_as();
+        assert_eq!(app.last_response_formatted.as_deref(), Some("{\"a\":1}"));
+        app.cycle_view_as();
+        assert!(app.last_response_formatted.as_deref().unwrap().starts_with("00000000  7b 22 61 22"));
+
+        app.open_history_entry(0);
+        assert_eq!(app.response_view_as, formatter::ViewAs::Auto);
+    }
+
+    #[test]
+    fn test_huge_json_is_cut_short_until_expanded() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "A", 1);
+        let items: Vec<u32> = (0..100_000).collect();
+        let body = serde_json::to_vec(&serde_json::json!({ "items": items })).unwrap();
+        app.record_history(0, 0, Some(200), Duration::from_millis(5), None, &body);
+        app.open_history_entry(0);
+
+        let limits = formatter::JsonLimits::default();
+        let formatted = app.last_response_formatted.clone().unwrap();
+        assert_eq!(formatted.lines().count(), limits.max_children + 5);
+        assert!(formatted.contains("… 99500 more items"));
+        assert_eq!(app.response_json_cuts.len(), 1);
+
+        // Off screen, nothing to expand
+        app.response_view_height.set(20);
+        app.expand_json();
+        assert_eq!(app.status_message.as_deref(), Some("Nothing on screen is cut short"));
+        app.response_scroll_offset = app.response_json_cuts[0].line - 5;
+        app.expand_json();
+        assert!(app.response_json_cuts.is_empty());
+        assert_eq!(app.last_response_formatted.as_deref().unwrap().lines().count(), 100_004);
+
+        // A new response starts folded again
+        app.open_history_entry(0);
+        assert_eq!(app.response_json_cuts.len(), 1);
+    }
+
+    #[test]
+    fn test_horizontal_scroll_stops_at_widest_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        // As drawn: the widest panel can scroll 60 columns
+        app.response_h_scroll_max.set(60);
+        app.toggle_response_wrap();
+
+        for _ in 0..20 {
+            app.scroll_response_right(8);
+        }
+        assert_eq!(app.response_h_scroll_offset, 60);
+        // One press back moves the view
+        app.scroll_response_left(8);
+        assert_eq!(app.response_h_scroll_offset, 52);
+
+        // Content that narrowed since doesn't swallow the next press
+        app.response_h_scroll_max.set(20);
+        app.scroll_response_left(8);
+        assert_eq!(app.response_h_scroll_offset, 12);
+
+        // Nothing to scroll while wrapping
+        app.toggle_response_wrap();
+        app.scroll_response_right(8);
+        assert_eq!(app.response_h_scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_open_history_entry_shows_stored_response() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "A", 0);
+        add_collection(&mut app, "B", 2);
+        app.record_history(1, 1, Some(201), Duration::from_millis(12), None, b"[{\"order\": \"ORD-1\"}]");
+
+        let hit = app.history.search("ord-1").remove(0);
+        app.open_history_entry(hit.index);
+        assert_eq!((app.selection.collection, app.selection.endpoint), (1, 1));
+        assert_eq!(app.panel_focus, PanelFocus::Endpoints);
+        let response = app.last_response.as_ref().unwrap();
+        assert_eq!((response.status.as_u16(), response.duration), (201, Duration::from_millis(12)));
+        assert!(app.last_response_formatted.as_deref().unwrap().contains("\"order\": \"ORD-1\""));
+        assert!(app.response_has_table);
+    }
+
+    #[test]
+    fn test_queued_load_tests_run_in_order_and_save_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 2);
+        // Nothing listens on port 1, so requests fail fast
+        for endpoint in &mut app.collections[0].endpoints {
+            endpoint.url = "http://127.0.0.1:1/".to_string();
+        }
+
+        let mut form = LoadTestConfigForm::for_endpoint(&app.collections[0].endpoints[1], &app.settings.load_test, 0, 1);
+        form.duration = "5".to_string();
+        form.queue = true;
+        assert!(app.execute_load_test(&form));
+        assert_eq!(app.load_test_queue.len(), 1);
+        assert!(app.load_test_engine.is_none());
+
+        // Starting a run without 'queue' runs the queued one first
+        let mut form = LoadTestConfigForm::for_endpoint(&app.collections[0].endpoints[0], &app.settings.load_test, 0, 0);
+        form.duration = "5".to_string();
+        assert!(app.execute_load_test(&form));
+        assert_eq!(app.current_screen, Screen::LoadTestRunning(0, 1));
+        assert_eq!(app.load_test_queue[0].endpoint_index, 0);
+        assert!(app.load_test_queue_status().is_some());
+
+        app.stop_load_test();
+        assert!(app.load_test_queue.is_empty());
+        let engine = app.load_test_engine.as_ref().unwrap();
+        assert!(engine.is_reported());
+        let saved: Vec<_> = std::fs::read_dir(temp_dir.path().join("results")).unwrap().collect();
+        assert_eq!(saved.len(), 1);
+    }
+
+    #[test]
+    fn test_load_test_rejects_non_numeric_client_overrides() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 1);
+
+        let mut form = LoadTestConfigForm::for_endpoint(&app.collections[0].endpoints[0], &app.settings.load_test, 0, 0);
+        form.timeout = "99999999999999999999".to_string();
+        assert!(!app.execute_load_test(&form));
+        assert_eq!(app.error_message.as_deref(), Some("Request timeout must be a number"));
+
+        form.timeout.clear();
+        form.pool_size = "lots".to_string();
+        assert!(!app.execute_load_test(&form));
+        assert_eq!(app.error_message.as_deref(), Some("Connection pool size must be a number"));
+        assert!(app.load_test_engine.is_none());
+    }
+
+    #[test]
+    fn test_load_test_rejects_overflowing_run_fields() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 1);
+
+        // Only digits can be typed, but they can still overflow
+        let mut form = LoadTestConfigForm::for_endpoint(&app.collections[0].endpoints[0], &app.settings.load_test, 0, 0);
+        form.cool_down = "99999999999999999999".to_string();
+        assert!(!app.execute_load_test(&form));
+        assert_eq!(app.error_message.as_deref(), Some("Cool-down must be a number"));
+
+        form.cool_down.clear();
+        form.har_samples = "99999999999999999999".to_string();
+        assert!(!app.execute_load_test(&form));
+        assert_eq!(app.error_message.as_deref(), Some("HAR samples must be a number"));
+
+        form.har_samples.clear();
+        form.duration.clear();
+        assert!(!app.execute_load_test(&form));
+        assert_eq!(app.error_message.as_deref(), Some("Duration must be a number"));
+        assert!(app.load_test_engine.is_none());
+        assert!(app.load_test_queue.is_empty());
+    }
+
+    #[test]
+    fn test_scenario_run_saves_per_endpoint_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 2);
+        for endpoint in &mut app.collections[0].endpoints {
+            endpoint.url = "http://127.0.0.1:1/".to_string();
+        }
+        app.start_scenario_load_test(0);
+        assert!(app.view.is_none());
+        assert!(app.error_message.as_deref().unwrap().contains("no load test scenario"));
+
+        let ids: Vec<_> = app.collections[0].endpoints.iter().map(|e| e.id).collect();
+        assert!(app.set_load_test_scenario(0, vec![
+            ScenarioStep { endpoint_id: ids[0], weight: 1 },
+            ScenarioStep { endpoint_id: ids[1], weight: 3 },
+        ]));
+        let mut form = LoadTestConfigForm::for_endpoint(&app.collections[0].endpoints[0], &app.settings.load_test, 0, 0);
+        form.duration = "5".to_string();
+        form.scenario = true;
+        assert!(app.execute_load_test(&form));
+        assert_eq!(app.get_load_test_metrics().unwrap().endpoints.len(), 2);
+        // The form's settings aren't saved to the endpoint it was prefilled from
+        assert!(app.collections[0].endpoints[0].load_test_config.is_none());
+
+        app.stop_load_test();
+        let reports = app.storage.load_test_reports().unwrap();
+        assert_eq!(reports.len(), 1);
+        let report = &reports[0];
+        assert_eq!((report.target.method.as_str(), report.target.endpoint_name.as_str()), ("MIX", "a scenario"));
+        assert_eq!(report.target.endpoint_id, app.collections[0].id);
+        let weights: Vec<u32> = report.endpoints.iter().map(|e| e.weight).collect();
+        assert_eq!(weights, vec![1, 3]);
+        assert_eq!(report.endpoints.iter().map(|e| e.total_requests).sum::<u64>(), report.total_requests);
+    }
+
+    #[test]
+    fn test_interrupted_soak_run_is_recovered_on_start() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let target = LoadTestTarget {
+            collection_id: uuid::Uuid::new_v4(),
+            endpoint_id: uuid::Uuid::new_v4(),
+            endpoint_name: "orders".to_string(),
+            method: "GET".to_string(),
+            url: "http://localhost/orders".to_string(),
+        };
+        let config = LoadTestConfig::new(5, Duration::from_secs(7200)).with_soak(true);
+        let mut checkpoint = load_test::LoadTestReport::new(target, &config, &LoadTestMetrics::new(), Duration::from_secs(600));
+        checkpoint.status = RunStatus::Running;
+        app.storage.save_load_test_report(&checkpoint).unwrap();
+
+        app.recover_interrupted_load_tests();
+        assert!(app.view.is_some());
+        assert!(app.status_message.as_deref().unwrap().contains("orders"));
+        let reports = app.storage.load_test_reports().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].status, RunStatus::Interrupted);
+
+        // Already recovered runs are left alone next time
+        app.view = None;
+        app.recover_interrupted_load_tests();
+        assert!(app.view.is_none());
+    }
+
+    #[test]
+    fn test_template_error_opens_error_panel() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        let mut endpoint = ApiEndpoint::new("create".to_string(), HttpMethod::POST, "http://localhost:9/users".to_string());
+        endpoint.body_template = Some("{\n  \"name\": \"{{name}}\"\n}".to_string());
+        collection.add_endpoint(endpoint);
+        app.collections.push(collection);
+
+        app.send_request(0, 0, HashMap::new());
+        app.wait_for_requests();
+        let error = app.error_message.clone().unwrap();
+        assert!(error.contains("Missing variable: name (body, line 2, column 12)"), "{}", error);
+        assert!(app.view.is_some());
+    }
+
+    #[test]
+    fn test_requests_run_in_background_and_can_be_cancelled() {
+        use std::io::{Read, Write};
+
+        // Sends the headers, then stalls before the body; the second connection never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buf = vec![0u8; 4096];
+            let _ = socket.read(&mut buf).unwrap();
+            socket.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 4\r\nconnection: close\r\n\r\nab").unwrap();
+            socket.flush().unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(350));
+            socket.write_all(b"cd").unwrap();
+            listener.accept().unwrap()
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("download".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port)));
+        app.collections.push(collection);
+
+        app.send_request(0, 0, HashMap::new());
+        assert!(app.last_response.is_none(), "the key handler doesn't wait for the response");
+        assert!(app.is_animating());
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let partial = loop {
+            app.tick();
+            let snapshot = app.in_flight[0].progress.snapshot();
+            if let Some(snapshot) = snapshot.filter(|s| s.received == 2) {
+                break snapshot;
+            }
+            assert!(std::time::Instant::now() < deadline, "no progress");
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        assert_eq!(partial.ratio(), Some(0.5));
+        app.wait_for_requests();
+        assert_eq!(app.last_response.as_ref().unwrap().body, b"abcd");
+        assert!(app.in_flight.is_empty());
+        assert_eq!(app.history.entries().len(), 1);
+
+        app.send_request(0, 0, HashMap::new());
+        let _stalled = server.join().unwrap();
+        app.cancel_requests();
+        assert!(app.in_flight.is_empty());
+        assert_eq!(app.status_message.as_deref(), Some("Request cancelled"));
+        app.tick();
+        assert_eq!(app.last_response.as_ref().unwrap().body, b"abcd");
+        assert_eq!(app.history.entries().len(), 1, "cancelled requests aren't recorded");
+    }
+
+    #[test]
+    fn test_traffic_panel_does_not_change_what_is_sent() {
+        use std::io::{Read, Write};
+
+        // Answers with the request head it got, so both sends can be compared
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for mut socket in listener.incoming().flatten().take(2) {
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).unwrap_or(0);
+                let mut head: Vec<String> = String::from_utf8_lossy(&buf[..n]).lines().map(str::to_lowercase).collect();
+                head.sort();
+                let head = head.join("\n");
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", head.len(), head);
+                let _ = socket.write_all(response.as_bytes());
+            }
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("echo".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/", port)));
+        app.collections.push(collection);
+
+        let mut responses = Vec::new();
+        for panel in [false, true] {
+            app.show_network_traffic = panel;
+            app.send_request(0, 0, HashMap::new());
+            app.wait_for_requests();
+            let response = app.last_response.clone().unwrap();
+            assert!(response.traffic.as_ref().unwrap().interim.is_empty());
+            responses.push((response.status, response.body));
+        }
+        assert_eq!(responses[0], responses[1]);
+        assert!(String::from_utf8_lossy(&responses[0].1).contains("accept-encoding: gzip"));
+    }
+
+    #[test]
+    fn test_repeat_sends_replace_or_queue() {
+        use std::io::{Read, Write};
+
+        // Echoes the path after a pause, so a second send finds the first in flight
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for mut socket in listener.incoming().flatten() {
+                std::thread::spawn(move || {
+                    let mut buf = vec![0u8; 4096];
+                    let n = socket.read(&mut buf).unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]).into_owned();
+                    let path = request.split(' ').nth(1).unwrap_or_default().to_string();
+                    std::thread::sleep(Duration::from_millis(150));
+                    let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", path.len(), path);
+                    let _ = socket.write_all(response.as_bytes());
+                });
+            }
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("slow".to_string(), HttpMethod::GET, format!("http://127.0.0.1:{}/{{{{n}}}}", port)));
+        app.collections.push(collection);
+        let n = |n: &str| HashMap::from([("n".to_string(), n.to_string())]);
+
+        // By default the newer send replaces the one in flight
+        app.send_request(0, 0, n("1"));
+        app.send_request(0, 0, n("2"));
+        assert_eq!(app.endpoint_requests(0, 0), (1, 0));
+        app.wait_for_requests();
+        assert_eq!(app.last_response.as_ref().unwrap().body, b"/2");
+        assert_eq!(app.history.entries().len(), 1);
+
+        // Queued sends go one after another, in order
+        app.settings.repeat_send = RepeatSend::Queue;
+        app.send_request(0, 0, n("3"));
+        app.send_request(0, 0, n("4"));
+        app.send_request(0, 0, n("5"));
+        assert_eq!(app.endpoint_requests(0, 0), (1, 2));
+        assert_eq!(app.status_message.as_deref(), Some("GET slow queued behind the one in flight (2 waiting)"));
+        app.wait_for_requests();
+        assert_eq!(app.last_response.as_ref().unwrap().body, b"/5");
+        assert_eq!(app.history.entries().len(), 4, "none of the queued sends was dropped");
+
+        // Esc drops the queue too
+        app.send_request(0, 0, n("6"));
+        app.send_request(0, 0, n("7"));
+        app.cancel_requests();
+        assert_eq!(app.status_message.as_deref(), Some("2 requests cancelled"));
+        assert_eq!(app.endpoint_requests(0, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_active_environment_applies_variables_and_headers() {
+        use crate::variables::VariableSet;
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).unwrap();
+            socket.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_lowercase()
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        app.variable_manager.set("host".to_string(), "http://localhost:1".to_string()).unwrap();
+        let mut staging = VariableSet::new("staging".to_string());
+        staging.set("host".to_string(), format!("http://127.0.0.1:{}", port));
+        staging.set("tenant".to_string(), "acme".to_string());
+        staging.defaults.headers.insert("X-Tenant".to_string(), "{{tenant}}".to_string());
+        app.variable_manager.save_environment(&staging).unwrap();
+        let mut collection = ApiCollection::new("A".to_string());
+        collection.add_endpoint(ApiEndpoint::new("users".to_string(), HttpMethod::GET, "{{host}}/users".to_string()));
+        app.collections.push(collection);
+
+        // An environment that doesn't exist isn't saved
+        assert!(!app.apply_settings(Settings { environment: Some("qa".to_string()), ..app.settings.clone() }));
+        assert!(app.apply_settings(Settings { environment: Some("staging".to_string()), ..app.settings.clone() }));
+        app.quick_execute_request(0, 0);
+        app.wait_for_requests();
+        let request = server.join().unwrap();
+        assert!(request.starts_with("get /users"));
+        assert!(request.contains("x-tenant: acme"));
+        assert_eq!(app.last_response.as_ref().unwrap().status, 204);
+
+        // A deleted environment is dropped on the next start, with a message
+        std::fs::remove_file(app.variable_manager.environments_dir().join("staging.json")).unwrap();
+        let app = test_app(&temp_dir);
+        assert_eq!(app.settings.environment, None);
+        assert!(app.error_message.as_deref().unwrap().starts_with("Environment 'staging' not activated"));
+    }
+
+    #[test]
+    fn test_size_budgets_warn_but_still_send() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().unwrap();
+                let mut buf = vec![0u8; 8192];
+                let _ = socket.read(&mut buf).unwrap();
+                let body = "x".repeat(4096);
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", body.len(), body);
+                socket.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        let mut collection = ApiCollection::new("A".to_string());
+        let mut endpoint = ApiEndpoint::new("upload".to_string(), HttpMethod::POST, format!("http://127.0.0.1:{}/", port));
+        endpoint.body_template = Some("y".repeat(2048));
+        collection.add_endpoint(endpoint);
+        app.collections.push(collection);
+        app.settings.budgets = crate::settings::SizeBudgets { request_kb: Some(1), response_kb: Some(2) };
+
+        app.send_request(0, 0, HashMap::new());
+        assert_eq!(app.error_message.as_deref(), Some("⚠ Request body is 2.0 KB, over the 1.0 KB budget"));
+        app.wait_for_requests();
+        assert_eq!(app.last_response.as_ref().unwrap().status.as_u16(), 200);
+        assert_eq!(app.error_message.as_deref(), Some("⚠ Request body is 2.0 KB, over the 1.0 KB budget"));
+
+        app.settings.budgets.request_kb = None;
+        app.send_request(0, 0, HashMap::new());
+        app.wait_for_requests();
+        assert_eq!(app.error_message.as_deref(), Some("⚠ Response body is 4.0 KB, over the 2.0 KB budget"));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_sync_with_remote_spec() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let versions = [
+                r#"{"openapi": "3.0.0", "info": {"title": "Shop"}, "paths": {"/items": {"get": {}}, "/legacy": {"get": {}}}}"#,
+                r#"{"openapi": "3.0.0", "info": {"title": "Shop"}, "paths": {"/items": {"get": {}}, "/orders": {"post": {}}}}"#,
+                r#"{"openapi": "3.0.0", "info": {"title": "Shop"}, "paths": {"/items": {"get": {}}, "/orders": {"post": {}}}}"#,
+            ];
+            for spec in versions {
+                let (mut socket, _) = listener.accept().unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).unwrap();
+                assert!(String::from_utf8_lossy(&buf[..n]).contains("x-key: secret"));
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", spec.len(), spec);
+                socket.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        app.variable_manager.set("key".to_string(), "secret".to_string()).unwrap();
+        let url = format!("http://127.0.0.1:{}/openapi.json", port);
+        assert!(app.import_url(&url, "X-Key: {{key}}"));
+        // Downloads on the background runtime while the UI keeps drawing
+        assert!(app.collections.is_empty() && app.is_animating());
+        app.wait_for_requests();
+        let source = app.collections[0].source.clone().unwrap();
+        assert_eq!((source.url.as_str(), source.header.as_str()), (url.as_str(), "X-Key: {{key}}"));
+        app.collections[0].endpoints[0].name = "Items (mine)".to_string();
+        let items_id = app.collections[0].endpoints[0].id;
+
+        // Review, then accept the defaults: /legacy goes, /orders arrives, local edits stay
+        app.sync_collection(0);
+        app.wait_for_requests();
+        let mut review = app.view.take().expect("sync review");
+        assert_eq!(app.status_message.as_deref(), Some("1 added, 1 removed, 1 different from the spec"));
+        review.handle_action(&mut app, crate::tui::input::Action::Submit);
+        let endpoints: Vec<(&str, uuid::Uuid)> = app.collections[0].endpoints.iter().map(|e| (e.name.as_str(), e.id)).collect();
+        assert_eq!(endpoints[0], ("Items (mine)", items_id));
+        assert_eq!(endpoints[1].0, "POST /orders");
+        assert_eq!(app.storage.load_collections().unwrap()[0].endpoints.len(), 2);
+
+        app.collections[0].endpoints[0].name = "GET /items".to_string();
+        app.sync_collection(0);
+        app.wait_for_requests();
+        assert!(app.view.is_none());
+        assert!(app.status_message.as_deref().unwrap().contains("is up to date"));
+        server.join().unwrap();
+
+        add_collection(&mut app, "local", 1);
+        app.sync_collection(1);
+        assert_eq!(app.error_message.as_deref(), Some("Only collections imported from a URL can be synced"));
+
+        // Esc abandons a download; nothing is applied when it would have ended
+        let silent = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        assert!(app.import_url(&format!("http://{}/spec.json", silent.local_addr().unwrap()), ""));
+        app.cancel_requests();
+        assert!(app.fetches.is_empty());
+        assert_eq!(app.collections.len(), 2);
+    }
+
+    #[test]
+    fn test_only_load_test_screen_animates() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        assert!(!app.is_animating());
+        app.current_screen = Screen::LoadTestRunning(0, 0);
+        assert!(app.is_animating());
+    }
+
+    #[test]
+    fn test_delete_last_collection_moves_selection_up() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 1);
+        add_collection(&mut app, "b", 1);
+        app.selection.collection = 1;
+
+        app.delete_collection(1);
+        assert_eq!(app.collections.len(), 1);
+        assert_eq!(app.selection.collection, 0);
+
+        app.delete_collection(0);
+        assert!(app.collections.is_empty());
+        assert_eq!(app.selection, Selection::default());
+    }
+
+    #[test]
+    fn test_delete_last_endpoint_moves_selection_up() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        add_collection(&mut app, "a", 3);
+        app.panel_focus = PanelFocus::Endpoints;
+        app.selection.endpoint = 2;
+
+        app.delete_endpoint(0, 2);
+        assert_eq!(app.current_screen, Screen::EndpointList(0));
+        assert_eq!(app.collections[0].endpoints.len(), 2);
+        assert_eq!(app.selection.endpoint, 1);
+        assert_eq!(app.focused_list(), FocusedList::Endpoints);
+    }
+
+    #[test]
+    fn test_delete_last_variable_moves_selection_up() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = test_app(&temp_dir);
+        app.variable_manager.set("a".to_string(), "1".to_string()).unwrap();
+        app.variable_manager.set("b".to_string(), "2".to_string()).unwrap();
+        app.current_screen = Screen::VariableList;
+        app.selection.variable = 1;
+
+        let last = app.variable_manager.keys()[1].clone();
+        app.delete_variable(&last);
+        assert_eq!(app.selection.variable, 0);
+    }
 }
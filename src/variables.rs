@@ -1,9 +1,15 @@
 // Variable management and storage
+// Saved variables live in variables.json; environments (staging, prod, ...)
+// are variable sets in environments/<name>.json layered on top of them.
+// Either can be exported to and imported from dotenv or JSON files. An
+// environment can also carry client defaults (headers, proxy, TLS) that apply
+// while it is the active one.
 
+use crate::http::HttpClientConfig;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -19,6 +25,12 @@ pub enum VariableError {
     
     #[error("Invalid variable name: {0}")]
     InvalidName(String),
+
+    #[error("Environment not found: {0}")]
+    EnvironmentNotFound(String),
+
+    #[error("Line {0}: expected KEY=value")]
+    Dotenv(usize),
 }
 
 pub type Result<T> = std::result::Result<T, VariableError>;
@@ -29,6 +41,39 @@ pub struct VariableSet {
     pub name: String,
     pub variables: HashMap<String, String>,
     pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "EnvironmentDefaults::is_empty")]
+    pub defaults: EnvironmentDefaults, // Only used for environments
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub protected: bool, // Environments only: requests that change data need the name typed first
+}
+
+/// Client settings that come with an environment, e.g. staging needs the
+/// corporate proxy and a tenant header
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct EnvironmentDefaults {
+    pub headers: BTreeMap<String, String>, // Added unless the endpoint sets them; may use {{variables}}
+    pub proxy: Option<String>, // Replaces the proxy from the settings
+    pub insecure: bool, // Accept invalid TLS certificates
+    pub ca_cert: Option<String>, // PEM file with an extra trusted root
+}
+
+impl EnvironmentDefaults {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+    
+    /// Layer these defaults over the client configuration from the settings
+    pub fn apply(&self, config: &mut HttpClientConfig) {
+        config.default_headers.extend(self.headers.clone());
+        if self.proxy.is_some() {
+            config.proxy = self.proxy.clone();
+        }
+        config.accept_invalid_certs |= self.insecure;
+        if self.ca_cert.is_some() {
+            config.ca_cert = self.ca_cert.clone();
+        }
+    }
 }
 
 impl VariableSet {
@@ -37,6 +82,8 @@ impl VariableSet {
             name,
             variables: HashMap::new(),
             description: None,
+            defaults: EnvironmentDefaults::default(),
+            protected: false,
         }
     }
     
@@ -68,11 +115,146 @@ impl VariableSet {
     pub fn len(&self) -> usize {
         self.variables.len()
     }
+    
+    /// Take in imported variables, returning how many were added or changed.
+    /// Merging keeps variables missing from the import, and an empty imported
+    /// value (e.g. a secret left out of an export) never overwrites one
+    pub fn import(&mut self, variables: HashMap<String, String>, mode: ImportMode) -> usize {
+        if mode == ImportMode::Replace {
+            let changed = variables.iter().filter(|(key, value)| self.variables.get(*key) != Some(value)).count();
+            self.variables = variables;
+            return changed;
+        }
+        let mut changed = 0;
+        for (key, value) in variables {
+            let keep = self.variables.get(&key).is_some_and(|current| value.is_empty() || *current == value);
+            if !keep {
+                self.variables.insert(key, value);
+                changed += 1;
+            }
+        }
+        changed
+    }
+    
+    /// The variables as a dotenv or JSON file; secrets are left empty unless included
+    pub fn export(&self, format: VariableFormat, include_secrets: bool) -> Result<String> {
+        let mut variables: Vec<(&String, &str)> = self.variables.iter()
+            .map(|(key, value)| (key, if include_secrets || !is_secret(key) { value.as_str() } else { "" }))
+            .collect();
+        variables.sort();
+        match format {
+            VariableFormat::Json => {
+                let map: serde_json::Map<String, serde_json::Value> = variables.into_iter()
+                    .map(|(key, value)| (key.clone(), value.into()))
+                    .collect();
+                Ok(serde_json::to_string_pretty(&map)?)
+            }
+            VariableFormat::Dotenv => Ok(variables.into_iter()
+                .map(|(key, value)| format!("{}={}\n", key, dotenv_quote(value)))
+                .collect()),
+        }
+    }
+}
+
+/// How imported variables combine with the ones already there
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    Merge,
+    Replace,
+}
+
+/// File format for importing and exporting variables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableFormat {
+    Dotenv,
+    Json, // A flat object of strings, or a saved variable set
+}
+
+impl VariableFormat {
+    /// JSON for `.json` files, dotenv for anything else (`.env`, `staging.env`, ...)
+    pub fn from_path(path: &Path) -> Self {
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+            VariableFormat::Json
+        } else {
+            VariableFormat::Dotenv
+        }
+    }
+    
+    /// Parse a file's contents into variables
+    pub fn parse(self, contents: &str) -> Result<HashMap<String, String>> {
+        match self {
+            VariableFormat::Json => {
+                let value: serde_json::Value = serde_json::from_str(contents)?;
+                // A saved variable set wraps the variables; an export is just the map
+                let variables = value.get("variables").filter(|v| v.is_object()).unwrap_or(&value);
+                Ok(serde_json::from_value(variables.clone())?)
+            }
+            VariableFormat::Dotenv => parse_dotenv(contents),
+        }
+    }
+}
+
+/// Names that look like they hold credentials; exports leave their values out
+pub fn is_secret(key: &str) -> bool {
+    let key = key.to_lowercase();
+    ["secret", "token", "password", "passwd", "api_key", "apikey", "private", "credential"]
+        .iter()
+        .any(|word| key.contains(word))
+}
+
+fn parse_dotenv(contents: &str) -> Result<HashMap<String, String>> {
+    let mut variables = HashMap::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line.split_once('=').ok_or(VariableError::Dotenv(number + 1))?;
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(VariableError::Dotenv(number + 1));
+        }
+        variables.insert(key.to_string(), dotenv_unquote(value.trim()));
+    }
+    Ok(variables)
+}
+
+/// `"a \"b\""` and `'a b'` are unquoted; unquoted values end at a ` #` comment
+fn dotenv_unquote(value: &str) -> String {
+    if let Some(inner) = value.strip_prefix('\'').and_then(|rest| rest.split_once('\'')) {
+        return inner.0.to_string();
+    }
+    let Some(rest) = value.strip_prefix('"') else {
+        return value.split(" #").next().unwrap_or_default().trim_end().to_string();
+    };
+    let mut unquoted = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => unquoted.push('\n'),
+                Some(other) => unquoted.push(other),
+                None => {}
+            },
+            c => unquoted.push(c),
+        }
+    }
+    unquoted
+}
+
+fn dotenv_quote(value: &str) -> String {
+    if value.chars().all(|c| c.is_ascii_alphanumeric() || "-_./:@,+".contains(c)) {
+        return value.to_string();
+    }
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
 }
 
 /// Variable manager for storing and loading variables
 pub struct VariableManager {
     storage_path: PathBuf,
+    environments_dir: PathBuf,
     current_set: VariableSet,
 }
 
@@ -97,21 +279,23 @@ impl VariableManager {
             VariableSet::new("default".to_string())
         };
         
+        let environments_dir = storage_path.with_file_name("environments");
         Ok(Self {
             storage_path,
+            environments_dir,
             current_set,
         })
     }
     
     /// Get the default storage path for variables
     fn default_storage_path() -> Result<PathBuf> {
-        let home = dirs::home_dir()
+        let dir = crate::storage::data_dir()
             .ok_or_else(|| VariableError::Io(std::io::Error::new(
                 std::io::ErrorKind::NotFound,
                 "Home directory not found"
             )))?;
         
-        Ok(home.join(".rest-api-tui").join("variables.json"))
+        Ok(dir.join("variables.json"))
     }
     
     /// Load variables from file
@@ -183,6 +367,88 @@ impl VariableManager {
         self.save()?;
         Ok(())
     }
+    
+    /// Directory holding one JSON file per environment
+    pub fn environments_dir(&self) -> &Path {
+        &self.environments_dir
+    }
+    
+    /// Names of the saved environments, sorted
+    pub fn environment_names(&self) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(&self.environments_dir) else { return Vec::new() };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+            .collect();
+        names.sort();
+        names
+    }
+    
+    fn environment_path(&self, name: &str) -> Result<PathBuf> {
+        if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(VariableError::InvalidName(name.to_string()));
+        }
+        Ok(self.environments_dir.join(format!("{}.json", name)))
+    }
+    
+    /// Load an environment by name
+    pub fn environment(&self, name: &str) -> Result<VariableSet> {
+        let path = self.environment_path(name)?;
+        if !path.exists() {
+            return Err(VariableError::EnvironmentNotFound(name.to_string()));
+        }
+        Self::load_from_file(&path)
+    }
+    
+    /// Save an environment under its name
+    pub fn save_environment(&self, environment: &VariableSet) -> Result<()> {
+        let path = self.environment_path(&environment.name)?;
+        fs::create_dir_all(&self.environments_dir)?;
+        fs::write(path, serde_json::to_string_pretty(environment)?)?;
+        Ok(())
+    }
+    
+    /// Saved variables (None) or an environment
+    pub fn variable_set(&self, environment: Option<&str>) -> Result<VariableSet> {
+        match environment {
+            Some(name) => self.environment(name),
+            None => Ok(self.current_set.clone()),
+        }
+    }
+    
+    /// Import variables into the saved variables (None) or an environment,
+    /// which is created if it doesn't exist. Returns how many changed
+    pub fn import_into(&mut self, environment: Option<&str>, variables: HashMap<String, String>, mode: ImportMode) -> Result<usize> {
+        let Some(name) = environment else {
+            let changed = self.current_set.import(variables, mode);
+            self.save()?;
+            return Ok(changed);
+        };
+        let mut set = match self.environment(name) {
+            Err(VariableError::EnvironmentNotFound(_)) => VariableSet::new(name.to_string()),
+            other => other?,
+        };
+        let changed = set.import(variables, mode);
+        self.save_environment(&set)?;
+        Ok(changed)
+    }
+    
+    /// Saved variables with an environment's values taking precedence
+    pub fn variables_for(&self, environment: Option<&str>) -> Result<HashMap<String, String>> {
+        Ok(self.resolved_set(environment)?.variables)
+    }
+    
+    /// What requests are sent with: the saved variables, overridden by the
+    /// environment's, along with the environment's name and defaults
+    pub fn resolved_set(&self, environment: Option<&str>) -> Result<VariableSet> {
+        let Some(name) = environment else { return Ok(self.current_set.clone()) };
+        let environment = self.environment(name)?;
+        let mut variables = self.current_set.variables.clone();
+        variables.extend(environment.variables.clone());
+        Ok(VariableSet { variables, ..environment })
+    }
 }
 
 impl Default for VariableManager {
@@ -262,4 +528,62 @@ mod tests {
         manager.clear().unwrap();
         assert!(manager.is_empty());
     }
+
+    #[test]
+    fn test_environments_layer_over_saved_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = VariableManager::with_path(temp_dir.path().join("variables.json")).unwrap();
+        manager.set("host".to_string(), "http://localhost".to_string()).unwrap();
+        manager.set("token".to_string(), "dev".to_string()).unwrap();
+        assert!(manager.environment_names().is_empty());
+        
+        let mut prod = VariableSet::new("prod".to_string());
+        prod.set("host".to_string(), "https://api.example.com".to_string());
+        manager.save_environment(&prod).unwrap();
+        manager.save_environment(&VariableSet::new("staging".to_string())).unwrap();
+        assert_eq!(manager.environment_names(), vec!["prod", "staging"]);
+        
+        let variables = manager.variables_for(Some("prod")).unwrap();
+        assert_eq!(variables["host"], "https://api.example.com");
+        assert_eq!(variables["token"], "dev");
+        assert_eq!(manager.variables_for(None).unwrap()["host"], "http://localhost");
+        assert!(matches!(manager.variables_for(Some("qa")), Err(VariableError::EnvironmentNotFound(_))));
+        assert!(matches!(manager.environment("../variables"), Err(VariableError::InvalidName(_))));
+    }
+
+    #[test]
+    fn test_export_and_import_round_trip() {
+        let mut set = VariableSet::new("staging".to_string());
+        set.set("host".to_string(), "https://staging.example.com".to_string());
+        set.set("greeting".to_string(), "say \"hi\" #1".to_string());
+        set.set("API_TOKEN".to_string(), "s3cr3t".to_string());
+
+        let dotenv = set.export(VariableFormat::Dotenv, false).unwrap();
+        assert_eq!(dotenv, "API_TOKEN=\ngreeting=\"say \\\"hi\\\" #1\"\nhost=https://staging.example.com\n");
+        let parsed = VariableFormat::Dotenv.parse(&format!("# shared\nexport {}extra='a b' # note\nplain=x # note", dotenv)).unwrap();
+        assert_eq!(parsed["greeting"], "say \"hi\" #1");
+        assert_eq!((parsed["API_TOKEN"].as_str(), parsed["extra"].as_str(), parsed["plain"].as_str()), ("", "a b", "x"));
+        assert!(matches!(VariableFormat::Dotenv.parse("ok=1\nnot a variable"), Err(VariableError::Dotenv(2))));
+
+        let json = set.export(VariableFormat::Json, true).unwrap();
+        assert_eq!(VariableFormat::Json.parse(&json).unwrap(), set.variables);
+        // A saved variable set file imports too
+        assert_eq!(VariableFormat::Json.parse(&serde_json::to_string(&set).unwrap()).unwrap(), set.variables);
+        assert_eq!(VariableFormat::from_path(Path::new("team.json")), VariableFormat::Json);
+        assert_eq!(VariableFormat::from_path(Path::new(".env")), VariableFormat::Dotenv);
+
+        // Merging keeps my token and my own variables; replacing takes the file as is
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = VariableManager::with_path(temp_dir.path().join("variables.json")).unwrap();
+        manager.set("API_TOKEN".to_string(), "mine".to_string()).unwrap();
+        manager.set("local".to_string(), "1".to_string()).unwrap();
+        assert_eq!(manager.import_into(None, parsed.clone(), ImportMode::Merge).unwrap(), 4);
+        assert_eq!((manager.get("API_TOKEN").unwrap().as_str(), manager.get("local").unwrap().as_str()), ("mine", "1"));
+        assert_eq!(manager.import_into(None, parsed.clone(), ImportMode::Merge).unwrap(), 0);
+        manager.import_into(None, parsed.clone(), ImportMode::Replace).unwrap();
+        assert_eq!(manager.get_all(), &parsed);
+
+        assert_eq!(manager.import_into(Some("staging"), set.variables.clone(), ImportMode::Merge).unwrap(), 3);
+        assert_eq!(manager.variable_set(Some("staging")).unwrap().variables, set.variables);
+    }
 }
@@ -0,0 +1,533 @@
+// Response visualizers
+// A small jq subset attached to an endpoint turns a JSON response into a table
+// or a key metrics view, e.g. `.items[] | {id, status, amount}` or
+// `{total: .items | length, next: .page.next}`.
+//
+// Supported: `.`, `.key`, `.["key"]`, `.[0]`, `.[-1]`, `.[]`, `|`,
+// `{a, b: filter}` and the `length` and `keys` builtins. Arrays of flat objects
+// can also be browsed as a sortable `JsonTable` without any transform.
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum VisualizeError {
+    #[error("Syntax error at column {column}: {message}")]
+    Syntax { column: usize, message: String },
+
+    #[error("{0}")]
+    Eval(String),
+
+    #[error("Response is not JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+pub type Result<T> = std::result::Result<T, VisualizeError>;
+
+/// Cells longer than this are cut off in tables
+const MAX_CELL_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Key(String),
+    Index(i64),
+    Iterate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Path(Vec<Step>),
+    Object(Vec<(String, Transform)>),
+    Length,
+    Keys,
+}
+
+/// A parsed visualizer expression: filters joined by `|`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transform {
+    filters: Vec<Filter>,
+}
+
+impl Transform {
+    pub fn parse(expression: &str) -> Result<Self> {
+        let mut parser = Parser { chars: expression.chars().collect(), pos: 0 };
+        let transform = parser.pipeline()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.chars.len() {
+            return Err(parser.error("unexpected input"));
+        }
+        Ok(transform)
+    }
+
+    /// Run the transform; each input can produce any number of outputs
+    pub fn apply(&self, input: &Value) -> Result<Vec<Value>> {
+        let mut values = vec![input.clone()];
+        for filter in &self.filters {
+            let mut next = Vec::new();
+            for value in &values {
+                next.extend(filter.apply(value)?);
+            }
+            values = next;
+        }
+        Ok(values)
+    }
+
+    /// Column order written in a final `{...}`, since JSON objects don't keep it
+    fn field_order(&self) -> Vec<String> {
+        match self.filters.last() {
+            Some(Filter::Object(fields)) => fields.iter().map(|(name, _)| name.clone()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Transform a response body and lay the result out as text
+    pub fn render(&self, body: &[u8]) -> Result<String> {
+        let input: Value = serde_json::from_slice(body)?;
+        Ok(render_values(&self.apply(&input)?, &self.field_order()))
+    }
+}
+
+impl Filter {
+    fn apply(&self, value: &Value) -> Result<Vec<Value>> {
+        match self {
+            Filter::Path(steps) => {
+                let mut values = vec![value.clone()];
+                for step in steps {
+                    let mut next = Vec::new();
+                    for value in &values {
+                        step.apply(value, &mut next)?;
+                    }
+                    values = next;
+                }
+                Ok(values)
+            }
+            Filter::Object(fields) => {
+                let mut object = Map::new();
+                for (name, transform) in fields {
+                    // One value per field; a field that yields nothing is null
+                    let value = transform.apply(value)?.into_iter().next().unwrap_or(Value::Null);
+                    object.insert(name.clone(), value);
+                }
+                Ok(vec![Value::Object(object)])
+            }
+            Filter::Length => Ok(vec![match value {
+                Value::Array(items) => items.len().into(),
+                Value::Object(map) => map.len().into(),
+                Value::String(s) => s.chars().count().into(),
+                Value::Null => 0.into(),
+                Value::Number(n) => n.as_f64().map(f64::abs).and_then(serde_json::Number::from_f64).map_or(Value::Null, Value::Number),
+                Value::Bool(_) => return Err(VisualizeError::Eval("boolean has no length".to_string())),
+            }]),
+            Filter::Keys => match value {
+                Value::Object(map) => Ok(vec![Value::Array(map.keys().cloned().map(Value::String).collect())]),
+                Value::Array(items) => Ok(vec![Value::Array((0..items.len()).map(Value::from).collect())]),
+                other => Err(VisualizeError::Eval(format!("{} has no keys", type_name(other)))),
+            },
+        }
+    }
+}
+
+impl Step {
+    fn apply(&self, value: &Value, out: &mut Vec<Value>) -> Result<()> {
+        match (self, value) {
+            (Step::Key(key), Value::Object(map)) => out.push(map.get(key).cloned().unwrap_or(Value::Null)),
+            (Step::Index(index), Value::Array(items)) => {
+                let index = if *index < 0 { items.len() as i64 + index } else { *index };
+                out.push(usize::try_from(index).ok().and_then(|i| items.get(i)).cloned().unwrap_or(Value::Null));
+            }
+            (Step::Iterate, Value::Array(items)) => out.extend(items.iter().cloned()),
+            (Step::Iterate, Value::Object(map)) => out.extend(map.values().cloned()),
+            (Step::Key(_) | Step::Index(_), Value::Null) => out.push(Value::Null),
+            (Step::Key(key), other) => {
+                return Err(VisualizeError::Eval(format!("Cannot index {} with \"{}\"", type_name(other), key)));
+            }
+            (Step::Index(_), other) => {
+                return Err(VisualizeError::Eval(format!("Cannot index {} with a number", type_name(other))));
+            }
+            (Step::Iterate, other) => {
+                return Err(VisualizeError::Eval(format!("Cannot iterate over {}", type_name(other))));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> VisualizeError {
+        VisualizeError::Syntax { column: self.pos + 1, message: message.to_string() }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected)))
+        }
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.pos;
+        while self.chars.get(self.pos).is_some_and(|c| c.is_alphanumeric() || *c == '_' || *c == '$') {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn pipeline(&mut self) -> Result<Transform> {
+        let mut filters = vec![self.filter()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            filters.push(self.filter()?);
+        }
+        // `.` on its own is the identity and can be dropped
+        filters.retain(|f| *f != Filter::Path(Vec::new()));
+        Ok(Transform { filters })
+    }
+
+    fn filter(&mut self) -> Result<Filter> {
+        match self.peek() {
+            Some('.') => self.path(),
+            Some('{') => self.object(),
+            Some(c) if c.is_alphabetic() => match self.identifier().as_str() {
+                "length" => Ok(Filter::Length),
+                "keys" => Ok(Filter::Keys),
+                name => {
+                    self.pos -= name.chars().count();
+                    Err(self.error(&format!("unknown function '{}'", name)))
+                }
+            },
+            _ => Err(self.error("expected a filter")),
+        }
+    }
+
+    fn path(&mut self) -> Result<Filter> {
+        let mut steps = Vec::new();
+        self.pos += 1; // Leading '.'
+        if self.chars.get(self.pos).is_some_and(|c| c.is_alphabetic() || *c == '_') {
+            steps.push(Step::Key(self.identifier()));
+        }
+        loop {
+            match self.chars.get(self.pos) {
+                Some('.') if self.chars.get(self.pos + 1).is_some_and(|c| c.is_alphabetic() || *c == '_') => {
+                    self.pos += 1;
+                    steps.push(Step::Key(self.identifier()));
+                }
+                Some('.') if self.chars.get(self.pos + 1) == Some(&'[') => self.pos += 1,
+                Some('[') => {
+                    self.pos += 1;
+                    steps.push(self.bracket()?);
+                }
+                _ => return Ok(Filter::Path(steps)),
+            }
+        }
+    }
+
+    /// The inside of `[...]`, after the opening bracket
+    fn bracket(&mut self) -> Result<Step> {
+        let step = match self.peek() {
+            Some(']') => Step::Iterate,
+            Some('"') => Step::Key(self.string()?),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let start = self.pos;
+                self.pos += 1;
+                while self.chars.get(self.pos).is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                let number: String = self.chars[start..self.pos].iter().collect();
+                Step::Index(number.parse().map_err(|_| self.error("invalid index"))?)
+            }
+            _ => return Err(self.error("expected ']', a number or a string")),
+        };
+        self.expect(']')?;
+        Ok(step)
+    }
+
+    fn object(&mut self) -> Result<Filter> {
+        self.pos += 1; // '{'
+        let mut fields = Vec::new();
+        loop {
+            if self.peek() == Some('}') && fields.is_empty() {
+                break;
+            }
+            let name = match self.peek() {
+                Some('"') => match self.string()? {
+                    name if !name.is_empty() => name,
+                    _ => return Err(self.error("empty field name")),
+                },
+                Some(c) if c.is_alphabetic() || c == '_' => self.identifier(),
+                _ => return Err(self.error("expected a field name")),
+            };
+            let transform = if self.peek() == Some(':') {
+                self.pos += 1;
+                self.pipeline()?
+            } else {
+                Transform { filters: vec![Filter::Path(vec![Step::Key(name.clone())])] }
+            };
+            fields.push((name, transform));
+            if self.peek() == Some(',') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        self.expect('}')?;
+        Ok(Filter::Object(fields))
+    }
+
+    /// A `"quoted"` key, starting at the opening quote
+    fn string(&mut self) -> Result<String> {
+        self.pos += 1;
+        let start = self.pos;
+        while self.chars.get(self.pos).is_some_and(|c| *c != '"') {
+            self.pos += 1;
+        }
+        if self.pos >= self.chars.len() {
+            return Err(self.error("unterminated string"));
+        }
+        let key = self.chars[start..self.pos].iter().collect();
+        self.pos += 1;
+        Ok(key)
+    }
+}
+
+/// An array of flat objects laid out as rows, for list endpoints
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonTable {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl JsonTable {
+    /// Some when the value is a non-empty array of objects with only scalar fields
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let items = value.as_array().filter(|items| !items.is_empty())?;
+        let mut columns: Vec<String> = Vec::new();
+        for item in items {
+            let object = item.as_object()?;
+            for (key, value) in object {
+                if value.is_array() || value.is_object() {
+                    return None;
+                }
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+        let rows = items.iter()
+            .map(|item| columns.iter().map(|key| item.get(key).cloned().unwrap_or(Value::Null)).collect())
+            .collect();
+        Some(Self { columns, rows })
+    }
+
+    pub fn from_body(body: &[u8]) -> Option<Self> {
+        Self::from_value(&serde_json::from_slice(body).ok()?)
+    }
+
+    /// Stable sort on one column: numbers numerically, then strings, nulls last
+    pub fn sort_by(&mut self, column: usize, descending: bool) {
+        self.rows.sort_by(|a, b| {
+            let ordering = compare_cells(&a[column], &b[column]);
+            // Missing values stay at the bottom either way
+            match (a[column].is_null(), b[column].is_null(), descending) {
+                (false, false, true) => ordering.reverse(),
+                _ => ordering,
+            }
+        });
+    }
+
+    /// Cell text, cut off at the table's cell width
+    pub fn cell(&self, row: usize, column: usize) -> String {
+        cell(&self.rows[row][column])
+    }
+}
+
+fn compare_cells(a: &Value, b: &Value) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Greater,
+        (_, Value::Null) => Ordering::Less,
+        (Value::Number(x), Value::Number(y)) => {
+            x.as_f64().unwrap_or(0.0).total_cmp(&y.as_f64().unwrap_or(0.0))
+        }
+        (Value::Number(_), _) => Ordering::Less,
+        (_, Value::Number(_)) => Ordering::Greater,
+        (Value::String(x), Value::String(y)) => x.to_lowercase().cmp(&y.to_lowercase()),
+        _ => a.to_string().cmp(&b.to_string()),
+    }
+}
+
+/// Plain text for a cell: strings without quotes, everything else as JSON
+fn cell(value: &Value) -> String {
+    let text = match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    };
+    if text.chars().count() > MAX_CELL_WIDTH {
+        text.chars().take(MAX_CELL_WIDTH - 1).chain(['…']).collect()
+    } else {
+        text
+    }
+}
+
+fn table(columns: &[String], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = columns.iter().enumerate()
+        .map(|(i, column)| rows.iter().map(|row| row[i].chars().count()).chain([column.chars().count()]).max().unwrap_or(0))
+        .collect();
+    let line = |cells: &[String]| {
+        cells.iter().zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" │ ")
+            .trim_end()
+            .to_string()
+    };
+    let mut lines = vec![
+        line(columns),
+        widths.iter().map(|w| "─".repeat(*w)).collect::<Vec<_>>().join("─┼─"),
+    ];
+    lines.extend(rows.iter().map(|row| line(row)));
+    lines.join("\n")
+}
+
+/// Objects become a table (one object: a field/value list), anything else one value per line
+pub fn render_values(values: &[Value], field_order: &[String]) -> String {
+    let objects: Vec<&Map<String, Value>> = values.iter().filter_map(Value::as_object).collect();
+    if values.is_empty() {
+        return "(no results)".to_string();
+    }
+    if objects.len() != values.len() {
+        return values.iter().map(|v| match v {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }).collect::<Vec<_>>().join("\n");
+    }
+
+    let mut columns: Vec<String> = field_order.to_vec();
+    for object in &objects {
+        for key in object.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+
+    if let [object] = objects.as_slice() {
+        let rows: Vec<Vec<String>> = columns.iter()
+            .map(|key| vec![key.clone(), object.get(key).map(cell).unwrap_or_default()])
+            .collect();
+        return table(&["Field".to_string(), "Value".to_string()], &rows);
+    }
+
+    let rows: Vec<Vec<String>> = objects.iter()
+        .map(|object| columns.iter().map(|key| object.get(key).map(cell).unwrap_or_default()).collect())
+        .collect();
+    format!("{}\n\n{} rows", table(&columns, &rows), rows.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn run(expression: &str, input: Value) -> Vec<Value> {
+        Transform::parse(expression).unwrap().apply(&input).unwrap()
+    }
+
+    #[test]
+    fn test_paths_and_builtins() {
+        let input = json!({ "items": [{ "id": 1 }, { "id": 2 }, { "id": 3 }], "page": { "next": "p2" } });
+        assert_eq!(run(".", input.clone()), vec![input.clone()]);
+        assert_eq!(run(".items[].id", input.clone()), vec![json!(1), json!(2), json!(3)]);
+        assert_eq!(run(".items[-1] | .id", input.clone()), vec![json!(3)]);
+        assert_eq!(run(".[\"page\"].next", input.clone()), vec![json!("p2")]);
+        assert_eq!(run(".items | length", input.clone()), vec![json!(3)]);
+        assert_eq!(run(".page | keys", input.clone()), vec![json!(["next"])]);
+        assert_eq!(run(".missing.deeper", input.clone()), vec![Value::Null]);
+        assert_eq!(run("{count: .items | length, next: .page.next}", input),
+            vec![json!({ "count": 3, "next": "p2" })]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(Transform::parse(".items[").unwrap_err(), VisualizeError::Syntax { column: 8, .. }));
+        assert!(matches!(Transform::parse("sum").unwrap_err(), VisualizeError::Syntax { column: 1, .. }));
+        assert!(matches!(Transform::parse("{id,}").unwrap_err(), VisualizeError::Syntax { .. }));
+        let err = Transform::parse(".name[]").unwrap().apply(&json!({ "name": "x" })).unwrap_err();
+        assert_eq!(err.to_string(), "Cannot iterate over string");
+        assert!(Transform::parse(".").unwrap().render(b"<html>").is_err());
+    }
+
+    #[test]
+    fn test_render_table_keeps_written_column_order() {
+        let body = br#"{"orders": [
+            {"id": 7, "status": "paid", "amount": 12.5, "notes": "x"},
+            {"id": 8, "status": "refunded", "amount": 3}
+        ]}"#;
+        let rendered = Transform::parse(".orders[] | {status, id, amount}").unwrap().render(body).unwrap();
+        assert_eq!(rendered, [
+            "status   │ id │ amount",
+            "─────────┼────┼───────",
+            "paid     │ 7  │ 12.5",
+            "refunded │ 8  │ 3",
+            "",
+            "2 rows",
+        ].join("\n"));
+
+        let metrics = Transform::parse("{total: .orders | length}").unwrap().render(body).unwrap();
+        assert_eq!(metrics, "Field │ Value\n──────┼──────\ntotal │ 2");
+        assert_eq!(Transform::parse(".orders[].status").unwrap().render(body).unwrap(), "paid\nrefunded");
+    }
+
+    #[test]
+    fn test_json_table_detection_and_sorting() {
+        let mut table = JsonTable::from_body(br#"[
+            {"id": 3, "name": "carol", "score": 7.5},
+            {"id": 1, "name": "Alice"},
+            {"id": 2, "name": "bob", "score": 10}
+        ]"#).unwrap();
+        assert_eq!(table.columns, vec!["id", "name", "score"]);
+        assert_eq!(table.cell(1, 2), "");
+
+        table.sort_by(2, true);
+        let ids: Vec<String> = (0..3).map(|row| table.cell(row, 0)).collect();
+        assert_eq!(ids, vec!["2", "3", "1"]);
+        table.sort_by(1, false);
+        let names: Vec<String> = (0..3).map(|row| table.cell(row, 1)).collect();
+        assert_eq!(names, vec!["Alice", "bob", "carol"]);
+
+        assert!(JsonTable::from_body(br#"[{"id": 1, "tags": ["a"]}]"#).is_none());
+        assert!(JsonTable::from_body(br#"[1, 2]"#).is_none());
+        assert!(JsonTable::from_body(br#"[]"#).is_none());
+        assert!(JsonTable::from_body(br#"{"id": 1}"#).is_none());
+    }
+}
@@ -0,0 +1,231 @@
+// WebSocket client
+// Endpoints with a ws:// or wss:// URL open a connection on a background tokio
+// task instead of sending a request. Messages to send go in over a channel;
+// what arrives, and the connection opening or closing, comes back as `WsEvent`s
+// for the TUI to poll on each tick.
+
+use crate::http::RequestDetails;
+use futures_util::{SinkExt, StreamExt};
+use std::sync::mpsc;
+use thiserror::Error;
+use tokio::runtime::Handle;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::AbortHandle;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+#[derive(Debug, Error)]
+pub enum WsError {
+    #[error("Invalid WebSocket URL: {0}")]
+    InvalidUrl(String),
+
+    #[error("Invalid header {0}")]
+    InvalidHeader(String),
+}
+
+pub type Result<T> = std::result::Result<T, WsError>;
+
+/// Headers the handshake sets itself; an endpoint's own values would break it
+const HANDSHAKE_HEADERS: &[&str] = &[
+    "host", "connection", "upgrade", "content-length", "content-type",
+    "sec-websocket-key", "sec-websocket-version", "sec-websocket-extensions",
+];
+
+/// Something that happened on a connection
+#[derive(Debug, Clone, PartialEq)]
+pub enum WsEvent {
+    /// The handshake succeeded with this HTTP status (101)
+    Connected(u16),
+    /// A message went out
+    Sent(String),
+    /// A text message arrived; binary messages are described instead
+    Received(String),
+    /// Either side closed the connection, with the close frame's code and reason if any
+    Closed(Option<String>),
+    /// Connecting failed or the connection broke
+    Error(String),
+}
+
+enum Command {
+    Send(String),
+    Close,
+}
+
+/// A connection running on a background task
+pub struct WsConnection {
+    commands: tokio_mpsc::UnboundedSender<Command>,
+    events: mpsc::Receiver<WsEvent>,
+    task: AbortHandle,
+}
+
+impl WsConnection {
+    /// Start connecting to the prepared request's URL with its headers. Only
+    /// building the handshake request can fail here; connection errors arrive
+    /// as `WsEvent::Error`.
+    pub fn connect(runtime: &Handle, request: &RequestDetails) -> Result<Self> {
+        let mut handshake = request.url.as_str().into_client_request()
+            .map_err(|e| WsError::InvalidUrl(format!("{}: {}", request.url, e)))?;
+        let mut headers: Vec<_> = request.headers.iter()
+            .filter(|(name, _)| !HANDSHAKE_HEADERS.contains(&name.to_ascii_lowercase().as_str()))
+            .collect();
+        headers.sort();
+        for (name, value) in headers {
+            let header = HeaderName::from_bytes(name.as_bytes()).map_err(|e| WsError::InvalidHeader(format!("{}: {}", name, e)))?;
+            let value = HeaderValue::from_str(value).map_err(|e| WsError::InvalidHeader(format!("{}: {}", name, e)))?;
+            handshake.headers_mut().insert(header, value);
+        }
+
+        let (commands, mut command_rx) = tokio_mpsc::unbounded_channel();
+        let (event_tx, events) = mpsc::channel();
+        let task = runtime.spawn(async move {
+            // The receiver may be gone when the screen closed first; nothing to report to then
+            let emit = |event| { let _ = event_tx.send(event); };
+            let (stream, response) = match tokio_tungstenite::connect_async(handshake).await {
+                Ok(connected) => connected,
+                Err(e) => return emit(WsEvent::Error(e.to_string())),
+            };
+            emit(WsEvent::Connected(response.status().as_u16()));
+            let (mut sink, mut stream) = stream.split();
+            loop {
+                tokio::select! {
+                    command = command_rx.recv() => match command {
+                        Some(Command::Send(text)) => match sink.send(Message::Text(text.clone())).await {
+                            Ok(()) => emit(WsEvent::Sent(text)),
+                            Err(e) => return emit(WsEvent::Error(e.to_string())),
+                        },
+                        Some(Command::Close) | None => {
+                            let _ = sink.send(Message::Close(None)).await;
+                            return emit(WsEvent::Closed(None));
+                        }
+                    },
+                    message = stream.next() => match message {
+                        Some(Ok(Message::Text(text))) => emit(WsEvent::Received(text)),
+                        Some(Ok(Message::Binary(bytes))) => emit(WsEvent::Received(format!("(binary, {} bytes)", bytes.len()))),
+                        Some(Ok(Message::Close(frame))) => {
+                            return emit(WsEvent::Closed(frame.map(|f| format!("{} {}", u16::from(f.code), f.reason).trim().to_string())));
+                        }
+                        // Pings are answered by tungstenite itself
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => return emit(WsEvent::Error(e.to_string())),
+                        None => return emit(WsEvent::Closed(None)),
+                    },
+                }
+            }
+        });
+        Ok(Self { commands, events, task: task.abort_handle() })
+    }
+
+    /// Queue a text message; false once the connection is gone
+    pub fn send(&self, text: String) -> bool {
+        !self.task.is_finished() && self.commands.send(Command::Send(text)).is_ok()
+    }
+
+    /// Events since the last poll, oldest first
+    pub fn poll(&self) -> Vec<WsEvent> {
+        self.events.try_iter().collect()
+    }
+
+    /// Send a close frame and end the connection
+    pub fn close(&self) {
+        let _ = self.commands.send(Command::Close);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for WsConnection {
+    fn drop(&mut self) {
+        // Closing politely needs the task, so it gets a moment before being stopped
+        if self.commands.send(Command::Close).is_err() {
+            self.task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::MergedHeaders;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn request(url: String, headers: HashMap<String, String>) -> RequestDetails {
+        RequestDetails {
+            method: "GET".to_string(),
+            url,
+            headers,
+            sent_headers: MergedHeaders::default(),
+            body: None,
+            body_size: 0,
+        }
+    }
+
+    async fn wait_for(connection: &WsConnection, count: usize) -> Vec<WsEvent> {
+        let mut events = Vec::new();
+        for _ in 0..200 {
+            events.extend(connection.poll());
+            if events.len() >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        events
+    }
+
+    #[tokio::test]
+    async fn test_echo_round_trip() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/echo", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut token = None;
+            #[allow(clippy::result_large_err)] // The callback's type is tungstenite's
+            let callback = |request: &tokio_tungstenite::tungstenite::handshake::server::Request, response| {
+                token = request.headers().get("authorization").map(|v| v.to_str().unwrap().to_string());
+                Ok(response)
+            };
+            let mut stream = tokio_tungstenite::accept_hdr_async(socket, callback).await.unwrap();
+            while let Some(Ok(message)) = stream.next().await {
+                match message {
+                    Message::Text(text) => stream.send(Message::Text(format!("echo: {}", text))).await.unwrap(),
+                    Message::Close(_) => break,
+                    _ => {}
+                }
+            }
+            token
+        });
+
+        let headers = HashMap::from([
+            ("Authorization".to_string(), "Bearer abc".to_string()),
+            ("Connection".to_string(), "close".to_string()),
+        ]);
+        let connection = WsConnection::connect(&Handle::current(), &request(url, headers)).unwrap();
+        assert_eq!(wait_for(&connection, 1).await, vec![WsEvent::Connected(101)]);
+        assert!(connection.send("hi".to_string()));
+        assert_eq!(wait_for(&connection, 2).await, vec![WsEvent::Sent("hi".to_string()), WsEvent::Received("echo: hi".to_string())]);
+        connection.close();
+        assert_eq!(wait_for(&connection, 1).await, vec![WsEvent::Closed(None)]);
+        assert_eq!(server.await.unwrap().as_deref(), Some("Bearer abc"));
+    }
+
+    #[tokio::test]
+    async fn test_connect_errors() {
+        assert!(matches!(
+            WsConnection::connect(&Handle::current(), &request("not a url".to_string(), HashMap::new())),
+            Err(WsError::InvalidUrl(_))
+        ));
+
+        // Nothing listens on the port once the listener is dropped
+        let port = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap().port();
+        let connection = WsConnection::connect(&Handle::current(), &request(format!("ws://127.0.0.1:{}/", port), HashMap::new())).unwrap();
+        let events = wait_for(&connection, 1).await;
+        assert!(matches!(events.as_slice(), [WsEvent::Error(_)]));
+        while !connection.is_finished() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(!connection.send("lost".to_string()));
+    }
+}